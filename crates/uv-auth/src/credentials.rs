@@ -8,7 +8,6 @@ use base64::prelude::BASE64_STANDARD;
 use base64::read::DecoderReader;
 use base64::write::EncoderWriter;
 use http::Uri;
-use netrc::Netrc;
 use reqsign::aws::DefaultSigner;
 use reqwest::Request;
 use reqwest::header::HeaderValue;
@@ -18,6 +17,8 @@ use url::Url;
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
+use crate::Netrc;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Credentials {
     /// RFC 7617 HTTP Basic Authentication
@@ -197,26 +198,16 @@ impl Credentials {
 
     /// Return [`Credentials`] for a [`Url`] from a [`Netrc`] file, if any.
     ///
-    /// If a username is provided, it must match the login in the netrc file or [`None`] is returned.
+    /// If a username is provided, only entries with a matching login are considered.
     pub(crate) fn from_netrc(
         netrc: &Netrc,
         url: &DisplaySafeUrl,
         username: Option<&str>,
     ) -> Option<Self> {
-        let host = url.host_str()?;
-        let entry = netrc
-            .hosts
-            .get(host)
-            .or_else(|| netrc.hosts.get("default"))?;
-
-        // Ensure the username matches if provided
-        if username.is_some_and(|username| username != entry.login) {
-            return None;
-        }
-
+        let entry = netrc.get(url, username)?;
         Some(Self::Basic {
             username: Username::new(Some(entry.login.clone())),
-            password: Some(Password(entry.password.clone())),
+            password: entry.password.clone().map(Password),
         })
     }
 
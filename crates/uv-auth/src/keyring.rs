@@ -1,4 +1,7 @@
+use std::sync::Mutex;
 use std::{io::Write, process::Stdio};
+
+use rustc_hash::FxHashMap;
 use tokio::process::Command;
 use tracing::{debug, instrument, trace, warn};
 use uv_redacted::DisplaySafeUrl;
@@ -16,6 +19,11 @@ static UV_SERVICE_PREFIX: &str = "uv:";
 #[derive(Debug)]
 pub struct KeyringProvider {
     backend: KeyringProviderBackend,
+    /// The results of previous lookups, keyed by service name and username.
+    ///
+    /// Lookups for different URLs on the same host fall back to the same host-level entry, so
+    /// caching avoids repeated queries to the keyring backend.
+    lookups: Mutex<FxHashMap<(String, Option<String>), Option<(String, String)>>>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -56,6 +64,7 @@ impl KeyringProvider {
     pub fn native() -> Self {
         Self {
             backend: KeyringProviderBackend::Native,
+            lookups: Mutex::default(),
         }
     }
 
@@ -63,6 +72,7 @@ impl KeyringProvider {
     pub fn subprocess() -> Self {
         Self {
             backend: KeyringProviderBackend::Subprocess,
+            lookups: Mutex::default(),
         }
     }
 
@@ -128,6 +138,7 @@ impl KeyringProvider {
         let prefixed_service = format!("{UV_SERVICE_PREFIX}{service}");
         let entry = uv_keyring::Entry::new(&prefixed_service, username)?;
         entry.set_password(password).await?;
+        self.lookups.lock().unwrap().clear();
         Ok(())
     }
 
@@ -180,10 +191,17 @@ impl KeyringProvider {
         let prefixed_service = format!("{UV_SERVICE_PREFIX}{service_name}");
         let entry = uv_keyring::Entry::new(&prefixed_service, username)?;
         entry.delete_credential().await?;
+        self.lookups.lock().unwrap().clear();
         trace!("Removed credentials for {username}@{service_name} from system keyring");
         Ok(())
     }
 
+    /// Discard the results of previous lookups, such that subsequent lookups query the keyring
+    /// backend again.
+    pub(crate) fn clear_lookups(&self) {
+        self.lookups.lock().unwrap().clear();
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -207,16 +225,7 @@ impl KeyringProvider {
         // Check the full URL first
         // <https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/network/auth.py#L376C1-L379C14>
         trace!("Checking keyring for URL {url}");
-        let mut credentials = match self.backend {
-            KeyringProviderBackend::Native => self.fetch_native(url.as_str(), username).await,
-            KeyringProviderBackend::Subprocess => {
-                self.fetch_subprocess(url.as_str(), username).await
-            }
-            #[cfg(test)]
-            KeyringProviderBackend::Dummy(ref store) => {
-                Self::fetch_dummy(store, url.as_str(), username)
-            }
-        };
+        let mut credentials = self.fetch_service(url.as_str(), username).await;
         // And fallback to a check for the host
         if credentials.is_none() {
             let host = if let Some(port) = url.port() {
@@ -225,19 +234,43 @@ impl KeyringProvider {
                 url.host_str()?.to_string()
             };
             trace!("Checking keyring for host {host}");
-            credentials = match self.backend {
-                KeyringProviderBackend::Native => self.fetch_native(&host, username).await,
-                KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
-                #[cfg(test)]
-                KeyringProviderBackend::Dummy(ref store) => {
-                    Self::fetch_dummy(store, &host, username)
-                }
-            };
+            credentials = self.fetch_service(&host, username).await;
         }
 
         credentials.map(|(username, password)| Credentials::basic(Some(username), Some(password)))
     }
 
+    /// Fetch the username and password for the given service from the keyring backend, reusing
+    /// the result of a previous lookup if available.
+    async fn fetch_service(
+        &self,
+        service_name: &str,
+        username: Option<&str>,
+    ) -> Option<(String, String)> {
+        let key = (service_name.to_string(), username.map(ToString::to_string));
+        if let Some(credentials) = self.lookups.lock().unwrap().get(&key) {
+            trace!("Using cached keyring lookup for {service_name}");
+            return credentials.clone();
+        }
+
+        let credentials = match self.backend {
+            KeyringProviderBackend::Native => self.fetch_native(service_name, username).await,
+            KeyringProviderBackend::Subprocess => {
+                self.fetch_subprocess(service_name, username).await
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(ref store) => {
+                Self::fetch_dummy(store, service_name, username)
+            }
+        };
+
+        self.lookups
+            .lock()
+            .unwrap()
+            .insert(key, credentials.clone());
+        credentials
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(
         &self,
@@ -381,6 +414,7 @@ impl KeyringProvider {
                     .map(|(service, username, password)| (service.into(), username, password))
                     .collect(),
             ),
+            lookups: Mutex::default(),
         }
     }
 
@@ -389,6 +423,7 @@ impl KeyringProvider {
     pub fn empty() -> Self {
         Self {
             backend: KeyringProviderBackend::Dummy(Vec::new()),
+            lookups: Mutex::default(),
         }
     }
 }
@@ -477,6 +512,31 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn fetch_url_cached() {
+        let url = Url::parse("https://example.com/").unwrap();
+        let keyring = KeyringProvider::dummy([(url.host_str().unwrap(), "user", "password")]);
+        for path in ["foo", "bar"] {
+            assert_eq!(
+                keyring
+                    .fetch(
+                        DisplaySafeUrl::ref_cast(&url.join(path).unwrap()),
+                        Some("user")
+                    )
+                    .await,
+                Some(Credentials::basic(
+                    Some("user".to_string()),
+                    Some("password".to_string())
+                ))
+            );
+        }
+
+        // The host-level lookup is shared across URLs.
+        let lookups = keyring.lookups.lock().unwrap();
+        assert_eq!(lookups.len(), 3);
+        assert!(lookups.contains_key(&("example.com".to_string(), Some("user".to_string()))));
+    }
+
     #[tokio::test]
     async fn fetch_url_no_match() {
         let url = Url::parse("https://example.com").unwrap();
@@ -11,6 +11,7 @@ pub use credentials::{Credentials, Username};
 pub use index::{AuthPolicy, Index, Indexes};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
+pub use netrc::{Netrc, NetrcError};
 pub use pyx::{
     DEFAULT_TOLERANCE_SECS, PyxJwt, PyxOAuthTokens, PyxTokenStore, PyxTokens, TokenStoreError,
 };
@@ -24,6 +25,7 @@ mod credentials;
 mod index;
 mod keyring;
 mod middleware;
+mod netrc;
 mod providers;
 mod pyx;
 mod realm;
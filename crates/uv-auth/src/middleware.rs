@@ -2,9 +2,9 @@ use std::sync::{Arc, LazyLock};
 
 use anyhow::{anyhow, format_err};
 use http::{Extensions, StatusCode};
-use netrc::Netrc;
 use reqwest::{Request, Response};
 use reqwest_middleware::{ClientWithMiddleware, Error, Middleware, Next};
+use rustc_hash::FxHashMap;
 use tokio::sync::Mutex;
 use tracing::{debug, trace, warn};
 
@@ -14,7 +14,7 @@ use uv_static::EnvVars;
 use uv_warnings::owo_colors::OwoColorize;
 
 use crate::credentials::Authentication;
-use crate::providers::{HuggingFaceProvider, S3EndpointProvider};
+use crate::providers::{HuggingFaceProvider, RegistryProvider, S3EndpointProvider};
 use crate::pyx::{DEFAULT_TOLERANCE_SECS, PyxTokenStore};
 use crate::{
     AccessToken, CREDENTIALS_CACHE, CredentialsCache, KeyringProvider,
@@ -23,7 +23,7 @@ use crate::{
     index::{AuthPolicy, Indexes},
     realm::Realm,
 };
-use crate::{Index, TextCredentialStore, TomlCredentialError};
+use crate::{Index, Netrc, NetrcError, TextCredentialStore, TomlCredentialError};
 
 /// Cached check for whether we're running in Dependabot.
 static IS_DEPENDABOT: LazyLock<bool> =
@@ -40,7 +40,7 @@ impl Default for NetrcMode {
     fn default() -> Self {
         Self::Automatic(LazyLock::new(|| match Netrc::new() {
             Ok(netrc) => Some(netrc),
-            Err(netrc::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            Err(NetrcError::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
                 debug!("No netrc file found");
                 None
             }
@@ -143,6 +143,9 @@ pub struct AuthMiddleware {
     pyx_token_store: Option<PyxTokenStore>,
     /// Tokens to use for persistent credentials.
     pyx_token_state: Mutex<TokenState>,
+    /// Credentials that were issued after a server rejected the previous credentials, per realm
+    /// and username.
+    refreshed: Mutex<FxHashMap<(Realm, Username), Arc<Authentication>>>,
     preview: Preview,
 }
 
@@ -158,6 +161,7 @@ impl AuthMiddleware {
             base_client: None,
             pyx_token_store: None,
             pyx_token_state: Mutex::new(TokenState::Uninitialized),
+            refreshed: Mutex::new(FxHashMap::default()),
             preview: Preview::default(),
         }
     }
@@ -499,6 +503,9 @@ impl AuthMiddleware {
     /// Run a request to completion.
     ///
     /// If credentials are present, insert them into the cache on success.
+    ///
+    /// If the server rejects the credentials on the request and the `credential-refresh` preview
+    /// feature is enabled, new credentials are requested and the request is retried once.
     async fn complete_request(
         &self,
         credentials: Option<Arc<Authentication>>,
@@ -507,15 +514,46 @@ impl AuthMiddleware {
         next: Next<'_>,
         auth_policy: AuthPolicy,
     ) -> reqwest_middleware::Result<Response> {
+        let url = DisplaySafeUrl::from_url(request.url().clone());
+        if let Some(credentials) = credentials.as_ref() {
+            if matches!(auth_policy, AuthPolicy::Always) && credentials.password().is_none() {
+                return Err(Error::Middleware(format_err!("Missing password for {url}")));
+            }
+        }
+
+        // Clone the request so we can retry it if the credentials are rejected. Streaming bodies
+        // can't be cloned, so those requests are never retried.
+        let retry_request = if self.preview.is_enabled(PreviewFeatures::CREDENTIAL_REFRESH) {
+            request.try_clone()
+        } else {
+            None
+        };
+
+        let mut result = next.clone().run(request, extensions).await;
+
+        let mut credentials = credentials;
+        if let Some(retry_request) = retry_request {
+            if result.as_ref().is_ok_and(|response| {
+                matches!(
+                    response.status(),
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+                )
+            }) {
+                if let Some(rejected) = Credentials::from_request(&retry_request) {
+                    if let Some(refreshed) = self.refresh_credentials(&rejected, &url).await {
+                        trace!("Retrying request for {url} with refreshed credentials");
+                        let retry_request = refreshed.authenticate(retry_request).await;
+                        result = next.run(retry_request, extensions).await;
+                        credentials = Some(refreshed);
+                    }
+                }
+            }
+        }
+
         let Some(credentials) = credentials else {
             // Nothing to insert into the cache if we don't have credentials
-            return next.run(request, extensions).await;
+            return result;
         };
-        let url = DisplaySafeUrl::from_url(request.url().clone());
-        if matches!(auth_policy, AuthPolicy::Always) && credentials.password().is_none() {
-            return Err(Error::Middleware(format_err!("Missing password for {url}")));
-        }
-        let result = next.run(request, extensions).await;
 
         // Update the cache with new credentials on a successful request
         if result
@@ -614,6 +652,84 @@ impl AuthMiddleware {
             .await
     }
 
+    /// Request new credentials for a URL after the server rejected the `rejected` credentials.
+    ///
+    /// Credentials are refreshed at most once per realm and username: concurrent requests that were rejected
+    /// with the stale credentials reuse the refreshed credentials, and if the refreshed
+    /// credentials are rejected too, we give up rather than refreshing again.
+    async fn refresh_credentials(
+        &self,
+        rejected: &Credentials,
+        url: &DisplaySafeUrl,
+    ) -> Option<Arc<Authentication>> {
+        let key = (Realm::from(&**url), rejected.to_username());
+        let rejected = Authentication::from(rejected.clone());
+
+        // Hold the lock for the duration of the refresh, to avoid requesting several tokens for
+        // the same realm in parallel.
+        let mut refreshed = self.refreshed.lock().await;
+        if let Some(credentials) = refreshed.get(&key) {
+            if **credentials == rejected {
+                trace!("Refreshed credentials for {url} were rejected, not refreshing again");
+                return None;
+            }
+            trace!("Using previously refreshed credentials for {url}");
+            return Some(credentials.clone());
+        }
+
+        debug!("Credentials for {url} were rejected, requesting new credentials");
+
+        let index = self.indexes.index_for(url);
+        let credentials = if let Some(credentials) = match RegistryProvider::from_url(url) {
+            Some(provider) => {
+                debug!("Requesting a new {provider} token for {url}");
+                provider.fetch().await
+            }
+            None => None,
+        } {
+            Some(credentials)
+        } else if let Some(credentials) = match (self.keyring.as_ref(), rejected.username()) {
+            (Some(keyring), Some(username)) => {
+                // Discard the previous lookups, since a keyring plugin may issue a new token.
+                keyring.clear_lookups();
+                if let Some(index) = index {
+                    debug!(
+                        "Checking keyring for new credentials for index URL {}@{}",
+                        username, index.url
+                    );
+                    keyring
+                        .fetch(DisplaySafeUrl::ref_cast(&index.url), Some(username))
+                        .await
+                } else {
+                    debug!("Checking keyring for new credentials for full URL {username}@{url}");
+                    keyring.fetch(url, Some(username)).await
+                }
+            }
+            _ => None,
+        } {
+            Some(credentials)
+        } else {
+            None
+        }
+        .map(Authentication::from)
+        .filter(|credentials| *credentials != rejected)
+        .map(Arc::new);
+
+        let Some(credentials) = credentials else {
+            debug!("No new credentials available for {url}");
+            return None;
+        };
+
+        // Replace the rejected credentials for the index, so that subsequent requests don't
+        // need to be rejected first.
+        if let Some(index) = index {
+            self.cache().insert(&index.url, credentials.clone());
+        }
+
+        refreshed.insert(key, credentials.clone());
+        Some(credentials)
+    }
+
     /// Fetch credentials for a URL.
     ///
     /// Supports netrc file and keyring lookups.
@@ -714,7 +830,6 @@ impl AuthMiddleware {
             }
         }
 
-        // Netrc support based on: <https://github.com/gribouille/netrc>.
         let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
             debug!("Checking netrc for credentials for {url}");
             Credentials::from_netrc(
@@ -796,6 +911,16 @@ impl AuthMiddleware {
         } {
             debug!("Found credentials in keyring for {url}");
             Some(credentials)
+        // Registries that issue short-lived tokens through their vendor's command-line tool.
+        } else if let Some(credentials) = match RegistryProvider::from_url(url) {
+            Some(provider) if self.preview.is_enabled(PreviewFeatures::CREDENTIAL_REFRESH) => {
+                debug!("Requesting {provider} token for {url}");
+                provider.fetch().await
+            }
+            _ => None,
+        } {
+            debug!("Found registry token for {url}");
+            Some(credentials)
         } else {
             None
         }
@@ -1293,6 +1418,76 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_keyring_refresh_rejected_credentials() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let keyring = || {
+            KeyringProvider::dummy([(
+                format!(
+                    "{}:{}",
+                    base_url.host_str().unwrap(),
+                    base_url.port().unwrap()
+                ),
+                username,
+                password,
+            )])
+        };
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some("expired")).unwrap();
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_keyring(Some(keyring())),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(url.clone()).send().await?.status(),
+            401,
+            "Rejected credentials are not refreshed without the preview feature"
+        );
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_keyring(Some(keyring()))
+                    .with_preview(Preview::new(PreviewFeatures::CREDENTIAL_REFRESH)),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(url.clone()).send().await?.status(),
+            200,
+            "Rejected credentials should be refreshed from the keyring"
+        );
+
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            200,
+            "Subsequent requests with the rejected credentials should use the refreshed credentials"
+        );
+
+        let mut url = base_url.clone();
+        url.set_username("other_user").unwrap();
+        url.set_password(Some("expired")).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials are not refreshed when the keyring has none for the username"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_keyring_always_authenticate() -> Result<(), Error> {
         let username = "user";
@@ -0,0 +1,502 @@
+//! Parse `.netrc` files, with support for scoping credentials to a port, a path prefix, or a realm.
+//!
+//! In addition to the standard `machine <host>` entries, a machine may be scoped to:
+//!
+//! - A port, e.g., `machine example.com:8443`.
+//! - A path prefix, e.g., `machine example.com/simple/team-a/`.
+//! - A realm, e.g., `machine https://example.com:8443/simple/team-a/`, in which case the scheme must
+//!   match too.
+//!
+//! When multiple machines match a URL, the most specific match is used, i.e., the machine with the
+//! longest path prefix, followed by machines scoped to a port, then to a scheme. Among equally
+//! specific machines, the first in the file is used. The `default` entry is used only if no machine
+//! matches.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use url::Url;
+
+use uv_static::EnvVars;
+
+#[derive(Debug, thiserror::Error)]
+pub enum NetrcError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse netrc file at line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+/// The credentials for a machine in a `.netrc` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct NetrcEntry {
+    pub(crate) login: String,
+    pub(crate) password: Option<String>,
+}
+
+/// A parsed `.netrc` file.
+#[derive(Debug, Clone, Default)]
+pub struct Netrc {
+    /// The `machine` entries, in the order in which they appear in the file.
+    machines: Vec<(MachineScope, NetrcEntry)>,
+    /// The `default` entry, if any.
+    default: Option<NetrcEntry>,
+}
+
+impl Netrc {
+    /// Read the `.netrc` file from the path in `NETRC`, falling back to `~/.netrc` (or `~/_netrc`
+    /// on Windows).
+    pub fn new() -> Result<Self, NetrcError> {
+        Self::from_file(Self::default_path()?)
+    }
+
+    /// Read a `.netrc` file from the given path.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, NetrcError> {
+        let content = fs_err::read_to_string(path.as_ref())?;
+        content.parse()
+    }
+
+    /// Return the path to the `.netrc` file.
+    fn default_path() -> Result<PathBuf, NetrcError> {
+        if let Some(path) = std::env::var_os(EnvVars::NETRC) {
+            return Ok(PathBuf::from(path));
+        }
+        let home = etcetera::home_dir().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "Could not determine the home directory",
+            )
+        })?;
+        let path = home.join(".netrc");
+        if cfg!(windows) && !path.exists() {
+            return Ok(home.join("_netrc"));
+        }
+        Ok(path)
+    }
+
+    /// Return the credentials for the given URL, if any.
+    ///
+    /// If a username is provided, only entries with a matching login are considered.
+    pub(crate) fn get(&self, url: &Url, username: Option<&str>) -> Option<&NetrcEntry> {
+        let matches_username =
+            |entry: &NetrcEntry| username.is_none_or(|username| username == entry.login);
+
+        let mut best: Option<((usize, bool, bool), &NetrcEntry)> = None;
+        for (scope, entry) in &self.machines {
+            if !scope.matches(url) || !matches_username(entry) {
+                continue;
+            }
+            let specificity = scope.specificity();
+            if best.is_none_or(|(best, _)| specificity > best) {
+                best = Some((specificity, entry));
+            }
+        }
+
+        best.map(|(_, entry)| entry).or_else(|| {
+            self.default
+                .as_ref()
+                .filter(|entry| matches_username(entry))
+        })
+    }
+}
+
+impl FromStr for Netrc {
+    type Err = NetrcError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut netrc = Self::default();
+
+        // The entry being parsed, with its scope (or `None`, for the `default` entry).
+        let mut current: Option<(Option<MachineScope>, NetrcEntry)> = None;
+        let mut finish = |current: Option<(Option<MachineScope>, NetrcEntry)>| match current {
+            Some((Some(scope), entry)) => netrc.machines.push((scope, entry)),
+            Some((None, entry)) => {
+                // Per the `ftp` manual, there can only be one `default` entry; keep the first.
+                netrc.default.get_or_insert(entry);
+            }
+            None => {}
+        };
+
+        let mut tokens = tokenize(content)?.into_iter();
+        while let Some((line, token)) = tokens.next() {
+            let mut value = |keyword: &str| {
+                tokens
+                    .next()
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| NetrcError::Parse {
+                        line,
+                        message: format!("Expected a value after `{keyword}`"),
+                    })
+            };
+            match token.as_str() {
+                "machine" => {
+                    let machine = value("machine")?;
+                    let scope = machine
+                        .parse::<MachineScope>()
+                        .map_err(|message| NetrcError::Parse { line, message })?;
+                    finish(current.replace((Some(scope), NetrcEntry::default())));
+                }
+                "default" => {
+                    finish(current.replace((None, NetrcEntry::default())));
+                }
+                "login" | "password" | "account" => {
+                    let value = value(&token)?;
+                    let Some((_, entry)) = current.as_mut() else {
+                        return Err(NetrcError::Parse {
+                            line,
+                            message: format!("Expected `{token}` to follow `machine` or `default`"),
+                        });
+                    };
+                    match token.as_str() {
+                        "login" => entry.login = value,
+                        "password" => entry.password = Some(value),
+                        _ => {}
+                    }
+                }
+                _ => {
+                    return Err(NetrcError::Parse {
+                        line,
+                        message: format!("Unexpected token `{token}`"),
+                    });
+                }
+            }
+        }
+        finish(current);
+
+        Ok(netrc)
+    }
+}
+
+/// The URLs to which a `machine` entry applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MachineScope {
+    /// The scheme, if the machine is a URL.
+    scheme: Option<String>,
+    /// The host, in lowercase.
+    host: String,
+    /// The port, if any; otherwise, the machine applies to all ports.
+    port: Option<u16>,
+    /// The path prefix, if any, without a trailing slash.
+    path: Option<String>,
+}
+
+impl MachineScope {
+    /// Returns `true` if the machine applies to the given URL.
+    fn matches(&self, url: &Url) -> bool {
+        if self
+            .scheme
+            .as_deref()
+            .is_some_and(|scheme| scheme != url.scheme())
+        {
+            return false;
+        }
+        if !url
+            .host_str()
+            .is_some_and(|host| host.eq_ignore_ascii_case(&self.host))
+        {
+            return false;
+        }
+        if self
+            .port
+            .is_some_and(|port| url.port_or_known_default() != Some(port))
+        {
+            return false;
+        }
+        if let Some(prefix) = self.path.as_deref() {
+            // Match on path segments, such that `/team-a` doesn't match `/team-ab`.
+            let path = url.path();
+            if !path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The specificity of the machine, in increasing order of precedence: the length of the path
+    /// prefix, whether a port is specified, and whether a scheme is specified.
+    fn specificity(&self) -> (usize, bool, bool) {
+        (
+            self.path.as_deref().map_or(0, str::len),
+            self.port.is_some(),
+            self.scheme.is_some(),
+        )
+    }
+}
+
+impl FromStr for MachineScope {
+    type Err = String;
+
+    fn from_str(machine: &str) -> Result<Self, Self::Err> {
+        let normalize_path = |path: &str| {
+            let path = path.trim_end_matches('/');
+            (!path.is_empty()).then(|| path.to_string())
+        };
+
+        // A realm, e.g., `https://example.com:8443/simple/`.
+        if machine.contains("://") {
+            let url = Url::parse(machine)
+                .map_err(|err| format!("Invalid machine URL `{machine}`: {err}"))?;
+            let host = url
+                .host_str()
+                .ok_or_else(|| format!("Invalid machine URL `{machine}`: missing host"))?;
+            return Ok(Self {
+                scheme: Some(url.scheme().to_string()),
+                host: host.to_ascii_lowercase(),
+                port: url.port_or_known_default(),
+                path: normalize_path(url.path()),
+            });
+        }
+
+        // A host, with an optional port and path prefix, e.g., `example.com:8443/simple/`.
+        let (authority, path) = match machine.find('/') {
+            Some(index) => machine.split_at(index),
+            None => (machine, ""),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            // Avoid splitting IPv6 addresses without a port, e.g., `[::1]`.
+            Some((host, port)) if !authority.ends_with(']') => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| format!("Invalid port in machine `{machine}`"))?;
+                (host, Some(port))
+            }
+            _ => (authority, None),
+        };
+        if host.is_empty() {
+            return Err(format!("Missing host in machine `{machine}`"));
+        }
+        Ok(Self {
+            scheme: None,
+            host: host.to_ascii_lowercase(),
+            port,
+            path: normalize_path(path),
+        })
+    }
+}
+
+/// Split a `.netrc` file into tokens, along with the line on which each token starts.
+///
+/// Tokens are separated by whitespace, and may be quoted with double quotes, in which case a
+/// backslash escapes the following character. Comments (starting with `#`) and macro definitions
+/// (`macdef`, ending at the next blank line) are skipped.
+fn tokenize(content: &str) -> Result<Vec<(usize, String)>, NetrcError> {
+    let mut tokens = Vec::new();
+    let mut in_macro = false;
+
+    for (index, text) in content.lines().enumerate() {
+        let line = index + 1;
+
+        if in_macro {
+            if text.trim().is_empty() {
+                in_macro = false;
+            }
+            continue;
+        }
+
+        let mut chars = text.chars().peekable();
+        let mut line_tokens = Vec::new();
+        loop {
+            while chars.next_if(|char| char.is_whitespace()).is_some() {}
+            let Some(&first) = chars.peek() else {
+                break;
+            };
+            if first == '#' {
+                break;
+            }
+
+            let mut token = String::new();
+            if first == '"' {
+                chars.next();
+                let mut closed = false;
+                while let Some(char) = chars.next() {
+                    match char {
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        '\\' => token.extend(chars.next()),
+                        _ => token.push(char),
+                    }
+                }
+                if !closed {
+                    return Err(NetrcError::Parse {
+                        line,
+                        message: "Unterminated quoted string".to_string(),
+                    });
+                }
+                line_tokens.push((token, true));
+            } else {
+                while let Some(char) = chars.next_if(|char| !char.is_whitespace()) {
+                    token.push(char);
+                }
+                line_tokens.push((token, false));
+            }
+        }
+
+        let mut line_tokens = line_tokens.into_iter();
+        while let Some((token, quoted)) = line_tokens.next() {
+            if token == "macdef" && !quoted {
+                // Skip the macro name and the rest of the line, then the macro body.
+                in_macro = true;
+                break;
+            }
+            tokens.push((line, token));
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get<'a>(netrc: &'a Netrc, url: &str, username: Option<&str>) -> Option<&'a str> {
+        netrc
+            .get(&Url::parse(url).unwrap(), username)
+            .and_then(|entry| entry.password.as_deref())
+    }
+
+    #[test]
+    fn parse() {
+        let netrc: Netrc = r#"
+            # A comment.
+            machine example.com
+                login user
+                password "pass word" # A trailing comment.
+
+            macdef init
+            cd /pub
+            machine ignored.com login ignored password ignored
+
+            machine other.com login other password "quoted \" password"
+            default login anonymous password guest
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            get(&netrc, "https://example.com/simple/", None),
+            Some("pass word")
+        );
+        assert_eq!(
+            get(&netrc, "https://other.com/simple/", None),
+            Some("quoted \" password")
+        );
+        assert_eq!(get(&netrc, "https://ignored.com/", None), Some("guest"));
+        assert_eq!(get(&netrc, "https://unknown.com/", None), Some("guest"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "login user".parse::<Netrc>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse netrc file at line 1: Expected `login` to follow `machine` or `default`"
+        );
+
+        let err = "machine example.com\nlogin".parse::<Netrc>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse netrc file at line 2: Expected a value after `login`"
+        );
+
+        let err = "machine example.com:port".parse::<Netrc>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse netrc file at line 1: Invalid port in machine `example.com:port`"
+        );
+
+        let err = "machine example.com login user password \"unterminated"
+            .parse::<Netrc>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse netrc file at line 1: Unterminated quoted string"
+        );
+    }
+
+    #[test]
+    fn most_specific_match() {
+        let netrc: Netrc = "
+            machine example.com login host password host
+            machine example.com:8443 login port password port
+            machine example.com/simple/team-a/ login team-a password team-a
+            machine example.com/simple/team-b login team-b password team-b
+            machine http://example.com/simple/team-b/ login realm password realm
+        "
+        .parse()
+        .unwrap();
+
+        // A host-only machine applies to any port and path.
+        assert_eq!(
+            get(&netrc, "https://example.com/simple/", None),
+            Some("host")
+        );
+        assert_eq!(
+            get(&netrc, "https://example.com:9000/simple/", None),
+            Some("host")
+        );
+
+        // A port-specific machine takes precedence over a host-only machine.
+        assert_eq!(
+            get(&netrc, "https://example.com:8443/simple/", None),
+            Some("port")
+        );
+
+        // A path prefix takes precedence, and matches on path segments.
+        assert_eq!(
+            get(&netrc, "https://example.com/simple/team-a/pkg/", None),
+            Some("team-a")
+        );
+        assert_eq!(
+            get(&netrc, "https://example.com:8443/simple/team-a/pkg/", None),
+            Some("team-a")
+        );
+        assert_eq!(
+            get(&netrc, "https://example.com/simple/team-b/pkg/", None),
+            Some("team-b")
+        );
+        assert_eq!(
+            get(&netrc, "https://example.com/simple/team-ab/pkg/", None),
+            Some("host")
+        );
+
+        // A realm must match the scheme and the (default) port.
+        assert_eq!(
+            get(&netrc, "http://example.com/simple/team-b/pkg/", None),
+            Some("realm")
+        );
+        assert_eq!(
+            get(&netrc, "http://example.com:8080/simple/team-b/pkg/", None),
+            Some("team-b")
+        );
+    }
+
+    #[test]
+    fn multiple_logins() {
+        let netrc: Netrc = "
+            machine example.com login alice password alice
+            machine example.com login bob password bob
+            default login anonymous password guest
+        "
+        .parse()
+        .unwrap();
+
+        // The first matching machine is used, unless a username is provided.
+        assert_eq!(get(&netrc, "https://example.com/", None), Some("alice"));
+        assert_eq!(
+            get(&netrc, "https://example.com/", Some("bob")),
+            Some("bob")
+        );
+        assert_eq!(
+            get(&netrc, "https://example.com/", Some("anonymous")),
+            Some("guest")
+        );
+        assert_eq!(get(&netrc, "https://example.com/", Some("carol")), None);
+    }
+}
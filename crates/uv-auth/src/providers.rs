@@ -1,8 +1,10 @@
 use std::borrow::Cow;
+use std::process::Stdio;
 use std::sync::LazyLock;
 
 use reqsign::aws::DefaultSigner;
-use tracing::debug;
+use tokio::process::Command;
+use tracing::{debug, warn};
 use url::Url;
 
 use uv_preview::{Preview, PreviewFeatures};
@@ -95,3 +97,226 @@ impl S3EndpointProvider {
         None
     }
 }
+
+/// A package registry that issues short-lived tokens through its vendor's command-line tool.
+///
+/// Tokens for these registries typically expire after a few hours (e.g., AWS CodeArtifact tokens
+/// default to a lifetime of twelve hours), so they're requested on demand and requested again if
+/// the registry rejects a previously issued token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RegistryProvider {
+    /// An AWS CodeArtifact repository, authenticated via `aws codeartifact`.
+    CodeArtifact {
+        domain: String,
+        owner: String,
+        region: String,
+    },
+    /// An Azure Artifacts feed, authenticated via `az account get-access-token`.
+    AzureArtifacts,
+    /// A JFrog Artifactory instance, authenticated via `jf access-token-create`.
+    Artifactory,
+}
+
+impl RegistryProvider {
+    /// The Azure DevOps resource identifier for which to request access tokens.
+    ///
+    /// See: <https://learn.microsoft.com/en-us/rest/api/azure/devops/tokens/>
+    const AZURE_DEVOPS_RESOURCE: &'static str = "499b84ac-1321-427f-aa17-267ca6975798";
+
+    /// Determine the registry provider for the given URL, if it belongs to a known registry.
+    pub(crate) fn from_url(url: &Url) -> Option<Self> {
+        let host = url.host_str()?;
+
+        // e.g., `my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com`
+        if let Some(prefix) = host.strip_suffix(".amazonaws.com") {
+            let (prefix, region) = prefix.split_once(".d.codeartifact.")?;
+            let (domain, owner) = prefix.rsplit_once('-')?;
+            if domain.is_empty() || owner.is_empty() || region.is_empty() {
+                return None;
+            }
+            return Some(Self::CodeArtifact {
+                domain: domain.to_string(),
+                owner: owner.to_string(),
+                region: region.to_string(),
+            });
+        }
+
+        // e.g., `pkgs.dev.azure.com` or `my-org.pkgs.visualstudio.com`
+        if host == "pkgs.dev.azure.com" || host.ends_with(".pkgs.visualstudio.com") {
+            return Some(Self::AzureArtifacts);
+        }
+
+        // e.g., `my-org.jfrog.io`
+        if host.ends_with(".jfrog.io") {
+            return Some(Self::Artifactory);
+        }
+
+        None
+    }
+
+    /// Request a fresh token for the registry from the vendor's command-line tool.
+    ///
+    /// Returns [`None`] if the tool is unavailable or fails to produce a token.
+    pub(crate) async fn fetch(&self) -> Option<Credentials> {
+        let mut command = match self {
+            Self::CodeArtifact {
+                domain,
+                owner,
+                region,
+            } => {
+                let mut command = Command::new("aws");
+                command
+                    .arg("codeartifact")
+                    .arg("get-authorization-token")
+                    .arg("--domain")
+                    .arg(domain)
+                    .arg("--domain-owner")
+                    .arg(owner)
+                    .arg("--region")
+                    .arg(region)
+                    .arg("--query")
+                    .arg("authorizationToken")
+                    .arg("--output")
+                    .arg("text");
+                command
+            }
+            Self::AzureArtifacts => {
+                let mut command = Command::new("az");
+                command
+                    .arg("account")
+                    .arg("get-access-token")
+                    .arg("--resource")
+                    .arg(Self::AZURE_DEVOPS_RESOURCE)
+                    .arg("--query")
+                    .arg("accessToken")
+                    .arg("--output")
+                    .arg("tsv");
+                command
+            }
+            Self::Artifactory => {
+                let mut command = Command::new("jf");
+                command.arg("access-token-create");
+                command
+            }
+        };
+
+        let output = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .await
+            .inspect_err(|err| debug!("Failed to run `{}`: {err}", self.program()))
+            .ok()?;
+
+        if !output.status.success() {
+            debug!(
+                "`{}` exited with {}: {}",
+                self.program(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+
+        let stdout = String::from_utf8(output.stdout)
+            .inspect_err(|err| warn!("Failed to parse output of `{}`: {err}", self.program()))
+            .ok()?;
+
+        match self {
+            Self::CodeArtifact { .. } => {
+                let token = stdout.trim();
+                (!token.is_empty())
+                    .then(|| Credentials::basic(Some("aws".to_string()), Some(token.to_string())))
+            }
+            Self::AzureArtifacts => {
+                // Azure Artifacts accepts any username alongside an access token.
+                let token = stdout.trim();
+                (!token.is_empty())
+                    .then(|| Credentials::basic(Some("azure".to_string()), Some(token.to_string())))
+            }
+            Self::Artifactory => {
+                #[derive(serde::Deserialize)]
+                struct AccessToken {
+                    access_token: String,
+                }
+
+                let token = serde_json::from_str::<AccessToken>(&stdout)
+                    .inspect_err(|err| {
+                        warn!("Failed to parse output of `{}`: {err}", self.program());
+                    })
+                    .ok()?
+                    .access_token;
+                (!token.is_empty()).then(|| Credentials::bearer(token.into_bytes()))
+            }
+        }
+    }
+
+    /// The name of the command-line tool used to request tokens.
+    fn program(&self) -> &'static str {
+        match self {
+            Self::CodeArtifact { .. } => "aws",
+            Self::AzureArtifacts => "az",
+            Self::Artifactory => "jf",
+        }
+    }
+}
+
+impl std::fmt::Display for RegistryProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CodeArtifact { .. } => write!(f, "AWS CodeArtifact"),
+            Self::AzureArtifacts => write!(f, "Azure Artifacts"),
+            Self::Artifactory => write!(f, "JFrog Artifactory"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::RegistryProvider;
+
+    #[test]
+    fn registry_provider_from_url() {
+        let url = Url::parse(
+            "https://my-domain-111122223333.d.codeartifact.us-west-2.amazonaws.com/pypi/repo/simple/",
+        )
+        .unwrap();
+        assert_eq!(
+            RegistryProvider::from_url(&url),
+            Some(RegistryProvider::CodeArtifact {
+                domain: "my-domain".to_string(),
+                owner: "111122223333".to_string(),
+                region: "us-west-2".to_string(),
+            })
+        );
+
+        let url = Url::parse("https://pkgs.dev.azure.com/org/project/_packaging/feed/pypi/simple/")
+            .unwrap();
+        assert_eq!(
+            RegistryProvider::from_url(&url),
+            Some(RegistryProvider::AzureArtifacts)
+        );
+
+        let url =
+            Url::parse("https://org.pkgs.visualstudio.com/_packaging/feed/pypi/simple/").unwrap();
+        assert_eq!(
+            RegistryProvider::from_url(&url),
+            Some(RegistryProvider::AzureArtifacts)
+        );
+
+        let url = Url::parse("https://org.jfrog.io/artifactory/api/pypi/repo/simple").unwrap();
+        assert_eq!(
+            RegistryProvider::from_url(&url),
+            Some(RegistryProvider::Artifactory)
+        );
+
+        let url = Url::parse("https://s3.us-west-2.amazonaws.com/bucket/simple/").unwrap();
+        assert_eq!(RegistryProvider::from_url(&url), None);
+
+        let url = Url::parse("https://pypi.org/simple/").unwrap();
+        assert_eq!(RegistryProvider::from_url(&url), None);
+    }
+}
@@ -28,7 +28,15 @@ pub enum AuthBackend {
 }
 
 impl AuthBackend {
-    pub fn from_settings(preview: Preview) -> Result<Self, TomlCredentialError> {
+    pub fn from_settings(
+        preview: Preview,
+        keyring: Option<KeyringProvider>,
+    ) -> Result<Self, TomlCredentialError> {
+        // If a keyring provider was requested, we'll use it as the store
+        if let Some(keyring) = keyring {
+            return Ok(Self::System(keyring));
+        }
+
         // If preview is enabled, we'll use the system-native store
         if preview.is_enabled(PreviewFeatures::NATIVE_AUTH) {
             return Ok(Self::System(KeyringProvider::native()));
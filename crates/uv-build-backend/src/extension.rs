@@ -0,0 +1,481 @@
+//! Compilation of C and Cython extension modules.
+//!
+//! Unlike the rest of the build backend, compiling extension modules requires a Python
+//! interpreter: We need its headers, the filename suffix for extension modules, and the tags of
+//! the wheel. The `uv_build` PEP 517 hooks pass the interpreter they run in through
+//! `UV_BUILD_BACKEND_PYTHON`.
+
+use std::ffi::OsString;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_distribution_filename::WheelFilename;
+use uv_fs::Simplified;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
+use uv_pypi_types::Identifier;
+use uv_static::EnvVars;
+
+use crate::settings::ExtensionModule;
+use crate::{Error, PyProjectToml};
+
+/// Query the paths and tags required for building extension modules.
+///
+/// The script prints one value per line: the include directory, the platform-specific include
+/// directory, the extension module suffix, and the python, ABI, and platform tags.
+const QUERY_SCRIPT: &str = r#"
+import sys, sysconfig
+
+paths = sysconfig.get_paths()
+version = f"{sys.version_info[0]}{sys.version_info[1]}"
+soabi = sysconfig.get_config_var("SOABI")
+if sys.implementation.name == "cpython":
+    python_tag = "cp" + version
+    abi_tag = "cp" + version
+    if sysconfig.get_config_var("Py_GIL_DISABLED"):
+        abi_tag += "t"
+    if sysconfig.get_config_var("Py_DEBUG"):
+        abi_tag += "d"
+elif sys.implementation.name == "pypy":
+    python_tag = "pp" + version
+    abi_tag = "_".join(soabi.split("-")[:2]) if soabi else "none"
+else:
+    python_tag = sys.implementation.name[:2] + version
+    abi_tag = soabi.replace("-", "_").replace(".", "_") if soabi else "none"
+platform_tag = sysconfig.get_platform().replace("-", "_").replace(".", "_")
+
+print(paths["include"])
+print(paths["platinclude"])
+print(sysconfig.get_config_var("EXT_SUFFIX"))
+print(python_tag)
+print(abi_tag)
+print(platform_tag)
+"#;
+
+/// The Python interpreter extension modules are built for.
+#[derive(Debug, Clone)]
+pub(crate) struct TargetInterpreter {
+    /// The path to the Python executable.
+    executable: PathBuf,
+    /// The directory with the Python headers, such as `Python.h`.
+    include: PathBuf,
+    /// The directory with the platform-specific Python headers, such as `pyconfig.h`.
+    platinclude: PathBuf,
+    /// The filename suffix for extension modules, e.g., `.cpython-312-x86_64-linux-gnu.so`.
+    ext_suffix: String,
+    python_tag: LanguageTag,
+    abi_tag: AbiTag,
+    platform_tag: PlatformTag,
+}
+
+impl TargetInterpreter {
+    /// Query the interpreter from `UV_BUILD_BACKEND_PYTHON`, or `python3` on `PATH`.
+    pub(crate) fn query() -> Result<Self, Error> {
+        let executable = std::env::var_os(EnvVars::UV_BUILD_BACKEND_PYTHON)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(if cfg!(windows) { "python" } else { "python3" }));
+        debug!(
+            "Querying Python interpreter for extension modules: {}",
+            executable.user_display()
+        );
+
+        let mut command = Command::new(&executable);
+        command.arg("-c").arg(QUERY_SCRIPT);
+        let output = command
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|err| Error::ExtensionPython(executable.clone(), err))?;
+        if !output.status.success() {
+            return Err(Error::ExtensionCommandFailed {
+                command: display_command(&command),
+                status: output.status,
+            });
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let [
+            include,
+            platinclude,
+            ext_suffix,
+            python_tag,
+            abi_tag,
+            platform_tag,
+        ] = stdout.lines().collect::<Vec<_>>()[..]
+        else {
+            return Err(Error::ExtensionPython(
+                executable,
+                io::Error::other(format!("Unexpected interpreter query output: {stdout}")),
+            ));
+        };
+
+        Ok(Self {
+            include: PathBuf::from(include),
+            platinclude: PathBuf::from(platinclude),
+            ext_suffix: ext_suffix.to_string(),
+            python_tag: LanguageTag::from_str(python_tag).map_err(|err| {
+                Error::InvalidInterpreterTag(python_tag.to_string(), Box::new(err))
+            })?,
+            abi_tag: AbiTag::from_str(abi_tag)
+                .map_err(|err| Error::InvalidInterpreterTag(abi_tag.to_string(), Box::new(err)))?,
+            platform_tag: PlatformTag::from_str(platform_tag).map_err(|err| {
+                Error::InvalidInterpreterTag(platform_tag.to_string(), Box::new(err))
+            })?,
+            executable,
+        })
+    }
+
+    /// The wheel filename for a platform-specific wheel built for this interpreter.
+    pub(crate) fn wheel_filename(&self, pyproject_toml: &PyProjectToml) -> WheelFilename {
+        WheelFilename::new(
+            pyproject_toml.name().clone(),
+            pyproject_toml.version().clone(),
+            self.python_tag,
+            self.abi_tag,
+            self.platform_tag.clone(),
+        )
+    }
+}
+
+/// An extension module, compiled or to be compiled.
+#[derive(Debug)]
+pub(crate) struct BuiltExtension {
+    /// The path of the extension module in the wheel, relative to the wheel root.
+    pub(crate) wheel_path: String,
+    /// The compiled extension module, or `None` if we're only listing files.
+    pub(crate) file: Option<PathBuf>,
+}
+
+/// Return the path of the extension module relative to the module root, without the suffix.
+///
+/// For example, `foo._speedups` is `foo/_speedups`.
+fn module_path(extension: &ExtensionModule) -> Result<PathBuf, Error> {
+    let mut path = PathBuf::new();
+    for segment in extension.name.split('.') {
+        Identifier::from_str(segment)
+            .map_err(|err| Error::InvalidModuleName(extension.name.clone(), err))?;
+        path.push(segment);
+    }
+    Ok(path)
+}
+
+/// Check that a path from an extension declaration is inside the project.
+pub(crate) fn check_extension_path(extension: &ExtensionModule, path: &Path) -> Result<(), Error> {
+    if path
+        .components()
+        .next()
+        .is_some_and(|component| !matches!(component, Component::CurDir | Component::Normal(_)))
+    {
+        return Err(Error::InvalidExtensionPath {
+            name: extension.name.clone(),
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(())
+}
+
+/// Glob expressions, anchored at the source tree, matching previously compiled extension modules
+/// below the module root, e.g., from an editable build.
+pub(crate) fn extension_artifact_excludes(
+    extensions: &[ExtensionModule],
+    module_root: &Path,
+) -> Result<Vec<String>, Error> {
+    let mut excludes = Vec::new();
+    for extension in extensions {
+        let module_path = module_root.join(module_path(extension)?);
+        let module_path = uv_fs::normalize_path(&module_path);
+        let module_path = globset::escape(&module_path.portable_display().to_string());
+        for suffix in [".so", ".pyd", ".*.so", ".*.pyd"] {
+            excludes.push(format!("/{module_path}{suffix}"));
+        }
+    }
+    Ok(excludes)
+}
+
+/// Glob expressions, anchored at the source tree, matching the sources of the extension modules,
+/// which are compiled instead of being included in the wheel.
+pub(crate) fn extension_source_excludes(
+    extensions: &[ExtensionModule],
+) -> Result<Vec<String>, Error> {
+    let mut excludes = Vec::new();
+    for extension in extensions {
+        for source in &extension.sources {
+            check_extension_path(extension, source)?;
+            let source = uv_fs::normalize_path(source);
+            excludes.push(format!(
+                "/{}",
+                globset::escape(&source.portable_display().to_string())
+            ));
+        }
+    }
+    Ok(excludes)
+}
+
+/// Glob expressions, relative to the source tree, to include the extension sources and include
+/// directories in the source distribution.
+pub(crate) fn extension_includes(extensions: &[ExtensionModule]) -> Result<Vec<String>, Error> {
+    let mut includes = Vec::new();
+    for extension in extensions {
+        for source in &extension.sources {
+            check_extension_path(extension, source)?;
+            let source = uv_fs::normalize_path(source);
+            includes.push(globset::escape(&source.portable_display().to_string()));
+        }
+        for include_dir in &extension.include_dirs {
+            check_extension_path(extension, include_dir)?;
+            let include_dir = uv_fs::normalize_path(include_dir);
+            includes.push(format!(
+                "{}/**",
+                globset::escape(&include_dir.portable_display().to_string())
+            ));
+        }
+    }
+    Ok(includes)
+}
+
+/// Return the extension modules that would be built, without compiling them.
+pub(crate) fn list_extensions(
+    extensions: &[ExtensionModule],
+    interpreter: &TargetInterpreter,
+) -> Result<Vec<BuiltExtension>, Error> {
+    extensions
+        .iter()
+        .map(|extension| {
+            Ok(BuiltExtension {
+                wheel_path: wheel_path(extension, interpreter)?,
+                file: None,
+            })
+        })
+        .collect()
+}
+
+/// The path of the compiled extension module in the wheel, e.g.,
+/// `foo/_speedups.cpython-312-x86_64-linux-gnu.so`.
+fn wheel_path(
+    extension: &ExtensionModule,
+    interpreter: &TargetInterpreter,
+) -> Result<String, Error> {
+    Ok(format!(
+        "{}{}",
+        module_path(extension)?.portable_display(),
+        interpreter.ext_suffix
+    ))
+}
+
+/// Compile the extension modules.
+///
+/// Intermediate files are written to `build_dir`. The compiled extension modules are written to
+/// `output_dir` following the module structure, e.g., `<output_dir>/foo/_speedups.<suffix>`.
+pub(crate) fn build_extensions(
+    source_tree: &Path,
+    extensions: &[ExtensionModule],
+    interpreter: &TargetInterpreter,
+    build_dir: &Path,
+    output_dir: &Path,
+) -> Result<Vec<BuiltExtension>, Error> {
+    if cfg!(windows) {
+        return Err(Error::ExtensionUnsupportedPlatform);
+    }
+
+    let compiler = env_args(EnvVars::CC).unwrap_or_else(|| vec![OsString::from("cc")]);
+    let cflags = env_args(EnvVars::CFLAGS).unwrap_or_default();
+    let ldflags = env_args(EnvVars::LDFLAGS).unwrap_or_default();
+
+    let mut built = Vec::new();
+    for extension in extensions {
+        let wheel_path = wheel_path(extension, interpreter)?;
+        debug!("Building extension module `{}`", extension.name);
+
+        let extension_build_dir = build_dir.join(&extension.name);
+        fs_err::create_dir_all(&extension_build_dir)?;
+
+        let mut objects = Vec::new();
+        for (index, source) in extension.sources.iter().enumerate() {
+            check_extension_path(extension, source)?;
+            let stem = source
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            // Translate Cython sources to C first.
+            let c_source = match source.extension().and_then(|extension| extension.to_str()) {
+                Some("c") => source.clone(),
+                Some("pyx") => {
+                    let c_source = extension_build_dir.join(format!("{index}-{stem}.c"));
+                    let mut command = Command::new(&interpreter.executable);
+                    command
+                        .args(["-m", "cython", "-3", "--module-name"])
+                        .arg(&extension.name)
+                        .arg(source)
+                        .arg("-o")
+                        .arg(&c_source)
+                        .current_dir(source_tree);
+                    run(&mut command)?;
+                    c_source
+                }
+                _ => {
+                    return Err(Error::UnsupportedExtensionSource {
+                        name: extension.name.clone(),
+                        path: source.clone(),
+                    });
+                }
+            };
+
+            let object = extension_build_dir.join(format!("{index}-{stem}.o"));
+            let mut command = Command::new(&compiler[0]);
+            command
+                .args(&compiler[1..])
+                .args(["-fPIC", "-O2"])
+                .arg("-I")
+                .arg(&interpreter.include);
+            if interpreter.platinclude != interpreter.include {
+                command.arg("-I").arg(&interpreter.platinclude);
+            }
+            for include_dir in &extension.include_dirs {
+                check_extension_path(extension, include_dir)?;
+                command.arg("-I").arg(include_dir);
+            }
+            for define_macro in &extension.define_macros {
+                command.arg(format!("-D{define_macro}"));
+            }
+            command
+                .args(&cflags)
+                .args(&extension.extra_compile_args)
+                .arg("-c")
+                .arg(&c_source)
+                .arg("-o")
+                .arg(&object)
+                // Compile relative to the source tree, so the build path doesn't leak into the
+                // extension module.
+                .current_dir(source_tree);
+            run(&mut command)?;
+            objects.push(object);
+        }
+
+        let output = output_dir.join(&wheel_path);
+        if let Some(parent) = output.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        let mut command = Command::new(&compiler[0]);
+        command.args(&compiler[1..]);
+        if cfg!(target_os = "macos") {
+            // Extension modules resolve the Python symbols from the interpreter that loads them.
+            command.args(["-bundle", "-undefined", "dynamic_lookup"]);
+        } else {
+            command.arg("-shared");
+        }
+        command.args(&objects).arg("-o").arg(&output);
+        for library_dir in &extension.library_dirs {
+            command.arg(format!("-L{}", library_dir.display()));
+        }
+        for library in &extension.libraries {
+            command.arg(format!("-l{library}"));
+        }
+        command
+            .args(&ldflags)
+            .args(&extension.extra_link_args)
+            .current_dir(source_tree);
+        run(&mut command)?;
+
+        built.push(BuiltExtension {
+            wheel_path,
+            file: Some(output),
+        });
+    }
+    Ok(built)
+}
+
+/// Split a whitespace-separated environment variable into arguments.
+fn env_args(name: &str) -> Option<Vec<OsString>> {
+    let value = std::env::var(name).ok()?;
+    let args: Vec<_> = value.split_whitespace().map(OsString::from).collect();
+    if args.is_empty() { None } else { Some(args) }
+}
+
+/// Run a compilation step, forwarding its output to stderr.
+///
+/// The build backend reports the filename of the built artifact on stdout, so subprocesses must
+/// not write to it.
+fn run(command: &mut Command) -> Result<(), Error> {
+    let command_display = display_command(command);
+    debug!("Running: {command_display}");
+    let status = command
+        .stdout(io::stderr())
+        .stderr(Stdio::inherit())
+        .status()
+        .map_err(|err| Error::ExtensionCommand {
+            command: command_display.clone(),
+            err,
+        })?;
+    if !status.success() {
+        return Err(Error::ExtensionCommandFailed {
+            command: command_display,
+            status,
+        });
+    }
+    Ok(())
+}
+
+/// Format a command for error messages.
+fn display_command(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| arg.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::settings::ExtensionModule;
+
+    use super::{extension_artifact_excludes, extension_includes, extension_source_excludes};
+
+    fn extension(name: &str, sources: &[&str]) -> ExtensionModule {
+        ExtensionModule {
+            name: name.to_string(),
+            sources: sources.iter().map(Into::into).collect(),
+            include_dirs: vec!["include".into()],
+            define_macros: Vec::new(),
+            libraries: Vec::new(),
+            library_dirs: Vec::new(),
+            extra_compile_args: Vec::new(),
+            extra_link_args: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn includes_and_excludes() {
+        let extensions = [extension("foo._speedups", &["src/foo/_speedups.c"])];
+        assert_eq!(
+            extension_includes(&extensions).unwrap(),
+            ["src/foo/_speedups.c", "include/**"]
+        );
+        assert_eq!(
+            extension_source_excludes(&extensions).unwrap(),
+            ["/src/foo/_speedups.c"]
+        );
+        assert_eq!(
+            extension_artifact_excludes(&extensions, Path::new("src")).unwrap(),
+            [
+                "/src/foo/_speedups.so",
+                "/src/foo/_speedups.pyd",
+                "/src/foo/_speedups.*.so",
+                "/src/foo/_speedups.*.pyd"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(extension_includes(&[extension("foo._speedups", &["../_speedups.c"])]).is_err());
+        assert!(
+            extension_artifact_excludes(&[extension("foo.1speedups", &[])], Path::new("src"))
+                .is_err()
+        );
+    }
+}
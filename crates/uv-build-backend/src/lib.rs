@@ -1,12 +1,20 @@
+mod extension;
 mod metadata;
 mod serde_verbatim;
 mod settings;
 mod source_dist;
+mod tags;
+mod trusted;
+mod version;
 mod wheel;
 
 pub use metadata::{PyProjectToml, check_direct_build};
-pub use settings::{BuildBackendSettings, WheelDataIncludes};
+pub use settings::{
+    BuildBackendSettings, ExtensionModule, VersionScheme, VersionSource, WheelDataIncludes,
+};
 pub use source_dist::{build_source_dist, list_source_dist};
+pub use tags::{TagMismatch, check_wheel_tags};
+pub use trusted::TrustedBuild;
 pub use wheel::{build_editable, build_wheel, list_wheel, metadata};
 
 use std::ffi::OsStr;
@@ -15,12 +23,14 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use thiserror::Error;
 use tracing::debug;
-use walkdir::DirEntry;
+use walkdir::{DirEntry, WalkDir};
 
+use uv_distribution_filename::WheelFilenameError;
 use uv_fs::Simplified;
 use uv_globfilter::PortableGlobError;
 use uv_normalize::PackageName;
 use uv_pypi_types::{Identifier, IdentifierParseError};
+use uv_static::EnvVars;
 
 use crate::metadata::ValidationError;
 use crate::settings::ModuleName;
@@ -64,6 +74,16 @@ pub enum Error {
     MissingInitPy(PathBuf),
     #[error("For namespace packages, `__init__.py[i]` is not allowed in parent directory: {}", _0.user_display())]
     NotANamespace(PathBuf),
+    #[error("`data-only = true` can't be combined with `{0}`")]
+    DataOnlyConflict(&'static str),
+    #[error("`data-only = true` requires at least one `data` entry")]
+    DataOnlyWithoutData,
+    #[error("Stubs packages must only contain `.pyi` files, found: {}", _0.user_display())]
+    PyFileInStubs(PathBuf),
+    #[error("`partial-stubs = true` is only supported for stubs packages, but `{}` doesn't end with `-stubs`", _0.user_display())]
+    PartialStubsWithoutStubs(PathBuf),
+    #[error("With `partial-stubs = true`, `py.typed` must contain `partial\\n`: {}", _0.user_display())]
+    InvalidPartialPyTyped(PathBuf),
     /// Either an absolute path or a parent path through `..`.
     #[error("Module root must be inside the project: {}", _0.user_display())]
     InvalidModuleRoot(PathBuf),
@@ -76,6 +96,75 @@ pub enum Error {
     InconsistentSteps(&'static str),
     #[error("Failed to write to {}", _0.user_display())]
     TarWrite(PathBuf, #[source] io::Error),
+    #[error("Building extension modules requires a Python interpreter, failed to query: {}", _0.user_display())]
+    ExtensionPython(PathBuf, #[source] io::Error),
+    #[error("Invalid tag reported by the Python interpreter: `{0}`")]
+    InvalidInterpreterTag(String, #[source] Box<dyn std::error::Error + Send + Sync>),
+    /// Either an absolute path or a parent path through `..`.
+    #[error("The paths for the extension module `{}` must be inside the project: {}", name, path.user_display())]
+    InvalidExtensionPath { name: String, path: PathBuf },
+    #[error("Unsupported source for the extension module `{}`, only `.c` and `.pyx` files are supported: {}", name, path.user_display())]
+    UnsupportedExtensionSource { name: String, path: PathBuf },
+    #[error("Building extension modules is not supported on Windows")]
+    ExtensionUnsupportedPlatform,
+    #[error("Failed to run `{command}`")]
+    ExtensionCommand {
+        command: String,
+        #[source]
+        err: io::Error,
+    },
+    #[error("`{command}` failed with {status}")]
+    ExtensionCommandFailed {
+        command: String,
+        status: std::process::ExitStatus,
+    },
+    #[error("Failed to run `{command}`, is git installed?")]
+    GitCommand {
+        command: String,
+        #[source]
+        err: io::Error,
+    },
+    #[error("`version-source = \"git\"` requires the project to be in a git repository: {}", _0.user_display())]
+    NotAGitRepository(PathBuf),
+    #[error("Failed to parse the output of `git describe`: `{0}`")]
+    InvalidGitDescribe(String),
+    #[error("Invalid version derived from git: `{0}`: {1}")]
+    InvalidGitVersion(String, String),
+    #[error("Missing `Version` field in: {}", _0.user_display())]
+    MissingPkgInfoVersion(PathBuf),
+    /// Either an absolute path or a parent path through `..`.
+    #[error("The version file must be inside the project: {}", _0.user_display())]
+    InvalidVersionFile(PathBuf),
+    #[error("Invalid `SOURCE_DATE_EPOCH`, expected a Unix timestamp: `{0}`")]
+    InvalidSourceDateEpoch(String),
+    #[error("Invalid wheel filename: `{0}`")]
+    InvalidWheelFilename(String, #[source] WheelFilenameError),
+    #[error("Failed to parse binary `{path}`")]
+    Binary {
+        path: String,
+        #[source]
+        err: goblin::error::Error,
+    },
+}
+
+/// Read the modification time to use for all files in wheels and source distributions from
+/// `SOURCE_DATE_EPOCH`, if set.
+///
+/// <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Result<Option<u64>, Error> {
+    let Some(value) = std::env::var_os(EnvVars::SOURCE_DATE_EPOCH) else {
+        return Ok(None);
+    };
+    let value = value.to_string_lossy();
+    // Per the specification, an empty value is treated as unset.
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    value
+        .trim()
+        .parse::<u64>()
+        .map(Some)
+        .map_err(|_| Error::InvalidSourceDateEpoch(value.to_string()))
 }
 
 /// Dispatcher between writing to a directory, writing to a zip, writing to a `.tar.gz` and
@@ -213,6 +302,7 @@ fn find_roots(
     relative_module_root: &Path,
     module_name: Option<&ModuleName>,
     namespace: bool,
+    data_only: bool,
 ) -> Result<(PathBuf, Vec<PathBuf>), Error> {
     let relative_module_root = uv_fs::normalize_path(relative_module_root);
     // Check that even if a path contains `..`, we only include files below the module root.
@@ -224,6 +314,23 @@ fn find_roots(
     let src_root = source_tree.join(&relative_module_root);
     debug!("Source root: {}", src_root.user_display());
 
+    if data_only {
+        if module_name.is_some() {
+            return Err(Error::DataOnlyConflict("module-name"));
+        }
+        if namespace {
+            return Err(Error::DataOnlyConflict("namespace"));
+        }
+        if pyproject_toml
+            .settings()
+            .is_none_or(|settings| settings.data.iter().next().is_none())
+        {
+            return Err(Error::DataOnlyWithoutData);
+        }
+        debug!("Building a data-only package without modules");
+        return Ok((src_root, Vec::new()));
+    }
+
     if namespace {
         // `namespace = true` disables module structure checks.
         let modules_relative = if let Some(module_name) = module_name {
@@ -355,6 +462,64 @@ fn module_path_from_module_name(src_root: &Path, module_name: &str) -> Result<Pa
     Ok(module_relative)
 }
 
+/// Whether the module is a stubs module, i.e., its root name ends with `-stubs`.
+fn is_stubs_module(module_relative: &Path) -> bool {
+    module_relative
+        .components()
+        .next()
+        .is_some_and(|root| root.as_os_str().to_string_lossy().ends_with("-stubs"))
+}
+
+/// Check that stubs modules only contain stubs, and that the `py.typed` marker of partial stubs
+/// packages is valid.
+///
+/// Returns the `py.typed` markers that need to be added to the wheel for partial stubs packages.
+fn check_stubs(
+    src_root: &Path,
+    modules_relative: &[PathBuf],
+    partial_stubs: bool,
+) -> Result<Vec<PathBuf>, Error> {
+    let mut missing_markers = Vec::new();
+    for module_relative in modules_relative {
+        if !is_stubs_module(module_relative) {
+            if partial_stubs {
+                return Err(Error::PartialStubsWithoutStubs(module_relative.clone()));
+            }
+            continue;
+        }
+
+        let module_root = src_root.join(module_relative);
+        for entry in WalkDir::new(&module_root).sort_by_file_name() {
+            let entry = entry.map_err(|err| Error::WalkDir {
+                root: module_root.clone(),
+                err,
+            })?;
+            if entry.file_type().is_file()
+                && entry.path().extension().is_some_and(|ext| ext == "py")
+            {
+                return Err(Error::PyFileInStubs(entry.into_path()));
+            }
+        }
+
+        if partial_stubs {
+            let py_typed = module_root.join("py.typed");
+            match fs_err::read_to_string(&py_typed) {
+                Ok(content) => {
+                    // PEP 561 requires `partial\n` as the first line.
+                    if content.lines().next() != Some("partial") {
+                        return Err(Error::InvalidPartialPyTyped(py_typed));
+                    }
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    missing_markers.push(module_relative.join("py.typed"));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+    Ok(missing_markers)
+}
+
 /// Error if we're adding a venv to a distribution.
 pub(crate) fn error_on_venv(file_name: &OsStr, path: &Path) -> Result<(), Error> {
     // On 64-bit Unix, `lib64` is a (compatibility) symlink to lib. If we traverse `lib64` before
@@ -391,6 +556,7 @@ mod tests {
     use tempfile::TempDir;
     use uv_distribution_filename::{SourceDistFilename, WheelFilename};
     use uv_fs::{copy_dir_all, relative_to};
+    use uv_platform_tags::PlatformTag;
 
     const MOCK_UV_VERSION: &str = "1.0.0+test";
 
@@ -1302,6 +1468,176 @@ mod tests {
         ");
     }
 
+    /// A partial stubs package, which must only contain stubs and gets a `py.typed` marker.
+    #[test]
+    fn partial_stubs_package() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "stuffed-bird-stubs"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            partial-stubs = true
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        let module = src.path().join("src").join("stuffed_bird-stubs");
+        fs_err::create_dir_all(&module).unwrap();
+        File::create(module.join("__init__.pyi")).unwrap();
+
+        // Stubs packages must not contain implementations.
+        File::create(module.join("feathers.py")).unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"Stubs packages must only contain `.pyi` files, found: [TEMP_PATH]/src/stuffed_bird-stubs/feathers.py"
+        );
+        fs_err::remove_file(module.join("feathers.py")).unwrap();
+
+        // Without a `py.typed`, the build backend adds one.
+        let dist = TempDir::new().unwrap();
+        let build1 = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build1.wheel_contents.join("\n"), @r"
+        stuffed_bird-stubs/
+        stuffed_bird-stubs/__init__.pyi
+        stuffed_bird-stubs/py.typed
+        stuffed_bird_stubs-1.0.0.dist-info/
+        stuffed_bird_stubs-1.0.0.dist-info/METADATA
+        stuffed_bird_stubs-1.0.0.dist-info/RECORD
+        stuffed_bird_stubs-1.0.0.dist-info/WHEEL
+        ");
+
+        // An existing `py.typed` must mark the stubs as partial.
+        fs_err::write(module.join("py.typed"), "").unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"With `partial-stubs = true`, `py.typed` must contain `partial\n`: [TEMP_PATH]/src/stuffed_bird-stubs/py.typed"
+        );
+        fs_err::write(module.join("py.typed"), "partial\n").unwrap();
+        let build2 = build(src.path(), dist.path()).unwrap();
+        assert_eq!(build1.wheel_contents, build2.wheel_contents);
+
+        // Regular packages can't be partial stubs.
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "stuffed-bird"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            partial-stubs = true
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("stuffed_bird")).unwrap();
+        File::create(
+            src.path()
+                .join("src")
+                .join("stuffed_bird")
+                .join("__init__.py"),
+        )
+        .unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"`partial-stubs = true` is only supported for stubs packages, but `stuffed_bird` doesn't end with `-stubs`"
+        );
+    }
+
+    /// A package that only contains data files, without any Python module.
+    #[test]
+    fn data_only_package() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "bird-sounds"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            data-only = true
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"`data-only = true` requires at least one `data` entry"
+        );
+
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "bird-sounds"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            data-only = true
+            data = { data = "sounds" }
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("sounds").join("share")).unwrap();
+        fs_err::write(
+            src.path().join("sounds").join("share").join("tweet.wav"),
+            "",
+        )
+        .unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path()).unwrap();
+        assert_snapshot!(build.source_dist_contents.join("\n"), @r"
+        bird_sounds-1.0.0/
+        bird_sounds-1.0.0/PKG-INFO
+        bird_sounds-1.0.0/pyproject.toml
+        bird_sounds-1.0.0/sounds
+        bird_sounds-1.0.0/sounds/share
+        bird_sounds-1.0.0/sounds/share/tweet.wav
+        ");
+        assert_snapshot!(build.wheel_contents.join("\n"), @r"
+        bird_sounds-1.0.0.data/data/
+        bird_sounds-1.0.0.data/data/share/
+        bird_sounds-1.0.0.data/data/share/tweet.wav
+        bird_sounds-1.0.0.dist-info/
+        bird_sounds-1.0.0.dist-info/METADATA
+        bird_sounds-1.0.0.dist-info/RECORD
+        bird_sounds-1.0.0.dist-info/WHEEL
+        ");
+
+        // Data-only packages don't have a module to configure.
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "bird-sounds"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            data-only = true
+            module-name = "bird_sounds"
+            data = { data = "sounds" }
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        assert_snapshot!(
+            build_err(src.path()),
+            @"`data-only = true` can't be combined with `module-name`"
+        );
+    }
+
     /// A package with multiple modules, one a regular module and two namespace modules.
     #[test]
     fn multiple_module_names() {
@@ -1414,4 +1750,108 @@ mod tests {
         simple_namespace_part-1.0.0.dist-info/WHEEL
         ");
     }
+
+    /// Build a wheel with a C extension module for `python3` on `PATH`.
+    #[test]
+    #[cfg(unix)]
+    fn extension_module() {
+        let src = TempDir::new().unwrap();
+        let pyproject_toml = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "1.0.0"
+
+            [tool.uv.build-backend]
+            extensions = [
+                { name = "foo._speedups", sources = ["src/foo/_speedups.c"], include-dirs = ["include"], define-macros = ["ANSWER=42"] },
+            ]
+
+            [build-system]
+            requires = ["uv_build>=0.5.15,<0.6.0"]
+            build-backend = "uv_build"
+            "#
+        };
+        fs_err::write(src.path().join("pyproject.toml"), pyproject_toml).unwrap();
+        fs_err::create_dir_all(src.path().join("src").join("foo")).unwrap();
+        fs_err::create_dir_all(src.path().join("include")).unwrap();
+        File::create(src.path().join("src").join("foo").join("__init__.py")).unwrap();
+        fs_err::write(
+            src.path().join("include").join("speedups.h"),
+            "static long answer(void) { return ANSWER; }\n",
+        )
+        .unwrap();
+        fs_err::write(
+            src.path().join("src").join("foo").join("_speedups.c"),
+            indoc! {r#"
+                #define PY_SSIZE_T_CLEAN
+                #include <Python.h>
+                #include "speedups.h"
+
+                static PyObject *speedups_answer(PyObject *self, PyObject *args) {
+                    return PyLong_FromLong(answer());
+                }
+
+                static PyMethodDef methods[] = {
+                    {"answer", speedups_answer, METH_NOARGS, NULL},
+                    {NULL, NULL, 0, NULL},
+                };
+
+                static struct PyModuleDef module = {PyModuleDef_HEAD_INIT, "_speedups", NULL, -1, methods};
+
+                PyMODINIT_FUNC PyInit__speedups(void) { return PyModule_Create(&module); }
+            "#},
+        )
+        .unwrap();
+
+        let dist = TempDir::new().unwrap();
+        let build = build(src.path(), dist.path()).unwrap();
+        assert_ne!(build.wheel_filename.platform_tags(), [PlatformTag::Any]);
+
+        // The sources and headers are included in the source distribution, but not the wheel.
+        assert_snapshot!(build.source_dist_contents.join("\n"), @r"
+        foo-1.0.0/
+        foo-1.0.0/PKG-INFO
+        foo-1.0.0/include
+        foo-1.0.0/include/speedups.h
+        foo-1.0.0/pyproject.toml
+        foo-1.0.0/src
+        foo-1.0.0/src/foo
+        foo-1.0.0/src/foo/__init__.py
+        foo-1.0.0/src/foo/_speedups.c
+        ");
+        let ext_suffix = Regex::new(r"_speedups\..*").unwrap();
+        assert_snapshot!(
+            build
+                .wheel_contents
+                .iter()
+                .map(|path| ext_suffix.replace(path, "_speedups.[EXT_SUFFIX]"))
+                .join("\n"),
+            @r"
+        foo-1.0.0.dist-info/
+        foo-1.0.0.dist-info/METADATA
+        foo-1.0.0.dist-info/RECORD
+        foo-1.0.0.dist-info/WHEEL
+        foo/
+        foo/__init__.py
+        foo/_speedups.[EXT_SUFFIX]
+        "
+        );
+
+        // Editable builds compile the extension module in-place, which must not leak into the
+        // source distribution.
+        let editable = TempDir::new().unwrap();
+        build_editable(src.path(), editable.path(), None, MOCK_UV_VERSION).unwrap();
+        let in_place: Vec<_> = fs_err::read_dir(src.path().join("src").join("foo"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("_speedups.") && name != "_speedups.c")
+            .collect();
+        assert_eq!(in_place.len(), 1);
+        let (_name, files) = list_source_dist(src.path(), MOCK_UV_VERSION).unwrap();
+        assert!(
+            files
+                .iter()
+                .all(|(path, _)| !path.ends_with(in_place[0].as_str()))
+        );
+    }
 }
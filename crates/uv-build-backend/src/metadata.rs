@@ -13,15 +13,17 @@ use walkdir::WalkDir;
 
 use uv_fs::Simplified;
 use uv_globfilter::{GlobDirFilter, PortableGlobParser};
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{
-    ExtraOperator, MarkerExpression, MarkerTree, MarkerValueExtra, Requirement, VersionOrUrl,
+    ExtraOperator, MarkerExpression, MarkerTree, MarkerValueExtra, Pep508Error, Requirement,
+    VersionOrUrl,
 };
-use uv_pypi_types::{Metadata23, VerbatimParsedUrl};
+use uv_pypi_types::{DependencyGroupSpecifier, DependencyGroups, Metadata23, VerbatimParsedUrl};
 
 use crate::serde_verbatim::SerdeVerbatim;
-use crate::{BuildBackendSettings, Error, error_on_venv};
+use crate::settings::VersionSource;
+use crate::{BuildBackendSettings, Error, error_on_venv, version};
 
 /// By default, we ignore generated python files.
 pub(crate) const DEFAULT_EXCLUDES: &[&str] = &["__pycache__", "*.pyc", "*.pyo"];
@@ -46,6 +48,33 @@ pub enum ValidationError {
     DescriptionNewlines,
     #[error("Dynamic metadata is not supported")]
     Dynamic,
+    #[error("`project.version` is required")]
+    MissingVersion,
+    #[error(
+        "A dynamic `project.version` requires `tool.uv.build-backend.version-source = \"git\"`"
+    )]
+    DynamicVersion,
+    #[error(
+        "With `tool.uv.build-backend.version-source = \"git\"`, `project.version` must not be set and `version` must be listed in `project.dynamic`"
+    )]
+    GitVersionNotDynamic,
+    #[error(
+        "With `tool.uv.build-backend.extras-from-groups`, `project.optional-dependencies` must not be set and `optional-dependencies` must be listed in `project.dynamic`"
+    )]
+    ExtrasFromGroupsNotDynamic,
+    #[error("Dependency group `{0}` is not defined in `dependency-groups`")]
+    MissingDependencyGroup(GroupName),
+    #[error("Dependency group `{0}` includes itself")]
+    DependencyGroupCycle(GroupName),
+    #[error("Failed to parse entry in dependency group `{group}`: `{requirement}`")]
+    DependencyGroupRequirement {
+        group: GroupName,
+        requirement: String,
+        #[source]
+        err: Box<Pep508Error>,
+    },
+    #[error("Dependency group `{0}` contains an unsupported entry")]
+    UnsupportedDependencyGroupEntry(GroupName),
     #[error(
         "When `project.license-files` is defined, `project.license` must be an SPDX expression string"
     )]
@@ -72,6 +101,24 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
     #[serde(rename_all = "kebab-case")]
     struct PyProjectToml {
         build_system: BuildSystem,
+        tool: Option<Tool>,
+    }
+
+    #[derive(Deserialize)]
+    struct Tool {
+        uv: Option<ToolUv>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct ToolUv {
+        build_backend: Option<BuildBackend>,
+    }
+
+    #[derive(Deserialize)]
+    struct BuildBackend {
+        #[serde(default)]
+        extensions: Vec<toml::Value>,
     }
 
     let pyproject_toml: PyProjectToml =
@@ -89,6 +136,20 @@ pub fn check_direct_build(source_tree: &Path, name: impl Display) -> bool {
                 return false;
             }
         };
+    // Compiling extension modules requires the Python interpreter from the build environment.
+    if pyproject_toml
+        .tool
+        .and_then(|tool| tool.uv)
+        .and_then(|tool_uv| tool_uv.build_backend)
+        .is_some_and(|build_backend| !build_backend.extensions.is_empty())
+    {
+        debug!(
+            "Not using uv build backend direct build for source tree `{name}`, \
+            the project declares extension modules"
+        );
+        return false;
+    }
+
     match pyproject_toml
         .build_system
         .check_build_system(uv_version::version())
@@ -143,6 +204,8 @@ pub struct PyProjectToml {
     tool: Option<Tool>,
     /// Build-related data
     build_system: BuildSystem,
+    /// PEP 735 dependency groups, which may be mirrored as extras.
+    dependency_groups: Option<DependencyGroups>,
 }
 
 impl PyProjectToml {
@@ -151,13 +214,124 @@ impl PyProjectToml {
     }
 
     pub(crate) fn version(&self) -> &Version {
-        &self.project.version
+        self.project
+            .version
+            .as_ref()
+            .expect("The version must be resolved when loading the `pyproject.toml`")
     }
 
     pub(crate) fn parse(contents: &str) -> Result<Self, Error> {
         Ok(toml::from_str(contents)?)
     }
 
+    /// Read the `pyproject.toml` from the source tree and resolve a version from git, if
+    /// configured.
+    pub(crate) fn load(source_tree: &Path) -> Result<Self, Error> {
+        let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
+        let mut pyproject_toml = Self::parse(&contents)?;
+        pyproject_toml.resolve_version(source_tree)?;
+        Ok(pyproject_toml)
+    }
+
+    /// With `version-source = "git"`, determine the version and write the version file.
+    ///
+    /// For source trees that are unpacked source distributions, the version is read from
+    /// `PKG-INFO` instead, as the git repository is not available anymore.
+    fn resolve_version(&mut self, source_tree: &Path) -> Result<(), Error> {
+        let Some(settings) = self.settings() else {
+            return self.check_version();
+        };
+        if settings.version_source != VersionSource::Git {
+            return self.check_version();
+        }
+        let dynamic_version = self
+            .project
+            .dynamic
+            .as_ref()
+            .is_some_and(|dynamic| dynamic.iter().any(|field| field == "version"));
+        if self.project.version.is_some() || !dynamic_version {
+            return Err(ValidationError::GitVersionNotDynamic.into());
+        }
+
+        let version = if let Some(version) = version::pkg_info_version(source_tree)? {
+            version
+        } else {
+            version::git_version(source_tree, settings.version_scheme)?
+        };
+        if let Some(version_file) = &settings.version_file {
+            version::write_version_file(source_tree, version_file, &version)?;
+        }
+
+        // The version is now static.
+        self.project.version = Some(version);
+        if let Some(dynamic) = &mut self.project.dynamic {
+            dynamic.retain(|field| field != "version");
+        }
+        Ok(())
+    }
+
+    /// Check that the version is set statically.
+    fn check_version(&self) -> Result<(), ValidationError> {
+        if self.project.version.is_some() {
+            return Ok(());
+        }
+        if self
+            .project
+            .dynamic
+            .as_ref()
+            .is_some_and(|dynamic| dynamic.iter().any(|field| field == "version"))
+        {
+            Err(ValidationError::DynamicVersion)
+        } else {
+            Err(ValidationError::MissingVersion)
+        }
+    }
+
+    /// Check that no fields are dynamic, except for `optional-dependencies` when mirroring
+    /// dependency groups as extras.
+    fn check_dynamic(&self) -> Result<(), ValidationError> {
+        let dynamic = self.project.dynamic.as_deref().unwrap_or_default();
+        let extras_from_groups = self
+            .settings()
+            .is_some_and(|settings| !settings.extras_from_groups.is_empty());
+        if extras_from_groups
+            && (self.project.optional_dependencies.is_some()
+                || !dynamic.iter().any(|field| field == "optional-dependencies"))
+        {
+            return Err(ValidationError::ExtrasFromGroupsNotDynamic);
+        }
+        if dynamic
+            .iter()
+            .any(|field| !(extras_from_groups && field == "optional-dependencies"))
+        {
+            return Err(ValidationError::Dynamic);
+        }
+        Ok(())
+    }
+
+    /// The optional dependencies of the project, including the dependency groups mirrored as
+    /// extras with `tool.uv.build-backend.extras-from-groups`.
+    fn optional_dependencies(&self) -> Result<BTreeMap<ExtraName, Vec<Requirement>>, Error> {
+        let mut optional_dependencies = self
+            .project
+            .optional_dependencies
+            .clone()
+            .unwrap_or_default();
+        for group in self
+            .settings()
+            .iter()
+            .flat_map(|settings| &settings.extras_from_groups)
+        {
+            let requirements =
+                group_requirements(self.dependency_groups.as_ref(), group, &mut Vec::new())?;
+            let extra = ExtraName::from_str(group.as_str())
+                .expect("Dependency group names are valid extra names");
+            debug!("Mirroring dependency group `{group}` as extra `{extra}`");
+            optional_dependencies.insert(extra, requirements);
+        }
+        Ok(optional_dependencies)
+    }
+
     pub(crate) fn readme(&self) -> Option<&Readme> {
         self.project.readme.as_ref()
     }
@@ -193,6 +367,16 @@ impl PyProjectToml {
         self.tool.as_ref()?.uv.as_ref()?.build_backend.as_ref()
     }
 
+    /// Replace the `tool.uv.build-backend` settings.
+    pub(crate) fn with_settings(mut self, settings: BuildBackendSettings) -> Self {
+        self.tool = Some(Tool {
+            uv: Some(ToolUv {
+                build_backend: Some(settings),
+            }),
+        });
+        self
+    }
+
     /// See [`BuildSystem::check_build_system`].
     pub fn check_build_system(&self, uv_version: &str) -> Vec<String> {
         self.build_system.check_build_system(uv_version)
@@ -263,14 +447,8 @@ impl PyProjectToml {
             None => (None, None),
         };
 
-        if self
-            .project
-            .dynamic
-            .as_ref()
-            .is_some_and(|dynamic| !dynamic.is_empty())
-        {
-            return Err(ValidationError::Dynamic.into());
-        }
+        self.check_version()?;
+        self.check_dynamic()?;
 
         let author = self
             .project
@@ -354,42 +532,36 @@ impl PyProjectToml {
             .map(|(key, value)| format!("{key}, {value}"))
             .collect();
 
-        let extras = self
+        let optional_dependencies = self.optional_dependencies()?;
+        let extras = optional_dependencies.keys().collect::<Vec<_>>();
+
+        let requires_dist = self
             .project
-            .optional_dependencies
+            .dependencies
             .iter()
-            .flat_map(|optional_dependencies| optional_dependencies.keys())
+            .flatten()
+            .cloned()
+            .chain(
+                optional_dependencies
+                    .iter()
+                    .flat_map(|(extra, requirements)| {
+                        requirements.iter().cloned().map(|mut requirement| {
+                            requirement.marker.and(MarkerTree::expression(
+                                MarkerExpression::Extra {
+                                    operator: ExtraOperator::Equal,
+                                    name: MarkerValueExtra::Extra(extra.clone()),
+                                },
+                            ));
+                            requirement
+                        })
+                    }),
+            )
             .collect::<Vec<_>>();
 
-        let requires_dist =
-            self.project
-                .dependencies
-                .iter()
-                .flatten()
-                .cloned()
-                .chain(self.project.optional_dependencies.iter().flat_map(
-                    |optional_dependencies| {
-                        optional_dependencies
-                            .iter()
-                            .flat_map(|(extra, requirements)| {
-                                requirements.iter().cloned().map(|mut requirement| {
-                                    requirement.marker.and(MarkerTree::expression(
-                                        MarkerExpression::Extra {
-                                            operator: ExtraOperator::Equal,
-                                            name: MarkerValueExtra::Extra(extra.clone()),
-                                        },
-                                    ));
-                                    requirement
-                                })
-                            })
-                    },
-                ))
-                .collect::<Vec<_>>();
-
         Ok(Metadata23 {
             metadata_version: metadata_version.to_string(),
             name: self.project.name.given.clone(),
-            version: self.project.version.to_string(),
+            version: self.version().to_string(),
             // Not supported.
             platforms: vec![],
             // Not supported.
@@ -663,7 +835,9 @@ struct Project {
     /// The name of the project.
     name: VerbatimPackageName,
     /// The version of the project.
-    version: Version,
+    ///
+    /// Only optional with `tool.uv.build-backend.version-source = "git"`.
+    version: Option<Version>,
     /// The summary description of the project in one line.
     description: Option<String>,
     /// The full description of the project (i.e. the README).
@@ -712,10 +886,69 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     ///
-    /// Not supported, an error if anything but the default empty list.
+    /// Not supported, an error if anything but the default empty list, except for `version` with
+    /// `tool.uv.build-backend.version-source = "git"` and `optional-dependencies` with
+    /// `tool.uv.build-backend.extras-from-groups`.
     dynamic: Option<Vec<String>>,
 }
 
+/// Resolve the requirements of a dependency group, including those of the groups it includes.
+///
+/// Supports the uv-specific `exclude` and `overrides` keys of `include-group` entries.
+fn group_requirements(
+    groups: Option<&DependencyGroups>,
+    name: &GroupName,
+    parents: &mut Vec<GroupName>,
+) -> Result<Vec<Requirement>, ValidationError> {
+    if parents.contains(name) {
+        return Err(ValidationError::DependencyGroupCycle(name.clone()));
+    }
+    let Some(specifiers) = groups.and_then(|groups| groups.get(name)) else {
+        return Err(ValidationError::MissingDependencyGroup(name.clone()));
+    };
+    let parse = |requirement: &String| {
+        Requirement::from_str(requirement).map_err(|err| {
+            ValidationError::DependencyGroupRequirement {
+                group: name.clone(),
+                requirement: requirement.clone(),
+                err: Box::new(err),
+            }
+        })
+    };
+
+    parents.push(name.clone());
+    let mut requirements = Vec::new();
+    for specifier in specifiers {
+        match specifier {
+            DependencyGroupSpecifier::Requirement(requirement) => {
+                requirements.push(parse(requirement)?);
+            }
+            DependencyGroupSpecifier::IncludeGroup {
+                include_group,
+                exclude,
+                overrides,
+            } => {
+                let overrides = overrides.iter().map(parse).collect::<Result<Vec<_>, _>>()?;
+                let included = group_requirements(groups, include_group, parents)?;
+                requirements.extend(included.into_iter().filter(|requirement| {
+                    !exclude.contains(&requirement.name)
+                        && !overrides
+                            .iter()
+                            .any(|replacement| replacement.name == requirement.name)
+                }));
+                requirements.extend(overrides);
+            }
+            DependencyGroupSpecifier::Object(_) => {
+                return Err(ValidationError::UnsupportedDependencyGroupEntry(
+                    name.clone(),
+                ));
+            }
+        }
+    }
+    parents.pop();
+    Ok(requirements)
+}
+
 /// The optional `project.readme` key in a pyproject.toml as specified in
 /// <https://packaging.python.org/en/latest/specifications/pyproject-toml/#readme>.
 #[derive(Deserialize, Debug, Clone)]
@@ -1505,6 +1738,172 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn dynamic_version_without_git() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            dynamic = ["version"]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+        "#
+        };
+
+        let err = PyProjectToml::parse(contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap_err();
+        assert_snapshot!(format_err(err), @r#"
+        Invalid pyproject.toml
+          Caused by: A dynamic `project.version` requires `tool.uv.build-backend.version-source = "git"`
+        "#);
+    }
+
+    #[test]
+    fn git_version_with_static_version() {
+        let src = TempDir::new().unwrap();
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            extend_project(indoc! {r#"
+                [tool.uv.build-backend]
+                version-source = "git"
+            "#
+            }),
+        )
+        .unwrap();
+
+        let err = PyProjectToml::load(src.path()).unwrap_err();
+        assert_snapshot!(format_err(err), @r#"
+        Invalid pyproject.toml
+          Caused by: With `tool.uv.build-backend.version-source = "git"`, `project.version` must not be set and `version` must be listed in `project.dynamic`
+        "#);
+    }
+
+    #[test]
+    fn git_version_from_pkg_info() {
+        let src = TempDir::new().unwrap();
+        fs_err::write(
+            src.path().join("pyproject.toml"),
+            indoc! {r#"
+                [project]
+                name = "hello-world"
+                dynamic = ["version"]
+
+                [build-system]
+                requires = ["uv_build>=0.4.15,<0.5.0"]
+                build-backend = "uv_build"
+
+                [tool.uv.build-backend]
+                version-source = "git"
+            "#
+            },
+        )
+        .unwrap();
+        fs_err::write(
+            src.path().join("PKG-INFO"),
+            "Metadata-Version: 2.4\nName: hello-world\nVersion: 1.2.4.dev3+g1a2b3c4\n",
+        )
+        .unwrap();
+
+        let pyproject_toml = PyProjectToml::load(src.path()).unwrap();
+        assert_eq!(pyproject_toml.version().to_string(), "1.2.4.dev3+g1a2b3c4");
+        let metadata = pyproject_toml.to_metadata(src.path()).unwrap();
+        assert_eq!(metadata.version, "1.2.4.dev3+g1a2b3c4");
+    }
+
+    #[test]
+    fn extras_from_groups() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            version = "0.1.0"
+            dependencies = ["anyio>=4"]
+            dynamic = ["optional-dependencies"]
+
+            [dependency-groups]
+            cli = ["click>=8", { include-group = "color" }]
+            color = ["rich>=13", "colorama ; sys_platform == 'win32'"]
+            dev = ["pytest"]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            extras-from-groups = ["cli"]
+        "#
+        };
+
+        let metadata = PyProjectToml::parse(contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap();
+
+        assert_snapshot!(metadata.core_metadata_format(), @r"
+        Metadata-Version: 2.3
+        Name: hello-world
+        Version: 0.1.0
+        Requires-Dist: anyio>=4
+        Requires-Dist: click>=8 ; extra == 'cli'
+        Requires-Dist: rich>=13 ; extra == 'cli'
+        Requires-Dist: colorama ; sys_platform == 'win32' and extra == 'cli'
+        Provides-Extra: cli
+        ");
+    }
+
+    #[test]
+    fn extras_from_groups_not_dynamic() {
+        let contents = extend_project(indoc! {r#"
+            [dependency-groups]
+            cli = ["click>=8"]
+
+            [tool.uv.build-backend]
+            extras-from-groups = ["cli"]
+        "#
+        });
+
+        let err = PyProjectToml::parse(&contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap_err();
+        assert_snapshot!(format_err(err), @r"
+        Invalid pyproject.toml
+          Caused by: With `tool.uv.build-backend.extras-from-groups`, `project.optional-dependencies` must not be set and `optional-dependencies` must be listed in `project.dynamic`
+        ");
+    }
+
+    #[test]
+    fn extras_from_missing_group() {
+        let contents = indoc! {r#"
+            [project]
+            name = "hello-world"
+            version = "0.1.0"
+            dynamic = ["optional-dependencies"]
+
+            [dependency-groups]
+            cli = [{ include-group = "color" }]
+
+            [build-system]
+            requires = ["uv_build>=0.4.15,<0.5.0"]
+            build-backend = "uv_build"
+
+            [tool.uv.build-backend]
+            extras-from-groups = ["cli"]
+        "#
+        };
+
+        let err = PyProjectToml::parse(contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap_err();
+        assert_snapshot!(format_err(err), @r"
+        Invalid pyproject.toml
+          Caused by: Dependency group `color` is not defined in `dependency-groups`
+        ");
+    }
+
     fn script_error(contents: &str) -> String {
         let err = PyProjectToml::parse(contents)
             .unwrap()
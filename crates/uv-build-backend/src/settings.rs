@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use uv_macros::OptionsMetadata;
+use uv_normalize::GroupName;
 
 /// Settings for the uv build backend (`uv_build`).
 ///
@@ -135,6 +136,35 @@ pub struct BuildBackendSettings {
     )]
     pub namespace: bool,
 
+    /// Build a package without any Python modules.
+    ///
+    /// Data-only packages ship only the files from `data` (and the license files), e.g., to
+    /// distribute executables, headers, or shared resources. The module checks are skipped, so
+    /// `data-only` can't be combined with `module-name` or `namespace`, and requires at least one
+    /// `data` entry.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"data-only = true"#
+    )]
+    pub data_only: bool,
+
+    /// Mark a stubs package as partial.
+    ///
+    /// Partial stubs packages only cover a part of the typed module, and type checkers fall back
+    /// to the installed module for everything else, as defined in
+    /// [PEP 561](https://peps.python.org/pep-0561/#partial-stub-packages). With this option, a
+    /// `py.typed` file containing `partial\n` is added to the stubs module in the wheel. If the
+    /// stubs module already contains a `py.typed`, it must contain `partial\n`.
+    ///
+    /// Only supported for stubs packages, whose module name ends with `-stubs`.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"partial-stubs = true"#
+    )]
+    pub partial_stubs: bool,
+
     /// Data includes for wheels.
     ///
     /// Each entry is a directory, whose contents are copied to the matching directory in the wheel
@@ -168,6 +198,97 @@ pub struct BuildBackendSettings {
         example = r#"data = { headers = "include/headers", scripts = "bin" }"#
     )]
     pub data: WheelDataIncludes,
+
+    /// C or Cython extension modules to compile into the wheel.
+    ///
+    /// Each entry declares the full module name of the extension and the C (`.c`) or Cython
+    /// (`.pyx`) sources to compile, relative to the project root. When extension modules are
+    /// declared, the wheel is tagged for the Python interpreter and platform it was built with
+    /// (e.g., `cp312-cp312-linux_x86_64`) instead of `py3-none-any`.
+    ///
+    /// Extension sources are compiled with the compiler from the `CC` environment variable, or
+    /// `cc` by default, respecting `CFLAGS` and `LDFLAGS`. Cython sources are translated to C with
+    /// `python -m cython`, so `cython>=3` must be listed in `build-system.requires`.
+    ///
+    /// The extension sources and include directories are added to the source distribution, and
+    /// excluded from the wheel. For editable installs, extension modules are compiled in-place
+    /// next to their sources.
+    ///
+    /// Building extension modules is currently only supported on Unix platforms with a GCC
+    /// compatible compiler.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[dict]",
+        example = r#"extensions = [{ name = "foo._speedups", sources = ["src/foo/_speedups.c"] }]"#
+    )]
+    pub extensions: Vec<ExtensionModule>,
+
+    /// The source of the project version.
+    ///
+    /// By default, the version is read from `project.version`. With `git`, the version is derived
+    /// from the most recent git tag reachable from `HEAD` instead, and `project.version` must be
+    /// declared as dynamic (`dynamic = ["version"]`).
+    ///
+    /// Tags may be prefixed with `v`, e.g., `v1.2.3`. If `HEAD` is tagged and the working tree is
+    /// clean, the version of the tag is used. Otherwise, the version is derived from the tag
+    /// following `version-scheme`, e.g., `1.2.4.dev3+g1a2b3c4`. If there are no tags, the
+    /// version is derived from `0.0`.
+    ///
+    /// The derived version is recorded in the source distribution's `PKG-INFO`, which is used when
+    /// building a wheel from the source distribution.
+    #[option(
+        default = r#""static""#,
+        value_type = r#""static" | "git""#,
+        example = r#"version-source = "git""#
+    )]
+    pub version_source: VersionSource,
+
+    /// How to derive the version from a git tag if `HEAD` isn't tagged, or has uncommitted
+    /// changes, when using `version-source = "git"`.
+    ///
+    /// - `guess-next-dev`: Increment the last release segment (or the pre-release number) of the
+    ///   tag, and add the number of commits since the tag as development release, e.g.,
+    ///   `1.2.4.dev3+g1a2b3c4` for the third commit after `v1.2.3`.
+    /// - `post-release`: Add the number of commits since the tag as post-release, e.g.,
+    ///   `1.2.3.post3+g1a2b3c4` for the third commit after `v1.2.3`.
+    ///
+    /// In both cases, the abbreviated commit hash is added as local version, followed by `dirty`
+    /// if the working tree has uncommitted changes.
+    #[option(
+        default = r#""guess-next-dev""#,
+        value_type = r#""guess-next-dev" | "post-release""#,
+        example = r#"version-scheme = "post-release""#
+    )]
+    pub version_scheme: VersionScheme,
+
+    /// A Python file to write the version derived from git to, relative to the project root.
+    ///
+    /// The file defines `__version__` and `version`, and is written before the source distribution
+    /// or wheel is built, so it's included if it's inside the module directory, e.g.,
+    /// `src/foo/_version.py`. Only used with `version-source = "git"`.
+    #[option(
+        default = r#"None"#,
+        value_type = "str",
+        example = r#"version-file = "src/foo/_version.py""#
+    )]
+    pub version_file: Option<PathBuf>,
+
+    /// Dependency groups to publish as extras of the same name.
+    ///
+    /// The requirements of each group, including those of any groups it includes with
+    /// `include-group`, are added to the package metadata as an extra, such that downstream
+    /// installers can install the group, e.g., with `pip install foo[cli]`, without duplicating its
+    /// requirements in `project.optional-dependencies`.
+    ///
+    /// As the extras are determined at build time, `optional-dependencies` must be listed in
+    /// `project.dynamic`, and extras that aren't mirrored from a group can't be declared in
+    /// `project.optional-dependencies`.
+    #[option(
+        default = r#"[]"#,
+        value_type = "list[str]",
+        example = r#"extras-from-groups = ["cli"]"#
+    )]
+    pub extras_from_groups: Vec<GroupName>,
 }
 
 impl Default for BuildBackendSettings {
@@ -180,11 +301,46 @@ impl Default for BuildBackendSettings {
             source_exclude: Vec::new(),
             wheel_exclude: Vec::new(),
             namespace: false,
+            data_only: false,
+            partial_stubs: false,
             data: WheelDataIncludes::default(),
+            extensions: Vec::new(),
+            version_source: VersionSource::default(),
+            version_scheme: VersionScheme::default(),
+            version_file: None,
+            extras_from_groups: Vec::new(),
         }
     }
 }
 
+/// The source of the project version.
+///
+/// See `BuildBackendSettings::version_source`.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionSource {
+    /// Read the version from `project.version`.
+    #[default]
+    Static,
+    /// Derive the version from git tags.
+    Git,
+}
+
+/// How to derive a version from a git tag.
+///
+/// See `BuildBackendSettings::version_scheme`.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VersionScheme {
+    /// Increment the tag's version, and add the distance to the tag as development release.
+    #[default]
+    GuessNextDev,
+    /// Add the distance to the tag as post-release.
+    PostRelease,
+}
+
 /// Whether to include a single module or multiple modules.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -196,6 +352,37 @@ pub enum ModuleName {
     Names(Vec<String>),
 }
 
+/// A C or Cython extension module.
+///
+/// See `BuildBackendSettings::extensions`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExtensionModule {
+    /// The full module name of the extension, e.g., `foo._speedups`.
+    pub name: String,
+    /// The C (`.c`) or Cython (`.pyx`) sources of the extension, relative to the project root.
+    pub sources: Vec<PathBuf>,
+    /// Additional directories to search for header files, relative to the project root.
+    #[serde(default)]
+    pub include_dirs: Vec<PathBuf>,
+    /// Preprocessor macros to define, either as `NAME` or `NAME=VALUE`.
+    #[serde(default)]
+    pub define_macros: Vec<String>,
+    /// Libraries to link against, e.g., `m` for `libm`.
+    #[serde(default)]
+    pub libraries: Vec<String>,
+    /// Additional directories to search for libraries, relative to the project root.
+    #[serde(default)]
+    pub library_dirs: Vec<PathBuf>,
+    /// Additional arguments to pass to the compiler.
+    #[serde(default)]
+    pub extra_compile_args: Vec<String>,
+    /// Additional arguments to pass to the linker.
+    #[serde(default)]
+    pub extra_link_args: Vec<String>,
+}
+
 /// Data includes for wheels.
 ///
 /// See `BuildBackendSettings::data`.
@@ -1,8 +1,9 @@
+use crate::extension::{extension_artifact_excludes, extension_includes};
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::wheel::build_exclude_matcher;
 use crate::{
     BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots,
+    error_on_venv, find_roots, source_date_epoch,
 };
 use flate2::Compression;
 use flate2::write::GzEncoder;
@@ -25,8 +26,7 @@ pub fn build_source_dist(
     source_dist_directory: &Path,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
@@ -43,8 +43,7 @@ pub fn list_source_dist(
     source_tree: &Path,
     uv_version: &str,
 ) -> Result<(SourceDistFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     let filename = SourceDistFilename {
         name: pyproject_toml.name().clone(),
         version: pyproject_toml.version().clone(),
@@ -75,6 +74,7 @@ fn source_dist_matcher(
         &settings.module_root,
         settings.module_name.as_ref(),
         settings.namespace,
+        settings.data_only,
     )?;
     for module_relative in modules_relative {
         // The wheel must not include any files included by the source distribution (at least until we
@@ -122,6 +122,18 @@ fn source_dist_matcher(
         include_globs.push(glob);
     }
 
+    // Include the extension module sources and headers
+    for include in extension_includes(&settings.extensions)? {
+        trace!("Including extension module files at: {include}");
+        let glob = PortableGlobParser::Uv
+            .parse(&include)
+            .map_err(|err| Error::PortableGlob {
+                field: "tool.uv.build-backend.extensions".to_string(),
+                source: err,
+            })?;
+        include_globs.push(glob);
+    }
+
     // Include the data files
     for (name, directory) in settings.data.iter() {
         let directory = uv_fs::normalize_path(directory);
@@ -169,6 +181,11 @@ fn source_dist_matcher(
             excludes.push(exclude);
         }
     }
+    // Compiled extension modules, e.g., from editable builds, are build artifacts.
+    excludes.extend(extension_artifact_excludes(
+        &settings.extensions,
+        &settings.module_root,
+    )?);
     debug!("Source dist excludes: {:?}", excludes);
     let exclude_matcher = build_exclude_matcher(excludes)?;
     if exclude_matcher.is_match("pyproject.toml") {
@@ -183,8 +200,7 @@ fn write_source_dist(
     mut writer: impl DirectoryWriter,
     uv_version: &str,
 ) -> Result<SourceDistFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
@@ -282,15 +298,18 @@ fn write_source_dist(
 struct TarGzWriter {
     path: PathBuf,
     tar: tar::Builder<GzEncoder<File>>,
+    /// The modification time of all entries, from `SOURCE_DATE_EPOCH` or the Unix epoch.
+    mtime: u64,
 }
 
 impl TarGzWriter {
     fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
         let path = path.into();
+        let mtime = source_date_epoch()?.unwrap_or_default();
         let file = File::create(&path)?;
         let enc = GzEncoder::new(file, Compression::default());
         let tar = tar::Builder::new(enc);
-        Ok(Self { path, tar })
+        Ok(Self { path, tar, mtime })
     }
 }
 
@@ -298,6 +317,7 @@ impl DirectoryWriter for TarGzWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         let mut header = Header::new_gnu();
         header.set_size(bytes.len() as u64);
+        header.set_mtime(self.mtime);
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
@@ -329,6 +349,7 @@ impl DirectoryWriter for TarGzWriter {
             header.set_mode(0o644);
         }
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         self.tar
             .append_data(&mut header, path, reader)
@@ -342,6 +363,7 @@ impl DirectoryWriter for TarGzWriter {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         self.tar
             .append_data(&mut header, directory, io::empty())
             .map_err(|err| Error::TarWrite(self.path.clone(), err))?;
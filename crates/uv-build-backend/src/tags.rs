@@ -0,0 +1,615 @@
+//! Check the tags of a built wheel against the binaries it contains, similar to the check phase of
+//! `auditwheel`.
+//!
+//! A wheel's platform and ABI tags are a claim about where the wheel can be installed. This module
+//! inspects the ELF, Mach-O, and PE binaries in a wheel and reports tags that are broader than the
+//! binaries justify, e.g., a `manylinux_2_17` wheel with a binary that requires glibc 2.28, or a
+//! `universal2` wheel that's missing the `arm64` slice.
+
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use fs_err::File;
+use goblin::elf::Elf;
+use goblin::mach::{Mach, MachO, SingleArch};
+use goblin::pe::PE;
+use tracing::{debug, trace};
+
+use uv_distribution_filename::WheelFilename;
+use uv_platform_tags::{AbiTag, Arch, BinaryFormat, PlatformTag};
+
+use crate::Error;
+
+/// A tag of a wheel that's broader than one of its binaries justifies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagMismatch {
+    /// The path of the binary inside the wheel.
+    pub file: String,
+    /// The tag that the binary doesn't support.
+    pub tag: String,
+    /// Why the binary doesn't support the tag.
+    pub reason: String,
+}
+
+impl fmt::Display for TagMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` {} (tag `{}`)", self.file, self.reason, self.tag)
+    }
+}
+
+/// Check that the platform and ABI tags of a wheel aren't broader than its binaries justify.
+///
+/// Returns the mismatches between the tags and the binaries, which is empty if the tags are
+/// justified. Binaries are detected by their magic bytes, such that executables and shared
+/// libraries without an extension are checked too.
+pub fn check_wheel_tags(wheel: &Path) -> Result<Vec<TagMismatch>, Error> {
+    let raw_filename = wheel
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let filename = WheelFilename::from_str(raw_filename)
+        .map_err(|err| Error::InvalidWheelFilename(raw_filename.to_string(), err))?;
+
+    let mut archive = zip::ZipArchive::new(File::open(wheel)?)?;
+    let mut mismatches = Vec::new();
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        if !entry.is_file() {
+            continue;
+        }
+        let file = entry.name().to_string();
+
+        let mut magic = [0u8; 4];
+        if entry.size() < 4 || entry.read_exact(&mut magic).is_err() {
+            continue;
+        }
+        if !is_binary(&file, magic) {
+            continue;
+        }
+        let mut bytes = magic.to_vec();
+        entry.read_to_end(&mut bytes)?;
+
+        let binary = Binary::parse(&bytes).map_err(|err| Error::Binary {
+            path: file.clone(),
+            err,
+        })?;
+        trace!("Found binary `{file}`: {binary:?}");
+
+        for tag in filename.platform_tags() {
+            if let Some(reason) = binary.check_platform(tag) {
+                mismatches.push(TagMismatch {
+                    file: file.clone(),
+                    tag: tag.to_string(),
+                    reason,
+                });
+            }
+        }
+        for tag in filename.abi_tags() {
+            if let Some(reason) = check_abi(&file, *tag) {
+                mismatches.push(TagMismatch {
+                    file: file.clone(),
+                    tag: tag.to_string(),
+                    reason,
+                });
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        debug!("The tags of `{filename}` are justified by its binaries");
+    }
+    Ok(mismatches)
+}
+
+/// Returns `true` if the file starts with the magic bytes of an ELF, Mach-O, or PE binary.
+fn is_binary(file: &str, magic: [u8; 4]) -> bool {
+    match magic {
+        // ELF
+        [0x7f, b'E', b'L', b'F'] => true,
+        // PE, which starts with an MS-DOS header.
+        [b'M', b'Z', ..] => Path::new(file).extension().is_some_and(|ext| {
+            ext.eq_ignore_ascii_case("pyd")
+                || ext.eq_ignore_ascii_case("dll")
+                || ext.eq_ignore_ascii_case("exe")
+        }),
+        // Thin Mach-O, 32-bit and 64-bit, in either byte order.
+        [0xfe, 0xed, 0xfa, 0xce | 0xcf] | [0xce | 0xcf, 0xfa, 0xed, 0xfe] => true,
+        // Fat Mach-O, which shares its magic bytes with Java class files.
+        [0xca, 0xfe, 0xba, 0xbe] => !file.ends_with(".class"),
+        _ => false,
+    }
+}
+
+/// The properties of a binary that determine which tags it supports.
+#[derive(Debug)]
+enum Binary {
+    Elf {
+        /// The architectures that match the ELF machine, e.g., any 32-bit ARM variant for `EM_ARM`.
+        archs: &'static [Arch],
+        /// The highest glibc symbol version the binary requires, if it links against glibc.
+        glibc: Option<(u16, u16)>,
+        /// Whether the binary links against musl.
+        musl: bool,
+    },
+    MachO {
+        /// The architecture and minimum macOS version of each slice.
+        slices: Vec<(Option<Arch>, Option<(u16, u16)>)>,
+    },
+    Pe {
+        arch: Option<Arch>,
+    },
+}
+
+impl Binary {
+    fn parse(bytes: &[u8]) -> Result<Self, goblin::error::Error> {
+        match bytes {
+            [0x7f, b'E', b'L', b'F', ..] => Ok(Self::from_elf(&Elf::parse(bytes)?)),
+            [b'M', b'Z', ..] => {
+                let pe = PE::parse(bytes)?;
+                let arch = match pe.header.coff_header.machine {
+                    goblin::pe::header::COFF_MACHINE_X86 => Some(Arch::X86),
+                    goblin::pe::header::COFF_MACHINE_X86_64 => Some(Arch::X86_64),
+                    goblin::pe::header::COFF_MACHINE_ARM64 => Some(Arch::Aarch64),
+                    _ => None,
+                };
+                Ok(Self::Pe { arch })
+            }
+            _ => {
+                let slices = match Mach::parse(bytes)? {
+                    Mach::Binary(macho) => vec![macho_slice(&macho)],
+                    Mach::Fat(multi) => {
+                        let mut slices = Vec::new();
+                        for index in 0..multi.narches {
+                            if let SingleArch::MachO(macho) = multi.get(index)? {
+                                slices.push(macho_slice(&macho));
+                            }
+                        }
+                        slices
+                    }
+                };
+                Ok(Self::MachO { slices })
+            }
+        }
+    }
+
+    fn from_elf(elf: &Elf) -> Self {
+        use goblin::elf::header::{
+            EM_386, EM_AARCH64, EM_ARM, EM_PPC, EM_PPC64, EM_RISCV, EM_S390, EM_X86_64,
+        };
+
+        /// The ELF machine of LoongArch, see <https://github.com/loongson/la-abi-specs>.
+        const EM_LOONGARCH: u16 = 258;
+
+        let archs: &'static [Arch] = match elf.header.e_machine {
+            EM_X86_64 => &[Arch::X86_64],
+            EM_386 => &[Arch::X86],
+            EM_AARCH64 => &[Arch::Aarch64],
+            EM_ARM => &[Arch::Armv5TEL, Arch::Armv6L, Arch::Armv7L],
+            EM_PPC64 if elf.little_endian => &[Arch::Powerpc64Le],
+            EM_PPC64 => &[Arch::Powerpc64],
+            EM_PPC => &[Arch::Powerpc],
+            EM_S390 => &[Arch::S390X],
+            EM_RISCV => &[Arch::Riscv64],
+            EM_LOONGARCH => &[Arch::LoongArch64],
+            _ => &[],
+        };
+
+        // Find the highest `GLIBC_x.y` version among the required symbol versions.
+        let mut glibc = None;
+        if let Some(verneed) = &elf.verneed {
+            for need in verneed.iter() {
+                for aux in need.iter() {
+                    let Some(version) = elf
+                        .dynstrtab
+                        .get_at(aux.vna_name)
+                        .and_then(|name| name.strip_prefix("GLIBC_"))
+                        .and_then(parse_version)
+                    else {
+                        continue;
+                    };
+                    glibc = glibc.max(Some(version));
+                }
+            }
+        }
+
+        let musl = elf
+            .libraries
+            .iter()
+            .any(|library| library.starts_with("libc.musl-"));
+
+        Self::Elf { archs, glibc, musl }
+    }
+
+    /// Check that the binary supports the platform tag, returning the reason if it doesn't.
+    fn check_platform(&self, tag: &PlatformTag) -> Option<String> {
+        match tag {
+            PlatformTag::Any => Some(
+                "is a compiled binary, but the wheel is tagged as platform-independent".to_string(),
+            ),
+            PlatformTag::Manylinux { major, minor, arch } => {
+                self.check_linux(*arch, Some((*major, *minor)), false)
+            }
+            PlatformTag::Manylinux1 { arch } => self.check_linux(*arch, Some((2, 5)), false),
+            PlatformTag::Manylinux2010 { arch } => self.check_linux(*arch, Some((2, 12)), false),
+            PlatformTag::Manylinux2014 { arch } => self.check_linux(*arch, Some((2, 17)), false),
+            PlatformTag::Linux { arch } => self.check_linux(*arch, None, false),
+            PlatformTag::Musllinux { arch, .. } => self.check_linux(*arch, None, true),
+            PlatformTag::Macos {
+                major,
+                minor,
+                binary_format,
+            } => self.check_macos((*major, *minor), *binary_format),
+            PlatformTag::Win32 => self.check_windows(Arch::X86),
+            PlatformTag::WinAmd64 => self.check_windows(Arch::X86_64),
+            PlatformTag::WinArm64 => self.check_windows(Arch::Aarch64),
+            // We don't check the remaining platforms.
+            _ => None,
+        }
+    }
+
+    fn check_linux(&self, arch: Arch, glibc: Option<(u16, u16)>, musl: bool) -> Option<String> {
+        let Self::Elf {
+            archs,
+            glibc: required_glibc,
+            musl: links_musl,
+        } = self
+        else {
+            return Some(format!("is a {}, not an ELF binary", self.kind()));
+        };
+        if !archs.is_empty() && !archs.contains(&arch) {
+            return Some(format!("is built for {}, not {arch}", archs[0]));
+        }
+        if musl {
+            if let Some((major, minor)) = required_glibc {
+                return Some(format!(
+                    "requires glibc {major}.{minor}, but the wheel is tagged for musl"
+                ));
+            }
+        } else {
+            if *links_musl {
+                return Some("links against musl, but the wheel is tagged for glibc".to_string());
+            }
+            if let (Some(required), Some(tagged)) = (required_glibc, glibc)
+                && *required > tagged
+            {
+                return Some(format!(
+                    "requires glibc {}.{}, but the wheel is tagged for glibc {}.{}",
+                    required.0, required.1, tagged.0, tagged.1
+                ));
+            }
+        }
+        None
+    }
+
+    fn check_macos(&self, version: (u16, u16), binary_format: BinaryFormat) -> Option<String> {
+        let Self::MachO { slices } = self else {
+            return Some(format!("is a {}, not a Mach-O binary", self.kind()));
+        };
+        for arch in macos_archs(binary_format) {
+            let Some((_, minimum)) = slices.iter().find(|(slice, _)| *slice == Some(*arch)) else {
+                return Some(format!("is missing the {arch} architecture"));
+            };
+            if let Some(minimum) = minimum
+                && *minimum > version
+            {
+                return Some(format!(
+                    "requires macOS {}.{}, but the wheel is tagged for macOS {}.{}",
+                    minimum.0, minimum.1, version.0, version.1
+                ));
+            }
+        }
+        None
+    }
+
+    fn check_windows(&self, arch: Arch) -> Option<String> {
+        let Self::Pe { arch: pe_arch } = self else {
+            return Some(format!("is a {}, not a PE binary", self.kind()));
+        };
+        match pe_arch {
+            Some(pe_arch) if *pe_arch != arch => {
+                Some(format!("is built for {pe_arch}, not {arch}"))
+            }
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Elf { .. } => "ELF binary",
+            Self::MachO { .. } => "Mach-O binary",
+            Self::Pe { .. } => "PE binary",
+        }
+    }
+}
+
+/// Read the architecture and minimum macOS version of a Mach-O slice.
+fn macho_slice(macho: &MachO) -> (Option<Arch>, Option<(u16, u16)>) {
+    use goblin::mach::cputype::{
+        CPU_TYPE_ARM64, CPU_TYPE_POWERPC, CPU_TYPE_POWERPC64, CPU_TYPE_X86, CPU_TYPE_X86_64,
+    };
+    use goblin::mach::load_command::CommandVariant;
+
+    let arch = match macho.header.cputype {
+        CPU_TYPE_X86_64 => Some(Arch::X86_64),
+        CPU_TYPE_X86 => Some(Arch::X86),
+        CPU_TYPE_ARM64 => Some(Arch::Aarch64),
+        CPU_TYPE_POWERPC => Some(Arch::Powerpc),
+        CPU_TYPE_POWERPC64 => Some(Arch::Powerpc64),
+        _ => None,
+    };
+
+    // The version is encoded as `xxxx.yy.zz` in nibbles.
+    let minimum = macho.load_commands.iter().find_map(|command| {
+        let version = match &command.command {
+            CommandVariant::BuildVersion(build_version) => build_version.minos,
+            CommandVariant::VersionMinMacosx(version_min) => version_min.version,
+            _ => return None,
+        };
+        let [major_high, major_low, minor, _patch] = version.to_be_bytes();
+        Some((
+            u16::from_be_bytes([major_high, major_low]),
+            u16::from(minor),
+        ))
+    });
+
+    (arch, minimum)
+}
+
+/// The architectures a macOS binary format requires.
+///
+/// See: <https://github.com/pypa/packaging/blob/fd4f11139d1c884a637be8aa26bb60a31fbc9411/packaging/tags.py#L314>
+fn macos_archs(binary_format: BinaryFormat) -> &'static [Arch] {
+    match binary_format {
+        BinaryFormat::Arm64 => &[Arch::Aarch64],
+        BinaryFormat::X86_64 => &[Arch::X86_64],
+        BinaryFormat::I386 => &[Arch::X86],
+        BinaryFormat::Ppc => &[Arch::Powerpc],
+        BinaryFormat::Ppc64 => &[Arch::Powerpc64],
+        BinaryFormat::Intel => &[Arch::X86, Arch::X86_64],
+        BinaryFormat::Universal2 => &[Arch::X86_64, Arch::Aarch64],
+        BinaryFormat::Fat | BinaryFormat::Fat32 => &[Arch::X86, Arch::Powerpc],
+        BinaryFormat::Fat64 => &[Arch::X86_64, Arch::Powerpc64],
+        BinaryFormat::Universal => &[Arch::X86, Arch::X86_64, Arch::Powerpc, Arch::Powerpc64],
+    }
+}
+
+/// Check that an extension module supports the ABI tag, based on the interpreter-specific suffix
+/// of its filename (e.g., `.cpython-312-x86_64-linux-gnu.so` or `.cp312-win_amd64.pyd`), returning
+/// the reason if it doesn't.
+fn check_abi(file: &str, tag: AbiTag) -> Option<String> {
+    let (version, gil_disabled) = cpython_suffix(file)?;
+    let tagged = match tag {
+        AbiTag::None | AbiTag::Abi3 => {
+            return Some(format!(
+                "is built for CPython {}.{}{} specifically, but the wheel is tagged `{tag}`",
+                version.0,
+                version.1,
+                if gil_disabled { "t" } else { "" }
+            ));
+        }
+        AbiTag::CPython {
+            python_version,
+            gil_disabled: tagged_gil_disabled,
+            ..
+        } => (python_version, tagged_gil_disabled),
+        _ => return None,
+    };
+    if tagged != (version, gil_disabled) {
+        return Some(format!(
+            "is built for CPython {}.{}{}",
+            version.0,
+            version.1,
+            if gil_disabled { "t" } else { "" }
+        ));
+    }
+    None
+}
+
+/// Parse the CPython version from the suffix of an extension module, e.g.,
+/// `foo.cpython-313t-x86_64-linux-gnu.so` or `foo.cp312-win_amd64.pyd`.
+///
+/// Returns `None` for modules without a version-specific suffix, e.g., `foo.abi3.so` or
+/// `foo.pyd`.
+fn cpython_suffix(file: &str) -> Option<((u8, u8), bool)> {
+    let name = file.rsplit('/').next().unwrap_or(file);
+    let mut parts = name.split('.');
+    // Skip the module name.
+    parts.next()?;
+    parts.find_map(|part| {
+        let tag = part
+            .strip_prefix("cpython-")
+            .or_else(|| part.strip_prefix("cp"))?;
+        let tag = tag.split('-').next()?;
+        let (digits, gil_disabled) = match tag.strip_suffix('t') {
+            Some(digits) => (digits, true),
+            None => (tag, false),
+        };
+        let minor = digits.strip_prefix('3')?.parse::<u8>().ok()?;
+        Some(((3, minor), gil_disabled))
+    })
+}
+
+/// Parse a `major.minor` version, ignoring any further components.
+fn parse_version(version: &str) -> Option<(u16, u16)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_platform_tags::{AbiTag, Arch, BinaryFormat, PlatformTag};
+
+    use super::{Binary, check_abi, cpython_suffix};
+
+    fn elf(glibc: Option<(u16, u16)>, musl: bool) -> Binary {
+        Binary::Elf {
+            archs: &[Arch::X86_64],
+            glibc,
+            musl,
+        }
+    }
+
+    #[test]
+    fn manylinux() {
+        let binary = elf(Some((2, 17)), false);
+        let tag = |major, minor| PlatformTag::Manylinux {
+            major,
+            minor,
+            arch: Arch::X86_64,
+        };
+        assert_eq!(binary.check_platform(&tag(2, 17)), None);
+        assert_eq!(binary.check_platform(&tag(2, 28)), None);
+        assert_eq!(
+            binary.check_platform(&tag(2, 5)).unwrap(),
+            "requires glibc 2.17, but the wheel is tagged for glibc 2.5"
+        );
+        assert_eq!(
+            binary
+                .check_platform(&PlatformTag::Manylinux2010 { arch: Arch::X86_64 })
+                .unwrap(),
+            "requires glibc 2.17, but the wheel is tagged for glibc 2.12"
+        );
+        assert_eq!(
+            binary.check_platform(&PlatformTag::Manylinux2014 { arch: Arch::X86_64 }),
+            None
+        );
+        assert_eq!(
+            binary
+                .check_platform(&PlatformTag::Manylinux2014 {
+                    arch: Arch::Aarch64
+                })
+                .unwrap(),
+            "is built for x86_64, not aarch64"
+        );
+        assert_eq!(
+            binary.check_platform(&PlatformTag::Linux { arch: Arch::X86_64 }),
+            None
+        );
+    }
+
+    #[test]
+    fn musllinux() {
+        let tag = PlatformTag::Musllinux {
+            major: 1,
+            minor: 2,
+            arch: Arch::X86_64,
+        };
+        assert_eq!(elf(None, true).check_platform(&tag), None);
+        assert_eq!(
+            elf(Some((2, 17)), false).check_platform(&tag).unwrap(),
+            "requires glibc 2.17, but the wheel is tagged for musl"
+        );
+        assert_eq!(
+            elf(None, true)
+                .check_platform(&PlatformTag::Manylinux2014 { arch: Arch::X86_64 })
+                .unwrap(),
+            "links against musl, but the wheel is tagged for glibc"
+        );
+    }
+
+    #[test]
+    fn macos() {
+        let binary = Binary::MachO {
+            slices: vec![(Some(Arch::X86_64), Some((10, 12)))],
+        };
+        let tag = |major, minor, binary_format| PlatformTag::Macos {
+            major,
+            minor,
+            binary_format,
+        };
+        assert_eq!(
+            binary.check_platform(&tag(10, 12, BinaryFormat::X86_64)),
+            None
+        );
+        assert_eq!(
+            binary
+                .check_platform(&tag(11, 0, BinaryFormat::Intel))
+                .unwrap(),
+            "is missing the x86 architecture"
+        );
+        assert_eq!(
+            binary
+                .check_platform(&tag(10, 9, BinaryFormat::X86_64))
+                .unwrap(),
+            "requires macOS 10.12, but the wheel is tagged for macOS 10.9"
+        );
+        assert_eq!(
+            binary
+                .check_platform(&tag(11, 0, BinaryFormat::Universal2))
+                .unwrap(),
+            "is missing the aarch64 architecture"
+        );
+        assert_eq!(
+            binary
+                .check_platform(&PlatformTag::Manylinux2014 { arch: Arch::X86_64 })
+                .unwrap(),
+            "is a Mach-O binary, not an ELF binary"
+        );
+    }
+
+    #[test]
+    fn windows() {
+        let binary = Binary::Pe {
+            arch: Some(Arch::X86_64),
+        };
+        assert_eq!(binary.check_platform(&PlatformTag::WinAmd64), None);
+        assert_eq!(
+            binary.check_platform(&PlatformTag::Win32).unwrap(),
+            "is built for x86_64, not x86"
+        );
+        assert_eq!(
+            binary.check_platform(&PlatformTag::Any).unwrap(),
+            "is a compiled binary, but the wheel is tagged as platform-independent"
+        );
+    }
+
+    #[test]
+    fn abi() {
+        assert_eq!(
+            cpython_suffix("foo/_speedups.cpython-312-x86_64-linux-gnu.so"),
+            Some(((3, 12), false))
+        );
+        assert_eq!(
+            cpython_suffix("foo/_speedups.cpython-313t-darwin.so"),
+            Some(((3, 13), true))
+        );
+        assert_eq!(
+            cpython_suffix("foo/_speedups.cp312-win_amd64.pyd"),
+            Some(((3, 12), false))
+        );
+        assert_eq!(cpython_suffix("foo/_speedups.abi3.so"), None);
+        assert_eq!(cpython_suffix("foo/_speedups.pyd"), None);
+        assert_eq!(cpython_suffix("foo.libs/libcpp-1a2b3c.so.1"), None);
+
+        let cp312 = AbiTag::CPython {
+            gil_disabled: false,
+            debug: false,
+            pymalloc: false,
+            python_version: (3, 12),
+        };
+        let module = "foo/_speedups.cpython-312-x86_64-linux-gnu.so";
+        assert_eq!(check_abi(module, cp312), None);
+        assert_eq!(check_abi("foo/_speedups.abi3.so", AbiTag::Abi3), None);
+        assert_eq!(
+            check_abi(module, AbiTag::Abi3).unwrap(),
+            "is built for CPython 3.12 specifically, but the wheel is tagged `abi3`"
+        );
+        assert_eq!(
+            check_abi(
+                module,
+                AbiTag::CPython {
+                    gil_disabled: false,
+                    debug: false,
+                    pymalloc: false,
+                    python_version: (3, 11),
+                }
+            )
+            .unwrap(),
+            "is built for CPython 3.12"
+        );
+    }
+}
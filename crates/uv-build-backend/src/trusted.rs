@@ -0,0 +1,347 @@
+//! Direct builds for projects using a third-party build backend.
+//!
+//! For simple projects, the wheels built by `hatchling` and `flit_core` are equivalent to the ones
+//! built by the uv build backend: all metadata is declared statically in the `[project]` table, and
+//! the wheel contains a single module. For such projects, we can build the wheel directly, without
+//! creating a build environment and spawning a Python subprocess. Any configuration of the
+//! backend itself is not reimplemented; instead, we fall back to a regular PEP 517 build.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+use tracing::debug;
+
+use uv_distribution_filename::WheelFilename;
+use uv_normalize::PackageName;
+use uv_pep508::Requirement;
+
+use crate::settings::ModuleName;
+use crate::wheel::{write_editable_file, write_wheel_file};
+use crate::{BuildBackendSettings, Error, PyProjectToml};
+
+/// A third-party build backend that we can replace with a direct build for simple projects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrustedBackend {
+    Hatchling,
+    FlitCore,
+}
+
+impl TrustedBackend {
+    /// Identify the trusted backend from the `build-system.build-backend` value.
+    fn from_build_backend(build_backend: &str) -> Option<Self> {
+        match build_backend {
+            "hatchling.build" => Some(Self::Hatchling),
+            "flit_core.buildapi" => Some(Self::FlitCore),
+            _ => None,
+        }
+    }
+
+    /// The name of the package providing the backend.
+    fn package(self) -> &'static str {
+        match self {
+            Self::Hatchling => "hatchling",
+            Self::FlitCore => "flit-core",
+        }
+    }
+}
+
+impl Display for TrustedBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.package())
+    }
+}
+
+/// A project that can be built directly in lieu of its trusted build backend.
+#[derive(Debug, Clone)]
+pub struct TrustedBuild {
+    pyproject_toml: PyProjectToml,
+}
+
+impl TrustedBuild {
+    /// Check if the project uses a trusted third-party build backend in a configuration that
+    /// we can build directly.
+    pub fn check(source_tree: &Path, name: impl Display) -> Option<Self> {
+        let contents = match fs_err::read_to_string(source_tree.join("pyproject.toml")) {
+            Ok(contents) => contents,
+            Err(err) => {
+                debug!(
+                    "Not using trusted direct build for `{name}`, failed to read pyproject.toml: {err}"
+                );
+                return None;
+            }
+        };
+        match Self::from_pyproject_toml(source_tree, &contents) {
+            Ok(trusted) => Some(trusted),
+            Err(reason) => {
+                debug!("Not using trusted direct build for `{name}`: {reason}");
+                None
+            }
+        }
+    }
+
+    /// Determine the uv build backend settings equivalent to the trusted backend configuration,
+    /// returning the reason for falling back to a regular build otherwise.
+    fn from_pyproject_toml(source_tree: &Path, contents: &str) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct RawPyProjectToml {
+            build_system: RawBuildSystem,
+            project: RawProject,
+            #[serde(default)]
+            tool: BTreeMap<String, toml::Value>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct RawBuildSystem {
+            requires: Vec<String>,
+            build_backend: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct RawProject {
+            name: PackageName,
+        }
+
+        let raw: RawPyProjectToml = toml::from_str(contents).map_err(|err| err.to_string())?;
+
+        let backend = raw
+            .build_system
+            .build_backend
+            .as_deref()
+            .and_then(TrustedBackend::from_build_backend)
+            .ok_or_else(|| "the build backend is not trusted".to_string())?;
+
+        // The build requirements must consist of the backend alone, without plugins.
+        for requires in &raw.build_system.requires {
+            let requirement = Requirement::<uv_pep508::VerbatimUrl>::from_str(requires)
+                .map_err(|err| err.to_string())?;
+            if requirement.name.as_ref() != backend.package() {
+                return Err(format!(
+                    "the build requirement `{requires}` is not provided by {backend}"
+                ));
+            }
+        }
+
+        // Any configuration of the backend may change the contents of the wheel.
+        let module_name = match backend {
+            TrustedBackend::Hatchling => {
+                if raw.tool.contains_key("hatch") {
+                    return Err("`tool.hatch` is not supported".to_string());
+                }
+                None
+            }
+            TrustedBackend::FlitCore => match raw.tool.get("flit") {
+                None => None,
+                Some(flit) => Some(
+                    flit_module_name(flit)
+                        .ok_or_else(|| "only `tool.flit.module.name` is supported".to_string())?,
+                ),
+            },
+        };
+        let module_name =
+            module_name.unwrap_or_else(|| raw.project.name.as_dist_info_name().to_string());
+
+        // Both backends support flat and src layouts; bail if the module is ambiguous.
+        let module_path = module_name.split('.').collect::<PathBuf>();
+        let roots = ["", "src"]
+            .into_iter()
+            .filter(|root| {
+                source_tree
+                    .join(root)
+                    .join(&module_path)
+                    .join("__init__.py")
+                    .is_file()
+            })
+            .collect::<Vec<_>>();
+        let [module_root] = roots.as_slice() else {
+            return Err(format!(
+                "expected exactly one package directory for the module `{module_name}`"
+            ));
+        };
+
+        let settings = BuildBackendSettings {
+            module_root: PathBuf::from(module_root),
+            module_name: Some(ModuleName::Name(module_name)),
+            ..BuildBackendSettings::default()
+        };
+        let pyproject_toml = PyProjectToml::parse(contents)
+            .map_err(|err| err.to_string())?
+            .with_settings(settings);
+
+        // Ensure that the metadata is static and supported by the uv build backend.
+        pyproject_toml
+            .to_metadata(source_tree)
+            .map_err(|err| err.to_string())?;
+
+        Ok(Self { pyproject_toml })
+    }
+
+    /// Build a wheel from the source tree and place it in the output directory.
+    pub fn build_wheel(
+        &self,
+        source_tree: &Path,
+        wheel_dir: &Path,
+        uv_version: &str,
+    ) -> Result<WheelFilename, Error> {
+        write_wheel_file(source_tree, wheel_dir, &self.pyproject_toml, uv_version)
+    }
+
+    /// Build an editable wheel from the source tree and place it in the output directory.
+    pub fn build_editable(
+        &self,
+        source_tree: &Path,
+        wheel_dir: &Path,
+        uv_version: &str,
+    ) -> Result<WheelFilename, Error> {
+        write_editable_file(source_tree, wheel_dir, &self.pyproject_toml, uv_version)
+    }
+}
+
+/// Read the module name from a `tool.flit` table that contains nothing but
+/// `tool.flit.module.name`.
+///
+/// Returns `None` if the table contains any other configuration.
+fn flit_module_name(flit: &toml::Value) -> Option<String> {
+    let flit = flit.as_table()?;
+    let module = flit.get("module")?.as_table()?;
+    if flit.len() != 1 || module.len() != 1 {
+        return None;
+    }
+    Some(module.get("name")?.as_str()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::{formatdoc, indoc};
+
+    fn check(contents: &str, files: &[&str]) -> Result<TrustedBuild, String> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for file in files {
+            let path = temp_dir.path().join(file);
+            fs_err::create_dir_all(path.parent().unwrap()).unwrap();
+            fs_err::write(path, "").unwrap();
+        }
+        TrustedBuild::from_pyproject_toml(temp_dir.path(), contents)
+    }
+
+    #[test]
+    fn hatchling_src_layout() {
+        let contents = indoc! {r#"
+            [project]
+            name = "foo-bar"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["hatchling>=1.26"]
+            build-backend = "hatchling.build"
+        "#};
+        let trusted = check(contents, &["src/foo_bar/__init__.py"]).unwrap();
+        let settings = trusted.pyproject_toml.settings().unwrap();
+        assert_eq!(settings.module_root, PathBuf::from("src"));
+        assert_eq!(
+            settings.module_name,
+            Some(ModuleName::Name("foo_bar".to_string()))
+        );
+    }
+
+    #[test]
+    fn flit_module_name_flat_layout() {
+        let contents = indoc! {r#"
+            [project]
+            name = "foo-bar"
+            version = "1.0.0"
+
+            [build-system]
+            requires = ["flit_core >=3.2,<4"]
+            build-backend = "flit_core.buildapi"
+
+            [tool.flit.module]
+            name = "foo"
+        "#};
+        let trusted = check(contents, &["foo/__init__.py"]).unwrap();
+        let settings = trusted.pyproject_toml.settings().unwrap();
+        assert_eq!(settings.module_root, PathBuf::from(""));
+        assert_eq!(
+            settings.module_name,
+            Some(ModuleName::Name("foo".to_string()))
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let pyproject_toml = |build_system: &str, tool: &str| {
+            formatdoc! {r#"
+                [project]
+                name = "foo"
+                version = "1.0.0"
+
+                [build-system]
+                {build_system}
+                {tool}
+            "#}
+        };
+        let hatchling = indoc! {r#"
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#};
+
+        // Plugins may change the wheel contents.
+        let err = check(
+            &pyproject_toml(
+                indoc! {r#"
+                    requires = ["hatchling", "hatch-vcs"]
+                    build-backend = "hatchling.build"
+                "#},
+                "",
+            ),
+            &["foo/__init__.py"],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            "the build requirement `hatch-vcs` is not provided by hatchling"
+        );
+
+        // Backend configuration isn't reimplemented.
+        let err = check(
+            &pyproject_toml(
+                hatchling,
+                "[tool.hatch.build.targets.wheel]\npackages = [\"foo\"]",
+            ),
+            &["foo/__init__.py"],
+        )
+        .unwrap_err();
+        assert_eq!(err, "`tool.hatch` is not supported");
+
+        // The module must be unambiguous.
+        let err = check(
+            &pyproject_toml(hatchling, ""),
+            &["foo/__init__.py", "src/foo/__init__.py"],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            "expected exactly one package directory for the module `foo`"
+        );
+
+        // Other backends aren't trusted.
+        let err = check(
+            &pyproject_toml(
+                indoc! {r#"
+                    requires = ["setuptools"]
+                    build-backend = "setuptools.build_meta"
+                "#},
+                "",
+            ),
+            &["foo/__init__.py"],
+        )
+        .unwrap_err();
+        assert_eq!(err, "the build backend is not trusted");
+    }
+}
@@ -0,0 +1,283 @@
+//! Derive the project version from git tags, for `version-source = "git"`.
+
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_pep440::{Prerelease, Version};
+
+use crate::Error;
+use crate::settings::VersionScheme;
+
+/// The state of the git checkout relative to the most recent tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Describe {
+    /// The version of the most recent tag, if any.
+    tag: Option<Version>,
+    /// The number of commits since the tag, or since the initial commit if there is no tag.
+    distance: u64,
+    /// The abbreviated hash of `HEAD`.
+    hash: String,
+    /// Whether the working tree has uncommitted changes.
+    dirty: bool,
+}
+
+/// Read the version from the `PKG-INFO` of a source distribution, if the source tree is one.
+pub(crate) fn pkg_info_version(root: &Path) -> Result<Option<Version>, Error> {
+    let path = root.join("PKG-INFO");
+    let contents = match fs_err::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    // The headers end at the first empty line, followed by the description.
+    let version = contents
+        .lines()
+        .take_while(|line| !line.is_empty())
+        .find_map(|line| line.strip_prefix("Version:"))
+        .ok_or_else(|| Error::MissingPkgInfoVersion(path.clone()))?;
+    let version = Version::from_str(version.trim())
+        .map_err(|err| Error::InvalidGitVersion(version.trim().to_string(), err.to_string()))?;
+    debug!("Using version `{version}` from `{}`", path.user_display());
+    Ok(Some(version))
+}
+
+/// Derive the version of the project from the git tags of the repository containing `root`.
+pub(crate) fn git_version(root: &Path, scheme: VersionScheme) -> Result<Version, Error> {
+    let describe = describe(root)?;
+    let version = describe.to_version(scheme)?;
+    debug!("Derived version `{version}` from git: {describe:?}");
+    Ok(version)
+}
+
+/// Write the version to a Python file, if it changed.
+pub(crate) fn write_version_file(root: &Path, file: &Path, version: &Version) -> Result<(), Error> {
+    if file.is_absolute()
+        || file
+            .components()
+            .any(|component| matches!(component, std::path::Component::ParentDir))
+    {
+        return Err(Error::InvalidVersionFile(file.to_path_buf()));
+    }
+    let path = root.join(file);
+    let contents = format!(
+        "# This file is generated by the uv build backend from git, do not edit.\n\
+        __version__ = version = \"{version}\"\n"
+    );
+    if fs_err::read_to_string(&path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    debug!("Writing version to `{}`", path.user_display());
+    fs_err::write(&path, contents)?;
+    Ok(())
+}
+
+/// Run `git` in the given directory, returning its standard output.
+fn git(root: &Path, args: &[&str]) -> Result<Option<String>, Error> {
+    let command = format!("git {}", args.join(" "));
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|err| Error::GitCommand {
+            command: command.clone(),
+            err,
+        })?;
+    if output.status.success() {
+        Ok(Some(
+            String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        ))
+    } else {
+        debug!(
+            "`{command}` failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        Ok(None)
+    }
+}
+
+/// Determine the most recent tag, and the distance to it, using `git describe`.
+fn describe(root: &Path) -> Result<Describe, Error> {
+    if git(root, &["rev-parse", "--is-inside-work-tree"])?.as_deref() != Some("true") {
+        return Err(Error::NotAGitRepository(root.to_path_buf()));
+    }
+
+    if let Some(output) = git(
+        root,
+        &["describe", "--tags", "--long", "--dirty", "--abbrev=7"],
+    )? {
+        return parse_describe(&output).ok_or(Error::InvalidGitDescribe(output));
+    }
+
+    // Without any tags, count the commits from the initial commit.
+    let hash = git(root, &["rev-parse", "--short=7", "HEAD"])?
+        .ok_or_else(|| Error::NotAGitRepository(root.to_path_buf()))?;
+    let distance = git(root, &["rev-list", "--count", "HEAD"])?
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(0);
+    let dirty = git(root, &["status", "--porcelain", "--untracked-files=no"])?
+        .is_some_and(|status| !status.is_empty());
+    Ok(Describe {
+        tag: None,
+        distance,
+        hash,
+        dirty,
+    })
+}
+
+/// Parse the output of `git describe --tags --long --dirty`, e.g., `v1.2.3-4-g1a2b3c4-dirty`.
+fn parse_describe(output: &str) -> Option<Describe> {
+    let (output, dirty) = match output.strip_suffix("-dirty") {
+        Some(output) => (output, true),
+        None => (output, false),
+    };
+    let (output, hash) = output.rsplit_once('-')?;
+    let hash = hash.strip_prefix('g')?.to_string();
+    let (tag, distance) = output.rsplit_once('-')?;
+    let distance = distance.parse().ok()?;
+    let tag = tag.strip_prefix('v').unwrap_or(tag);
+    let tag = Version::from_str(tag).ok()?;
+    Some(Describe {
+        tag: Some(tag),
+        distance,
+        hash,
+        dirty,
+    })
+}
+
+impl Describe {
+    /// Compute the version following the given scheme.
+    fn to_version(&self, scheme: VersionScheme) -> Result<Version, Error> {
+        let base = self.tag.clone().unwrap_or_else(|| Version::new([0, 0]));
+        if self.tag.is_some() && self.distance == 0 && !self.dirty {
+            return Ok(base);
+        }
+
+        let version = match scheme {
+            VersionScheme::GuessNextDev => {
+                let next = if let Some(pre) = base.pre() {
+                    base.only_release().with_pre(Some(Prerelease {
+                        kind: pre.kind,
+                        number: pre.number + 1,
+                    }))
+                } else {
+                    let mut release = base.release().to_vec();
+                    if let Some(last) = release.last_mut() {
+                        *last += 1;
+                    }
+                    Version::new(release)
+                };
+                format!("{}.dev{}", next.with_epoch(base.epoch()), self.distance)
+            }
+            VersionScheme::PostRelease => {
+                let post = base
+                    .post()
+                    .map_or(self.distance, |post| post + self.distance);
+                base.only_release()
+                    .with_epoch(base.epoch())
+                    .with_post(Some(post))
+                    .to_string()
+            }
+        };
+        let local = if self.dirty {
+            format!("g{}.dirty", self.hash)
+        } else {
+            format!("g{}", self.hash)
+        };
+        let version = format!("{version}+{local}");
+        Version::from_str(&version)
+            .map_err(|err| Error::InvalidGitVersion(version, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(output: &str, scheme: VersionScheme) -> String {
+        parse_describe(output)
+            .unwrap()
+            .to_version(scheme)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn describe_tagged() {
+        assert_eq!(
+            version("v1.2.3-0-g1a2b3c4", VersionScheme::GuessNextDev),
+            "1.2.3"
+        );
+        assert_eq!(
+            version("1.2.3-0-g1a2b3c4", VersionScheme::PostRelease),
+            "1.2.3"
+        );
+    }
+
+    #[test]
+    fn describe_guess_next_dev() {
+        assert_eq!(
+            version("v1.2.3-4-g1a2b3c4", VersionScheme::GuessNextDev),
+            "1.2.4.dev4+g1a2b3c4"
+        );
+        assert_eq!(
+            version("v1.2.3-0-g1a2b3c4-dirty", VersionScheme::GuessNextDev),
+            "1.2.4.dev0+g1a2b3c4.dirty"
+        );
+        assert_eq!(
+            version("v2.0rc1-3-g1a2b3c4", VersionScheme::GuessNextDev),
+            "2.0rc2.dev3+g1a2b3c4"
+        );
+        // Hyphens in the tag are preserved.
+        assert_eq!(
+            version("v1.0-beta-1-g1a2b3c4", VersionScheme::GuessNextDev),
+            "1.0b1.dev1+g1a2b3c4"
+        );
+    }
+
+    #[test]
+    fn describe_post_release() {
+        assert_eq!(
+            version("v1.2.3-4-g1a2b3c4", VersionScheme::PostRelease),
+            "1.2.3.post4+g1a2b3c4"
+        );
+        assert_eq!(
+            version("v1.2.3.post1-2-g1a2b3c4-dirty", VersionScheme::PostRelease),
+            "1.2.3.post3+g1a2b3c4.dirty"
+        );
+    }
+
+    #[test]
+    fn describe_untagged() {
+        let describe = Describe {
+            tag: None,
+            distance: 5,
+            hash: "1a2b3c4".to_string(),
+            dirty: false,
+        };
+        assert_eq!(
+            describe
+                .to_version(VersionScheme::GuessNextDev)
+                .unwrap()
+                .to_string(),
+            "0.1.dev5+g1a2b3c4"
+        );
+        assert_eq!(
+            describe
+                .to_version(VersionScheme::PostRelease)
+                .unwrap()
+                .to_string(),
+            "0.0.post5+g1a2b3c4"
+        );
+    }
+
+    #[test]
+    fn describe_invalid() {
+        assert_eq!(parse_describe("not-a-version-1-g1a2b3c4"), None);
+        assert_eq!(parse_describe("v1.2.3"), None);
+    }
+}
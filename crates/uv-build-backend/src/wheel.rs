@@ -17,10 +17,14 @@ use uv_globfilter::{GlobDirFilter, PortableGlobParser};
 use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
 use uv_warnings::warn_user_once;
 
+use crate::extension::{
+    BuiltExtension, TargetInterpreter, build_extensions, extension_artifact_excludes,
+    extension_source_excludes, list_extensions,
+};
 use crate::metadata::DEFAULT_EXCLUDES;
 use crate::{
-    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml,
-    error_on_venv, find_roots,
+    BuildBackendSettings, DirectoryWriter, Error, FileList, ListWriter, PyProjectToml, check_stubs,
+    error_on_venv, find_roots, source_date_epoch,
 };
 
 /// Build a wheel from the source tree and place it in the output directory.
@@ -30,32 +34,56 @@ pub fn build_wheel(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
     crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    write_wheel_file(source_tree, wheel_dir, &pyproject_toml, uv_version)
+}
+
+/// Build a wheel from a validated `pyproject.toml` and place it in the output directory.
+pub(crate) fn write_wheel_file(
+    source_tree: &Path,
+    wheel_dir: &Path,
+    pyproject_toml: &PyProjectToml,
+    uv_version: &str,
+) -> Result<WheelFilename, Error> {
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
+
+    let (filename, interpreter) = wheel_filename(pyproject_toml, &settings)?;
+
+    // Compile the extension modules, if any, into a temporary directory.
+    let build_dir = if interpreter.is_some() {
+        Some(tempfile::tempdir()?)
+    } else {
+        None
+    };
+    let extensions = if let (Some(interpreter), Some(build_dir)) = (&interpreter, &build_dir) {
+        build_extensions(
+            source_tree,
+            &settings.extensions,
+            interpreter,
+            &build_dir.path().join("build"),
+            &build_dir.path().join("lib"),
+        )?
+    } else {
+        Vec::new()
+    };
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?)?;
 
     write_wheel(
         source_tree,
-        &pyproject_toml,
+        pyproject_toml,
         &filename,
+        &extensions,
         uv_version,
         wheel_writer,
     )?;
@@ -63,31 +91,66 @@ pub fn build_wheel(
     Ok(filename)
 }
 
+/// Return the filename of the wheel.
+///
+/// If the project declares extension modules, the wheel is tagged for the target interpreter,
+/// which is returned alongside it.
+fn wheel_filename(
+    pyproject_toml: &PyProjectToml,
+    settings: &BuildBackendSettings,
+) -> Result<(WheelFilename, Option<TargetInterpreter>), Error> {
+    if settings.extensions.is_empty() {
+        let filename = WheelFilename::new(
+            pyproject_toml.name().clone(),
+            pyproject_toml.version().clone(),
+            LanguageTag::Python {
+                major: 3,
+                minor: None,
+            },
+            AbiTag::None,
+            PlatformTag::Any,
+        );
+        Ok((filename, None))
+    } else {
+        let interpreter = TargetInterpreter::query()?;
+        Ok((
+            interpreter.wheel_filename(pyproject_toml),
+            Some(interpreter),
+        ))
+    }
+}
+
 /// List the files that would be included in a source distribution and their origin.
 pub fn list_wheel(
     source_tree: &Path,
     uv_version: &str,
 ) -> Result<(WheelFilename, FileList), Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let (filename, interpreter) = wheel_filename(&pyproject_toml, &settings)?;
+    let extensions = if let Some(interpreter) = &interpreter {
+        list_extensions(&settings.extensions, interpreter)?
+    } else {
+        Vec::new()
+    };
 
     let mut files = FileList::new();
     let writer = ListWriter::new(&mut files);
-    write_wheel(source_tree, &pyproject_toml, &filename, uv_version, writer)?;
+    write_wheel(
+        source_tree,
+        &pyproject_toml,
+        &filename,
+        &extensions,
+        uv_version,
+        writer,
+    )?;
     Ok((filename, files))
 }
 
@@ -95,6 +158,7 @@ fn write_wheel(
     source_tree: &Path,
     pyproject_toml: &PyProjectToml,
     filename: &WheelFilename,
+    extensions: &[BuiltExtension],
     uv_version: &str,
     mut wheel_writer: impl DirectoryWriter,
 ) -> Result<(), Error> {
@@ -122,6 +186,13 @@ fn write_wheel(
             excludes.push(exclude.clone());
         }
     }
+    // Extension sources are compiled instead, and compiled extension modules from editable builds
+    // are replaced by the ones compiled for this wheel.
+    excludes.extend(extension_source_excludes(&settings.extensions)?);
+    excludes.extend(extension_artifact_excludes(
+        &settings.extensions,
+        &settings.module_root,
+    )?);
     debug!("Wheel excludes: {:?}", excludes);
     let exclude_matcher = build_exclude_matcher(excludes)?;
 
@@ -132,7 +203,9 @@ fn write_wheel(
         &settings.module_root,
         settings.module_name.as_ref(),
         settings.namespace,
+        settings.data_only,
     )?;
+    let partial_stubs_markers = check_stubs(&src_root, &module_relative, settings.partial_stubs)?;
 
     let mut files_visited = 0;
     let mut prefix_directories = FxHashSet::default();
@@ -190,6 +263,22 @@ fn write_wheel(
     }
     debug!("Visited {files_visited} files for wheel build");
 
+    // Mark partial stubs packages, if the marker isn't already part of the module
+    for marker in partial_stubs_markers {
+        let marker = marker.portable_display().to_string();
+        debug!("Adding partial stubs marker to wheel: {marker}");
+        wheel_writer.write_bytes(&marker, b"partial\n")?;
+    }
+
+    // Add the compiled extension modules
+    for extension in extensions {
+        debug!("Adding extension module to wheel: {}", extension.wheel_path);
+        match &extension.file {
+            Some(file) => wheel_writer.write_file(&extension.wheel_path, file)?,
+            None => wheel_writer.write_bytes(&extension.wheel_path, &[])?,
+        }
+    }
+
     // Add the license files
     if pyproject_toml.license_files_wheel().next().is_some() {
         debug!("Adding license files");
@@ -260,43 +349,58 @@ pub fn build_editable(
     metadata_directory: Option<&Path>,
     uv_version: &str,
 ) -> Result<WheelFilename, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
+    crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
+
+    write_editable_file(source_tree, wheel_dir, &pyproject_toml, uv_version)
+}
+
+/// Build an editable wheel from a validated `pyproject.toml` and place it in the output
+/// directory.
+pub(crate) fn write_editable_file(
+    source_tree: &Path,
+    wheel_dir: &Path,
+    pyproject_toml: &PyProjectToml,
+    uv_version: &str,
+) -> Result<WheelFilename, Error> {
     let settings = pyproject_toml
         .settings()
         .cloned()
         .unwrap_or_else(BuildBackendSettings::default);
 
-    crate::check_metadata_directory(source_tree, metadata_directory, &pyproject_toml)?;
-
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
-
-    let wheel_path = wheel_dir.join(filename.to_string());
-    debug!("Writing wheel at {}", wheel_path.user_display());
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let (filename, interpreter) = wheel_filename(pyproject_toml, &settings)?;
 
-    debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
     let (src_root, _module_relative) = find_roots(
         source_tree,
-        &pyproject_toml,
+        pyproject_toml,
         &settings.module_root,
         settings.module_name.as_ref(),
         settings.namespace,
+        settings.data_only,
     )?;
 
+    // Compile the extension modules in-place, so they are importable through the `.pth` file.
+    if let Some(interpreter) = &interpreter {
+        let build_dir = tempfile::tempdir()?;
+        build_extensions(
+            source_tree,
+            &settings.extensions,
+            interpreter,
+            build_dir.path(),
+            &src_root,
+        )?;
+    }
+
+    let wheel_path = wheel_dir.join(filename.to_string());
+    debug!("Writing wheel at {}", wheel_path.user_display());
+    let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?)?;
+
+    debug!("Adding pth file to {}", wheel_path.user_display());
+
     wheel_writer.write_bytes(
         &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
         src_root.as_os_str().as_encoded_bytes(),
@@ -305,7 +409,7 @@ pub fn build_editable(
     debug!("Adding metadata files to: {}", wheel_path.user_display());
     let dist_info_dir = write_dist_info(
         &mut wheel_writer,
-        &pyproject_toml,
+        pyproject_toml,
         &filename,
         source_tree,
         uv_version,
@@ -321,22 +425,16 @@ pub fn metadata(
     metadata_directory: &Path,
     uv_version: &str,
 ) -> Result<String, Error> {
-    let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
-    let pyproject_toml = PyProjectToml::parse(&contents)?;
+    let pyproject_toml = PyProjectToml::load(source_tree)?;
     for warning in pyproject_toml.check_build_system(uv_version) {
         warn_user_once!("{warning}");
     }
+    let settings = pyproject_toml
+        .settings()
+        .cloned()
+        .unwrap_or_else(BuildBackendSettings::default);
 
-    let filename = WheelFilename::new(
-        pyproject_toml.name().clone(),
-        pyproject_toml.version().clone(),
-        LanguageTag::Python {
-            major: 3,
-            minor: None,
-        },
-        AbiTag::None,
-        PlatformTag::Any,
-    );
+    let (filename, _interpreter) = wheel_filename(&pyproject_toml, &settings)?;
 
     debug!(
         "Writing metadata files to {}",
@@ -589,7 +687,15 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
     let mut wheel_info = vec![
         ("Wheel-Version", "1.0".to_string()),
         ("Generator", format!("uv {uv_version}")),
-        ("Root-Is-Purelib", "true".to_string()),
+        (
+            "Root-Is-Purelib",
+            // Wheels with extension modules are platform-specific.
+            filename
+                .platform_tags()
+                .iter()
+                .all(|platform_tag| *platform_tag == PlatformTag::Any)
+                .to_string(),
+        ),
     ];
     for python_tag in filename.python_tags() {
         for abi_tag in filename.abi_tags() {
@@ -604,59 +710,109 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
         .join("\n")
 }
 
+/// Entries of at least this size are written with zip64 extended information.
+///
+/// The sizes in a regular zip header are 32-bit. We leave some headroom below `u32::MAX`, since
+/// deflating incompressible data can produce an entry that is slightly larger than its input.
+const ZIP64_THRESHOLD: u64 = 0xF000_0000;
+
 /// Zip archive (wheel) writer.
+///
+/// The archive is written to a seekable file, so entries never need data descriptors. The zip64
+/// end of central directory record is added automatically once the archive exceeds 65,535 entries
+/// or 4 GiB.
 struct ZipDirectoryWriter {
     writer: ZipWriter<File>,
     compression: CompressionMethod,
+    /// The modification time of all entries, for reproducible builds.
+    last_modified: zip::DateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
 
 impl ZipDirectoryWriter {
     /// A wheel writer with deflate compression.
-    fn new_wheel(file: File) -> Self {
-        Self {
+    fn new_wheel(file: File) -> Result<Self, Error> {
+        Ok(Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Deflated,
+            last_modified: zip_modification_time()?,
             record: Vec::new(),
-        }
+        })
     }
 
     /// A wheel writer with no (stored) compression.
     ///
     /// Since editables are temporary, we save time be skipping compression and decompression.
     #[expect(dead_code)]
-    fn new_editable(file: File) -> Self {
-        Self {
+    fn new_editable(file: File) -> Result<Self, Error> {
+        Ok(Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Stored,
+            last_modified: zip_modification_time()?,
             record: Vec::new(),
-        }
+        })
     }
 
-    /// Add a file with the given name and return a writer for it.
+    /// The options for a file entry with the given permissions and (uncompressed) size.
+    fn file_options(&self, permissions: u32, size: u64) -> zip::write::SimpleFileOptions {
+        zip::write::SimpleFileOptions::default()
+            .unix_permissions(permissions)
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified)
+            .large_file(size >= ZIP64_THRESHOLD)
+    }
+
+    /// Add a file with the given name and size and return a writer for it.
     fn new_writer<'slf>(
         &'slf mut self,
         path: &str,
+        size: u64,
         executable_bit: bool,
     ) -> Result<Box<dyn Write + 'slf>, Error> {
         // Set file permissions: 644 (rw-r--r--) for regular files, 755 (rwxr-xr-x) for executables
         let permissions = if executable_bit { 0o755 } else { 0o644 };
-        let options = zip::write::SimpleFileOptions::default()
-            .unix_permissions(permissions)
-            .compression_method(self.compression);
+        let options = self.file_options(permissions, size);
         self.writer.start_file(path, options)?;
         Ok(Box::new(&mut self.writer))
     }
 }
 
+/// The modification time for all entries in a wheel.
+///
+/// Zip archives store local times without a timezone, starting in 1980. Without
+/// `SOURCE_DATE_EPOCH`, we use the earliest representable time, 1980-01-01 00:00:00, so that the
+/// wheel doesn't depend on when it was built. Earlier timestamps are clamped to the same time.
+fn zip_modification_time() -> Result<zip::DateTime, Error> {
+    let Some(epoch) = source_date_epoch()? else {
+        return Ok(zip::DateTime::default());
+    };
+    zip_date_time(epoch).ok_or_else(|| Error::InvalidSourceDateEpoch(epoch.to_string()))
+}
+
+/// Convert a Unix timestamp to a zip timestamp in UTC, clamping timestamps before 1980.
+fn zip_date_time(epoch: u64) -> Option<zip::DateTime> {
+    let timestamp = jiff::Timestamp::from_second(i64::try_from(epoch).ok()?).ok()?;
+    let date_time = timestamp.to_zoned(jiff::tz::TimeZone::UTC).datetime();
+    if date_time.year() < 1980 {
+        return Some(zip::DateTime::default());
+    }
+    zip::DateTime::from_date_and_time(
+        u16::try_from(date_time.year()).ok()?,
+        u8::try_from(date_time.month()).ok()?,
+        u8::try_from(date_time.day()).ok()?,
+        u8::try_from(date_time.hour()).ok()?,
+        u8::try_from(date_time.minute()).ok()?,
+        u8::try_from(date_time.second()).ok()?,
+    )
+    .ok()
+}
+
 impl DirectoryWriter for ZipDirectoryWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         trace!("Adding {}", path);
         // Set appropriate permissions for metadata files (644 = rw-r--r--)
-        let options = zip::write::SimpleFileOptions::default()
-            .unix_permissions(0o644)
-            .compression_method(self.compression);
+        let options = self.file_options(0o644, bytes.len() as u64);
         self.writer.start_file(path, options)?;
         self.writer.write_all(bytes)?;
 
@@ -673,16 +829,17 @@ impl DirectoryWriter for ZipDirectoryWriter {
     fn write_file(&mut self, path: &str, file: &Path) -> Result<(), Error> {
         trace!("Adding {} from {}", path, file.user_display());
         let mut reader = BufReader::new(File::open(file)?);
+        let metadata = file.metadata()?;
         // Preserve the executable bit, especially for scripts
         #[cfg(unix)]
         let executable_bit = {
             use std::os::unix::fs::PermissionsExt;
-            file.metadata()?.permissions().mode() & 0o111 != 0
+            metadata.permissions().mode() & 0o111 != 0
         };
         // Windows has no executable bit
         #[cfg(not(unix))]
         let executable_bit = false;
-        let mut writer = self.new_writer(path, executable_bit)?;
+        let mut writer = self.new_writer(path, metadata.len(), executable_bit)?;
         let record = write_hashed(path, &mut reader, &mut writer)?;
         drop(writer);
         self.record.push(record);
@@ -691,7 +848,8 @@ impl DirectoryWriter for ZipDirectoryWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         trace!("Adding directory {}", directory);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        // Directories are always executable, which means they can be listed.
+        let options = self.file_options(0o755, 0);
         Ok(self.writer.add_directory(directory, options)?)
     }
 
@@ -701,7 +859,7 @@ impl DirectoryWriter for ZipDirectoryWriter {
         trace!("Adding {record_path}");
         let record = mem::take(&mut self.record);
         write_record(
-            &mut self.new_writer(&record_path, false)?,
+            &mut self.new_writer(&record_path, 0, false)?,
             dist_info_dir,
             record,
         )?;
@@ -826,6 +984,57 @@ mod test {
         ");
     }
 
+    #[test]
+    fn zip_timestamps() {
+        // 2023-11-14T22:13:20Z
+        let date_time = zip_date_time(1_700_000_000).unwrap();
+        assert_eq!(
+            (
+                date_time.year(),
+                date_time.month(),
+                date_time.day(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second()
+            ),
+            (2023, 11, 14, 22, 13, 20)
+        );
+
+        // Timestamps before 1980 can't be represented in zip archives.
+        assert_eq!(zip_date_time(0), Some(zip::DateTime::default()));
+
+        // Neither can timestamps after 2107.
+        assert_eq!(zip_date_time(5_000_000_000), None);
+    }
+
+    /// Wheels with more than 65,535 entries require a zip64 end of central directory record.
+    #[test]
+    fn zip64_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let wheel_path = temp_dir.path().join("many_files-0.1.0-py3-none-any.whl");
+
+        let mut writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path).unwrap()).unwrap();
+        for index in 0..70_000 {
+            writer
+                .write_bytes(&format!("many_files/module_{index}.py"), b"")
+                .unwrap();
+        }
+        writer.close("many_files-0.1.0.dist-info").unwrap();
+
+        let archive = zip::ZipArchive::new(File::open(&wheel_path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 70_001);
+        assert!(
+            archive
+                .index_for_name("many_files/module_69999.py")
+                .is_some()
+        );
+        assert!(
+            archive
+                .index_for_name("many_files-0.1.0.dist-info/RECORD")
+                .is_some()
+        );
+    }
+
     /// Snapshot all files from the prepare metadata hook.
     #[test]
     fn test_prepare_metadata() {
@@ -38,6 +38,7 @@ def call(
     args: "Sequence[str]", config_settings: "Mapping[Any, Any] | None" = None
 ) -> str:
     """Invoke a uv subprocess and return the filename from stdout."""
+    import os
     import shutil
     import subprocess
     import sys
@@ -50,9 +51,11 @@ def call(
     if uv_bin is None:
         raise RuntimeError(f"{uv_bin_name} was not properly installed")
     build_backend_args = ["build-backend"] if USE_UV_EXECUTABLE else []
+    # Extension modules are compiled for the interpreter running the build
+    env = {**os.environ, "UV_BUILD_BACKEND_PYTHON": sys.executable}
     # Forward stderr, capture stdout for the filename
     result = subprocess.run(
-        [uv_bin, *build_backend_args, *args], stdout=subprocess.PIPE
+        [uv_bin, *build_backend_args, *args], stdout=subprocess.PIPE, env=env
     )
     if result.returncode != 0:
         sys.exit(result.returncode)
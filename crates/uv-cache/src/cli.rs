@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{Cache, CachePartition, RemoteCache, RemoteCacheUrl};
 use clap::Parser;
 use tracing::{debug, warn};
 
@@ -29,6 +29,38 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// Partition the cache by project or by index.
+    ///
+    /// With `project`, each project uses a separate cache, keyed by the path to the workspace root.
+    /// With `index`, each default index uses a separate cache, keyed by the URL of the default index
+    /// configured in `pyproject.toml` or `uv.toml` (or PyPI, if none is configured).
+    ///
+    /// Partitions are stored in the `partitions` directory of the cache directory, and can be
+    /// mounted as separate volumes. To view the location of the partition, run `uv cache dir`.
+    #[arg(global = true, long, env = EnvVars::UV_CACHE_PARTITION, value_enum, value_name = "MODE")]
+    pub cache_partition: Option<CachePartition>,
+
+    /// The URL of a remote cache to fetch wheels built from source distributions from, rather than
+    /// building them locally.
+    ///
+    /// Accepts `http://` and `https://` URLs, along with `s3://<bucket>/<prefix>` URLs, which
+    /// are resolved against the endpoint in `UV_S3_ENDPOINT_URL`.
+    ///
+    /// Wheels are keyed by the SHA-256 hash of the source distribution and its build settings, so
+    /// only source distributions with a known hash are read from the remote cache.
+    #[arg(global = true, long, env = EnvVars::UV_CACHE_REMOTE, value_name = "URL")]
+    pub cache_remote: Option<RemoteCacheUrl>,
+
+    /// Upload wheels built from source distributions to the remote cache.
+    #[arg(
+        global = true,
+        long,
+        env = EnvVars::UV_CACHE_REMOTE_WRITE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        requires = "cache_remote",
+    )]
+    pub cache_remote_write: bool,
 }
 
 impl Cache {
@@ -82,7 +114,10 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Self::from_settings(value.no_cache, value.cache_dir)
+        let remote = value
+            .cache_remote
+            .map(|url| RemoteCache::new(url.into_url(), value.cache_remote_write));
+        Ok(Self::from_settings(value.no_cache, value.cache_dir)?.with_remote(remote))
     }
 }
 
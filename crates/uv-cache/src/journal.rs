@@ -0,0 +1,273 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use tracing::{debug, warn};
+
+use crate::ArchiveId;
+
+/// A write-ahead journal for multi-file mutations to the cache.
+///
+/// Persisting an archive touches several files: the unpacked archive itself, the link to the
+/// archive from another cache bucket, and any pointers (e.g., HTTP cache metadata) that embed the
+/// archive ID. Before the archive is moved into place, a record of the mutation is flushed to the
+/// journal; the record is only removed once the archive's contents are durable and the link is
+/// in place. A record that outlives its process (e.g., due to a crash or power loss) thus
+/// identifies an archive that may be incomplete, and is rolled back by `uv cache verify`.
+#[derive(Debug, Clone)]
+pub(crate) struct Journal {
+    /// The directory containing the pending records.
+    root: PathBuf,
+}
+
+/// A pending mutation in the [`Journal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JournalRecord {
+    /// The ID of the archive being persisted.
+    pub(crate) id: ArchiveId,
+    /// The link to the archive, relative to the cache root.
+    pub(crate) link: PathBuf,
+}
+
+impl Journal {
+    /// Create a [`Journal`] for the cache at the given root.
+    pub(crate) fn new(cache: &Path) -> Self {
+        Self {
+            root: cache.join(".journal"),
+        }
+    }
+
+    /// Durably record the start of a mutation that persists the archive `id` and links it from
+    /// `link` (relative to the cache root).
+    pub(crate) fn begin(&self, id: &ArchiveId, link: &Path) -> io::Result<()> {
+        fs_err::create_dir_all(&self.root)?;
+        let path = self.root.join(id);
+        let mut file = fs_err::File::create(&path)?;
+        io::Write::write_all(&mut file, link.to_string_lossy().as_bytes())?;
+        file.sync_all()?;
+        sync_dir(&self.root)
+    }
+
+    /// Mark the mutation that persisted the archive `id` as complete.
+    pub(crate) fn commit(&self, id: &ArchiveId) -> io::Result<()> {
+        match fs_err::remove_file(self.root.join(id)) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the mutations that were started, but never completed.
+    pub(crate) fn pending(&self) -> io::Result<Vec<JournalRecord>> {
+        let entries = match fs_err::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut records = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let Some(id) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| ArchiveId::from_str(name).ok())
+            else {
+                warn!(
+                    "Ignoring unexpected entry in the cache journal: {}",
+                    entry.path().display()
+                );
+                continue;
+            };
+            // A record may be truncated if the process was interrupted while writing it; in that
+            // case, the archive has not been moved into place yet, but we still roll it back.
+            let link = fs_err::read_to_string(entry.path()).unwrap_or_default();
+            records.push(JournalRecord {
+                id,
+                link: PathBuf::from(link),
+            });
+        }
+        records.sort_by(|a, b| a.id.to_string().cmp(&b.id.to_string()));
+        Ok(records)
+    }
+}
+
+/// Flush the contents of every file in the given directory to disk.
+pub(crate) fn sync_tree(path: &Path) -> io::Result<()> {
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            sync_file(entry.path())?;
+        } else if entry.file_type().is_dir() {
+            sync_dir(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Flush the contents of a file to disk.
+fn sync_file(path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        fs_err::File::open(path)?.sync_all()
+    }
+
+    // On Windows, flushing a file requires write access; read-only files are skipped.
+    #[cfg(windows)]
+    {
+        match fs_err::OpenOptions::new().write(true).open(path) {
+            Ok(file) => file.sync_all(),
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                debug!("Skipping flush of read-only file: {}", path.display());
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Flush a directory's entries to disk, such that renames into the directory are durable.
+///
+/// On Windows, directories can't be opened for flushing, so this is a no-op.
+pub(crate) fn sync_dir(path: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        match fs_err::File::open(path).and_then(|dir| dir.sync_all()) {
+            Ok(()) => Ok(()),
+            // Some filesystems don't support flushing directories.
+            Err(err) if err.kind() == io::ErrorKind::InvalidInput => {
+                debug!("Failed to flush directory: {}", path.display());
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let _ = path;
+        Ok(())
+    }
+}
+
+/// Returns `true` if the unpacked wheel at `archive` is complete, i.e., every file listed in its
+/// `RECORD` exists with the recorded size.
+///
+/// Archives that aren't unpacked wheels (like cached environments) are always considered complete.
+pub(crate) fn is_complete(archive: &Path) -> io::Result<bool> {
+    let Ok(entries) = fs_err::read_dir(archive) else {
+        return Ok(false);
+    };
+    let mut record = None;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "dist-info") {
+            record = Some(path.join("RECORD"));
+            break;
+        }
+    }
+    let Some(record) = record else {
+        return Ok(true);
+    };
+
+    let contents = match fs_err::read_to_string(&record) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) if err.kind() == io::ErrorKind::InvalidData => return Ok(false),
+        Err(err) => return Err(err),
+    };
+
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // Split from the right, since the path may itself contain commas.
+        let mut fields = line.rsplitn(3, ',');
+        let size = fields.next().unwrap_or_default();
+        let _hash = fields.next();
+        let Some(path) = fields.next() else {
+            return Ok(false);
+        };
+        let path = path
+            .strip_prefix('"')
+            .and_then(|path| path.strip_suffix('"'))
+            .map(|path| path.replace("\"\"", "\""))
+            .unwrap_or_else(|| path.to_string());
+
+        // Ignore any entries that would escape the archive.
+        let path = Path::new(&path);
+        if path.is_absolute()
+            || path
+                .components()
+                .any(|component| matches!(component, std::path::Component::ParentDir))
+        {
+            continue;
+        }
+
+        let metadata = match fs_err::symlink_metadata(archive.join(path)) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(err),
+        };
+        if let Ok(size) = u64::from_str(size.trim()) {
+            if metadata.len() != size {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn journal_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(temp_dir.path());
+        assert!(journal.pending().unwrap().is_empty());
+
+        let id = ArchiveId::new();
+        let link = Path::new("wheels-v5").join("pypi").join("iniconfig");
+        journal.begin(&id, &link).unwrap();
+        assert_eq!(
+            journal.pending().unwrap(),
+            vec![JournalRecord {
+                id: id.clone(),
+                link
+            }]
+        );
+
+        journal.commit(&id).unwrap();
+        assert!(journal.pending().unwrap().is_empty());
+    }
+
+    #[test]
+    fn record_completeness() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let archive = temp_dir.path();
+        fs_err::create_dir_all(archive.join("foo-1.0.dist-info")).unwrap();
+        fs_err::write(archive.join("foo.py"), "x = 1\n").unwrap();
+        fs_err::write(
+            archive.join("foo-1.0.dist-info").join("RECORD"),
+            "foo.py,sha256=abc,6\n\"foo-1.0.dist-info/RECORD\",,\n",
+        )
+        .unwrap();
+        assert!(is_complete(archive).unwrap());
+
+        // A truncated file is incomplete.
+        fs_err::write(archive.join("foo.py"), "x =").unwrap();
+        assert!(!is_complete(archive).unwrap());
+
+        // A missing file is incomplete.
+        fs_err::remove_file(archive.join("foo.py")).unwrap();
+        assert!(!is_complete(archive).unwrap());
+
+        // Archives without a `RECORD` (e.g., environments) are always complete.
+        let environment = tempfile::tempdir().unwrap();
+        fs_err::create_dir_all(environment.path().join("bin")).unwrap();
+        assert!(is_complete(environment.path()).unwrap());
+    }
+}
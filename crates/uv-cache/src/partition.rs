@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+use uv_cache_key::{CanonicalUrl, cache_digest};
+use uv_distribution_types::IndexUrl;
+
+/// The strategy to use when partitioning the cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CachePartition {
+    /// Share a single cache across all projects and indexes.
+    #[default]
+    None,
+    /// Use a separate cache for each project, keyed by the path to the workspace root.
+    Project,
+    /// Use a separate cache for each default index, keyed by the index URL.
+    Index,
+}
+
+impl Display for CachePartition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Project => f.write_str("project"),
+            Self::Index => f.write_str("index"),
+        }
+    }
+}
+
+/// The key that identifies a partition of the cache.
+#[derive(Debug, Clone, Copy)]
+pub enum PartitionKey<'a> {
+    /// A project, identified by the path to its workspace root.
+    Project(&'a Path),
+    /// A package index, identified by its URL.
+    Index(&'a IndexUrl),
+}
+
+impl PartitionKey<'_> {
+    /// The name of the directory, relative to the `partitions` directory in the cache root, in
+    /// which the partition is stored.
+    pub fn directory(&self) -> String {
+        match self {
+            Self::Project(root) => format!("project-{}", cache_digest(root)),
+            Self::Index(IndexUrl::Pypi(_)) => "index-pypi".to_string(),
+            Self::Index(url) => format!("index-{}", cache_digest(&CanonicalUrl::new(url.url()))),
+        }
+    }
+}
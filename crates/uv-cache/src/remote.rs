@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
+
+/// A remote cache, shared across machines, from which wheels built from source distributions are
+/// fetched before building them locally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCache {
+    /// The base URL of the remote cache.
+    url: DisplaySafeUrl,
+    /// Whether to upload wheels built locally to the remote cache.
+    write: bool,
+}
+
+impl RemoteCache {
+    /// Create a [`RemoteCache`] rooted at the given URL.
+    pub fn new(url: DisplaySafeUrl, write: bool) -> Self {
+        Self { url, write }
+    }
+
+    /// Return the base URL of the remote cache.
+    pub fn url(&self) -> &DisplaySafeUrl {
+        &self.url
+    }
+
+    /// Returns `true` if wheels built locally should be uploaded to the remote cache.
+    pub fn write(&self) -> bool {
+        self.write
+    }
+
+    /// Return the URL of the object at the given path, relative to the root of the remote cache.
+    pub fn object(&self, path: &str) -> DisplaySafeUrl {
+        let mut url = self.url.clone();
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty();
+            segments.extend(path.split('/'));
+        }
+        url
+    }
+}
+
+/// The location of a [`RemoteCache`], as provided by the user.
+///
+/// Accepts `http://` and `https://` URLs, along with `s3://<bucket>/<prefix>` URLs, which are
+/// resolved against the `UV_S3_ENDPOINT_URL` endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteCacheUrl(DisplaySafeUrl);
+
+impl RemoteCacheUrl {
+    /// Return the resolved URL.
+    pub fn into_url(self) -> DisplaySafeUrl {
+        self.0
+    }
+}
+
+impl FromStr for RemoteCacheUrl {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = DisplaySafeUrl::parse(s).map_err(|err| err.to_string())?;
+        match url.scheme() {
+            "http" | "https" => Ok(Self(url)),
+            "s3" => {
+                let Some(bucket) = url.host_str() else {
+                    return Err(format!("Expected a bucket name in `{url}`"));
+                };
+                let endpoint = std::env::var(EnvVars::UV_S3_ENDPOINT_URL).map_err(|_| {
+                    format!(
+                        "`{url}` requires `{}` to be set to the S3 endpoint",
+                        EnvVars::UV_S3_ENDPOINT_URL
+                    )
+                })?;
+                let mut resolved =
+                    DisplaySafeUrl::parse(&endpoint).map_err(|err| err.to_string())?;
+                if let Ok(mut segments) = resolved.path_segments_mut() {
+                    segments.pop_if_empty();
+                    segments.push(bucket);
+                    segments.extend(url.path().split('/').filter(|segment| !segment.is_empty()));
+                }
+                Ok(Self(resolved))
+            }
+            scheme => Err(format!(
+                "Unsupported scheme `{scheme}` for a remote cache; expected `http`, `https`, or `s3`"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object() {
+        let remote = RemoteCache::new(
+            DisplaySafeUrl::parse("https://cache.example.com/uv").unwrap(),
+            false,
+        );
+        assert_eq!(
+            remote.object("built-wheels/foo/abc/default/index").as_str(),
+            "https://cache.example.com/uv/built-wheels/foo/abc/default/index"
+        );
+
+        let remote = RemoteCache::new(
+            DisplaySafeUrl::parse("https://cache.example.com/uv/").unwrap(),
+            false,
+        );
+        assert_eq!(
+            remote.object("built-wheels/foo/abc/default/index").as_str(),
+            "https://cache.example.com/uv/built-wheels/foo/abc/default/index"
+        );
+    }
+
+    #[test]
+    fn unsupported_scheme() {
+        assert!(RemoteCacheUrl::from_str("ftp://cache.example.com").is_err());
+        assert!(RemoteCacheUrl::from_str("https://cache.example.com").is_ok());
+    }
+}
@@ -1,5 +1,7 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::net::IpAddr;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -14,8 +16,9 @@ use clap::{Args, Parser, Subcommand};
 use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    DownloadRate, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    PipCompileFormat, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    UpgradeTarget, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
@@ -25,11 +28,11 @@ use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
 use uv_preview::PreviewFeatures;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{ImplementationName, PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerTimestamp, ForkStrategy, PrereleaseMode,
-    ResolutionMode,
+    ResolutionMode, UpgradePolicy, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -58,6 +61,24 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonFindFormat {
+    /// Display the path to the interpreter (for humans).
+    #[default]
+    Text,
+    /// Display the interpreter's path, version, and platform as JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheInfoFormat {
+    /// Display the cache usage in a human-readable format.
+    #[default]
+    Text,
+    /// Display the cache usage in JSON format.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -67,6 +88,25 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum FreezeFormat {
+    /// Display the list of packages in a `requirements.txt`-compatible format.
+    #[default]
+    Text,
+    /// Display the list of packages in a machine-readable JSON format, including the source and
+    /// installer of each package.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -240,6 +280,34 @@ pub struct GlobalArgs {
     )]
     pub color: Option<ColorChoice>,
 
+    /// The format to use for log messages.
+    ///
+    /// With `json`, each log message is written to stderr as a JSON object, including the spans it
+    /// was emitted in. In addition, an event is written when a span closes, recording the time
+    /// spent in it. Logging is enabled separately, e.g., with `--verbose` or `RUST_LOG`.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = EnvVars::UV_LOG_FORMAT,
+        value_name = "LOG_FORMAT"
+    )]
+    pub log_format: Option<LogFormat>,
+
+    /// The format to use for error messages.
+    ///
+    /// With `json`, resolution failures are written to stderr as a JSON object, including the
+    /// chain of sources (e.g., a `pyproject.toml` file, a dependency group, a constraints file, or
+    /// another package) that introduced each of the conflicting requirements.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = EnvVars::UV_ERROR_FORMAT,
+        value_name = "ERROR_FORMAT"
+    )]
+    pub error_format: Option<ErrorFormat>,
+
     /// Whether to load TLS certificates from the platform's native certificate store.
     ///
     /// By default, uv loads certificates from the bundled `webpki-roots` crate. The
@@ -264,6 +332,13 @@ pub struct GlobalArgs {
     #[arg(global = true, long, overrides_with("offline"), hide = true)]
     pub no_offline: bool,
 
+    /// Limit the rate at which uv downloads data, in bytes per second.
+    ///
+    /// Accepts a number of bytes with an optional unit, e.g., `500K`, `10M` (megabytes), or
+    /// `10Mi` (mebibytes). The limit is shared across all concurrent downloads.
+    #[arg(global = true, long, env = EnvVars::UV_MAX_DOWNLOAD_RATE, value_name = "RATE")]
+    pub max_download_rate: Option<DownloadRate>,
+
     /// Allow insecure connections to a host.
     ///
     /// Can be provided multiple times.
@@ -333,10 +408,31 @@ pub struct GlobalArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_PROGRESS, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// The format to use for progress output.
+    ///
+    /// With `json`, progress bars and spinners are replaced by newline-delimited JSON events on
+    /// stderr (e.g., when a download or build starts or completes, or as packages are prepared
+    /// and installed), for consumption by other tools. Ignored with `--no-progress` or `--quiet`.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        env = EnvVars::UV_PROGRESS,
+        value_name = "PROGRESS"
+    )]
+    pub progress: Option<ProgressFormat>,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
 
+    /// The maximum time to wait for another uv process to release the environment, in seconds.
+    ///
+    /// Concurrent uv processes that modify the same environment are serialized via an advisory
+    /// lock on the environment. By default, uv waits indefinitely for the lock to be released.
+    #[arg(global = true, long, env = EnvVars::UV_WAIT_TIMEOUT, value_name = "SECONDS")]
+    pub wait_timeout: Option<u64>,
+
     /// Change to the given directory prior to running the command.
     ///
     /// Relative paths are resolved with the given directory as the base.
@@ -373,6 +469,36 @@ pub enum ColorChoice {
     Never,
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable log messages.
+    #[default]
+    Text,
+
+    /// Newline-delimited JSON log messages.
+    Json,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// Human-readable error messages.
+    #[default]
+    Text,
+
+    /// JSON error messages.
+    Json,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProgressFormat {
+    /// Interactive progress bars and spinners.
+    #[default]
+    Text,
+
+    /// Newline-delimited JSON progress events.
+    Json,
+}
+
 impl ColorChoice {
     /// Combine self (higher priority) with an [`anstream::ColorChoice`] (lower priority).
     ///
@@ -527,6 +653,33 @@ pub enum Commands {
         hide = true
     )]
     Workspace(WorkspaceNamespace),
+    /// Manage the package indexes used by uv.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Index(IndexNamespace),
+    /// Manage the snapshots of a Python environment.
+    #[command(
+        after_help = "Use `uv help env` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Env(EnvNamespace),
+    /// Pack a Python environment into a bundle, or install one.
+    #[command(
+        after_help = "Use `uv help bundle` for more details.",
+        after_long_help = "",
+        hide = true
+    )]
+    Bundle(BundleNamespace),
+    /// Manage the inline metadata of Python scripts.
+    #[command(
+        after_help = "Use `uv help script` for more details.",
+        after_long_help = ""
+    )]
+    Script(ScriptNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -832,6 +985,8 @@ pub struct SelfNamespace {
 pub enum SelfCommand {
     /// Update uv.
     Update(SelfUpdateArgs),
+    /// Restore the version of uv that was replaced by the most recent `uv self update`.
+    Rollback,
     /// Display uv's version
     Version {
         /// Only print the version
@@ -844,9 +999,24 @@ pub enum SelfCommand {
 
 #[derive(Args, Debug)]
 pub struct SelfUpdateArgs {
-    /// Update to the specified version. If not provided, uv will update to the latest version.
+    /// Update to the specified version. If not provided, uv will update to the latest version on
+    /// the selected channel.
     pub target_version: Option<String>,
 
+    /// The release channel to update from.
+    ///
+    /// The `stable` channel only considers stable releases, while the `preview` channel also
+    /// considers pre-releases. To pin a specific version instead, provide it as the target version.
+    ///
+    /// Defaults to `stable`.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_SELF_UPDATE_CHANNEL,
+        conflicts_with = "target_version"
+    )]
+    pub channel: Option<SelfUpdateChannel>,
+
     /// A GitHub token for authentication.
     /// A token is not required but can be used to reduce the chance of encountering rate limits.
     #[arg(long, env = EnvVars::UV_GITHUB_TOKEN)]
@@ -857,6 +1027,14 @@ pub struct SelfUpdateArgs {
     pub dry_run: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelfUpdateChannel {
+    /// Update to the latest stable release.
+    Stable,
+    /// Update to the latest release, including pre-releases.
+    Preview,
+}
+
 #[derive(Args)]
 pub struct CacheNamespace {
     #[command(subcommand)]
@@ -889,6 +1067,18 @@ pub enum CacheCommand {
     /// wheels, source distributions, and other cached data. By default, outputs the size in raw
     /// bytes; use `--human` for human-readable output.
     Size(SizeArgs),
+    /// Show a breakdown of the cache's disk usage.
+    ///
+    /// Displays the size of each cache bucket, along with the space used by each package index and
+    /// by each package. Sizes are displayed in human-readable format; use `--output-format json` to
+    /// display sizes in raw bytes.
+    Info(InfoArgs),
+    /// Scan the cache for incomplete writes and repair them.
+    ///
+    /// Rolls back any writes that were interrupted (e.g., by a crash or power loss), removes any
+    /// unpacked wheels that are missing files, and removes any links to entries that no longer
+    /// exist. Affected entries are re-downloaded or rebuilt on next use.
+    Verify(VerifyArgs),
 }
 
 #[derive(Args, Debug)]
@@ -922,6 +1112,26 @@ pub struct PruneArgs {
     #[arg(long)]
     pub ci: bool,
 
+    /// Only remove cached `uv tool run` environments that have expired.
+    ///
+    /// Environments created by `uv tool run` (and `uvx`) are reused for identical invocations
+    /// within the time-to-live set by `UV_TOOL_RUN_CACHE_TTL`. With `--tools`, uv removes any such
+    /// environments that have outlived the time-to-live (or all of them, if no time-to-live is
+    /// set), leaving the rest of the cache untouched.
+    #[arg(long, conflicts_with = "ci")]
+    pub tools: bool,
+
+    /// Remove any cached wheels that aren't installed in a known environment.
+    ///
+    /// uv scans the virtual environment of the current project and the environments of any
+    /// installed tools, and removes any unzipped wheels (whether downloaded or built from source)
+    /// from the cache that don't match a package installed in one of them.
+    ///
+    /// Unlike time-based pruning, wheels that are still in use are always retained, such that
+    /// they can continue to be linked into environments without being downloaded or built again.
+    #[arg(long, conflicts_with = "tools")]
+    pub referenced_only: bool,
+
     /// Force removal of the cache, ignoring in-use checks.
     ///
     /// By default, `uv cache prune` will block until no process is reading the cache. When
@@ -930,6 +1140,16 @@ pub struct PruneArgs {
     pub force: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Force repair of the cache, ignoring in-use checks.
+    ///
+    /// By default, `uv cache verify` will block until no process is reading the cache. When
+    /// `--force` is used, `uv cache verify` will proceed without taking a lock.
+    #[arg(long)]
+    pub force: bool,
+}
+
 #[derive(Args, Debug)]
 pub struct SizeArgs {
     /// Display the cache size in human-readable format (e.g., `1.2 GiB` instead of raw bytes).
@@ -937,6 +1157,13 @@ pub struct SizeArgs {
     pub human: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct InfoArgs {
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheInfoFormat::default())]
+    pub output_format: CacheInfoFormat,
+}
+
 #[derive(Args)]
 pub struct PipNamespace {
     #[command(subcommand)]
@@ -1040,6 +1267,25 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Run(RunArgs),
+    /// Spawn a shell with the project's environment activated.
+    ///
+    /// The shell is detected from the environment, e.g., Bash, Zsh, Fish, Nushell, or PowerShell.
+    /// Where supported, the environment's activation script is sourced when the shell starts, such
+    /// that the prompt reflects the active environment. Exit the shell to deactivate the
+    /// environment.
+    ///
+    /// Use `--command` to run a single command with the environment activated, rather than
+    /// starting an interactive shell.
+    ///
+    /// The project environment is not created or updated. Use `uv sync` to create it.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help shell` for more details.",
+        after_long_help = ""
+    )]
+    Shell(ShellArgs),
     /// Create a new project.
     ///
     /// Follows the `pyproject.toml` specification.
@@ -1158,6 +1404,23 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Verify the integrity of the project's environment.
+    ///
+    /// Each installed file is hashed and compared against the `RECORD` of the package that
+    /// installed it, to detect modified or missing files. Files within the packages' directories
+    /// that aren't listed in any `RECORD` are reported as extraneous. If a lockfile is present,
+    /// installed packages that are not in the lockfile, or that differ from the locked version, are
+    /// reported as well.
+    ///
+    /// The lockfile and project environment are not updated.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help verify` for more details.",
+        after_long_help = ""
+    )]
+    Verify(VerifyArgs),
     /// Format Python code in the project.
     ///
     /// Formats Python code using the Ruff formatter. By default, all Python files in the project
@@ -1173,6 +1436,43 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Format(FormatArgs),
+    /// Run the project's tests.
+    ///
+    /// Ensures that the project environment is up-to-date, including the `test` dependency group
+    /// (if defined), then runs the configured test command in the project environment.
+    ///
+    /// The test command is read from `tool.uv.test.command` in the `pyproject.toml`, and defaults
+    /// to `pytest`.
+    ///
+    /// Arguments following uv's options are passed to the test command, e.g., `uv test -x` runs
+    /// `pytest -x`. A `--` can be used to separate the arguments from uv options for clarity,
+    /// e.g., `uv test --locked -- -k test_foo`.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help test` for more details.",
+        after_long_help = ""
+    )]
+    Test(TestArgs),
+    /// Run a JSON-RPC server for editor integrations.
+    ///
+    /// The server listens on a local socket (or a named pipe, on Windows) and answers JSON-RPC 2.0
+    /// requests to resolve the project, explain resolution conflicts, list outdated packages, and
+    /// query package metadata, such that editor integrations can avoid starting a new uv process
+    /// for each query.
+    ///
+    /// Messages are framed with a `Content-Length` header, as in the Language Server Protocol.
+    ///
+    /// The lockfile and project environment are not updated.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help serve` for more details.",
+        after_long_help = ""
+    )]
+    Serve(ServeArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -1596,6 +1896,35 @@ pub struct PipCompileArgs {
     #[arg(long, help_heading = "Python options")]
     pub python_version: Option<PythonVersion>,
 
+    /// The Python versions to resolve for, as a comma-separated list.
+    ///
+    /// For example, `3.9,3.10,3.11`.
+    ///
+    /// Performs a single universal resolution limited to the given Python versions, rather than
+    /// resolving separately for each version. By default, the output is a single file, with
+    /// environment markers to distinguish between the versions. Use `--split-python-versions` to
+    /// write a separate output file for each version instead.
+    ///
+    /// Implies `--universal`.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        num_args = 1..,
+        conflicts_with = "python_version",
+        conflicts_with = "python_platform",
+        conflicts_with = "strip_markers",
+        help_heading = "Python options"
+    )]
+    pub python_versions: Option<Vec<PythonVersion>>,
+
+    /// Write a separate output file for each of the `--python-versions`.
+    ///
+    /// Each file is named after the `--output-file`, with the Python version appended to the
+    /// file stem. For example, `-o requirements.txt --python-versions 3.9,3.10` writes
+    /// `requirements-py3.9.txt` and `requirements-py3.10.txt`.
+    #[arg(long, requires = "python_versions", help_heading = "Python options")]
+    pub split_python_versions: bool,
+
     /// The platform for which requirements should be resolved.
     ///
     /// Represented as a "target triple", a string that describes the target platform in terms of
@@ -1635,6 +1964,10 @@ pub struct PipCompileArgs {
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
+    ///
+    /// Omitted packages are listed as comments at the end of the output, along with their pinned
+    /// versions (e.g., `# setuptools==69.0.0`). As with other pins in an existing output file,
+    /// these versions are preferred when re-compiling, unless the package is upgraded.
     #[arg(long, alias = "unsafe-package")]
     pub no_emit_package: Option<Vec<PackageName>>,
 
@@ -1863,6 +2196,10 @@ pub struct PipSyncArgs {
     /// environment and only searches for a Python interpreter to use for package resolution.
     /// If a suitable Python interpreter cannot be found, uv will install one.
     /// To disable this, add `--no-python-downloads`.
+    ///
+    /// If both `--python-version` and `--python-platform` are provided, uv will not install a
+    /// Python interpreter, and can install wheels without any interpreter at all (see
+    /// `--implementation`).
     #[arg(long, conflicts_with = "prefix")]
     pub target: Option<PathBuf>,
 
@@ -1878,6 +2215,10 @@ pub struct PipSyncArgs {
     /// environment and only searches for a Python interpreter to use for package resolution.
     /// If a suitable Python interpreter cannot be found, uv will install one.
     /// To disable this, add `--no-python-downloads`.
+    ///
+    /// If both `--python-version` and `--python-platform` are provided, uv will not install a
+    /// Python interpreter, and can install wheels without any interpreter at all (see
+    /// `--implementation`).
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
@@ -1964,6 +2305,18 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The Python implementation for which requirements should be installed (e.g., `cpython` or
+    /// `pypy`).
+    ///
+    /// Requires `--python-version` and `--python-platform`. Defaults to `cpython`.
+    ///
+    /// When installing into a `--target` or `--prefix` directory, uv will look for a local Python
+    /// interpreter matching the requested implementation and version. If none is found, uv will
+    /// install the requirements without a Python interpreter, in which case source distributions
+    /// will not be built and bytecode will not be compiled.
+    #[arg(long, requires_all = ["python_version", "python_platform"])]
+    pub implementation: Option<ImplementationName>,
+
     /// Validate the Python environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1977,6 +2330,17 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// With `--dry-run`, plan the installation against a hypothetical environment containing the
+    /// packages listed in the given file, rather than the packages installed in the target
+    /// environment.
+    ///
+    /// The file should contain a JSON list of objects with `name` and `version` keys, as produced
+    /// by `uv pip list --format json` (or `pip list --format json`) in the hypothetical
+    /// environment. The listed packages are treated as installed from a registry, without any
+    /// dependencies.
+    #[arg(long, requires = "dry_run")]
+    pub assume_installed: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2202,6 +2566,10 @@ pub struct PipInstallArgs {
     /// environment and only searches for a Python interpreter to use for package resolution.
     /// If a suitable Python interpreter cannot be found, uv will install one.
     /// To disable this, add `--no-python-downloads`.
+    ///
+    /// If both `--python-version` and `--python-platform` are provided, uv will not install a
+    /// Python interpreter, and can install wheels without any interpreter at all (see
+    /// `--implementation`).
     #[arg(long, conflicts_with = "prefix")]
     pub target: Option<PathBuf>,
 
@@ -2217,6 +2585,10 @@ pub struct PipInstallArgs {
     /// environment and only searches for a Python interpreter to use for package resolution.
     /// If a suitable Python interpreter cannot be found, uv will install one.
     /// To disable this, add `--no-python-downloads`.
+    ///
+    /// If both `--python-version` and `--python-platform` are provided, uv will not install a
+    /// Python interpreter, and can install wheels without any interpreter at all (see
+    /// `--implementation`).
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
@@ -2296,6 +2668,18 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The Python implementation for which requirements should be installed (e.g., `cpython` or
+    /// `pypy`).
+    ///
+    /// Requires `--python-version` and `--python-platform`. Defaults to `cpython`.
+    ///
+    /// When installing into a `--target` or `--prefix` directory, uv will look for a local Python
+    /// interpreter matching the requested implementation and version. If none is found, uv will
+    /// install the requirements without a Python interpreter, in which case source distributions
+    /// will not be built and bytecode will not be compiled.
+    #[arg(long, requires_all = ["python_version", "python_platform"])]
+    pub implementation: Option<ImplementationName>,
+
     /// Do not remove extraneous packages present in the environment.
     #[arg(long, overrides_with("exact"), alias = "no-exact", hide = true)]
     pub inexact: bool,
@@ -2321,6 +2705,17 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// With `--dry-run`, plan the installation against a hypothetical environment containing the
+    /// packages listed in the given file, rather than the packages installed in the target
+    /// environment.
+    ///
+    /// The file should contain a JSON list of objects with `name` and `version` keys, as produced
+    /// by `uv pip list --format json` (or `pip list --format json`) in the hypothetical
+    /// environment. The listed packages are treated as installed from a registry, without any
+    /// dependencies.
+    #[arg(long, requires = "dry_run")]
+    pub assume_installed: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2373,8 +2768,9 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2438,6 +2834,10 @@ pub struct PipFreezeArgs {
     #[arg(long)]
     pub exclude_editable: bool,
 
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = FreezeFormat::default())]
+    pub format: FreezeFormat,
+
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -2463,6 +2863,9 @@ pub struct PipFreezeArgs {
     pub python: Option<Maybe<String>>,
 
     /// Restrict to the specified installation path for listing packages (can be used multiple times).
+    ///
+    /// When multiple paths are provided, the packages are merged, and a warning is shown for any
+    /// package that is installed with different versions across the paths.
     #[arg(long("path"), value_parser = parse_file_path)]
     pub paths: Option<Vec<PathBuf>>,
 
@@ -2805,6 +3208,24 @@ pub struct BuildArgs {
     #[arg(long)]
     pub clear: bool,
 
+    /// Build each distribution twice and check that both builds are byte-identical.
+    ///
+    /// The second build is performed in a temporary directory and the SHA-256 digests of the
+    /// artifacts are compared. If any artifact differs between the builds, the build fails.
+    ///
+    /// Use `SOURCE_DATE_EPOCH` to set the timestamps used by build backends that support it.
+    #[arg(long, conflicts_with = "list")]
+    pub reproducible_check: bool,
+
+    /// Check that the tags of the built wheels are justified by the binaries they contain.
+    ///
+    /// Inspects the shared libraries and executables in each wheel (ELF, Mach-O, and PE) and
+    /// fails if a platform or ABI tag is broader than the binaries support, e.g., a
+    /// `manylinux_2_17` wheel with a binary that requires a newer glibc, a `universal2` wheel
+    /// missing an architecture, or a platform-independent wheel containing binaries.
+    #[arg(long, conflicts_with = "list")]
+    pub check_tags: bool,
+
     #[arg(long, overrides_with("no_create_gitignore"), hide = true)]
     pub create_gitignore: bool,
 
@@ -2943,6 +3364,21 @@ pub struct VenvArgs {
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_VENV_SEED)]
     pub seed: bool,
 
+    /// Seed the virtual environment with the packages installed in an existing environment.
+    ///
+    /// Rather than copying, each file in the existing environment's `site-packages` is linked
+    /// into the new virtual environment, such that the environment can be created without
+    /// downloading or installing any packages. Subsequent installs, e.g., with `uv sync` or
+    /// `uv pip install`, are layered on top of the linked packages; replacing a linked package
+    /// removes the links without modifying the existing environment.
+    ///
+    /// The existing environment must use the same Python version and implementation as the new
+    /// virtual environment. Entrypoints and scripts are not linked.
+    ///
+    /// On Windows, files are copied rather than linked.
+    #[arg(long)]
+    pub seed_from: Option<PathBuf>,
+
     /// Remove any existing files or directories at the target path.
     ///
     /// By default, `uv venv` will exit with an error if the given path is non-empty. The
@@ -3032,8 +3468,9 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -3059,8 +3496,8 @@ pub struct VenvArgs {
     ///
     /// This option is only used for installing seed packages.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
@@ -3201,6 +3638,23 @@ pub struct InitArgs {
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Add a shebang to the script, such that it can be executed directly.
+    ///
+    /// The shebang invokes `uv run --script`, which respects the script's inline metadata. On
+    /// Unix, the script is also marked as executable. If the script exists and has a shebang that
+    /// doesn't invoke uv, the shebang is replaced.
+    #[arg(long, requires = "script")]
+    pub shebang: bool,
+
+    /// Include the given requirements as dependencies of the script.
+    ///
+    /// The requirements are added to the script's inline metadata as provided, without resolving
+    /// them. To add dependencies with version bounds, use `uv add --script` instead.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "script")]
+    pub with: Vec<Requirement<VerbatimParsedUrl>>,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description")]
     pub description: Option<String>,
@@ -3400,6 +3854,10 @@ pub struct RunArgs {
     /// Load environment variables from a `.env` file.
     ///
     /// Can be provided multiple times, with subsequent files overriding values defined in previous
+    /// files. Files are layered on top of any files configured via `tool.uv.env-files`, while
+    /// variables that are already set in the environment take precedence over all files.
+    ///
+    /// Values may reference other variables with `${VAR}`, including those defined in earlier
     /// files.
     #[arg(long, env = EnvVars::UV_ENV_FILE)]
     pub env_file: Vec<String>,
@@ -3455,6 +3913,16 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_ISOLATED, value_parser = clap::builder::BoolishValueParser::new())]
     pub isolated: bool,
 
+    /// Write the packages installed for `--with` and `--with-requirements` to the given file after
+    /// a successful run.
+    ///
+    /// Each package is pinned to the exact version that was installed, such that an ad-hoc run can
+    /// be reproduced later. If the path ends in `.py`, the packages are written to the
+    /// `dependencies` of the script's inline metadata, creating the script if it doesn't exist.
+    /// Otherwise, the packages are written in the `requirements.txt` format.
+    #[arg(long, value_name = "PATH")]
+    pub snapshot: Option<PathBuf>,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -3840,6 +4308,14 @@ pub struct SyncArgs {
     #[arg(long, conflicts_with = "all_packages")]
     pub package: Vec<PackageName>,
 
+    /// Apply the named sync profile, as defined in `tool.uv.sync-profiles`.
+    ///
+    /// A profile is a named combination of sync options, like the groups and extras to include
+    /// or whether to install the project itself. The options in the profile are combined with
+    /// any provided on the command line.
+    #[arg(long, env = EnvVars::UV_SYNC_PROFILE, conflicts_with = "script")]
+    pub profile: Option<String>,
+
     /// Sync the environment for a Python script, rather than the current project.
     ///
     /// If provided, uv will sync the dependencies based on the script's inline metadata table, in
@@ -3917,6 +4393,30 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Record a snapshot of the project environment before modifying it.
+    ///
+    /// The snapshot can be restored with `uv sync --rollback` or `uv env restore`. Up to five
+    /// snapshots are retained in the environment.
+    #[arg(long, env = EnvVars::UV_SNAPSHOT, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["dry_run", "check", "script"])]
+    pub snapshot: bool,
+
+    /// Restore the project environment from the most recent snapshot, rather than syncing it.
+    ///
+    /// Reverts the changes made by the last `uv sync --snapshot`.
+    #[arg(long, conflicts_with_all = ["snapshot", "dry_run", "check", "script"])]
+    pub rollback: bool,
+
+    /// Apply changes to a copy of the environment, and swap it into place once complete.
+    ///
+    /// The environment is copied into a staging directory alongside it, in which packages are
+    /// installed and removed. Once the sync succeeds, the staged environment replaces the original
+    /// with an atomic rename, such that an interrupted or failed sync never leaves the environment
+    /// partially modified.
+    ///
+    /// Requires additional disk space and time to copy the environment.
+    #[arg(long, conflicts_with_all = ["snapshot", "rollback", "dry_run", "check"])]
+    pub atomic: bool,
 }
 
 #[derive(Args)]
@@ -3957,6 +4457,15 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// The format in which to report an outdated lockfile with `--check`.
+    ///
+    /// When the lockfile needs to be updated, uv reports the inputs that changed since it was
+    /// written, e.g., the requirements, dependency groups, or sources of a workspace member, the
+    /// project's `requires-python`, or the set of workspace members. With `json`, the report is
+    /// written to stdout.
+    #[arg(long, value_enum, default_value_t = LockFormat::default())]
+    pub format: LockFormat,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3964,6 +4473,75 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Explain the changes between two lockfiles, rather than locking the project.
+    ///
+    /// For each package that was added, removed, or updated between the `OLD` and `NEW`
+    /// lockfiles, uv reports the likely cause of the change, e.g., a modified requirement or
+    /// constraint, a new dependency edge, or an update to a package that depends on it.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(
+        long,
+        num_args = 2,
+        value_names = ["OLD", "NEW"],
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "script"]
+    )]
+    pub audit: Option<Vec<PathBuf>>,
+
+    /// Explain under which environments each locked version of a package is installed, and why,
+    /// rather than locking the project.
+    ///
+    /// For each version of the package in the lockfile, uv reports the marker under which it's
+    /// installed, the resolver forks that selected it, and the packages that depend on it. Requires
+    /// a lockfile written with `marker-provenance` enabled.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(
+        long,
+        value_name = "PACKAGE",
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "script", "audit"]
+    )]
+    pub explain: Option<PackageName>,
+
+    /// Upgrade the package selected with `--upgrade-package` to the given version or range.
+    ///
+    /// Accepts a version (e.g., `2.1`), which is locked exactly, or version specifiers (e.g.,
+    /// `>=2.1,<3`), in which case the latest compatible version is locked. Requires exactly one
+    /// `--upgrade-package`.
+    #[arg(
+        long,
+        value_name = "VERSION",
+        requires = "upgrade_package",
+        conflicts_with_all = ["check", "locked", "check_exists", "audit"]
+    )]
+    pub to: Option<UpgradeTarget>,
+
+    /// Update the requirement on the upgraded package in `pyproject.toml` to match `--to`.
+    ///
+    /// All requirements on the package in the workspace are updated. A version (e.g., `--to 2.1`)
+    /// is saved as a lower bound (e.g., `>=2.1`), while version specifiers are saved as provided.
+    #[arg(long, requires = "to", conflicts_with_all = ["dry_run", "script"])]
+    pub save: bool,
+
+    /// Fail if a resolved package doesn't support all Python versions included in the project's
+    /// `requires-python`.
+    ///
+    /// By default, uv warns when a dependency declares a `requires-python` upper bound that
+    /// excludes a Python version included in the project's `requires-python`, or has no source
+    /// distribution and no wheels for such a Python version. Only Python versions targeted by at
+    /// least one wheel in the resolution are considered.
+    #[arg(long, conflicts_with = "check_exists")]
+    pub strict_requires_python: bool,
+
+    /// Write the locked resolution as a constraints file to the given path.
+    ///
+    /// The constraints file pins every package in the lockfile, across all extras and dependency
+    /// groups, as `name==version` with environment markers, for use with `pip install -c` or
+    /// other tools that accept constraints files. Workspace members and other local packages are
+    /// omitted, as are extras, such that the file only constrains, but never adds, requirements.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["dry_run", "audit"])]
+    pub emit_constraints: Option<PathBuf>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -4007,6 +4585,31 @@ pub struct AddArgs {
     #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
     pub requirements: Vec<PathBuf>,
 
+    /// Add any third-party packages imported by the project's Python sources that are not yet
+    /// declared as dependencies.
+    ///
+    /// uv scans the `import` statements in the project's Python files (or, with `--script`, in
+    /// the script itself), ignoring the standard library and first-party modules, and maps each
+    /// imported module to the distribution that provides it, using the packages installed in the
+    /// project environment along with a bundled mapping of well-known import names.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, group = "sources", conflicts_with = "raw")]
+    pub from_imports: bool,
+
+    /// Search the configured indexes for a package to add, interactively.
+    ///
+    /// Prompts for a search query, and shows the matching projects along with their latest
+    /// version, summary, Python requirement, and download count, where the index reports them.
+    /// After picking a project, prompts for an optional version specifier (e.g., `>=2,<3`).
+    ///
+    /// Projects are searched by name, in the project list of each index. Summaries are read from
+    /// the JSON API of indexes that provide one, such as PyPI.
+    ///
+    /// Requires an interactive terminal.
+    #[arg(long, group = "sources")]
+    pub interactive: bool,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -4092,6 +4695,16 @@ pub struct AddArgs {
     #[arg(long, value_enum)]
     pub bounds: Option<AddBoundsKind>,
 
+    /// The policy to apply to locked packages that must change to accommodate the new
+    /// dependencies.
+    ///
+    /// By default, if a locked version is no longer compatible, the best compatible version is
+    /// selected, which may be a new major version. With `same-major`, versions with the same major
+    /// version as the locked version are preferred, and a different major version is only selected
+    /// if necessary.
+    #[arg(long, value_enum)]
+    pub upgrade_policy: Option<UpgradePolicy>,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub rev: Option<String>,
@@ -4309,11 +4922,33 @@ pub struct AddArgs {
 }
 
 #[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true))]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(group = "sources")]
     pub packages: Vec<Requirement<VerbatimParsedUrl>>,
 
+    /// Remove any dependencies that are never imported by the project's Python sources.
+    ///
+    /// uv scans the `import` statements in the project's Python files (or, with `--script`, in
+    /// the script itself) and removes each declared dependency that doesn't provide any of the
+    /// imported modules. Imported modules are mapped to distributions using the packages
+    /// installed in the project environment along with a bundled mapping of well-known import
+    /// names.
+    ///
+    /// By default, `project.dependencies` is checked; use `--dev`, `--group`, or `--optional` to
+    /// check a different table.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, group = "sources")]
+    pub unused: bool,
+
+    /// Report unused dependencies without removing them.
+    ///
+    /// Exits with a non-zero status if any unused dependencies are found.
+    #[arg(long, requires = "unused")]
+    pub dry_run: bool,
+
     /// Remove the packages from the development dependency group.
     ///
     /// This option is an alias for `--group dev`.
@@ -4403,7 +5038,45 @@ pub struct RemoveArgs {
 }
 
 #[derive(Args)]
-pub struct TreeArgs {
+pub struct ShellArgs {
+    /// Run the given command in the shell, rather than starting an interactive session.
+    ///
+    /// The command is passed to the shell as a string, e.g., `uv shell --command "python -V"`.
+    #[arg(long, short)]
+    pub command: Option<String>,
+
+    /// Prefer the active virtual environment over the project's virtual environment.
+    ///
+    /// If the project virtual environment is active or no virtual environment is active, this has
+    /// no effect.
+    #[arg(long, overrides_with = "no_active")]
+    pub active: bool,
+
+    /// Prefer project's virtual environment over an active environment.
+    ///
+    /// This is the default behavior.
+    #[arg(long, overrides_with = "active", hide = true)]
+    pub no_active: bool,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Prefer the active virtual environment over the project's virtual environment.
+    ///
+    /// If the project virtual environment is active or no virtual environment is active, this has
+    /// no effect.
+    #[arg(long, overrides_with = "no_active")]
+    pub active: bool,
+
+    /// Prefer project's virtual environment over an active environment.
+    ///
+    /// This is the default behavior.
+    #[arg(long, overrides_with = "active", hide = true)]
+    pub no_active: bool,
+}
+
+#[derive(Args)]
+pub struct TreeArgs {
     /// Show a platform-independent dependency tree.
     ///
     /// Shows resolved package versions for all Python versions and platforms, rather than filtering
@@ -4413,6 +5086,15 @@ pub struct TreeArgs {
     #[arg(long)]
     pub universal: bool,
 
+    /// Explore the dependency tree interactively.
+    ///
+    /// Packages can be expanded and collapsed, searched by name, and inspected to show their
+    /// markers, extras, licenses, and reverse dependencies.
+    ///
+    /// Requires an interactive terminal.
+    #[arg(long)]
+    pub interactive: bool,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -4546,7 +5228,9 @@ pub struct ExportArgs {
     #[allow(clippy::doc_markdown)]
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output
+    /// formats, along with a multi-stage `Dockerfile` snippet that installs the project with
+    /// `uv sync`.
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
@@ -4688,6 +5372,28 @@ pub struct ExportArgs {
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
 
+    /// Write each dependency group to a separate requirements file.
+    ///
+    /// The file given by `--output-file` will only include the project's dependencies. For each
+    /// included dependency group, a file named after the output file and the group (e.g.,
+    /// `requirements-dev.txt`) is written alongside it, which includes the output file via `-r`
+    /// and lists the additional requirements of the group.
+    ///
+    /// Only supported for the `requirements.txt` format.
+    #[arg(long, requires = "output_file", conflicts_with_all = ["only_group", "only_dev"])]
+    pub split_groups: bool,
+
+    /// Strip environment markers from the exported requirements.
+    ///
+    /// Only the requirements that apply to the target environment are exported, without the
+    /// markers that distinguish between environments. The target environment is that of the
+    /// discovered Python interpreter, unless overridden with `--python-version` or
+    /// `--python-platform`.
+    ///
+    /// Not supported for the CycloneDX format.
+    #[arg(long)]
+    pub strip_markers: bool,
+
     /// Do not emit the current project.
     ///
     /// By default, the current project is included in the exported requirements file with all of
@@ -4827,6 +5533,37 @@ pub struct ExportArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// The Python version to use when filtering the exported requirements.
+    ///
+    /// For example, pass `--python-version 3.11` to export only the requirements that would be
+    /// installed on Python 3.11. Environment markers are retained in the output, unless
+    /// `--strip-markers` is provided.
+    ///
+    /// Defaults to the version of the discovered Python interpreter when `--python-platform` or
+    /// `--strip-markers` is provided; otherwise, the requirements are exported for all Python
+    /// versions.
+    ///
+    /// Not supported for the CycloneDX format.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform to use when filtering the exported requirements.
+    ///
+    /// For example, pass `--python-platform linux` to export only the requirements that would be
+    /// installed on Linux. Environment markers are retained in the output, unless
+    /// `--strip-markers` is provided.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Defaults to the platform of the discovered Python interpreter when `--python-version` or
+    /// `--strip-markers` is provided; otherwise, the requirements are exported for all platforms.
+    ///
+    /// Not supported for the CycloneDX format.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
 }
 
 #[derive(Args)]
@@ -4863,6 +5600,220 @@ pub struct FormatArgs {
     pub no_project: bool,
 }
 
+#[derive(Args)]
+pub struct TestArgs {
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    ///
+    /// Optional dependencies are defined via `project.optional-dependencies` in a `pyproject.toml`.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    ///
+    /// Optional dependencies are defined via `project.optional-dependencies` in a `pyproject.toml`.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    /// Exclude the specified optional dependencies, if `--all-extras` is supplied.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_extra: Vec<ExtraName>,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Disable the development dependency group.
+    ///
+    /// This option is an alias of `--no-group dev`.
+    /// See `--no-default-groups` to disable all default groups instead.
+    #[arg(long, env = EnvVars::UV_NO_DEV, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// The `test` group is included by default, if defined.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with = "all_groups")]
+    pub group: Vec<GroupName>,
+
+    /// Disable the specified dependency group.
+    ///
+    /// This option always takes precedence over default groups,
+    /// `--all-groups`, and `--group`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, env = EnvVars::UV_NO_GROUP, value_delimiter = ' ')]
+    pub no_group: Vec<GroupName>,
+
+    /// Ignore the default dependency groups.
+    ///
+    /// uv includes the groups defined in `tool.uv.default-groups` by default.
+    /// This disables that option, however, specific groups can still be included with `--group`.
+    #[arg(long, env = EnvVars::UV_NO_DEFAULT_GROUPS)]
+    pub no_default_groups: bool,
+
+    /// Include dependencies from all dependency groups.
+    ///
+    /// `--no-group` can be used to exclude specific groups.
+    #[arg(long, conflicts_with = "group")]
+    pub all_groups: bool,
+
+    /// Install any non-editable dependencies, including the project and any workspace members, as
+    /// editable.
+    #[arg(long, overrides_with = "no_editable", hide = true)]
+    pub editable: bool,
+
+    /// Install any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long, overrides_with = "editable", value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_NO_EDITABLE)]
+    pub no_editable: bool,
+
+    /// Do not remove extraneous packages present in the environment.
+    #[arg(long, overrides_with("exact"), alias = "no-exact", hide = true)]
+    pub inexact: bool,
+
+    /// Perform an exact sync, removing extraneous packages.
+    ///
+    /// When enabled, uv will remove any extraneous packages from the environment. By default, `uv
+    /// test` will make the minimum necessary changes to satisfy the requirements.
+    #[arg(long, overrides_with("inexact"))]
+    pub exact: bool,
+
+    /// Load environment variables from a `.env` file.
+    ///
+    /// Can be provided multiple times, with subsequent files overriding values defined in previous
+    /// files. Files are layered on top of any files configured via `tool.uv.env-files`, while
+    /// variables that are already set in the environment take precedence over all files.
+    ///
+    /// Values may reference other variables with `${VAR}`, including those defined in earlier
+    /// files.
+    #[arg(long, env = EnvVars::UV_ENV_FILE)]
+    pub env_file: Vec<String>,
+
+    /// Avoid reading environment variables from a `.env` file.
+    #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), env = EnvVars::UV_NO_ENV_FILE)]
+    pub no_env_file: bool,
+
+    /// Prefer the active virtual environment over the project's virtual environment.
+    ///
+    /// If the project virtual environment is active or no virtual environment is active, this has
+    /// no effect.
+    #[arg(long, overrides_with = "no_active")]
+    pub active: bool,
+
+    /// Prefer project's virtual environment over an active environment.
+    ///
+    /// This is the default behavior.
+    #[arg(long, overrides_with = "active", hide = true)]
+    pub no_active: bool,
+
+    /// Avoid syncing the virtual environment.
+    ///
+    /// Implies `--frozen`, as the project dependencies will be ignored (i.e., the lockfile will not
+    /// be updated, since the environment will not be synced regardless).
+    #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new())]
+    pub no_sync: bool,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Run without updating the `uv.lock` file.
+    ///
+    /// Instead of checking if the lockfile is up-to-date, uses the versions in the lockfile as the
+    /// source of truth. If the lockfile is missing, uv will exit with an error. If the
+    /// `pyproject.toml` includes changes to dependencies that have not been included in the
+    /// lockfile yet, they will not be present in the environment.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Run the tests with all workspace members installed.
+    ///
+    /// The workspace's environment (`.venv`) is updated to include all workspace members.
+    #[arg(long, conflicts_with = "package")]
+    pub all_packages: bool,
+
+    /// Run the tests of a specific package in the workspace.
+    ///
+    /// The test command is read from the package's `pyproject.toml`, falling back to the
+    /// workspace root.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with = "all_packages")]
+    pub package: Option<PackageName>,
+
+    /// The Python interpreter to use for the test environment.
+    ///
+    /// If the interpreter request is satisfied by a discovered environment, the environment will be
+    /// used.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Additional arguments to pass to the test command.
+    ///
+    /// For example, use `uv test -k test_foo` to select tests with pytest, or
+    /// `uv test -- --help` to show the test command's help.
+    #[arg(
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        value_name = "ARGS"
+    )]
+    pub args: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// The path of the socket to listen on.
+    ///
+    /// On Windows, the name of a named pipe, e.g., `\\.\pipe\uv-serve`.
+    ///
+    /// Defaults to a socket in the temporary directory (or a named pipe, on Windows) that's unique
+    /// to the project.
+    #[arg(long)]
+    pub socket: Option<PathBuf>,
+
+    /// The Python interpreter to use for resolution.
+    ///
+    /// The interpreter is discovered when the first resolution is requested, and reused for the
+    /// lifetime of the server.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct AuthNamespace {
     #[command(subcommand)]
@@ -4957,6 +5908,18 @@ pub enum ToolCommand {
     List(ToolListArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Record the installed tools and the exact versions of their packages in a lockfile.
+    ///
+    /// The lockfile can be used to reinstall the same tools on another machine with `uv tool sync`.
+    #[command(hide = true)]
+    Lock(ToolLockArgs),
+    /// Install the tools recorded in a lockfile.
+    ///
+    /// Each tool is installed with the exact package versions recorded by `uv tool lock`. Tools that
+    /// are already installed with the recorded versions are left unchanged. Installed tools that are
+    /// missing from the lockfile are not removed.
+    #[command(hide = true)]
+    Sync(ToolSyncArgs),
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -5053,6 +6016,10 @@ pub struct ToolRunArgs {
     /// Load environment variables from a `.env` file.
     ///
     /// Can be provided multiple times, with subsequent files overriding values defined in previous
+    /// files, while variables that are already set in the environment take precedence over all
+    /// files.
+    ///
+    /// Values may reference other variables with `${VAR}`, including those defined in earlier
     /// files.
     #[arg(long, value_delimiter = ' ', env = EnvVars::UV_ENV_FILE)]
     pub env_file: Vec<PathBuf>,
@@ -5161,6 +6128,19 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub with_executables_from: Vec<comma::CommaSeparatedRequirements>,
 
+    /// Only install executables matching the given glob patterns (e.g., `ansible-*`).
+    ///
+    /// Applies to the executables of the tool package and of any packages provided via
+    /// `--with-executables-from`. May be provided multiple times.
+    #[arg(long, value_delimiter = ',', value_name = "PATTERN")]
+    pub include_executable: Vec<String>,
+
+    /// Skip installing executables matching the given glob patterns (e.g., `*-legacy`).
+    ///
+    /// Takes precedence over `--include-executable`. May be provided multiple times.
+    #[arg(long, value_delimiter = ',', value_name = "PATTERN")]
+    pub exclude_executable: Vec<String>,
+
     /// Constrain versions using the given requirements files.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -5313,6 +6293,24 @@ pub struct ToolUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+pub struct ToolLockArgs {
+    /// The path to the lockfile to write.
+    ///
+    /// Defaults to `uv-tools.lock` in the current directory.
+    #[arg(value_name = "PATH")]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ToolSyncArgs {
+    /// The path to the lockfile to install the tools from.
+    ///
+    /// Defaults to `uv-tools.lock` in the current directory.
+    #[arg(value_name = "PATH")]
+    pub path: Option<PathBuf>,
+}
+
 #[derive(Args)]
 pub struct ToolUpgradeArgs {
     /// The name of the tool to upgrade, along with an optional version specifier.
@@ -5414,8 +6412,9 @@ pub struct ToolUpgradeArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5472,6 +6471,45 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The policy to apply when the resolver selects a yanked version.
+    ///
+    /// By default (`warn`), uv will only select a yanked version if it's pinned exactly or
+    /// preserved from an existing lockfile, and will warn when doing so.
+    ///
+    /// Under `allow-pinned`, uv will select pinned yanked versions without a warning. Under
+    /// `error`, uv will refuse to select any yanked version that isn't listed in `allow-yanked`.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub yanked_policy: Option<YankedPolicy>,
+
+    /// The maximum number of times the resolver may backtrack on a single package before aborting
+    /// the resolution.
+    ///
+    /// When the limit is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no limit.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS, help_heading = "Resolver options")]
+    pub max_backtracks: Option<NonZeroUsize>,
+
+    /// The maximum time, in seconds, the resolver may run before aborting the resolution.
+    ///
+    /// When the timeout is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_timeout: Option<u64>,
+
+    /// Prefer the variant with the smallest artifacts among versions that only differ in their
+    /// local version segment, e.g., `2.4.0+cpu` over `2.4.0+cu121`.
+    ///
+    /// By default, the highest local version is selected. With this option, uv compares the size
+    /// of the best-matching wheel of each variant instead, which avoids pulling in large variants
+    /// (such as CUDA builds) when a smaller one satisfies the same requirements.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prefer_smaller_artifacts: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -5533,8 +6571,8 @@ pub struct ToolUpgradeArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
@@ -5891,6 +6929,19 @@ pub struct PythonInstallArgs {
     /// If multiple Python versions are requested, uv will exit with an error.
     #[arg(long, conflicts_with("no_bin"))]
     pub default: bool,
+
+    /// Install Python for all users of the machine.
+    ///
+    /// Installations are stored in `/opt/uv/python` on Unix and `%PROGRAMDATA%\uv\python` on
+    /// Windows, unless `--install-dir` is provided. Executables are linked into `/usr/local/bin` on
+    /// Unix and `%PROGRAMDATA%\uv\bin` on Windows, unless `UV_PYTHON_BIN_DIR` is set. On
+    /// Windows, the installations are registered under `HKEY_LOCAL_MACHINE` and the executable
+    /// directory is added to the system `PATH`.
+    ///
+    /// Requires elevated privileges, e.g., running with `sudo` on Unix or from an administrator
+    /// terminal on Windows.
+    #[arg(long)]
+    pub system_scope: bool,
 }
 
 impl PythonInstallArgs {
@@ -5899,6 +6950,7 @@ impl PythonInstallArgs {
         PythonInstallMirrors {
             python_install_mirror: self.mirror.clone(),
             pypy_install_mirror: self.pypy_mirror.clone(),
+            python_download_attestations: None,
             python_downloads_json_url: self.python_downloads_json_url.clone(),
         }
     }
@@ -5948,9 +7000,27 @@ pub struct PythonUpgradeArgs {
     #[arg(long, short)]
     pub reinstall: bool,
 
+    /// Re-link virtual environments that use an older patch release to the upgraded Python.
+    ///
+    /// By default, virtual environments that were created with a specific patch release continue
+    /// to use that release after an upgrade. With `--relink`, uv will update the project's
+    /// virtual environment and any tool environments that use an older patch release of an
+    /// upgraded Python version to use the latest patch release instead.
+    ///
+    /// Patch releases are compatible with each other, so the packages installed in the
+    /// environments are retained.
+    #[arg(long)]
+    pub relink: bool,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long)]
     pub python_downloads_json_url: Option<String>,
+
+    /// Upgrade the Python versions installed for all users of the machine.
+    ///
+    /// See `uv python install --system-scope`.
+    #[arg(long)]
+    pub system_scope: bool,
 }
 
 impl PythonUpgradeArgs {
@@ -5959,6 +7029,7 @@ impl PythonUpgradeArgs {
         PythonInstallMirrors {
             python_install_mirror: self.mirror.clone(),
             pypy_install_mirror: self.pypy_mirror.clone(),
+            python_download_attestations: None,
             python_downloads_json_url: self.python_downloads_json_url.clone(),
         }
     }
@@ -5979,13 +7050,21 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Uninstall Python versions installed for all users of the machine.
+    ///
+    /// See `uv python install --system-scope`.
+    #[arg(long)]
+    pub system_scope: bool,
 }
 
 #[derive(Args)]
 pub struct PythonFindArgs {
     /// The Python request.
     ///
-    /// See `uv help python` to view supported request formats.
+    /// In addition to the formats described in `uv help python`, the request may be followed by a
+    /// `;` and a comma-separated list of `key=value` filters, where the supported keys are
+    /// `implementation`, `arch`, `os`, and `libc`, e.g., `cpython>=3.11,<3.13; arch=aarch64`.
     pub request: Option<String>,
 
     /// Avoid discovering a project or workspace.
@@ -6028,6 +7107,12 @@ pub struct PythonFindArgs {
     #[arg(long)]
     pub show_version: bool,
 
+    /// The format in which to display the discovered interpreter.
+    ///
+    /// The JSON format includes the interpreter's path, version, implementation, and platform.
+    #[arg(long, value_enum, default_value_t = PythonFindFormat::default(), conflicts_with = "show_version")]
+    pub output_format: PythonFindFormat,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long)]
     pub python_downloads_json_url: Option<String>,
@@ -6299,6 +7384,13 @@ pub struct RefreshArgs {
     /// Refresh cached data for a specific package.
     #[arg(long, help_heading = "Cache options")]
     pub refresh_package: Vec<PackageName>,
+
+    /// Refresh cached data for a specific URL.
+    ///
+    /// Forces revalidation of a direct URL requirement (e.g., `foo @ https://example.com/foo.whl`)
+    /// with the server, without refreshing the other cached data of the package.
+    #[arg(long, help_heading = "Cache options")]
+    pub refresh_url: Vec<DisplaySafeUrl>,
 }
 
 #[derive(Args)]
@@ -6400,8 +7492,9 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -6467,8 +7560,8 @@ pub struct InstallerArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
@@ -6568,8 +7661,9 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -6626,6 +7720,45 @@ pub struct ResolverArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The policy to apply when the resolver selects a yanked version.
+    ///
+    /// By default (`warn`), uv will only select a yanked version if it's pinned exactly or
+    /// preserved from an existing lockfile, and will warn when doing so.
+    ///
+    /// Under `allow-pinned`, uv will select pinned yanked versions without a warning. Under
+    /// `error`, uv will refuse to select any yanked version that isn't listed in `allow-yanked`.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub yanked_policy: Option<YankedPolicy>,
+
+    /// The maximum number of times the resolver may backtrack on a single package before aborting
+    /// the resolution.
+    ///
+    /// When the limit is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no limit.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS, help_heading = "Resolver options")]
+    pub max_backtracks: Option<NonZeroUsize>,
+
+    /// The maximum time, in seconds, the resolver may run before aborting the resolution.
+    ///
+    /// When the timeout is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_timeout: Option<u64>,
+
+    /// Prefer the variant with the smallest artifacts among versions that only differ in their
+    /// local version segment, e.g., `2.4.0+cpu` over `2.4.0+cu121`.
+    ///
+    /// By default, the highest local version is selected. With this option, uv compares the size
+    /// of the best-matching wheel of each variant instead, which avoids pulling in large variants
+    /// (such as CUDA builds) when a smaller one satisfies the same requirements.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prefer_smaller_artifacts: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6689,8 +7822,8 @@ pub struct ResolverArgs {
     ///
     /// This option is only used when building source distributions.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
@@ -6784,8 +7917,9 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -6842,6 +7976,45 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The policy to apply when the resolver selects a yanked version.
+    ///
+    /// By default (`warn`), uv will only select a yanked version if it's pinned exactly or
+    /// preserved from an existing lockfile, and will warn when doing so.
+    ///
+    /// Under `allow-pinned`, uv will select pinned yanked versions without a warning. Under
+    /// `error`, uv will refuse to select any yanked version that isn't listed in `allow-yanked`.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub yanked_policy: Option<YankedPolicy>,
+
+    /// The maximum number of times the resolver may backtrack on a single package before aborting
+    /// the resolution.
+    ///
+    /// When the limit is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no limit.
+    #[arg(long, env = EnvVars::UV_MAX_BACKTRACKS, help_heading = "Resolver options")]
+    pub max_backtracks: Option<NonZeroUsize>,
+
+    /// The maximum time, in seconds, the resolver may run before aborting the resolution.
+    ///
+    /// When the timeout is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLUTION_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Resolver options"
+    )]
+    pub resolution_timeout: Option<u64>,
+
+    /// Prefer the variant with the smallest artifacts among versions that only differ in their
+    /// local version segment, e.g., `2.4.0+cpu` over `2.4.0+cu121`.
+    ///
+    /// By default, the highest local version is selected. With this option, uv compares the size
+    /// of the best-matching wheel of each variant instead, which avoids pulling in large variants
+    /// (such as CUDA builds) when a smaller one satisfies the same requirements.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prefer_smaller_artifacts: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6903,8 +8076,8 @@ pub struct ResolverInstallerArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
@@ -6981,8 +8154,9 @@ pub struct FetchArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -7032,7 +8206,10 @@ pub struct DisplayTreeArgs {
     pub outdated: bool,
 
     /// Show compressed wheel sizes for packages in the tree.
-    #[arg(long)]
+    ///
+    /// For packages with dependencies, the cumulative size of the package and its transitive
+    /// dependencies is shown as well.
+    #[arg(long, alias = "size")]
     pub show_sizes: bool,
 }
 
@@ -7105,8 +8282,9 @@ pub struct PublishArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`, uv
+    /// reads credentials directly from the system's credential store, i.e., the macOS Keychain,
+    /// the Windows Credential Manager, or the Secret Service on Linux.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -7117,6 +8295,9 @@ pub struct PublishArgs {
     /// Note that there are typically different URLs for index access (e.g., `https:://.../simple`)
     /// and index upload.
     ///
+    /// A `file://` URL or an `s3://<bucket>/<prefix>` URL publishes to a directory or S3 bucket
+    /// laid out as a simple index, regenerating the index listings.
+    ///
     /// Defaults to PyPI's publish URL (<https://upload.pypi.org/legacy/>).
     #[arg(long, env = EnvVars::UV_PUBLISH_URL)]
     pub publish_url: Option<DisplaySafeUrl>,
@@ -7156,6 +8337,433 @@ pub struct PublishArgs {
     pub no_attestations: bool,
 }
 
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Store the list of projects available on each configured index in the cache.
+    ///
+    /// The project list is revalidated on each invocation, such that unchanged lists are not
+    /// downloaded again.
+    ///
+    /// When a project list is available in the cache, `uv add` will suggest corrections for package
+    /// names that are not available on any of the configured indexes.
+    Sync(IndexSyncArgs),
+    /// Serve the wheels in the cache as a package index over HTTP.
+    ///
+    /// The index implements the Simple Repository API (PEP 503 and PEP 691), such that other
+    /// tools (e.g., pip) can install the wheels that uv has already downloaded or built.
+    ///
+    /// Wheels that are stored unzipped in the cache are re-archived on request.
+    Serve(IndexServeArgs),
+}
+
+#[derive(Args)]
+pub struct IndexSyncArgs {
+    #[command(flatten)]
+    pub fetch: FetchArgs,
+}
+
+#[derive(Args)]
+pub struct IndexServeArgs {
+    /// The address to listen on.
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: IpAddr,
+
+    /// The port to listen on.
+    ///
+    /// If `0`, an available port is chosen by the operating system.
+    #[arg(long, default_value_t = 8000)]
+    pub port: u16,
+}
+
+#[derive(Args)]
+pub struct EnvNamespace {
+    #[command(subcommand)]
+    pub command: EnvCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnvCommand {
+    /// Restore a Python environment from a snapshot.
+    ///
+    /// Snapshots are recorded by `uv sync --snapshot`. Restoring a snapshot removes it, along with
+    /// any newer snapshots.
+    Restore(EnvRestoreArgs),
+}
+
+#[derive(Args)]
+pub struct EnvRestoreArgs {
+    /// The number of operations to revert.
+    ///
+    /// By default, the most recent snapshot is restored, reverting the last operation.
+    #[arg(long, default_value = "1")]
+    pub steps: NonZeroUsize,
+
+    /// The Python interpreter of the environment to restore.
+    ///
+    /// By default, uv restores the virtual environment in the current working directory or any
+    /// parent directory, or the active virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct ScriptNamespace {
+    #[command(subcommand)]
+    pub command: ScriptCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ScriptCommand {
+    /// Add dependencies to a script's inline metadata.
+    ///
+    /// Equivalent to `uv add --script <SCRIPT>`. If the script doesn't have an inline metadata
+    /// table, one will be created, in adherence with PEP 723.
+    Add(ScriptAddArgs),
+    /// Remove dependencies from a script's inline metadata.
+    ///
+    /// Equivalent to `uv remove --script <SCRIPT>`.
+    Remove(ScriptRemoveArgs),
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct ScriptAddArgs {
+    /// The path to the script.
+    pub script: PathBuf,
+
+    /// The packages to add, as PEP 508 requirements (e.g., `ruff==0.5.0`).
+    #[arg(group = "sources")]
+    pub packages: Vec<String>,
+
+    /// Add the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path)]
+    pub requirements: Vec<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. The constraints will _not_ be added to the script's inline
+    /// metadata, but _will_ be respected during dependency resolution.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short, alias = "constraint", env = EnvVars::UV_CONSTRAINT, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Apply this marker to all added packages.
+    #[arg(long, short, value_parser = MarkerTree::from_str)]
+    pub marker: Option<MarkerTree>,
+
+    /// Add a dependency as provided.
+    ///
+    /// By default, uv will use the `tool.uv.sources` section to record source information for Git,
+    /// local, editable, and direct URL requirements. When `--raw` is provided, uv will add source
+    /// requirements to the script's `dependencies`, rather than `tool.uv.sources`.
+    ///
+    /// Additionally, by default, uv will add bounds to your dependency, e.g., `foo>=1.0.0`. When
+    /// `--raw` is provided, uv will add the dependency without bounds.
+    #[arg(
+        long,
+        conflicts_with = "rev",
+        conflicts_with = "tag",
+        conflicts_with = "branch",
+        alias = "raw-sources"
+    )]
+    pub raw: bool,
+
+    /// The kind of version specifier to use when adding dependencies.
+    ///
+    /// When adding a dependency to the script, if no constraint or URL is provided, a constraint
+    /// is added based on the latest compatible version of the package. By default, a lower bound
+    /// constraint is used, e.g., `>=1.2.3`.
+    ///
+    /// When `--frozen` is provided, no resolution is performed, and dependencies are always added
+    /// without constraints.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[arg(long, value_enum)]
+    pub bounds: Option<AddBoundsKind>,
+
+    /// Commit to use when adding a dependency from Git.
+    #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
+    pub rev: Option<String>,
+
+    /// Tag to use when adding a dependency from Git.
+    #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
+    pub tag: Option<String>,
+
+    /// Branch to use when adding a dependency from Git.
+    #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
+    pub branch: Option<String>,
+
+    /// Extras to enable for the dependency.
+    ///
+    /// May be provided more than once.
+    #[arg(long)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Add dependencies without re-locking the script.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for resolving.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+impl From<ScriptAddArgs> for AddArgs {
+    fn from(args: ScriptAddArgs) -> Self {
+        let ScriptAddArgs {
+            script,
+            packages,
+            requirements,
+            constraints,
+            marker,
+            raw,
+            bounds,
+            rev,
+            tag,
+            branch,
+            extra,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+        Self {
+            packages,
+            requirements,
+            from_imports: false,
+            interactive: false,
+            constraints,
+            marker,
+            dev: false,
+            optional: None,
+            group: None,
+            editable: false,
+            no_editable: false,
+            raw,
+            bounds,
+            upgrade_policy: None,
+            rev,
+            tag,
+            branch,
+            extra,
+            no_sync: false,
+            locked,
+            frozen,
+            active: false,
+            no_active: false,
+            installer,
+            build,
+            refresh,
+            package: None,
+            script: Some(script),
+            python,
+            workspace: false,
+            no_workspace: false,
+            no_install_project: false,
+            only_install_project: false,
+            no_install_workspace: false,
+            only_install_workspace: false,
+            no_install_local: false,
+            only_install_local: false,
+            no_install_package: Vec::new(),
+            only_install_package: Vec::new(),
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct ScriptRemoveArgs {
+    /// The path to the script.
+    pub script: PathBuf,
+
+    /// The names of the dependencies to remove (e.g., `ruff`).
+    #[arg(required = true)]
+    pub packages: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// Assert that the script's lockfile will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or needs to be updated,
+    /// uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["frozen", "upgrade"])]
+    pub locked: bool,
+
+    /// Remove dependencies without re-locking the script.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use for resolving.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+impl From<ScriptRemoveArgs> for RemoveArgs {
+    fn from(args: ScriptRemoveArgs) -> Self {
+        let ScriptRemoveArgs {
+            script,
+            packages,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+        Self {
+            packages,
+            unused: false,
+            dry_run: false,
+            dev: false,
+            optional: None,
+            group: None,
+            no_sync: false,
+            active: false,
+            no_active: false,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            package: None,
+            script: Some(script),
+            python,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct BundleNamespace {
+    #[command(subcommand)]
+    pub command: BundleCommand,
+}
+
+#[derive(Subcommand)]
+pub enum BundleCommand {
+    /// Pack the packages installed in a Python environment into a bundle.
+    ///
+    /// The bundle is a Zstandard-compressed tarball containing a wheel for each installed package,
+    /// taken from the cache, along with a manifest of the packages to install. Bundles can be
+    /// restored with `uv bundle install`, without resolution or access to a package index.
+    ///
+    /// Only packages installed from a package index can be bundled.
+    Create(BundleCreateArgs),
+    /// Install the packages in a bundle into a Python environment.
+    ///
+    /// Packages are installed exactly as listed in the bundle's manifest, without resolution or
+    /// network access. Each wheel is verified against the hash recorded in the manifest, and must
+    /// be compatible with the target environment.
+    Install(BundleInstallArgs),
+}
+
+#[derive(Args)]
+pub struct BundleCreateArgs {
+    /// The path to write the bundle to.
+    #[arg(default_value = "bundle.tar.zst")]
+    pub output: PathBuf,
+
+    /// The Python interpreter of the environment to bundle.
+    ///
+    /// By default, uv bundles the virtual environment in the current working directory or any
+    /// parent directory, or the active virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
+#[derive(Args)]
+pub struct BundleInstallArgs {
+    /// The path to the bundle to install.
+    pub bundle: PathBuf,
+
+    /// The Python interpreter of the environment to install into.
+    ///
+    /// By default, uv installs into the virtual environment in the current working directory or
+    /// any parent directory, or the active virtual environment.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct WorkspaceNamespace {
     #[command(subcommand)]
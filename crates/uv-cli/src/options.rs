@@ -43,9 +43,14 @@ impl From<RefreshArgs> for Refresh {
             refresh,
             no_refresh,
             refresh_package,
+            refresh_url,
         } = value;
 
-        Self::from_args(flag(refresh, no_refresh, "no-refresh"), refresh_package)
+        Self::from_args(
+            flag(refresh, no_refresh, "no-refresh"),
+            refresh_package,
+            refresh_url,
+        )
     }
 }
 
@@ -62,6 +67,10 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -80,6 +89,10 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts: prefer_smaller_artifacts.then_some(true),
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -163,6 +176,10 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -190,6 +207,10 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts: prefer_smaller_artifacts.then_some(true),
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -284,6 +305,10 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        yanked_policy,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -347,6 +372,12 @@ pub fn resolver_options(
             prerelease
         },
         fork_strategy,
+        yanked_policy,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts: prefer_smaller_artifacts.then_some(true),
+        allow_yanked: None,
+        known_incompatibilities: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -393,6 +424,10 @@ pub fn resolver_installer_options(
         prerelease,
         pre,
         fork_strategy,
+        yanked_policy,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -464,6 +499,12 @@ pub fn resolver_installer_options(
             prerelease
         },
         fork_strategy,
+        yanked_policy,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts: prefer_smaller_artifacts.then_some(true),
+        allow_yanked: None,
+        known_incompatibilities: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -482,6 +523,9 @@ pub fn resolver_installer_options(
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
+        script_launcher: None,
+        no_gui_scripts: None,
+        wheel_checks: None,
         no_build: flag(no_build, build, "build"),
         no_build_package: if no_build_package.is_empty() {
             None
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
-use std::num::ParseIntError;
+use std::num::{NonZeroUsize, ParseIntError};
 use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
@@ -25,24 +25,28 @@ use reqwest_retry::{
 };
 use thiserror::Error;
 use tracing::{debug, trace};
-use url::ParseError;
-use url::Url;
+use url::{Origin, ParseError, Url};
 
 use uv_auth::{AuthMiddleware, Credentials, Indexes, PyxTokenStore};
-use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_configuration::{DownloadRate, KeyringProviderType, TrustedHost};
+use uv_distribution_types::IndexTls;
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeatures};
 use uv_redacted::DisplaySafeUrl;
 use uv_redacted::DisplaySafeUrlError;
 use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+#[cfg(feature = "http3")]
+use crate::http3::Http3Middleware;
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::proxy::ProxyHelper;
+use crate::throttle::{Throttle, ThrottleMiddleware};
+use crate::tls::{read_ca_bundle, read_identity, read_identity_with_key};
 use crate::{Connectivity, WrappedReqwestError};
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -79,6 +83,8 @@ pub struct BaseClientBuilder<'a> {
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     indexes: Indexes,
+    /// The TLS configuration for each index that defines one, keyed by the index URL.
+    index_tls: Vec<(DisplaySafeUrl, IndexTls)>,
     timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
     proxies: Vec<Proxy>,
@@ -89,6 +95,8 @@ pub struct BaseClientBuilder<'a> {
     cross_origin_credential_policy: CrossOriginCredentialsPolicy,
     /// Optional custom reqwest client to use instead of creating a new one.
     custom_client: Option<Client>,
+    /// The rate and concurrency limits, shared by all clients created from this builder.
+    throttle: Arc<Throttle>,
 }
 
 /// The policy for handling HTTP redirects.
@@ -137,12 +145,14 @@ impl Default for BaseClientBuilder<'_> {
             platform: None,
             auth_integration: AuthIntegration::default(),
             indexes: Indexes::new(),
+            index_tls: Vec::new(),
             timeout: Duration::from_secs(30),
             extra_middleware: None,
             proxies: vec![],
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
             custom_client: None,
+            throttle: Arc::default(),
         }
     }
 }
@@ -204,6 +214,20 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Limit the download rate and the number of concurrent requests to each host.
+    #[must_use]
+    pub fn throttle(
+        mut self,
+        max_download_rate: Option<DownloadRate>,
+        concurrent_downloads_per_host: Option<NonZeroUsize>,
+    ) -> Self {
+        self.throttle = Arc::new(Throttle::new(
+            max_download_rate,
+            concurrent_downloads_per_host,
+        ));
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -240,6 +264,13 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Use a dedicated TLS configuration for requests to the host of each of the given indexes.
+    #[must_use]
+    pub fn index_tls(mut self, index_tls: Vec<(DisplaySafeUrl, IndexTls)>) -> Self {
+        self.index_tls = index_tls;
+        self
+    }
+
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
@@ -298,8 +329,8 @@ impl<'a> BaseClientBuilder<'a> {
         debug!("Using request timeout of {}s", timeout.as_secs());
 
         // Use the custom client if provided, otherwise create a new one
-        let (raw_client, raw_dangerous_client) = match &self.custom_client {
-            Some(client) => (client.clone(), client.clone()),
+        let (raw_client, raw_dangerous_client, raw_index_clients) = match &self.custom_client {
+            Some(client) => (client.clone(), client.clone(), Vec::new()),
             None => self.create_secure_and_insecure_clients(timeout),
         };
 
@@ -314,6 +345,7 @@ impl<'a> BaseClientBuilder<'a> {
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
+        let index_clients = self.wrap_index_clients(raw_index_clients);
 
         BaseClient {
             connectivity: self.connectivity,
@@ -323,6 +355,7 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
             timeout,
         }
     }
@@ -340,6 +373,13 @@ impl<'a> BaseClientBuilder<'a> {
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
+        let index_clients = self.wrap_index_clients(
+            existing
+                .index_clients
+                .iter()
+                .map(|index_client| (index_client.origin.clone(), index_client.raw_client.clone()))
+                .collect(),
+        );
 
         BaseClient {
             connectivity: self.connectivity,
@@ -349,11 +389,31 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients,
             timeout: existing.timeout,
         }
     }
 
-    fn create_secure_and_insecure_clients(&self, timeout: Duration) -> (Client, Client) {
+    /// Wrap the per-index clients in any relevant middleware.
+    fn wrap_index_clients(&self, raw_index_clients: Vec<(Origin, Client)>) -> Vec<IndexClient> {
+        raw_index_clients
+            .into_iter()
+            .map(|(origin, raw_client)| IndexClient {
+                origin,
+                client: RedirectClientWithMiddleware {
+                    client: self.apply_middleware(raw_client.clone()),
+                    redirect_policy: self.redirect_policy,
+                    cross_origin_credentials_policy: self.cross_origin_credential_policy,
+                },
+                raw_client,
+            })
+            .collect()
+    }
+
+    fn create_secure_and_insecure_clients(
+        &self,
+        timeout: Duration,
+    ) -> (Client, Client, Vec<(Origin, Client)>) {
         // Create user agent.
         let mut user_agent_string = format!("uv/{}", version());
 
@@ -433,6 +493,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             ssl_cert_dir_exists,
             Security::Secure,
+            None,
             self.redirect_policy,
         );
 
@@ -443,10 +504,38 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             ssl_cert_dir_exists,
             Security::Insecure,
+            None,
             self.redirect_policy,
         );
 
-        (raw_client, raw_dangerous_client)
+        // Create a dedicated client for each host with its own TLS configuration. If multiple
+        // indexes on the same host define one, the first takes precedence.
+        let mut raw_index_clients: Vec<(Origin, Client)> = Vec::new();
+        for (url, tls) in &self.index_tls {
+            let origin = url.origin();
+            if !origin.is_tuple() {
+                continue;
+            }
+            if raw_index_clients
+                .iter()
+                .any(|(existing, _)| *existing == origin)
+            {
+                continue;
+            }
+            debug!("Using custom TLS configuration for: {url}");
+            let raw_index_client = self.create_client(
+                &user_agent_string,
+                timeout,
+                ssl_cert_file_exists,
+                ssl_cert_dir_exists,
+                Security::Secure,
+                Some(tls),
+                self.redirect_policy,
+            );
+            raw_index_clients.push((origin, raw_index_client));
+        }
+
+        (raw_client, raw_dangerous_client, raw_index_clients)
     }
 
     fn create_client(
@@ -456,15 +545,19 @@ impl<'a> BaseClientBuilder<'a> {
         ssl_cert_file_exists: bool,
         ssl_cert_dir_exists: bool,
         security: Security,
+        tls: Option<&IndexTls>,
         redirect_policy: RedirectPolicy,
     ) -> Client {
+        // Unless disabled for the index, trust the system (or built-in) root certificates.
+        let system_trust = tls.and_then(|tls| tls.system_trust).unwrap_or(true);
+
         // Configure the builder.
         let client_builder = ClientBuilder::new()
             .http1_title_case_headers()
             .user_agent(user_agent)
             .pool_max_idle_per_host(20)
             .read_timeout(timeout)
-            .tls_built_in_root_certs(self.built_in_root_certs)
+            .tls_built_in_root_certs(self.built_in_root_certs && system_trust)
             .redirect(redirect_policy.reqwest_policy());
 
         // If necessary, accept invalid certificates.
@@ -473,18 +566,27 @@ impl<'a> BaseClientBuilder<'a> {
             Security::Insecure => client_builder.danger_accept_invalid_certs(true),
         };
 
-        let client_builder = if self.native_tls || ssl_cert_file_exists || ssl_cert_dir_exists {
+        let client_builder = if !system_trust {
+            client_builder
+                .tls_built_in_native_certs(false)
+                .tls_built_in_webpki_certs(false)
+        } else if self.native_tls || ssl_cert_file_exists || ssl_cert_dir_exists {
             client_builder.tls_built_in_native_certs(true)
         } else {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
-            match read_identity(&ssl_client_cert) {
-                Ok(identity) => client_builder.identity(identity),
+        // Trust the CA bundle configured for the index, if any.
+        let client_builder = if let Some(ca_bundle) = tls.and_then(|tls| tls.ca_bundle.as_deref()) {
+            match read_ca_bundle(ca_bundle) {
+                Ok(certificates) => certificates
+                    .into_iter()
+                    .fold(client_builder, ClientBuilder::add_root_certificate),
                 Err(err) => {
-                    warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                    warn_user_once!(
+                        "Ignoring invalid `ca-bundle` ({}): {err}",
+                        ca_bundle.simplified_display().cyan()
+                    );
                     client_builder
                 }
             }
@@ -492,13 +594,53 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Configure mTLS, preferring the client certificate configured for the index, if any.
+        let client_builder =
+            if let Some(client_cert) = tls.and_then(|tls| tls.client_cert.as_deref()) {
+                let identity = match tls.and_then(|tls| tls.client_key.as_deref()) {
+                    Some(client_key) => read_identity_with_key(client_cert, client_key),
+                    None => read_identity(client_cert.as_os_str()),
+                };
+                match identity {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring invalid `client-cert` ({}): {err}",
+                            client_cert.simplified_display().cyan()
+                        );
+                        client_builder
+                    }
+                }
+            } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+                match read_identity(&ssl_client_cert) {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                        client_builder
+                    }
+                }
+            } else {
+                client_builder
+            };
+
         // apply proxies
         let mut client_builder = client_builder;
         for p in &self.proxies {
             client_builder = client_builder.proxy(p.clone());
         }
+        if let Some(proxy_helper) = ProxyHelper::from_env() {
+            client_builder = client_builder.proxy(proxy_helper.into_proxy());
+        }
         let client_builder = client_builder;
 
+        // Allow TLS 0-RTT early data, which HTTP/3 uses when resuming a session.
+        #[cfg(feature = "http3")]
+        let client_builder = if self.preview.is_enabled(PreviewFeatures::HTTP3) {
+            client_builder.tls_early_data(true)
+        } else {
+            client_builder
+        };
+
         client_builder
             .build()
             .expect("Failed to build HTTP client.")
@@ -528,6 +670,8 @@ impl<'a> BaseClientBuilder<'a> {
                         }
                     }
 
+                    client = client.with(ThrottleMiddleware::new(self.throttle.clone()));
+
                     client.build()
                 };
 
@@ -580,6 +724,25 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Enforce the rate and concurrency limits, and respect any backoff requested by
+                // the server. This runs within the retry middleware, such that retries are delayed.
+                client = client.with(ThrottleMiddleware::new(self.throttle.clone()));
+
+                // Upgrade requests to HTTP/3 for hosts that advertise support. This must be the
+                // innermost middleware, such that it falls back to HTTP/2 before any retries.
+                if self.preview.is_enabled(PreviewFeatures::HTTP3) {
+                    #[cfg(feature = "http3")]
+                    {
+                        client = client.with(Http3Middleware::default());
+                    }
+                    #[cfg(not(feature = "http3"))]
+                    {
+                        warn_user_once!(
+                            "HTTP/3 is not supported by this build of uv; using HTTP/2 instead"
+                        );
+                    }
+                }
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -600,6 +763,8 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for hosts with a dedicated TLS configuration.
+    index_clients: Vec<IndexClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -610,6 +775,17 @@ pub struct BaseClient {
     retries: u32,
 }
 
+/// An HTTP client with a dedicated TLS configuration for a single host.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The origin (scheme, host, and port) served by the client.
+    origin: Origin,
+    /// The underlying HTTP client.
+    client: RedirectClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -622,10 +798,19 @@ impl BaseClient {
     /// Selects the appropriate client based on the host's trustworthiness.
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
         if self.disable_ssl(url) {
-            &self.dangerous_client
-        } else {
-            &self.client
+            return &self.dangerous_client;
+        }
+        if !self.index_clients.is_empty() {
+            let origin = url.origin();
+            if let Some(index_client) = self
+                .index_clients
+                .iter()
+                .find(|index_client| index_client.origin == origin)
+            {
+                return &index_client.client;
+            }
         }
+        &self.client
     }
 
     /// Executes a request, applying redirect policy.
@@ -685,11 +870,21 @@ impl RedirectClientWithMiddleware {
         RequestBuilder::new(self.client.post(url), self)
     }
 
+    /// Convenience method to make a `PUT` request to a URL.
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
+        RequestBuilder::new(self.client.put(url), self)
+    }
+
     /// Convenience method to make a `HEAD` request to a URL.
     pub fn head<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
         RequestBuilder::new(self.client.head(url), self)
     }
 
+    /// Convenience method to make a `DELETE` request to a URL.
+    pub fn delete<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
+        RequestBuilder::new(self.client.delete(url), self)
+    }
+
     /// Executes a request, applying the redirect policy.
     pub async fn execute(&self, req: Request) -> reqwest_middleware::Result<Response> {
         match self.redirect_policy {
@@ -965,6 +1160,12 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Set the request body.
+    pub fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.builder = self.builder.body(body);
+        self
+    }
+
     /// Build a `Request`.
     pub fn build(self) -> reqwest::Result<Request> {
         self.builder.build()
@@ -938,6 +938,13 @@ impl DataWithCachePolicy {
         Self::from_reader(file)
     }
 
+    /// Returns true if the cached data may be used without revalidating it
+    /// with the origin server, as per the `Cache-Control` and related headers
+    /// of the cached response.
+    pub fn is_fresh(&self) -> bool {
+        self.cache_policy.is_fresh_without_request()
+    }
+
     /// Loads cached data and its associated HTTP cache policy from the given
     /// reader.
     ///
@@ -0,0 +1,138 @@
+use std::sync::{Arc, Mutex};
+
+use http::{Extensions, HeaderMap, Method, Version, header};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+/// Whether a host supports HTTP/3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Http3Support {
+    /// The host advertised HTTP/3 via an `Alt-Svc` header.
+    Advertised,
+    /// An HTTP/3 request to the host failed, so it should not be attempted again.
+    Broken,
+}
+
+/// A middleware that upgrades requests to HTTP/3 (QUIC) for hosts that advertise support.
+///
+/// Requests are initially sent over HTTP/2 or HTTP/1.1. If a response includes an `Alt-Svc`
+/// header advertising HTTP/3 on the same port, subsequent idempotent requests to that host are
+/// sent over HTTP/3, with TLS 0-RTT early data when resuming a session. If an HTTP/3 request
+/// fails, the request is retried over HTTP/2 or HTTP/1.1, and HTTP/3 is disabled for the host for
+/// the remainder of the process.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Http3Middleware {
+    hosts: Arc<Mutex<FxHashMap<(String, u16), Http3Support>>>,
+}
+
+impl Http3Middleware {
+    fn support(&self, key: &(String, u16)) -> Option<Http3Support> {
+        self.hosts.lock().unwrap().get(key).copied()
+    }
+
+    fn set_support(&self, key: (String, u16), support: Http3Support) {
+        self.hosts.lock().unwrap().insert(key, support);
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Http3Middleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        // HTTP/3 requires TLS.
+        let key = match (req.url().scheme(), req.url().host_str()) {
+            ("https", Some(host)) => (
+                host.to_string(),
+                req.url().port_or_known_default().unwrap_or(443),
+            ),
+            _ => return next.run(req, extensions).await,
+        };
+
+        // Only upgrade requests that can be safely replayed, both when falling back and when
+        // sent as early data.
+        let idempotent = matches!(*req.method(), Method::GET | Method::HEAD);
+
+        if idempotent && self.support(&key) == Some(Http3Support::Advertised) {
+            if let Some(mut upgraded) = req.try_clone() {
+                *upgraded.version_mut() = Version::HTTP_3;
+                match next.clone().run(upgraded, extensions).await {
+                    Ok(response) => return Ok(response),
+                    Err(err) => {
+                        debug!(
+                            "HTTP/3 request to `{}` failed; falling back to HTTP/2: {err}",
+                            key.0
+                        );
+                        self.set_support(key.clone(), Http3Support::Broken);
+                    }
+                }
+            }
+        }
+
+        let response = next.run(req, extensions).await?;
+        if self.support(&key).is_none() && advertises_http3(response.headers(), key.1) {
+            debug!("Host `{}` advertises HTTP/3 support", key.0);
+            self.set_support(key, Http3Support::Advertised);
+        }
+        Ok(response)
+    }
+}
+
+/// Returns `true` if the `Alt-Svc` headers advertise HTTP/3 on the given port, e.g.,
+/// `h3=":443"; ma=86400`.
+///
+/// Alternative services on a different host or port are ignored, since requests are always sent to
+/// the origin.
+fn advertises_http3(headers: &HeaderMap, port: u16) -> bool {
+    let expected = format!("\":{port}\"");
+    headers
+        .get_all(header::ALT_SVC)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|service| service.split(';').next())
+        .filter_map(|service| service.trim().split_once('='))
+        .any(|(protocol, authority)| protocol == "h3" && authority.trim() == expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue, header};
+
+    use super::advertises_http3;
+
+    fn alt_svc(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ALT_SVC, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn alt_svc_http3() {
+        assert!(advertises_http3(&alt_svc("h3=\":443\"; ma=86400"), 443));
+        assert!(advertises_http3(
+            &alt_svc("h3-29=\":443\"; ma=86400, h3=\":443\"; ma=86400"),
+            443
+        ));
+    }
+
+    #[test]
+    fn alt_svc_other() {
+        // A different port.
+        assert!(!advertises_http3(&alt_svc("h3=\":8443\"; ma=86400"), 443));
+        // A different host.
+        assert!(!advertises_http3(
+            &alt_svc("h3=\"alt.example.com:443\"; ma=86400"),
+            443
+        ));
+        // Only a draft version.
+        assert!(!advertises_http3(&alt_svc("h3-29=\":443\"; ma=86400"), 443));
+        // Clearing alternative services.
+        assert!(!advertises_http3(&alt_svc("clear"), 443));
+    }
+}
@@ -539,6 +539,24 @@ impl ArchivedCachePolicy {
         }
     }
 
+    /// Returns true if the cached response may be reused without contacting
+    /// the origin server, based on the stored response alone.
+    ///
+    /// Unlike [`ArchivedCachePolicy::before_request`], this doesn't require a
+    /// new request, which makes it suitable for deciding whether an artifact
+    /// that was cached from a URL can be used as-is, or whether it needs to
+    /// be revalidated (e.g., with its `ETag`) first.
+    pub fn is_fresh_without_request(&self) -> bool {
+        if !self.is_storable() || self.response.headers.cc.no_cache {
+            return false;
+        }
+        let now = SystemTime::now();
+        if self.age(now) > self.freshness_lifetime() {
+            return self.allows_stale(now);
+        }
+        true
+    }
+
     /// Returns true if and only if the response is storable as per
     /// [RFC 9111 S3].
     ///
@@ -12,16 +12,23 @@ pub use registry_client::{
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use search::ProjectSummary;
+pub use throttle::Throttle;
 
 mod base_client;
 mod cached_client;
 mod error;
 mod flat_index;
 mod html;
+#[cfg(feature = "http3")]
+mod http3;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
+mod search;
+mod throttle;
 mod tls;
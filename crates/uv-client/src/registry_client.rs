@@ -10,7 +10,7 @@ use futures::{FutureExt, StreamExt, TryStreamExt};
 use http::{HeaderMap, StatusCode};
 use itertools::Either;
 use reqwest::{Proxy, Response};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
@@ -156,6 +156,7 @@ impl<'a> RegistryClientBuilder<'a> {
         let builder = self
             .base_client_builder
             .indexes(Indexes::from(&self.index_locations))
+            .index_tls(self.index_locations.index_tls())
             .redirect(RedirectPolicy::RetriggerMiddleware);
 
         let client = builder.build();
@@ -259,6 +260,11 @@ impl RegistryClient {
         self.client.uncached().disable_ssl(url)
     }
 
+    /// Return the [`Cache`] used by the client.
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
     /// Return the [`Connectivity`] mode used by this client.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -373,7 +379,9 @@ impl RegistryClient {
             }
 
             // Otherwise, fetch concurrently.
-            IndexStrategy::UnsafeBestMatch | IndexStrategy::UnsafeFirstMatch => {
+            IndexStrategy::UnsafeBestMatch
+            | IndexStrategy::UnsafeFirstMatch
+            | IndexStrategy::MergeLatestCompatible => {
                 results = futures::stream::iter(indexes)
                     .map(async |index| {
                         let _permit = download_concurrency.acquire().await;
@@ -636,14 +644,23 @@ impl RegistryClient {
                         )
                     }
                     MediaType::PypiV1Json => {
+                        let headers = response.headers().clone();
                         let bytes = response
                             .bytes()
                             .await
                             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
 
-                        let data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
+                        let mut data: PypiSimpleDetail = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
 
+                        // If the response is paginated, fetch and merge the remaining pages.
+                        for page in self
+                            .fetch_next_pages::<PypiSimpleDetail>(&headers, &url, accept)
+                            .await?
+                        {
+                            data.files.extend(page.files);
+                        }
+
                         SimpleDetailMetadata::from_pypi_files(data.files, package_name, &url)
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
@@ -671,6 +688,51 @@ impl RegistryClient {
         Ok(simple)
     }
 
+    /// Fetch the remaining pages of a paginated Simple API JSON response.
+    ///
+    /// Pages are linked via `Link` headers with `rel="next"` (per RFC 8288), starting from the
+    /// headers of the first page. Each subsequent page is returned in order.
+    async fn fetch_next_pages<T: serde::de::DeserializeOwned>(
+        &self,
+        headers: &HeaderMap,
+        url: &DisplaySafeUrl,
+        accept: &str,
+    ) -> Result<Vec<T>, Error> {
+        let mut pages = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut next = next_page_url(headers, url);
+        while let Some(url) = next.take() {
+            // Guard against cycles in the pagination links.
+            if !visited.insert(url.clone()) {
+                warn!("Detected a cycle in the pagination links at: {url}");
+                break;
+            }
+
+            debug!("Fetching next page of Simple API response: {url}");
+            let response = self
+                .uncached_client(&url)
+                .get(Url::from(url.clone()))
+                .header("Accept-Encoding", "gzip, deflate, zstd")
+                .header("Accept", accept)
+                .send()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
+                .error_for_status()
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+
+            let url = DisplaySafeUrl::from_url(response.url().clone());
+            next = next_page_url(response.headers(), &url);
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let page: T = serde_json::from_slice(bytes.as_ref())
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+            pages.push(page);
+        }
+        Ok(pages)
+    }
+
     /// Fetch the [`SimpleDetailMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
     async fn fetch_local_simple_detail(
@@ -803,12 +865,22 @@ impl RegistryClient {
                         SimpleIndexMetadata::from_pyx_index(data)
                     }
                     MediaType::PypiV1Json => {
+                        let headers = response.headers().clone();
                         let bytes = response
                             .bytes()
                             .await
                             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
-                        let data: PypiSimpleIndex = serde_json::from_slice(bytes.as_ref())
+                        let mut data: PypiSimpleIndex = serde_json::from_slice(bytes.as_ref())
                             .map_err(|err| Error::from_json_err(err, url.clone()))?;
+
+                        // If the response is paginated, fetch and merge the remaining pages.
+                        for page in self
+                            .fetch_next_pages::<PypiSimpleIndex>(&headers, &url, accept)
+                            .await?
+                        {
+                            data.projects.extend(page.projects);
+                        }
+
                         SimpleIndexMetadata::from_pypi_index(data)
                     }
                     MediaType::PypiV1Html | MediaType::TextHtml => {
@@ -1513,6 +1585,35 @@ enum MediaType {
     TextHtml,
 }
 
+/// Return the URL of the next page from the `Link` headers of a response, if any.
+///
+/// Relative links are resolved against the `url` of the response.
+fn next_page_url(headers: &HeaderMap, url: &DisplaySafeUrl) -> Option<DisplaySafeUrl> {
+    headers
+        .get_all(http::header::LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let mut parts = link.split(';');
+            let target = parts.next()?.trim().strip_prefix('<')?.strip_suffix('>')?;
+            let is_next = parts.any(|param| {
+                param.split_once('=').is_some_and(|(key, value)| {
+                    key.trim().eq_ignore_ascii_case("rel")
+                        && value
+                            .trim()
+                            .trim_matches('"')
+                            .split_ascii_whitespace()
+                            .any(|rel| rel.eq_ignore_ascii_case("next"))
+                })
+            });
+            if !is_next {
+                return None;
+            }
+            url.join(target).ok()
+        })
+}
+
 impl MediaType {
     /// Parse a media type from a string, returning `None` if the media type is not supported.
     fn from_str(s: &str) -> Option<Self> {
@@ -1588,9 +1689,11 @@ mod tests {
 
     use crate::RegistryClientBuilder;
     use uv_cache::Cache;
-    use uv_distribution_types::{FileLocation, ToUrlError};
+    use uv_distribution_types::{FileLocation, IndexUrl, ToUrlError};
     use uv_small_str::SmallString;
-    use wiremock::matchers::{basic_auth, method, path_regex};
+    use wiremock::matchers::{
+        basic_auth, method, path, path_regex, query_param, query_param_is_missing,
+    };
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     type Error = Box<dyn std::error::Error>;
@@ -1862,4 +1965,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_simple_index_pagination() -> Result<(), Error> {
+        let server = MockServer::start().await;
+
+        // The first page links to the second page with a relative URL.
+        Mock::given(method("GET"))
+            .and(path("/simple/"))
+            .and(query_param_is_missing("page"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", r#"</simple/?page=2>; rel="next""#)
+                    .set_body_raw(
+                        r#"{"meta": {"api-version": "1.0"}, "projects": [{"name": "anyio"}, {"name": "flask"}]}"#,
+                        "application/vnd.pypi.simple.v1+json",
+                    ),
+            )
+            .mount(&server)
+            .await;
+
+        // The second page links back to itself, which should be ignored.
+        Mock::given(method("GET"))
+            .and(path("/simple/"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Link", r#"</simple/?page=2>; rel="next""#)
+                    .set_body_raw(
+                        r#"{"meta": {"api-version": "1.0"}, "projects": [{"name": "requests"}]}"#,
+                        "application/vnd.pypi.simple.v1+json",
+                    ),
+            )
+            .mount(&server)
+            .await;
+
+        let cache = Cache::temp()?;
+        let client = RegistryClientBuilder::new(BaseClientBuilder::default(), cache).build();
+        let index_url = IndexUrl::from_str(&format!("{}/simple", server.uri()))?;
+        let index = client.fetch_simple_index(&index_url).await?;
+
+        let projects = index.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(projects, vec!["anyio", "flask", "requests"]);
+
+        Ok(())
+    }
 }
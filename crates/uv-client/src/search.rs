@@ -0,0 +1,322 @@
+//! Search the projects available on an index.
+//!
+//! The project list is read from the root of the Simple API (e.g., `https://pypi.org/simple/`),
+//! while summaries are read from the JSON API (e.g., `https://pypi.org/pypi/<name>/json`), where
+//! available.
+
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use uv_cache::{CacheBucket, WheelCache};
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::cached_client::CacheControl;
+use crate::registry_client::Connectivity;
+use crate::{Error, ErrorKind, RegistryClient};
+
+/// A summary of a project, as reported by the JSON API of an index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    /// The latest version of the project.
+    pub version: String,
+    /// The one-line summary of the project.
+    pub summary: Option<String>,
+    /// The Python version requirement of the latest version.
+    pub requires_python: Option<String>,
+    /// The number of downloads in the last month, if reported by the index.
+    pub downloads: Option<u64>,
+}
+
+/// The subset of the JSON API response used for a [`ProjectSummary`].
+///
+/// <https://docs.pypi.org/api/json/#get-a-project>
+#[derive(Debug, Deserialize)]
+struct JsonProject {
+    info: JsonProjectInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonProjectInfo {
+    version: String,
+    summary: Option<String>,
+    requires_python: Option<String>,
+    downloads: Option<JsonDownloads>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonDownloads {
+    /// PyPI reports `-1` since download statistics were moved to `BigQuery`.
+    last_month: i64,
+}
+
+impl From<JsonProject> for ProjectSummary {
+    fn from(project: JsonProject) -> Self {
+        let JsonProjectInfo {
+            version,
+            summary,
+            requires_python,
+            downloads,
+        } = project.info;
+        Self {
+            version,
+            summary: summary.filter(|summary| !summary.trim().is_empty()),
+            requires_python: requires_python.filter(|specifiers| !specifiers.trim().is_empty()),
+            downloads: downloads.and_then(|downloads| u64::try_from(downloads.last_month).ok()),
+        }
+    }
+}
+
+impl RegistryClient {
+    /// Search the projects available on the given index, returning at most `limit` projects
+    /// that match the query, best matches first.
+    pub async fn search_projects(
+        &self,
+        index: &IndexUrl,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<PackageName>, Error> {
+        let projects = self.fetch_simple_index(index).await?;
+        Ok(rank_projects(projects.iter(), query, limit))
+    }
+
+    /// Fetch the [`ProjectSummary`] for a project from the JSON API of the given index.
+    ///
+    /// Returns `None` if the index doesn't follow the layout of PyPI's JSON API, i.e., if the
+    /// index URL doesn't end in `/simple`.
+    pub async fn project_summary(
+        &self,
+        index: &IndexUrl,
+        package_name: &PackageName,
+    ) -> Result<Option<ProjectSummary>, Error> {
+        let Some(url) = json_api_url(index.url(), package_name) else {
+            return Ok(None);
+        };
+
+        let cache_entry = self.cache().entry(
+            CacheBucket::Simple,
+            WheelCache::Index(index).root(),
+            format!("{package_name}.json-api.msgpack"),
+        );
+        let cache_control = match self.connectivity() {
+            Connectivity::Online => CacheControl::from(
+                self.cache()
+                    .freshness(&cache_entry, Some(package_name), None)
+                    .map_err(ErrorKind::Io)?,
+            ),
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
+
+        let parse_json_response = async |response: Response| {
+            let url = DisplaySafeUrl::from_url(response.url().clone());
+            let bytes = response
+                .bytes()
+                .await
+                .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+            let project: JsonProject = serde_json::from_slice(bytes.as_ref())
+                .map_err(|err| Error::from_json_err(err, url.clone()))?;
+            Ok::<_, Error>(ProjectSummary::from(project))
+        };
+
+        let request = self
+            .uncached_client(&url)
+            .get(Url::from(url.clone()))
+            .header("Accept", "application/json")
+            .build()
+            .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+
+        let summary = self
+            .cached_client()
+            .get_serde_with_retry(request, &cache_entry, cache_control, parse_json_response)
+            .await?;
+
+        Ok(Some(summary))
+    }
+}
+
+/// Derive the JSON API URL for a project from a Simple API index URL, e.g.,
+/// `https://pypi.org/simple` to `https://pypi.org/pypi/<name>/json`.
+fn json_api_url(index: &DisplaySafeUrl, package_name: &PackageName) -> Option<DisplaySafeUrl> {
+    let mut url = index.clone();
+    {
+        let mut segments = url.path_segments_mut().ok()?;
+        segments.pop_if_empty();
+    }
+    if url.path_segments()?.next_back()? != "simple" {
+        return None;
+    }
+    url.path_segments_mut()
+        .ok()?
+        .pop()
+        .extend(["pypi", package_name.as_str(), "json"]);
+    Some(url)
+}
+
+/// How closely a project name matches a search query, from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchKind {
+    /// The name is the query.
+    Exact,
+    /// The name starts with the query.
+    Prefix,
+    /// The name contains the query.
+    Substring,
+    /// The name contains all characters of the query, in order.
+    Subsequence,
+}
+
+/// Rank the given projects against the query, returning at most `limit` matching projects, best
+/// matches first.
+///
+/// Names are compared in their normalized form, so `Foo_Bar` matches `foo-bar`. Within the same
+/// kind of match, shorter names rank first.
+fn rank_projects<'a>(
+    projects: impl IntoIterator<Item = &'a PackageName>,
+    query: &str,
+    limit: usize,
+) -> Vec<PackageName> {
+    let query = normalize_query(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = projects
+        .into_iter()
+        .filter_map(|project| {
+            let kind = match_kind(project.as_str(), &query)?;
+            Some((kind, project))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_unstable_by(|(kind_a, project_a), (kind_b, project_b)| {
+        (kind_a, project_a.as_str().len(), project_a.as_str()).cmp(&(
+            kind_b,
+            project_b.as_str().len(),
+            project_b.as_str(),
+        ))
+    });
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, project)| project.clone())
+        .collect()
+}
+
+/// Normalize a query like a package name, i.e., lowercase with runs of `-`, `_`, and `.` replaced
+/// by a single `-`.
+fn normalize_query(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut separator = false;
+    for char in query.trim().chars() {
+        if matches!(char, '-' | '_' | '.') {
+            separator = true;
+            continue;
+        }
+        if separator && !normalized.is_empty() {
+            normalized.push('-');
+        }
+        separator = false;
+        normalized.extend(char.to_lowercase());
+    }
+    normalized
+}
+
+fn match_kind(name: &str, query: &str) -> Option<MatchKind> {
+    if name == query {
+        Some(MatchKind::Exact)
+    } else if name.starts_with(query) {
+        Some(MatchKind::Prefix)
+    } else if name.contains(query) {
+        Some(MatchKind::Substring)
+    } else {
+        let mut chars = name.chars();
+        query
+            .chars()
+            .all(|query_char| chars.any(|char| char == query_char))
+            .then_some(MatchKind::Subsequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn rank() {
+        let projects = [
+            "flask",
+            "flask-login",
+            "pyflakes",
+            "fastapi",
+            "flake8",
+            "django",
+        ]
+        .map(|name| PackageName::from_str(name).unwrap());
+
+        let ranked = rank_projects(&projects, "Flask", 10);
+        assert_eq!(
+            ranked.iter().map(PackageName::as_str).collect::<Vec<_>>(),
+            ["flask", "flask-login"]
+        );
+
+        let ranked = rank_projects(&projects, "fla", 10);
+        assert_eq!(
+            ranked.iter().map(PackageName::as_str).collect::<Vec<_>>(),
+            ["flake8", "flask", "flask-login", "pyflakes"]
+        );
+
+        let ranked = rank_projects(&projects, "dgo", 10);
+        assert_eq!(
+            ranked.iter().map(PackageName::as_str).collect::<Vec<_>>(),
+            ["django"]
+        );
+
+        let ranked = rank_projects(&projects, "flask_login", 10);
+        assert_eq!(
+            ranked.iter().map(PackageName::as_str).collect::<Vec<_>>(),
+            ["flask-login"]
+        );
+
+        assert!(rank_projects(&projects, "  ", 10).is_empty());
+        assert_eq!(rank_projects(&projects, "f", 2).len(), 2);
+    }
+
+    #[test]
+    fn json_api() {
+        let index = DisplaySafeUrl::parse("https://pypi.org/simple/").unwrap();
+        let name = PackageName::from_str("flask").unwrap();
+        assert_eq!(
+            json_api_url(&index, &name).unwrap().as_str(),
+            "https://pypi.org/pypi/flask/json"
+        );
+
+        let index = DisplaySafeUrl::parse("https://example.com/api/simple").unwrap();
+        assert_eq!(
+            json_api_url(&index, &name).unwrap().as_str(),
+            "https://example.com/api/pypi/flask/json"
+        );
+
+        let index = DisplaySafeUrl::parse("https://download.pytorch.org/whl/cpu").unwrap();
+        assert_eq!(json_api_url(&index, &name), None);
+    }
+
+    #[test]
+    fn summary() {
+        let project: JsonProject = serde_json::from_str(
+            r#"{"info": {"version": "3.0.0", "summary": "A simple framework", "requires_python": ">=3.8", "downloads": {"last_day": -1, "last_month": -1, "last_week": -1}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ProjectSummary::from(project),
+            ProjectSummary {
+                version: "3.0.0".to_string(),
+                summary: Some("A simple framework".to_string()),
+                requires_python: Some(">=3.8".to_string()),
+                downloads: None,
+            }
+        );
+    }
+}
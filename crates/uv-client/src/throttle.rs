@@ -0,0 +1,275 @@
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use http::{Extensions, StatusCode};
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::FxHashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+
+use uv_configuration::DownloadRate;
+
+/// The longest delay we'll honor from a `Retry-After` header.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The initial backoff after a `429 Too Many Requests` response without a `Retry-After` header.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The longest backoff after repeated `429 Too Many Requests` responses.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Limits on the rate and concurrency of requests, shared by all clients created from the same
+/// [`BaseClientBuilder`](crate::BaseClientBuilder).
+///
+/// Regardless of the configured limits, hosts that respond with `429 Too Many Requests` (or `503
+/// Service Unavailable` with a `Retry-After` header) are backed off, such that any subsequent
+/// requests to the host, including retries, are delayed.
+#[derive(Debug, Default)]
+pub struct Throttle {
+    /// The maximum download rate, across all requests.
+    rate: Option<RateLimiter>,
+    /// The maximum number of concurrent requests to a single host.
+    per_host: Option<NonZeroUsize>,
+    /// The state of each host, keyed by authority (e.g., `pypi.org:443`).
+    hosts: Mutex<FxHashMap<String, HostState>>,
+}
+
+#[derive(Debug, Default)]
+struct HostState {
+    /// The semaphore limiting the number of concurrent requests to the host.
+    semaphore: Option<Arc<Semaphore>>,
+    /// The instant before which no further requests should be sent to the host.
+    not_before: Option<Instant>,
+    /// The current backoff for responses that don't include a `Retry-After` header.
+    backoff: Option<Duration>,
+}
+
+impl Throttle {
+    /// Create a [`Throttle`] with the given download rate and per-host concurrency limits.
+    pub fn new(rate: Option<DownloadRate>, per_host: Option<NonZeroUsize>) -> Self {
+        Self {
+            rate: rate.map(RateLimiter::new),
+            per_host,
+            hosts: Mutex::default(),
+        }
+    }
+
+    /// Returns how long to wait before sending a request to the given host.
+    fn delay(&self, host: &str) -> Option<Duration> {
+        let hosts = self.hosts.lock().unwrap();
+        let not_before = hosts.get(host)?.not_before?;
+        let delay = not_before.saturating_duration_since(Instant::now());
+        (!delay.is_zero()).then_some(delay)
+    }
+
+    /// Returns the semaphore limiting concurrent requests to the given host, if any.
+    fn semaphore(&self, host: &str) -> Option<Arc<Semaphore>> {
+        let per_host = self.per_host?;
+        let mut hosts = self.hosts.lock().unwrap();
+        let state = hosts.entry(host.to_string()).or_default();
+        Some(
+            state
+                .semaphore
+                .get_or_insert_with(|| Arc::new(Semaphore::new(per_host.get())))
+                .clone(),
+        )
+    }
+
+    /// Update the backoff for the given host based on the response.
+    fn observe(&self, host: &str, response: &Response) {
+        let status = response.status();
+        let retry_after = retry_after(response);
+
+        let mut hosts = self.hosts.lock().unwrap();
+        if status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::SERVICE_UNAVAILABLE && retry_after.is_some())
+        {
+            let state = hosts.entry(host.to_string()).or_default();
+            let delay = if let Some(retry_after) = retry_after {
+                retry_after.min(MAX_RETRY_AFTER)
+            } else {
+                // Without guidance from the server, back off exponentially.
+                let backoff = state
+                    .backoff
+                    .map_or(INITIAL_BACKOFF, |backoff| (backoff * 2).min(MAX_BACKOFF));
+                state.backoff = Some(backoff);
+                backoff
+            };
+            debug!(
+                "Received {status} from {host}; delaying further requests by {:.1}s",
+                delay.as_secs_f32()
+            );
+            state.not_before = Some(Instant::now() + delay);
+        } else if status.is_success() {
+            if let Some(state) = hosts.get_mut(host) {
+                state.backoff = None;
+            }
+        }
+    }
+}
+
+/// Parse the `Retry-After` header of a response, as either a number of seconds or an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response
+        .headers()
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(value)
+        .ok()?;
+    Some(Duration::try_from(date.duration_since(jiff::Timestamp::now())).unwrap_or(Duration::ZERO))
+}
+
+/// A limiter for the number of bytes downloaded per second, across all requests.
+#[derive(Debug)]
+struct RateLimiter {
+    /// The number of bytes that may be downloaded per second.
+    bytes_per_second: u64,
+    /// The instant at which the bytes downloaded so far would have been downloaded at the
+    /// configured rate.
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(rate: DownloadRate) -> Self {
+        Self {
+            bytes_per_second: rate.bytes_per_second(),
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Record that `bytes` were downloaded, returning how long to wait to stay within the limit.
+    fn reserve(&self, bytes: usize) -> Duration {
+        #[allow(clippy::cast_precision_loss)]
+        let cost = Duration::from_secs_f64(bytes as f64 / self.bytes_per_second as f64);
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap();
+        // Don't allow idle time to accumulate into a burst.
+        *next = (*next).max(now) + cost;
+        next.saturating_duration_since(now)
+    }
+}
+
+/// A middleware that enforces the limits of a [`Throttle`].
+pub(crate) struct ThrottleMiddleware(Arc<Throttle>);
+
+impl ThrottleMiddleware {
+    pub(crate) fn new(throttle: Arc<Throttle>) -> Self {
+        Self(throttle)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for ThrottleMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let host = format!(
+            "{}:{}",
+            req.url().host_str().unwrap_or_default(),
+            req.url().port_or_known_default().unwrap_or_default()
+        );
+
+        // Respect any backoff requested by the host.
+        if let Some(delay) = self.0.delay(&host) {
+            debug!(
+                "Waiting {:.1}s before sending request to: {}",
+                delay.as_secs_f32(),
+                req.url()
+            );
+            tokio::time::sleep(delay).await;
+        }
+
+        // Limit the number of concurrent requests to the host.
+        let permit = if let Some(semaphore) = self.0.semaphore(&host) {
+            Some(
+                semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("throttle semaphores are never closed"),
+            )
+        } else {
+            None
+        };
+
+        let response = next.run(req, extensions).await?;
+        self.0.observe(&host, &response);
+
+        if permit.is_none() && self.0.rate.is_none() {
+            return Ok(response);
+        }
+
+        Ok(throttle_body(response, self.0.clone(), permit))
+    }
+}
+
+/// Wrap the body of a response, such that it's streamed at the configured download rate and
+/// holds the host's concurrency permit until it's fully consumed (or dropped).
+fn throttle_body(
+    response: Response,
+    throttle: Arc<Throttle>,
+    permit: Option<OwnedSemaphorePermit>,
+) -> Response {
+    // Converting via `http::Response` retains the URL of the response.
+    let response = http::Response::<reqwest::Body>::from(response);
+    let (parts, body) = response.into_parts();
+
+    let stream = http_body_util::BodyDataStream::new(body)
+        .then(move |chunk| {
+            let throttle = throttle.clone();
+            async move {
+                if let (Ok(bytes), Some(rate)) = (&chunk, &throttle.rate) {
+                    let delay = rate.reserve(bytes.len());
+                    if !delay.is_zero() {
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+                chunk
+            }
+        })
+        // Hold the permit until the body is consumed (or dropped).
+        .inspect(move |_| {
+            let _ = &permit;
+        });
+
+    Response::from(http::Response::from_parts(
+        parts,
+        reqwest::Body::wrap_stream(stream),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use uv_configuration::DownloadRate;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn rate_limiter() {
+        let rate = DownloadRate::from_str("1000").unwrap();
+        let limiter = RateLimiter::new(rate);
+
+        // The first second's worth of bytes is delayed by up to a second.
+        let delay = limiter.reserve(1000);
+        assert!(delay <= Duration::from_secs(1));
+
+        // Subsequent bytes accumulate.
+        let delay = limiter.reserve(1000);
+        assert!(delay > Duration::from_secs(1));
+        assert!(delay <= Duration::from_secs(2));
+    }
+}
@@ -1,6 +1,7 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::Path;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum CertificateError {
@@ -19,3 +20,25 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Identity` from a PEM-encoded certificate and a separate PEM-encoded private key.
+pub(crate) fn read_identity_with_key(
+    client_cert: &Path,
+    client_key: &Path,
+) -> Result<Identity, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(client_cert)?.read_to_end(&mut buf)?;
+    buf.push(b'\n');
+    fs_err::File::open(client_key)?.read_to_end(&mut buf)?;
+    Identity::from_pem(&buf).map_err(|tls_err| {
+        debug_assert!(tls_err.is_builder(), "must be a rustls::Error internally");
+        CertificateError::Reqwest(tls_err)
+    })
+}
+
+/// Return the certificates from the provided PEM-encoded CA bundle.
+pub(crate) fn read_ca_bundle(ca_bundle: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ca_bundle)?.read_to_end(&mut buf)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
@@ -11,6 +11,8 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use the system's native credential store for credential lookup.
+    Native,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -23,6 +25,7 @@ impl KeyringProviderType {
         match self {
             Self::Disabled => None,
             Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Native => Some(KeyringProvider::native()),
         }
     }
 }
@@ -32,6 +35,7 @@ impl std::fmt::Display for KeyringProviderType {
         match self {
             Self::Disabled => write!(f, "disabled"),
             Self::Subprocess => write!(f, "subprocess"),
+            Self::Native => write!(f, "native"),
         }
     }
 }
@@ -351,6 +351,20 @@ pub enum IndexStrategy {
     ///
     /// See: <https://peps.python.org/pep-0708/>
     UnsafeBestMatch,
+    /// Search for every package name across all indexes, preferring the "best" version found. If a
+    /// package version is in multiple indexes, prefer the index with the most wheels that are
+    /// compatible with the target platform.
+    ///
+    /// Like `unsafe-best-match`, but intended for mirrors that only carry a subset of the wheels
+    /// for a given version: rather than always using the entry from the first index, each version
+    /// is taken from the index that provides the most compatible wheels, falling back to the
+    /// first index in the case of a tie.
+    ///
+    /// Like `unsafe-best-match`, this strategy exposes the resolver to "dependency confusion"
+    /// attacks.
+    ///
+    /// See: <https://peps.python.org/pep-0708/>
+    MergeLatestCompatible,
 }
 
 #[cfg(test)]
@@ -153,6 +153,14 @@ impl DependencyGroups {
             prev: self.clone(),
         }
     }
+
+    /// Include an additional group in a base [`DependencyGroups`], as if by `--group`.
+    #[must_use]
+    pub fn with_group(&self, group: GroupName) -> Self {
+        let mut history = self.0.history.clone();
+        history.group.push(group);
+        Self::from_history(history)
+    }
 }
 
 impl std::ops::Deref for DependencyGroups {
@@ -0,0 +1,133 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::num::NonZeroU64;
+use std::str::FromStr;
+
+/// A maximum download rate, in bytes per second (e.g. `500K`, `10M`, or `1G`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DownloadRate(NonZeroU64);
+
+impl DownloadRate {
+    /// Return the rate in bytes per second.
+    pub fn bytes_per_second(self) -> u64 {
+        self.0.get()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadRateError {
+    #[error(
+        "Invalid download rate `{0}`; expected a number of bytes per second with an optional unit (e.g., `500K`, `10M`, or `1G`)"
+    )]
+    Invalid(String),
+    #[error("The download rate must be greater than zero")]
+    Zero,
+}
+
+impl FromStr for DownloadRate {
+    type Err = DownloadRateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let trimmed = trimmed.strip_suffix("/s").unwrap_or(trimmed);
+
+        // Split into the numeric value and the unit, e.g., `10` and `MB`.
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (value, unit) = trimmed.split_at(split);
+        let value = value
+            .parse::<f64>()
+            .map_err(|_| DownloadRateError::Invalid(s.to_string()))?;
+
+        let multiplier: u32 = match unit.trim().to_ascii_uppercase().as_str() {
+            "" | "B" => 1,
+            "K" | "KB" => 1_000,
+            "KI" | "KIB" => 1 << 10,
+            "M" | "MB" => 1_000_000,
+            "MI" | "MIB" => 1 << 20,
+            "G" | "GB" => 1_000_000_000,
+            "GI" | "GIB" => 1 << 30,
+            _ => return Err(DownloadRateError::Invalid(s.to_string())),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bytes = (value * f64::from(multiplier)) as u64;
+        NonZeroU64::new(bytes)
+            .map(Self)
+            .ok_or(DownloadRateError::Zero)
+    }
+}
+
+impl std::fmt::Display for DownloadRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DownloadRate {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("DownloadRate")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A download rate in bytes per second, with an optional unit, e.g. `500K`, `10M`, or `1G`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DownloadRate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = DownloadRate;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string or an integer")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                DownloadRate::from_str(v).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                NonZeroU64::new(v)
+                    .map(DownloadRate)
+                    .ok_or_else(|| serde::de::Error::custom(DownloadRateError::Zero))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .map_err(|_| serde::de::Error::custom(DownloadRateError::Zero))
+                    .and_then(|v| self.visit_u64(v))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::DownloadRate;
+
+    #[test]
+    fn parse() {
+        let rate = |s: &str| DownloadRate::from_str(s).map(DownloadRate::bytes_per_second);
+        assert_eq!(rate("1024").unwrap(), 1024);
+        assert_eq!(rate("500K").unwrap(), 500_000);
+        assert_eq!(rate("10MB").unwrap(), 10_000_000);
+        assert_eq!(rate("10 MiB/s").unwrap(), 10 * 1024 * 1024);
+        assert_eq!(rate("1.5g").unwrap(), 1_500_000_000);
+        assert!(rate("0").is_err());
+        assert!(rate("fast").is_err());
+        assert!(rate("10 TB").is_err());
+    }
+}
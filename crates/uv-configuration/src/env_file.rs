@@ -44,6 +44,22 @@ impl EnvFile {
         Self(paths)
     }
 
+    /// Parse the env file paths from command-line arguments, layered on top of the env files
+    /// configured in the project settings.
+    pub fn from_settings(
+        env_file: Vec<String>,
+        no_env_file: bool,
+        env_files: Option<Vec<PathBuf>>,
+    ) -> Self {
+        if no_env_file {
+            return Self::default();
+        }
+
+        let mut paths = env_files.unwrap_or_default();
+        paths.extend(Self::from_args(env_file, false).0);
+        Self(paths)
+    }
+
     /// Iterate over the paths in the env file.
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &PathBuf> {
         self.0.iter()
@@ -123,6 +139,33 @@ mod tests {
         assert_eq!(env_file.0, vec![PathBuf::from(r"path\with\backslashes")]);
     }
 
+    #[test]
+    fn test_from_settings_layering() {
+        let env_file = EnvFile::from_settings(
+            vec!["path2 path3".to_string()],
+            false,
+            Some(vec![PathBuf::from("path1")]),
+        );
+        assert_eq!(
+            env_file.0,
+            vec![
+                PathBuf::from("path1"),
+                PathBuf::from("path2"),
+                PathBuf::from("path3")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_settings_no_env_file() {
+        let env_file = EnvFile::from_settings(
+            vec!["path2".to_string()],
+            true,
+            Some(vec![PathBuf::from("path1")]),
+        );
+        assert_eq!(env_file, EnvFile::default());
+    }
+
     #[test]
     fn test_iter() {
         let env_file = EnvFile(vec![PathBuf::from("path1"), PathBuf::from("path2")]);
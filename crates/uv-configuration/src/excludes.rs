@@ -1,25 +1,60 @@
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 
 /// A set of packages to exclude from resolution.
 #[derive(Debug, Default, Clone)]
-pub struct Excludes(FxHashSet<PackageName>);
+pub struct Excludes {
+    names: FxHashSet<PackageName>,
+    provided: FxHashMap<PackageName, Version>,
+}
 
 impl Excludes {
+    /// Mark the given packages as provided by the environment.
+    ///
+    /// Provided packages are excluded from resolution. If a version is given, the resolver will
+    /// additionally require that all dependencies on the package are compatible with it.
+    #[must_use]
+    pub fn with_provided(
+        mut self,
+        provided: impl IntoIterator<Item = (PackageName, Option<Version>)>,
+    ) -> Self {
+        for (name, version) in provided {
+            if let Some(version) = version {
+                self.provided.insert(name.clone(), version);
+            }
+            self.names.insert(name);
+        }
+        self
+    }
+
     /// Return an iterator over all package names in the exclusion set.
     pub fn iter(&self) -> impl Iterator<Item = &PackageName> {
-        self.0.iter()
+        self.names.iter()
     }
 
     /// Check if a package is excluded.
     pub fn contains(&self, name: &PackageName) -> bool {
-        self.0.contains(name)
+        self.names.contains(name)
+    }
+
+    /// Return an iterator over the packages that are provided at a known version.
+    pub fn provided(&self) -> impl Iterator<Item = (&PackageName, &Version)> {
+        self.provided.iter()
+    }
+
+    /// Return the version at which a package is provided, if known.
+    pub fn provided_version(&self, name: &PackageName) -> Option<&Version> {
+        self.provided.get(name)
     }
 }
 
 impl FromIterator<PackageName> for Excludes {
     fn from_iter<I: IntoIterator<Item = PackageName>>(iter: I) -> Self {
-        Self(iter.into_iter().collect())
+        Self {
+            names: iter.into_iter().collect(),
+            provided: FxHashMap::default(),
+        }
     }
 }
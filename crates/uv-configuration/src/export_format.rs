@@ -22,6 +22,10 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export a multi-stage `Dockerfile` snippet that installs the project with `uv sync`.
+    #[serde(rename = "dockerfile-snippet")]
+    #[cfg_attr(feature = "clap", clap(name = "dockerfile-snippet"))]
+    DockerfileSnippet,
 }
 
 /// The output format to use in `uv pip compile`.
@@ -3,6 +3,7 @@ pub use build_options::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
+pub use download_rate::*;
 pub use dry_run::*;
 pub use editable::*;
 pub use env_file::*;
@@ -28,6 +29,7 @@ mod build_options;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
+mod download_rate;
 mod dry_run;
 mod editable;
 mod env_file;
@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::str::FromStr;
 
 use either::Either;
 use rustc_hash::FxHashMap;
@@ -7,6 +8,7 @@ use uv_cache::Refresh;
 use uv_cache_info::Timestamp;
 use uv_distribution_types::Requirement;
 use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers, VersionSpecifiersParseError};
 
 /// Whether to reinstall packages.
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
@@ -126,7 +128,7 @@ impl From<Reinstall> for Refresh {
             Reinstall::None => Self::None(Timestamp::now()),
             Reinstall::All => Self::All(Timestamp::now()),
             Reinstall::Packages(packages, paths) => {
-                Self::Packages(packages, paths, Timestamp::now())
+                Self::Packages(packages, paths, Vec::new(), Timestamp::now())
             }
         }
     }
@@ -234,6 +236,50 @@ impl Upgrade {
     }
 }
 
+/// The target of a single-package upgrade (e.g., `--to 2.1`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeTarget {
+    /// Upgrade to an exact version, e.g., `2.1`.
+    Version(Version),
+    /// Upgrade to a version within a range, e.g., `>=2.1,<3`.
+    Specifiers(VersionSpecifiers),
+}
+
+impl UpgradeTarget {
+    /// The specifiers to constrain the resolution with.
+    pub fn specifiers(&self) -> VersionSpecifiers {
+        match self {
+            Self::Version(version) => {
+                VersionSpecifiers::from(VersionSpecifier::equals_version(version.clone()))
+            }
+            Self::Specifiers(specifiers) => specifiers.clone(),
+        }
+    }
+
+    /// The specifiers to write to the requirement in the `pyproject.toml`.
+    ///
+    /// An exact version is written as a lower bound, as with `uv add`.
+    pub fn requirement_specifiers(&self) -> VersionSpecifiers {
+        match self {
+            Self::Version(version) => VersionSpecifiers::from(
+                VersionSpecifier::greater_than_equal_version(version.clone()),
+            ),
+            Self::Specifiers(specifiers) => specifiers.clone(),
+        }
+    }
+}
+
+impl FromStr for UpgradeTarget {
+    type Err = VersionSpecifiersParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(version) = Version::from_str(s.trim()) {
+            return Ok(Self::Version(version));
+        }
+        VersionSpecifiers::from_str(s).map(Self::Specifiers)
+    }
+}
+
 /// Create a [`Refresh`] policy by integrating the [`Upgrade`] policy.
 impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
@@ -243,6 +289,7 @@ impl From<Upgrade> for Refresh {
             Upgrade::Packages(packages) => Self::Packages(
                 packages.into_keys().collect::<Vec<_>>(),
                 Vec::new(),
+                Vec::new(),
                 Timestamp::now(),
             ),
         }
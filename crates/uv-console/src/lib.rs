@@ -297,6 +297,92 @@ pub fn input(prompt: &str, term: &Term) -> std::io::Result<String> {
     Ok(input)
 }
 
+/// Prompt the user to select one of the given items in the given [`Term`].
+///
+/// Returns `None` if the user cancels the selection with `Esc` or `q`.
+///
+/// This is a slimmed-down version of `dialoguer::Select`.
+pub fn select(message: &str, items: &[String], term: &Term) -> std::io::Result<Option<usize>> {
+    if items.is_empty() {
+        return Ok(None);
+    }
+
+    let prompt = format!(
+        "{} {} {}",
+        style("?".to_string()).for_stderr().yellow(),
+        style(message).for_stderr().bold(),
+        style("[↑/↓, enter to select, esc to cancel]")
+            .for_stderr()
+            .black()
+            .bright(),
+    );
+
+    term.hide_cursor()?;
+    let mut position = 0;
+    let response = loop {
+        term.write_line(&prompt)?;
+        for (index, item) in items.iter().enumerate() {
+            if index == position {
+                term.write_line(&format!(
+                    "{} {}",
+                    style("❯").for_stderr().cyan(),
+                    style(item).for_stderr().cyan()
+                ))?;
+            } else {
+                term.write_line(&format!("  {item}"))?;
+            }
+        }
+        term.flush()?;
+
+        let response = match term.read_key_raw()? {
+            Key::ArrowUp | Key::Char('k') => {
+                position = position.checked_sub(1).unwrap_or(items.len() - 1);
+                None
+            }
+            Key::ArrowDown | Key::Char('j') => {
+                position = (position + 1) % items.len();
+                None
+            }
+            Key::Enter => Some(Some(position)),
+            Key::Escape | Key::Char('q') => Some(None),
+            Key::CtrlC => {
+                let term = Term::stderr();
+                term.show_cursor()?;
+                term.write_str("\n")?;
+                term.flush()?;
+
+                #[allow(clippy::exit, clippy::cast_possible_wrap)]
+                std::process::exit(if cfg!(windows) {
+                    0xC000_013A_u32 as i32
+                } else {
+                    130
+                });
+            }
+            _ => None,
+        };
+
+        term.clear_last_lines(items.len() + 1)?;
+        if let Some(response) = response {
+            break response;
+        }
+    };
+
+    if let Some(position) = response {
+        let report = format!(
+            "{} {} {} {}",
+            style("✔".to_string()).for_stderr().green(),
+            style(message).for_stderr().bold(),
+            style("·").for_stderr().black().bright(),
+            style(&items[position]).for_stderr().cyan(),
+        );
+        term.write_line(&report)?;
+    }
+    term.show_cursor()?;
+    term.flush()?;
+
+    Ok(response)
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size (binary units).
 ///
 /// Returns a tuple of `(quantity, units)`.
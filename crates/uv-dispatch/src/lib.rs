@@ -12,7 +12,7 @@ use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{debug, instrument, trace};
 
-use uv_build_backend::check_direct_build;
+use uv_build_backend::{TrustedBuild, check_direct_build};
 use uv_build_frontend::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
@@ -25,11 +25,11 @@ use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires, ExtraBuildVariables,
     Identifier, IndexCapabilities, IndexLocations, IsBuildBackendError, Name,
-    PackageConfigSettings, Requirement, Resolution, SourceDist, VersionOrUrlRef,
+    PackageConfigSettings, Patches, Requirement, Resolution, SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_installer::{InstallationStrategy, Installer, Plan, Planner, Preparer, SitePackages};
-use uv_preview::Preview;
+use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::Conflicts;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_resolver::{
@@ -99,6 +99,7 @@ pub struct BuildDispatch<'a> {
     exclude_newer: ExcludeNewer,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    patches: Patches,
     sources: SourceStrategy,
     workspace_cache: WorkspaceCache,
     concurrency: Concurrency,
@@ -151,6 +152,7 @@ impl<'a> BuildDispatch<'a> {
             exclude_newer,
             source_build_context: SourceBuildContext::default(),
             build_extra_env_vars: FxHashMap::default(),
+            patches: Patches::default(),
             sources,
             workspace_cache,
             concurrency,
@@ -172,6 +174,13 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the patches to apply to source distributions before building.
+    #[must_use]
+    pub fn with_patches(mut self, patches: Patches) -> Self {
+        self.patches = patches;
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -238,6 +247,10 @@ impl BuildContext for BuildDispatch<'_> {
         self.extra_build_variables
     }
 
+    fn patches(&self) -> &Patches {
+        &self.patches
+    }
+
     async fn resolve<'data>(
         &'data self,
         requirements: &'data [Requirement],
@@ -325,6 +338,7 @@ impl BuildContext for BuildDispatch<'_> {
             self.config_settings_package,
             self.extra_build_requires(),
             self.extra_build_variables,
+            &self.patches,
             self.cache(),
             venv,
             tags,
@@ -513,18 +527,40 @@ impl BuildContext for BuildDispatch<'_> {
             source.to_path_buf()
         };
 
-        // Only perform the direct build if the backend is uv in a compatible version.
+        // Only perform the direct build if the backend is uv in a compatible version, or, in
+        // preview, if the backend is trusted and the project is simple enough to build directly.
         let source_tree_str = source_tree.display().to_string();
         let identifier = version_id.unwrap_or_else(|| &source_tree_str);
-        if !check_direct_build(&source_tree, identifier) {
+        let trusted = if check_direct_build(&source_tree, identifier) {
+            debug!("Performing direct build for {identifier}");
+            None
+        } else if self.preview.is_enabled(PreviewFeatures::TRUSTED_BACKENDS)
+            && build_kind != BuildKind::Sdist
+        {
+            let Some(trusted) = TrustedBuild::check(&source_tree, identifier) else {
+                return Ok(None);
+            };
+            debug!("Performing trusted direct build for {identifier}");
+            Some(trusted)
+        } else {
             trace!("Requirements for direct build not matched: {identifier}");
             return Ok(None);
-        }
-
-        debug!("Performing direct build for {identifier}");
+        };
 
         let output_dir = output_dir.to_path_buf();
         let filename = tokio::task::spawn_blocking(move || -> Result<_> {
+            if let Some(trusted) = trusted {
+                let wheel = match build_kind {
+                    BuildKind::Wheel => {
+                        trusted.build_wheel(&source_tree, &output_dir, uv_version::version())?
+                    }
+                    BuildKind::Editable => {
+                        trusted.build_editable(&source_tree, &output_dir, uv_version::version())?
+                    }
+                    BuildKind::Sdist => unreachable!("Trusted direct builds exclude sdists"),
+                };
+                return Ok(DistFilename::WheelFilename(wheel));
+            }
             let filename = match build_kind {
                 BuildKind::Wheel => {
                     let wheel = uv_build_backend::build_wheel(
@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use uv_platform_tags::{
     AbiTag, LanguageTag, ParseAbiTagError, ParseLanguageTagError, ParsePlatformTagError,
-    PlatformTag, TagCompatibility, Tags,
+    PlatformTag, TagCompatibility, TagMatch, Tags,
 };
 
 use crate::splitter::MemchrSplitter;
@@ -63,6 +63,16 @@ impl ExpandedTags {
             self.platform_tags().cloned().collect::<Vec<_>>().as_slice(),
         )
     }
+
+    /// Return the most-compatible [`TagMatch`] of the wheel with the given tags, or `None` if the
+    /// wheel is incompatible.
+    pub fn best_match(&self, compatible_tags: &Tags) -> Option<TagMatch> {
+        compatible_tags.best_match(
+            self.python_tags().copied().collect::<Vec<_>>().as_slice(),
+            self.abi_tags().copied().collect::<Vec<_>>().as_slice(),
+            self.platform_tags().cloned().collect::<Vec<_>>().as_slice(),
+        )
+    }
 }
 
 #[derive(Error, Debug)]
@@ -212,6 +222,8 @@ mod tests {
                         },
                         abi_tag: CPython {
                             gil_disabled: false,
+                            debug: false,
+                            pymalloc: false,
                             python_version: (
                                 3,
                                 9,
@@ -251,6 +263,8 @@ mod tests {
                         abi_tag: [
                             CPython {
                                 gil_disabled: false,
+                                debug: false,
+                                pymalloc: false,
                                 python_version: (
                                     3,
                                     12,
@@ -424,6 +438,8 @@ mod tests {
                 abi_tag: [
                     CPython {
                         gil_disabled: false,
+                        debug: false,
+                        pymalloc: false,
                         python_version: (
                             3,
                             9,
@@ -431,6 +447,8 @@ mod tests {
                     },
                     CPython {
                         gil_disabled: false,
+                        debug: false,
+                        pymalloc: false,
                         python_version: (
                             3,
                             10,
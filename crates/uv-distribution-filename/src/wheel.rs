@@ -11,7 +11,7 @@ use uv_normalize::{InvalidNameError, PackageName};
 use uv_pep440::{Version, VersionParseError};
 use uv_platform_tags::{
     AbiTag, LanguageTag, ParseAbiTagError, ParseLanguageTagError, ParsePlatformTagError,
-    PlatformTag, TagCompatibility, Tags,
+    PlatformTag, TagCompatibility, TagMatch, Tags,
 };
 
 use crate::splitter::MemchrSplitter;
@@ -95,6 +95,12 @@ impl WheelFilename {
         compatible_tags.compatibility(self.python_tags(), self.abi_tags(), self.platform_tags())
     }
 
+    /// Return the most-compatible [`TagMatch`] of the wheel with the given tags, or `None` if the
+    /// wheel is incompatible.
+    pub fn best_match(&self, compatible_tags: &Tags) -> Option<TagMatch> {
+        compatible_tags.best_match(self.python_tags(), self.abi_tags(), self.platform_tags())
+    }
+
     /// The wheel filename without the extension.
     pub fn stem(&self) -> String {
         format!(
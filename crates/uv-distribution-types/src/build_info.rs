@@ -1,6 +1,6 @@
 use uv_cache_key::{CacheKey, CacheKeyHasher, cache_digest};
 
-use crate::{BuildVariables, ConfigSettings, ExtraBuildRequirement};
+use crate::{BuildVariables, ConfigSettings, ExtraBuildRequirement, Patch};
 
 /// A digest representing the build settings, such as build dependencies or other build-time
 /// configuration.
@@ -12,6 +12,8 @@ pub struct BuildInfo {
     extra_build_requires: Vec<ExtraBuildRequirement>,
     #[serde(default, skip_serializing_if = "BuildVariables::is_empty")]
     extra_build_variables: BuildVariables,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    patches: Vec<String>,
 }
 
 impl CacheKey for BuildInfo {
@@ -19,21 +21,27 @@ impl CacheKey for BuildInfo {
         self.config_settings.cache_key(state);
         self.extra_build_requires.cache_key(state);
         self.extra_build_variables.cache_key(state);
+        self.patches.cache_key(state);
     }
 }
 
 impl BuildInfo {
     /// Creates a [`BuildInfo`] instance with the given configuration settings, extra build
-    /// dependencies, and extra build variables.
-    pub fn from_settings(
+    /// dependencies, extra build variables, and patches.
+    pub fn from_settings<'a>(
         config_settings: &ConfigSettings,
         extra_build_dependencies: &[ExtraBuildRequirement],
         extra_build_variables: Option<&BuildVariables>,
+        patches: impl IntoIterator<Item = &'a Patch>,
     ) -> Self {
         Self {
             config_settings: config_settings.clone(),
             extra_build_requires: extra_build_dependencies.to_vec(),
             extra_build_variables: extra_build_variables.cloned().unwrap_or_default(),
+            patches: patches
+                .into_iter()
+                .map(|patch| patch.hash.to_string())
+                .collect(),
         }
     }
 
@@ -42,6 +50,12 @@ impl BuildInfo {
         self.config_settings.is_empty()
             && self.extra_build_requires.is_empty()
             && self.extra_build_variables.is_empty()
+            && self.patches.is_empty()
+    }
+
+    /// Returns `true` if the [`BuildInfo`] includes any patches.
+    pub fn has_patches(&self) -> bool {
+        !self.patches.is_empty()
     }
 
     /// Return the cache shard for this [`BuildInfo`].
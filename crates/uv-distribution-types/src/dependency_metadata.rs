@@ -26,17 +26,35 @@ impl DependencyMetadata {
         package: &PackageName,
         version: Option<&Version>,
     ) -> Option<ResolutionMetadata> {
-        let versions = self.0.get(package)?;
+        // Patch entries are applied on top of the upstream metadata, rather than replacing it.
+        let versions = self
+            .0
+            .get(package)?
+            .iter()
+            .filter(|entry| !entry.is_patch())
+            .collect::<Vec<_>>();
+        if versions.is_empty() {
+            return None;
+        }
 
         if let Some(version) = version {
-            // If a specific version was requested, search for an exact match, then a global match.
+            // If a specific version was requested, search for an exact match, then a range match,
+            // then a global match.
             let metadata = if let Some(metadata) = versions
                 .iter()
                 .find(|entry| entry.version.as_ref() == Some(version))
             {
                 debug!("Found dependency metadata entry for `{package}=={version}`");
                 metadata
-            } else if let Some(metadata) = versions.iter().find(|entry| entry.version.is_none()) {
+            } else if let Some(metadata) = versions.iter().find(|entry| {
+                entry
+                    .versions
+                    .as_ref()
+                    .is_some_and(|versions| versions.contains(version))
+            }) {
+                debug!("Found dependency metadata entry for `{package}` matching `{version}`");
+                metadata
+            } else if let Some(metadata) = versions.iter().find(|entry| entry.is_global()) {
                 debug!("Found global metadata entry for `{package}`");
                 metadata
             } else {
@@ -76,6 +94,20 @@ impl DependencyMetadata {
         }
     }
 
+    /// Retrieve the [`StaticMetadata`] entries that patch the upstream metadata for the given
+    /// [`PackageName`] and [`Version`].
+    pub fn patches<'a>(
+        &'a self,
+        package: &PackageName,
+        version: &'a Version,
+    ) -> impl Iterator<Item = &'a StaticMetadata> + 'a {
+        self.0
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry.is_patch() && entry.applies_to(version))
+    }
+
     /// Retrieve all [`StaticMetadata`] entries.
     pub fn values(&self) -> impl Iterator<Item = &StaticMetadata> {
         self.0.values().flatten()
@@ -98,6 +130,15 @@ pub struct StaticMetadata {
         )
     )]
     pub version: Option<Version>,
+    #[serde(default)]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<String>",
+            description = "PEP 440-style version specifiers, e.g., `>=1.0,<2.0`"
+        )
+    )]
+    pub versions: Option<VersionSpecifiers>,
     // Optional fields
     #[serde(default)]
     pub requires_dist: Box<[Requirement<VerbatimParsedUrl>]>,
@@ -111,4 +152,78 @@ pub struct StaticMetadata {
     pub requires_python: Option<VersionSpecifiers>,
     #[serde(default, alias = "provides-extras")]
     pub provides_extra: Box<[ExtraName]>,
+    #[serde(default)]
+    pub add_requires_dist: Box<[Requirement<VerbatimParsedUrl>]>,
+    #[serde(default)]
+    pub remove_requires_dist: Box<[PackageName]>,
+}
+
+impl StaticMetadata {
+    /// Returns `true` if the entry patches the upstream metadata, rather than replacing it.
+    pub fn is_patch(&self) -> bool {
+        !self.add_requires_dist.is_empty() || !self.remove_requires_dist.is_empty()
+    }
+
+    /// Returns `true` if the entry applies to all versions of the package.
+    fn is_global(&self) -> bool {
+        self.version.is_none() && self.versions.is_none()
+    }
+
+    /// Returns `true` if the entry applies to the given [`Version`].
+    fn applies_to(&self, version: &Version) -> bool {
+        if let Some(expected) = self.version.as_ref() {
+            return expected == version;
+        }
+        if let Some(versions) = self.versions.as_ref() {
+            return versions.contains(version);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn version_ranges() {
+        #[derive(Deserialize)]
+        struct Entries {
+            entries: Vec<StaticMetadata>,
+        }
+
+        let Entries { entries } = toml::from_str(
+            r#"
+            entries = [
+                { name = "foo", version = "1.0.0", requires-dist = ["a"] },
+                { name = "foo", versions = ">=1.0,<2.0", requires-dist = ["b"] },
+                { name = "foo", requires-dist = ["c"] },
+                { name = "foo", versions = "<1.5", add-requires-dist = ["d"], remove-requires-dist = ["e"] },
+            ]
+            "#,
+        )
+        .unwrap();
+        let metadata = DependencyMetadata::from_entries(entries);
+        let foo = PackageName::from_str("foo").unwrap();
+
+        let requires_dist = |version: &str| {
+            metadata
+                .get(&foo, Some(&Version::from_str(version).unwrap()))
+                .unwrap()
+                .requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(requires_dist("1.0.0"), vec!["a"]);
+        assert_eq!(requires_dist("1.2.0"), vec!["b"]);
+        assert_eq!(requires_dist("2.0.0"), vec!["c"]);
+
+        let version = Version::from_str("1.2.0").unwrap();
+        assert_eq!(metadata.patches(&foo, &version).count(), 1);
+        let version = Version::from_str("1.5.0").unwrap();
+        assert_eq!(metadata.patches(&foo, &version).count(), 0);
+    }
 }
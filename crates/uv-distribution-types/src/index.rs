@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,9 @@ use uv_small_str::SmallString;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
-use crate::{IndexStatusCodeStrategy, IndexUrl, IndexUrlError, SerializableStatusCode};
+use crate::{
+    IndexStatusCodeStrategy, IndexUrl, IndexUrlError, PackageNamePattern, SerializableStatusCode,
+};
 
 /// Cache control configuration for an index.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Default)]
@@ -48,6 +50,39 @@ impl IndexCacheControl {
     }
 }
 
+/// TLS configuration for an index.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct IndexTls {
+    /// Path to a PEM-encoded client certificate to present to the index (for mutual TLS).
+    ///
+    /// If `client-key` is omitted, the file must contain both the certificate and the private key.
+    pub client_cert: Option<PathBuf>,
+    /// Path to the PEM-encoded private key for `client-cert`.
+    pub client_key: Option<PathBuf>,
+    /// Path to a PEM-encoded bundle of CA certificates to trust when connecting to the index.
+    pub ca_bundle: Option<PathBuf>,
+    /// Whether to trust the system (or built-in) root certificates when connecting to the index.
+    ///
+    /// Defaults to `true`. Set to `false` to trust only the certificates in `ca-bundle`.
+    pub system_trust: Option<bool>,
+}
+
+impl IndexTls {
+    /// Resolve any relative paths against the given root directory.
+    #[must_use]
+    pub fn relative_to(self, root_dir: &Path) -> Self {
+        let resolve = |path: Option<PathBuf>| path.map(|path| root_dir.join(path));
+        Self {
+            client_cert: resolve(self.client_cert),
+            client_key: resolve(self.client_key),
+            ca_bundle: resolve(self.ca_bundle),
+            system_trust: self.system_trust,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -154,6 +189,42 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub cache_control: Option<IndexCacheControl>,
+    /// TLS configuration for this index.
+    ///
+    /// Allows presenting a client certificate (for mutual TLS), and trusting a custom CA bundle
+    /// when connecting to the index's host, without affecting requests to other hosts. Relative
+    /// paths are resolved relative to the configuration file.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.mycorp.com/simple"
+    /// tls = { client-cert = "certs/client.pem", client-key = "certs/client.key", ca-bundle = "certs/ca.pem" }
+    /// ```
+    #[serde(default)]
+    pub tls: Option<IndexTls>,
+    /// Restrict packages matching the given patterns to this index.
+    ///
+    /// Packages whose names match any of the patterns will _only_ be resolved from this index,
+    /// including when they're required transitively, and will never be looked up on any other
+    /// index (e.g., PyPI). This protects against "dependency confusion", in which a package on a
+    /// public index shadows the name of a private package.
+    ///
+    /// Patterns are matched against normalized package names, and may include `*` to match any
+    /// sequence of characters or `?` to match a single character, such that an organization's
+    /// packages can be claimed by prefix:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.mycorp.com/simple"
+    /// packages = ["mycorp-*", "internal-tool"]
+    /// ```
+    ///
+    /// If a package matches the patterns of multiple indexes, the first index takes precedence.
+    /// Packages pinned to an index in `[tool.uv.sources]` are unaffected.
+    #[serde(default)]
+    pub packages: Vec<PackageNamePattern>,
 }
 
 impl PartialEq for Index {
@@ -169,6 +240,8 @@ impl PartialEq for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            tls,
+            packages,
         } = self;
         *url == other.url
             && *name == other.name
@@ -179,6 +252,8 @@ impl PartialEq for Index {
             && *authenticate == other.authenticate
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
+            && *tls == other.tls
+            && *packages == other.packages
     }
 }
 
@@ -203,6 +278,8 @@ impl Ord for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            tls,
+            packages,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -213,6 +290,8 @@ impl Ord for Index {
             .then_with(|| authenticate.cmp(&other.authenticate))
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
+            .then_with(|| tls.cmp(&other.tls))
+            .then_with(|| packages.cmp(&other.packages))
     }
 }
 
@@ -229,6 +308,8 @@ impl std::hash::Hash for Index {
             authenticate,
             ignore_error_codes,
             cache_control,
+            tls,
+            packages,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -239,6 +320,8 @@ impl std::hash::Hash for Index {
         authenticate.hash(state);
         ignore_error_codes.hash(state);
         cache_control.hash(state);
+        tls.hash(state);
+        packages.hash(state);
     }
 }
 
@@ -279,6 +362,8 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            tls: None,
+            packages: Vec::new(),
         }
     }
 
@@ -295,6 +380,8 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            tls: None,
+            packages: Vec::new(),
         }
     }
 
@@ -311,6 +398,8 @@ impl Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            tls: None,
+            packages: Vec::new(),
         }
     }
 
@@ -364,6 +453,7 @@ impl Index {
                 self.url = IndexUrl::parse(given, Some(root_dir))?;
             }
         }
+        self.tls = self.tls.map(|tls| tls.relative_to(root_dir));
         Ok(self)
     }
 
@@ -416,6 +506,8 @@ impl From<IndexUrl> for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            tls: None,
+            packages: Vec::new(),
         }
     }
 }
@@ -440,6 +532,8 @@ impl FromStr for Index {
                     authenticate: AuthPolicy::default(),
                     ignore_error_codes: None,
                     cache_control: None,
+                    tls: None,
+                    packages: Vec::new(),
                 });
             }
         }
@@ -457,6 +551,8 @@ impl FromStr for Index {
             authenticate: AuthPolicy::default(),
             ignore_error_codes: None,
             cache_control: None,
+            tls: None,
+            packages: Vec::new(),
         })
     }
 }
@@ -557,6 +653,35 @@ pub enum IndexSourceError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_index_tls() {
+        let toml_str = r#"
+            name = "internal"
+            url = "https://pypi.mycorp.com/simple"
+            tls = { client-cert = "certs/client.pem", ca-bundle = "/etc/ssl/ca.pem", system-trust = false }
+        "#;
+
+        let index: Index = toml::from_str(toml_str).unwrap();
+        let root = if cfg!(windows) {
+            Path::new("C:\\project")
+        } else {
+            Path::new("/project")
+        };
+        let index = index.relative_to(root).unwrap();
+
+        let tls = index.tls.as_ref().unwrap();
+        assert_eq!(
+            tls.client_cert.as_deref(),
+            Some(root.join("certs/client.pem").as_path())
+        );
+        assert_eq!(tls.client_key, None);
+        assert_eq!(
+            tls.ca_bundle.as_deref(),
+            Some(root.join("/etc/ssl/ca.pem").as_path())
+        );
+        assert_eq!(tls.system_trust, Some(false));
+    }
+
     #[test]
     fn test_index_cache_control_headers() {
         // Test that cache control headers are properly parsed from TOML
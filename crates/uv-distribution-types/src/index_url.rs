@@ -12,11 +12,12 @@ use tracing::trace;
 use url::{ParseError, Url};
 use uv_auth::RealmRef;
 use uv_cache_key::CanonicalUrl;
+use uv_normalize::PackageName;
 use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{Index, IndexStatusCodeStrategy, IndexTls, Verbatim};
 
 static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -383,6 +384,15 @@ impl<'a> IndexLocations {
         }
     }
 
+    /// Return the [`Index`] that the given package is restricted to, as determined by the
+    /// `packages` patterns of the configured indexes.
+    ///
+    /// If multiple indexes match, the first-defined index takes precedence.
+    pub fn pinned_index(&'a self, name: &PackageName) -> Option<&'a Index> {
+        self.simple_indexes()
+            .find(|index| index.packages.iter().any(|pattern| pattern.matches(name)))
+    }
+
     /// Return a vector containing all allowed [`Index`] entries.
     ///
     /// This includes explicit indexes, implicit indexes, flat indexes, and the default index.
@@ -460,6 +470,17 @@ impl<'a> IndexLocations {
         }
     }
 
+    /// Return the TLS configuration for each allowed index that defines one, in priority order.
+    pub fn index_tls(&'a self) -> Vec<(DisplaySafeUrl, IndexTls)> {
+        self.allowed_indexes()
+            .into_iter()
+            .filter_map(|index| {
+                let tls = index.tls.clone()?;
+                Some((index.url().url().clone(), tls))
+            })
+            .collect()
+    }
+
     /// Return the Simple API cache control header for an [`IndexUrl`], if configured.
     pub fn simple_api_cache_control_for(&self, url: &IndexUrl) -> Option<&str> {
         for index in &self.indexes {
@@ -777,6 +798,8 @@ mod tests {
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
+                tls: None,
+                packages: Vec::new(),
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -789,6 +812,8 @@ mod tests {
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
+                tls: None,
+                packages: Vec::new(),
             },
         ];
 
@@ -827,6 +852,8 @@ mod tests {
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
+            tls: None,
+            packages: Vec::new(),
         }];
 
         let index_urls = IndexUrls::from_indexes(indexes.clone());
@@ -869,6 +896,8 @@ mod tests {
             publish_url: None,
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
+            tls: None,
+            packages: Vec::new(),
         }];
 
         let index_urls = IndexUrls::from_indexes(indexes.clone());
@@ -15,7 +15,7 @@ use uv_fs::Simplified;
 use uv_install_wheel::WheelFile;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, MetadataError};
+use uv_pypi_types::{DirectUrl, HashDigests, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::{
@@ -429,6 +429,21 @@ impl InstalledDist {
         Ok(Some(build_info))
     }
 
+    /// Read the `uv_hashes.json` file from a `.dist-info` directory.
+    ///
+    /// The file records the hashes of the archive from which the distribution was installed.
+    pub fn read_hashes(path: &Path) -> Result<Option<HashDigests>, InstalledDistError> {
+        let path = path.join("uv_hashes.json");
+        let file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let hashes =
+            serde_json::from_reader::<BufReader<fs_err::File>, HashDigests>(BufReader::new(file))?;
+        Ok(Some(hashes))
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn read_metadata(&self) -> Result<&uv_pypi_types::ResolutionMetadata, InstalledDistError> {
         if let Some(metadata) = self.metadata_cache.get() {
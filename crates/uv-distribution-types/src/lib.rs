@@ -72,6 +72,8 @@ pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
+pub use crate::package_name_pattern::*;
+pub use crate::patches::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
 pub use crate::requested::*;
@@ -103,6 +105,8 @@ mod index_url;
 mod installed;
 mod known_platform;
 mod origin;
+mod package_name_pattern;
+mod patches;
 mod pip_index;
 mod prioritized_distribution;
 mod requested;
@@ -0,0 +1,168 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+use uv_small_str::SmallString;
+
+/// A pattern that matches package names, like `mycorp-*`.
+///
+/// Patterns are normalized in the same way as package names (i.e., lowercased, with runs of `-`,
+/// `_`, and `.` replaced by a single `-`), and may contain `*` to match any sequence of characters
+/// or `?` to match a single character.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageNamePattern(SmallString);
+
+impl PackageNamePattern {
+    /// Validate and normalize the given pattern.
+    pub fn new(pattern: &str) -> Result<Self, PackageNamePatternError> {
+        if pattern.is_empty() {
+            return Err(PackageNamePatternError::Empty);
+        }
+
+        let mut normalized = String::with_capacity(pattern.len());
+        let mut separator = false;
+        for c in pattern.chars() {
+            match c {
+                '-' | '_' | '.' => {
+                    separator = true;
+                }
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '*' | '?' => {
+                    if separator {
+                        normalized.push('-');
+                        separator = false;
+                    }
+                    normalized.push(c.to_ascii_lowercase());
+                }
+                c => {
+                    return Err(PackageNamePatternError::UnsupportedCharacter(
+                        c,
+                        pattern.to_string(),
+                    ));
+                }
+            }
+        }
+        if separator {
+            normalized.push('-');
+        }
+
+        Ok(Self(SmallString::from(normalized)))
+    }
+
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        glob_match(self.0.as_bytes(), name.as_str().as_bytes())
+    }
+}
+
+/// Match a name against a pattern containing `*` and `?` wildcards.
+fn glob_match(pattern: &[u8], name: &[u8]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern, and the position in the name it was matched at.
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(b'?') => {
+                p += 1;
+                n += 1;
+            }
+            Some(c) if *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => {
+                // Let the last `*` consume one more character, if possible.
+                let Some((star, matched)) = backtrack else {
+                    return false;
+                };
+                p = star + 1;
+                n = matched + 1;
+                backtrack = Some((star, matched + 1));
+            }
+        }
+    }
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+impl FromStr for PackageNamePattern {
+    type Err = PackageNamePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for PackageNamePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = Cow::<'_, str>::deserialize(deserializer)?;
+        Self::new(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PackageNamePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An error that can occur when parsing a [`PackageNamePattern`].
+#[derive(Error, Debug)]
+pub enum PackageNamePatternError {
+    #[error("Package name patterns must not be empty")]
+    Empty,
+    #[error(
+        "Package name patterns may only contain letters, digits, `-`, `_`, `.`, `*`, and `?`, but found `{0}` in: `{1}`"
+    )]
+    UnsupportedCharacter(char, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use super::PackageNamePattern;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        PackageNamePattern::from_str(pattern)
+            .unwrap()
+            .matches(&PackageName::from_str(name).unwrap())
+    }
+
+    #[test]
+    fn exact() {
+        assert!(matches("mycorp-utils", "mycorp-utils"));
+        assert!(matches("MyCorp_Utils", "mycorp.utils"));
+        assert!(!matches("mycorp-utils", "mycorp-utils2"));
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(matches("mycorp-*", "mycorp-utils"));
+        assert!(matches("mycorp.*", "mycorp-data-tools"));
+        assert!(!matches("mycorp-*", "mycorp"));
+        assert!(!matches("mycorp-*", "notmycorp-utils"));
+        assert!(matches("*-mycorp", "plugin-for-mycorp"));
+        assert!(matches("*corp*", "mycorporation"));
+        assert!(matches("lib?", "lib1"));
+        assert!(!matches("lib?", "lib12"));
+        assert!(matches("*", "anything"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(PackageNamePattern::from_str("").is_err());
+        assert!(PackageNamePattern::from_str("mycorp/*").is_err());
+        assert!(PackageNamePattern::from_str("[ab]*").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use rustc_hash::FxHashMap;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pypi_types::HashDigest;
+
+/// A patch to apply to a source distribution before building.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patch {
+    /// The name of the package to which the patch applies.
+    pub name: PackageName,
+    /// The version of the package to which the patch applies, if it's limited to a single version.
+    pub version: Option<Version>,
+    /// The absolute path to the patch file.
+    pub path: PathBuf,
+    /// The hash of the patch file.
+    pub hash: HashDigest,
+}
+
+/// Pre-defined [`Patch`] entries, indexed by [`PackageName`].
+#[derive(Debug, Clone, Default)]
+pub struct Patches(FxHashMap<PackageName, Vec<Patch>>);
+
+impl Patches {
+    /// Index a set of [`Patch`] entries by [`PackageName`].
+    ///
+    /// The order of the patches for each package is preserved.
+    pub fn from_entries(entries: impl IntoIterator<Item = Patch>) -> Self {
+        let mut map = Self::default();
+        for entry in entries {
+            map.0.entry(entry.name.clone()).or_default().push(entry);
+        }
+        map
+    }
+
+    /// Return the [`Patch`] entries that apply to the given package and version, in order.
+    ///
+    /// Patches without a version apply to all versions of a package; patches with a version only
+    /// apply if the version is known and matches exactly.
+    pub fn get<'a>(
+        &'a self,
+        package: &PackageName,
+        version: Option<&Version>,
+    ) -> impl Iterator<Item = &'a Patch> + 'a {
+        let version = version.cloned();
+        self.0
+            .get(package)
+            .into_iter()
+            .flatten()
+            .filter(move |patch| {
+                patch
+                    .version
+                    .as_ref()
+                    .is_none_or(|expected| version.as_ref() == Some(expected))
+            })
+    }
+
+    /// Return `true` if there are no patches.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the names of all patched packages.
+    pub fn names(&self) -> impl Iterator<Item = &PackageName> {
+        self.0.keys()
+    }
+
+    /// Return an iterator over all [`Patch`] entries.
+    pub fn values(&self) -> impl Iterator<Item = &Patch> {
+        self.0.values().flatten()
+    }
+}
@@ -7,7 +7,9 @@ use tracing::debug;
 use uv_distribution_filename::{BuildTag, WheelFilename};
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString};
-use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagPriority, Tags};
+use uv_platform_tags::{
+    AbiTag, IncompatibleTag, LanguageTag, PlatformTag, PlatformTagSet, TagPriority, Tags,
+};
 use uv_pypi_types::{HashDigest, Yanked};
 
 use crate::{
@@ -81,6 +83,19 @@ pub enum CompatibleDist<'a> {
 }
 
 impl CompatibleDist<'_> {
+    /// Return the size of the distribution's file, if known.
+    ///
+    /// For a distribution that's resolved using an incompatible wheel, this is the size of the
+    /// source distribution that's used for installation.
+    pub fn size(&self) -> Option<u64> {
+        match self {
+            Self::InstalledDist(_) => None,
+            Self::SourceDist { sdist, .. } => sdist.file.size,
+            Self::CompatibleWheel { wheel, .. } => wheel.file.size,
+            Self::IncompatibleWheel { sdist, .. } => sdist.file.size,
+        }
+    }
+
     /// Return the `requires-python` specifier for the distribution, if any.
     pub fn requires_python(&self) -> Option<&VersionSpecifiers> {
         match self {
@@ -565,6 +580,15 @@ impl PrioritizedDist {
         self.0.best_wheel_index.map(|i| &self.0.wheels[i])
     }
 
+    /// Returns the number of wheels that are compatible with the target platform.
+    pub fn compatible_wheels(&self) -> usize {
+        self.0
+            .wheels
+            .iter()
+            .filter(|(_, compatibility)| compatibility.is_compatible())
+            .count()
+    }
+
     /// Returns an iterator of all wheels and the source distribution, if any.
     pub fn files(&self) -> impl Iterator<Item = &File> {
         self.0
@@ -597,23 +621,30 @@ impl PrioritizedDist {
 
     /// Returns the set of platform tags for the distribution that are ABI-compatible with the given
     /// tags.
-    pub fn platform_tags<'a>(
-        &'a self,
-        tags: &'a Tags,
-    ) -> impl Iterator<Item = &'a PlatformTag> + 'a {
-        self.0.wheels.iter().flat_map(move |(wheel, _)| {
-            if wheel.filename.python_tags().iter().any(|wheel_py| {
+    ///
+    /// The tags are normalized, such that equivalent tags (e.g., `manylinux2014_x86_64` and
+    /// `manylinux_2_17_x86_64`) are only included once.
+    pub fn platform_tags(&self, tags: &Tags) -> PlatformTagSet {
+        self.0
+            .wheels
+            .iter()
+            .filter(|(wheel, _)| {
+                wheel.filename.python_tags().iter().any(|wheel_py| {
+                    wheel
+                        .filename
+                        .abi_tags()
+                        .iter()
+                        .any(|wheel_abi| tags.is_compatible_abi(*wheel_py, *wheel_abi))
+                })
+            })
+            .flat_map(|(wheel, _)| {
                 wheel
                     .filename
-                    .abi_tags()
+                    .platform_tags()
                     .iter()
-                    .any(|wheel_abi| tags.is_compatible_abi(*wheel_py, *wheel_abi))
-            }) {
-                wheel.filename.platform_tags().iter()
-            } else {
-                [].iter()
-            }
-        })
+                    .map(PlatformTag::normalize)
+            })
+            .collect()
     }
 }
 
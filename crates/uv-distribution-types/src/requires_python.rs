@@ -311,6 +311,8 @@ impl RequiresPython {
                 let minor = u8::try_from(minor).ok()?;
                 Some(AbiTag::CPython {
                     gil_disabled: false,
+                    debug: false,
+                    pymalloc: (major, minor) < (3, 8),
                     python_version: (major, minor),
                 })
             }
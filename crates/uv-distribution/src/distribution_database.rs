@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::future::Future;
 use std::io;
 use std::path::Path;
@@ -26,9 +27,10 @@ use uv_distribution_types::{
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
+use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
+use uv_warnings::warn_user_once;
 
 use crate::archive::Archive;
 use crate::metadata::{ArchiveMetadata, Metadata};
@@ -131,20 +133,20 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         &self,
         dist: &InstalledDist,
     ) -> Result<ArchiveMetadata, Error> {
-        // If the metadata was provided by the user directly, prefer it.
-        if let Some(metadata) = self
-            .build_context
-            .dependency_metadata()
-            .get(dist.name(), Some(dist.version()))
-        {
-            return Ok(ArchiveMetadata::from_metadata23(metadata.clone()));
-        }
+        let dependency_metadata = self.build_context.dependency_metadata();
 
         let metadata = dist
             .read_metadata()
             .map_err(|err| Error::ReadInstalled(Box::new(dist.clone()), err))?;
 
-        Ok(ArchiveMetadata::from_metadata23(metadata.clone()))
+        // If the metadata was provided by the user directly, prefer it.
+        if let Some(override_metadata) = dependency_metadata.get(dist.name(), Some(dist.version()))
+        {
+            warn_on_redundant_override(&override_metadata, metadata);
+            return Ok(ArchiveMetadata::from_metadata23(override_metadata));
+        }
+
+        Ok(ArchiveMetadata::from_metadata23(metadata.clone()).with_patches(dependency_metadata))
     }
 
     /// Either fetch the only wheel metadata (directly from the index or with range requests) or
@@ -455,21 +457,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // TODO(charlie): Request the hashes via a separate method, to reduce the coupling in this API.
         if hashes.is_generate(dist) {
             let wheel = self.get_wheel(dist, hashes).await?;
+            let upstream = wheel.metadata()?;
             // If the metadata was provided by the user directly, prefer it.
             let metadata = if let Some(metadata) = self
                 .build_context
                 .dependency_metadata()
                 .get(dist.name(), Some(dist.version()))
             {
-                metadata.clone()
+                warn_on_redundant_override(&metadata, &upstream);
+                Metadata::from_metadata23(metadata)
             } else {
-                wheel.metadata()?
+                Metadata::from_metadata23(upstream)
+                    .with_patches(self.build_context.dependency_metadata())
             };
             let hashes = wheel.hashes;
-            return Ok(ArchiveMetadata {
-                metadata: Metadata::from_metadata23(metadata),
-                hashes,
-            });
+            return Ok(ArchiveMetadata { metadata, hashes });
         }
 
         // If the metadata was provided by the user directly, prefer it.
@@ -493,7 +495,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         match result {
             Ok(metadata) => {
                 // Validate that the metadata is consistent with the distribution.
-                Ok(ArchiveMetadata::from_metadata23(metadata))
+                Ok(ArchiveMetadata::from_metadata23(metadata)
+                    .with_patches(self.build_context.dependency_metadata()))
             }
             Err(err) if err.is_http_streaming_unsupported() => {
                 warn!(
@@ -506,7 +509,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 let metadata = wheel.metadata()?;
                 let hashes = wheel.hashes;
                 Ok(ArchiveMetadata {
-                    metadata: Metadata::from_metadata23(metadata),
+                    metadata: Metadata::from_metadata23(metadata)
+                        .with_patches(self.build_context.dependency_metadata()),
                     hashes,
                 })
             }
@@ -544,7 +548,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .boxed_local()
             .await?;
 
-        Ok(metadata)
+        Ok(metadata.with_patches(self.build_context.dependency_metadata()))
     }
 
     /// Return the [`RequiresDist`] from a `pyproject.toml`, if it can be statically extracted.
@@ -583,6 +587,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let download = |response: reqwest::Response| {
             async {
                 let size = size.or_else(|| content_length(&response));
+                if let Some(size) = size {
+                    tracing::Span::current().record("size", size);
+                }
 
                 let progress = self
                     .reporter
@@ -656,7 +663,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     filename.clone(),
                 ))
             }
-            .instrument(info_span!("wheel", wheel = %dist))
+            .instrument(info_span!("wheel", wheel = %dist, size = tracing::field::Empty))
         };
 
         // Fetch the archive from the cache, or download it if necessary.
@@ -675,7 +682,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     CacheControl::from(
                         self.build_context
                             .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
+                            .url_freshness(&http_entry, Some(&filename.name), &url)
                             .map_err(Error::CacheRead)?,
                     )
                 }
@@ -754,6 +761,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let download = |response: reqwest::Response| {
             async {
                 let size = size.or_else(|| content_length(&response));
+                if let Some(size) = size {
+                    tracing::Span::current().record("size", size);
+                }
 
                 let progress = self
                     .reporter
@@ -861,7 +871,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
                 Ok(Archive::new(id, hashes, filename.clone()))
             }
-            .instrument(info_span!("wheel", wheel = %dist))
+            .instrument(info_span!("wheel", wheel = %dist, size = tracing::field::Empty))
         };
 
         // Fetch the archive from the cache, or download it if necessary.
@@ -880,7 +890,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                     CacheControl::from(
                         self.build_context
                             .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
+                            .url_freshness(&http_entry, Some(&filename.name), &url)
                             .map_err(Error::CacheRead)?,
                     )
                 }
@@ -1169,6 +1179,36 @@ fn content_length(response: &reqwest::Response) -> Option<u64> {
         .and_then(|val| val.parse::<u64>().ok())
 }
 
+/// Warn if a user-provided metadata override is identical to the upstream metadata, in which case
+/// the override has likely drifted from the package it was written for (e.g., because the package
+/// was fixed upstream), and can be removed.
+fn warn_on_redundant_override(metadata: &ResolutionMetadata, upstream: &ResolutionMetadata) {
+    let requirements = |metadata: &ResolutionMetadata| {
+        metadata
+            .requires_dist
+            .iter()
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>()
+    };
+    let extras = |metadata: &ResolutionMetadata| {
+        metadata
+            .provides_extra
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<_>>()
+    };
+    if requirements(metadata) == requirements(upstream)
+        && metadata.requires_python == upstream.requires_python
+        && extras(metadata) == extras(upstream)
+    {
+        warn_user_once!(
+            "The `dependency-metadata` entry for `{}=={}` matches the metadata published by the package, and can likely be removed",
+            upstream.name,
+            upstream.version
+        );
+    }
+}
+
 /// An asynchronous reader that reports progress as bytes are read.
 struct ProgressReader<'a, R> {
     reader: R,
@@ -1211,6 +1251,7 @@ where
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HttpArchivePointer {
     archive: Archive,
+    fresh: bool,
 }
 
 impl HttpArchivePointer {
@@ -1218,15 +1259,24 @@ impl HttpArchivePointer {
     pub fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
         match fs_err::File::open(path.as_ref()) {
             Ok(file) => {
-                let data = DataWithCachePolicy::from_reader(file)?.data;
-                let archive = rmp_serde::from_slice::<Archive>(&data)?;
-                Ok(Some(Self { archive }))
+                let cached = DataWithCachePolicy::from_reader(file)?;
+                let archive = rmp_serde::from_slice::<Archive>(&cached.data)?;
+                Ok(Some(Self {
+                    archive,
+                    fresh: cached.is_fresh(),
+                }))
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
         }
     }
 
+    /// Returns `true` if the archive may be used without revalidating it, as per the HTTP
+    /// caching headers of the response it was downloaded from.
+    pub fn is_fresh(&self) -> bool {
+        self.fresh
+    }
+
     /// Return the [`Archive`] from the pointer.
     pub fn into_archive(self) -> Archive {
         self.archive
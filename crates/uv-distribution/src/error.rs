@@ -88,6 +88,8 @@ pub enum Error {
     MissingPkgInfo,
     #[error("The source distribution `{}` has no subdirectory `{}`", _0, _1.display())]
     MissingSubdirectory(DisplaySafeUrl, PathBuf),
+    #[error("Failed to apply patch `{}`", _0.user_display())]
+    Patch(PathBuf, #[source] crate::source::PatchError),
     #[error("Failed to extract static metadata from `PKG-INFO`")]
     PkgInfo(#[source] uv_pypi_types::MetadataError),
     #[error("Failed to extract metadata from `requires.txt`")]
@@ -96,6 +98,8 @@ pub enum Error {
     MissingPyprojectToml,
     #[error("Failed to extract static metadata from `pyproject.toml`")]
     PyprojectToml(#[source] uv_pypi_types::MetadataError),
+    #[error("Failed to extract static metadata from the setuptools configuration")]
+    Setuptools(#[source] uv_pypi_types::MetadataError),
     #[error("Unsupported scheme in URL: {0}")]
     UnsupportedScheme(String),
     #[error(transparent)]
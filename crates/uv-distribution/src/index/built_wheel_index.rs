@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
+use tracing::debug;
+
 use uv_cache::{Cache, CacheBucket, CacheShard, WheelCache};
 use uv_cache_info::CacheInfo;
 use uv_distribution_types::{
     BuildInfo, BuildVariables, ConfigSettings, DirectUrlSourceDist, DirectorySourceDist,
     ExtraBuildRequirement, ExtraBuildRequires, ExtraBuildVariables, GitSourceDist, Hashed,
-    PackageConfigSettings, PathSourceDist,
+    PackageConfigSettings, Patches, PathSourceDist,
 };
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
@@ -26,6 +28,7 @@ pub struct BuiltWheelIndex<'a> {
     config_settings_package: &'a PackageConfigSettings,
     extra_build_requires: &'a ExtraBuildRequires,
     extra_build_variables: &'a ExtraBuildVariables,
+    patches: &'a Patches,
 }
 
 impl<'a> BuiltWheelIndex<'a> {
@@ -38,6 +41,7 @@ impl<'a> BuiltWheelIndex<'a> {
         config_settings_package: &'a PackageConfigSettings,
         extra_build_requires: &'a ExtraBuildRequires,
         extra_build_variables: &'a ExtraBuildVariables,
+        patches: &'a Patches,
     ) -> Self {
         Self {
             cache,
@@ -47,13 +51,17 @@ impl<'a> BuiltWheelIndex<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            patches,
         }
     }
 
     /// Return the most compatible [`CachedWheel`] for a given source distribution at a direct URL.
     ///
-    /// This method does not perform any freshness checks and assumes that the source distribution
-    /// is already up-to-date.
+    /// This method does not perform any freshness checks against the [`Refresh`] policy. However,
+    /// if the HTTP caching headers of the source distribution require revalidation, and the
+    /// source distribution isn't pinned by hash, no wheel is returned.
+    ///
+    /// [`Refresh`]: uv_cache::Refresh
     pub fn url(&self, source_dist: &DirectUrlSourceDist) -> Result<Option<CachedWheel>, Error> {
         // For direct URLs, cache directly under the hash of the URL itself.
         let cache_shard = self.cache.shard(
@@ -67,20 +75,32 @@ impl<'a> BuiltWheelIndex<'a> {
             return Ok(None);
         };
 
+        // If the server requires revalidation, the source distribution may have changed, unless
+        // it's pinned by hash.
+        let hashes = self.hasher.get(source_dist);
+        if !pointer.is_fresh() && hashes.is_none() {
+            debug!("Cached revision is stale for: {source_dist}");
+            return Ok(None);
+        }
+
         // Enforce hash-checking by omitting any wheels that don't satisfy the required hashes.
         let revision = pointer.into_revision();
-        if !revision.satisfies(self.hasher.get(source_dist)) {
+        if !revision.satisfies(hashes) {
             return Ok(None);
         }
 
         let cache_shard = cache_shard.shard(revision.id());
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or patches, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars,
+            self.patches.get(&source_dist.name, None),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -116,20 +136,33 @@ impl<'a> BuiltWheelIndex<'a> {
             return Ok(None);
         }
 
+        // If the server requires revalidation, the source distribution may have changed, unless
+        // it's pinned by hash.
+        let hashes = self.hasher.get(source_dist);
+        if !pointer.is_fresh() && hashes.is_none() {
+            debug!("Cached revision is stale for: {source_dist}");
+            return Ok(None);
+        }
+
         // Enforce hash-checking by omitting any wheels that don't satisfy the required hashes.
         let revision = pointer.into_revision();
-        if !revision.satisfies(self.hasher.get(source_dist)) {
+        if !revision.satisfies(hashes) {
             return Ok(None);
         }
 
         let cache_shard = cache_shard.shard(revision.id());
 
-        // If there are build settings, we need to scope to a cache shard.
+        // If there are build settings or patches, we need to scope to a cache shard.
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars,
+            self.patches
+                .get(&source_dist.name, source_dist.version.as_ref()),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -167,9 +200,17 @@ impl<'a> BuiltWheelIndex<'a> {
             return Ok(None);
         }
 
+        // If the server requires revalidation, the source distribution may have changed, unless
+        // it's pinned by hash.
+        let hashes = self.hasher.get(source_dist);
+        if !pointer.is_fresh() && hashes.is_none() {
+            debug!("Cached revision is stale for: {source_dist}");
+            return Ok(None);
+        }
+
         // Enforce hash-checking by omitting any wheels that don't satisfy the required hashes.
         let revision = pointer.into_revision();
-        if !revision.satisfies(self.hasher.get(source_dist)) {
+        if !revision.satisfies(hashes) {
             return Ok(None);
         }
 
@@ -180,7 +221,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars, []);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -210,7 +251,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars, []);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
+use std::str::FromStr;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -8,10 +9,11 @@ use uv_cache_info::CacheInfo;
 use uv_distribution_types::{
     BuildInfo, BuildVariables, CachedRegistryDist, ConfigSettings, ExtraBuildRequirement,
     ExtraBuildRequires, ExtraBuildVariables, Hashed, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings,
+    PackageConfigSettings, Patches,
 };
 use uv_fs::{directories, files};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_platform_tags::Tags;
 use uv_types::HashStrategy;
 
@@ -41,6 +43,7 @@ pub struct RegistryWheelIndex<'a> {
     config_settings_package: &'a PackageConfigSettings,
     extra_build_requires: &'a ExtraBuildRequires,
     extra_build_variables: &'a ExtraBuildVariables,
+    patches: &'a Patches,
 }
 
 impl<'a> RegistryWheelIndex<'a> {
@@ -54,6 +57,7 @@ impl<'a> RegistryWheelIndex<'a> {
         config_settings_package: &'a PackageConfigSettings,
         extra_build_requires: &'a ExtraBuildRequires,
         extra_build_variables: &'a ExtraBuildVariables,
+        patches: &'a Patches,
     ) -> Self {
         Self {
             cache,
@@ -64,6 +68,7 @@ impl<'a> RegistryWheelIndex<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            patches,
             index: FxHashMap::default(),
         }
     }
@@ -89,6 +94,7 @@ impl<'a> RegistryWheelIndex<'a> {
                 self.config_settings_package,
                 self.extra_build_requires,
                 self.extra_build_variables,
+                self.patches,
             )),
         }) as _
     }
@@ -104,6 +110,7 @@ impl<'a> RegistryWheelIndex<'a> {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        patches: &Patches,
     ) -> Vec<IndexEntry<'index>> {
         let mut entries = vec![];
 
@@ -189,6 +196,10 @@ impl<'a> RegistryWheelIndex<'a> {
 
             // For registry source distributions, the cache structure is: `<index>/<package-name>/<version>/`.
             for shard in directories(&cache_shard).ok().into_iter().flatten() {
+                let version = shard
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| Version::from_str(name).ok());
                 let cache_shard = cache_shard.shard(shard);
 
                 // Read the revision from the cache.
@@ -216,7 +227,7 @@ impl<'a> RegistryWheelIndex<'a> {
                 if let Some(revision) = revision {
                     let cache_shard = cache_shard.shard(revision.id());
 
-                    // If there are build settings, we need to scope to a cache shard.
+                    // If there are build settings or patches, we need to scope to a cache shard.
                     let extra_build_deps =
                         Self::extra_build_requires_for(package, extra_build_requires);
                     let extra_build_vars =
@@ -230,6 +241,7 @@ impl<'a> RegistryWheelIndex<'a> {
                         &config_settings,
                         extra_build_deps,
                         extra_build_vars,
+                        patches.get(package, version.as_ref()),
                     );
                     let cache_shard = build_info
                         .cache_shard()
@@ -16,7 +16,7 @@ use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl, looks_like_git_repository
 use uv_pypi_types::{ConflictItem, ParsedGitUrl, ParsedUrlError, VerbatimParsedUrl};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_workspace::Workspace;
-use uv_workspace::pyproject::{PyProjectToml, Source, Sources};
+use uv_workspace::pyproject::{PyProjectToml, Source, Sources, ToolUvSources};
 
 use crate::metadata::GitWorkspaceMember;
 
@@ -59,6 +59,11 @@ impl LoweredRequirement {
             sources
                 .iter()
                 .filter(|source| {
+                    // Fallback sources are only used by the resolver, once the index is exhausted.
+                    if source.is_fallback() {
+                        return false;
+                    }
+
                     if let Some(target) = source.extra() {
                         if extra != Some(target) {
                             return false;
@@ -184,8 +189,11 @@ impl LoweredRequirement {
                             marker,
                             ..
                         } => {
-                            let source =
-                                url_source(&requirement, url, subdirectory.map(Box::<Path>::from))?;
+                            let source = url_source(
+                                &requirement.name,
+                                url,
+                                subdirectory.map(Box::<Path>::from),
+                            )?;
                             (source, marker)
                         }
                         Source::Path {
@@ -366,6 +374,7 @@ impl LoweredRequirement {
         // If the source only applies to a given extra, filter it out.
         let source = source
             .iter()
+            .filter(|source| !source.is_fallback())
             .filter(|source| {
                 source.extra().is_none_or(|target| {
                     requirement
@@ -425,8 +434,11 @@ impl LoweredRequirement {
                             marker,
                             ..
                         } => {
-                            let source =
-                                url_source(&requirement, url, subdirectory.map(Box::<Path>::from))?;
+                            let source = url_source(
+                                &requirement.name,
+                                url,
+                                subdirectory.map(Box::<Path>::from),
+                            )?;
                             (source, marker)
                         }
                         Source::Path {
@@ -496,6 +508,69 @@ impl LoweredRequirement {
         )
     }
 
+    /// Lower the `fallback = true` entries in `tool.uv.sources`, across the workspace root and all
+    /// workspace members, into the requirements the resolver should use when no compatible
+    /// version of the package is available on the index.
+    pub fn fallbacks(workspace: &Workspace) -> Result<Vec<Requirement>, LoweringError> {
+        let member_sources = workspace.packages().values().filter_map(|member| {
+            member
+                .pyproject_toml()
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.sources.as_ref())
+                .map(ToolUvSources::inner)
+        });
+
+        let mut fallbacks: Vec<Requirement> = Vec::new();
+        for (name, sources) in std::iter::once(workspace.sources())
+            .chain(member_sources)
+            .flat_map(BTreeMap::iter)
+        {
+            for source in sources.iter().filter(|source| source.is_fallback()) {
+                let marker = source.marker();
+                let source = match source {
+                    Source::Git {
+                        git,
+                        subdirectory,
+                        rev,
+                        tag,
+                        branch,
+                        ..
+                    } => git_source(
+                        git,
+                        subdirectory.clone().map(Box::<Path>::from),
+                        rev.clone(),
+                        tag.clone(),
+                        branch.clone(),
+                    )?,
+                    Source::Url {
+                        url, subdirectory, ..
+                    } => url_source(
+                        name,
+                        url.clone(),
+                        subdirectory.clone().map(Box::<Path>::from),
+                    )?,
+                    Source::Path { .. } | Source::Registry { .. } | Source::Workspace { .. } => {
+                        continue;
+                    }
+                };
+                let requirement = Requirement {
+                    name: name.clone(),
+                    extras: Box::new([]),
+                    groups: Box::new([]),
+                    marker,
+                    source,
+                    origin: None,
+                };
+                if !fallbacks.contains(&requirement) {
+                    fallbacks.push(requirement);
+                }
+            }
+        }
+        Ok(fallbacks)
+    }
+
     /// Convert back into a [`Requirement`].
     pub fn into_inner(self) -> Requirement {
         self.0
@@ -614,7 +689,7 @@ fn git_source(
 
 /// Convert a URL source into a [`RequirementSource`].
 fn url_source(
-    requirement: &uv_pep508::Requirement<VerbatimParsedUrl>,
+    name: &PackageName,
     url: DisplaySafeUrl,
     subdirectory: Option<Box<Path>>,
 ) -> Result<RequirementSource, LoweringError> {
@@ -632,10 +707,7 @@ fn url_source(
     let ext = match DistExtension::from_path(url.path()) {
         Ok(ext) => ext,
         Err(..) if looks_like_git_repository(&url) => {
-            return Err(LoweringError::MissingGitSource(
-                requirement.name.clone(),
-                url.clone(),
-            ));
+            return Err(LoweringError::MissingGitSource(name.clone(), url.clone()));
         }
         Err(err) => {
             return Err(ParsedUrlError::MissingExtensionUrl(url.to_string(), err).into());
@@ -4,10 +4,11 @@ use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 use uv_configuration::SourceStrategy;
-use uv_distribution_types::{GitSourceUrl, IndexLocations, Requirement};
+use uv_distribution_types::{DependencyMetadata, GitSourceUrl, IndexLocations, Requirement};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{HashDigests, ResolutionMetadata};
+use uv_warnings::warn_user_once;
 use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::{WorkspaceCache, WorkspaceError};
 
@@ -126,6 +127,55 @@ impl Metadata {
             dynamic,
         })
     }
+
+    /// Apply any matching patch entries from the user-provided [`DependencyMetadata`].
+    ///
+    /// Warns if an entry no longer applies to the upstream metadata (e.g., because it removes a
+    /// requirement that the package no longer declares), as the entry is likely outdated.
+    #[must_use]
+    pub fn with_patches(mut self, dependency_metadata: &DependencyMetadata) -> Self {
+        let name = self.name.clone();
+        let version = self.version.clone();
+        let mut requires_dist = self.requires_dist.into_vec();
+        for patch in dependency_metadata.patches(&name, &version) {
+            for removed in &patch.remove_requires_dist {
+                if requires_dist
+                    .iter()
+                    .any(|requirement| requirement.name == *removed)
+                {
+                    requires_dist.retain(|requirement| requirement.name != *removed);
+                } else {
+                    warn_user_once!(
+                        "The `dependency-metadata` entry for `{name}` removes `{removed}`, but `{name}=={version}` does not depend on `{removed}`; the entry may be outdated"
+                    );
+                }
+            }
+            for added in &patch.add_requires_dist {
+                let added = Requirement::from(added.clone());
+                if requires_dist.contains(&added) {
+                    warn_user_once!(
+                        "The `dependency-metadata` entry for `{name}` adds `{added}`, but `{name}=={version}` already depends on it; the entry may be outdated"
+                    );
+                } else {
+                    requires_dist.push(added);
+                }
+            }
+            if let Some(requires_python) = patch.requires_python.as_ref() {
+                self.requires_python = Some(requires_python.clone());
+            }
+            if !patch.provides_extra.is_empty() {
+                let mut provides_extra = self.provides_extra.into_vec();
+                for extra in &patch.provides_extra {
+                    if !provides_extra.contains(extra) {
+                        provides_extra.push(extra.clone());
+                    }
+                }
+                self.provides_extra = provides_extra.into_boxed_slice();
+            }
+        }
+        self.requires_dist = requires_dist.into_boxed_slice();
+        self
+    }
 }
 
 /// The metadata associated with an archive.
@@ -146,6 +196,15 @@ impl ArchiveMetadata {
             hashes: HashDigests::empty(),
         }
     }
+
+    /// Apply any matching patch entries from the user-provided [`DependencyMetadata`].
+    #[must_use]
+    pub fn with_patches(self, dependency_metadata: &DependencyMetadata) -> Self {
+        Self {
+            metadata: self.metadata.with_patches(dependency_metadata),
+            hashes: self.hashes,
+        }
+    }
 }
 
 impl From<Metadata> for ArchiveMetadata {
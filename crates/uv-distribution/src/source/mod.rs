@@ -32,8 +32,8 @@ use uv_configuration::{BuildKind, BuildOutput, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildInfo, BuildVariables, BuildableSource, ConfigSettings, DirectorySourceUrl,
-    ExtraBuildRequirement, GitSourceUrl, HashPolicy, Hashed, IndexUrl, PathSourceUrl, SourceDist,
-    SourceUrl,
+    ExtraBuildRequirement, GitSourceUrl, HashPolicy, Hashed, IndexUrl, Patch, PathSourceUrl,
+    SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic};
@@ -50,10 +50,15 @@ use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
+use crate::source::remote::RemoteBuiltWheels;
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
+pub use patch::PatchError;
+
 mod built_wheel_metadata;
+mod patch;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -420,6 +425,59 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         name.and_then(|name| self.build_context.extra_build_variables().get(name))
     }
 
+    /// Determine the patches to apply to the given source distribution.
+    fn patches_for(&self, source: &BuildableSource<'_>) -> Vec<&Patch> {
+        source
+            .name()
+            .map(|name| {
+                self.build_context
+                    .patches()
+                    .get(name, source.version())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Apply the given patches to a copy of the source tree at `source_root`.
+    ///
+    /// Returns `None` if there are no patches to apply.
+    async fn apply_patches(
+        &self,
+        source: &BuildableSource<'_>,
+        source_root: &Path,
+        patches: &[&Patch],
+    ) -> Result<Option<tempfile::TempDir>, Error> {
+        if patches.is_empty() {
+            return Ok(None);
+        }
+
+        debug!("Applying patches to: {source}");
+        let temp_dir = self
+            .build_context
+            .cache()
+            .build_dir()
+            .map_err(Error::CacheWrite)?;
+
+        let source_root = source_root.to_path_buf();
+        let target = temp_dir.path().to_path_buf();
+        let patches = patches
+            .iter()
+            .map(|patch| patch.path.clone())
+            .collect::<Vec<_>>();
+        tokio::task::spawn_blocking(move || {
+            uv_fs::copy_dir_all(&source_root, &target).map_err(Error::CacheWrite)?;
+            for path in patches {
+                let contents = fs_err::read_to_string(&path)
+                    .map_err(|err| Error::Patch(path.clone(), PatchError::Io(err)))?;
+                patch::apply(&target, &contents).map_err(|err| Error::Patch(path, err))?;
+            }
+            Ok::<_, Error>(())
+        })
+        .await??;
+
+        Ok(Some(temp_dir))
+    }
+
     /// Build a source distribution from a remote URL.
     async fn url<'data>(
         &self,
@@ -458,12 +516,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // to be immutable.
         let cache_info = CacheInfo::default();
 
-        // If there are build settings or extra build dependencies, we need to scope to a cache shard.
+        // If there are build settings, extra build dependencies, or patches, we need to scope to a
+        // cache shard.
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let patches = self.patches_for(source);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            patches.iter().copied(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -483,6 +547,33 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // If a remote cache is configured, check it for a compatible wheel.
+        let remote = self.build_context.cache().remote().and_then(|remote| {
+            RemoteBuiltWheels::new(remote, source.name(), revision.hashes(), &build_info)
+        });
+        if let Some(remote) = remote.as_ref() {
+            match client
+                .managed(|client| remote.fetch(tags, &cache_shard, client))
+                .await
+            {
+                Ok(Some(file)) if file.matches(source.name(), source.version()) => {
+                    debug!(
+                        "Found wheel for `{source}` in remote cache: {}",
+                        file.filename
+                    );
+                    remote::write_metadata(&file, cache_shard.entry(METADATA).path()).await?;
+                    return Ok(BuiltWheelMetadata::from_file(
+                        file,
+                        revision.into_hashes(),
+                        cache_info,
+                        build_info,
+                    ));
+                }
+                Ok(_) => debug!("No compatible wheel for `{source}` in remote cache"),
+                Err(err) => warn!("Failed to read `{source}` from remote cache: {err}"),
+            }
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -510,6 +601,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Apply any patches to a copy of the source distribution.
+        let patched = self
+            .apply_patches(source, source_dist_entry.path(), &patches)
+            .await?;
+        let source_root = patched
+            .as_ref()
+            .map_or(source_dist_entry.path(), tempfile::TempDir::path);
+
         let task = self
             .reporter
             .as_ref()
@@ -519,7 +618,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (disk_filename, wheel_filename, metadata) = self
             .build_distribution(
                 source,
-                source_dist_entry.path(),
+                source_root,
                 subdirectory,
                 &cache_shard,
                 SourceStrategy::Disabled,
@@ -538,6 +637,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .await
             .map_err(Error::CacheWrite)?;
 
+        // If enabled, upload the wheel to the remote cache.
+        if let Some(remote) = remote.as_ref().filter(|remote| remote.write()) {
+            let wheel = cache_shard.join(&disk_filename);
+            if let Err(err) = client
+                .managed(|client| remote.upload(&wheel_filename, &wheel, client))
+                .await
+            {
+                warn!("Failed to upload `{source}` to remote cache: {err}");
+            }
+        }
+
         Ok(BuiltWheelMetadata {
             path: cache_shard.join(&disk_filename).into_boxed_path(),
             target: cache_shard.join(wheel_filename.stem()).into_boxed_path(),
@@ -584,8 +694,33 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_dist_entry = cache_shard.entry(SOURCE);
 
+        // If there are build settings, extra build dependencies, or patches, we need to scope to a
+        // cache shard.
+        let config_settings = self.config_settings_for(source.name());
+        let extra_build_deps = self.extra_build_dependencies_for(source.name());
+        let extra_build_variables = self.extra_build_variables_for(source.name());
+        let patches = self.patches_for(source);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            patches.iter().copied(),
+        );
+        let build_shard = build_info
+            .cache_shard()
+            .map(|digest| cache_shard.shard(digest))
+            .unwrap_or_else(|| cache_shard.clone());
+
+        // Patches may change the metadata, so patched source distributions can't use static
+        // metadata, and must scope their metadata to the build settings.
+        let metadata_shard = if patches.is_empty() {
+            &cache_shard
+        } else {
+            &build_shard
+        };
+
         // If the metadata is static, return it.
-        let dynamic =
+        let dynamic = if patches.is_empty() {
             match StaticMetadata::read(source, source_dist_entry.path(), subdirectory).await? {
                 StaticMetadata::Some(metadata) => {
                     return Ok(ArchiveMetadata {
@@ -595,10 +730,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 StaticMetadata::Dynamic => true,
                 StaticMetadata::None => false,
-            };
+            }
+        } else {
+            false
+        };
 
         // If the cache contains compatible metadata, return it.
-        let metadata_entry = cache_shard.entry(METADATA);
+        let metadata_entry = metadata_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
             Ok(Some(metadata)) => {
                 if metadata.matches(source.name(), source.version()) {
@@ -643,15 +781,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Apply any patches to a copy of the source distribution.
+        let patched = self
+            .apply_patches(source, source_dist_entry.path(), &patches)
+            .await?;
+        let source_root = patched
+            .as_ref()
+            .map_or(source_dist_entry.path(), tempfile::TempDir::path);
+
         // Otherwise, we either need to build the metadata.
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(
-                source,
-                source_dist_entry.path(),
-                subdirectory,
-                SourceStrategy::Disabled,
-            )
+            .build_metadata(source, source_root, subdirectory, SourceStrategy::Disabled)
             .boxed_local()
             .await?
         {
@@ -679,17 +820,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             });
         }
 
-        // If there are build settings or extra build dependencies, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let extra_build_deps = self.extra_build_dependencies_for(source.name());
-        let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
-        let cache_shard = build_info
-            .cache_shard()
-            .map(|digest| cache_shard.shard(digest))
-            .unwrap_or(cache_shard);
-
         let task = self
             .reporter
             .as_ref()
@@ -699,9 +829,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (_disk_filename, _wheel_filename, metadata) = self
             .build_distribution(
                 source,
-                source_dist_entry.path(),
+                source_root,
                 subdirectory,
-                &cache_shard,
+                &build_shard,
                 SourceStrategy::Disabled,
             )
             .await?;
@@ -759,7 +889,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheControl::from(
                         self.build_context
                             .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
+                            .url_freshness(&cache_entry, source.name(), url)
                             .map_err(Error::CacheRead)?,
                     )
                 }
@@ -859,12 +989,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
-        // If there are build settings or extra build dependencies, we need to scope to a cache shard.
+        // If there are build settings, extra build dependencies, or patches, we need to scope to a
+        // cache shard.
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let patches = self.patches_for(source);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            patches.iter().copied(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -892,6 +1028,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        // Apply any patches to a copy of the source distribution.
+        let patched = self
+            .apply_patches(source, source_entry.path(), &patches)
+            .await?;
+        let source_root = patched
+            .as_ref()
+            .map_or(source_entry.path(), tempfile::TempDir::path);
+
         let task = self
             .reporter
             .as_ref()
@@ -900,7 +1044,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (disk_filename, filename, metadata) = self
             .build_distribution(
                 source,
-                source_entry.path(),
+                source_root,
                 None,
                 &cache_shard,
                 SourceStrategy::Disabled,
@@ -961,20 +1105,49 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
+        // If there are build settings, extra build dependencies, or patches, we need to scope to a
+        // cache shard.
+        let config_settings = self.config_settings_for(source.name());
+        let extra_build_deps = self.extra_build_dependencies_for(source.name());
+        let extra_build_variables = self.extra_build_variables_for(source.name());
+        let patches = self.patches_for(source);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            patches.iter().copied(),
+        );
+        let build_shard = build_info
+            .cache_shard()
+            .map(|digest| cache_shard.shard(digest))
+            .unwrap_or_else(|| cache_shard.clone());
+
+        // Patches may change the metadata, so patched source distributions can't use static
+        // metadata, and must scope their metadata to the build settings.
+        let metadata_shard = if patches.is_empty() {
+            &cache_shard
+        } else {
+            &build_shard
+        };
+
         // If the metadata is static, return it.
-        let dynamic = match StaticMetadata::read(source, source_entry.path(), None).await? {
-            StaticMetadata::Some(metadata) => {
-                return Ok(ArchiveMetadata {
-                    metadata: Metadata::from_metadata23(metadata),
-                    hashes: revision.into_hashes(),
-                });
+        let dynamic = if patches.is_empty() {
+            match StaticMetadata::read(source, source_entry.path(), None).await? {
+                StaticMetadata::Some(metadata) => {
+                    return Ok(ArchiveMetadata {
+                        metadata: Metadata::from_metadata23(metadata),
+                        hashes: revision.into_hashes(),
+                    });
+                }
+                StaticMetadata::Dynamic => true,
+                StaticMetadata::None => false,
             }
-            StaticMetadata::Dynamic => true,
-            StaticMetadata::None => false,
+        } else {
+            false
         };
 
         // If the cache contains compatible metadata, return it.
-        let metadata_entry = cache_shard.entry(METADATA);
+        let metadata_entry = metadata_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
             Ok(Some(metadata)) => {
                 if metadata.matches(source.name(), source.version()) {
@@ -1000,9 +1173,17 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        // Apply any patches to a copy of the source distribution.
+        let patched = self
+            .apply_patches(source, source_entry.path(), &patches)
+            .await?;
+        let source_root = patched
+            .as_ref()
+            .map_or(source_entry.path(), tempfile::TempDir::path);
+
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_entry.path(), None, SourceStrategy::Disabled)
+            .build_metadata(source, source_root, None, SourceStrategy::Disabled)
             .boxed_local()
             .await?
         {
@@ -1030,17 +1211,6 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             });
         }
 
-        // If there are build settings or extra build dependencies, we need to scope to a cache shard.
-        let config_settings = self.config_settings_for(source.name());
-        let extra_build_deps = self.extra_build_dependencies_for(source.name());
-        let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
-        let cache_shard = build_info
-            .cache_shard()
-            .map(|digest| cache_shard.shard(digest))
-            .unwrap_or(cache_shard);
-
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -1050,9 +1220,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let (_disk_filename, _filename, metadata) = self
             .build_distribution(
                 source,
-                source_entry.path(),
+                source_root,
                 None,
-                &cache_shard,
+                &build_shard,
                 SourceStrategy::Disabled,
             )
             .await?;
@@ -1179,8 +1349,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            [],
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1376,8 +1550,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            [],
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1542,6 +1720,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.subdirectory,
                 client.unmanaged.disable_ssl(resource.git.repository()),
                 client.unmanaged.connectivity() == Connectivity::Offline,
                 self.build_context.cache().bucket(CacheBucket::Git),
@@ -1583,8 +1762,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            [],
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1742,6 +1925,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 resource.git,
+                resource.subdirectory,
                 client.unmanaged.disable_ssl(resource.git.repository()),
                 client.unmanaged.connectivity() == Connectivity::Offline,
                 self.build_context.cache().bucket(CacheBucket::Git),
@@ -1888,8 +2072,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables,
+            [],
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1951,9 +2139,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<Option<GitOid>, Error> {
-        let git = match source {
-            BuildableSource::Dist(SourceDist::Git(source)) => &*source.git,
-            BuildableSource::Url(SourceUrl::Git(source)) => source.git,
+        let (git, subdirectory) = match source {
+            BuildableSource::Dist(SourceDist::Git(source)) => {
+                (&*source.git, source.subdirectory.as_deref())
+            }
+            BuildableSource::Url(SourceUrl::Git(source)) => (source.git, source.subdirectory),
             _ => {
                 return Ok(None);
             }
@@ -1988,6 +2178,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .git()
             .fetch(
                 git,
+                subdirectory,
                 client.unmanaged.disable_ssl(git.repository()),
                 client.unmanaged.connectivity() == Connectivity::Offline,
                 self.build_context.cache().bucket(CacheBucket::Git),
@@ -2169,7 +2360,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheControl::from(
                         self.build_context
                             .cache()
-                            .freshness(&cache_entry, source.name(), source.source_tree())
+                            .url_freshness(&cache_entry, source.name(), url)
                             .map_err(Error::CacheRead)?,
                     )
                 }
@@ -2736,6 +2927,12 @@ impl StaticMetadata {
             })
         });
 
+        // Determine whether the `pyproject.toml` declares a `[project]` table. If not, the project
+        // may be a legacy setuptools project, whose metadata we can attempt to extract statically.
+        let has_project = pyproject_toml
+            .as_ref()
+            .is_some_and(|pyproject_toml| pyproject_toml.project.is_some());
+
         // Attempt to read static metadata from the `pyproject.toml`.
         if let Some(pyproject_toml) = pyproject_toml {
             match ResolutionMetadata::parse_pyproject_toml(pyproject_toml, source.version()) {
@@ -2767,45 +2964,71 @@ impl StaticMetadata {
         // If the source distribution is a source tree, avoid reading `PKG-INFO`, since it could be
         // out-of-date.
         if source.is_source_tree() {
-            return Ok(if dynamic { Self::Dynamic } else { Self::None });
-        }
-
-        // Attempt to read static metadata from the `PKG-INFO` file.
-        match read_pkg_info(source_root, subdirectory).await {
-            Ok(metadata) => {
-                debug!("Found static `PKG-INFO` for: {source}");
-
-                // Validate the metadata, but ignore it if the metadata doesn't match.
-                match validate_metadata(source, &metadata) {
-                    Ok(()) => {
-                        // If necessary, mark the metadata as dynamic.
-                        let metadata = if dynamic {
-                            ResolutionMetadata {
-                                dynamic: true,
-                                ..metadata
-                            }
-                        } else {
-                            metadata
-                        };
-                        return Ok(Self::Some(metadata));
-                    }
-                    Err(err) => {
-                        debug!("Ignoring `PKG-INFO` for {source}: {err}");
+            if dynamic {
+                return Ok(Self::Dynamic);
+            }
+        } else {
+            // Attempt to read static metadata from the `PKG-INFO` file.
+            match read_pkg_info(source_root, subdirectory).await {
+                Ok(metadata) => {
+                    debug!("Found static `PKG-INFO` for: {source}");
+
+                    // Validate the metadata, but ignore it if the metadata doesn't match.
+                    match validate_metadata(source, &metadata) {
+                        Ok(()) => {
+                            // If necessary, mark the metadata as dynamic.
+                            let metadata = if dynamic {
+                                ResolutionMetadata {
+                                    dynamic: true,
+                                    ..metadata
+                                }
+                            } else {
+                                metadata
+                            };
+                            return Ok(Self::Some(metadata));
+                        }
+                        Err(err) => {
+                            debug!("Ignoring `PKG-INFO` for {source}: {err}");
+                        }
                     }
                 }
+                Err(
+                    err @ (Error::MissingPkgInfo
+                    | Error::PkgInfo(
+                        uv_pypi_types::MetadataError::Pep508Error(_)
+                        | uv_pypi_types::MetadataError::DynamicField(_)
+                        | uv_pypi_types::MetadataError::FieldNotFound(_)
+                        | uv_pypi_types::MetadataError::UnsupportedMetadataVersion(_),
+                    )),
+                ) => {
+                    debug!("No static `PKG-INFO` available for: {source} ({err:?})");
+                }
+                Err(err) => return Err(err),
             }
-            Err(
-                err @ (Error::MissingPkgInfo
-                | Error::PkgInfo(
-                    uv_pypi_types::MetadataError::Pep508Error(_)
-                    | uv_pypi_types::MetadataError::DynamicField(_)
-                    | uv_pypi_types::MetadataError::FieldNotFound(_)
-                    | uv_pypi_types::MetadataError::UnsupportedMetadataVersion(_),
-                )),
-            ) => {
-                debug!("No static `PKG-INFO` available for: {source} ({err:?})");
+        }
+
+        // Attempt to extract static metadata from the setuptools configuration, as long as the
+        // `pyproject.toml` (if any) doesn't declare its own metadata.
+        if !has_project {
+            match read_setuptools(source_root, subdirectory, source.version()).await {
+                Ok(metadata) => {
+                    debug!("Found static setuptools configuration for: {source}");
+
+                    // Validate the metadata, but ignore it if the metadata doesn't match.
+                    match validate_metadata(source, &metadata) {
+                        Ok(()) => {
+                            return Ok(Self::Some(metadata));
+                        }
+                        Err(err) => {
+                            debug!("Ignoring setuptools configuration for {source}: {err}");
+                        }
+                    }
+                }
+                Err(Error::Setuptools(err)) => {
+                    debug!("No static setuptools configuration available for: {source} ({err})");
+                }
+                Err(err) => return Err(err),
             }
-            Err(err) => return Err(err),
         }
 
         Ok(Self::None)
@@ -2894,6 +3117,7 @@ fn validate_filename(filename: &WheelFilename, metadata: &ResolutionMetadata) ->
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub(crate) struct HttpRevisionPointer {
     revision: Revision,
+    fresh: bool,
 }
 
 impl HttpRevisionPointer {
@@ -2901,15 +3125,24 @@ impl HttpRevisionPointer {
     pub(crate) fn read_from(path: impl AsRef<Path>) -> Result<Option<Self>, Error> {
         match fs_err::File::open(path.as_ref()) {
             Ok(file) => {
-                let data = DataWithCachePolicy::from_reader(file)?.data;
-                let revision = rmp_serde::from_slice::<Revision>(&data)?;
-                Ok(Some(Self { revision }))
+                let cached = DataWithCachePolicy::from_reader(file)?;
+                let revision = rmp_serde::from_slice::<Revision>(&cached.data)?;
+                Ok(Some(Self {
+                    revision,
+                    fresh: cached.is_fresh(),
+                }))
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(Error::CacheRead(err)),
         }
     }
 
+    /// Returns `true` if the revision may be used without revalidating it, as per the HTTP
+    /// caching headers of the response it was downloaded from.
+    pub(crate) fn is_fresh(&self) -> bool {
+        self.fresh
+    }
+
     /// Return the [`Revision`] from the pointer.
     pub(crate) fn into_revision(self) -> Revision {
         self.revision
@@ -2987,6 +3220,38 @@ async fn read_pkg_info(
     Ok(metadata)
 }
 
+/// Read the [`ResolutionMetadata`] from a source distribution's `setup.cfg` and `setup.py` files, if
+/// they can be statically analyzed without invoking the build backend.
+async fn read_setuptools(
+    source_tree: &Path,
+    subdirectory: Option<&Path>,
+    version: Option<&Version>,
+) -> Result<ResolutionMetadata, Error> {
+    let root = match subdirectory {
+        Some(subdirectory) => source_tree.join(subdirectory),
+        None => source_tree.to_path_buf(),
+    };
+
+    // Read the `setup.cfg` and `setup.py` files, either of which may be absent.
+    let setup_cfg = match fs::read_to_string(root.join("setup.cfg")).await {
+        Ok(content) => Some(content),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(Error::CacheRead(err)),
+    };
+    let setup_py = match fs::read_to_string(root.join("setup.py")).await {
+        Ok(content) => Some(content),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(Error::CacheRead(err)),
+    };
+
+    // Parse the metadata.
+    let metadata =
+        ResolutionMetadata::parse_setuptools(setup_cfg.as_deref(), setup_py.as_deref(), version)
+            .map_err(Error::Setuptools)?;
+
+    Ok(metadata)
+}
+
 /// Read the [`ResolutionMetadata`] from a source distribution's `pyproject.toml` file, if it defines static
 /// metadata consistent with PEP 621.
 async fn read_pyproject_toml(
@@ -0,0 +1,392 @@
+//! Apply patches, in unified diff format, to an unpacked source distribution.
+
+use std::path::{Component, Path, PathBuf};
+
+use tracing::debug;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PatchError {
+    #[error("The patch does not modify any files")]
+    Empty,
+    #[error("Malformed patch at line {0}: {1}")]
+    Malformed(usize, String),
+    #[error("The patch references an invalid path: `{0}`")]
+    InvalidPath(String),
+    #[error("Hunk #{hunk} failed to apply to `{path}`")]
+    HunkFailed { path: String, hunk: usize },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A set of changes to a single file.
+#[derive(Debug)]
+struct FilePatch {
+    /// The path to the original file, or `None` if the file is being created.
+    old: Option<String>,
+    /// The path to the modified file, or `None` if the file is being deleted.
+    new: Option<String>,
+    hunks: Vec<Hunk>,
+}
+
+/// A contiguous block of changes to a file.
+#[derive(Debug, Default)]
+struct Hunk {
+    /// The (one-based) line at which the hunk starts in the original file.
+    old_start: usize,
+    /// The lines expected in the original file, including line terminators.
+    old_lines: Vec<String>,
+    /// The lines to write in the modified file, including line terminators.
+    new_lines: Vec<String>,
+}
+
+/// Apply a patch in unified diff format to the directory at `root`.
+///
+/// Paths in the patch are interpreted relative to `root`. Git-style `a/` and `b/` prefixes are
+/// removed. Hunks are applied at their recorded positions if possible, and otherwise at the
+/// nearest position at which the original lines match exactly.
+pub(crate) fn apply(root: &Path, patch: &str) -> Result<(), PatchError> {
+    let files = parse(patch)?;
+    if files.is_empty() {
+        return Err(PatchError::Empty);
+    }
+
+    for file in files {
+        let Some(target) = file.new.as_ref().or(file.old.as_ref()) else {
+            continue;
+        };
+        let path = root.join(validate_path(target)?);
+        debug!("Patching: {}", path.display());
+
+        let original = if file.old.is_some() {
+            fs_err::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+
+        let mut lines = original
+            .split_inclusive('\n')
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        let mut offset = 0isize;
+        let mut minimum = 0usize;
+        for (index, hunk) in file.hunks.iter().enumerate() {
+            let expected = if hunk.old_lines.is_empty() {
+                hunk.old_start
+            } else {
+                hunk.old_start.saturating_sub(1)
+            };
+            let expected = expected.saturating_add_signed(offset);
+            let Some(position) = find_hunk(&lines, &hunk.old_lines, expected, minimum) else {
+                return Err(PatchError::HunkFailed {
+                    path: target.clone(),
+                    hunk: index + 1,
+                });
+            };
+            lines.splice(
+                position..position + hunk.old_lines.len(),
+                hunk.new_lines.iter().cloned(),
+            );
+            offset += hunk.new_lines.len().cast_signed() - hunk.old_lines.len().cast_signed();
+            minimum = position + hunk.new_lines.len();
+        }
+
+        if file.new.is_none() {
+            fs_err::remove_file(&path)?;
+        } else {
+            if let Some(parent) = path.parent() {
+                fs_err::create_dir_all(parent)?;
+            }
+            fs_err::write(&path, lines.concat())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the position at which the `old` lines appear in `lines`, searching outwards from the
+/// `expected` position, without matching before `minimum`.
+fn find_hunk(lines: &[String], old: &[String], expected: usize, minimum: usize) -> Option<usize> {
+    let last = lines.len().checked_sub(old.len())?;
+    let matches = |position: usize| {
+        position >= minimum
+            && position <= last
+            && lines[position..position + old.len()]
+                .iter()
+                .zip(old)
+                .all(|(line, old)| line == old)
+    };
+
+    let expected = expected.min(last);
+    for distance in 0..=last {
+        if matches(expected + distance) {
+            return Some(expected + distance);
+        }
+        if distance <= expected && matches(expected - distance) {
+            return Some(expected - distance);
+        }
+    }
+    None
+}
+
+/// Validate that a path in a patch is relative and doesn't escape the source tree.
+fn validate_path(path: &str) -> Result<PathBuf, PatchError> {
+    let path = Path::new(path);
+    if path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        && path.components().next().is_some()
+    {
+        Ok(path.to_path_buf())
+    } else {
+        Err(PatchError::InvalidPath(path.display().to_string()))
+    }
+}
+
+/// Parse the path from a `---` or `+++` header line, returning `None` for `/dev/null`.
+fn parse_path(header: &str) -> Option<String> {
+    // Drop any trailing timestamp, as emitted by `diff -u`.
+    let path = header.split('\t').next().unwrap_or(header).trim_end();
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path
+        .strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Parse a hunk header (e.g., `@@ -1,3 +1,4 @@`) into the start and length of each side.
+fn parse_hunk_header(line: &str) -> Option<(usize, usize, usize)> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let parse_range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_len) = parse_range(old)?;
+    let (_, new_len) = parse_range(new)?;
+    Some((old_start, old_len, new_len))
+}
+
+/// Parse a unified diff into a list of per-file changes.
+fn parse(patch: &str) -> Result<Vec<FilePatch>, PatchError> {
+    let lines = patch.lines().collect::<Vec<_>>();
+    let mut files: Vec<FilePatch> = Vec::new();
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+
+        // Start a new file at each `---` and `+++` header pair.
+        if let (Some(old), Some(new)) = (
+            line.strip_prefix("--- "),
+            lines
+                .get(index + 1)
+                .and_then(|line| line.strip_prefix("+++ ")),
+        ) {
+            files.push(FilePatch {
+                old: parse_path(old),
+                new: parse_path(new),
+                hunks: Vec::new(),
+            });
+            index += 2;
+            continue;
+        }
+
+        if line.starts_with("@@ ") {
+            let Some(file) = files.last_mut() else {
+                return Err(PatchError::Malformed(
+                    index + 1,
+                    "hunk without a file header".to_string(),
+                ));
+            };
+            let Some((old_start, mut old_remaining, mut new_remaining)) = parse_hunk_header(line)
+            else {
+                return Err(PatchError::Malformed(
+                    index + 1,
+                    format!("invalid hunk header `{line}`"),
+                ));
+            };
+
+            let mut hunk = Hunk {
+                old_start,
+                ..Hunk::default()
+            };
+            index += 1;
+
+            // The side(s) to which the most recent line was added, for `\ No newline` markers.
+            let mut last = (false, false);
+            while old_remaining > 0
+                || new_remaining > 0
+                || lines.get(index).is_some_and(|line| line.starts_with('\\'))
+            {
+                let Some(line) = lines.get(index) else {
+                    return Err(PatchError::Malformed(
+                        index + 1,
+                        "unexpected end of hunk".to_string(),
+                    ));
+                };
+                // Treat empty lines as empty context lines, since some tools strip trailing
+                // whitespace from patches.
+                let (tag, content) = line.split_at_checked(1).unwrap_or((" ", ""));
+                match tag {
+                    " " if old_remaining > 0 && new_remaining > 0 => {
+                        hunk.old_lines.push(format!("{content}\n"));
+                        hunk.new_lines.push(format!("{content}\n"));
+                        old_remaining -= 1;
+                        new_remaining -= 1;
+                        last = (true, true);
+                    }
+                    "-" if old_remaining > 0 => {
+                        hunk.old_lines.push(format!("{content}\n"));
+                        old_remaining -= 1;
+                        last = (true, false);
+                    }
+                    "+" if new_remaining > 0 => {
+                        hunk.new_lines.push(format!("{content}\n"));
+                        new_remaining -= 1;
+                        last = (false, true);
+                    }
+                    "\\" => {
+                        // `\ No newline at end of file` applies to the preceding line.
+                        if last.0 {
+                            if let Some(line) = hunk.old_lines.last_mut() {
+                                line.pop();
+                            }
+                        }
+                        if last.1 {
+                            if let Some(line) = hunk.new_lines.last_mut() {
+                                line.pop();
+                            }
+                        }
+                        last = (false, false);
+                    }
+                    _ => {
+                        return Err(PatchError::Malformed(
+                            index + 1,
+                            format!("unexpected line in hunk `{line}`"),
+                        ));
+                    }
+                }
+                index += 1;
+            }
+
+            file.hunks.push(hunk);
+            continue;
+        }
+
+        // Ignore any other lines (e.g., `diff --git` or `index` headers, or commentary).
+        index += 1;
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_to(files: &[(&str, &str)], patch: &str) -> Result<tempfile::TempDir, PatchError> {
+        let root = tempfile::tempdir()?;
+        for (path, contents) in files {
+            let path = root.path().join(path);
+            fs_err::create_dir_all(path.parent().unwrap())?;
+            fs_err::write(path, contents)?;
+        }
+        apply(root.path(), patch)?;
+        Ok(root)
+    }
+
+    #[test]
+    fn modify() {
+        let patch = "\
+diff --git a/pkg/__init__.py b/pkg/__init__.py
+index 1111111..2222222 100644
+--- a/pkg/__init__.py
++++ b/pkg/__init__.py
+@@ -1,3 +1,3 @@
+ a = 1
+-b = 2
++b = 3
+ c = 4
+";
+        let root = apply_to(&[("pkg/__init__.py", "a = 1\nb = 2\nc = 4\n")], patch).unwrap();
+        assert_eq!(
+            fs_err::read_to_string(root.path().join("pkg/__init__.py")).unwrap(),
+            "a = 1\nb = 3\nc = 4\n"
+        );
+    }
+
+    #[test]
+    fn offset() {
+        // The hunk claims to start at line 1, but the context appears later in the file.
+        let patch = "\
+--- setup.py\t2024-01-01 00:00:00
++++ setup.py\t2024-01-01 00:00:00
+@@ -1,2 +1,2 @@
+ x = 1
+-y = 2
++y = 5
+";
+        let root = apply_to(&[("setup.py", "# header\n\nx = 1\ny = 2\n")], patch).unwrap();
+        assert_eq!(
+            fs_err::read_to_string(root.path().join("setup.py")).unwrap(),
+            "# header\n\nx = 1\ny = 5\n"
+        );
+    }
+
+    #[test]
+    fn create_and_delete() {
+        let patch = "\
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1,2 @@
++hello
++world
+\\ No newline at end of file
+--- a/old.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-goodbye
+";
+        let root = apply_to(&[("old.txt", "goodbye\n")], patch).unwrap();
+        assert_eq!(
+            fs_err::read_to_string(root.path().join("new.txt")).unwrap(),
+            "hello\nworld"
+        );
+        assert!(!root.path().join("old.txt").exists());
+    }
+
+    #[test]
+    fn mismatch() {
+        let patch = "\
+--- a/setup.py
++++ b/setup.py
+@@ -1 +1 @@
+-x = 1
++x = 2
+";
+        let err = apply_to(&[("setup.py", "x = 3\n")], patch).unwrap_err();
+        assert_eq!(err.to_string(), "Hunk #1 failed to apply to `setup.py`");
+    }
+
+    #[test]
+    fn invalid_path() {
+        let patch = "\
+--- a/../escape.py
++++ b/../escape.py
+@@ -0,0 +1 @@
++x = 1
+";
+        let err = apply_to(&[], patch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The patch references an invalid path: `../escape.py`"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use futures::TryStreamExt;
+use reqwest::StatusCode;
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{CacheShard, RemoteCache};
+use uv_client::RegistryClient;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::BuildInfo;
+use uv_fs::{rename_with_retry, write_atomic};
+use uv_normalize::PackageName;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::error::Error;
+use crate::source::built_wheel_metadata::BuiltWheelFile;
+
+/// The name of the object that lists the wheels available for a source distribution in the
+/// remote cache, one filename per line.
+const INDEX: &str = "index";
+
+/// The wheels built from a single source distribution in a [`RemoteCache`].
+///
+/// Objects are laid out as `built-wheels/{name}/{sha256}/{build}/`, where `{sha256}` is the
+/// SHA-256 hash of the source distribution archive and `{build}` is the [`BuildInfo`] cache shard
+/// (or `default`, in the absence of any build settings), mirroring the local cache layout.
+#[derive(Debug)]
+pub(crate) struct RemoteBuiltWheels<'a> {
+    remote: &'a RemoteCache,
+    prefix: String,
+}
+
+impl<'a> RemoteBuiltWheels<'a> {
+    /// Locate the wheels for a source distribution in the remote cache.
+    ///
+    /// Returns `None` if the source distribution can't be keyed, i.e., if it's unnamed or its
+    /// SHA-256 hash is unknown.
+    pub(crate) fn new(
+        remote: &'a RemoteCache,
+        name: Option<&PackageName>,
+        hashes: &[HashDigest],
+        build_info: &BuildInfo,
+    ) -> Option<Self> {
+        let name = name?;
+        let sha256 = hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?;
+        let build = build_info
+            .cache_shard()
+            .unwrap_or_else(|| "default".to_string());
+        Some(Self {
+            remote,
+            prefix: format!("built-wheels/{name}/{}/{build}", sha256.digest),
+        })
+    }
+
+    /// Returns `true` if wheels built locally should be uploaded to the remote cache.
+    pub(crate) fn write(&self) -> bool {
+        self.remote.write()
+    }
+
+    /// Return the URL of the object with the given name.
+    fn object(&self, name: &str) -> DisplaySafeUrl {
+        self.remote.object(&format!("{}/{name}", self.prefix))
+    }
+
+    /// Read the filenames of the wheels available in the remote cache.
+    async fn index(&self, client: &RegistryClient) -> Result<Vec<WheelFilename>, Error> {
+        let url = self.object(INDEX);
+        let response = client
+            .uncached_client(&url)
+            .get(Url::from(url))
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        let text = response.error_for_status()?.text().await?;
+        Ok(text
+            .lines()
+            .filter_map(|line| WheelFilename::from_str(line.trim()).ok())
+            .collect())
+    }
+
+    /// Download a wheel compatible with the given tags into the cache shard, if one is available.
+    pub(crate) async fn fetch(
+        &self,
+        tags: &Tags,
+        cache_shard: &CacheShard,
+        client: &RegistryClient,
+    ) -> Result<Option<BuiltWheelFile>, Error> {
+        let Some(filename) = self
+            .index(client)
+            .await?
+            .into_iter()
+            .find(|filename| filename.is_compatible(tags))
+        else {
+            return Ok(None);
+        };
+
+        let url = self.object(&filename.to_string());
+        debug!("Downloading wheel from remote cache: {url}");
+        let response = client
+            .uncached_client(&url)
+            .get(Url::from(url))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        // Download into a temporary file, to prevent partial writes.
+        fs_err::tokio::create_dir_all(cache_shard)
+            .await
+            .map_err(Error::CacheWrite)?;
+        let temp_file = tempfile::NamedTempFile::new_in(cache_shard).map_err(Error::CacheWrite)?;
+        let mut reader = response
+            .bytes_stream()
+            .map_err(std::io::Error::other)
+            .into_async_read()
+            .compat();
+        let mut writer = fs_err::tokio::File::create(temp_file.path())
+            .await
+            .map_err(Error::CacheWrite)?;
+        tokio::io::copy(&mut reader, &mut writer)
+            .await
+            .map_err(Error::CacheWrite)?;
+        drop(writer);
+
+        let path = cache_shard.join(filename.to_string());
+        rename_with_retry(temp_file.into_temp_path(), &path)
+            .await
+            .map_err(Error::CacheWrite)?;
+
+        Ok(Some(BuiltWheelFile {
+            target: cache_shard.join(filename.stem()).into_boxed_path(),
+            path: path.into_boxed_path(),
+            filename,
+        }))
+    }
+
+    /// Upload a wheel built locally to the remote cache, and add it to the index.
+    pub(crate) async fn upload(
+        &self,
+        filename: &WheelFilename,
+        wheel: &Path,
+        client: &RegistryClient,
+    ) -> Result<(), Error> {
+        let url = self.object(&filename.to_string());
+        debug!("Uploading wheel to remote cache: {url}");
+        let contents = fs_err::tokio::read(wheel).await.map_err(Error::CacheRead)?;
+        client
+            .uncached_client(&url)
+            .put(Url::from(url))
+            .body(contents)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        // Add the wheel to the index, unless another writer has already done so.
+        let mut index = self.index(client).await?;
+        if index.contains(filename) {
+            return Ok(());
+        }
+        index.push(filename.clone());
+        let contents = index
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let url = self.object(INDEX);
+        client
+            .uncached_client(&url)
+            .put(Url::from(url))
+            .body(contents)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Write the fetched wheel's metadata alongside it, as if it had been built locally.
+pub(crate) async fn write_metadata(
+    file: &BuiltWheelFile,
+    metadata_path: &Path,
+) -> Result<(), Error> {
+    let metadata = super::read_wheel_metadata(&file.filename, &file.path)?;
+    write_atomic(metadata_path, rmp_serde::to_vec(&metadata)?)
+        .await
+        .map_err(Error::CacheWrite)
+}
@@ -4,6 +4,8 @@ use std::{ffi::OsString, path::PathBuf};
 pub enum Error {
     #[error("I/O operation failed during extraction")]
     Io(#[source] std::io::Error),
+    #[error("The archive contains a path that can't be created on Windows (`{component}` is a reserved name): {}", path.display())]
+    ReservedPath { path: PathBuf, component: String },
     #[error("Invalid zip file")]
     Zip(#[from] zip::result::ZipError),
     #[error("Invalid zip file structure")]
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::sync::LazyLock;
 
 pub use error::Error;
@@ -42,6 +43,20 @@ pub(crate) fn validate_archive_member_name(name: &str) -> Result<(), Error> {
     }
 }
 
+/// On Windows, reject any archive member whose path can't be created, like those that contain a
+/// reserved device name (e.g., `CON` or `NUL`).
+pub(crate) fn check_reserved_path(path: &Path) -> Result<(), Error> {
+    if cfg!(windows) {
+        if let Some(component) = uv_fs::windows_reserved_component(path) {
+            return Err(Error::ReservedPath {
+                path: path.to_path_buf(),
+                component: component.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Returns `true` if ZIP validation is disabled.
 pub(crate) fn insecure_no_validate() -> bool {
     // TODO(charlie) Parse this in `EnvironmentOptions`.
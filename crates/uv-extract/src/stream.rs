@@ -10,7 +10,7 @@ use tracing::{debug, warn};
 
 use uv_distribution_filename::SourceDistExtension;
 
-use crate::{Error, insecure_no_validate, validate_archive_member_name};
+use crate::{Error, check_reserved_path, insecure_no_validate, validate_archive_member_name};
 
 const DEFAULT_BUF_SIZE: usize = 128 * 1024;
 
@@ -70,7 +70,8 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
     // Determine whether ZIP validation is disabled.
     let skip_validation = insecure_no_validate();
 
-    let target = target.as_ref();
+    // On Windows, use an extended-length path, to support deeply nested files.
+    let target = uv_fs::extended_length(target.as_ref());
     let mut reader = futures::io::BufReader::with_capacity(DEFAULT_BUF_SIZE, reader.compat());
     let mut zip = async_zip::base::read::stream::ZipFileReader::new(&mut reader);
 
@@ -107,6 +108,9 @@ pub async fn unzip<R: tokio::io::AsyncRead + Unpin>(
             continue;
         };
 
+        // Reject any paths that would refer to a device (or be silently renamed) on Windows.
+        check_reserved_path(&relpath)?;
+
         let file_offset = entry.reader().entry().file_offset();
         let expected_compressed_size = entry.reader().entry().compressed_size();
         let expected_uncompressed_size = entry.reader().entry().uncompressed_size();
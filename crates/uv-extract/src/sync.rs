@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 use std::sync::{LazyLock, Mutex};
 
 use crate::vendor::{CloneableSeekableReader, HasLength};
-use crate::{Error, insecure_no_validate, validate_archive_member_name};
+use crate::{Error, check_reserved_path, insecure_no_validate, validate_archive_member_name};
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use tracing::warn;
@@ -14,6 +14,9 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
     reader: R,
     target: &Path,
 ) -> Result<(), Error> {
+    // On Windows, use an extended-length path, to support deeply nested files.
+    let target = uv_fs::extended_length(target);
+
     // Unzip in parallel.
     let reader = std::io::BufReader::new(reader);
     let archive = ZipArchive::new(CloneableSeekableReader::new(reader))?;
@@ -39,6 +42,9 @@ pub fn unzip<R: Send + std::io::Read + std::io::Seek + HasLength>(
                 return Ok(());
             };
 
+            // Reject any paths that would refer to a device (or be silently renamed) on Windows.
+            check_reserved_path(&enclosed_name)?;
+
             // Create necessary parent directories.
             let path = target.join(enclosed_name);
             if file.is_dir() {
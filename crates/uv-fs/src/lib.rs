@@ -276,6 +276,37 @@ pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io
     persist_with_retry_sync(temp_file, to.as_ref())
 }
 
+/// Atomically exchange two paths, such that each refers to what was previously at the other.
+///
+/// Returns an error of kind [`std::io::ErrorKind::Unsupported`] if the platform or file system
+/// doesn't support exchanging paths, which is only supported on Linux (via `renameat2`).
+pub fn exchange(left: impl AsRef<Path>, right: impl AsRef<Path>) -> std::io::Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        use rustix::fs::{CWD, RenameFlags, renameat_with};
+        use rustix::io::Errno;
+
+        match renameat_with(
+            CWD,
+            left.as_ref(),
+            CWD,
+            right.as_ref(),
+            RenameFlags::EXCHANGE,
+        ) {
+            Ok(()) => Ok(()),
+            Err(Errno::INVAL | Errno::NOSYS | Errno::OPNOTSUPP) => {
+                Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+            }
+            Err(err) => Err(std::io::Error::from(err)),
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (left, right);
+        Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+    }
+}
+
 #[cfg(windows)]
 fn backoff_file_move() -> backon::ExponentialBackoff {
     use backon::BackoffBuilder;
@@ -789,6 +820,41 @@ impl LockedFile {
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Acquire a cross-process lock for a resource using a file at the provided path, giving up
+    /// with an [`std::io::ErrorKind::TimedOut`] error if another process doesn't release the lock
+    /// within the given timeout.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_timeout(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+        timeout: std::time::Duration,
+    ) -> Result<Self, std::io::Error> {
+        let file = Self::create(path)?;
+        let resource = resource.to_string();
+        let start = std::time::Instant::now();
+        loop {
+            match file.file().try_lock() {
+                Ok(()) => {
+                    debug!("Acquired lock for `{resource}`");
+                    return Ok(Self(file));
+                }
+                Err(err) if is_known_already_locked_error(&err) => {}
+                Err(err) => return Err(err.into()),
+            }
+            if start.elapsed() >= timeout {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!(
+                        "Timed out after {}s waiting to acquire lock for `{resource}` at `{}`",
+                        timeout.as_secs(),
+                        file.path().user_display(),
+                    ),
+                ));
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
     /// Acquire a cross-process read lock for a shared resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire_shared(
@@ -967,4 +1033,23 @@ mod tests {
         let result = with_added_extension(&path, "exe");
         assert_eq!(result, path); // Should return unchanged
     }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn acquire_timeout() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(".lock");
+
+        let lock = LockedFile::acquire(&path, "test").await.unwrap();
+        let err = LockedFile::acquire_timeout(&path, "test", std::time::Duration::from_millis(200))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+
+        drop(lock);
+        let _lock =
+            LockedFile::acquire_timeout(&path, "test", std::time::Duration::from_millis(200))
+                .await
+                .unwrap();
+    }
 }
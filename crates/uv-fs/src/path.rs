@@ -445,6 +445,84 @@ impl AsRef<Path> for PortablePathBuf {
     }
 }
 
+/// Convert an absolute path to its extended-length form on Windows, lifting the `MAX_PATH` limit
+/// of 260 characters (e.g., for deeply nested packages in a monorepo).
+///
+/// For example, transforms `C:\Users\ferris` to `\\?\C:\Users\ferris`, and
+/// `\\server\share\ferris` to `\\?\UNC\server\share\ferris`. Since extended-length paths are
+/// passed to the filesystem verbatim, the path is normalized first.
+///
+/// Relative paths and paths that are already in extended-length form are returned as-is. On other
+/// platforms, this is a no-op.
+pub fn extended_length(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::path::Prefix;
+
+        let Some(Component::Prefix(prefix)) = path.components().next() else {
+            return Cow::Borrowed(path);
+        };
+        if !path.has_root() {
+            return Cow::Borrowed(path);
+        }
+        let mut extended = match prefix.kind() {
+            Prefix::Disk(letter) => PathBuf::from(format!(r"\\?\{}:\", char::from(letter))),
+            Prefix::UNC(server, share) => {
+                let mut extended = PathBuf::from(r"\\?\UNC\");
+                extended.push(server);
+                extended.push(share);
+                extended
+            }
+            Prefix::Verbatim(_)
+            | Prefix::VerbatimUNC(..)
+            | Prefix::VerbatimDisk(_)
+            | Prefix::DeviceNS(_) => return Cow::Borrowed(path),
+        };
+        for component in normalize_path(path).components() {
+            if let Component::Normal(component) = component {
+                extended.push(component);
+            }
+        }
+        Cow::Owned(extended)
+    }
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
+/// Return the first component of a path that can't be created on Windows, either because it's a
+/// reserved device name (like `CON`, `NUL`, or `COM1`, with or without an extension) or because it
+/// ends in a dot or a space.
+///
+/// Such components are rejected by Windows APIs or, worse, silently refer to a device instead of a
+/// file, so they're checked explicitly to surface a precise error.
+pub fn windows_reserved_component(path: &Path) -> Option<&str> {
+    const RESERVED: &[&str] = &[
+        "CON", "PRN", "AUX", "NUL", "CONIN$", "CONOUT$", "COM0", "COM1", "COM2", "COM3", "COM4",
+        "COM5", "COM6", "COM7", "COM8", "COM9", "COM¹", "COM²", "COM³", "LPT0", "LPT1", "LPT2",
+        "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9", "LPT¹", "LPT²", "LPT³",
+    ];
+
+    path.components().find_map(|component| {
+        let Component::Normal(component) = component else {
+            return None;
+        };
+        let component = component.to_str()?;
+        if component.ends_with(['.', ' ']) {
+            return Some(component);
+        }
+        let stem = component
+            .split_once('.')
+            .map_or(component, |(stem, _)| stem)
+            .trim_end_matches(' ');
+        RESERVED
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+            .then_some(component)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,4 +628,59 @@ mod tests {
             assert_eq!(normalize_path(Path::new(input)), Path::new(expected));
         }
     }
+
+    #[test]
+    fn test_windows_reserved_component() {
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/con/bar.py")),
+            Some("con")
+        );
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/NUL.txt")),
+            Some("NUL.txt")
+        );
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/Com1.tar.gz")),
+            Some("Com1.tar.gz")
+        );
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/bar./baz.py")),
+            Some("bar.")
+        );
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/bar /baz.py")),
+            Some("bar ")
+        );
+        assert_eq!(
+            windows_reserved_component(Path::new("foo/console.py")),
+            None
+        );
+        assert_eq!(windows_reserved_component(Path::new("foo/com10.py")), None);
+        assert_eq!(windows_reserved_component(Path::new("foo/.venv/lib")), None);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_extended_length() {
+        assert_eq!(
+            extended_length(Path::new(r"C:\Users\ferris\..\crab\.venv")),
+            Path::new(r"\\?\C:\Users\crab\.venv")
+        );
+        assert_eq!(
+            extended_length(Path::new(r"C:/Users/ferris")),
+            Path::new(r"\\?\C:\Users\ferris")
+        );
+        assert_eq!(
+            extended_length(Path::new(r"\\server\share\ferris\.venv")),
+            Path::new(r"\\?\UNC\server\share\ferris\.venv")
+        );
+        assert_eq!(
+            extended_length(Path::new(r"\\?\C:\Users\ferris")),
+            Path::new(r"\\?\C:\Users\ferris")
+        );
+        assert_eq!(
+            extended_length(Path::new(r"Users\ferris")),
+            Path::new(r"Users\ferris")
+        );
+    }
 }
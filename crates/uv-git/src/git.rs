@@ -2,7 +2,7 @@
 //! Cargo is dual-licensed under either Apache 2.0 or MIT, at the user's choice.
 //! Source: <https://github.com/rust-lang/cargo/blob/23eb492cf920ce051abfc56bbaf838514dc8365c/src/cargo/sources/git/utils.rs>
 use std::fmt::Display;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::str::{self};
 use std::sync::LazyLock;
 
@@ -20,6 +20,9 @@ use uv_static::EnvVars;
 /// checkout is ready to go. See [`GitCheckout::reset`] for why we need this.
 const CHECKOUT_READY_LOCK: &str = ".ok";
 
+/// The object filter used for blobless fetches, which omit file contents until they're needed.
+const BLOBLESS_FILTER: &str = "blob:none";
+
 #[derive(Debug, thiserror::Error)]
 pub enum GitError {
     #[error("Git executable not found. Ensure that Git is installed and available.")]
@@ -139,6 +142,30 @@ pub(crate) struct GitDatabase {
     repo: GitRepository,
 }
 
+/// A sparse checkout of a single subdirectory, backed by a blobless [`GitDatabase`].
+pub(crate) struct SparseCheckout<'a> {
+    /// The subdirectory to check out, relative to the repository root.
+    pub(crate) subdirectory: &'a Path,
+    /// The remote from which any missing blobs should be fetched.
+    pub(crate) remote: &'a GitRemote,
+    /// Whether to disable SSL verification when fetching missing blobs.
+    pub(crate) disable_ssl: bool,
+}
+
+impl SparseCheckout<'_> {
+    /// Configure a Git command to lazily fetch missing blobs from the remote.
+    ///
+    /// The URL is passed on the command line, rather than persisted to the repository
+    /// configuration, to avoid writing any embedded credentials to disk.
+    fn configure(&self, cmd: &mut ProcessBuilder) {
+        cmd.arg("-c")
+            .arg(format!("remote.origin.url={}", self.remote.url.as_str()));
+        if self.disable_ssl {
+            cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
+        }
+    }
+}
+
 /// A local checkout of a particular revision from a [`GitRepository`].
 pub(crate) struct GitCheckout {
     /// The git revision this checkout is for.
@@ -219,6 +246,9 @@ impl GitRemote {
     ///
     /// When `locked_rev` is provided, it takes precedence over `reference`.
     ///
+    /// When `blobless` is set, file contents are omitted from the fetch, to be fetched lazily
+    /// by a [`SparseCheckout`].
+    ///
     /// If we have a previous instance of [`GitDatabase`] then fetch into that
     /// if we can. If that can successfully load our revision then we've
     /// populated the database with the latest version of `reference`, so
@@ -229,17 +259,28 @@ impl GitRemote {
         db: Option<GitDatabase>,
         reference: &GitReference,
         locked_rev: Option<GitOid>,
+        blobless: bool,
         disable_ssl: bool,
         offline: bool,
     ) -> Result<(GitDatabase, GitOid)> {
         let reference = locked_rev
             .map(ReferenceOrOid::Oid)
             .unwrap_or(ReferenceOrOid::Reference(reference));
-        let enable_lfs_fetch = std::env::var(EnvVars::UV_GIT_LFS).is_ok();
+
+        // In a blobless database, the LFS objects are instead fetched by the smudge filter when
+        // the subdirectory is checked out.
+        let enable_lfs_fetch = lfs_enabled() && !blobless;
 
         if let Some(mut db) = db {
-            fetch(&mut db.repo, &self.url, reference, disable_ssl, offline)
-                .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
+            fetch(
+                &mut db.repo,
+                &self.url,
+                reference,
+                blobless,
+                disable_ssl,
+                offline,
+            )
+            .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
                 Some(rev) => db.contains(rev).then_some(rev),
@@ -266,8 +307,15 @@ impl GitRemote {
 
         fs_err::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, &self.url, reference, disable_ssl, offline)
-            .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
+        fetch(
+            &mut repo,
+            &self.url,
+            reference,
+            blobless,
+            disable_ssl,
+            offline,
+        )
+        .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
             None => reference.resolve(&repo)?,
@@ -290,7 +338,14 @@ impl GitRemote {
 
 impl GitDatabase {
     /// Checkouts to a revision at `destination` from this database.
-    pub(crate) fn copy_to(&self, rev: GitOid, destination: &Path) -> Result<GitCheckout> {
+    ///
+    /// If `sparse` is provided, only the given subdirectory is checked out.
+    pub(crate) fn copy_to(
+        &self,
+        rev: GitOid,
+        destination: &Path,
+        sparse: Option<&SparseCheckout<'_>>,
+    ) -> Result<GitCheckout> {
         // If the existing checkout exists, and it is fresh, use it.
         // A non-fresh checkout can happen if the checkout operation was
         // interrupted. In that case, the checkout gets deleted and a new
@@ -301,7 +356,10 @@ impl GitDatabase {
             .filter(GitCheckout::is_fresh)
         {
             Some(co) => co,
-            None => GitCheckout::clone_into(destination, self, rev)?,
+            None => match sparse {
+                Some(sparse) => GitCheckout::sparse_into(destination, self, rev, sparse)?,
+                None => GitCheckout::clone_into(destination, self, rev)?,
+            },
         };
         Ok(checkout)
     }
@@ -372,7 +430,72 @@ impl GitCheckout {
 
         let repo = GitRepository::open(into)?;
         let checkout = Self::new(revision, repo);
-        checkout.reset()?;
+        checkout.reset(None)?;
+        Ok(checkout)
+    }
+
+    /// Check out a single subdirectory of a `revision` into a local path from a blobless
+    /// `database`.
+    ///
+    /// Cloning the database would require every blob to be present. Instead, the checkout borrows
+    /// the database's commits and trees via `objects/info/alternates`, and the blobs within the
+    /// subdirectory are fetched from the remote on demand.
+    fn sparse_into(
+        into: &Path,
+        database: &GitDatabase,
+        revision: GitOid,
+        sparse: &SparseCheckout<'_>,
+    ) -> Result<Self> {
+        let dirname = into.parent().unwrap();
+        fs_err::create_dir_all(dirname)?;
+        match fs_err::remove_dir_all(into) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        fs_err::create_dir_all(into)?;
+
+        let repo = GitRepository::init(into)?;
+        let git_dir = into.join(".git");
+
+        // Borrow the objects from the database.
+        let info = git_dir.join("objects").join("info");
+        fs_err::create_dir_all(&info)?;
+        fs_err::write(
+            info.join("alternates"),
+            format!(
+                "{}\n",
+                database.repo.path.join(".git").join("objects").display()
+            ),
+        )?;
+
+        // Mark `origin` as a promisor remote, such that missing blobs are fetched lazily.
+        for (key, value) in [
+            ("core.repositoryformatversion", "1"),
+            ("extensions.partialClone", "origin"),
+            ("remote.origin.promisor", "true"),
+            ("remote.origin.partialclonefilter", BLOBLESS_FILTER),
+            ("core.sparseCheckout", "true"),
+            ("core.sparseCheckoutCone", "true"),
+        ] {
+            ProcessBuilder::new(GIT.as_ref()?)
+                .arg("config")
+                .arg(key)
+                .arg(value)
+                .cwd(into)
+                .exec_with_output()?;
+        }
+
+        // Restrict the working tree to the subdirectory.
+        let info = git_dir.join("info");
+        fs_err::create_dir_all(&info)?;
+        fs_err::write(
+            info.join("sparse-checkout"),
+            sparse_checkout_patterns(sparse.subdirectory),
+        )?;
+
+        let checkout = Self::new(revision, repo);
+        checkout.reset(Some(sparse))?;
         Ok(checkout)
     }
 
@@ -400,28 +523,43 @@ impl GitCheckout {
     /// *doesn't* exist, and then once we're done we create the file.
     ///
     /// [`.cargo-ok`]: CHECKOUT_READY_LOCK
-    fn reset(&self) -> Result<()> {
+    fn reset(&self, sparse: Option<&SparseCheckout<'_>>) -> Result<()> {
         let ok_file = self.repo.path.join(CHECKOUT_READY_LOCK);
         let _ = paths::remove_file(&ok_file);
         debug!("Reset {} to {}", self.repo.path.display(), self.revision);
 
+        let git = || -> Result<ProcessBuilder> {
+            let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
+            if let Some(sparse) = sparse {
+                sparse.configure(&mut cmd);
+            }
+            // Unless LFS is enabled, check out the LFS pointer files as-is, rather than attempting
+            // to download the objects they refer to.
+            if !lfs_enabled() {
+                cmd.env(EnvVars::GIT_LFS_SKIP_SMUDGE, "1");
+            }
+            cmd.cwd(&self.repo.path);
+            Ok(cmd)
+        };
+
         // Perform the hard reset.
-        ProcessBuilder::new(GIT.as_ref()?)
+        git()?
             .arg("reset")
             .arg("--hard")
             .arg(self.revision.as_str())
-            .cwd(&self.repo.path)
             .exec_with_output()?;
 
-        // Update submodules (`git submodule update --recursive`).
-        ProcessBuilder::new(GIT.as_ref()?)
-            .arg("submodule")
+        // Update submodules (`git submodule update --recursive`), limited to the subdirectory in
+        // a sparse checkout.
+        let mut cmd = git()?;
+        cmd.arg("submodule")
             .arg("update")
             .arg("--recursive")
-            .arg("--init")
-            .cwd(&self.repo.path)
-            .exec_with_output()
-            .map(drop)?;
+            .arg("--init");
+        if let Some(sparse) = sparse {
+            cmd.arg("--").arg(sparse.subdirectory);
+        }
+        cmd.exec_with_output().map(drop)?;
 
         paths::create(ok_file)?;
         Ok(())
@@ -440,6 +578,7 @@ fn fetch(
     repo: &mut GitRepository,
     remote_url: &Url,
     reference: ReferenceOrOid<'_>,
+    blobless: bool,
     disable_ssl: bool,
     offline: bool,
 ) -> Result<()> {
@@ -527,6 +666,7 @@ fn fetch(
             remote_url,
             refspecs.as_slice(),
             tags,
+            blobless,
             disable_ssl,
             offline,
         ),
@@ -540,6 +680,7 @@ fn fetch(
                         remote_url,
                         std::slice::from_ref(refspec),
                         tags,
+                        blobless,
                         disable_ssl,
                         offline,
                     );
@@ -582,11 +723,13 @@ fn fetch(
 }
 
 /// Attempts to use `git` CLI installed on the system to fetch a repository.
+#[allow(clippy::fn_params_excessive_bools)]
 fn fetch_with_cli(
     repo: &mut GitRepository,
     url: &Url,
     refspecs: &[String],
     tags: bool,
+    blobless: bool,
     disable_ssl: bool,
     offline: bool,
 ) -> Result<()> {
@@ -596,10 +739,21 @@ fn fetch_with_cli(
     // are still usable.
     cmd.env(EnvVars::GIT_TERMINAL_PROMPT, "0");
 
+    // A partial fetch must go through a named remote, which Git then registers as a promisor
+    // remote. As in `SparseCheckout::configure`, the URL itself is only passed on the command
+    // line.
+    if blobless {
+        cmd.arg("-c")
+            .arg(format!("remote.origin.url={}", url.as_str()));
+    }
+
     cmd.arg("fetch");
     if tags {
         cmd.arg("--tags");
     }
+    if blobless {
+        cmd.arg(format!("--filter={BLOBLESS_FILTER}"));
+    }
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch via `GIT_SSL_NO_VERIFY`");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
@@ -610,7 +764,7 @@ fn fetch_with_cli(
     }
     cmd.arg("--force") // handle force pushes
         .arg("--update-head-ok") // see discussion in #2078
-        .arg(url.as_str())
+        .arg(if blobless { "origin" } else { url.as_str() })
         .args(refspecs)
         // If cargo is run by git (for example, the `exec` command in `git
         // rebase`), the GIT_DIR is set by git and will point to the wrong
@@ -639,6 +793,11 @@ fn fetch_with_cli(
     Ok(())
 }
 
+/// Returns `true` if fetching files stored in Git LFS is enabled.
+fn lfs_enabled() -> bool {
+    std::env::var(EnvVars::UV_GIT_LFS).is_ok()
+}
+
 /// A global cache of the `git lfs` command.
 ///
 /// Returns an error if Git LFS isn't available.
@@ -688,6 +847,31 @@ fn fetch_lfs(
     Ok(())
 }
 
+/// Return the cone-mode sparse-checkout patterns that include the given subdirectory, along with
+/// the files at the root of the repository (e.g., a workspace `pyproject.toml`) and in each
+/// intermediate directory.
+fn sparse_checkout_patterns(subdirectory: &Path) -> String {
+    let components = subdirectory
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut patterns = vec!["/*".to_string(), "!/*/".to_string()];
+    let mut prefix = String::new();
+    for (index, component) in components.iter().enumerate() {
+        prefix.push('/');
+        prefix.push_str(component);
+        patterns.push(format!("{prefix}/"));
+        if index + 1 < components.len() {
+            patterns.push(format!("!{prefix}/*/"));
+        }
+    }
+    patterns.into_iter().map(|pattern| pattern + "\n").collect()
+}
+
 /// Whether `rev` is a shorter hash of `oid`.
 fn is_short_hash_of(rev: &str, oid: GitOid) -> bool {
     let long_hash = oid.to_string();
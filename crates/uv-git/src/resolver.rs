@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -141,9 +141,12 @@ impl GitResolver {
     }
 
     /// Fetch a remote Git repository.
+    ///
+    /// If a `subdirectory` is provided, only that subdirectory is required from the checkout.
     pub async fn fetch(
         &self,
         url: &GitUrl,
+        subdirectory: Option<&Path>,
         disable_ssl: bool,
         offline: bool,
         cache: PathBuf,
@@ -178,7 +181,8 @@ impl GitResolver {
             GitSource::new(url.as_ref().clone(), cache, offline).with_reporter(reporter)
         } else {
             GitSource::new(url.as_ref().clone(), cache, offline)
-        };
+        }
+        .with_subdirectory(subdirectory.map(Path::to_path_buf));
 
         // If necessary, disable SSL.
         let source = if disable_ssl {
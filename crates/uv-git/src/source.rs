@@ -3,7 +3,7 @@
 //! Source: <https://github.com/rust-lang/cargo/blob/23eb492cf920ce051abfc56bbaf838514dc8365c/src/cargo/sources/git/source.rs>
 
 use std::borrow::Cow;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -12,14 +12,17 @@ use tracing::{debug, instrument};
 use uv_cache_key::{RepositoryUrl, cache_digest};
 use uv_git_types::{GitOid, GitReference, GitUrl};
 use uv_redacted::DisplaySafeUrl;
+use uv_static::EnvVars;
 
 use crate::GIT_STORE;
-use crate::git::{GitDatabase, GitRemote};
+use crate::git::{GitDatabase, GitRemote, SparseCheckout};
 
 /// A remote Git source that can be checked out locally.
 pub struct GitSource {
     /// The Git reference from the manifest file.
     git: GitUrl,
+    /// The subdirectory within the repository that's required, if any.
+    subdirectory: Option<PathBuf>,
     /// Whether to disable SSL verification.
     disable_ssl: bool,
     /// Whether to operate without network connectivity.
@@ -35,6 +38,7 @@ impl GitSource {
     pub fn new(git: GitUrl, cache: impl Into<PathBuf>, offline: bool) -> Self {
         Self {
             git,
+            subdirectory: None,
             disable_ssl: false,
             offline,
             cache: cache.into(),
@@ -51,6 +55,17 @@ impl GitSource {
         }
     }
 
+    /// Only require the given subdirectory of the repository.
+    ///
+    /// If sparse checkouts are enabled, the rest of the repository is omitted from the checkout.
+    #[must_use]
+    pub fn with_subdirectory(self, subdirectory: Option<PathBuf>) -> Self {
+        Self {
+            subdirectory,
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for the [`GitSource`].
     #[must_use]
     pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -66,9 +81,26 @@ impl GitSource {
         // Compute the canonical URL for the repository.
         let canonical = RepositoryUrl::new(self.git.repository());
 
-        // The path to the repo, within the Git database.
+        // Determine whether to perform a sparse checkout of the subdirectory. A subdirectory of
+        // `.` (or similar) refers to the repository root, which requires a full checkout.
+        let sparse_subdirectory = self
+            .subdirectory
+            .as_deref()
+            .filter(|_| std::env::var(EnvVars::UV_GIT_SPARSE_CHECKOUT).is_ok())
+            .filter(|subdirectory| {
+                subdirectory
+                    .components()
+                    .any(|component| matches!(component, Component::Normal(_)))
+            });
+
+        // The path to the repo, within the Git database. Blobless databases are stored separately,
+        // since a full checkout can't be created from them.
         let ident = cache_digest(&canonical);
-        let db_path = self.cache.join("db").join(&ident);
+        let db_path = if sparse_subdirectory.is_some() {
+            self.cache.join("db").join(format!("{ident}-blobless"))
+        } else {
+            self.cache.join("db").join(&ident)
+        };
 
         // Authenticate the URL, if necessary.
         let remote = if let Some(credentials) = GIT_STORE.get(&canonical) {
@@ -123,6 +155,7 @@ impl GitSource {
                 maybe_db,
                 self.git.reference(),
                 self.git.precise(),
+                sparse_subdirectory.is_some(),
                 self.disable_ssl,
                 self.offline,
             )?;
@@ -137,13 +170,43 @@ impl GitSource {
         // Check out `actual_rev` from the database to a scoped location on the
         // filesystem. This will use hard links and such to ideally make the
         // checkout operation here pretty fast.
-        let checkout_path = self
-            .cache
-            .join("checkouts")
-            .join(&ident)
-            .join(short_id.as_str());
+        //
+        // Sparse checkouts are keyed by the subdirectory, as they omit the rest of the repository.
+        let checkout_path = match sparse_subdirectory {
+            Some(subdirectory) => self
+                .cache
+                .join("checkouts")
+                .join(&ident)
+                .join(format!("{short_id}-{}", cache_digest(&subdirectory))),
+            None => self
+                .cache
+                .join("checkouts")
+                .join(&ident)
+                .join(short_id.as_str()),
+        };
 
-        db.copy_to(actual_rev, &checkout_path)?;
+        match sparse_subdirectory {
+            Some(subdirectory) => {
+                debug!(
+                    "Performing a sparse checkout of `{}` in `{}`",
+                    subdirectory.display(),
+                    self.git.repository()
+                );
+                let git_remote = GitRemote::new(&remote);
+                db.copy_to(
+                    actual_rev,
+                    &checkout_path,
+                    Some(&SparseCheckout {
+                        subdirectory,
+                        remote: &git_remote,
+                        disable_ssl: self.disable_ssl,
+                    }),
+                )?;
+            }
+            None => {
+                db.copy_to(actual_rev, &checkout_path, None)?;
+            }
+        }
 
         // Report the checkout operation to the reporter.
         if let Some(task) = maybe_task {
@@ -0,0 +1,361 @@
+//! Checks on the contents of a wheel, to reject suspicious wheels before they're installed.
+
+use std::path::{Component, Path};
+use std::sync::LazyLock;
+
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Error;
+use crate::record::{RecordPathFix, SanitizedRecordPath};
+
+/// The names of the top-level modules in the Python standard library, across all supported
+/// Python versions.
+static STDLIB_MODULES: LazyLock<FxHashSet<String>> = LazyLock::new(|| {
+    let modules: Vec<String> = serde_json::from_str(include_str!("stdlib_modules.json")).unwrap();
+    modules.into_iter().collect()
+});
+
+/// Returns `true` if the top-level module is part of the Python standard library, for any
+/// supported Python version.
+pub fn is_stdlib_module(module: &str) -> bool {
+    STDLIB_MODULES.contains(module)
+}
+
+/// The subdirectories of the `.data` directory of a wheel that map to an install location.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/binary-distribution-format/#the-data-directory>
+const DATA_DIRECTORIES: &[&str] = &["purelib", "platlib", "headers", "scripts", "data"];
+
+/// A check on the contents of a wheel, performed before the wheel is installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WheelCheck {
+    /// Reject wheels with files that would be installed outside of the environment's install
+    /// locations, i.e., with `..` path components or in an unknown `.data` subdirectory.
+    Prefixes,
+    /// Reject wheels whose `RECORD` lists files by absolute path.
+    AbsolutePaths,
+    /// Reject wheels containing world-writable files.
+    ///
+    /// Only enforced on Unix.
+    WorldWritable,
+    /// Reject wheels with top-level modules that shadow a module of the Python standard library.
+    StdlibShadowing,
+    /// Reject wheels whose `RECORD` lists malformed paths (e.g., non-UTF-8, absolute, or
+    /// `\`-separated paths), rather than normalizing them with a warning.
+    RecordPaths,
+}
+
+impl std::fmt::Display for WheelCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prefixes => write!(f, "prefixes"),
+            Self::AbsolutePaths => write!(f, "absolute-paths"),
+            Self::WorldWritable => write!(f, "world-writable"),
+            Self::StdlibShadowing => write!(f, "stdlib-shadowing"),
+            Self::RecordPaths => write!(f, "record-paths"),
+        }
+    }
+}
+
+/// A violation of a [`WheelCheck`].
+#[derive(Error, Debug)]
+pub enum ContentError {
+    #[error(
+        "The wheel contains `{0}`, which would be installed outside of the environment (rejected by the `prefixes` check)"
+    )]
+    OutsidePrefix(String),
+    #[error(
+        "The wheel's `RECORD` lists an absolute path: `{0}` (rejected by the `absolute-paths` check)"
+    )]
+    AbsolutePath(String),
+    #[error(
+        "The wheel contains a world-writable file: `{0}` (rejected by the `world-writable` check)"
+    )]
+    WorldWritable(String),
+    #[error(
+        "The wheel provides the top-level module `{0}`, which shadows a standard library module (rejected by the `stdlib-shadowing` check)"
+    )]
+    StdlibShadowing(String),
+    #[error(
+        "The wheel's `RECORD` lists a path that {1}: `{0}` (rejected by the `record-paths` check)"
+    )]
+    MalformedRecordPath(String, RecordPathFix),
+}
+
+/// The set of [`WheelCheck`]s to perform before installing a wheel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WheelChecks {
+    prefixes: bool,
+    absolute_paths: bool,
+    world_writable: bool,
+    stdlib_shadowing: bool,
+    record_paths: bool,
+}
+
+impl WheelChecks {
+    /// Enable the given checks.
+    pub fn from_checks(checks: &[WheelCheck]) -> Self {
+        Self {
+            prefixes: checks.contains(&WheelCheck::Prefixes),
+            absolute_paths: checks.contains(&WheelCheck::AbsolutePaths),
+            world_writable: checks.contains(&WheelCheck::WorldWritable),
+            stdlib_shadowing: checks.contains(&WheelCheck::StdlibShadowing),
+            record_paths: checks.contains(&WheelCheck::RecordPaths),
+        }
+    }
+
+    /// Returns `true` if no checks are enabled.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns `true` if malformed `RECORD` paths should be rejected, rather than normalized.
+    pub(crate) fn record_paths(&self) -> bool {
+        self.record_paths
+    }
+
+    /// Check the contents of an unpacked wheel.
+    pub(crate) fn check(&self, wheel: &Path, dist_info_prefix: &str) -> Result<(), Error> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        if self.prefixes || self.absolute_paths {
+            // Read the `RECORD` verbatim, as `read_wheel_record` normalizes the paths.
+            let record = wheel.join(format!("{dist_info_prefix}.dist-info/RECORD"));
+            for entry in csv::ReaderBuilder::new()
+                .has_headers(false)
+                .escape(Some(b'"'))
+                .from_path(record)?
+                .byte_records()
+            {
+                let entry = entry?;
+                let path = String::from_utf8_lossy(entry.get(0).unwrap_or_default());
+                self.check_record_path(&path, dist_info_prefix)?;
+            }
+        }
+
+        if self.stdlib_shadowing {
+            check_top_level_modules(wheel, dist_info_prefix)?;
+        }
+
+        #[cfg(unix)]
+        if self.world_writable {
+            use std::os::unix::fs::PermissionsExt;
+
+            for entry in walkdir::WalkDir::new(wheel) {
+                let entry = entry?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                if entry.metadata()?.permissions().mode() & 0o002 != 0 {
+                    let relative = entry.path().strip_prefix(wheel).unwrap_or(entry.path());
+                    return Err(ContentError::WorldWritable(relative.display().to_string()).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check a path listed in the `RECORD` of a wheel.
+    fn check_record_path(&self, path: &str, dist_info_prefix: &str) -> Result<(), ContentError> {
+        if self.absolute_paths && is_absolute(path) {
+            return Err(ContentError::AbsolutePath(path.to_string()));
+        }
+        if self.prefixes {
+            let relative = Path::new(path.trim_start_matches(['/', '\\']));
+            if relative
+                .components()
+                .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+            {
+                return Err(ContentError::OutsidePrefix(path.to_string()));
+            }
+            let data_dir = format!("{dist_info_prefix}.data");
+            let mut components = relative.components();
+            if components
+                .next()
+                .is_some_and(|component| component.as_os_str() == data_dir.as_str())
+                && let Some(key) = components.next()
+                && !DATA_DIRECTORIES
+                    .iter()
+                    .any(|directory| key.as_os_str() == *directory)
+            {
+                return Err(ContentError::OutsidePrefix(path.to_string()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if a `RECORD` path is absolute on any platform, e.g., `/etc/passwd` or
+/// `C:\Windows`.
+fn is_absolute(path: &str) -> bool {
+    path.starts_with(['/', '\\'])
+        || matches!(path.as_bytes(), [drive, b':', ..] if drive.is_ascii_alphabetic())
+}
+
+/// Reject top-level modules of the wheel that shadow a standard library module, including those
+/// installed from the `purelib` and `platlib` data directories.
+fn check_top_level_modules(wheel: &Path, dist_info_prefix: &str) -> Result<(), Error> {
+    let data_dir = wheel.join(format!("{dist_info_prefix}.data"));
+    let roots = [
+        wheel.to_path_buf(),
+        data_dir.join("purelib"),
+        data_dir.join("platlib"),
+    ];
+    for root in roots {
+        if !root.is_dir() {
+            continue;
+        }
+        for entry in fs_err::read_dir(&root)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else {
+                continue;
+            };
+            let module = if entry.file_type()?.is_dir() {
+                if file_name.contains('.') {
+                    // E.g., the `.dist-info` and `.data` directories.
+                    continue;
+                }
+                file_name
+            } else {
+                let Some((module, extension)) = file_name.split_once('.') else {
+                    continue;
+                };
+                let extension = extension.rsplit('.').next().unwrap_or(extension);
+                if !matches!(extension, "py" | "pyc" | "so" | "pyd") {
+                    continue;
+                }
+                module
+            };
+            if is_stdlib_module(module) {
+                return Err(ContentError::StdlibShadowing(module.to_string()).into());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+
+    use super::{ContentError, WheelCheck, WheelChecks};
+    use crate::Error;
+
+    fn all() -> WheelChecks {
+        WheelChecks::from_checks(&[
+            WheelCheck::Prefixes,
+            WheelCheck::AbsolutePaths,
+            WheelCheck::WorldWritable,
+            WheelCheck::StdlibShadowing,
+            WheelCheck::RecordPaths,
+        ])
+    }
+
+    fn wheel(record: &str, files: &[&str]) -> Result<assert_fs::TempDir> {
+        let wheel = assert_fs::TempDir::new()?;
+        wheel
+            .child("foo-1.0.0.dist-info/RECORD")
+            .write_str(record)?;
+        for file in files {
+            wheel.child(file).touch()?;
+        }
+        Ok(wheel)
+    }
+
+    #[test]
+    fn valid() -> Result<()> {
+        let wheel = wheel(
+            indoc! {"
+                foo/__init__.py,,
+                foo-1.0.0.data/scripts/foo,,
+                foo-1.0.0.dist-info/RECORD,,
+            "},
+            &[
+                "foo/__init__.py",
+                "foo-1.0.0.data/scripts/foo",
+                "_foo.abi3.so",
+            ],
+        )?;
+        all().check(wheel.path(), "foo-1.0.0")?;
+        Ok(())
+    }
+
+    #[test]
+    fn disabled() -> Result<()> {
+        let wheel = wheel("/etc/passwd,,\n../../bin/foo,,\n", &["typing.py"])?;
+        WheelChecks::default().check(wheel.path(), "foo-1.0.0")?;
+        Ok(())
+    }
+
+    #[test]
+    fn absolute_path() -> Result<()> {
+        for path in ["/etc/passwd", "C:\\Windows\\foo.dll"] {
+            let wheel = wheel(&format!("\"{path}\",,\n"), &[])?;
+            let err = all().check(wheel.path(), "foo-1.0.0").unwrap_err();
+            assert!(
+                matches!(&err, Error::Content(ContentError::AbsolutePath(found)) if found == path),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn outside_prefix() -> Result<()> {
+        for path in ["../../bin/foo", "foo-1.0.0.data/config/foo.toml"] {
+            let wheel = wheel(&format!("{path},,\n"), &[])?;
+            let err = all().check(wheel.path(), "foo-1.0.0").unwrap_err();
+            assert!(
+                matches!(&err, Error::Content(ContentError::OutsidePrefix(found)) if found == path),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn stdlib_shadowing() -> Result<()> {
+        for file in [
+            "typing.py",
+            "asyncio/__init__.py",
+            "foo-1.0.0.data/purelib/enum.py",
+        ] {
+            let wheel = wheel("", &[file])?;
+            let err = all().check(wheel.path(), "foo-1.0.0").unwrap_err();
+            assert!(
+                matches!(&err, Error::Content(ContentError::StdlibShadowing(_))),
+                "{err}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn world_writable() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let wheel = wheel("", &["foo/__init__.py"])?;
+        fs_err::set_permissions(
+            wheel.child("foo/__init__.py").path(),
+            std::fs::Permissions::from_mode(0o666),
+        )?;
+        let err = all().check(wheel.path(), "foo-1.0.0").unwrap_err();
+        assert!(
+            matches!(&err, Error::Content(ContentError::WorldWritable(found)) if found == "foo/__init__.py"),
+            "{err}"
+        );
+        Ok(())
+    }
+}
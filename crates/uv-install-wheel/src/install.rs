@@ -9,14 +9,14 @@ use tracing::{instrument, trace};
 
 use uv_distribution_filename::WheelFilename;
 use uv_pep440::Version;
-use uv_pypi_types::{DirectUrl, Metadata10};
+use uv_pypi_types::{DirectUrl, HashDigest, Metadata10};
 
 use crate::linker::{LinkMode, Locks};
 use crate::wheel::{
     LibKind, WheelFile, dist_info_metadata, find_dist_info, install_data, parse_scripts,
-    read_record_file, write_installer_metadata, write_script_entrypoints,
+    read_wheel_record, write_installer_metadata, write_script_entrypoints,
 };
-use crate::{Error, Layout};
+use crate::{Error, Layout, ScriptPolicy, WheelChecks};
 
 /// Install the given wheel to the given venv
 ///
@@ -34,9 +34,12 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    hashes: &[HashDigest],
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     locks: &Locks,
 ) -> Result<(), Error> {
     let dist_info_prefix = find_dist_info(&wheel)?;
@@ -57,6 +60,9 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
         }
     }
 
+    // Check the contents of the wheel before anything is written to the environment.
+    wheel_checks.check(wheel.as_ref(), &dist_info_prefix)?;
+
     // We're going step by step though
     // https://packaging.python.org/en/latest/specifications/binary-distribution-format/#installing-a-wheel-distribution-1-0-py32-none-any-whl
     // > 1.a Parse distribution-1.0.dist-info/WHEEL.
@@ -69,21 +75,26 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
 
     // > 1.c If Root-Is-Purelib == ‘true’, unpack archive into purelib (site-packages).
     // > 1.d Else unpack archive into platlib (site-packages).
-    trace!(?name, "Extracting file");
     let site_packages = match lib_kind {
         LibKind::Pure => &layout.scheme.purelib,
         LibKind::Plat => &layout.scheme.platlib,
     };
-    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks, filename)?;
-    trace!(?name, "Extracted {num_unpacked} files");
 
-    // Read the RECORD file.
-    let mut record_file = File::open(
+    // Read the RECORD file, normalizing (or rejecting) any malformed paths.
+    let record_file = File::open(
         wheel
             .as_ref()
             .join(format!("{dist_info_prefix}.dist-info/RECORD")),
     )?;
-    let mut record = read_record_file(&mut record_file)?;
+    let mut record = read_wheel_record(record_file, filename, wheel_checks.record_paths())?;
+
+    // Detect any files that are provided with different contents by another package, before
+    // they're overwritten.
+    locks.register_files(site_packages, &record, filename)?;
+
+    trace!(?name, "Extracting file");
+    let num_unpacked = link_mode.link_wheel_files(site_packages, &wheel, locks, filename)?;
+    trace!(?name, "Extracted {num_unpacked} files");
 
     let (console_scripts, gui_scripts) =
         parse_scripts(&wheel, &dist_info_prefix, None, layout.python_version.1)?;
@@ -98,18 +109,26 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             layout,
             relocatable,
             site_packages,
+            &dist_info_prefix,
             &console_scripts,
             &mut record,
             false,
+            script_policy.launcher,
         )?;
-        write_script_entrypoints(
-            layout,
-            relocatable,
-            site_packages,
-            &gui_scripts,
-            &mut record,
-            true,
-        )?;
+        if script_policy.gui_scripts {
+            write_script_entrypoints(
+                layout,
+                relocatable,
+                site_packages,
+                &dist_info_prefix,
+                &gui_scripts,
+                &mut record,
+                true,
+                script_policy.launcher,
+            )?;
+        } else if !gui_scripts.is_empty() {
+            trace!(?name, "Skipping GUI script launchers");
+        }
     }
 
     // 2.a Unpacked archive includes distribution-1.0.dist-info/ and (if there is data) distribution-1.0.data/.
@@ -143,6 +162,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             direct_url,
             cache_info,
             build_info,
+            hashes,
             installer,
             &mut record,
         )?;
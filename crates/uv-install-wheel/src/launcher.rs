@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of launcher to generate for the console and GUI scripts of a wheel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ScriptLauncher {
+    /// Generate the platform's native launcher: a Python script with a shebang on Unix, or a
+    /// launcher executable on Windows.
+    #[default]
+    Native,
+    /// Generate a POSIX shell script that executes the entry point with the environment's
+    /// interpreter, regardless of the length of the interpreter path.
+    ///
+    /// Only supported on Unix.
+    Shell,
+    /// Write the launcher to the package's `.dist-info` directory, alongside its entry point
+    /// metadata, and symlink to it from the scripts directory.
+    ///
+    /// Only supported on Unix.
+    Symlink,
+}
+
+impl std::fmt::Display for ScriptLauncher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native => write!(f, "native"),
+            Self::Shell => write!(f, "shell"),
+            Self::Symlink => write!(f, "symlink"),
+        }
+    }
+}
+
+/// The launcher to generate for console and GUI scripts, either for every platform or per
+/// platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ScriptLaunchers {
+    /// Use the same launcher on every platform.
+    All(ScriptLauncher),
+    /// Use a different launcher on each platform.
+    PerPlatform(PlatformScriptLaunchers),
+}
+
+/// The launcher to generate for console and GUI scripts on each platform.
+///
+/// Platforms that are omitted use the native launcher.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlatformScriptLaunchers {
+    pub linux: Option<ScriptLauncher>,
+    pub macos: Option<ScriptLauncher>,
+    pub windows: Option<ScriptLauncher>,
+}
+
+impl Default for ScriptLaunchers {
+    fn default() -> Self {
+        Self::All(ScriptLauncher::default())
+    }
+}
+
+impl ScriptLaunchers {
+    /// Return the [`ScriptLauncher`] to use on the current platform.
+    pub fn current(&self) -> ScriptLauncher {
+        match self {
+            Self::All(launcher) => *launcher,
+            Self::PerPlatform(launchers) => {
+                let launcher = if cfg!(target_os = "linux") {
+                    launchers.linux
+                } else if cfg!(target_os = "macos") {
+                    launchers.macos
+                } else if cfg!(windows) {
+                    launchers.windows
+                } else {
+                    None
+                };
+                launcher.unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// The policy for generating the console and GUI script launchers of a wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptPolicy {
+    /// The kind of launcher to generate.
+    pub launcher: ScriptLauncher,
+    /// Whether to generate launchers for GUI scripts.
+    pub gui_scripts: bool,
+}
+
+impl Default for ScriptPolicy {
+    fn default() -> Self {
+        Self {
+            launcher: ScriptLauncher::default(),
+            gui_scripts: true,
+        }
+    }
+}
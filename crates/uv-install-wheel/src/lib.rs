@@ -11,12 +11,17 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
+pub use checks::{ContentError, WheelCheck, WheelChecks, is_stdlib_module};
 pub use install::install_wheel;
+pub use launcher::{PlatformScriptLaunchers, ScriptLauncher, ScriptLaunchers, ScriptPolicy};
 pub use linker::{LinkMode, Locks};
+pub use record::{RecordPathError, RecordPathFix};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
 pub use wheel::{LibKind, WheelFile, read_record_file};
 
+mod checks;
 mod install;
+mod launcher;
 mod linker;
 mod record;
 mod script;
@@ -85,4 +90,10 @@ pub enum Error {
     LauncherError(#[from] uv_trampoline_builder::Error),
     #[error("Scripts must not use the reserved name {0}")]
     ReservedScriptName(String),
+    #[error("The wheel contains a path that can't be created on Windows (`{component}` is a reserved name): {}", path.user_display())]
+    ReservedPath { path: PathBuf, component: String },
+    #[error(transparent)]
+    Content(#[from] ContentError),
+    #[error(transparent)]
+    RecordPath(#[from] RecordPathError),
 }
@@ -1,6 +1,7 @@
+use std::collections::hash_map::Entry;
 use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::SystemTime;
 
 use fs_err as fs;
@@ -18,6 +19,8 @@ use uv_preview::{Preview, PreviewFeatures};
 use uv_warnings::{warn_user, warn_user_once};
 
 use crate::Error;
+use crate::record::RecordEntry;
+use crate::wheel::copy_and_hash;
 
 #[allow(clippy::struct_field_names)]
 #[derive(Debug, Default)]
@@ -26,6 +29,10 @@ pub struct Locks {
     copy_dir_locks: Mutex<FxHashMap<PathBuf, Arc<Mutex<()>>>>,
     /// Top level modules (excluding namespaces) we write to.
     modules: Mutex<FxHashMap<OsString, WheelFilename>>,
+    /// Files we write to, relative to `site-packages`, with the wheel and hash they came from.
+    files: Mutex<FxHashMap<String, (WheelFilename, String)>>,
+    /// Files that are provided with different contents by more than one package.
+    file_conflicts: Mutex<Vec<FileConflict>>,
     /// Preview settings for feature flags.
     preview: Preview,
 }
@@ -36,10 +43,125 @@ impl Locks {
         Self {
             copy_dir_locks: Mutex::new(FxHashMap::default()),
             modules: Mutex::new(FxHashMap::default()),
+            files: Mutex::new(FxHashMap::default()),
+            file_conflicts: Mutex::new(Vec::new()),
             preview,
         }
     }
 
+    /// Record the files that the given wheel will write, detecting any file that's already
+    /// provided with different contents, either by another wheel in the same installation or by
+    /// a package that's already installed.
+    ///
+    /// Conflicts are collected, and reported by [`Locks::warn_file_conflicts`] once all wheels
+    /// are installed.
+    pub(crate) fn register_files(
+        &self,
+        site_packages: &Path,
+        record: &[RecordEntry],
+        filename: &WheelFilename,
+    ) -> Result<(), Error> {
+        // Only detect conflicts if the preview feature is enabled.
+        if !self
+            .preview
+            .is_enabled(PreviewFeatures::DETECT_MODULE_CONFLICTS)
+        {
+            return Ok(());
+        }
+
+        // Hold the lock throughout, such that any file that exists on disk, but hasn't been
+        // registered, must belong to a package that was installed previously.
+        let mut files = self.files.lock().unwrap();
+        for entry in record {
+            // We can only compare files with a SHA-256 hash (i.e., not the `RECORD` itself).
+            let Some(hash) = entry
+                .hash
+                .as_ref()
+                .filter(|hash| hash.starts_with("sha256="))
+            else {
+                continue;
+            };
+
+            // Skip metadata and data files, which are unique to each package or moved elsewhere.
+            let Some(first) = Path::new(&entry.path).components().next() else {
+                continue;
+            };
+            if Path::new(first.as_os_str())
+                .extension()
+                .is_some_and(|ext| ext == "dist-info" || ext == "data")
+            {
+                continue;
+            }
+
+            match files.entry(entry.path.clone()) {
+                Entry::Occupied(existing) => {
+                    let (existing_filename, existing_hash) = existing.get();
+                    if existing_filename.name != filename.name && existing_hash != hash {
+                        self.file_conflicts.lock().unwrap().push(FileConflict {
+                            path: entry.path.clone(),
+                            existing: Some(existing_filename.clone()),
+                            filename: filename.clone(),
+                        });
+                    }
+                }
+                Entry::Vacant(vacant) => {
+                    let path = site_packages.join(&entry.path);
+                    if path.is_file() {
+                        let (_, existing_hash) =
+                            copy_and_hash(&mut fs::File::open(&path)?, &mut std::io::sink())?;
+                        if existing_hash != *hash {
+                            self.file_conflicts.lock().unwrap().push(FileConflict {
+                                path: entry.path.clone(),
+                                existing: None,
+                                filename: filename.clone(),
+                            });
+                        }
+                    }
+                    vacant.insert((filename.clone(), hash.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Warn for each file that was provided with different contents by more than one package.
+    pub fn warn_file_conflicts(&self) {
+        let mut conflicts = std::mem::take(&mut *self.file_conflicts.lock().unwrap());
+        conflicts.sort();
+        for conflict in conflicts {
+            // Sort for consistent output, at least with two packages
+            match conflict.existing {
+                Some(existing) => {
+                    let (wheel_a, wheel_b) = if existing.name > conflict.filename.name {
+                        (&existing, &conflict.filename)
+                    } else {
+                        (&conflict.filename, &existing)
+                    };
+                    warn_user!(
+                        "The file `{}` is provided with different contents by more than one package, \
+                        so only one of the copies was installed, which can result in a broken module. \
+                        Consider removing your dependency on either `{}` ({}) or `{}` ({}).",
+                        conflict.path.green(),
+                        wheel_a.name.cyan(),
+                        format!("v{}", wheel_a.version).cyan(),
+                        wheel_b.name.cyan(),
+                        format!("v{}", wheel_b.version).cyan()
+                    );
+                }
+                None => {
+                    warn_user!(
+                        "The file `{}` from `{}` ({}) overwrote a file with different contents \
+                        that was provided by an installed package, which can result in a broken module.",
+                        conflict.path.green(),
+                        conflict.filename.name.cyan(),
+                        format!("v{}", conflict.filename.version).cyan(),
+                    );
+                }
+            }
+        }
+    }
+
     /// Warn when a module exists in multiple packages.
     fn warn_module_conflict(&self, module: &OsStr, wheel_a: &WheelFilename) {
         if let Some(wheel_b) = self
@@ -76,6 +198,18 @@ impl Locks {
     }
 }
 
+/// A file that's provided with different contents by more than one package.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct FileConflict {
+    /// The path to the file, relative to `site-packages`.
+    path: String,
+    /// The wheel in the same installation that provided the file first, if any; otherwise, the
+    /// file was provided by a package that was already installed.
+    existing: Option<WheelFilename>,
+    /// The wheel that provided the file with different contents.
+    filename: WheelFilename,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -89,12 +223,17 @@ pub enum LinkMode {
     Hardlink,
     /// Symbolically link packages from the wheel into the `site-packages` directory.
     Symlink,
+    /// Clone packages if the filesystem supports it (e.g., APFS, Btrfs, or XFS), and hard link
+    /// them otherwise.
+    ReflinkAuto,
 }
 
 impl Default for LinkMode {
     fn default() -> Self {
         if cfg!(any(target_os = "macos", target_os = "ios")) {
             Self::Clone
+        } else if cfg!(target_os = "linux") {
+            Self::ReflinkAuto
         } else {
             Self::Hardlink
         }
@@ -111,11 +250,21 @@ impl LinkMode {
         locks: &Locks,
         filename: &WheelFilename,
     ) -> Result<usize, Error> {
+        // On Windows, use extended-length paths, to support deeply nested packages.
+        let site_packages = uv_fs::extended_length(site_packages.as_ref());
+        let wheel = uv_fs::extended_length(wheel.as_ref());
         match self {
             Self::Clone => clone_wheel_files(site_packages, wheel, locks, filename),
             Self::Copy => copy_wheel_files(site_packages, wheel, locks, filename),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel, locks, filename),
             Self::Symlink => symlink_wheel_files(site_packages, wheel, locks, filename),
+            Self::ReflinkAuto => {
+                if supports_reflink(site_packages.as_ref(), wheel.as_ref()) {
+                    clone_wheel_files(site_packages, wheel, locks, filename)
+                } else {
+                    hardlink_wheel_files(site_packages, wheel, locks, filename)
+                }
+            }
         }
     }
 
@@ -125,6 +274,72 @@ impl LinkMode {
     }
 }
 
+/// Whether reflinking is supported between a pair of filesystems, keyed by device ID.
+static REFLINK_SUPPORT: LazyLock<Mutex<FxHashMap<(u64, u64), bool>>> =
+    LazyLock::new(|| Mutex::new(FxHashMap::default()));
+
+/// Determine whether files in the `wheel` directory can be reflinked into `site_packages`.
+///
+/// Reflinks are only supported on some filesystems (e.g., APFS, Btrfs, and XFS), and never across
+/// filesystem boundaries, so we probe by cloning a single file from the wheel. The result is cached
+/// for each pair of source and target filesystems, such that the probe runs at most once per
+/// pair.
+fn supports_reflink(site_packages: &Path, wheel: &Path) -> bool {
+    let key = filesystem_id(wheel).zip(filesystem_id(site_packages));
+    if let Some(key) = key {
+        if let Some(supported) = REFLINK_SUPPORT.lock().unwrap().get(&key) {
+            return *supported;
+        }
+    }
+
+    let supported = probe_reflink(site_packages, wheel);
+    debug!(
+        "Reflinking from `{}` to `{}` is {}",
+        wheel.simplified_display(),
+        site_packages.simplified_display(),
+        if supported {
+            "supported"
+        } else {
+            "not supported"
+        }
+    );
+
+    if let Some(key) = key {
+        REFLINK_SUPPORT.lock().unwrap().insert(key, supported);
+    }
+    supported
+}
+
+/// Attempt to reflink the first file in the `wheel` directory into a temporary directory within
+/// `site_packages`.
+fn probe_reflink(site_packages: &Path, wheel: &Path) -> bool {
+    let Some(file) = WalkDir::new(wheel)
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_type().is_file())
+    else {
+        return false;
+    };
+    let Ok(tempdir) = tempdir_in(site_packages) else {
+        return false;
+    };
+    reflink::reflink(file.path(), tempdir.path().join(file.file_name())).is_ok()
+}
+
+/// Return an identifier for the filesystem that contains the given path.
+#[cfg(unix)]
+fn filesystem_id(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    fs::metadata(path).ok().map(|metadata| metadata.dev())
+}
+
+/// Return an identifier for the filesystem that contains the given path.
+#[cfg(not(unix))]
+fn filesystem_id(_path: &Path) -> Option<u64> {
+    None
+}
+
 /// Extract a wheel by cloning all of its files into site packages. The files will be cloned
 /// via copy-on-write, which is similar to a hard link, but allows the files to be modified
 /// independently (that is, the file is copied upon modification).
@@ -333,6 +548,7 @@ fn copy_wheel_files(
         let out_path = site_packages.as_ref().join(relative);
 
         warn_module_conflict(locks, filename, relative);
+        check_reserved_path(relative)?;
 
         if entry.file_type().is_dir() {
             fs::create_dir_all(&out_path)?;
@@ -365,6 +581,7 @@ fn hardlink_wheel_files(
         let out_path = site_packages.as_ref().join(relative);
 
         warn_module_conflict(locks, filename, relative);
+        check_reserved_path(relative)?;
 
         if entry.file_type().is_dir() {
             fs::create_dir_all(&out_path)?;
@@ -465,6 +682,7 @@ fn symlink_wheel_files(
         let out_path = site_packages.as_ref().join(relative);
 
         warn_module_conflict(locks, filename, relative);
+        check_reserved_path(relative)?;
 
         if entry.file_type().is_dir() {
             fs::create_dir_all(&out_path)?;
@@ -570,6 +788,20 @@ fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<(
     Ok(())
 }
 
+/// On Windows, reject any path that can't be created, since extended-length paths would otherwise
+/// create files that can't be opened by other tools.
+fn check_reserved_path(relative: &Path) -> Result<(), Error> {
+    if cfg!(windows) {
+        if let Some(component) = uv_fs::windows_reserved_component(relative) {
+            return Err(Error::ReservedPath {
+                path: relative.to_path_buf(),
+                component: component.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
 /// Warn when a module exists in multiple packages.
 fn warn_module_conflict(locks: &Locks, filename: &WheelFilename, relative: &Path) {
     // Check for `__init__.py` to account for namespace packages.
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Line in a RECORD file
 /// <https://www.python.org/dev/peps/pep-0376/#record>
@@ -14,3 +15,181 @@ pub struct RecordEntry {
     #[allow(dead_code)]
     pub size: Option<u64>,
 }
+
+/// A malformed path in the `RECORD` of a wheel that can be normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordPathFix {
+    /// The path is not valid UTF-8, and was decoded lossily.
+    NotUtf8,
+    /// The path has a leading `/`, which was stripped.
+    LeadingSlash,
+    /// The path uses `\` as a separator, which was replaced with `/`.
+    Backslashes,
+    /// The path contains `.` components or duplicate separators, which were removed.
+    Redundant,
+}
+
+impl std::fmt::Display for RecordPathFix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotUtf8 => write!(f, "is not valid UTF-8"),
+            Self::LeadingSlash => write!(f, "is absolute"),
+            Self::Backslashes => write!(f, "uses `\\` as a separator"),
+            Self::Redundant => write!(f, "contains redundant components"),
+        }
+    }
+}
+
+/// A path in the `RECORD` of a wheel that is always rejected, regardless of the enabled checks.
+#[derive(Error, Debug)]
+pub enum RecordPathError {
+    #[error("The wheel's `RECORD` lists an empty path")]
+    Empty,
+    #[error("The wheel's `RECORD` lists a path containing a null byte: `{0}`")]
+    NullByte(String),
+    #[error("The wheel's `RECORD` lists a path with a drive or UNC prefix: `{0}`")]
+    Prefix(String),
+    #[error("The wheel's `RECORD` lists a path outside of the wheel: `{0}`")]
+    ParentDir(String),
+}
+
+/// A path in the `RECORD` of a wheel, normalized to a relative, `/`-separated path.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct SanitizedRecordPath {
+    /// The path as listed in the `RECORD`, decoded lossily.
+    pub(crate) original: String,
+    /// The normalized path.
+    pub(crate) path: String,
+    /// The fixes that were applied to normalize the path, if any.
+    pub(crate) fixes: Vec<RecordPathFix>,
+}
+
+impl SanitizedRecordPath {
+    /// Normalize a raw path from the `RECORD` of a wheel.
+    ///
+    /// Paths that can't be mapped to a location within the wheel (e.g., with `..` components or
+    /// a drive prefix) are rejected outright; common legacy malformations (e.g., a leading `/`,
+    /// as used by some releases of `selenium`) are fixed and recorded in [`Self::fixes`].
+    pub(crate) fn from_bytes(raw: &[u8]) -> Result<Self, RecordPathError> {
+        let mut fixes = Vec::new();
+        let original = if let Ok(original) = std::str::from_utf8(raw) {
+            original.to_string()
+        } else {
+            fixes.push(RecordPathFix::NotUtf8);
+            String::from_utf8_lossy(raw).into_owned()
+        };
+
+        if original.is_empty() {
+            return Err(RecordPathError::Empty);
+        }
+        if original.contains('\0') {
+            return Err(RecordPathError::NullByte(original));
+        }
+        if matches!(original.as_bytes(), [drive, b':', ..] if drive.is_ascii_alphabetic())
+            || original.starts_with("\\\\")
+            || original.starts_with("//")
+        {
+            return Err(RecordPathError::Prefix(original));
+        }
+
+        let separated = if original.contains('\\') {
+            fixes.push(RecordPathFix::Backslashes);
+            original.replace('\\', "/")
+        } else {
+            original.clone()
+        };
+        let relative = if let Some(relative) = separated.strip_prefix('/') {
+            fixes.push(RecordPathFix::LeadingSlash);
+            relative
+        } else {
+            separated.as_str()
+        };
+
+        let mut components = Vec::new();
+        for component in relative.split('/') {
+            match component {
+                "" | "." => {}
+                ".." => return Err(RecordPathError::ParentDir(original)),
+                component => components.push(component),
+            }
+        }
+        let path = components.join("/");
+        if path.is_empty() {
+            return Err(RecordPathError::Empty);
+        }
+        if path != relative {
+            fixes.push(RecordPathFix::Redundant);
+        }
+
+        Ok(Self {
+            original,
+            path,
+            fixes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RecordPathError, RecordPathFix, SanitizedRecordPath};
+
+    #[test]
+    fn sanitize_valid() {
+        let sanitized = SanitizedRecordPath::from_bytes(b"foo/__init__.py").unwrap();
+        assert_eq!(sanitized.path, "foo/__init__.py");
+        assert!(sanitized.fixes.is_empty());
+    }
+
+    #[test]
+    fn sanitize_fixes() {
+        for (raw, path, fixes) in [
+            (
+                &b"/foo/__init__.py"[..],
+                "foo/__init__.py",
+                &[RecordPathFix::LeadingSlash][..],
+            ),
+            (
+                &b"foo\\__init__.py"[..],
+                "foo/__init__.py",
+                &[RecordPathFix::Backslashes][..],
+            ),
+            (
+                &b"./foo//__init__.py"[..],
+                "foo/__init__.py",
+                &[RecordPathFix::Redundant][..],
+            ),
+            (
+                &b"foo/caf\xe9.py"[..],
+                "foo/caf\u{FFFD}.py",
+                &[RecordPathFix::NotUtf8][..],
+            ),
+        ] {
+            let sanitized = SanitizedRecordPath::from_bytes(raw).unwrap();
+            assert_eq!(sanitized.path, path);
+            assert_eq!(sanitized.fixes, fixes);
+        }
+    }
+
+    #[test]
+    fn sanitize_rejected() {
+        for raw in [&b""[..], b"./"] {
+            let err = SanitizedRecordPath::from_bytes(raw).unwrap_err();
+            assert!(matches!(err, RecordPathError::Empty), "{err}");
+        }
+        let err = SanitizedRecordPath::from_bytes(b"foo\0.py").unwrap_err();
+        assert!(matches!(err, RecordPathError::NullByte(_)), "{err}");
+        for raw in [&b"C:\\Windows\\foo.dll"[..], b"\\\\server\\share\\foo.py"] {
+            let err = SanitizedRecordPath::from_bytes(raw).unwrap_err();
+            assert!(matches!(err, RecordPathError::Prefix(_)), "{err}");
+        }
+        for raw in [
+            &b"../../bin/foo"[..],
+            b"/../foo.py",
+            b"foo/../../bar.py",
+            b"foo\\..\\..\\bar.py",
+        ] {
+            let err = SanitizedRecordPath::from_bytes(raw).unwrap_err();
+            assert!(matches!(err, RecordPathError::ParentDir(_)), "{err}");
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use std::collections::BTreeSet;
+use std::ffi::OsStr;
 use std::path::{Component, Path, PathBuf};
 
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::{LazyLock, Mutex};
 use tracing::trace;
 use uv_fs::write_atomic_sync;
 
 use crate::Error;
+use crate::record::RecordEntry;
 use crate::wheel::read_record_file;
 
 /// Uninstall the wheel represented by the given `.dist-info` directory.
@@ -29,6 +32,14 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         read_record_file(&mut record_file)?
     };
 
+    // Determine the files that are shared with other packages, like the `__init__.py` of a
+    // `pkgutil`-style namespace package, which must be retained.
+    let shared = shared_files(site_packages, dist_info, &record)?;
+    let retained = shared
+        .iter()
+        .filter_map(|path| site_packages.join(path).parent().map(normalize_path))
+        .collect::<FxHashSet<_>>();
+
     let mut file_count = 0usize;
     let mut dir_count = 0usize;
 
@@ -40,6 +51,11 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
     for entry in &record {
         let path = site_packages.join(&entry.path);
 
+        if shared.contains(entry.path.as_str()) {
+            trace!("Retaining shared file: {}", path.display());
+            continue;
+        }
+
         // On Windows, deleting the current executable is a special case.
         #[cfg(windows)]
         if let Some(itself) = itself.as_ref() {
@@ -103,6 +119,11 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 break;
             }
 
+            // If the directory contains a shared file, it's still in use by another package.
+            if retained.contains(path) {
+                break;
+            }
+
             // If the directory contains a `__pycache__` directory, always remove it. `__pycache__`
             // may or may not be listed in the RECORD, but installers are expected to be smart
             // enough to remove it either way.
@@ -148,6 +169,99 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
     })
 }
 
+/// Return the files in the `RECORD` that are also recorded by another package in the same
+/// `site-packages` directory.
+///
+/// Packages can share a directory, as in a namespace package. For `pkgutil`-style namespace
+/// packages, every package in the namespace ships (and records) the same `__init__.py`, which
+/// must be retained until the last of the packages is removed. To avoid reading every `RECORD`
+/// in the environment, we only consider `__init__.py` files in directories that contain files
+/// from another package.
+fn shared_files<'a>(
+    site_packages: &Path,
+    dist_info: &Path,
+    record: &'a [RecordEntry],
+) -> Result<FxHashSet<&'a str>, Error> {
+    // Map each directory to the names of its recorded children.
+    let mut children: FxHashMap<&Path, FxHashSet<&OsStr>> = FxHashMap::default();
+    for entry in record {
+        let path = Path::new(&entry.path);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            continue;
+        }
+        for ancestor in path.ancestors() {
+            let (Some(parent), Some(name)) = (ancestor.parent(), ancestor.file_name()) else {
+                continue;
+            };
+            children.entry(parent).or_default().insert(name);
+        }
+    }
+
+    // Find any `__init__.py` in a package directory that contains other packages' files.
+    let mut candidates = FxHashSet::default();
+    for entry in record {
+        let path = Path::new(&entry.path);
+        if path.file_name().is_none_or(|name| name != "__init__.py") {
+            continue;
+        }
+        let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        else {
+            continue;
+        };
+        let Some(recorded) = children.get(parent) else {
+            continue;
+        };
+        let read_dir = match fs_err::read_dir(site_packages.join(parent)) {
+            Ok(read_dir) => read_dir,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        for child in read_dir {
+            let name = child?.file_name();
+            if name != "__pycache__" && !recorded.contains(name.as_os_str()) {
+                candidates.insert(entry.path.as_str());
+                break;
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        return Ok(FxHashSet::default());
+    }
+
+    // Determine whether any other package records the same files.
+    let mut shared = FxHashSet::default();
+    for entry in fs_err::read_dir(site_packages)? {
+        let path = entry?.path();
+        if path.file_name() == dist_info.file_name()
+            || path.extension().is_none_or(|ext| ext != "dist-info")
+        {
+            continue;
+        }
+        let Ok(mut record_file) = fs_err::File::open(path.join("RECORD")) else {
+            continue;
+        };
+        let Ok(other) = read_record_file(&mut record_file) else {
+            continue;
+        };
+        for entry in other {
+            if let Some(path) = candidates.take(entry.path.as_str()) {
+                shared.insert(path);
+            }
+        }
+        if candidates.is_empty() {
+            break;
+        }
+    }
+
+    Ok(shared)
+}
+
 /// Uninstall the egg represented by the `.egg-info` directory.
 ///
 /// See: <https://github.com/pypa/pip/blob/41587f5e0017bcd849f42b314dc8a34a7db75621/src/pip/_internal/req/req_uninstall.py#L483>
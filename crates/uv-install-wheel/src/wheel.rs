@@ -12,16 +12,17 @@ use sha2::{Digest, Sha256};
 use tracing::{debug, instrument, trace, warn};
 use walkdir::WalkDir;
 
+use uv_distribution_filename::WheelFilename;
 use uv_fs::{Simplified, persist_with_retry_sync, relative_to};
 use uv_normalize::PackageName;
-use uv_pypi_types::DirectUrl;
+use uv_pypi_types::{DirectUrl, HashDigest};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_trampoline_builder::windows_script_launcher;
 use uv_warnings::warn_user_once;
 
-use crate::record::RecordEntry;
+use crate::record::{RecordEntry, SanitizedRecordPath};
 use crate::script::{Script, scripts_from_ini};
-use crate::{Error, Layout};
+use crate::{ContentError, Error, Layout, ScriptLauncher};
 
 /// Wrapper script template function
 ///
@@ -78,7 +79,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -122,23 +126,25 @@ fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool
         // Same applies for relocatable scripts (executable is relative to script dir, hence `dirname` trick)
         // (note: the Windows trampoline binaries natively support relative paths to executable)
         if shebang_length > 127 || executable.contains(' ') || relocatable {
-            let prefix = if relocatable {
-                r#""$(dirname -- "$(realpath -- "$0")")"/"#
-            } else {
-                ""
-            };
-            let executable = format!(
-                "{}'{}'",
-                prefix,
-                escape_posix_for_single_quotes(&executable)
-            );
-            return format!("#!/bin/sh\n'''exec' {executable} \"$0\" \"$@\"\n' '''");
+            return format_sh_shebang(&executable, relocatable);
         }
     }
 
     format!("#!{executable}")
 }
 
+/// Format a shebang that wraps the executable in `/bin/sh`, such that the launcher is valid as
+/// both a POSIX shell script and a Python script.
+fn format_sh_shebang(executable: &str, relocatable: bool) -> String {
+    let prefix = if relocatable {
+        r#""$(dirname -- "$(realpath -- "$0")")"/"#
+    } else {
+        ""
+    };
+    let executable = format!("{}'{}'", prefix, escape_posix_for_single_quotes(executable));
+    format!("#!/bin/sh\n'''exec' {executable} \"$0\" \"$@\"\n' '''")
+}
+
 /// Returns a [`PathBuf`] to `python[w].exe` for script execution.
 ///
 /// <https://github.com/pypa/pip/blob/76e82a43f8fb04695e834810df64f2d9a2ff6020/src/pip/_vendor/distlib/scripts.py#L121-L126>
@@ -181,10 +187,27 @@ pub(crate) fn write_script_entrypoints(
     layout: &Layout,
     relocatable: bool,
     site_packages: &Path,
+    dist_info_prefix: &str,
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
     is_gui: bool,
+    launcher: ScriptLauncher,
 ) -> Result<(), Error> {
+    // Non-native launchers are only supported on Unix.
+    let launcher = if cfg!(windows) && launcher != ScriptLauncher::Native {
+        warn_user_once!(
+            "The `{launcher}` script launcher is not supported on Windows; using the native launcher instead"
+        );
+        ScriptLauncher::Native
+    } else if relocatable && launcher == ScriptLauncher::Symlink {
+        // Relocatable launchers locate the interpreter relative to their own path, which would
+        // resolve to the `.dist-info` directory through a symlink.
+        debug!("Using native script launchers in a relocatable environment");
+        ScriptLauncher::Native
+    } else {
+        launcher
+    };
+
     for entrypoint in entrypoints {
         let warn_names = ["activate", "activate_this.py"];
         if warn_names.contains(&entrypoint.name.as_str())
@@ -219,10 +242,15 @@ pub(crate) fn write_script_entrypoints(
         let launcher_executable = get_script_executable(&layout.sys_executable, is_gui);
         let launcher_executable =
             get_relocatable_executable(launcher_executable, layout, relocatable)?;
-        let launcher_python_script = get_script_launcher(
-            entrypoint,
-            &format_shebang(&launcher_executable, &layout.os_name, relocatable),
-        );
+        let shebang = if launcher == ScriptLauncher::Shell {
+            format_sh_shebang(
+                &launcher_executable.simplified_display().to_string(),
+                relocatable,
+            )
+        } else {
+            format_shebang(&launcher_executable, &layout.os_name, relocatable)
+        };
+        let launcher_python_script = get_script_launcher(entrypoint, &shebang);
 
         // If necessary, wrap the launcher script in a Windows launcher binary.
         if cfg!(windows) {
@@ -232,6 +260,34 @@ pub(crate) fn write_script_entrypoints(
                 &windows_script_launcher(&launcher_python_script, is_gui, &launcher_executable)?,
                 record,
             )?;
+        } else if launcher == ScriptLauncher::Symlink {
+            // Write the launcher script to the `.dist-info` directory, and link to it from the
+            // scripts directory.
+            let target_relative = Path::new(&format!("{dist_info_prefix}.dist-info"))
+                .join("scripts")
+                .join(&entrypoint.name);
+            let target_absolute = site_packages.join(&target_relative);
+            if let Some(parent) = target_absolute.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            write_file_recorded(
+                site_packages,
+                &target_relative,
+                &launcher_python_script,
+                record,
+            )?;
+            make_executable(&target_absolute)?;
+
+            let target = entrypoint_absolute
+                .parent()
+                .and_then(|scripts| pathdiff::diff_paths(&target_absolute, scripts))
+                .unwrap_or(target_absolute);
+            uv_fs::replace_symlink(&target, &entrypoint_absolute)?;
+            record.push(RecordEntry {
+                path: entrypoint_relative.portable_display().to_string(),
+                hash: None,
+                size: None,
+            });
         } else {
             write_file_recorded(
                 site_packages,
@@ -241,22 +297,32 @@ pub(crate) fn write_script_entrypoints(
             )?;
 
             // Make the launcher executable.
-            #[cfg(unix)]
-            {
-                use std::fs::Permissions;
-                use std::os::unix::fs::PermissionsExt;
-
-                let path = site_packages.join(entrypoint_relative);
-                let permissions = fs::metadata(&path)?.permissions();
-                if permissions.mode() & 0o111 != 0o111 {
-                    fs::set_permissions(path, Permissions::from_mode(permissions.mode() | 0o111))?;
-                }
-            }
+            make_executable(&site_packages.join(entrypoint_relative))?;
         }
     }
     Ok(())
 }
 
+/// Make the file at the given path executable, on Unix.
+#[allow(clippy::unnecessary_wraps)]
+fn make_executable(path: &Path) -> Result<(), Error> {
+    #[cfg(unix)]
+    {
+        use std::fs::Permissions;
+        use std::os::unix::fs::PermissionsExt;
+
+        let permissions = fs::metadata(path)?.permissions();
+        if permissions.mode() & 0o111 != 0o111 {
+            fs::set_permissions(path, Permissions::from_mode(permissions.mode() | 0o111))?;
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+    }
+    Ok(())
+}
+
 /// A parsed `WHEEL` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WheelFile(FxHashMap<String, Vec<String>>);
@@ -725,6 +791,7 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    hashes: &[HashDigest],
     installer: Option<&str>,
     record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
@@ -756,6 +823,14 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
             record,
         )?;
     }
+    if !hashes.is_empty() {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_hashes.json"),
+            serde_json::to_string(hashes)?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(installer) = installer {
         write_file_recorded(
             site_packages,
@@ -808,6 +883,52 @@ pub fn read_record_file(record: &mut impl Read) -> Result<Vec<RecordEntry>, Erro
         .collect()
 }
 
+/// Reads the `RECORD` file of a wheel that's about to be installed.
+///
+/// Unlike [`read_record_file`], which reads the `RECORD` of an installed distribution, paths are
+/// sanitized: paths that would escape the install location are always rejected, while malformed
+/// paths are either normalized with a warning or, if `strict`, rejected.
+pub(crate) fn read_wheel_record(
+    record: impl Read,
+    filename: &WheelFilename,
+    strict: bool,
+) -> Result<Vec<RecordEntry>, Error> {
+    let mut entries = Vec::new();
+    for entry in csv::ReaderBuilder::new()
+        .has_headers(false)
+        .escape(Some(b'"'))
+        .from_reader(record)
+        .byte_records()
+    {
+        let entry = entry?;
+        let sanitized = SanitizedRecordPath::from_bytes(entry.get(0).unwrap_or_default())?;
+        if let Some(fix) = sanitized.fixes.first() {
+            if strict {
+                return Err(ContentError::MalformedRecordPath(sanitized.original, *fix).into());
+            }
+            warn_user_once!(
+                "The `RECORD` of `{filename}` lists a path that {fix}: `{}` (installing as `{}`)",
+                sanitized.original,
+                sanitized.path
+            );
+        }
+        let hash = entry
+            .get(1)
+            .filter(|hash| !hash.is_empty())
+            .map(|hash| String::from_utf8_lossy(hash).into_owned());
+        let size = entry
+            .get(2)
+            .and_then(|size| std::str::from_utf8(size).ok())
+            .and_then(|size| size.parse().ok());
+        entries.push(RecordEntry {
+            path: sanitized.path,
+            hash,
+            size,
+        });
+    }
+    Ok(entries)
+}
+
 /// Parse a file with email message format such as WHEEL and METADATA
 fn parse_email_message_file(
     file: impl Read,
@@ -951,8 +1072,10 @@ mod test {
 
     use super::{
         Error, RecordEntry, Script, WheelFile, format_shebang, get_script_executable,
-        parse_email_message_file, read_record_file, write_installer_metadata,
+        parse_email_message_file, read_record_file, read_wheel_record, write_installer_metadata,
     };
+    use crate::ContentError;
+    use crate::record::RecordPathError;
 
     #[test]
     fn test_parse_email_message_file() {
@@ -1054,6 +1177,50 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn wheel_record_malformed_paths() {
+        let record: &[u8] =
+            b"/selenium/__init__.py,sha256=l8nEsTP4D2dZVula_p4ZuCe8AGnxOq7MxMeAWNvR0Qc,811\n\
+            selenium\\webdriver.py,,\n\
+            ./selenium//caf\xe9.py,,\n\
+            selenium-4.1.0.dist-info/RECORD,,\n";
+        let filename = "selenium-4.1.0-py3-none-any.whl".parse().unwrap();
+
+        let entries = read_wheel_record(record, &filename, false).unwrap();
+        let actual = entries
+            .iter()
+            .map(|entry| entry.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                "selenium/__init__.py",
+                "selenium/webdriver.py",
+                "selenium/caf\u{FFFD}.py",
+                "selenium-4.1.0.dist-info/RECORD",
+            ]
+        );
+        assert_eq!(entries[0].size, Some(811));
+
+        let err = read_wheel_record(record, &filename, true).unwrap_err();
+        assert!(
+            matches!(&err, Error::Content(ContentError::MalformedRecordPath(found, _)) if found == "/selenium/__init__.py"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn wheel_record_escaping_paths() {
+        let filename = "foo-1.0.0-py3-none-any.whl".parse().unwrap();
+        for record in [&b"../../bin/foo,,\n"[..], b"foo/../../../etc/passwd,,\n"] {
+            let err = read_wheel_record(record, &filename, false).unwrap_err();
+            assert!(
+                matches!(&err, Error::RecordPath(RecordPathError::ParentDir(_))),
+                "{err}"
+            );
+        }
+    }
+
     #[test]
     fn test_script_from_value() {
         assert_eq!(
@@ -1248,6 +1415,7 @@ mod test {
             None,
             None,
             None,
+            &[],
             Some("uv"),
             &mut record,
         )
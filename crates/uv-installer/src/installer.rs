@@ -9,13 +9,15 @@ use tracing::instrument;
 use uv_cache::Cache;
 use uv_configuration::RAYON_INITIALIZE;
 use uv_distribution_types::CachedDist;
-use uv_install_wheel::{Layout, LinkMode};
+use uv_install_wheel::{Layout, LinkMode, ScriptPolicy, WheelChecks};
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     cache: Option<&'a Cache>,
     reporter: Option<Arc<dyn Reporter>>,
     /// The name of the [`Installer`].
@@ -32,6 +34,8 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: LinkMode::default(),
+            script_policy: ScriptPolicy::default(),
+            wheel_checks: WheelChecks::default(),
             cache: None,
             reporter: None,
             name: Some("uv".to_string()),
@@ -46,6 +50,24 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`ScriptPolicy`] to use when generating script launchers.
+    #[must_use]
+    pub fn with_script_policy(self, script_policy: ScriptPolicy) -> Self {
+        Self {
+            script_policy,
+            ..self
+        }
+    }
+
+    /// Set the [`WheelChecks`] to perform on the contents of each wheel before it's installed.
+    #[must_use]
+    pub fn with_wheel_checks(self, wheel_checks: WheelChecks) -> Self {
+        Self {
+            wheel_checks,
+            ..self
+        }
+    }
+
     /// Set the [`Cache`] to use for this installer.
     #[must_use]
     pub fn with_cache(self, cache: &'a Cache) -> Self {
@@ -89,6 +111,8 @@ impl<'a> Installer<'a> {
             venv,
             cache,
             link_mode,
+            script_policy,
+            wheel_checks,
             reporter,
             name: installer_name,
             metadata: installer_metadata,
@@ -115,6 +139,8 @@ impl<'a> Installer<'a> {
                 &layout,
                 installer_name.as_deref(),
                 link_mode,
+                script_policy,
+                wheel_checks,
                 reporter.as_ref(),
                 relocatable,
                 installer_metadata,
@@ -146,6 +172,8 @@ impl<'a> Installer<'a> {
             &self.venv.interpreter().layout(),
             self.name.as_deref(),
             self.link_mode,
+            self.script_policy,
+            self.wheel_checks,
             self.reporter.as_ref(),
             self.venv.relocatable(),
             self.metadata,
@@ -161,6 +189,8 @@ fn install(
     layout: &Layout,
     installer_name: Option<&str>,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     reporter: Option<&Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
@@ -185,9 +215,12 @@ fn install(
                 Some(wheel.cache_info())
             },
             wheel.build_info(),
+            wheel.hashes(),
             installer_name,
             installer_metadata,
             link_mode,
+            script_policy,
+            wheel_checks,
             &locks,
         )
         .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
@@ -199,6 +232,12 @@ fn install(
         Ok::<(), Error>(())
     })?;
 
+    locks.warn_file_conflicts();
+
+    if let Some(reporter) = reporter.as_ref() {
+        reporter.on_install_complete();
+    }
+
     Ok(wheels)
 }
 
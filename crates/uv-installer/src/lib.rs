@@ -5,6 +5,8 @@ pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter}
 pub use site_packages::{
     InstallationStrategy, SatisfiesResult, SitePackages, SitePackagesDiagnostic,
 };
+pub use snapshot::{Restore, Snapshot};
+pub use staging::StagedEnvironment;
 pub use uninstall::{UninstallError, uninstall};
 
 mod compile;
@@ -14,4 +16,6 @@ mod installer;
 mod plan;
 mod satisfies;
 mod site_packages;
+mod snapshot;
+mod staging;
 mod uninstall;
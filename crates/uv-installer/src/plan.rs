@@ -14,7 +14,7 @@ use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
     BuiltDist, CachedDirectUrlDist, CachedDist, ConfigSettings, Dist, Error, ExtraBuildRequires,
     ExtraBuildVariables, Hashed, IndexLocations, InstalledDist, Name, PackageConfigSettings,
-    RequirementSource, Resolution, ResolvedDist, SourceDist,
+    Patches, RequirementSource, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
@@ -61,6 +61,7 @@ impl<'a> Planner<'a> {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        patches: &Patches,
         cache: &Cache,
         venv: &PythonEnvironment,
         tags: &Tags,
@@ -75,6 +76,7 @@ impl<'a> Planner<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            patches,
         );
         let built_index = BuiltWheelIndex::new(
             cache,
@@ -84,6 +86,7 @@ impl<'a> Planner<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            patches,
         );
 
         let mut cached = vec![];
@@ -132,6 +135,7 @@ impl<'a> Planner<'a> {
                             config_settings_package,
                             extra_build_requires,
                             extra_build_variables,
+                            patches,
                         ) {
                             RequirementSatisfaction::Mismatch => {
                                 debug!(
@@ -184,6 +188,15 @@ impl<'a> Planner<'a> {
                 || dist
                     .source_tree()
                     .is_some_and(|source_tree| cache.must_revalidate_path(source_tree))
+                || match dist.as_ref() {
+                    Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                        cache.must_revalidate_url(&wheel.location)
+                    }
+                    Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                        cache.must_revalidate_url(&sdist.location)
+                    }
+                    _ => false,
+                }
             {
                 debug!("Must revalidate requirement: {}", dist.name());
                 remote.push(dist.clone());
@@ -249,6 +262,13 @@ impl<'a> Planner<'a> {
 
                     // Read the HTTP pointer.
                     match HttpArchivePointer::read_from(&cache_entry) {
+                        Ok(Some(pointer))
+                            if !pointer.is_fresh() && hasher.get(dist.as_ref()).is_none() =>
+                        {
+                            // Unless the wheel is pinned by hash, the server must confirm that
+                            // the cached wheel is still up-to-date.
+                            debug!("Cached URL wheel requirement is stale for: {wheel}");
+                        }
                         Ok(Some(pointer)) => {
                             let cache_info = pointer.to_cache_info();
                             let build_info = pointer.to_build_info();
@@ -11,7 +11,7 @@ use uv_distribution_filename::ExpandedTags;
 use uv_distribution_types::{
     BuildInfo, BuildVariables, ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires,
     ExtraBuildVariables, InstalledDirectUrlDist, InstalledDist, InstalledDistKind,
-    PackageConfigSettings, RequirementSource,
+    PackageConfigSettings, Patches, RequirementSource,
 };
 use uv_git_types::GitOid;
 use uv_normalize::PackageName;
@@ -42,6 +42,7 @@ impl RequirementSatisfaction {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        patches: &Patches,
     ) -> Self {
         trace!(
             "Comparing installed with source: {:?} {:?}",
@@ -49,19 +50,26 @@ impl RequirementSatisfaction {
         );
 
         // If the distribution was built with other settings, it is out of date.
-        if distribution.build_info().is_some_and(|dist_build_info| {
+        let build_info = || {
             let config_settings =
                 config_settings_for(name, config_settings, config_settings_package);
             let extra_build_requires = extra_build_requires_for(name, extra_build_requires);
             let extra_build_variables = extra_build_variables_for(name, extra_build_variables);
-            let build_info = BuildInfo::from_settings(
+            BuildInfo::from_settings(
                 &config_settings,
                 extra_build_requires,
                 extra_build_variables,
-            );
-            dist_build_info != &build_info
-        }) {
-            debug!("Build info mismatch for {name}: {distribution}");
+                patches.get(name, Some(distribution.version())),
+            )
+        };
+        if let Some(dist_build_info) = distribution.build_info() {
+            if dist_build_info != &build_info() {
+                debug!("Build info mismatch for {name}: {distribution}");
+                return Self::OutOfDate;
+            }
+        } else if build_info().has_patches() {
+            // If the distribution should be patched, but wasn't built by uv, it is out of date.
+            debug!("Missing patches for {name}: {distribution}");
             return Self::OutOfDate;
         }
 
@@ -1,16 +1,17 @@
 use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use fs_err as fs;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 
 use uv_distribution_types::{
-    ConfigSettings, Diagnostic, ExtraBuildRequires, ExtraBuildVariables, InstalledDist,
-    InstalledDistKind, Name, NameRequirementSpecification, PackageConfigSettings, Requirement,
-    UnresolvedRequirement, UnresolvedRequirementSpecification,
+    ConfigSettings, Diagnostic, ExtraBuildRequires, ExtraBuildVariables, HashPolicy, InstalledDist,
+    InstalledDistKind, Name, NameRequirementSpecification, PackageConfigSettings, Patches,
+    Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
@@ -20,7 +21,7 @@ use uv_platform_tags::Tags;
 use uv_pypi_types::{ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_redacted::DisplaySafeUrl;
-use uv_types::InstalledPackagesProvider;
+use uv_types::{HashStrategy, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::satisfies::RequirementSatisfaction;
@@ -51,13 +52,25 @@ impl SitePackages {
 
     /// Build an index of installed packages from the given Python executable.
     pub fn from_interpreter(interpreter: &Interpreter) -> Result<Self> {
+        Self::from_site_packages(interpreter, interpreter.site_packages())
+    }
+
+    /// Build an index of installed packages from the given `site-packages` directories, rather
+    /// than those of the interpreter (e.g., to plan an installation against a hypothetical
+    /// environment).
+    pub fn from_site_packages(
+        interpreter: &Interpreter,
+        directories: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<Self> {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
 
-        for site_packages in interpreter.site_packages() {
+        for site_packages in directories {
+            let site_packages = site_packages.as_ref();
+
             // Read the site-packages directory.
-            let site_packages = match fs::read_dir(site_packages.as_ref()) {
+            let site_packages = match fs::read_dir(site_packages) {
                 Ok(read_dir) => {
                     // Collect sorted directory paths; `read_dir` is not stable across platforms
                     let dist_likes: BTreeSet<_> = read_dir
@@ -321,6 +334,8 @@ impl SitePackages {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        patches: &Patches,
+        hasher: &HashStrategy,
     ) -> Result<SatisfiesResult> {
         // First, map all unnamed requirements to named requirements.
         let requirements = {
@@ -412,6 +427,8 @@ impl SitePackages {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            patches,
+            hasher,
         )
     }
 
@@ -428,6 +445,8 @@ impl SitePackages {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        patches: &Patches,
+        hasher: &HashStrategy,
     ) -> Result<SatisfiesResult> {
         // Collect the constraints and overrides by package name.
         let constraints: FxHashMap<&PackageName, Vec<&Requirement>> =
@@ -491,6 +510,7 @@ impl SitePackages {
                             config_settings_package,
                             extra_build_requires,
                             extra_build_variables,
+                            patches,
                         ) {
                             RequirementSatisfaction::Mismatch
                             | RequirementSatisfaction::OutOfDate
@@ -514,6 +534,7 @@ impl SitePackages {
                                 config_settings_package,
                                 extra_build_requires,
                                 extra_build_variables,
+                                patches,
                             ) {
                                 RequirementSatisfaction::Mismatch
                                 | RequirementSatisfaction::OutOfDate
@@ -527,6 +548,11 @@ impl SitePackages {
                         }
                     }
 
+                    // Validate that the installed distribution matches the required hashes.
+                    if !satisfies_hashes(distribution, hasher) {
+                        return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
+                    }
+
                     // Recurse into the dependencies.
                     let metadata = distribution
                         .read_metadata()
@@ -586,6 +612,36 @@ pub enum InstallationStrategy {
     Strict,
 }
 
+/// Returns `true` if the installed distribution satisfies the given [`HashStrategy`].
+///
+/// Like the install plan, an installed distribution that doesn't record the hashes of the archive
+/// from which it was installed is assumed to match. In `--require-hashes` mode, though, every
+/// installed distribution must still be covered by a hash.
+fn satisfies_hashes(distribution: &InstalledDist, hasher: &HashStrategy) -> bool {
+    let (allowed, policy) = match &distribution.kind {
+        InstalledDistKind::Url(dist) => (hasher.allows_url(&dist.url), hasher.get_url(&dist.url)),
+        _ => (
+            hasher.allows_package(distribution.name(), distribution.version()),
+            hasher.get_package(distribution.name(), distribution.version()),
+        ),
+    };
+    if !allowed {
+        return false;
+    }
+
+    let HashPolicy::Validate(digests) = policy else {
+        return true;
+    };
+    match InstalledDist::read_hashes(distribution.install_path()) {
+        Ok(Some(hashes)) => hashes.iter().any(|hash| digests.contains(hash)),
+        Ok(None) => true,
+        Err(err) => {
+            debug!("Failed to read hashes for: {distribution} ({err})");
+            false
+        }
+    }
+}
+
 /// We check if all requirements are already satisfied, recursing through the requirements tree.
 #[derive(Debug)]
 pub enum SatisfiesResult {
@@ -0,0 +1,322 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use uv_distribution_types::{InstalledDist, InstalledDistKind, Name};
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+
+use crate::{SitePackages, uninstall};
+
+/// The directory in the environment root in which snapshots are stored.
+const SNAPSHOTS_DIR: &str = ".uv-snapshots";
+
+/// The number of snapshots to retain for each environment.
+const MAX_SNAPSHOTS: usize = 5;
+
+/// A snapshot of the distributions installed in an environment.
+///
+/// A snapshot contains a hard link (or, if hard linking fails, a copy) of every file listed in the
+/// `RECORD` of each `.dist-info` distribution, at the same path relative to the environment root.
+/// The `RECORD` files double as the manifest of the snapshot.
+#[derive(Debug)]
+pub struct Snapshot {
+    /// The sequence number of the snapshot, increasing with each snapshot of the environment.
+    id: u64,
+    /// The directory containing the snapshot.
+    path: PathBuf,
+}
+
+/// The changes made to an environment when restoring a [`Snapshot`].
+#[derive(Debug, Default)]
+pub struct Restore {
+    /// The distributions that were removed from the environment.
+    pub removed: Vec<InstalledDist>,
+    /// The distributions that were restored from the snapshot.
+    pub restored: Vec<InstalledDist>,
+}
+
+impl Snapshot {
+    /// Record a snapshot of the distributions installed in the environment.
+    pub fn create(environment: &PythonEnvironment) -> Result<Self> {
+        let root = root(environment);
+        let snapshots_dir = root.join(SNAPSHOTS_DIR);
+        fs_err::create_dir_all(&snapshots_dir)?;
+
+        let id = Self::list(environment)?
+            .last()
+            .map_or(1, |snapshot| snapshot.id + 1);
+        let path = snapshots_dir.join(id.to_string());
+        debug!("Creating environment snapshot at: {}", path.user_display());
+
+        // Populate a temporary directory first, such that a partial snapshot is never visible.
+        let temp_dir = tempfile::tempdir_in(&snapshots_dir)?;
+        for (dist_info, dist) in installed(environment)? {
+            for file in record_paths(root, &dist_info, &dist.record)? {
+                let source = root.join(&file);
+                let target = temp_dir.path().join(&file);
+                if let Some(parent) = target.parent() {
+                    fs_err::create_dir_all(parent)?;
+                }
+                match link_or_copy(&source, &target) {
+                    Ok(()) => {}
+                    // Files listed in the `RECORD` may have been removed since.
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                        trace!("Skipping missing file: {}", source.user_display());
+                    }
+                    Err(err) => {
+                        return Err(err).with_context(|| {
+                            format!("Failed to snapshot `{}`", dist_info.user_display())
+                        });
+                    }
+                }
+            }
+        }
+        fs_err::rename(temp_dir.keep(), &path)?;
+
+        // Remove the oldest snapshots beyond the retention limit.
+        let snapshots = Self::list(environment)?;
+        let excess = snapshots.len().saturating_sub(MAX_SNAPSHOTS);
+        for snapshot in snapshots.into_iter().take(excess) {
+            debug!("Removing old environment snapshot: {}", snapshot.id);
+            snapshot.discard()?;
+        }
+
+        Ok(Self { id, path })
+    }
+
+    /// Return the snapshots of the environment, from oldest to newest.
+    pub fn list(environment: &PythonEnvironment) -> Result<Vec<Self>> {
+        let snapshots_dir = root(environment).join(SNAPSHOTS_DIR);
+        let entries = match fs_err::read_dir(&snapshots_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut snapshots = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            // Skip any partially written snapshots.
+            let Some(id) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            else {
+                continue;
+            };
+            snapshots.push(Self {
+                id,
+                path: entry.path(),
+            });
+        }
+        snapshots.sort_by_key(|snapshot| snapshot.id);
+        Ok(snapshots)
+    }
+
+    /// The sequence number of the snapshot.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns `true` if the distributions installed in the environment match the snapshot.
+    pub fn is_current(&self, environment: &PythonEnvironment) -> Result<bool> {
+        let installed = installed(environment)?;
+        let snapshot = self.distributions(environment)?;
+        Ok(installed.len() == snapshot.len()
+            && installed
+                .iter()
+                .all(|(dist_info, dist)| snapshot.get(dist_info) == Some(&dist.record)))
+    }
+
+    /// Remove the snapshot.
+    pub fn discard(self) -> Result<()> {
+        fs_err::remove_dir_all(&self.path)?;
+        Ok(())
+    }
+
+    /// Restore the environment to the state recorded in the snapshot.
+    ///
+    /// Distributions that changed since the snapshot are uninstalled, and the distributions from
+    /// the snapshot are linked back into the environment. The snapshot and all newer snapshots are
+    /// removed afterwards.
+    pub async fn restore(self, environment: &PythonEnvironment) -> Result<Restore> {
+        let root = root(environment);
+        let installed = installed(environment)?;
+        let snapshot = self.distributions(environment)?;
+        let mut restore = Restore::default();
+
+        // Remove the distributions that were added or changed since the snapshot.
+        for (dist_info, dist) in &installed {
+            if snapshot.get(dist_info) == Some(&dist.record) {
+                continue;
+            }
+            debug!("Removing: {}", dist.dist);
+            uninstall(&dist.dist)
+                .await
+                .with_context(|| format!("Failed to uninstall `{}`", dist.dist.name()))?;
+            restore.removed.push(dist.dist.clone());
+        }
+
+        // Link the distributions from the snapshot back into the environment.
+        for (dist_info, record) in &snapshot {
+            if installed
+                .get(dist_info)
+                .is_some_and(|dist| dist.record == *record)
+            {
+                continue;
+            }
+            for file in record_paths(root, dist_info, record)? {
+                let source = self.path.join(&file);
+                let target = root.join(&file);
+                if let Some(parent) = target.parent() {
+                    fs_err::create_dir_all(parent)?;
+                }
+                match fs_err::remove_file(&target) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
+                match link_or_copy(&source, &target) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                        trace!("Skipping missing file: {}", source.user_display());
+                    }
+                    Err(err) => {
+                        return Err(err).with_context(|| {
+                            format!("Failed to restore `{}`", dist_info.user_display())
+                        });
+                    }
+                }
+            }
+            if let Some(dist) = InstalledDist::try_from_path(&root.join(dist_info))? {
+                debug!("Restored: {dist}");
+                restore.restored.push(dist);
+            }
+        }
+
+        // Remove the restored snapshot, along with the snapshots that were taken after it.
+        for snapshot in Self::list(environment)? {
+            if snapshot.id >= self.id {
+                snapshot.discard()?;
+            }
+        }
+
+        Ok(restore)
+    }
+
+    /// Read the `RECORD` of each distribution in the snapshot, keyed by the path of its
+    /// `.dist-info` directory relative to the environment root.
+    fn distributions(&self, environment: &PythonEnvironment) -> Result<BTreeMap<PathBuf, Vec<u8>>> {
+        let mut distributions = BTreeMap::new();
+        for site_packages in environment.site_packages() {
+            let Ok(relative) = site_packages.strip_prefix(root(environment)) else {
+                continue;
+            };
+            let site_packages = self.path.join(relative);
+            if !site_packages.is_dir() {
+                continue;
+            }
+            for entry in WalkDir::new(&site_packages)
+                .min_depth(1)
+                .max_depth(1)
+                .sort_by_file_name()
+            {
+                let entry = entry?;
+                if !entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.ends_with(".dist-info"))
+                {
+                    continue;
+                }
+                let record = fs_err::read(entry.path().join("RECORD"))?;
+                distributions.insert(relative.join(entry.file_name()), record);
+            }
+        }
+        Ok(distributions)
+    }
+}
+
+/// A `.dist-info` distribution installed in an environment.
+struct InstalledRecord {
+    dist: InstalledDist,
+    /// The contents of the `RECORD` file.
+    record: Vec<u8>,
+}
+
+/// Read the `.dist-info` distributions installed in the environment, keyed by the path of their
+/// `.dist-info` directory relative to the environment root.
+///
+/// Other distributions, such as legacy editables, are not included in snapshots.
+fn installed(environment: &PythonEnvironment) -> Result<BTreeMap<PathBuf, InstalledRecord>> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    let mut installed = BTreeMap::new();
+    for dist in site_packages.iter() {
+        if !matches!(
+            dist.kind,
+            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+        ) {
+            debug!("Skipping snapshot of non-`.dist-info` distribution: {dist}");
+            continue;
+        }
+        let Ok(dist_info) = dist.install_path().strip_prefix(root(environment)) else {
+            debug!("Skipping snapshot of distribution outside of the environment: {dist}");
+            continue;
+        };
+        let record = fs_err::read(dist.install_path().join("RECORD"))?;
+        installed.insert(
+            dist_info.to_path_buf(),
+            InstalledRecord {
+                dist: dist.clone(),
+                record,
+            },
+        );
+    }
+    Ok(installed)
+}
+
+/// Return the paths of the files in the `RECORD` of a `.dist-info` directory, relative to the
+/// environment root.
+///
+/// Paths in a `RECORD` are relative to `site-packages`, but may point outside of it, e.g., for
+/// scripts. Files outside of the environment are skipped.
+fn record_paths(root: &Path, dist_info: &Path, record: &[u8]) -> Result<Vec<PathBuf>> {
+    let site_packages = root.join(dist_info.parent().unwrap_or(Path::new("")));
+    let mut paths = Vec::new();
+    for entry in uv_install_wheel::read_record_file(&mut &record[..])? {
+        let path = site_packages.join(&entry.path);
+        let path = uv_fs::normalize_path(&path);
+        if let Ok(relative) = path.strip_prefix(root) {
+            paths.push(relative.to_path_buf());
+        } else {
+            debug!(
+                "Skipping file outside of the environment: {}",
+                path.user_display()
+            );
+        }
+    }
+    Ok(paths)
+}
+
+/// The root of the environment, which snapshot paths are relative to.
+fn root(environment: &PythonEnvironment) -> &Path {
+    environment.interpreter().sys_prefix()
+}
+
+/// Hard link the file, falling back to a copy, e.g., across file systems.
+fn link_or_copy(source: &Path, target: &Path) -> io::Result<()> {
+    match fs_err::hard_link(source, target) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(err),
+        Err(err) => {
+            trace!("Failed to hard link, copying instead: {err}");
+            fs_err::copy(source, target)?;
+            Ok(())
+        }
+    }
+}
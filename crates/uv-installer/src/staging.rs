@@ -0,0 +1,199 @@
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tempfile::TempDir;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+use uv_python::{Prefix, PythonEnvironment};
+
+/// A copy of an environment in which changes are staged, to be swapped into place once complete.
+///
+/// The staging directory is created next to the environment root, such that it can be renamed into
+/// place. Files are reflinked (or, if reflinking isn't supported, copied) rather than hard linked,
+/// as writes to the staged environment must never be visible in the original.
+///
+/// If the staged environment is dropped without being committed, e.g., due to a failed
+/// installation, the staging directory is removed and the original environment is unchanged.
+#[derive(Debug)]
+pub struct StagedEnvironment {
+    /// The root of the original environment.
+    root: PathBuf,
+    /// The staging directory, which mirrors the original environment root.
+    staging: TempDir,
+    /// The environment, with installations redirected to the staging directory.
+    environment: PythonEnvironment,
+}
+
+impl StagedEnvironment {
+    /// Copy the environment into a staging directory.
+    pub fn create(environment: &PythonEnvironment) -> Result<Self> {
+        let root = environment.root().to_path_buf();
+        let parent = root
+            .parent()
+            .context("The environment root must have a parent directory")?;
+
+        // Remove any staging directories left behind by an interrupted operation.
+        remove_stale(&root)?;
+
+        let staging = tempfile::Builder::new()
+            .prefix(&staging_prefix(&root))
+            .tempdir_in(parent)?;
+        debug!(
+            "Staging environment changes at: {}",
+            staging.path().user_display()
+        );
+        copy_tree(&root, staging.path()).with_context(|| {
+            format!(
+                "Failed to copy the environment at `{}` into a staging directory",
+                root.user_display()
+            )
+        })?;
+
+        // Install into the staging directory, while retaining the original interpreter, such that
+        // scripts and other absolute paths refer to the final location of the environment.
+        let environment = environment
+            .clone()
+            .with_prefix(Prefix::from(staging.path().to_path_buf()))?;
+
+        Ok(Self {
+            root,
+            staging,
+            environment,
+        })
+    }
+
+    /// The environment, with installations redirected to the staging directory.
+    pub fn environment(&self) -> &PythonEnvironment {
+        &self.environment
+    }
+
+    /// Swap the staged environment into place, replacing the original environment.
+    ///
+    /// Where supported, the directories are exchanged atomically. Otherwise, the original
+    /// environment is moved aside before the staged environment is moved into place, such that an
+    /// interruption may leave the environment missing (to be recreated), but never partially
+    /// modified.
+    pub fn commit(self) -> Result<()> {
+        let staging = self.staging.keep();
+        match uv_fs::exchange(&staging, &self.root) {
+            Ok(()) => {
+                debug!(
+                    "Exchanged staged environment with: {}",
+                    self.root.user_display()
+                );
+            }
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                debug!("Atomic exchange is unsupported; renaming the staged environment instead");
+                let replaced = replaced_path(&self.root);
+                if replaced.exists() {
+                    fs_err::remove_dir_all(&replaced)?;
+                }
+                fs_err::rename(&self.root, &replaced)?;
+                if let Err(err) = fs_err::rename(&staging, &self.root) {
+                    fs_err::rename(&replaced, &self.root)?;
+                    return Err(err.into());
+                }
+                fs_err::rename(&replaced, &staging)?;
+            }
+            Err(err) => {
+                let _ = fs_err::remove_dir_all(&staging);
+                return Err(err).with_context(|| {
+                    format!(
+                        "Failed to replace the environment at `{}`",
+                        self.root.user_display()
+                    )
+                });
+            }
+        }
+
+        // The staging directory now contains the original environment.
+        fs_err::remove_dir_all(&staging)?;
+        Ok(())
+    }
+}
+
+/// The prefix for staging directories of the environment, e.g., `.venv.uv-staging-`.
+fn staging_prefix(root: &Path) -> OsString {
+    let mut prefix = OsString::from(".");
+    prefix.push(root.file_name().unwrap_or_default());
+    prefix.push(".uv-staging-");
+    prefix
+}
+
+/// The path to which the original environment is moved while it's replaced, if it can't be
+/// exchanged atomically.
+fn replaced_path(root: &Path) -> PathBuf {
+    let mut name = staging_prefix(root);
+    name.push("replaced");
+    root.with_file_name(name)
+}
+
+/// Remove any staging directories for the environment that were left behind, e.g., by a killed
+/// process.
+///
+/// If the original environment was moved aside but the staged environment was never moved into
+/// place, the original environment is restored.
+fn remove_stale(root: &Path) -> Result<()> {
+    let replaced = replaced_path(root);
+    if replaced.is_dir() && !root.exists() {
+        debug!(
+            "Restoring interrupted environment replacement: {}",
+            root.user_display()
+        );
+        fs_err::rename(&replaced, root)?;
+    }
+
+    let Some(parent) = root.parent() else {
+        return Ok(());
+    };
+    let prefix = staging_prefix(root);
+    for entry in fs_err::read_dir(parent)? {
+        let entry = entry?;
+        if !entry
+            .file_name()
+            .as_encoded_bytes()
+            .starts_with(prefix.as_encoded_bytes())
+        {
+            continue;
+        }
+        debug!(
+            "Removing stale staging directory: {}",
+            entry.path().user_display()
+        );
+        fs_err::remove_dir_all(entry.path())?;
+    }
+    Ok(())
+}
+
+/// Recursively copy a directory into an existing directory, preserving symlinks.
+fn copy_tree(source: &Path, target: &Path) -> io::Result<()> {
+    for entry in WalkDir::new(source).min_depth(1) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .expect("walkdir starts with root");
+        let destination = target.join(relative);
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            fs_err::create_dir(&destination)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            {
+                let link = fs_err::read_link(entry.path())?;
+                fs_err::os::unix::fs::symlink(link, &destination)?;
+            }
+            #[cfg(not(unix))]
+            {
+                fs_err::copy(entry.path(), &destination)?;
+            }
+        } else {
+            reflink_copy::reflink_or_copy(entry.path(), &destination)?;
+        }
+    }
+    Ok(())
+}
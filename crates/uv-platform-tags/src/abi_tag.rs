@@ -24,9 +24,11 @@ pub enum AbiTag {
     None,
     /// Ex) `abi3`
     Abi3,
-    /// Ex) `cp39m`, `cp310t`
+    /// Ex) `cp37m`, `cp310`, `cp313t`, `cp313td`
     CPython {
         gil_disabled: bool,
+        debug: bool,
+        pymalloc: bool,
         python_version: (u8, u8),
     },
     /// Ex) `pypy39_pp73`
@@ -79,17 +81,25 @@ impl std::fmt::Display for AbiTag {
             Self::Abi3 => write!(f, "abi3"),
             Self::CPython {
                 gil_disabled,
+                debug,
+                pymalloc,
                 python_version: (major, minor),
             } => {
-                if *minor <= 7 {
-                    write!(f, "cp{major}{minor}m")
-                } else if *gil_disabled {
-                    // https://peps.python.org/pep-0703/#build-configuration-changes
-                    // Python 3.13+ only, but it makes more sense to just rely on the sysconfig var.
-                    write!(f, "cp{major}{minor}t")
-                } else {
-                    write!(f, "cp{major}{minor}")
+                write!(f, "cp{major}{minor}")?;
+                // https://peps.python.org/pep-0703/#build-configuration-changes
+                // Python 3.13+ only, but it makes more sense to just rely on the sysconfig var.
+                if *gil_disabled {
+                    write!(f, "t")?;
                 }
+                // https://peps.python.org/pep-3149/#proposal
+                if *debug {
+                    write!(f, "d")?;
+                }
+                // The `m` flag was dropped in Python 3.8, as pymalloc no longer affects the ABI.
+                if *pymalloc {
+                    write!(f, "m")?;
+                }
+                Ok(())
             }
             Self::PyPy {
                 python_version: Some((py_major, py_minor)),
@@ -198,13 +208,14 @@ impl FromStr for AbiTag {
         } else if s == "abi3" {
             Ok(Self::Abi3)
         } else if let Some(cp) = s.strip_prefix("cp") {
-            // Ex) `cp39m`, `cp310t`
+            // Ex) `cp37m`, `cp310`, `cp313t`, `cp313td`
             let version_end = cp.find(|c: char| !c.is_ascii_digit()).unwrap_or(cp.len());
-            let version_str = &cp[..version_end];
+            let (version_str, flags) = cp.split_at(version_end);
             let (major, minor) = parse_python_version(version_str, "CPython", s)?;
-            let gil_disabled = cp.ends_with('t');
             Ok(Self::CPython {
-                gil_disabled,
+                gil_disabled: flags.contains('t'),
+                debug: flags.contains('d'),
+                pymalloc: flags.contains('m'),
                 python_version: (major, minor),
             })
         } else if let Some(rest) = s.strip_prefix("pypy") {
@@ -353,6 +364,8 @@ mod tests {
     fn cpython_abi() {
         let tag = AbiTag::CPython {
             gil_disabled: false,
+            debug: false,
+            pymalloc: false,
             python_version: (3, 9),
         };
         assert_eq!(AbiTag::from_str("cp39"), Ok(tag));
@@ -360,18 +373,49 @@ mod tests {
 
         let tag = AbiTag::CPython {
             gil_disabled: false,
+            debug: false,
+            pymalloc: true,
             python_version: (3, 7),
         };
         assert_eq!(AbiTag::from_str("cp37m"), Ok(tag));
         assert_eq!(tag.to_string(), "cp37m");
 
+        let tag = AbiTag::CPython {
+            gil_disabled: false,
+            debug: true,
+            pymalloc: true,
+            python_version: (3, 7),
+        };
+        assert_eq!(AbiTag::from_str("cp37dm"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp37dm");
+
+        let tag = AbiTag::CPython {
+            gil_disabled: false,
+            debug: true,
+            pymalloc: false,
+            python_version: (3, 12),
+        };
+        assert_eq!(AbiTag::from_str("cp312d"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp312d");
+
         let tag = AbiTag::CPython {
             gil_disabled: true,
+            debug: false,
+            pymalloc: false,
             python_version: (3, 13),
         };
         assert_eq!(AbiTag::from_str("cp313t"), Ok(tag));
         assert_eq!(tag.to_string(), "cp313t");
 
+        let tag = AbiTag::CPython {
+            gil_disabled: true,
+            debug: true,
+            pymalloc: false,
+            python_version: (3, 13),
+        };
+        assert_eq!(AbiTag::from_str("cp313td"), Ok(tag));
+        assert_eq!(tag.to_string(), "cp313td");
+
         assert_eq!(
             AbiTag::from_str("cpXY"),
             Err(ParseAbiTagError::MissingMajorVersion {
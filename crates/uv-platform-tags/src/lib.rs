@@ -2,10 +2,14 @@ pub use abi_tag::{AbiTag, ParseAbiTagError};
 pub use language_tag::{LanguageTag, ParseLanguageTagError};
 pub use platform::{Arch, Os, Platform, PlatformError};
 pub use platform_tag::{ParsePlatformTagError, PlatformTag};
-pub use tags::{BinaryFormat, IncompatibleTag, TagCompatibility, TagPriority, Tags, TagsError};
+pub use platform_tag_set::{PlatformFamily, PlatformTagSet};
+pub use tags::{
+    BinaryFormat, IncompatibleTag, TagCompatibility, TagMatch, TagPriority, Tags, TagsError,
+};
 
 mod abi_tag;
 mod language_tag;
 mod platform;
 mod platform_tag;
+mod platform_tag_set;
 mod tags;
@@ -5,7 +5,7 @@ use uv_small_str::SmallString;
 
 use crate::tags::AndroidAbi;
 use crate::tags::IosMultiarch;
-use crate::{Arch, BinaryFormat};
+use crate::{Arch, BinaryFormat, PlatformFamily};
 
 /// A tag to represent the platform compatibility of a Python distribution.
 ///
@@ -259,6 +259,140 @@ impl PlatformTag {
     }
 }
 
+impl PlatformTag {
+    /// Return the [`PlatformFamily`] to which the tag applies, or `None` for `any`.
+    pub fn family(&self) -> Option<PlatformFamily> {
+        match self {
+            Self::Any => None,
+            Self::Manylinux { .. }
+            | Self::Manylinux1 { .. }
+            | Self::Manylinux2010 { .. }
+            | Self::Manylinux2014 { .. }
+            | Self::Linux { .. }
+            | Self::Musllinux { .. } => Some(PlatformFamily::Linux),
+            Self::Macos { .. } => Some(PlatformFamily::Macos),
+            Self::Win32 | Self::WinAmd64 | Self::WinArm64 | Self::WinIa64 => {
+                Some(PlatformFamily::Windows)
+            }
+            Self::Android { .. } => Some(PlatformFamily::Android),
+            Self::FreeBsd { .. } => Some(PlatformFamily::FreeBsd),
+            Self::NetBsd { .. } => Some(PlatformFamily::NetBsd),
+            Self::OpenBsd { .. } => Some(PlatformFamily::OpenBsd),
+            Self::Dragonfly { .. } => Some(PlatformFamily::Dragonfly),
+            Self::Haiku { .. } => Some(PlatformFamily::Haiku),
+            Self::Illumos { .. } => Some(PlatformFamily::Illumos),
+            Self::Solaris { .. } => Some(PlatformFamily::Solaris),
+            Self::Pyodide { .. } => Some(PlatformFamily::Pyodide),
+            Self::Ios { .. } => Some(PlatformFamily::Ios),
+        }
+    }
+
+    /// Return the normalized form of the tag.
+    ///
+    /// The legacy manylinux aliases are replaced with their [PEP 600] equivalents (e.g.,
+    /// `manylinux2014_x86_64` is normalized to `manylinux_2_17_x86_64`); all other tags are
+    /// returned unchanged.
+    ///
+    /// [PEP 600]: https://peps.python.org/pep-0600/
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        match self {
+            Self::Manylinux1 { arch } => Self::Manylinux {
+                major: 2,
+                minor: 5,
+                arch: *arch,
+            },
+            Self::Manylinux2010 { arch } => Self::Manylinux {
+                major: 2,
+                minor: 12,
+                arch: *arch,
+            },
+            Self::Manylinux2014 { arch } => Self::Manylinux {
+                major: 2,
+                minor: 17,
+                arch: *arch,
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns `true` if the tags are equivalent, i.e., they're equal after normalization.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.normalize() == other.normalize()
+    }
+
+    /// Returns `true` if every platform that supports wheels with this tag also supports wheels
+    /// with the `other` tag.
+    ///
+    /// For example, `manylinux_2_28_x86_64` implies `manylinux_2_17_x86_64` (as any system with
+    /// glibc 2.28 also has glibc 2.17), `manylinux2014_x86_64` implies `manylinux_2_17_x86_64`
+    /// (and vice versa), and every tag implies `any`.
+    pub fn implies(&self, other: &Self) -> bool {
+        match (self.normalize(), other.normalize()) {
+            (_, Self::Any) => true,
+            (
+                Self::Manylinux { major, minor, arch },
+                Self::Manylinux {
+                    major: other_major,
+                    minor: other_minor,
+                    arch: other_arch,
+                },
+            )
+            | (
+                Self::Musllinux { major, minor, arch },
+                Self::Musllinux {
+                    major: other_major,
+                    minor: other_minor,
+                    arch: other_arch,
+                },
+            ) => arch == other_arch && (major, minor) >= (other_major, other_minor),
+            // The generic `linux` tag is supported by all manylinux and musllinux systems.
+            (
+                Self::Manylinux { arch, .. } | Self::Musllinux { arch, .. } | Self::Linux { arch },
+                Self::Linux { arch: other_arch },
+            ) => arch == other_arch,
+            (
+                Self::Macos {
+                    major,
+                    minor,
+                    binary_format,
+                },
+                Self::Macos {
+                    major: other_major,
+                    minor: other_minor,
+                    binary_format: other_binary_format,
+                },
+            ) => {
+                (major, minor) >= (other_major, other_minor)
+                    && binary_format
+                        .platform_machine()
+                        .iter()
+                        .all(|machine| other_binary_format.platform_machine().contains(machine))
+            }
+            (
+                Self::Android { api_level, abi },
+                Self::Android {
+                    api_level: other_api_level,
+                    abi: other_abi,
+                },
+            ) => abi == other_abi && api_level >= other_api_level,
+            (
+                Self::Ios {
+                    major,
+                    minor,
+                    multiarch,
+                },
+                Self::Ios {
+                    major: other_major,
+                    minor: other_minor,
+                    multiarch: other_multiarch,
+                },
+            ) => multiarch == other_multiarch && (major, minor) >= (other_major, other_minor),
+            (tag, other) => tag == other,
+        }
+    }
+}
+
 impl std::fmt::Display for PlatformTag {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
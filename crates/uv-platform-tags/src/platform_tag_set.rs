@@ -0,0 +1,245 @@
+use std::collections::BTreeSet;
+use std::fmt::Formatter;
+
+use crate::PlatformTag;
+
+/// A family of platforms, irrespective of version or architecture.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PlatformFamily {
+    Linux,
+    Macos,
+    Windows,
+    Android,
+    Ios,
+    Pyodide,
+    FreeBsd,
+    NetBsd,
+    OpenBsd,
+    Dragonfly,
+    Haiku,
+    Illumos,
+    Solaris,
+}
+
+impl std::fmt::Display for PlatformFamily {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linux => write!(f, "Linux"),
+            Self::Macos => write!(f, "macOS"),
+            Self::Windows => write!(f, "Windows"),
+            Self::Android => write!(f, "Android"),
+            Self::Ios => write!(f, "iOS"),
+            Self::Pyodide => write!(f, "Pyodide"),
+            Self::FreeBsd => write!(f, "FreeBSD"),
+            Self::NetBsd => write!(f, "NetBSD"),
+            Self::OpenBsd => write!(f, "OpenBSD"),
+            Self::Dragonfly => write!(f, "DragonFly"),
+            Self::Haiku => write!(f, "Haiku"),
+            Self::Illumos => write!(f, "Illumos"),
+            Self::Solaris => write!(f, "Solaris"),
+        }
+    }
+}
+
+/// A set of [`PlatformTag`]s, e.g., the platform tags of a wheel or of all wheels for a given
+/// release.
+///
+/// A set is interpreted as the union of its tags: a platform supports the set if it supports any
+/// of the tags in the set.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PlatformTagSet(BTreeSet<PlatformTag>);
+
+impl PlatformTagSet {
+    /// Return an iterator over the tags in the set.
+    pub fn iter(&self) -> impl Iterator<Item = &PlatformTag> {
+        self.0.iter()
+    }
+
+    /// Return the number of tags in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set contains no tags.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the set contains the given tag, or a tag that's equivalent to it.
+    pub fn contains(&self, tag: &PlatformTag) -> bool {
+        self.0.iter().any(|existing| existing.is_equivalent(tag))
+    }
+
+    /// Return the subset of tags that apply to the given [`PlatformFamily`].
+    ///
+    /// `any` applies to every family, and so is always retained.
+    #[must_use]
+    pub fn intersect_family(&self, family: PlatformFamily) -> Self {
+        self.0
+            .iter()
+            .filter(|tag| tag.family().is_none_or(|tag_family| tag_family == family))
+            .cloned()
+            .collect()
+    }
+
+    /// Return the set of families to which the tags apply.
+    ///
+    /// Returns `None` if the set contains `any`, as it applies to every family.
+    pub fn families(&self) -> Option<BTreeSet<PlatformFamily>> {
+        self.0.iter().map(PlatformTag::family).collect()
+    }
+
+    /// Returns `true` if every platform that supports this set also supports the `other` set.
+    ///
+    /// See [`PlatformTag::implies`].
+    pub fn implies(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .all(|tag| other.0.iter().any(|other| tag.implies(other)))
+    }
+
+    /// Normalize the tags in the set, collapsing equivalent tags into a single entry.
+    ///
+    /// See [`PlatformTag::normalize`].
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        self.0.iter().map(PlatformTag::normalize).collect()
+    }
+
+    /// Remove any tags that are implied by another tag in the set, such that the remaining tags
+    /// describe the same set of platforms.
+    ///
+    /// For example, `{manylinux_2_17_x86_64, manylinux_2_28_x86_64}` is reduced to
+    /// `{manylinux_2_17_x86_64}`, since any platform that supports `manylinux_2_28_x86_64` also
+    /// supports `manylinux_2_17_x86_64`.
+    #[must_use]
+    pub fn reduce(&self) -> Self {
+        let normalized = self.normalize();
+        normalized
+            .0
+            .iter()
+            .filter(|tag| {
+                !normalized
+                    .0
+                    .iter()
+                    .any(|other| other != *tag && tag.implies(other))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl FromIterator<PlatformTag> for PlatformTagSet {
+    fn from_iter<T: IntoIterator<Item = PlatformTag>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for PlatformTagSet {
+    type Item = PlatformTag;
+    type IntoIter = std::collections::btree_set::IntoIter<PlatformTag>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PlatformTagSet {
+    type Item = &'a PlatformTag;
+    type IntoIter = std::collections::btree_set::Iter<'a, PlatformTag>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{PlatformFamily, PlatformTag, PlatformTagSet};
+
+    fn set(tags: &[&str]) -> PlatformTagSet {
+        tags.iter()
+            .map(|tag| PlatformTag::from_str(tag).unwrap())
+            .collect()
+    }
+
+    fn tag(tag: &str) -> PlatformTag {
+        PlatformTag::from_str(tag).unwrap()
+    }
+
+    #[test]
+    fn implies() {
+        assert!(tag("manylinux2014_x86_64").implies(&tag("manylinux_2_17_x86_64")));
+        assert!(tag("manylinux_2_17_x86_64").implies(&tag("manylinux2014_x86_64")));
+        assert!(tag("manylinux_2_28_x86_64").implies(&tag("manylinux2014_x86_64")));
+        assert!(!tag("manylinux1_x86_64").implies(&tag("manylinux_2_17_x86_64")));
+        assert!(!tag("manylinux_2_28_x86_64").implies(&tag("manylinux_2_28_aarch64")));
+        assert!(!tag("manylinux_2_28_x86_64").implies(&tag("musllinux_1_1_x86_64")));
+        assert!(tag("musllinux_1_2_x86_64").implies(&tag("linux_x86_64")));
+        assert!(!tag("linux_x86_64").implies(&tag("manylinux_2_17_x86_64")));
+        assert!(tag("macosx_14_0_arm64").implies(&tag("macosx_11_0_universal2")));
+        assert!(!tag("macosx_11_0_universal2").implies(&tag("macosx_11_0_arm64")));
+        assert!(!tag("macosx_10_9_x86_64").implies(&tag("macosx_11_0_x86_64")));
+        assert!(tag("win_amd64").implies(&tag("any")));
+        assert!(!tag("any").implies(&tag("win_amd64")));
+        assert!(!tag("pyodide_2025_0_wasm32").implies(&tag("pyodide_2024_0_wasm32")));
+    }
+
+    #[test]
+    fn intersect_family() {
+        let tags = set(&[
+            "any",
+            "manylinux_2_17_x86_64",
+            "musllinux_1_2_aarch64",
+            "macosx_11_0_arm64",
+            "win_amd64",
+        ]);
+        assert_eq!(
+            tags.intersect_family(PlatformFamily::Linux),
+            set(&["any", "manylinux_2_17_x86_64", "musllinux_1_2_aarch64"])
+        );
+        assert_eq!(
+            tags.intersect_family(PlatformFamily::Windows),
+            set(&["any", "win_amd64"])
+        );
+        assert_eq!(tags.families(), None);
+        assert_eq!(
+            set(&["manylinux_2_17_x86_64", "win_amd64"]).families(),
+            Some([PlatformFamily::Linux, PlatformFamily::Windows].into())
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let tags = set(&[
+            "manylinux_2_17_x86_64",
+            "manylinux2014_x86_64",
+            "manylinux1_i686",
+        ]);
+        assert_eq!(
+            tags.normalize(),
+            set(&["manylinux_2_17_x86_64", "manylinux_2_5_i686"])
+        );
+        assert!(tags.contains(&tag("manylinux_2_5_i686")));
+        assert!(!tags.contains(&tag("manylinux_2_28_x86_64")));
+    }
+
+    #[test]
+    fn reduce() {
+        let tags = set(&[
+            "manylinux2014_x86_64",
+            "manylinux_2_28_x86_64",
+            "linux_x86_64",
+            "macosx_11_0_arm64",
+            "macosx_10_9_universal2",
+        ]);
+        assert_eq!(
+            tags.reduce(),
+            set(&["linux_x86_64", "macosx_10_9_universal2"])
+        );
+        assert!(tags.implies(&tags.reduce()));
+        assert!(tags.reduce().implies(&tags));
+    }
+}
@@ -116,8 +116,11 @@ impl Tags {
         implementation_version: (u8, u8),
         manylinux_compatible: bool,
         gil_disabled: bool,
+        debug_enabled: bool,
+        pymalloc: bool,
     ) -> Result<Self, TagsError> {
-        let implementation = Implementation::parse(implementation_name, gil_disabled)?;
+        let implementation =
+            Implementation::parse(implementation_name, gil_disabled, debug_enabled, pymalloc)?;
 
         // Determine the compatible tags for the current platform.
         let platform_tags = {
@@ -138,8 +141,31 @@ impl Tags {
                 platform_tag.clone(),
             ));
         }
+        // Since Python 3.8, debug builds can also load extension modules built for the
+        // corresponding release build.
+        if let Implementation::CPython {
+            gil_disabled,
+            debug: true,
+            ..
+        } = implementation
+        {
+            if python_version >= (3, 8) {
+                for platform_tag in &platform_tags {
+                    tags.push((
+                        implementation.language_tag(python_version),
+                        AbiTag::CPython {
+                            gil_disabled,
+                            debug: false,
+                            pymalloc: false,
+                            python_version,
+                        },
+                        platform_tag.clone(),
+                    ));
+                }
+            }
+        }
         // 2. abi3 and no abi (e.g. executable binary)
-        if let Implementation::CPython { gil_disabled } = implementation {
+        if let Implementation::CPython { gil_disabled, .. } = implementation {
             // For some reason 3.2 is the minimum python for the cp abi
             for minor in (2..=python_version.1).rev() {
                 // No abi3 for free-threading python
@@ -297,6 +323,45 @@ impl Tags {
         max_compatibility
     }
 
+    /// Returns the most-compatible tag triple among the given tags, along with its
+    /// [`TagPriority`], or `None` if none of the tags are compatible.
+    ///
+    /// Unlike [`Tags::compatibility`], the matched Python, ABI, and platform tags are included,
+    /// which can be used to explain why one wheel is preferred over another.
+    pub fn best_match(
+        &self,
+        wheel_python_tags: &[LanguageTag],
+        wheel_abi_tags: &[AbiTag],
+        wheel_platform_tags: &[PlatformTag],
+    ) -> Option<TagMatch> {
+        let mut best: Option<TagMatch> = None;
+
+        for wheel_py in wheel_python_tags {
+            let Some(abis) = self.map.get(wheel_py) else {
+                continue;
+            };
+            for wheel_abi in wheel_abi_tags {
+                let Some(platforms) = abis.get(wheel_abi) else {
+                    continue;
+                };
+                for wheel_platform in wheel_platform_tags {
+                    let Some(priority) = platforms.get(wheel_platform).copied() else {
+                        continue;
+                    };
+                    if best.as_ref().is_none_or(|best| priority > best.priority) {
+                        best = Some(TagMatch {
+                            priority,
+                            python_tag: *wheel_py,
+                            abi_tag: *wheel_abi,
+                            platform_tag: wheel_platform.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        best
+    }
+
     /// Return the highest-priority Python tag for the [`Tags`].
     pub fn python_tag(&self) -> Option<LanguageTag> {
         self.best.as_ref().map(|(python, _, _)| *python)
@@ -341,6 +406,43 @@ impl TryFrom<usize> for TagPriority {
     }
 }
 
+impl TagPriority {
+    /// Returns the numeric value of the priority, where higher values indicate a more specific
+    /// match for the environment.
+    pub fn get(self) -> u32 {
+        self.0.get()
+    }
+}
+
+impl std::fmt::Display for TagPriority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The most-compatible tag triple for a wheel, as returned by [`Tags::best_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagMatch {
+    /// The priority of the matched tag within the [`Tags`].
+    pub priority: TagPriority,
+    /// The matched Python tag, e.g., `cp312`.
+    pub python_tag: LanguageTag,
+    /// The matched ABI tag, e.g., `abi3`.
+    pub abi_tag: AbiTag,
+    /// The matched platform tag, e.g., `manylinux_2_17_x86_64`.
+    pub platform_tag: PlatformTag,
+}
+
+impl std::fmt::Display for TagMatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            self.python_tag, self.abi_tag, self.platform_tag
+        )
+    }
+}
+
 impl std::fmt::Display for Tags {
     /// Display tags from high to low priority
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -361,7 +463,11 @@ impl std::fmt::Display for Tags {
 
 #[derive(Debug, Clone, Copy)]
 enum Implementation {
-    CPython { gil_disabled: bool },
+    CPython {
+        gil_disabled: bool,
+        debug: bool,
+        pymalloc: bool,
+    },
     PyPy,
     GraalPy,
     Pyston,
@@ -386,8 +492,15 @@ impl Implementation {
     fn abi_tag(self, python_version: (u8, u8), implementation_version: (u8, u8)) -> AbiTag {
         match self {
             // Ex) `cp39`
-            Self::CPython { gil_disabled } => AbiTag::CPython {
+            Self::CPython {
+                gil_disabled,
+                debug,
+                pymalloc,
+            } => AbiTag::CPython {
                 gil_disabled,
+                debug,
+                // The `m` flag was dropped in Python 3.8.
+                pymalloc: pymalloc && python_version < (3, 8),
                 python_version,
             },
             // Ex) `pypy39_pp73`
@@ -407,13 +520,22 @@ impl Implementation {
         }
     }
 
-    fn parse(name: &str, gil_disabled: bool) -> Result<Self, TagsError> {
+    fn parse(
+        name: &str,
+        gil_disabled: bool,
+        debug: bool,
+        pymalloc: bool,
+    ) -> Result<Self, TagsError> {
         if gil_disabled && name != "cpython" {
             return Err(TagsError::GilIsACPythonProblem(name.to_string()));
         }
         match name {
             // Known and supported implementations.
-            "cpython" => Ok(Self::CPython { gil_disabled }),
+            "cpython" => Ok(Self::CPython {
+                gil_disabled,
+                debug,
+                pymalloc,
+            }),
             "pypy" => Ok(Self::PyPy),
             "graalpy" => Ok(Self::GraalPy),
             "pyston" => Ok(Self::Pyston),
@@ -964,6 +1086,61 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_best_match() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 28,
+                },
+                Arch::X86_64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            true,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+
+        let best_match = |python: &[&str], abi: &[&str], platform: &[&str]| {
+            tags.best_match(
+                &python
+                    .iter()
+                    .map(|tag| LanguageTag::from_str(tag).unwrap())
+                    .collect::<Vec<_>>(),
+                &abi.iter()
+                    .map(|tag| AbiTag::from_str(tag).unwrap())
+                    .collect::<Vec<_>>(),
+                &platform
+                    .iter()
+                    .map(|tag| PlatformTag::from_str(tag).unwrap())
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        // The most-specific platform tag should win.
+        let matched = best_match(
+            &["cp312"],
+            &["cp312"],
+            &["manylinux2014_x86_64", "manylinux_2_28_x86_64"],
+        )
+        .unwrap();
+        assert_snapshot!(matched, @"cp312-cp312-manylinux_2_28_x86_64");
+
+        // A pure-Python wheel is less specific than a platform-specific wheel.
+        let pure = best_match(&["py2", "py3"], &["none"], &["any"]).unwrap();
+        assert_snapshot!(pure, @"py3-none-any");
+        assert!(pure.priority < matched.priority);
+        assert!(pure.priority.get() < matched.priority.get());
+
+        // Incompatible tags have no match.
+        assert!(best_match(&["cp311"], &["cp311"], &["manylinux_2_28_x86_64"]).is_none());
+    }
+
     /// Check platform tag ordering.
     /// The list is displayed in decreasing priority.
     ///
@@ -1467,6 +1644,8 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
+            true,
         )
         .unwrap();
         assert_snapshot!(
@@ -1508,6 +1687,93 @@ mod tests {
     "###);
     }
 
+    /// Ensure debug builds are compatible with both debug and release extension modules on
+    /// Python 3.8 and later, and that pre-3.8 builds use the `m` (pymalloc) flag.
+    #[test]
+    fn test_debug_abi() {
+        let platform = Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 28,
+            },
+            Arch::X86_64,
+        );
+        let cp313 = LanguageTag::CPython {
+            python_version: (3, 13),
+        };
+
+        let tags = Tags::from_env(
+            &platform,
+            (3, 13),
+            "cpython",
+            (3, 13),
+            true,
+            true,
+            true,
+            true,
+        )
+        .unwrap();
+        for (abi, expected) in [
+            ("cp313td", true),
+            ("cp313t", true),
+            ("cp313d", false),
+            ("cp313", false),
+            ("abi3", false),
+        ] {
+            assert_eq!(
+                tags.is_compatible_abi(cp313, AbiTag::from_str(abi).unwrap()),
+                expected,
+                "{abi}"
+            );
+        }
+
+        let tags = Tags::from_env(
+            &platform,
+            (3, 13),
+            "cpython",
+            (3, 13),
+            true,
+            false,
+            true,
+            true,
+        )
+        .unwrap();
+        for (abi, expected) in [
+            ("cp313d", true),
+            ("cp313", true),
+            ("cp313t", false),
+            ("abi3", true),
+        ] {
+            assert_eq!(
+                tags.is_compatible_abi(cp313, AbiTag::from_str(abi).unwrap()),
+                expected,
+                "{abi}"
+            );
+        }
+
+        let cp37 = LanguageTag::CPython {
+            python_version: (3, 7),
+        };
+        let tags = Tags::from_env(
+            &platform,
+            (3, 7),
+            "cpython",
+            (3, 7),
+            true,
+            false,
+            true,
+            true,
+        )
+        .unwrap();
+        for (abi, expected) in [("cp37dm", true), ("cp37m", false), ("cp37", false)] {
+            assert_eq!(
+                tags.is_compatible_abi(cp37, AbiTag::from_str(abi).unwrap()),
+                expected,
+                "{abi}"
+            );
+        }
+    }
+
     /// Check full tag ordering.
     /// The list is displayed in decreasing priority.
     ///
@@ -1530,6 +1796,8 @@ mod tests {
             (3, 9),
             true,
             false,
+            false,
+            true,
         )
         .unwrap();
         assert_snapshot!(
@@ -2154,6 +2422,8 @@ mod tests {
             (3, 9),
             false,
             false,
+            false,
+            true,
         )
         .unwrap();
         assert_snapshot!(
@@ -8,7 +8,7 @@ use uv_warnings::warn_user_once;
 
 bitflags::bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-    pub struct PreviewFeatures: u32 {
+    pub struct PreviewFeatures: u64 {
         const PYTHON_INSTALL_DEFAULT = 1 << 0;
         const PYTHON_UPGRADE = 1 << 1;
         const JSON_OUTPUT = 1 << 2;
@@ -26,6 +26,26 @@ bitflags::bitflags! {
         const WORKSPACE_DIR = 1 << 14;
         const WORKSPACE_LIST = 1 << 15;
         const SBOM_EXPORT = 1 << 16;
+        const ADD_FROM_IMPORTS = 1 << 17;
+        const PUBLISH_UPLOAD_V2 = 1 << 18;
+        const VERIFY = 1 << 19;
+        const INDEX_SYNC = 1 << 20;
+        const LOCK_AUDIT = 1 << 21;
+        const ENV_SNAPSHOTS = 1 << 22;
+        const TRUSTED_BACKENDS = 1 << 23;
+        const PYTHON_DELTA_DOWNLOADS = 1 << 24;
+        const TOOL_LOCK = 1 << 25;
+        const TREE_INTERACTIVE = 1 << 26;
+        const REMOTE_CACHE = 1 << 27;
+        const INDEX_SERVE = 1 << 28;
+        const REMOVE_UNUSED = 1 << 29;
+        const HTTP3 = 1 << 30;
+        const BUNDLE = 1 << 31;
+        const TEST = 1 << 32;
+        const RUN_CACHE = 1 << 33;
+        const CREDENTIAL_REFRESH = 1 << 34;
+        const LOCK_EXPLAIN = 1 << 35;
+        const SERVE = 1 << 36;
     }
 }
 
@@ -52,6 +72,26 @@ impl PreviewFeatures {
             Self::WORKSPACE_DIR => "workspace-dir",
             Self::WORKSPACE_LIST => "workspace-list",
             Self::SBOM_EXPORT => "sbom-export",
+            Self::ADD_FROM_IMPORTS => "add-from-imports",
+            Self::PUBLISH_UPLOAD_V2 => "publish-upload-v2",
+            Self::VERIFY => "verify",
+            Self::INDEX_SYNC => "index-sync",
+            Self::LOCK_AUDIT => "lock-audit",
+            Self::ENV_SNAPSHOTS => "env-snapshots",
+            Self::TRUSTED_BACKENDS => "trusted-backends",
+            Self::PYTHON_DELTA_DOWNLOADS => "python-delta-downloads",
+            Self::TOOL_LOCK => "tool-lock",
+            Self::TREE_INTERACTIVE => "tree-interactive",
+            Self::REMOTE_CACHE => "remote-cache",
+            Self::INDEX_SERVE => "index-serve",
+            Self::REMOVE_UNUSED => "remove-unused",
+            Self::HTTP3 => "http3",
+            Self::BUNDLE => "bundle",
+            Self::TEST => "test",
+            Self::RUN_CACHE => "run-cache",
+            Self::CREDENTIAL_REFRESH => "credential-refresh",
+            Self::LOCK_EXPLAIN => "lock-explain",
+            Self::SERVE => "serve",
             _ => panic!("`flag_as_str` can only be used for exactly one feature flag"),
         }
     }
@@ -106,6 +146,26 @@ impl FromStr for PreviewFeatures {
                 "workspace-dir" => Self::WORKSPACE_DIR,
                 "workspace-list" => Self::WORKSPACE_LIST,
                 "sbom-export" => Self::SBOM_EXPORT,
+                "add-from-imports" => Self::ADD_FROM_IMPORTS,
+                "publish-upload-v2" => Self::PUBLISH_UPLOAD_V2,
+                "verify" => Self::VERIFY,
+                "index-sync" => Self::INDEX_SYNC,
+                "lock-audit" => Self::LOCK_AUDIT,
+                "env-snapshots" => Self::ENV_SNAPSHOTS,
+                "trusted-backends" => Self::TRUSTED_BACKENDS,
+                "python-delta-downloads" => Self::PYTHON_DELTA_DOWNLOADS,
+                "tool-lock" => Self::TOOL_LOCK,
+                "tree-interactive" => Self::TREE_INTERACTIVE,
+                "remote-cache" => Self::REMOTE_CACHE,
+                "index-serve" => Self::INDEX_SERVE,
+                "remove-unused" => Self::REMOVE_UNUSED,
+                "http3" => Self::HTTP3,
+                "bundle" => Self::BUNDLE,
+                "test" => Self::TEST,
+                "run-cache" => Self::RUN_CACHE,
+                "credential-refresh" => Self::CREDENTIAL_REFRESH,
+                "lock-explain" => Self::LOCK_EXPLAIN,
+                "serve" => Self::SERVE,
                 _ => {
                     warn_user_once!("Unknown preview feature: `{part}`");
                     continue;
@@ -282,6 +342,50 @@ mod tests {
         assert_eq!(PreviewFeatures::FORMAT.flag_as_str(), "format");
         assert_eq!(PreviewFeatures::S3_ENDPOINT.flag_as_str(), "s3-endpoint");
         assert_eq!(PreviewFeatures::SBOM_EXPORT.flag_as_str(), "sbom-export");
+        assert_eq!(
+            PreviewFeatures::ADD_FROM_IMPORTS.flag_as_str(),
+            "add-from-imports"
+        );
+        assert_eq!(
+            PreviewFeatures::PUBLISH_UPLOAD_V2.flag_as_str(),
+            "publish-upload-v2"
+        );
+        assert_eq!(PreviewFeatures::VERIFY.flag_as_str(), "verify");
+        assert_eq!(PreviewFeatures::INDEX_SYNC.flag_as_str(), "index-sync");
+        assert_eq!(PreviewFeatures::LOCK_AUDIT.flag_as_str(), "lock-audit");
+        assert_eq!(
+            PreviewFeatures::ENV_SNAPSHOTS.flag_as_str(),
+            "env-snapshots"
+        );
+        assert_eq!(
+            PreviewFeatures::TRUSTED_BACKENDS.flag_as_str(),
+            "trusted-backends"
+        );
+        assert_eq!(
+            PreviewFeatures::PYTHON_DELTA_DOWNLOADS.flag_as_str(),
+            "python-delta-downloads"
+        );
+        assert_eq!(PreviewFeatures::TOOL_LOCK.flag_as_str(), "tool-lock");
+        assert_eq!(
+            PreviewFeatures::TREE_INTERACTIVE.flag_as_str(),
+            "tree-interactive"
+        );
+        assert_eq!(PreviewFeatures::REMOTE_CACHE.flag_as_str(), "remote-cache");
+        assert_eq!(PreviewFeatures::INDEX_SERVE.flag_as_str(), "index-serve");
+        assert_eq!(
+            PreviewFeatures::REMOVE_UNUSED.flag_as_str(),
+            "remove-unused"
+        );
+        assert_eq!(PreviewFeatures::HTTP3.flag_as_str(), "http3");
+        assert_eq!(PreviewFeatures::BUNDLE.flag_as_str(), "bundle");
+        assert_eq!(PreviewFeatures::TEST.flag_as_str(), "test");
+        assert_eq!(PreviewFeatures::RUN_CACHE.flag_as_str(), "run-cache");
+        assert_eq!(
+            PreviewFeatures::CREDENTIAL_REFRESH.flag_as_str(),
+            "credential-refresh"
+        );
+        assert_eq!(PreviewFeatures::LOCK_EXPLAIN.flag_as_str(), "lock-explain");
+        assert_eq!(PreviewFeatures::SERVE.flag_as_str(), "serve");
     }
 
     #[test]
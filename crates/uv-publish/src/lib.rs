@@ -1,4 +1,6 @@
+mod static_index;
 mod trusted_publishing;
+mod upload_session;
 
 use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
@@ -40,7 +42,9 @@ use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
+pub use crate::static_index::{StaticIndex, StaticIndexError};
 use crate::trusted_publishing::{TrustedPublishingError, TrustedPublishingToken};
+pub use crate::upload_session::{UploadSession, UploadSessionError, supports_upload_sessions};
 
 #[derive(Error, Debug)]
 pub enum PublishError {
@@ -69,6 +73,18 @@ pub enum PublishError {
         Box<DisplaySafeUrl>,
         #[source] Box<PublishSendError>,
     ),
+    #[error("Failed to publish `{}` to {}", _0.user_display(), _1)]
+    StaticIndex(
+        PathBuf,
+        Box<DisplaySafeUrl>,
+        #[source] Box<StaticIndexError>,
+    ),
+    #[error("Failed to publish {0} to {1}")]
+    UploadSession(
+        String,
+        Box<DisplaySafeUrl>,
+        #[source] Box<UploadSessionError>,
+    ),
     #[error("Failed to obtain token for trusted publishing")]
     TrustedPublishing(#[from] Box<TrustedPublishingError>),
     #[error("{0} are not allowed when using trusted publishing")]
@@ -110,6 +126,8 @@ pub enum PublishPrepareError {
     Read(String, #[source] io::Error),
     #[error("Invalid PEP 740 attestation (not JSON): `{0}`")]
     InvalidAttestation(PathBuf, #[source] serde_json::Error),
+    #[error("`License-Expression` is not a valid SPDX expression: `{0}`")]
+    InvalidLicenseExpression(String, #[source] spdx::error::ParseError),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -609,9 +627,11 @@ pub async fn check_url(
     } = check_url_client;
 
     // Avoid using the PyPI 10min default cache.
-    let cache_refresh = (*cache)
-        .clone()
-        .with_refresh(Refresh::from_args(None, vec![filename.name().clone()]));
+    let cache_refresh = (*cache).clone().with_refresh(Refresh::from_args(
+        None,
+        vec![filename.name().clone()],
+        vec![],
+    ));
     let registry_client = registry_client_builder
         .clone()
         .cache(cache_refresh)
@@ -761,7 +781,11 @@ async fn source_dist_pkg_info(file: &Path) -> Result<Vec<u8>, PublishPrepareErro
     }
 }
 
-async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, PublishPrepareError> {
+/// Read the raw core metadata (`METADATA` or `PKG-INFO`) from a distribution.
+async fn raw_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<Vec<u8>, PublishPrepareError> {
     let contents = match filename {
         DistFilename::SourceDistFilename(source_dist) => {
             if source_dist.extension != SourceDistExtension::TarGz {
@@ -776,6 +800,11 @@ async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, Pu
             read_metadata_async_seek(wheel, reader).await?
         }
     };
+    Ok(contents)
+}
+
+async fn metadata(file: &Path, filename: &DistFilename) -> Result<Metadata23, PublishPrepareError> {
+    let contents = raw_metadata(file, filename).await?;
     Ok(Metadata23::parse(&contents)?)
 }
 
@@ -840,6 +869,12 @@ impl FormMetadata {
             dynamic,
         } = metadata(file, filename).await?;
 
+        // Reject invalid PEP 639 license expressions before uploading, rather than relying on the
+        // registry to do so.
+        if let Some(license_expression) = &license_expression {
+            validate_license_expression(license_expression)?;
+        }
+
         let mut form_metadata = vec![
             (":action", "file_upload".to_string()),
             ("sha256_digest", sha256_hash.digest.to_string()),
@@ -922,6 +957,14 @@ impl<'a> IntoIterator for &'a FormMetadata {
 /// Build the upload request.
 ///
 /// Returns the [`RequestBuilder`] and the reporter progress bar ID.
+/// Validate a `License-Expression` against the SPDX license expression syntax, per PEP 639.
+fn validate_license_expression(license_expression: &str) -> Result<(), PublishPrepareError> {
+    spdx::Expression::parse(license_expression).map_err(|err| {
+        PublishPrepareError::InvalidLicenseExpression(license_expression.to_string(), err)
+    })?;
+    Ok(())
+}
+
 async fn build_upload_request<'a>(
     group: &UploadDistribution,
     registry: &DisplaySafeUrl,
@@ -1143,7 +1186,10 @@ mod tests {
     use uv_distribution_filename::DistFilename;
     use uv_redacted::DisplaySafeUrl;
 
-    use crate::{FormMetadata, Reporter, UploadDistribution, build_upload_request, group_files};
+    use crate::{
+        FormMetadata, Reporter, UploadDistribution, build_upload_request, group_files,
+        validate_license_expression,
+    };
 
     struct DummyReporter;
 
@@ -1717,4 +1763,15 @@ mod tests {
             "#);
         });
     }
+
+    #[test]
+    fn license_expression() {
+        validate_license_expression("MIT").unwrap();
+        validate_license_expression("MIT OR Apache-2.0").unwrap();
+        validate_license_expression("GPL-3.0-or-later WITH Classpath-exception-2.0").unwrap();
+
+        let err = validate_license_expression("MIT OR").unwrap_err();
+        assert_snapshot!(err, @"`License-Expression` is not a valid SPDX expression: `MIT OR`");
+        validate_license_expression("Not-A-License").unwrap_err();
+    }
 }
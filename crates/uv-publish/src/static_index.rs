@@ -0,0 +1,513 @@
+//! Publishing to static indexes, i.e., a local directory or an S3 bucket laid out as a
+//! [simple repository](https://packaging.python.org/en/latest/specifications/simple-repository-api/).
+//!
+//! Each project is stored in a directory named after the normalized project name, alongside the
+//! project page and the core metadata of each distribution:
+//!
+//! ```text
+//! <root>/index.html
+//! <root>/index.json
+//! <root>/<project>/index.html
+//! <root>/<project>/index.json
+//! <root>/<project>/<filename>
+//! <root>/<project>/<filename>.metadata
+//! ```
+//!
+//! The JSON listings ([PEP 691](https://peps.python.org/pep-0691/)) are the source of truth: on
+//! each publish, they're read, updated, and used to regenerate the HTML listings
+//! ([PEP 503](https://peps.python.org/pep-0503/)). Since S3 doesn't resolve `index.html` for
+//! directory-style keys, the HTML listings in S3 buckets are additionally stored under the
+//! directory key itself (e.g., `<root>/<project>/`).
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use reqwest::StatusCode;
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_client::BaseClient;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23};
+use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
+use uv_static::EnvVars;
+
+use crate::{PublishPrepareError, UploadDistribution, hash_file, raw_metadata};
+
+/// The media type for HTML listings.
+const SIMPLE_HTML: &str = "text/html";
+
+/// The media type for JSON listings.
+const SIMPLE_JSON: &str = "application/vnd.pypi.simple.v1+json";
+
+#[derive(Debug, Error)]
+pub enum StaticIndexError {
+    #[error(transparent)]
+    Prepare(#[from] PublishPrepareError),
+    #[error("Failed to read `{}`", _0.user_display())]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Failed to write `{}`", _0.user_display())]
+    Write(PathBuf, #[source] io::Error),
+    #[error("Failed to send request to `{0}`")]
+    ReqwestMiddleware(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("Failed to read response from `{0}`")]
+    Reqwest(DisplaySafeUrl, #[source] reqwest::Error),
+    #[error("Request to `{0}` failed with status code {1}. Server says: {2}")]
+    Status(DisplaySafeUrl, StatusCode, String),
+    #[error("Invalid index listing: `{0}`")]
+    InvalidListing(String, #[source] serde_json::Error),
+    #[error("`{0}` already exists in the index with different contents")]
+    Conflict(String),
+    #[error(
+        "Publishing to `s3://` URLs requires `{}` to be set",
+        EnvVars::UV_S3_ENDPOINT_URL
+    )]
+    MissingS3Endpoint,
+    #[error("Invalid S3 endpoint URL: `{0}`")]
+    InvalidS3Endpoint(String, #[source] DisplaySafeUrlError),
+    #[error("Invalid publish URL: `{0}`")]
+    InvalidUrl(DisplaySafeUrl),
+}
+
+/// A static index to publish to.
+#[derive(Debug, Clone)]
+pub enum StaticIndex {
+    /// A local directory, from a `file://` publish URL.
+    Directory(PathBuf),
+    /// A prefix in an S3 bucket, from an `s3://<bucket>/<prefix>` publish URL.
+    ///
+    /// Requests are sent to the bucket via the `UV_S3_ENDPOINT_URL` endpoint (using path-style
+    /// addressing), and are signed by the authentication middleware.
+    S3(DisplaySafeUrl),
+}
+
+impl StaticIndex {
+    /// Determine the static index for a publish URL, if it refers to one.
+    pub fn from_url(url: &DisplaySafeUrl) -> Result<Option<Self>, StaticIndexError> {
+        match url.scheme() {
+            "file" => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|()| StaticIndexError::InvalidUrl(url.clone()))?;
+                Ok(Some(Self::Directory(path)))
+            }
+            "s3" => {
+                let bucket = url
+                    .host_str()
+                    .ok_or_else(|| StaticIndexError::InvalidUrl(url.clone()))?;
+                let endpoint = std::env::var(EnvVars::UV_S3_ENDPOINT_URL)
+                    .map_err(|_| StaticIndexError::MissingS3Endpoint)?;
+                let mut root = DisplaySafeUrl::parse(&endpoint)
+                    .map_err(|err| StaticIndexError::InvalidS3Endpoint(endpoint.clone(), err))?;
+                {
+                    let mut segments = root
+                        .path_segments_mut()
+                        .map_err(|()| StaticIndexError::InvalidUrl(url.clone()))?;
+                    segments.pop_if_empty().push(bucket);
+                    for segment in url.path().split('/').filter(|segment| !segment.is_empty()) {
+                        segments.push(segment);
+                    }
+                    // The URL must end in a trailing slash for relative paths to resolve.
+                    segments.push("");
+                }
+                Ok(Some(Self::S3(root)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Publish a distribution to the index, regenerating the listings.
+    ///
+    /// Returns `false` if an identical file already exists in the index.
+    pub async fn publish(
+        &self,
+        group: &UploadDistribution,
+        client: &BaseClient,
+    ) -> Result<bool, StaticIndexError> {
+        let project = group.filename.name().to_string();
+
+        let sha256 = hash_file(&group.file, vec![Hasher::from(HashAlgorithm::Sha256)])
+            .await
+            .map_err(PublishPrepareError::from)?
+            .remove(0)
+            .digest
+            .to_string();
+
+        // Check for an existing file with the same name.
+        let page_key = format!("{project}/index.json");
+        let mut page = match self.read(client, &page_key).await? {
+            Some(contents) => serde_json::from_slice::<ProjectPage>(&contents)
+                .map_err(|err| StaticIndexError::InvalidListing(page_key.clone(), err))?,
+            None => ProjectPage {
+                meta: Meta::default(),
+                name: project.clone(),
+                files: Vec::new(),
+            },
+        };
+        if let Some(existing) = page
+            .files
+            .iter()
+            .find(|file| file.filename == group.raw_filename)
+        {
+            if existing.hashes.get("sha256") == Some(&sha256) {
+                return Ok(false);
+            }
+            return Err(StaticIndexError::Conflict(group.raw_filename.clone()));
+        }
+
+        // Upload the distribution and its core metadata.
+        let metadata = raw_metadata(&group.file, &group.filename).await?;
+        let requires_python = Metadata23::parse(&metadata)
+            .map_err(PublishPrepareError::from)?
+            .requires_python;
+        let metadata_sha256 = {
+            let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+            hasher.update(&metadata);
+            HashDigest::from(hasher).digest.to_string()
+        };
+
+        let contents = fs_err::tokio::read(&group.file)
+            .await
+            .map_err(PublishPrepareError::from)?;
+        self.write(
+            client,
+            &format!("{project}/{}", group.raw_filename),
+            contents,
+            "application/octet-stream",
+        )
+        .await?;
+        self.write(
+            client,
+            &format!("{project}/{}.metadata", group.raw_filename),
+            metadata,
+            "application/octet-stream",
+        )
+        .await?;
+
+        // Regenerate the project page.
+        page.files.push(FileEntry {
+            filename: group.raw_filename.clone(),
+            url: group.raw_filename.clone(),
+            hashes: BTreeMap::from([("sha256".to_string(), sha256)]),
+            requires_python,
+            core_metadata: Some(BTreeMap::from([("sha256".to_string(), metadata_sha256)])),
+        });
+        page.files
+            .sort_by(|file, other| file.filename.cmp(&other.filename));
+        self.write_listing(client, &project, &page, &page.to_html())
+            .await?;
+
+        // Regenerate the project list, if the project is new.
+        let mut list = match self.read(client, "index.json").await? {
+            Some(contents) => serde_json::from_slice::<ProjectList>(&contents)
+                .map_err(|err| StaticIndexError::InvalidListing("index.json".to_string(), err))?,
+            None => ProjectList::default(),
+        };
+        if !list.projects.iter().any(|entry| entry.name == project) {
+            list.projects.push(ProjectEntry { name: project });
+            list.projects
+                .sort_by(|entry, other| entry.name.cmp(&other.name));
+            self.write_listing(client, "", &list, &list.to_html())
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Write the JSON and HTML listings for a directory of the index.
+    async fn write_listing(
+        &self,
+        client: &BaseClient,
+        directory: &str,
+        json: &impl Serialize,
+        html: &str,
+    ) -> Result<(), StaticIndexError> {
+        let prefix = if directory.is_empty() {
+            String::new()
+        } else {
+            format!("{directory}/")
+        };
+        let json = serde_json::to_vec(json).expect("Index listings are serializable");
+        self.write(client, &format!("{prefix}index.json"), json, SIMPLE_JSON)
+            .await?;
+        self.write(
+            client,
+            &format!("{prefix}index.html"),
+            html.as_bytes().to_vec(),
+            SIMPLE_HTML,
+        )
+        .await?;
+        if matches!(self, Self::S3(_)) {
+            self.write(client, &prefix, html.as_bytes().to_vec(), SIMPLE_HTML)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Read a file from the index, relative to its root.
+    ///
+    /// Returns `None` if the file doesn't exist.
+    async fn read(
+        &self,
+        client: &BaseClient,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, StaticIndexError> {
+        match self {
+            Self::Directory(root) => {
+                let path = root.join(key);
+                match fs_err::tokio::read(&path).await {
+                    Ok(contents) => Ok(Some(contents)),
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+                    Err(err) => Err(StaticIndexError::Read(path, err)),
+                }
+            }
+            Self::S3(root) => {
+                let url = join(root, key)?;
+                debug!("Reading {url}");
+                let response = client
+                    .for_host(&url)
+                    .get(Url::from(url.clone()))
+                    .send()
+                    .await
+                    .map_err(|err| StaticIndexError::ReqwestMiddleware(url.clone(), err))?;
+                if response.status() == StatusCode::NOT_FOUND {
+                    return Ok(None);
+                }
+                let response = check_status(&url, response).await?;
+                let contents = response
+                    .bytes()
+                    .await
+                    .map_err(|err| StaticIndexError::Reqwest(url.clone(), err))?;
+                Ok(Some(contents.to_vec()))
+            }
+        }
+    }
+
+    /// Write a file to the index, relative to its root.
+    async fn write(
+        &self,
+        client: &BaseClient,
+        key: &str,
+        contents: Vec<u8>,
+        content_type: &'static str,
+    ) -> Result<(), StaticIndexError> {
+        match self {
+            Self::Directory(root) => {
+                let path = root.join(key);
+                debug!("Writing {}", path.user_display());
+                write_file(&path, &contents)
+                    .await
+                    .map_err(|err| StaticIndexError::Write(path, err))
+            }
+            Self::S3(root) => {
+                let url = join(root, key)?;
+                debug!("Writing {url}");
+                let response = client
+                    .for_host(&url)
+                    .put(Url::from(url.clone()))
+                    .header(CONTENT_TYPE, content_type)
+                    .body(contents)
+                    .send()
+                    .await
+                    .map_err(|err| StaticIndexError::ReqwestMiddleware(url.clone(), err))?;
+                check_status(&url, response).await?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Write a file, such that readers never observe a partially written file.
+async fn write_file(path: &Path, contents: &[u8]) -> Result<(), io::Error> {
+    let parent = path.parent().expect("Index files are in a directory");
+    fs_err::tokio::create_dir_all(parent).await?;
+    uv_fs::write_atomic(path, contents).await
+}
+
+/// Join a key to the root URL of an S3-backed index.
+fn join(root: &DisplaySafeUrl, key: &str) -> Result<DisplaySafeUrl, StaticIndexError> {
+    root.join(key)
+        .map_err(|_| StaticIndexError::InvalidUrl(root.clone()))
+}
+
+/// Map an unsuccessful response to an error.
+async fn check_status(
+    url: &DisplaySafeUrl,
+    response: reqwest::Response,
+) -> Result<reqwest::Response, StaticIndexError> {
+    let status = response.status();
+    debug!("Response code for {url}: {status}");
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response
+        .text()
+        .await
+        .map_err(|err| StaticIndexError::Reqwest(url.clone(), err))?;
+    Err(StaticIndexError::Status(url.clone(), status, body))
+}
+
+/// Escape a string for use in HTML text or attribute values.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The `meta` key included in every JSON listing.
+#[derive(Debug, Serialize, Deserialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: String,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self {
+            api_version: "1.0".to_string(),
+        }
+    }
+}
+
+/// The list of projects in the index.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProjectList {
+    meta: Meta,
+    projects: Vec<ProjectEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectEntry {
+    name: String,
+}
+
+impl ProjectList {
+    fn to_html(&self) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<body>\n");
+        for ProjectEntry { name } in &self.projects {
+            let name = escape(name);
+            writeln!(html, "<a href=\"{name}/\">{name}</a><br/>").unwrap();
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// The files available for a single project.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectPage {
+    meta: Meta,
+    name: String,
+    files: Vec<FileEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FileEntry {
+    filename: String,
+    url: String,
+    hashes: BTreeMap<String, String>,
+    #[serde(
+        rename = "requires-python",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    requires_python: Option<String>,
+    #[serde(
+        rename = "core-metadata",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    core_metadata: Option<BTreeMap<String, String>>,
+}
+
+impl ProjectPage {
+    fn to_html(&self) -> String {
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<body>\n<h1>Links for {}</h1>\n",
+            escape(&self.name)
+        );
+        for file in &self.files {
+            let mut href = escape(&file.url);
+            if let Some(sha256) = file.hashes.get("sha256") {
+                write!(href, "#sha256={sha256}").unwrap();
+            }
+            write!(html, "<a href=\"{href}\"").unwrap();
+            if let Some(requires_python) = &file.requires_python {
+                write!(
+                    html,
+                    " data-requires-python=\"{}\"",
+                    escape(requires_python)
+                )
+                .unwrap();
+            }
+            if let Some(sha256) = file
+                .core_metadata
+                .as_ref()
+                .and_then(|hashes| hashes.get("sha256"))
+            {
+                write!(html, " data-core-metadata=\"sha256={sha256}\"").unwrap();
+            }
+            writeln!(html, ">{}</a><br/>", escape(&file.filename)).unwrap();
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{FileEntry, Meta, ProjectEntry, ProjectList, ProjectPage};
+
+    #[test]
+    fn render_listings() {
+        let list = ProjectList {
+            meta: Meta::default(),
+            projects: vec![ProjectEntry {
+                name: "foo".to_string(),
+            }],
+        };
+        insta::assert_snapshot!(list.to_html(), @r#"
+        <!DOCTYPE html>
+        <html>
+        <body>
+        <a href="foo/">foo</a><br/>
+        </body>
+        </html>
+        "#);
+
+        let page = ProjectPage {
+            meta: Meta::default(),
+            name: "foo".to_string(),
+            files: vec![FileEntry {
+                filename: "foo-1.0.0-py3-none-any.whl".to_string(),
+                url: "foo-1.0.0-py3-none-any.whl".to_string(),
+                hashes: BTreeMap::from([("sha256".to_string(), "abc".to_string())]),
+                requires_python: Some(">=3.9".to_string()),
+                core_metadata: Some(BTreeMap::from([("sha256".to_string(), "def".to_string())])),
+            }],
+        };
+        insta::assert_snapshot!(page.to_html(), @r#"
+        <!DOCTYPE html>
+        <html>
+        <body>
+        <h1>Links for foo</h1>
+        <a href="foo-1.0.0-py3-none-any.whl#sha256=abc" data-requires-python="&gt;=3.9" data-core-metadata="sha256=def">foo-1.0.0-py3-none-any.whl</a><br/>
+        </body>
+        </html>
+        "#);
+        insta::assert_snapshot!(
+            serde_json::to_string(&page).unwrap(),
+            @r#"{"meta":{"api-version":"1.0"},"name":"foo","files":[{"filename":"foo-1.0.0-py3-none-any.whl","url":"foo-1.0.0-py3-none-any.whl","hashes":{"sha256":"abc"},"requires-python":">=3.9","core-metadata":{"sha256":"def"}}]}"#
+        );
+    }
+}
@@ -0,0 +1,505 @@
+//! Staged uploads via the [PEP 694](https://peps.python.org/pep-0694/) Upload 2.0 API.
+//!
+//! Instead of uploading each file on its own, an upload session is created for a release. Files
+//! are staged into the session one at a time and the release is then published atomically, such
+//! that either all files of the release become visible on the index or none do.
+
+use std::sync::Arc;
+
+use fs_err::tokio::File;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Body, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, trace};
+use url::Url;
+
+use uv_auth::Credentials;
+use uv_client::{BaseClient, RequestBuilder};
+use uv_extract::hash::Hasher;
+use uv_fs::ProgressReader;
+use uv_pypi_types::HashAlgorithm;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::{PublishPrepareError, Reporter, UploadDistribution, hash_file, raw_metadata};
+
+/// The media type for requests to and responses from the Upload 2.0 API.
+const UPLOAD_V2_MEDIA_TYPE: &str = "application/vnd.pypi.upload.v2+json";
+
+/// The only file upload mechanism supported by uv.
+const HTTP_POST_BYTES: &str = "http-post-bytes";
+
+#[derive(Debug, Error)]
+pub enum UploadSessionError {
+    #[error(transparent)]
+    Prepare(#[from] PublishPrepareError),
+    #[error("Failed to send request to `{0}`")]
+    ReqwestMiddleware(DisplaySafeUrl, #[source] reqwest_middleware::Error),
+    #[error("Failed to read response from `{0}`")]
+    Reqwest(DisplaySafeUrl, #[source] reqwest::Error),
+    #[error("Upload session request to `{0}` failed with status code {1}. Server says: {2}")]
+    Status(DisplaySafeUrl, StatusCode, String),
+    #[error("Invalid upload session response from `{0}`")]
+    InvalidResponse(DisplaySafeUrl, #[source] serde_json::Error),
+    #[error(
+        "The index requested the unsupported file upload mechanism `{0}`; only `{HTTP_POST_BYTES}` is supported"
+    )]
+    UnsupportedMechanism(String),
+    #[error("The upload session for {0} was not published; its status is `{1}`")]
+    NotPublished(String, String),
+}
+
+/// The `meta` key included in every Upload 2.0 API request and response.
+#[derive(Debug, Serialize, Deserialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: String,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self {
+            api_version: "2.0".to_string(),
+        }
+    }
+}
+
+/// A response that only carries the `meta` key, as returned when probing for support.
+#[derive(Debug, Deserialize)]
+struct MetaResponse {
+    meta: Meta,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateSessionRequest<'a> {
+    meta: Meta,
+    name: &'a str,
+    version: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionResponse {
+    links: SessionLinks,
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionLinks {
+    /// The URL used to publish, cancel, or query the session.
+    session: Url,
+    /// The URL used to initiate file uploads.
+    upload: Url,
+}
+
+#[derive(Debug, Serialize)]
+struct FileUploadRequest<'a> {
+    meta: Meta,
+    filename: &'a str,
+    size: u64,
+    hashes: FileHashes,
+    metadata: String,
+    mechanism: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct FileHashes {
+    sha256: String,
+    blake2b: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileUploadResponse {
+    links: FileUploadLinks,
+    mechanism: FileUploadMechanism,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileUploadLinks {
+    /// The URL used to complete or cancel the file upload.
+    #[serde(rename = "file-upload-session")]
+    file_upload_session: Url,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileUploadMechanism {
+    identifier: String,
+    /// The URL to which the file contents are sent, for the `http-post-bytes` mechanism.
+    #[serde(rename = "file-url")]
+    file_url: Option<Url>,
+}
+
+#[derive(Debug, Serialize)]
+struct ActionRequest {
+    meta: Meta,
+    action: &'static str,
+}
+
+/// Whether the publish URL advertises support for the Upload 2.0 API.
+///
+/// An index signals support by responding to a `GET` request for the publish URL with an Upload
+/// 2.0 API response. Any failure is treated as a lack of support, so that uv falls back to the
+/// legacy upload API.
+pub async fn supports_upload_sessions(
+    registry: &DisplaySafeUrl,
+    client: &BaseClient,
+    credentials: &Credentials,
+) -> bool {
+    let request = authenticate(
+        client.for_host(registry).get(Url::from(registry.clone())),
+        credentials,
+    )
+    .header(ACCEPT, UPLOAD_V2_MEDIA_TYPE);
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(err) => {
+            debug!("Failed to query {registry} for Upload 2.0 API support: {err}");
+            return false;
+        }
+    };
+    if !response.status().is_success() {
+        debug!(
+            "{registry} does not support the Upload 2.0 API (status code {})",
+            response.status()
+        );
+        return false;
+    }
+    let is_upload_v2 = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with(UPLOAD_V2_MEDIA_TYPE));
+    if !is_upload_v2 {
+        debug!("{registry} does not support the Upload 2.0 API (unexpected content type)");
+        return false;
+    }
+    match response.json::<MetaResponse>().await {
+        Ok(MetaResponse { meta }) if meta.api_version.starts_with("2.") => {
+            debug!(
+                "{registry} supports the Upload 2.0 API (version {})",
+                meta.api_version
+            );
+            true
+        }
+        Ok(MetaResponse { meta }) => {
+            debug!(
+                "{registry} advertises unsupported upload API version {}",
+                meta.api_version
+            );
+            false
+        }
+        Err(err) => {
+            debug!("Invalid Upload 2.0 API response from {registry}: {err}");
+            false
+        }
+    }
+}
+
+/// An upload session for a single release, i.e., a name and version.
+#[derive(Debug)]
+pub struct UploadSession {
+    /// The `name==version` of the release, for display purposes.
+    release: String,
+    links: SessionLinks,
+}
+
+impl UploadSession {
+    /// Create a new upload session for the given release.
+    pub async fn create(
+        registry: &DisplaySafeUrl,
+        name: &str,
+        version: &str,
+        client: &BaseClient,
+        credentials: &Credentials,
+    ) -> Result<Self, UploadSessionError> {
+        debug!("Creating upload session for {name}=={version} at {registry}");
+        let body = CreateSessionRequest {
+            meta: Meta::default(),
+            name,
+            version,
+        };
+        let response = send_json(
+            credentials,
+            client.for_host(registry).post(Url::from(registry.clone())),
+            registry,
+            &body,
+        )
+        .await?;
+        let session: SessionResponse = read_json(registry, response).await?;
+        trace!("Created upload session: {}", session.links.session);
+        Ok(Self {
+            release: format!("{name}=={version}"),
+            links: session.links,
+        })
+    }
+
+    /// Stage a file into the upload session.
+    ///
+    /// The file isn't visible on the index until the session is published.
+    pub async fn upload(
+        &self,
+        group: &UploadDistribution,
+        client: &BaseClient,
+        credentials: &Credentials,
+        reporter: Arc<impl Reporter>,
+    ) -> Result<(), UploadSessionError> {
+        let hashes = hash_file(
+            &group.file,
+            vec![
+                Hasher::from(HashAlgorithm::Sha256),
+                Hasher::from(HashAlgorithm::Blake2b),
+            ],
+        )
+        .await
+        .map_err(PublishPrepareError::from)?;
+        let digest = |algorithm| {
+            hashes
+                .iter()
+                .find(|hash| hash.algorithm == algorithm)
+                .map(|hash| hash.digest.to_string())
+                .unwrap()
+        };
+        let metadata = raw_metadata(&group.file, &group.filename).await?;
+        let size = fs_err::tokio::metadata(&group.file)
+            .await
+            .map_err(PublishPrepareError::from)?
+            .len();
+
+        // Initiate the file upload.
+        let upload_url = DisplaySafeUrl::from_url(self.links.upload.clone());
+        let body = FileUploadRequest {
+            meta: Meta::default(),
+            filename: &group.raw_filename,
+            size,
+            hashes: FileHashes {
+                sha256: digest(HashAlgorithm::Sha256),
+                blake2b: digest(HashAlgorithm::Blake2b),
+            },
+            metadata: String::from_utf8_lossy(&metadata).to_string(),
+            mechanism: HTTP_POST_BYTES,
+        };
+        let response = send_json(
+            credentials,
+            client.for_host(&upload_url).post(self.links.upload.clone()),
+            &upload_url,
+            &body,
+        )
+        .await?;
+        let file_upload: FileUploadResponse = read_json(&upload_url, response).await?;
+
+        // Send the file contents.
+        let file_url = match file_upload.mechanism {
+            FileUploadMechanism {
+                identifier,
+                file_url: Some(file_url),
+            } if identifier == HTTP_POST_BYTES => DisplaySafeUrl::from_url(file_url),
+            FileUploadMechanism { identifier, .. } => {
+                return Err(UploadSessionError::UnsupportedMechanism(identifier));
+            }
+        };
+        debug!("Uploading {} to {file_url}", group.raw_filename);
+        let file = File::open(&group.file)
+            .await
+            .map_err(PublishPrepareError::from)?;
+        let idx = reporter.on_upload_start(&group.filename.to_string(), Some(size));
+        let progress = reporter.clone();
+        let reader = ProgressReader::new(file, move |read| {
+            progress.on_upload_progress(idx, read as u64);
+        });
+        let request = authenticate(
+            client.for_host(&file_url).post(Url::from(file_url.clone())),
+            credentials,
+        )
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(Body::wrap_stream(ReaderStream::new(reader)));
+        let response = request
+            .send()
+            .await
+            .map_err(|err| UploadSessionError::ReqwestMiddleware(file_url.clone(), err))?;
+        reporter.on_upload_complete(idx);
+        check_status(&file_url, response).await?;
+
+        // Mark the file upload as complete.
+        let file_upload_session =
+            DisplaySafeUrl::from_url(file_upload.links.file_upload_session.clone());
+        let body = ActionRequest {
+            meta: Meta::default(),
+            action: "complete",
+        };
+        let response = send_json(
+            credentials,
+            client
+                .for_host(&file_upload_session)
+                .post(file_upload.links.file_upload_session),
+            &file_upload_session,
+            &body,
+        )
+        .await?;
+        check_status(&file_upload_session, response).await?;
+
+        Ok(())
+    }
+
+    /// Publish the staged files, making the release visible on the index.
+    pub async fn publish(
+        self,
+        client: &BaseClient,
+        credentials: &Credentials,
+    ) -> Result<(), UploadSessionError> {
+        debug!("Publishing upload session for {}", self.release);
+        let session_url = DisplaySafeUrl::from_url(self.links.session.clone());
+        let body = ActionRequest {
+            meta: Meta::default(),
+            action: "publish",
+        };
+        let response = send_json(
+            credentials,
+            client.for_host(&session_url).post(self.links.session),
+            &session_url,
+            &body,
+        )
+        .await?;
+        let session: SessionResponse = read_json(&session_url, response).await?;
+        // Publishing may be processed asynchronously by the index.
+        match session.status.as_str() {
+            "published" | "processing" => Ok(()),
+            _ => Err(UploadSessionError::NotPublished(
+                self.release,
+                session.status,
+            )),
+        }
+    }
+
+    /// Cancel the upload session, discarding all staged files.
+    pub async fn cancel(
+        self,
+        client: &BaseClient,
+        credentials: &Credentials,
+    ) -> Result<(), UploadSessionError> {
+        debug!("Cancelling upload session for {}", self.release);
+        let session_url = DisplaySafeUrl::from_url(self.links.session.clone());
+        let response = authenticate(
+            client.for_host(&session_url).delete(self.links.session),
+            credentials,
+        )
+        .header(ACCEPT, UPLOAD_V2_MEDIA_TYPE)
+        .send()
+        .await
+        .map_err(|err| UploadSessionError::ReqwestMiddleware(session_url.clone(), err))?;
+        check_status(&session_url, response).await
+    }
+}
+
+/// Attach the publish credentials to a request.
+fn authenticate<'a>(request: RequestBuilder<'a>, credentials: &Credentials) -> RequestBuilder<'a> {
+    match credentials {
+        Credentials::Basic { password, .. } if password.is_some() => {
+            request.header(AUTHORIZATION, credentials.to_header_value())
+        }
+        Credentials::Basic { .. } => request,
+        Credentials::Bearer { .. } => request.header(AUTHORIZATION, credentials.to_header_value()),
+    }
+}
+
+/// Send an Upload 2.0 API request with a JSON body.
+async fn send_json(
+    credentials: &Credentials,
+    request: RequestBuilder<'_>,
+    url: &DisplaySafeUrl,
+    body: &impl Serialize,
+) -> Result<Response, UploadSessionError> {
+    let body = serde_json::to_vec(body).expect("Upload 2.0 API requests are serializable");
+    authenticate(request, credentials)
+        .header(CONTENT_TYPE, UPLOAD_V2_MEDIA_TYPE)
+        .header(ACCEPT, UPLOAD_V2_MEDIA_TYPE)
+        .body(body)
+        .send()
+        .await
+        .map_err(|err| UploadSessionError::ReqwestMiddleware(url.clone(), err))
+}
+
+/// Map an unsuccessful response to an error.
+async fn check_status(url: &DisplaySafeUrl, response: Response) -> Result<(), UploadSessionError> {
+    let status = response.status();
+    debug!("Response code for {url}: {status}");
+    if status.is_success() {
+        return Ok(());
+    }
+    let body = response
+        .text()
+        .await
+        .map_err(|err| UploadSessionError::Reqwest(url.clone(), err))?;
+    Err(UploadSessionError::Status(url.clone(), status, body))
+}
+
+/// Deserialize a successful Upload 2.0 API response.
+async fn read_json<T: for<'de> Deserialize<'de>>(
+    url: &DisplaySafeUrl,
+    response: Response,
+) -> Result<T, UploadSessionError> {
+    let status = response.status();
+    debug!("Response code for {url}: {status}");
+    let body = response
+        .bytes()
+        .await
+        .map_err(|err| UploadSessionError::Reqwest(url.clone(), err))?;
+    if !status.is_success() {
+        return Err(UploadSessionError::Status(
+            url.clone(),
+            status,
+            String::from_utf8_lossy(&body).to_string(),
+        ));
+    }
+    trace!(
+        "Upload session response from {url}: {}",
+        String::from_utf8_lossy(&body)
+    );
+    serde_json::from_slice(&body)
+        .map_err(|err| UploadSessionError::InvalidResponse(url.clone(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionRequest, CreateSessionRequest, FileUploadResponse, Meta};
+
+    #[test]
+    fn serialize_create_session() {
+        let body = CreateSessionRequest {
+            meta: Meta::default(),
+            name: "foo",
+            version: "1.0.0",
+        };
+        insta::assert_snapshot!(
+            serde_json::to_string(&body).unwrap(),
+            @r#"{"meta":{"api-version":"2.0"},"name":"foo","version":"1.0.0"}"#
+        );
+
+        let body = ActionRequest {
+            meta: Meta::default(),
+            action: "publish",
+        };
+        insta::assert_snapshot!(
+            serde_json::to_string(&body).unwrap(),
+            @r#"{"meta":{"api-version":"2.0"},"action":"publish"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_file_upload() {
+        let response = r#"{
+            "meta": {"api-version": "2.0"},
+            "links": {"file-upload-session": "https://upload.example.org/session/abc/file/1"},
+            "status": "pending",
+            "mechanism": {
+                "identifier": "http-post-bytes",
+                "file-url": "https://upload.example.org/session/abc/file/1/content"
+            }
+        }"#;
+        let response: FileUploadResponse = serde_json::from_str(response).unwrap();
+        assert_eq!(response.mechanism.identifier, "http-post-bytes");
+        assert_eq!(
+            response.mechanism.file_url.unwrap().as_str(),
+            "https://upload.example.org/session/abc/file/1/content"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use uv_normalize::GroupName;
+use uv_normalize::{GroupName, PackageName};
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DependencyGroups(BTreeMap<GroupName, Vec<DependencyGroupSpecifier>>);
@@ -90,6 +90,11 @@ pub enum DependencyGroupSpecifier {
     IncludeGroup {
         /// The name of the group to include.
         include_group: GroupName,
+        /// Packages to omit from the included group (a uv extension).
+        exclude: Vec<PackageName>,
+        /// Requirements that replace those for the same package in the included group (a uv
+        /// extension).
+        overrides: Vec<String>,
     },
     /// A Dependency Object Specifier.
     Object(BTreeMap<String, String>),
@@ -120,23 +125,38 @@ impl<'de> Deserialize<'de> for DependencyGroupSpecifier {
             where
                 M: serde::de::MapAccess<'de>,
             {
+                let mut include_group = None;
+                let mut exclude = None;
+                let mut overrides = None;
                 let mut map_data = BTreeMap::new();
-                while let Some((key, value)) = map.next_entry()? {
-                    map_data.insert(key, value);
-                }
-
-                if map_data.is_empty() {
-                    return Err(serde::de::Error::custom("missing field `include-group`"));
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "include-group" => {
+                            let value = map.next_value::<String>()?;
+                            include_group = Some(
+                                GroupName::from_str(&value).map_err(serde::de::Error::custom)?,
+                            );
+                        }
+                        "exclude" => exclude = Some(map.next_value::<Vec<PackageName>>()?),
+                        "overrides" => overrides = Some(map.next_value::<Vec<String>>()?),
+                        _ => {
+                            map_data.insert(key, map.next_value::<String>()?);
+                        }
+                    }
                 }
 
-                if let Some(include_group) = map_data
-                    .get("include-group")
-                    .map(String::as_str)
-                    .map(GroupName::from_str)
-                    .transpose()
-                    .map_err(serde::de::Error::custom)?
-                {
-                    Ok(DependencyGroupSpecifier::IncludeGroup { include_group })
+                if let Some(include_group) = include_group {
+                    Ok(DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        exclude: exclude.unwrap_or_default(),
+                        overrides: overrides.unwrap_or_default(),
+                    })
+                } else if exclude.is_some() || overrides.is_some() {
+                    Err(serde::de::Error::custom(
+                        "`exclude` and `overrides` can only be used alongside `include-group`",
+                    ))
+                } else if map_data.is_empty() {
+                    Err(serde::de::Error::custom("missing field `include-group`"))
                 } else {
                     Ok(DependencyGroupSpecifier::Object(map_data))
                 }
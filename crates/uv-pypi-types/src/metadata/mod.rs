@@ -5,6 +5,7 @@ mod metadata_resolver;
 mod pyproject_toml;
 mod requires_dist;
 mod requires_txt;
+mod setuptools;
 
 use std::str::Utf8Error;
 
@@ -62,6 +63,10 @@ pub enum MetadataError {
     PoetrySyntax,
     #[error("Failed to read `requires.txt` contents")]
     RequiresTxtContents(#[from] std::io::Error),
+    #[error("Invalid `setup.cfg`: {0}")]
+    InvalidSetupCfg(String),
+    #[error("`setup.py` can't be statically analyzed: {0}")]
+    UnsupportedSetupPy(&'static str),
     #[error("The description is not valid utf-8")]
     DescriptionEncoding(#[source] Utf8Error),
 }
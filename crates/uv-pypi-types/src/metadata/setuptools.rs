@@ -0,0 +1,851 @@
+//! Static analysis of setuptools configuration (`setup.cfg` and `setup.py`).
+//!
+//! The analysis is deliberately strict: if the configuration contains anything that could affect
+//! the metadata at build time (e.g., `attr:` directives, non-literal arguments, or arbitrary code
+//! in `setup.py`), we refuse to extract it, and the caller is expected to fall back to a build.
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::{MarkerTree, Requirement};
+
+use crate::lenient_requirement::LenientRequirement;
+use crate::{LenientVersionSpecifiers, MetadataError, ResolutionMetadata, VerbatimParsedUrl};
+
+/// The modules that a `setup.py` may import without affecting the metadata.
+const ALLOWED_IMPORTS: &[&str] = &[
+    "__future__",
+    "codecs",
+    "distutils",
+    "io",
+    "os",
+    "pathlib",
+    "setuptools",
+    "sys",
+];
+
+/// The metadata fields declared in a setuptools configuration.
+#[derive(Debug, Default)]
+struct SetuptoolsMetadata {
+    name: Option<String>,
+    version: Option<SetuptoolsVersion>,
+    python_requires: Option<String>,
+    install_requires: Option<Vec<String>>,
+    extras_require: Option<Vec<(String, Vec<String>)>>,
+}
+
+#[derive(Debug)]
+enum SetuptoolsVersion {
+    /// The version is declared as a literal.
+    Static(String),
+    /// The version is computed at build time (e.g., `attr:` or `use_scm_version`).
+    Dynamic,
+}
+
+impl SetuptoolsMetadata {
+    /// Combine the metadata, preferring the fields in `self` (i.e., `setup.py` arguments take
+    /// precedence over `setup.cfg` options, as in setuptools).
+    fn combine(self, other: Self) -> Self {
+        Self {
+            name: self.name.or(other.name),
+            version: self.version.or(other.version),
+            python_requires: self.python_requires.or(other.python_requires),
+            install_requires: self.install_requires.or(other.install_requires),
+            extras_require: self.extras_require.or(other.extras_require),
+        }
+    }
+}
+
+impl ResolutionMetadata {
+    /// Extract the metadata from the setuptools configuration of a source tree, i.e., its
+    /// `setup.cfg` and `setup.py` files, without invoking the build backend.
+    ///
+    /// If we're coming from a source distribution, we may already know the version (unlike for a
+    /// source tree), so we can tolerate dynamic versions.
+    pub fn parse_setuptools(
+        setup_cfg: Option<&str>,
+        setup_py: Option<&str>,
+        sdist_version: Option<&Version>,
+    ) -> Result<Self, MetadataError> {
+        let setup_cfg = setup_cfg.map(parse_setup_cfg).transpose()?;
+        let setup_py = setup_py.map(parse_setup_py).transpose()?;
+        let metadata = match (setup_py, setup_cfg) {
+            (Some(setup_py), Some(setup_cfg)) => setup_py.combine(setup_cfg),
+            (Some(metadata), None) | (None, Some(metadata)) => metadata,
+            (None, None) => return Err(MetadataError::FieldNotFound("name")),
+        };
+
+        let name = PackageName::from_str(
+            metadata
+                .name
+                .as_deref()
+                .ok_or(MetadataError::FieldNotFound("name"))?,
+        )?;
+
+        // When building from a source distribution, the version is known from the filename and
+        // fixed by it, so we can pretend it's static.
+        let (version, dynamic) = match metadata.version {
+            Some(SetuptoolsVersion::Static(version)) => (
+                Version::from_str(&version).map_err(MetadataError::Pep440VersionError)?,
+                false,
+            ),
+            Some(SetuptoolsVersion::Dynamic) | None => (
+                sdist_version
+                    .cloned()
+                    .ok_or(MetadataError::DynamicField("version"))?,
+                true,
+            ),
+        };
+
+        // Parse the Python version requirements.
+        let requires_python = metadata
+            .python_requires
+            .map(|requires_python| {
+                LenientVersionSpecifiers::from_str(&requires_python).map(VersionSpecifiers::from)
+            })
+            .transpose()?;
+
+        // Extract the requirements.
+        let mut requires_dist = metadata
+            .install_requires
+            .unwrap_or_default()
+            .iter()
+            .map(|requirement| parse_requirement(requirement))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Extract the optional dependencies, each of which may include a marker, as in
+        // `test:sys_platform == "win32"`.
+        let mut provides_extra = Vec::new();
+        for (key, requirements) in metadata.extras_require.unwrap_or_default() {
+            let (extra, marker) = match key.split_once(':') {
+                Some((extra, marker)) => (extra.trim(), Some(marker.trim())),
+                None => (key.trim(), None),
+            };
+            let extra = if extra.is_empty() {
+                None
+            } else {
+                Some(ExtraName::from_str(extra)?)
+            };
+            let marker = marker
+                .filter(|marker| !marker.is_empty())
+                .map(MarkerTree::parse_str)
+                .transpose()?;
+
+            for requirement in &requirements {
+                let mut requirement = parse_requirement(requirement)?;
+                if let Some(marker) = marker {
+                    requirement.marker.and(marker);
+                }
+                if let Some(extra) = &extra {
+                    requirement = requirement.with_extra_marker(extra);
+                }
+                requires_dist.push(requirement);
+            }
+
+            if let Some(extra) = extra {
+                provides_extra.push(extra);
+            }
+        }
+
+        Ok(Self {
+            name,
+            version,
+            requires_dist: requires_dist.into_boxed_slice(),
+            requires_python,
+            provides_extra: provides_extra.into_iter().unique().collect(),
+            dynamic,
+        })
+    }
+}
+
+/// Parse a requirement, as declared in `install_requires` or `extras_require`.
+fn parse_requirement(requirement: &str) -> Result<Requirement<VerbatimParsedUrl>, MetadataError> {
+    Ok(Requirement::from(LenientRequirement::from_str(
+        requirement,
+    )?))
+}
+
+/// Parse a list of requirements from a `setup.cfg` value, one per line.
+fn parse_requirements_list(field: &'static str, value: &str) -> Result<Vec<String>, MetadataError> {
+    if value.trim_start().starts_with("file:") {
+        return Err(MetadataError::DynamicField(field));
+    }
+    Ok(value
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Parse the metadata from a `setup.cfg` file.
+///
+/// See: <https://setuptools.pypa.io/en/latest/userguide/declarative_config.html>
+fn parse_setup_cfg(content: &str) -> Result<SetuptoolsMetadata, MetadataError> {
+    let mut metadata = SetuptoolsMetadata::default();
+    let mut extras_require = None;
+
+    for (section, key, value) in parse_ini(content)? {
+        match (section.as_str(), key.as_str()) {
+            ("metadata", "name") => metadata.name = Some(value),
+            ("metadata", "version") => {
+                metadata.version = Some(
+                    if value.starts_with("attr:") || value.starts_with("file:") {
+                        SetuptoolsVersion::Dynamic
+                    } else {
+                        SetuptoolsVersion::Static(value)
+                    },
+                );
+            }
+            ("options", "python_requires") => metadata.python_requires = Some(value),
+            ("options", "install_requires") => {
+                metadata.install_requires =
+                    Some(parse_requirements_list("install_requires", &value)?);
+            }
+            ("options.extras_require", extra) => {
+                extras_require.get_or_insert_with(Vec::new).push((
+                    extra.to_string(),
+                    parse_requirements_list("extras_require", &value)?,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    metadata.extras_require = extras_require;
+    Ok(metadata)
+}
+
+/// Parse an INI file into a list of `(section, key, value)` entries, following the conventions of
+/// Python's `configparser` (as used by setuptools): keys are case-insensitive, values may span
+/// multiple indented lines, and lines starting with `#` or `;` are comments.
+///
+/// Keys are normalized to lowercase, with dashes replaced by underscores.
+fn parse_ini(content: &str) -> Result<Vec<(String, String, String)>, MetadataError> {
+    let mut entries: Vec<(String, String, String)> = Vec::new();
+    let mut section = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+
+        // Indented lines continue the value of the previous key.
+        if line.starts_with(char::is_whitespace) {
+            if let Some((.., value)) = entries.last_mut() {
+                value.push('\n');
+                value.push_str(trimmed);
+                continue;
+            }
+        }
+
+        if let Some(name) = trimmed
+            .strip_prefix('[')
+            .and_then(|name| name.strip_suffix(']'))
+        {
+            section = Some(name.trim().to_string());
+            continue;
+        }
+
+        let Some(section) = section.as_ref() else {
+            return Err(MetadataError::InvalidSetupCfg(format!(
+                "expected a section header at line {}",
+                index + 1
+            )));
+        };
+        let Some(delimiter) = trimmed.find(['=', ':']) else {
+            return Err(MetadataError::InvalidSetupCfg(format!(
+                "expected a key-value pair at line {}",
+                index + 1
+            )));
+        };
+        let key = trimmed[..delimiter]
+            .trim()
+            .to_ascii_lowercase()
+            .replace('-', "_");
+        let value = trimmed[delimiter + 1..].trim().to_string();
+        entries.push((section.clone(), key, value));
+    }
+
+    Ok(entries)
+}
+
+/// A token in a `setup.py` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A string literal.
+    String(String),
+    /// A string that isn't a plain literal, like an f-string or a bytes literal.
+    FormattedString,
+    /// An identifier or keyword.
+    Name(String),
+    /// A single-character operator or delimiter.
+    Op(char),
+    /// Any other token, like a number.
+    Other,
+    /// The end of a logical line.
+    Newline,
+}
+
+/// Parse the metadata from a `setup.py` file.
+///
+/// Only the simplest `setup.py` files are supported: a sequence of imports from a fixed set of
+/// modules, followed by a single `setup(...)` call with keyword arguments. The relevant arguments
+/// (`name`, `version`, `python_requires`, `install_requires`, and `extras_require`) must be
+/// literals.
+fn parse_setup_py(content: &str) -> Result<SetuptoolsMetadata, MetadataError> {
+    let tokens = tokenize(content)?;
+
+    let mut metadata = None;
+    for statement in tokens.split(|token| *token == Token::Newline) {
+        match statement {
+            [] => {}
+            // A docstring.
+            statement
+                if statement
+                    .iter()
+                    .all(|token| matches!(token, Token::String(_))) => {}
+            // An import, e.g., `import os` or `from setuptools import setup, find_packages`.
+            [Token::Name(keyword), rest @ ..] if keyword == "import" || keyword == "from" => {
+                let modules = if keyword == "from" {
+                    rest.first().into_iter().collect::<Vec<_>>()
+                } else {
+                    rest.split(|token| *token == Token::Op(','))
+                        .filter_map(|module| module.first())
+                        .collect::<Vec<_>>()
+                };
+                for module in modules {
+                    let Token::Name(module) = module else {
+                        return Err(MetadataError::UnsupportedSetupPy("unsupported import"));
+                    };
+                    if !ALLOWED_IMPORTS.contains(&module.as_str()) {
+                        return Err(MetadataError::UnsupportedSetupPy("unsupported import"));
+                    }
+                }
+            }
+            // The `setup(...)` call.
+            [
+                Token::Name(setup),
+                Token::Op('('),
+                args @ ..,
+                Token::Op(')'),
+            ]
+            | [
+                Token::Name(_),
+                Token::Op('.'),
+                Token::Name(setup),
+                Token::Op('('),
+                args @ ..,
+                Token::Op(')'),
+            ] if setup == "setup" && is_balanced(args) => {
+                if metadata.is_some() {
+                    return Err(MetadataError::UnsupportedSetupPy(
+                        "multiple `setup()` calls",
+                    ));
+                }
+                metadata = Some(parse_setup_args(args)?);
+            }
+            _ => {
+                return Err(MetadataError::UnsupportedSetupPy("unsupported statement"));
+            }
+        }
+    }
+
+    metadata.ok_or(MetadataError::UnsupportedSetupPy("missing `setup()` call"))
+}
+
+/// Parse the arguments to a `setup(...)` call.
+fn parse_setup_args(args: &[Token]) -> Result<SetuptoolsMetadata, MetadataError> {
+    let mut metadata = SetuptoolsMetadata::default();
+    let mut dynamic_version = false;
+
+    for arg in split_top_level(args) {
+        let [Token::Name(key), Token::Op('='), value @ ..] = arg else {
+            if arg.is_empty() {
+                continue;
+            }
+            return Err(MetadataError::UnsupportedSetupPy(
+                "`setup()` accepts only keyword arguments",
+            ));
+        };
+        match key.as_str() {
+            "name" => {
+                metadata.name = Some(
+                    parse_string(value)
+                        .ok_or(MetadataError::UnsupportedSetupPy("`name` is not a literal"))?,
+                );
+            }
+            "version" => {
+                metadata.version = Some(
+                    parse_string(value)
+                        .map(SetuptoolsVersion::Static)
+                        .unwrap_or(SetuptoolsVersion::Dynamic),
+                );
+            }
+            "python_requires" => {
+                metadata.python_requires = Some(parse_string(value).ok_or(
+                    MetadataError::UnsupportedSetupPy("`python_requires` is not a literal"),
+                )?);
+            }
+            "install_requires" => {
+                metadata.install_requires = Some(parse_string_list(value).ok_or(
+                    MetadataError::UnsupportedSetupPy("`install_requires` is not a literal"),
+                )?);
+            }
+            "extras_require" => {
+                metadata.extras_require = Some(parse_string_dict(value).ok_or(
+                    MetadataError::UnsupportedSetupPy("`extras_require` is not a literal"),
+                )?);
+            }
+            // The version is derived from version control.
+            "use_scm_version" => dynamic_version = true,
+            // pbr reads the metadata from other files entirely.
+            "pbr" => return Err(MetadataError::UnsupportedSetupPy("pbr is not supported")),
+            _ => {}
+        }
+    }
+
+    if dynamic_version {
+        metadata.version = Some(SetuptoolsVersion::Dynamic);
+    }
+
+    Ok(metadata)
+}
+
+/// Returns `true` if the brackets in a sequence of tokens are balanced.
+fn is_balanced(tokens: &[Token]) -> bool {
+    let mut depth = 0usize;
+    for token in tokens {
+        match token {
+            Token::Op('(' | '[' | '{') => depth += 1,
+            Token::Op(')' | ']' | '}') => {
+                let Some(next) = depth.checked_sub(1) else {
+                    return false;
+                };
+                depth = next;
+            }
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+/// Split a sequence of tokens on the commas at the top level (i.e., outside any brackets).
+fn split_top_level(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Op('(' | '[' | '{') => depth += 1,
+            Token::Op(')' | ']' | '}') => depth = depth.saturating_sub(1),
+            Token::Op(',') if depth == 0 => {
+                parts.push(&tokens[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
+
+/// Parse a string literal, concatenating adjacent literals (e.g., `"foo" "bar"`).
+fn parse_string(tokens: &[Token]) -> Option<String> {
+    if tokens.is_empty() {
+        return None;
+    }
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::String(value) => Some(value.as_str()),
+            _ => None,
+        })
+        .collect::<Option<String>>()
+}
+
+/// Parse a list or tuple of string literals, or a single string with one entry per line.
+fn parse_string_list(tokens: &[Token]) -> Option<Vec<String>> {
+    if let Some(value) = parse_string(tokens) {
+        return Some(
+            value
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(ToString::to_string)
+                .collect(),
+        );
+    }
+    let inner = match tokens {
+        [Token::Op('['), inner @ .., Token::Op(']')] => inner,
+        [Token::Op('('), inner @ .., Token::Op(')')] => inner,
+        _ => return None,
+    };
+    split_top_level(inner)
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .map(parse_string)
+        .collect()
+}
+
+/// Parse a dictionary literal mapping strings to lists of strings.
+fn parse_string_dict(tokens: &[Token]) -> Option<Vec<(String, Vec<String>)>> {
+    let [Token::Op('{'), inner @ .., Token::Op('}')] = tokens else {
+        return None;
+    };
+    split_top_level(inner)
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let colon = item.iter().position(|token| *token == Token::Op(':'))?;
+            let key = parse_string(&item[..colon])?;
+            let value = parse_string_list(&item[colon + 1..])?;
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Tokenize a `setup.py` file.
+///
+/// Returns an error for any indented statement, since blocks (e.g., `if` or `def`) could affect
+/// the metadata.
+fn tokenize(content: &str) -> Result<Vec<Token>, MetadataError> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    let mut depth = 0usize;
+    let mut line_start = true;
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\n' => {
+                chars.next();
+                if depth == 0 {
+                    if tokens.last().is_some_and(|token| *token != Token::Newline) {
+                        tokens.push(Token::Newline);
+                    }
+                    line_start = true;
+                }
+            }
+            '#' => while chars.next_if(|c| *c != '\n').is_some() {},
+            '\\' => {
+                chars.next();
+                if chars.next_if_eq(&'\r').is_some() || chars.peek() == Some(&'\n') {
+                    chars.next();
+                } else {
+                    return Err(MetadataError::UnsupportedSetupPy("unexpected backslash"));
+                }
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+                // An indented statement, which must be part of a block.
+                if line_start
+                    && depth == 0
+                    && chars
+                        .peek()
+                        .is_some_and(|c| !c.is_whitespace() && *c != '#')
+                {
+                    return Err(MetadataError::UnsupportedSetupPy("unsupported block"));
+                }
+            }
+            '\'' | '"' => {
+                line_start = false;
+                tokens.push(Token::String(read_string(&mut chars, false)?));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                line_start = false;
+                let mut name = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    name.push(c);
+                }
+
+                // A string prefix, like `r"..."` or `f"..."`.
+                if matches!(chars.peek(), Some('\'' | '"'))
+                    && name.len() <= 2
+                    && name
+                        .chars()
+                        .all(|c| matches!(c.to_ascii_lowercase(), 'r' | 'u' | 'b' | 'f'))
+                {
+                    let lowercase = name.to_ascii_lowercase();
+                    let value = read_string(&mut chars, lowercase.contains('r'))?;
+                    if lowercase.contains('f') || lowercase.contains('b') {
+                        tokens.push(Token::FormattedString);
+                    } else {
+                        tokens.push(Token::String(value));
+                    }
+                } else {
+                    tokens.push(Token::Name(name));
+                }
+            }
+            c if c.is_ascii_digit() => {
+                line_start = false;
+                while chars
+                    .next_if(|c| c.is_ascii_alphanumeric() || *c == '.' || *c == '_')
+                    .is_some()
+                {}
+                tokens.push(Token::Other);
+            }
+            c => {
+                line_start = false;
+                chars.next();
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                if matches!(
+                    c,
+                    '(' | ')' | '[' | ']' | '{' | '}' | ',' | ':' | '=' | '.' | '*'
+                ) {
+                    tokens.push(Token::Op(c));
+                } else {
+                    tokens.push(Token::Other);
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Read a (possibly triple-quoted) string literal, starting at the opening quote.
+fn read_string(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    raw: bool,
+) -> Result<String, MetadataError> {
+    let quote = chars.next().expect("expected a quote");
+    let triple = if chars.next_if_eq(&quote).is_some() {
+        if chars.next_if_eq(&quote).is_some() {
+            true
+        } else {
+            // An empty string.
+            return Ok(String::new());
+        }
+    } else {
+        false
+    };
+
+    let mut value = String::new();
+    loop {
+        let Some(c) = chars.next() else {
+            return Err(MetadataError::UnsupportedSetupPy("unterminated string"));
+        };
+        match c {
+            '\\' => {
+                let Some(escaped) = chars.next() else {
+                    return Err(MetadataError::UnsupportedSetupPy("unterminated string"));
+                };
+                if raw {
+                    value.push('\\');
+                    value.push(escaped);
+                } else {
+                    match escaped {
+                        '\n' => {}
+                        'n' => value.push('\n'),
+                        't' => value.push('\t'),
+                        '\\' | '\'' | '"' => value.push(escaped),
+                        _ => {
+                            value.push('\\');
+                            value.push(escaped);
+                        }
+                    }
+                }
+            }
+            c if c == quote => {
+                if !triple {
+                    return Ok(value);
+                }
+                if chars.next_if_eq(&quote).is_some() {
+                    if chars.next_if_eq(&quote).is_some() {
+                        return Ok(value);
+                    }
+                    value.push(quote);
+                }
+                value.push(quote);
+            }
+            '\n' if !triple => {
+                return Err(MetadataError::UnsupportedSetupPy("unterminated string"));
+            }
+            c => value.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use indoc::indoc;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use crate::{MetadataError, ResolutionMetadata};
+
+    #[test]
+    fn setup_cfg() {
+        let setup_cfg = indoc! {r#"
+            [metadata]
+            name = foo
+            version = 1.2.3
+
+            [options]
+            python_requires = >=3.8
+            install_requires =
+                requests>=2
+                # A comment.
+                tomli; python_version < "3.11"
+
+            [options.extras_require]
+            dev =
+                pytest
+        "#};
+        let meta = ResolutionMetadata::parse_setuptools(Some(setup_cfg), None, None).unwrap();
+        assert_eq!(meta.name, PackageName::from_str("foo").unwrap());
+        assert_eq!(meta.version, Version::new([1, 2, 3]));
+        assert_eq!(meta.requires_python.unwrap().to_string(), ">=3.8");
+        assert_eq!(
+            meta.requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "requests>=2",
+                "tomli ; python_full_version < '3.11'",
+                "pytest ; extra == 'dev'",
+            ]
+        );
+        assert_eq!(meta.provides_extra.len(), 1);
+        assert!(!meta.dynamic);
+    }
+
+    #[test]
+    fn setup_cfg_dynamic() {
+        let setup_cfg = indoc! {r"
+            [metadata]
+            name = foo
+            version = attr: foo.__version__
+
+            [options]
+            install_requires = file: requirements.txt
+        "};
+        let err = ResolutionMetadata::parse_setuptools(
+            Some(setup_cfg),
+            None,
+            Some(&Version::new([1, 0])),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            MetadataError::DynamicField("install_requires")
+        ));
+
+        let setup_cfg = indoc! {r"
+            [metadata]
+            name = foo
+            version = attr: foo.__version__
+        "};
+        let err = ResolutionMetadata::parse_setuptools(Some(setup_cfg), None, None).unwrap_err();
+        assert!(matches!(err, MetadataError::DynamicField("version")));
+
+        let meta = ResolutionMetadata::parse_setuptools(
+            Some(setup_cfg),
+            Some("from setuptools import setup\nsetup()\n"),
+            Some(&Version::new([1, 0])),
+        )
+        .unwrap();
+        assert_eq!(meta.version, Version::new([1, 0]));
+        assert!(meta.dynamic);
+    }
+
+    #[test]
+    fn setup_py() {
+        let setup_py = indoc! {r#"
+            #!/usr/bin/env python
+            """The setup script."""
+            import os
+            from setuptools import find_packages, setup
+
+            setup(
+                name="foo",
+                version="0.1.0",
+                packages=find_packages(exclude=["tests"]),
+                long_description=open(os.path.join("README.md")).read(),
+                python_requires=">=3.7",
+                install_requires=[
+                    "click>=7",  # A comment.
+                    'colorama; sys_platform == "win32"',
+                ],
+                extras_require={
+                    "test": ["pytest"],
+                    "docs:python_version >= '3.8'": ("sphinx",),
+                },
+            )
+        "#};
+        let meta = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap();
+        assert_eq!(meta.name, PackageName::from_str("foo").unwrap());
+        assert_eq!(meta.version, Version::new([0, 1, 0]));
+        assert_eq!(
+            meta.requires_dist
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "click>=7",
+                "colorama ; sys_platform == 'win32'",
+                "pytest ; extra == 'test'",
+                "sphinx ; python_full_version >= '3.8' and extra == 'docs'",
+            ]
+        );
+        assert_eq!(meta.provides_extra.len(), 2);
+    }
+
+    #[test]
+    fn setup_py_unsupported() {
+        // Non-literal requirements.
+        let setup_py = indoc! {r#"
+            from setuptools import setup
+            setup(name="foo", version="1.0", install_requires=REQUIREMENTS)
+        "#};
+        let err = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap_err();
+        assert!(matches!(err, MetadataError::UnsupportedSetupPy(_)));
+
+        // Arbitrary statements.
+        let setup_py = indoc! {r#"
+            from setuptools import setup
+            requirements = open("requirements.txt").read().splitlines()
+            setup(name="foo", version="1.0", install_requires=requirements)
+        "#};
+        let err = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap_err();
+        assert!(matches!(err, MetadataError::UnsupportedSetupPy(_)));
+
+        // Blocks.
+        let setup_py = indoc! {r#"
+            from setuptools import setup
+            if True:
+                setup(name="foo", version="1.0")
+        "#};
+        let err = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap_err();
+        assert!(matches!(err, MetadataError::UnsupportedSetupPy(_)));
+
+        // Unknown imports.
+        let setup_py = indoc! {r#"
+            from setuptools import setup
+            import versioneer
+            setup(name="foo", version="1.0")
+        "#};
+        let err = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap_err();
+        assert!(matches!(err, MetadataError::UnsupportedSetupPy(_)));
+
+        // Keyword argument unpacking.
+        let setup_py = indoc! {r#"
+            from setuptools import setup
+            setup(name="foo", version="1.0", **{"install_requires": ["bar"]})
+        "#};
+        let err = ResolutionMetadata::parse_setuptools(None, Some(setup_py), None).unwrap_err();
+        assert!(matches!(err, MetadataError::UnsupportedSetupPy(_)));
+    }
+}
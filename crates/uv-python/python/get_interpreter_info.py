@@ -237,6 +237,50 @@ def get_virtualenv():
         }
 
 
+def get_dependencies():
+    """Return the files, beyond the executable, that the interpreter information is derived from.
+
+    uv fingerprints these files to invalidate its cached interpreter information when, e.g., a
+    patch upgrade replaces the standard library or the shared library in-place, or a distributor
+    modifies `sysconfig`.
+    """
+    candidates = [
+        # The landmark of the standard library.
+        getattr(os, "__file__", None),
+        getattr(sysconfig, "__file__", None),
+    ]
+
+    # The shared library, if the interpreter is dynamically linked against `libpython`.
+    if sysconfig.get_config_var("Py_ENABLE_SHARED"):
+        libdir = sysconfig.get_config_var("LIBDIR")
+        ldlibrary = sysconfig.get_config_var("LDLIBRARY")
+        if libdir and ldlibrary:
+            candidates.append(os.path.join(libdir, ldlibrary))
+    elif sys.platform == "win32":
+        candidates.append(
+            os.path.join(
+                sys.base_exec_prefix,
+                "python{}{}.dll".format(sys.version_info[0], sys.version_info[1]),
+            )
+        )
+
+    # The module holding the build-time configuration variables on POSIX (e.g.,
+    # `_sysconfigdata__linux_x86_64-linux-gnu`), as loaded by `sysconfig.get_config_var` above.
+    try:
+        sysconfigdata = sys.modules.get(sysconfig._get_sysconfigdata_name())
+    except Exception:
+        sysconfigdata = None
+    candidates.append(getattr(sysconfigdata, "__file__", None))
+
+    dependencies = []
+    for candidate in candidates:
+        if candidate and os.path.isfile(candidate):
+            candidate = os.path.realpath(candidate)
+            if candidate not in dependencies:
+                dependencies.append(candidate)
+    return dependencies
+
+
 def get_scheme(use_sysconfig_scheme: bool):
     """Return the Scheme for the current interpreter.
 
@@ -665,9 +709,14 @@ def main() -> None:
         "gil_disabled": bool(sysconfig.get_config_var("Py_GIL_DISABLED")),
         # https://docs.python.org/3/using/configure.html#debug-build
         "debug_enabled": bool(sysconfig.get_config_var("Py_DEBUG")),
+        # The `m` abiflag for pymalloc builds of Python 3.7 and earlier. The variable is missing on
+        # Windows, where pymalloc is always enabled.
+        # https://peps.python.org/pep-3149/
+        "pymalloc": sysconfig.get_config_var("WITH_PYMALLOC") != 0,
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
+        "dependencies": get_dependencies(),
     }
     print(json.dumps(interpreter_info))
 
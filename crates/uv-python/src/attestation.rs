@@ -0,0 +1,116 @@
+//! Verification of the build provenance of managed Python downloads.
+//!
+//! python-build-standalone publishes a [GitHub artifact attestation] (a Sigstore bundle) for each
+//! release archive. Verification is delegated to the GitHub CLI, which validates the bundle
+//! against the Sigstore trust root and checks the signing certificate against the configured
+//! [`PythonDownloadAttestations`] policy.
+//!
+//! [GitHub artifact attestation]: https://docs.github.com/en/actions/security-for-github-actions/using-artifact-attestations
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+/// The repository that builds the default managed CPython distributions.
+pub const PYTHON_BUILD_STANDALONE_REPOSITORY: &str = "astral-sh/python-build-standalone";
+
+/// A global cache of the result of `which gh`.
+static GH: LazyLock<Option<PathBuf>> = LazyLock::new(|| which::which("gh").ok());
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error(
+        "Verifying the build provenance of managed Python downloads requires the GitHub CLI (`gh`), but it was not found"
+    )]
+    GhNotFound,
+    #[error("Build provenance attestations are not published for {0} distributions")]
+    UnsupportedImplementation(String),
+    #[error("Failed to run the GitHub CLI (`gh`)")]
+    Io(#[source] std::io::Error),
+    #[error("Failed to verify the build provenance of `{}`:\n{stderr}", path.user_display())]
+    Verification { path: PathBuf, stderr: String },
+}
+
+/// A trust policy for the build provenance of managed Python downloads.
+///
+/// If set, the attestation for each downloaded CPython archive is verified before the archive is
+/// extracted, and the installation fails if verification fails. This is in addition to the
+/// SHA-256 check against the download metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PythonDownloadAttestations {
+    /// The GitHub repository (as `owner/name`) that must have produced the archive.
+    ///
+    /// Defaults to `astral-sh/python-build-standalone`.
+    #[serde(default)]
+    pub repository: Option<String>,
+    /// The workflow that must have signed the attestation, as a path within a repository (e.g.,
+    /// `astral-sh/python-build-standalone/.github/workflows/release.yml`).
+    ///
+    /// By default, any workflow in the `repository` is trusted.
+    #[serde(default)]
+    pub signer_workflow: Option<String>,
+    /// Reject attestations that were produced on self-hosted runners.
+    #[serde(default)]
+    pub deny_self_hosted_runners: bool,
+}
+
+impl PythonDownloadAttestations {
+    /// Return the repository that must have produced the archive.
+    pub fn repository(&self) -> &str {
+        self.repository
+            .as_deref()
+            .unwrap_or(PYTHON_BUILD_STANDALONE_REPOSITORY)
+    }
+
+    /// Ensure that the GitHub CLI is available, so that verification can't fail after the archive
+    /// has been downloaded.
+    pub(crate) fn check_available(&self) -> Result<(), AttestationError> {
+        if GH.is_none() {
+            return Err(AttestationError::GhNotFound);
+        }
+        Ok(())
+    }
+
+    /// Verify the build provenance of the archive at the given path.
+    pub(crate) async fn verify(&self, archive: &Path) -> Result<(), AttestationError> {
+        let Some(gh) = GH.as_ref() else {
+            return Err(AttestationError::GhNotFound);
+        };
+
+        debug!(
+            "Verifying the build provenance of `{}` (repository: `{}`)",
+            archive.simplified_display(),
+            self.repository()
+        );
+
+        let mut cmd = tokio::process::Command::new(gh);
+        cmd.arg("attestation")
+            .arg("verify")
+            .arg(archive)
+            .arg("--repo")
+            .arg(self.repository());
+        if let Some(signer_workflow) = &self.signer_workflow {
+            cmd.arg("--signer-workflow").arg(signer_workflow);
+        }
+        if self.deny_self_hosted_runners {
+            cmd.arg("--deny-self-hosted-runners");
+        }
+
+        let output = cmd.output().await.map_err(AttestationError::Io)?;
+        if !output.status.success() {
+            return Err(AttestationError::Verification {
+                path: archive.to_path_buf(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
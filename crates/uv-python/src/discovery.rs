@@ -265,6 +265,10 @@ pub enum Error {
     #[error("Requesting the 'latest' Python version is not yet supported")]
     LatestVersionRequest,
 
+    /// An invalid filter was given in a Python request, e.g., `3.12; arch=unknown`
+    #[error("Invalid filter `{0}` in Python request: {1}")]
+    InvalidRequestFilter(String, String),
+
     // TODO(zanieb): Is this error case necessary still? We should probably drop it.
     #[error("Interpreter discovery for `{0}` requires `{1}` but only `{2}` is allowed")]
     SourceNotAllowed(PythonRequest, PythonSource, PythonPreference),
@@ -1241,6 +1245,27 @@ pub fn find_python_installations<'a>(
             .filter_ok(|(_source, interpreter)| implementation.matches_interpreter(interpreter))
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
         }),
+        PythonRequest::ImplementationVersion(implementation, version)
+            if implementation.is_implementation_version(version) =>
+        {
+            Box::new({
+                debug!("Searching for {request} in {sources}");
+                python_interpreters(
+                    &VersionRequest::Default,
+                    Some(implementation),
+                    PlatformRequest::default(),
+                    environments,
+                    preference,
+                    cache,
+                    preview,
+                )
+                .filter_ok(|(_source, interpreter)| {
+                    implementation.matches_interpreter(interpreter)
+                        && version.matches_implementation_version(interpreter)
+                })
+                .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
+            })
+        }
         PythonRequest::ImplementationVersion(implementation, version) => {
             if let Err(err) = version.check_supported() {
                 return Box::new(iter::once(Err(Error::InvalidVersionRequest(err))));
@@ -1827,6 +1852,64 @@ impl PythonRequest {
         Self::ExecutableName(value.to_string())
     }
 
+    /// Create a request from a string with optional filters, e.g., `cpython>=3.11,<3.13; arch=aarch64`.
+    ///
+    /// The portion before the first `;` is parsed with [`PythonRequest::parse`]. The remainder is a
+    /// comma-separated list of `key=value` filters, where the supported keys are `implementation`,
+    /// `arch`, `os`, and `libc`. If any filters are present, the request is converted to a
+    /// [`PythonRequest::Key`], so it cannot refer to a path or an executable name.
+    pub fn parse_with_filters(value: &str) -> Result<Self, Error> {
+        let Some((request, filters)) = value.split_once(';') else {
+            return Ok(Self::parse(value));
+        };
+
+        let request = request.trim();
+        let request = if request.is_empty() {
+            Self::Default
+        } else {
+            Self::parse(request)
+        };
+        let Some(mut download_request) = PythonDownloadRequest::from_request(&request) else {
+            return Err(Error::InvalidRequestFilter(
+                filters.trim().to_string(),
+                format!("filters cannot be applied to `{request}`"),
+            ));
+        };
+
+        for filter in filters
+            .split([',', ';'])
+            .map(str::trim)
+            .filter(|filter| !filter.is_empty())
+        {
+            let invalid = |reason: String| Error::InvalidRequestFilter(filter.to_string(), reason);
+            let Some((key, value)) = filter.split_once('=') else {
+                return Err(invalid("expected `key=value`".to_string()));
+            };
+            let value = value.trim().to_ascii_lowercase();
+            download_request = match key.trim() {
+                "implementation" => download_request.with_implementation(
+                    ImplementationName::from_str(&value).map_err(|err| invalid(err.to_string()))?,
+                ),
+                "arch" => download_request.with_arch(
+                    uv_platform::Arch::from_str(&value).map_err(|err| invalid(err.to_string()))?,
+                ),
+                "os" => download_request.with_os(
+                    uv_platform::Os::from_str(&value).map_err(|err| invalid(err.to_string()))?,
+                ),
+                "libc" => download_request.with_libc(
+                    uv_platform::Libc::from_str(&value).map_err(|err| invalid(err.to_string()))?,
+                ),
+                key => {
+                    return Err(invalid(format!(
+                        "unknown key `{key}`, expected one of `implementation`, `arch`, `os`, or `libc`"
+                    )));
+                }
+            };
+        }
+
+        Ok(Self::Key(download_request))
+    }
+
     /// Try to parse a tool name as a Python version, e.g. `uvx python311`.
     ///
     /// The `PythonRequest::parse` constructor above is intended for the `--python` flag, where the
@@ -1903,6 +1986,25 @@ impl PythonRequest {
                     version_request,
                 )));
             }
+            // e.g. `graalpy-24`, for implementations that are versioned independently of Python
+            if let Some(version_request) = lowercase_value
+                .strip_prefix(implementation)
+                .and_then(|rest| rest.strip_prefix('-'))
+                .and_then(|rest| match rest.parse::<u8>() {
+                    // Unlike Python versions, `24` is a major version rather than a wheel tag.
+                    Ok(major) => Some(VersionRequest::Major(major, PythonVariant::Default)),
+                    Err(_) => VersionRequest::from_str(rest).ok(),
+                })
+            {
+                // Safety: The name matched the possible names above
+                let implementation = ImplementationName::from_str(implementation).unwrap();
+                if implementation.is_implementation_version(&version_request) {
+                    return Ok(Some(Self::ImplementationVersion(
+                        implementation,
+                        version_request,
+                    )));
+                }
+            }
         }
         Ok(None)
     }
@@ -2065,7 +2167,12 @@ impl PythonRequest {
                 .implementation_name()
                 .eq_ignore_ascii_case(implementation.into()),
             Self::ImplementationVersion(implementation, version) => {
-                version.matches_interpreter(interpreter)
+                let matches_version = if implementation.is_implementation_version(version) {
+                    version.matches_implementation_version(interpreter)
+                } else {
+                    version.matches_interpreter(interpreter)
+                };
+                matches_version
                     && interpreter
                         .implementation_name()
                         .eq_ignore_ascii_case(implementation.into())
@@ -2132,7 +2239,11 @@ impl PythonRequest {
             Self::ExecutableName(name) => name.clone(),
             Self::Implementation(implementation) => implementation.to_string(),
             Self::ImplementationVersion(implementation, version) => {
-                format!("{implementation}@{version}")
+                if implementation.is_implementation_version(version) {
+                    format!("{implementation}-{version}")
+                } else {
+                    format!("{implementation}@{version}")
+                }
             }
             Self::Key(request) => request.to_string(),
         }
@@ -2758,6 +2869,14 @@ impl VersionRequest {
         }
     }
 
+    /// Check if the implementation version of an interpreter (e.g., GraalPy 24.2.1, as opposed to
+    /// the Python version it implements) is compatible with the request.
+    pub(crate) fn matches_implementation_version(&self, interpreter: &Interpreter) -> bool {
+        let version = interpreter.markers().implementation_version();
+        PythonVersion::from_str(&version.to_string())
+            .is_ok_and(|version| self.matches_version(&version))
+    }
+
     /// Check if a version is compatible with the request.
     ///
     /// WARNING: Use [`VersionRequest::matches_interpreter`] too. This method is only suitable to
@@ -2978,16 +3097,16 @@ impl FromStr for VersionRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        /// Extract the variant from the end of a version request string, returning the prefix and
-        /// the variant type.
-        fn parse_variant(s: &str) -> Result<(&str, PythonVariant), Error> {
+        /// Extract the variant from the end of a version request string, returning the prefix, the
+        /// variant type, and whether the pymalloc (`m`) flag was present.
+        fn parse_variant(s: &str) -> Result<(&str, PythonVariant, bool), Error> {
             // This cannot be a valid version, just error immediately
             if s.chars().all(char::is_alphabetic) {
                 return Err(Error::InvalidVersionRequest(s.to_string()));
             }
 
             let Some(mut start) = s.rfind(|c: char| c.is_numeric()) else {
-                return Ok((s, PythonVariant::Default));
+                return Ok((s, PythonVariant::Default, false));
             };
 
             // Advance past the first digit
@@ -2995,7 +3114,7 @@ impl FromStr for VersionRequest {
 
             // Ensure we're not out of bounds
             if start + 1 > s.len() {
-                return Ok((s, PythonVariant::Default));
+                return Ok((s, PythonVariant::Default, false));
             }
 
             let variant = &s[start..];
@@ -3004,18 +3123,27 @@ impl FromStr for VersionRequest {
             // Strip a leading `+` if present
             let variant = variant.strip_prefix('+').unwrap_or(variant);
 
+            // Strip a trailing pymalloc flag if present, e.g., `3.7m` or `3.7dm`
+            let (variant, pymalloc) = match variant.strip_suffix('m') {
+                Some(variant) => (variant, true),
+                None => (variant, false),
+            };
+
             // TODO(zanieb): Special-case error for use of `dt` instead of `td`
 
             // If there's not a valid variant, fallback to failure in [`Version::from_str`]
             let Ok(variant) = PythonVariant::from_str(variant) else {
-                return Ok((s, PythonVariant::Default));
+                return Ok((s, PythonVariant::Default, false));
             };
 
-            Ok((prefix, variant))
+            Ok((prefix, variant, pymalloc))
         }
 
-        let (s, variant) = parse_variant(s)?;
+        let (s, variant, pymalloc) = parse_variant(s)?;
         let Ok(version) = Version::from_str(s) else {
+            if pymalloc {
+                return Err(Error::InvalidVersionRequest(s.to_string()));
+            }
             return parse_version_specifiers_request(s, variant);
         };
 
@@ -3038,6 +3166,12 @@ impl FromStr for VersionRequest {
             return Err(Error::InvalidVersionRequest(s.to_string()));
         };
 
+        // The pymalloc flag only applies to Python 3.7 and earlier, where it's implied by the
+        // default build configuration. It was dropped from the ABI flags in Python 3.8.
+        if pymalloc && !matches!(release.as_slice(), [2, _, ..] | [3, 0..=7, ..]) {
+            return Err(Error::InvalidVersionRequest(s.to_string()));
+        }
+
         let prerelease = version.pre();
 
         match release.as_slice() {
@@ -3548,6 +3682,31 @@ mod tests {
                 VersionRequest::from_str("3.10").unwrap(),
             )
         );
+        assert_eq!(
+            PythonRequest::parse("graalpy-24"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::GraalPy,
+                VersionRequest::Major(24, PythonVariant::Default),
+            )
+        );
+        assert_eq!(
+            PythonRequest::parse("graalpy-24.2"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::GraalPy,
+                VersionRequest::from_str("24.2").unwrap(),
+            )
+        );
+        assert_eq!(
+            PythonRequest::parse("pyston"),
+            PythonRequest::Implementation(ImplementationName::Pyston)
+        );
+        assert_eq!(
+            PythonRequest::parse("pyston3.8"),
+            PythonRequest::ImplementationVersion(
+                ImplementationName::Pyston,
+                VersionRequest::from_str("3.8").unwrap(),
+            )
+        );
         assert_eq!(
             PythonRequest::parse("graalpy3.10"),
             PythonRequest::ImplementationVersion(
@@ -3626,6 +3785,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn interpreter_request_with_filters() {
+        assert_eq!(
+            PythonRequest::parse_with_filters("3.12").unwrap(),
+            PythonRequest::parse("3.12")
+        );
+        assert_eq!(
+            PythonRequest::parse_with_filters("cpython>=3.11,<3.13; arch=aarch64").unwrap(),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: Some(VersionRequest::from_str(">=3.11,<3.13").unwrap()),
+                implementation: Some(ImplementationName::CPython),
+                arch: Some(ArchRequest::Explicit(Arch::new(
+                    Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                    None
+                ))),
+                os: None,
+                libc: None,
+                build: None,
+                prereleases: None
+            })
+        );
+        assert_eq!(
+            PythonRequest::parse_with_filters(">=3.12; implementation=pypy, os=linux, libc=gnu")
+                .unwrap(),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: Some(VersionRequest::from_str(">=3.12").unwrap()),
+                implementation: Some(ImplementationName::PyPy),
+                arch: None,
+                os: Some(Os::new(target_lexicon::OperatingSystem::Linux)),
+                libc: Some(Libc::Some(target_lexicon::Environment::Gnu)),
+                build: None,
+                prereleases: None
+            })
+        );
+        assert_eq!(
+            PythonRequest::parse_with_filters("; arch=aarch64").unwrap(),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: None,
+                implementation: None,
+                arch: Some(ArchRequest::Explicit(Arch::new(
+                    Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                    None
+                ))),
+                os: None,
+                libc: None,
+                build: None,
+                prereleases: None
+            })
+        );
+        assert!(matches!(
+            PythonRequest::parse_with_filters("3.12; arch"),
+            Err(Error::InvalidRequestFilter(..))
+        ));
+        assert!(matches!(
+            PythonRequest::parse_with_filters("3.12; abi=cp312"),
+            Err(Error::InvalidRequestFilter(..))
+        ));
+        assert!(matches!(
+            PythonRequest::parse_with_filters("3.12; arch=not-an-arch"),
+            Err(Error::InvalidRequestFilter(..))
+        ));
+        assert!(matches!(
+            PythonRequest::parse_with_filters("foo; arch=aarch64"),
+            Err(Error::InvalidRequestFilter(..))
+        ));
+    }
+
     #[test]
     fn discovery_sources_prefer_system_orders_search_path_first() {
         let preferences = DiscoveryPreferences {
@@ -3715,6 +3941,14 @@ mod tests {
             .to_canonical_string(),
             "cpython@3.12.2"
         );
+        assert_eq!(
+            PythonRequest::ImplementationVersion(
+                ImplementationName::GraalPy,
+                VersionRequest::Major(24, PythonVariant::Default),
+            )
+            .to_canonical_string(),
+            "graalpy-24"
+        );
         assert_eq!(
             PythonRequest::Implementation(ImplementationName::PyPy).to_canonical_string(),
             "pypy"
@@ -3922,6 +4156,30 @@ mod tests {
             VersionRequest::from_str("3.13tt"),
             Err(Error::InvalidVersionRequest(_))
         ));
+        assert_eq!(
+            VersionRequest::from_str("3.13td").unwrap(),
+            VersionRequest::MajorMinor(3, 13, PythonVariant::FreethreadedDebug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.12d").unwrap(),
+            VersionRequest::MajorMinor(3, 12, PythonVariant::Debug)
+        );
+        assert_eq!(
+            VersionRequest::from_str("3.7m").unwrap(),
+            VersionRequest::MajorMinor(3, 7, PythonVariant::Default)
+        );
+        assert_eq!(
+            VersionRequest::from_str("37dm").unwrap(),
+            VersionRequest::MajorMinor(3, 7, PythonVariant::Debug)
+        );
+        assert!(matches!(
+            VersionRequest::from_str("3.12m"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
+        assert!(matches!(
+            VersionRequest::from_str("3m"),
+            Err(Error::InvalidVersionRequest(_))
+        ));
     }
 
     #[test]
@@ -3960,7 +4218,7 @@ mod tests {
             "any",
             &[
                 "python", "python3", "cpython", "cpython3", "pypy", "pypy3", "graalpy", "graalpy3",
-                "pyodide", "pyodide3",
+                "pyston", "pyston3", "pyodide", "pyodide3",
             ],
         );
 
@@ -26,11 +26,13 @@ use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
 use uv_fs::{Simplified, rename_with_retry};
 use uv_platform::{self as platform, Arch, Libc, Os, Platform};
+use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 use uv_static::EnvVars;
 
 use crate::PythonVariant;
+use crate::attestation::{AttestationError, PythonDownloadAttestations};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -115,6 +117,8 @@ pub enum Error {
     },
     #[error(transparent)]
     BuildVersion(#[from] BuildVersionError),
+    #[error(transparent)]
+    Attestation(#[from] AttestationError),
 }
 
 impl Error {
@@ -1109,7 +1113,9 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        attestations: Option<&PythonDownloadAttestations>,
         reporter: Option<&dyn Reporter>,
+        preview: Preview,
     ) -> Result<DownloadResult, Error> {
         let mut total_attempts = 0;
         let mut retried_here = false;
@@ -1123,7 +1129,9 @@ impl ManagedPythonDownload {
                     reinstall,
                     python_install_mirror,
                     pypy_install_mirror,
+                    attestations,
                     reporter,
+                    preview,
                 )
                 .await;
             let result = match result {
@@ -1176,7 +1184,9 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        attestations: Option<&PythonDownloadAttestations>,
         reporter: Option<&dyn Reporter>,
+        preview: Preview,
     ) -> Result<DownloadResult, Error> {
         let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
         let path = installation_dir.join(self.key().to_string());
@@ -1186,6 +1196,19 @@ impl ManagedPythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
+        // If the build provenance must be verified, ensure that's possible before downloading.
+        if let Some(attestations) = attestations {
+            if self.key.implementation
+                != LenientImplementationName::Known(ImplementationName::CPython)
+            {
+                return Err(AttestationError::UnsupportedImplementation(
+                    self.key.implementation.pretty().to_string(),
+                )
+                .into());
+            }
+            attestations.check_available()?;
+        }
+
         // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
         // decodes to.
         let filename = url
@@ -1243,14 +1266,30 @@ impl ManagedPythonDownload {
                             });
                         }
 
-                        self.download_archive(
-                            &url,
-                            client,
-                            reporter,
-                            &python_builds_dir,
-                            &target_cache_file,
-                        )
-                        .await?;
+                        // Prefer reconstructing the archive from a cached archive of an earlier
+                        // patch release, if possible.
+                        let patched = preview.is_enabled(PreviewFeatures::PYTHON_DELTA_DOWNLOADS)
+                            && self
+                                .download_delta(
+                                    &url,
+                                    &filename,
+                                    client,
+                                    reporter,
+                                    &python_builds_dir,
+                                    &target_cache_file,
+                                )
+                                .await;
+
+                        if !patched {
+                            self.download_archive(
+                                &url,
+                                client,
+                                reporter,
+                                &python_builds_dir,
+                                &target_cache_file,
+                            )
+                            .await?;
+                        }
 
                         debug!("Extracting `{}`", target_cache_file.simplified_display());
                         let file = fs_err::tokio::File::open(&target_cache_file).await?;
@@ -1261,6 +1300,10 @@ impl ManagedPythonDownload {
                     Err(err) => return Err(err.into()),
                 };
 
+            if let Some(attestations) = attestations {
+                attestations.verify(&target_cache_file).await?;
+            }
+
             // Extract the downloaded archive into a temporary directory.
             self.extract_reader(
                 reader,
@@ -1272,6 +1315,31 @@ impl ManagedPythonDownload {
                 Direction::Extract,
             )
             .await?;
+        } else if let Some(attestations) = attestations {
+            // The archive must be on disk to verify its build provenance, so it can't be
+            // extracted as it's downloaded.
+            let archive_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
+            let archive = archive_dir.path().join(&filename);
+            self.download_archive(&url, client, reporter, archive_dir.path(), &archive)
+                .await?;
+            attestations.verify(&archive).await?;
+
+            debug!(
+                "Extracting {filename} to temporary location: {}",
+                temp_dir.path().simplified_display()
+            );
+            let file = fs_err::tokio::File::open(&archive).await?;
+            let size = file.metadata().await?.len();
+            self.extract_reader(
+                tokio::io::BufReader::new(file),
+                temp_dir.path(),
+                &filename,
+                ext,
+                Some(size),
+                reporter,
+                Direction::Extract,
+            )
+            .await?;
         } else {
             // Avoid overlong log lines
             debug!("Downloading {url}");
@@ -1404,6 +1472,128 @@ impl ManagedPythonDownload {
         Ok(())
     }
 
+    /// Reconstruct the managed Python archive from a cached archive of an earlier patch release
+    /// and a binary delta, returning `false` if the full archive should be downloaded instead.
+    ///
+    /// The delta is a zstd frame compressed with the earlier archive as its reference prefix
+    /// (i.e., `zstd --patch-from`), published next to the archive as
+    /// `<archive>.patch-from-<version>-<build>.zst`.
+    async fn download_delta(
+        &self,
+        url: &DisplaySafeUrl,
+        filename: &str,
+        client: &BaseClient,
+        reporter: Option<&dyn Reporter>,
+        python_builds_dir: &Path,
+        target_cache_file: &Path,
+    ) -> bool {
+        // Without a hash, we can't verify the reconstructed archive.
+        let Some(expected) = self.sha256.as_deref() else {
+            return false;
+        };
+
+        // Deltas are only published for python-build-standalone archives.
+        if self.key.implementation != LenientImplementationName::Known(ImplementationName::CPython)
+        {
+            return false;
+        }
+
+        let base = match find_delta_base(python_builds_dir, filename) {
+            Ok(Some(base)) => base,
+            Ok(None) => {
+                debug!("No cached archive to patch for `{filename}`");
+                return false;
+            }
+            Err(err) => {
+                debug!("Failed to read cached archives for `{filename}`: {err}");
+                return false;
+            }
+        };
+
+        match self
+            .apply_delta_from(
+                url,
+                &base,
+                expected,
+                client,
+                reporter,
+                python_builds_dir,
+                target_cache_file,
+            )
+            .await
+        {
+            Ok(()) => true,
+            Err(err) => {
+                debug!("Failed to patch `{filename}`, downloading the full archive: {err}");
+                false
+            }
+        }
+    }
+
+    /// Download the delta from the given cached archive and apply it.
+    async fn apply_delta_from(
+        &self,
+        url: &DisplaySafeUrl,
+        base: &DeltaBase,
+        expected: &str,
+        client: &BaseClient,
+        reporter: Option<&dyn Reporter>,
+        python_builds_dir: &Path,
+        target_cache_file: &Path,
+    ) -> Result<(), Error> {
+        let delta_url = DisplaySafeUrl::parse(&format!(
+            "{url}.patch-from-{}-{}.zst",
+            base.version, base.build
+        ))?;
+        debug!(
+            "Downloading {delta_url} to patch `{}`",
+            base.path.simplified_display()
+        );
+
+        let (mut reader, size) = read_url(&delta_url, client).await?;
+        let temp_dir = tempfile::tempdir_in(python_builds_dir)?;
+        let delta_file = temp_dir.path().join("delta");
+        let patched_file = temp_dir.path().join("download");
+
+        {
+            let mut delta_writer = BufWriter::new(fs_err::tokio::File::create(&delta_file).await?);
+            if let Some(reporter) = reporter {
+                let key = reporter.on_request_start(Direction::Download, &self.key, size);
+                tokio::io::copy(
+                    &mut ProgressReader::new(reader, key, reporter),
+                    &mut delta_writer,
+                )
+                .await?;
+                reporter.on_request_complete(Direction::Download, key);
+            } else {
+                tokio::io::copy(&mut reader, &mut delta_writer).await?;
+            }
+            delta_writer.flush().await?;
+        }
+
+        let actual = {
+            let base = base.path.clone();
+            let patched_file = patched_file.clone();
+            tokio::task::spawn_blocking(move || apply_delta(&base, &delta_file, &patched_file))
+                .await
+                .map_err(io::Error::other)??
+        };
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::HashMismatch {
+                installation: self.key.to_string(),
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+
+        match rename_with_retry(&patched_file, target_cache_file).await {
+            Ok(()) => {}
+            Err(_) if target_cache_file.is_file() => {}
+            Err(err) => return Err(err.into()),
+        }
+        Ok(())
+    }
+
     /// Extract a Python interpreter archive into a (temporary) directory, either from a file or
     /// from a download stream.
     async fn extract_reader(
@@ -1719,6 +1909,105 @@ where
 }
 
 /// Convert a [`Url`] into an [`AsyncRead`] stream.
+/// A cached python-build-standalone archive that can serve as the base of a delta.
+#[derive(Debug, PartialEq, Eq)]
+struct DeltaBase {
+    path: PathBuf,
+    version: uv_pep440::Version,
+    build: String,
+}
+
+/// Split a python-build-standalone archive filename, with the `+` replaced by `-`, into its
+/// version, build, and the remainder, e.g.,
+/// `cpython-3.12.8-20250106-x86_64-unknown-linux-gnu-install_only.tar.gz`.
+fn split_archive_filename(filename: &str) -> Option<(uv_pep440::Version, &str, &str)> {
+    let rest = filename.strip_prefix("cpython-")?;
+    let (version, rest) = rest.split_once('-')?;
+    let (build, rest) = rest.split_once('-')?;
+    let version = uv_pep440::Version::from_str(version).ok()?;
+    Some((version, build, rest))
+}
+
+/// Find the most recent cached archive for an earlier patch release of the same minor version and
+/// platform as the given archive.
+fn find_delta_base(
+    python_builds_dir: &Path,
+    filename: &str,
+) -> Result<Option<DeltaBase>, io::Error> {
+    let Some((version, _, suffix)) = split_archive_filename(filename) else {
+        return Ok(None);
+    };
+
+    let mut base: Option<DeltaBase> = None;
+    for entry in fs_err::read_dir(python_builds_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        // Cached archives are prefixed with the (shortened) hash of the archive.
+        let Some((_, cached)) = name.to_str().and_then(|name| name.split_once('-')) else {
+            continue;
+        };
+        let Some((cached_version, cached_build, cached_suffix)) = split_archive_filename(cached)
+        else {
+            continue;
+        };
+        if cached_suffix != suffix
+            || cached_version.release().get(..2) != version.release().get(..2)
+            || cached_version >= version
+        {
+            continue;
+        }
+        if base
+            .as_ref()
+            .is_some_and(|base| base.version >= cached_version)
+        {
+            continue;
+        }
+        base = Some(DeltaBase {
+            path: entry.path(),
+            version: cached_version,
+            build: cached_build.to_string(),
+        });
+    }
+    Ok(base)
+}
+
+/// Apply a `zstd --patch-from` delta to the base archive, returning the SHA-256 digest of the
+/// patched archive.
+fn apply_delta(base: &Path, delta: &Path, target: &Path) -> Result<String, io::Error> {
+    /// Hash the contents as they're written.
+    struct HashWriter<W> {
+        writer: W,
+        hasher: Hasher,
+    }
+
+    impl<W: io::Write> io::Write for HashWriter<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = self.writer.write(buf)?;
+            self.hasher.update(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.writer.flush()
+        }
+    }
+
+    let base = fs_err::read(base)?;
+    let delta = io::BufReader::new(fs_err::File::open(delta)?);
+    let mut decoder = zstd::stream::read::Decoder::with_ref_prefix(delta, &base)?;
+    // Archives exceed the default window size of the decoder.
+    decoder.window_log_max(31)?;
+
+    let mut writer = HashWriter {
+        writer: io::BufWriter::new(fs_err::File::create(target)?),
+        hasher: Hasher::from(HashAlgorithm::Sha256),
+    };
+    io::copy(&mut decoder, &mut writer)?;
+    io::Write::flush(&mut writer)?;
+
+    Ok(HashDigest::from(writer.hasher).digest.to_string())
+}
+
 async fn read_url(
     url: &DisplaySafeUrl,
     client: &BaseClient,
@@ -2239,4 +2528,77 @@ mod tests {
             "cpython-3.12.0-linux-x86_64-gnu"
         );
     }
+
+    #[test]
+    fn find_delta_base_earlier_patch() -> Result<(), io::Error> {
+        let python_builds_dir = tempfile::tempdir()?;
+        for cached in [
+            "abcdef012-cpython-3.12.6-20240909-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "abcdef012-cpython-3.12.7-20241016-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            // A different platform.
+            "abcdef012-cpython-3.12.7-20241016-aarch64-apple-darwin-install_only.tar.gz",
+            // A different minor version.
+            "abcdef012-cpython-3.13.0-20241016-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            // A later patch version.
+            "abcdef012-cpython-3.12.9-20250205-x86_64-unknown-linux-gnu-install_only.tar.gz",
+        ] {
+            fs_err::write(python_builds_dir.path().join(cached), "")?;
+        }
+
+        let base = find_delta_base(
+            python_builds_dir.path(),
+            "cpython-3.12.8-20250106-x86_64-unknown-linux-gnu-install_only.tar.gz",
+        )?
+        .unwrap();
+        assert_eq!(
+            base,
+            DeltaBase {
+                path: python_builds_dir.path().join(
+                    "abcdef012-cpython-3.12.7-20241016-x86_64-unknown-linux-gnu-install_only.tar.gz"
+                ),
+                version: uv_pep440::Version::new([3, 12, 7]),
+                build: "20241016".to_string(),
+            }
+        );
+
+        let base = find_delta_base(
+            python_builds_dir.path(),
+            "cpython-3.11.10-20241016-x86_64-unknown-linux-gnu-install_only.tar.gz",
+        )?;
+        assert_eq!(base, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_delta_roundtrip() -> Result<(), io::Error> {
+        let temp_dir = tempfile::tempdir()?;
+        let old = (0..100_000u32)
+            .flat_map(u32::to_le_bytes)
+            .collect::<Vec<_>>();
+        let mut new = old.clone();
+        new[1000..1010].copy_from_slice(b"3.12.8+new");
+
+        // Equivalent to `zstd --patch-from old new`.
+        let mut encoder =
+            zstd::stream::write::Encoder::with_ref_prefix(Vec::new(), 3, old.as_slice())?;
+        io::Write::write_all(&mut encoder, &new)?;
+        let delta = encoder.finish()?;
+        assert!(delta.len() < new.len() / 100);
+
+        fs_err::write(temp_dir.path().join("old"), &old)?;
+        fs_err::write(temp_dir.path().join("delta"), &delta)?;
+        let digest = apply_delta(
+            &temp_dir.path().join("old"),
+            &temp_dir.path().join("delta"),
+            &temp_dir.path().join("new"),
+        )?;
+
+        assert_eq!(fs_err::read(temp_dir.path().join("new"))?, new);
+        let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+        hasher.update(&new);
+        assert_eq!(digest, HashDigest::from(hasher).digest.to_string());
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use owo_colors::OwoColorize;
 use tracing::debug;
@@ -316,6 +317,17 @@ impl PythonEnvironment {
         self.0.interpreter.lock().await
     }
 
+    /// Grab a file lock for the environment, returning `None` if it's held by another process.
+    pub fn try_lock(&self) -> Option<LockedFile> {
+        self.0.interpreter.try_lock()
+    }
+
+    /// Grab a file lock for the environment, waiting at most `timeout` for another process to
+    /// release it.
+    pub async fn lock_timeout(&self, timeout: Duration) -> Result<LockedFile, std::io::Error> {
+        self.0.interpreter.lock_timeout(timeout).await
+    }
+
     /// Return the [`Interpreter`] for this environment.
     ///
     /// See also [`PythonEnvironment::interpreter`].
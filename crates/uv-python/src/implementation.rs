@@ -4,7 +4,7 @@ use std::{
 };
 use thiserror::Error;
 
-use crate::Interpreter;
+use crate::{Interpreter, VersionRequest};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -15,6 +15,7 @@ pub enum Error {
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
 pub enum ImplementationName {
     Pyodide,
+    Pyston,
     GraalPy,
     PyPy,
     #[default]
@@ -33,11 +34,18 @@ impl ImplementationName {
     }
 
     pub(crate) fn long_names() -> impl Iterator<Item = &'static str> {
-        ["cpython", "pypy", "graalpy", "pyodide"].into_iter()
+        ["cpython", "pypy", "graalpy", "pyston", "pyodide"].into_iter()
     }
 
     pub(crate) fn iter_all() -> impl Iterator<Item = Self> {
-        [Self::CPython, Self::PyPy, Self::GraalPy, Self::Pyodide].into_iter()
+        [
+            Self::CPython,
+            Self::PyPy,
+            Self::GraalPy,
+            Self::Pyston,
+            Self::Pyodide,
+        ]
+        .into_iter()
     }
 
     pub fn pretty(self) -> &'static str {
@@ -45,6 +53,7 @@ impl ImplementationName {
             Self::CPython => "CPython",
             Self::PyPy => "PyPy",
             Self::GraalPy => "GraalPy",
+            Self::Pyston => "Pyston",
             Self::Pyodide => "Pyodide",
         }
     }
@@ -52,10 +61,20 @@ impl ImplementationName {
     pub fn executable_name(self) -> &'static str {
         match self {
             Self::CPython | Self::Pyodide => "python",
-            Self::PyPy | Self::GraalPy => self.into(),
+            Self::PyPy | Self::GraalPy | Self::Pyston => self.into(),
         }
     }
 
+    /// Whether the version in a request for this implementation refers to the version of the
+    /// implementation itself, rather than the Python version.
+    ///
+    /// GraalPy releases are versioned independently of the Python language version they
+    /// implement, and their major versions (e.g., GraalPy 24) can never be confused with a Python
+    /// major version.
+    pub fn is_implementation_version(self, version: &VersionRequest) -> bool {
+        matches!(self, Self::GraalPy) && version.major().is_some_and(|major| major >= 20)
+    }
+
     pub fn matches_interpreter(self, interpreter: &Interpreter) -> bool {
         match self {
             Self::Pyodide => interpreter.os().is_emscripten(),
@@ -88,6 +107,7 @@ impl From<&ImplementationName> for &'static str {
             ImplementationName::CPython => "cpython",
             ImplementationName::PyPy => "pypy",
             ImplementationName::GraalPy => "graalpy",
+            ImplementationName::Pyston => "pyston",
             ImplementationName::Pyodide => "pyodide",
         }
     }
@@ -119,6 +139,7 @@ impl FromStr for ImplementationName {
             "cpython" | "cp" => Ok(Self::CPython),
             "pypy" | "pp" => Ok(Self::PyPy),
             "graalpy" | "gp" => Ok(Self::GraalPy),
+            "pyston" => Ok(Self::Pyston),
             "pyodide" => Ok(Self::Pyodide),
             _ => Err(Error::UnknownImplementation(s.to_string())),
         }
@@ -15,6 +15,7 @@ use uv_pep440::{Prerelease, Version};
 use uv_platform::{Arch, Libc, Os, Platform};
 use uv_preview::Preview;
 
+use crate::attestation::PythonDownloadAttestations;
 use crate::discovery::{
     EnvironmentPreference, PythonRequest, find_best_python_installation, find_python_installation,
 };
@@ -102,6 +103,7 @@ impl PythonInstallation {
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        attestations: Option<&PythonDownloadAttestations>,
         python_downloads_json_url: Option<&str>,
         preview: Preview,
     ) -> Result<Self, Error> {
@@ -229,6 +231,7 @@ impl PythonInstallation {
             reporter,
             python_install_mirror,
             pypy_install_mirror,
+            attestations,
             preview,
         )
         .await?;
@@ -247,6 +250,7 @@ impl PythonInstallation {
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        attestations: Option<&PythonDownloadAttestations>,
         preview: Preview,
     ) -> Result<Self, Error> {
         let installations = ManagedPythonInstallations::from_settings(None)?.init()?;
@@ -264,7 +268,9 @@ impl PythonInstallation {
                 false,
                 python_install_mirror,
                 pypy_install_mirror,
+                attestations,
                 reporter,
+                preview,
             )
             .await?;
 
@@ -551,6 +557,29 @@ impl PythonInstallationKey {
         &self.variant
     }
 
+    /// Return the (scheduled) end-of-life month of the Python minor version, in `YYYY-MM` format.
+    ///
+    /// Returns `None` for implementations other than CPython, which follow their own release
+    /// schedules, and for versions without a published end-of-life date.
+    pub fn end_of_life(&self) -> Option<&'static str> {
+        if *self.implementation() != LenientImplementationName::Known(ImplementationName::CPython) {
+            return None;
+        }
+        // See: https://devguide.python.org/versions/
+        match (self.major, self.minor) {
+            (3, 7) => Some("2023-06"),
+            (3, 8) => Some("2024-10"),
+            (3, 9) => Some("2025-10"),
+            (3, 10) => Some("2026-10"),
+            (3, 11) => Some("2027-10"),
+            (3, 12) => Some("2028-10"),
+            (3, 13) => Some("2029-10"),
+            (3, 14) => Some("2030-10"),
+            (3, 15) => Some("2031-10"),
+            _ => None,
+        }
+    }
+
     /// Return a canonical name for a minor versioned executable.
     pub fn executable_name_minor(&self) -> String {
         format!(
@@ -4,7 +4,8 @@ use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::str::FromStr;
-use std::sync::OnceLock;
+use std::sync::{LazyLock, OnceLock};
+use std::time::Duration;
 use std::{env, io};
 
 use configparser::ini::Ini;
@@ -15,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, trace, warn};
 
-use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
+use uv_cache::{Cache, CacheBucket, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
 use uv_fs::{LockedFile, PythonExt, Simplified, write_atomic_sync};
@@ -62,6 +63,8 @@ pub struct Interpreter {
     gil_disabled: bool,
     real_executable: PathBuf,
     debug_enabled: bool,
+    pymalloc: bool,
+    synthetic: bool,
 }
 
 impl Interpreter {
@@ -86,6 +89,7 @@ impl Interpreter {
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
             debug_enabled: info.debug_enabled,
+            pymalloc: info.pymalloc,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -97,9 +101,98 @@ impl Interpreter {
             target: None,
             prefix: None,
             real_executable: executable.as_ref().to_path_buf(),
+            synthetic: false,
         })
     }
 
+    /// Create an [`Interpreter`] from the given markers and platform, without querying a Python
+    /// executable.
+    ///
+    /// The resulting interpreter is rooted at the given directory, and is only suitable for
+    /// resolving and installing wheels into a `--target` or `--prefix` directory; it cannot be
+    /// used to run Python (e.g., to build source distributions or compile bytecode).
+    pub fn synthetic(
+        markers: MarkerEnvironment,
+        platform: Platform,
+        manylinux_compatible: bool,
+        root: PathBuf,
+    ) -> Self {
+        let python_version = markers.python_version().version.release();
+        let (major, minor) = (python_version[0], python_version[1]);
+        let executable = match markers.implementation_name() {
+            "pypy" => format!("pypy{major}.{minor}"),
+            _ => format!("python{major}.{minor}"),
+        };
+
+        // Mirror the layout of a virtual environment created by the target interpreter.
+        let (virtualenv, sys_executable) = if markers.os_name() == "nt" {
+            (
+                Scheme {
+                    purelib: PathBuf::from("Lib").join("site-packages"),
+                    platlib: PathBuf::from("Lib").join("site-packages"),
+                    scripts: PathBuf::from("Scripts"),
+                    data: PathBuf::new(),
+                    include: PathBuf::from("Include"),
+                },
+                PathBuf::from("python.exe"),
+            )
+        } else {
+            (
+                Scheme {
+                    purelib: PathBuf::from("lib").join(&executable).join("site-packages"),
+                    platlib: PathBuf::from("lib").join(&executable).join("site-packages"),
+                    scripts: PathBuf::from("bin"),
+                    data: PathBuf::new(),
+                    include: PathBuf::from("include").join("site").join(&executable),
+                },
+                PathBuf::from("/usr/bin").join(&executable),
+            )
+        };
+        let scheme = Scheme {
+            purelib: root.join(&virtualenv.purelib),
+            platlib: root.join(&virtualenv.platlib),
+            scripts: root.join(&virtualenv.scripts),
+            data: root.join(&virtualenv.data),
+            include: root.join(&virtualenv.include),
+        };
+
+        let pointer_size = match platform.arch() {
+            uv_platform_tags::Arch::Armv5TEL
+            | uv_platform_tags::Arch::Armv6L
+            | uv_platform_tags::Arch::Armv7L
+            | uv_platform_tags::Arch::Powerpc
+            | uv_platform_tags::Arch::X86
+            | uv_platform_tags::Arch::Wasm32 => PointerSize::_32,
+            _ => PointerSize::_64,
+        };
+
+        Self {
+            platform,
+            markers: Box::new(markers),
+            scheme,
+            virtualenv,
+            manylinux_compatible,
+            sys_prefix: root.clone(),
+            sys_base_exec_prefix: root.clone(),
+            sys_base_prefix: root.clone(),
+            sys_base_executable: None,
+            sys_executable: sys_executable.clone(),
+            sys_path: vec![],
+            site_packages: vec![],
+            stdlib: root,
+            standalone: false,
+            tags: OnceLock::new(),
+            target: None,
+            prefix: None,
+            pointer_size,
+            gil_disabled: false,
+            real_executable: sys_executable,
+            debug_enabled: false,
+            pymalloc: true,
+            synthetic: true,
+        }
+    }
+
     /// Return a new [`Interpreter`] with the given virtual environment root.
     #[must_use]
     pub fn with_virtualenv(self, virtualenv: VirtualEnvironment) -> Self {
@@ -253,6 +346,8 @@ impl Interpreter {
                 self.implementation_tuple(),
                 self.manylinux_compatible,
                 self.gil_disabled,
+                self.debug_enabled,
+                self.pymalloc,
             )?;
             self.tags.set(tags).expect("tags should not be set");
         }
@@ -267,6 +362,14 @@ impl Interpreter {
         self.sys_prefix != self.sys_base_prefix
     }
 
+    /// Returns `true` if the interpreter was synthesized from a target platform, rather than
+    /// queried from a Python executable.
+    ///
+    /// See [`Interpreter::synthetic`].
+    pub fn is_synthetic(&self) -> bool {
+        self.synthetic
+    }
+
     /// Returns `true` if the environment is a `--target` environment.
     pub fn is_target(&self) -> bool {
         self.target.is_some()
@@ -536,6 +639,15 @@ impl Interpreter {
         self.debug_enabled
     }
 
+    /// Return whether this Python was built with the pymalloc allocator, as specified by the
+    /// sysconfig var `WITH_PYMALLOC`.
+    ///
+    /// On Python 3.7 and earlier, pymalloc builds are marked by an `m` abiflag.
+    /// <https://peps.python.org/pep-3149/>
+    pub fn pymalloc(&self) -> bool {
+        self.pymalloc
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -667,26 +779,50 @@ impl Interpreter {
 
     /// Grab a file lock for the environment to prevent concurrent writes across processes.
     pub async fn lock(&self) -> Result<LockedFile, io::Error> {
+        let (path, resource) = self.lock_path();
+        LockedFile::acquire(path, resource).await
+    }
+
+    /// Grab a file lock for the environment, returning `None` if it's held by another process.
+    pub fn try_lock(&self) -> Option<LockedFile> {
+        let (path, resource) = self.lock_path();
+        LockedFile::acquire_no_wait(path, resource)
+    }
+
+    /// Grab a file lock for the environment, waiting at most `timeout` for another process to
+    /// release it.
+    pub async fn lock_timeout(&self, timeout: Duration) -> Result<LockedFile, io::Error> {
+        let (path, resource) = self.lock_path();
+        LockedFile::acquire_timeout(path, resource, timeout).await
+    }
+
+    /// Return the path to the lockfile for the environment, along with a display name for the
+    /// locked resource.
+    fn lock_path(&self) -> (PathBuf, String) {
         if let Some(target) = self.target() {
             // If we're installing into a `--target`, use a target-specific lockfile.
-            LockedFile::acquire(target.root().join(".lock"), target.root().user_display()).await
+            (
+                target.root().join(".lock"),
+                target.root().user_display().to_string(),
+            )
         } else if let Some(prefix) = self.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
-            LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+            (
+                prefix.root().join(".lock"),
+                prefix.root().user_display().to_string(),
+            )
         } else if self.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
-            LockedFile::acquire(
+            (
                 self.sys_prefix.join(".lock"),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         } else {
             // Otherwise, use a global lockfile.
-            LockedFile::acquire(
+            (
                 env::temp_dir().join(format!("uv-{}.lock", cache_digest(&self.sys_executable))),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         }
     }
 }
@@ -925,6 +1061,11 @@ struct InterpreterInfo {
     pointer_size: PointerSize,
     gil_disabled: bool,
     debug_enabled: bool,
+    pymalloc: bool,
+    /// Files, beyond the executable, that the interpreter info is derived from, e.g., the
+    /// `sysconfig` module and the shared library.
+    #[serde(default)]
+    dependencies: Vec<PathBuf>,
 }
 
 impl InterpreterInfo {
@@ -1027,42 +1168,21 @@ impl InterpreterInfo {
     /// Duplicate the directory structure we have in `../python` into a tempdir, so we can run
     /// the Python probing scripts with `python -m python.get_interpreter_info` from that tempdir.
     fn setup_python_query_files(root: &Path) -> Result<(), Error> {
-        let python_dir = root.join("python");
-        fs_err::create_dir(&python_dir)?;
-        fs_err::write(
-            python_dir.join("get_interpreter_info.py"),
-            include_str!("../python/get_interpreter_info.py"),
-        )?;
-        fs_err::write(
-            python_dir.join("__init__.py"),
-            include_str!("../python/__init__.py"),
-        )?;
-        let packaging_dir = python_dir.join("packaging");
-        fs_err::create_dir(&packaging_dir)?;
-        fs_err::write(
-            packaging_dir.join("__init__.py"),
-            include_str!("../python/packaging/__init__.py"),
-        )?;
-        fs_err::write(
-            packaging_dir.join("_elffile.py"),
-            include_str!("../python/packaging/_elffile.py"),
-        )?;
-        fs_err::write(
-            packaging_dir.join("_manylinux.py"),
-            include_str!("../python/packaging/_manylinux.py"),
-        )?;
-        fs_err::write(
-            packaging_dir.join("_musllinux.py"),
-            include_str!("../python/packaging/_musllinux.py"),
-        )?;
+        fs_err::create_dir(root.join("python"))?;
+        fs_err::create_dir(root.join("python").join("packaging"))?;
+        for (path, contents) in QUERY_FILES {
+            fs_err::write(root.join(path), contents)?;
+        }
         Ok(())
     }
 
     /// A wrapper around [`markers::query_interpreter_info`] to cache the computed markers.
     ///
-    /// Running a Python script is (relatively) expensive, and the markers won't change
-    /// unless the Python executable changes, so we use the executable's last modified
-    /// time as a cache key.
+    /// Running a Python script is (relatively) expensive, and the markers won't change unless the
+    /// Python executable, or one of the files it loads on startup (like `sysconfig` or the shared
+    /// library), changes. We fingerprint those files by their size, modification time, and
+    /// content hash, along with the digest of the query script, and re-query the interpreter when
+    /// any of them changes.
     pub(crate) fn query_cached(executable: &Path, cache: &Cache) -> Result<Self, Error> {
         let absolute = std::path::absolute(executable)?;
 
@@ -1110,9 +1230,8 @@ impl InterpreterInfo {
             format!("{}.msgpack", cache_digest(&(&absolute, &canonical))),
         );
 
-        // We check the timestamp of the canonicalized executable to check if an underlying
-        // interpreter has been modified.
-        let modified = Timestamp::from_path(canonical).map_err(handle_io_error)?;
+        // Ensure that the canonicalized executable exists before consulting the cache.
+        fs::metadata(&canonical).map_err(handle_io_error)?;
 
         // Read from the cache.
         if cache
@@ -1120,22 +1239,48 @@ impl InterpreterInfo {
             .is_ok_and(Freshness::is_fresh)
         {
             if let Ok(data) = fs::read(cache_entry.path()) {
-                match rmp_serde::from_slice::<CachedByTimestamp<Self>>(&data) {
-                    Ok(cached) => {
-                        if cached.timestamp == modified {
+                match rmp_serde::from_slice::<CachedInterpreterInfo>(&data) {
+                    Ok(cached) if cached.query_digest != *QUERY_DIGEST => {
+                        trace!(
+                            "Ignoring interpreter markers from an outdated query script for: {}",
+                            executable.user_display()
+                        );
+                    }
+                    Ok(cached) => match cached.revalidate() {
+                        Revalidation::Unchanged => {
                             trace!(
                                 "Found cached interpreter info for Python {}, skipping query of: {}",
-                                cached.data.markers.python_full_version(),
+                                cached.info.markers.python_full_version(),
                                 executable.user_display()
                             );
-                            return Ok(cached.data);
+                            return Ok(cached.info);
                         }
-
-                        trace!(
-                            "Ignoring stale interpreter markers for: {}",
-                            executable.user_display()
-                        );
-                    }
+                        Revalidation::Touched(fingerprints) => {
+                            // The files were touched, but their contents are unchanged. Update the
+                            // fingerprints, to avoid hashing the files again on the next run.
+                            trace!(
+                                "Found cached interpreter info for Python {} with unchanged contents, skipping query of: {}",
+                                cached.info.markers.python_full_version(),
+                                executable.user_display()
+                            );
+                            let cached = CachedInterpreterInfo {
+                                fingerprints,
+                                ..cached
+                            };
+                            if let Err(err) =
+                                write_atomic_sync(cache_entry.path(), rmp_serde::to_vec(&cached)?)
+                            {
+                                debug!("Failed to update interpreter cache entry: {err}");
+                            }
+                            return Ok(cached.info);
+                        }
+                        Revalidation::Changed => {
+                            trace!(
+                                "Ignoring stale interpreter markers for: {}",
+                                executable.user_display()
+                            );
+                        }
+                    },
                     Err(err) => {
                         warn!(
                             "Broken interpreter cache entry at {}, removing: {err}",
@@ -1147,6 +1292,11 @@ impl InterpreterInfo {
             }
         }
 
+        // We fingerprint the canonicalized executable before running the query, such that a
+        // modification during the query invalidates the entry.
+        let executable_fingerprint =
+            FileFingerprint::from_path(&canonical).map_err(handle_io_error)?;
+
         // Otherwise, run the Python script.
         trace!(
             "Querying interpreter executable at {}",
@@ -1157,12 +1307,23 @@ impl InterpreterInfo {
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
         if is_same_file(executable, &info.sys_executable).unwrap_or(false) {
+            let mut fingerprints = vec![executable_fingerprint];
+            for path in &info.dependencies {
+                match FileFingerprint::from_path(path) {
+                    Ok(fingerprint) => fingerprints.push(fingerprint),
+                    Err(err) => debug!(
+                        "Failed to fingerprint interpreter dependency `{}`: {err}",
+                        path.user_display()
+                    ),
+                }
+            }
             fs::create_dir_all(cache_entry.dir())?;
             write_atomic_sync(
                 cache_entry.path(),
-                rmp_serde::to_vec(&CachedByTimestamp {
-                    timestamp: modified,
-                    data: info.clone(),
+                rmp_serde::to_vec(&CachedInterpreterInfo {
+                    query_digest: QUERY_DIGEST.clone(),
+                    fingerprints,
+                    info: info.clone(),
                 })?,
             )?;
         }
@@ -1171,6 +1332,139 @@ impl InterpreterInfo {
     }
 }
 
+/// The files of the interpreter query script, relative to the query directory.
+const QUERY_FILES: &[(&str, &str)] = &[
+    (
+        "python/get_interpreter_info.py",
+        include_str!("../python/get_interpreter_info.py"),
+    ),
+    ("python/__init__.py", include_str!("../python/__init__.py")),
+    (
+        "python/packaging/__init__.py",
+        include_str!("../python/packaging/__init__.py"),
+    ),
+    (
+        "python/packaging/_elffile.py",
+        include_str!("../python/packaging/_elffile.py"),
+    ),
+    (
+        "python/packaging/_manylinux.py",
+        include_str!("../python/packaging/_manylinux.py"),
+    ),
+    (
+        "python/packaging/_musllinux.py",
+        include_str!("../python/packaging/_musllinux.py"),
+    ),
+];
+
+/// The digest of the interpreter query script, such that cached interpreter info is invalidated
+/// whenever the script changes.
+static QUERY_DIGEST: LazyLock<String> = LazyLock::new(|| cache_digest(&QUERY_FILES));
+
+/// Interpreter info, cached alongside fingerprints of the files it was derived from.
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedInterpreterInfo {
+    /// The digest of the query script that produced the info.
+    query_digest: String,
+    /// The fingerprints of the executable and its dependencies, starting with the executable.
+    fingerprints: Vec<FileFingerprint>,
+    info: InterpreterInfo,
+}
+
+impl CachedInterpreterInfo {
+    /// Check whether any of the fingerprinted files changed since the info was cached.
+    fn revalidate(&self) -> Revalidation<Vec<FileFingerprint>> {
+        let mut touched = false;
+        let mut fingerprints = Vec::with_capacity(self.fingerprints.len());
+        for fingerprint in &self.fingerprints {
+            match fingerprint.revalidate() {
+                Revalidation::Unchanged => fingerprints.push(fingerprint.clone()),
+                Revalidation::Touched(updated) => {
+                    touched = true;
+                    fingerprints.push(updated);
+                }
+                Revalidation::Changed => {
+                    trace!(
+                        "Interpreter dependency changed: {}",
+                        fingerprint.path.user_display()
+                    );
+                    return Revalidation::Changed;
+                }
+            }
+        }
+        if touched {
+            Revalidation::Touched(fingerprints)
+        } else {
+            Revalidation::Unchanged
+        }
+    }
+}
+
+/// The result of comparing cached fingerprints against the files on disk.
+#[derive(Debug)]
+enum Revalidation<T> {
+    /// The files are unchanged.
+    Unchanged,
+    /// The files were modified, but their contents are unchanged, e.g., due to a `touch` or a
+    /// reinstall of the same build. Contains the updated fingerprints.
+    Touched(T),
+    /// The contents of the files changed, or the files were removed.
+    Changed,
+}
+
+/// The fingerprint of a file that the interpreter info depends on.
+///
+/// The size and modification time are compared first; the content hash is only computed when the
+/// modification time changed but the size didn't.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct FileFingerprint {
+    path: PathBuf,
+    size: u64,
+    modified: Timestamp,
+    /// The hex-encoded hash of the file contents.
+    hash: String,
+}
+
+impl FileFingerprint {
+    /// Fingerprint the file at the given path, hashing its contents.
+    fn from_path(path: &Path) -> io::Result<Self> {
+        let metadata = fs::metadata(path)?;
+        let mut file = fs::File::open(path)?;
+        let mut hasher = seahash::SeaHasher::new();
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let read = io::Read::read(&mut file, &mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            std::hash::Hasher::write(&mut hasher, &buffer[..read]);
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            size: metadata.len(),
+            modified: Timestamp::from_metadata(&metadata),
+            hash: format!("{:016x}", std::hash::Hasher::finish(&hasher)),
+        })
+    }
+
+    /// Compare the fingerprint against the file on disk.
+    fn revalidate(&self) -> Revalidation<Self> {
+        let Ok(metadata) = fs::metadata(&self.path) else {
+            return Revalidation::Changed;
+        };
+        if metadata.len() != self.size {
+            return Revalidation::Changed;
+        }
+        if Timestamp::from_metadata(&metadata) == self.modified {
+            return Revalidation::Unchanged;
+        }
+        match Self::from_path(&self.path) {
+            Ok(fingerprint) if fingerprint.hash == self.hash => Revalidation::Touched(fingerprint),
+            _ => Revalidation::Changed,
+        }
+    }
+}
+
 /// Find the Python executable that should be considered the "base" for a virtual environment.
 ///
 /// Assumes that the provided executable is that of a standalone Python interpreter.
@@ -1272,11 +1566,8 @@ mod tests {
 
     use crate::Interpreter;
 
-    #[test]
-    fn test_cache_invalidation() {
-        let mock_dir = tempdir().unwrap();
-        let mocked_interpreter = mock_dir.path().join("python");
-        let json = indoc! {r##"
+    /// The interpreter info reported by the mocked interpreters.
+    const MOCK_INTERPRETER_INFO: &str = indoc! {r##"
         {
             "result": "success",
             "platform": {
@@ -1330,10 +1621,17 @@ mod tests {
             },
             "pointer_size": "64",
             "gil_disabled": true,
-            "debug_enabled": false
+            "debug_enabled": false,
+            "pymalloc": true
         }
     "##};
 
+    #[test]
+    fn test_cache_invalidation() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = MOCK_INTERPRETER_INFO;
+
         let cache = Cache::temp().unwrap().init().unwrap();
 
         fs::write(
@@ -1369,4 +1667,58 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn test_cache_invalidation_dependencies() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let sysconfig = mock_dir.path().join("sysconfig.py");
+        let queries = mock_dir.path().join("queries.log");
+        fs::write(&sysconfig, "").unwrap();
+
+        // Report the mocked interpreter as `sys.executable`, such that the info is cached.
+        let json = MOCK_INTERPRETER_INFO
+            .replace(
+                "/home/ferris/projects/uv/.venv/bin/python",
+                &mocked_interpreter.display().to_string(),
+            )
+            .replace(
+                r#""pymalloc": true"#,
+                &format!(
+                    r#""pymalloc": true, "dependencies": ["{}"]"#,
+                    sysconfig.display()
+                ),
+            );
+        let script = formatdoc! {r"
+        #!/bin/sh
+        echo >> '{}'
+        echo '{json}'
+        ", queries.display()};
+        let query_count = || fs::read_to_string(&queries).unwrap().lines().count();
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+        fs::write(&mocked_interpreter, &script).unwrap();
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(query_count(), 1);
+
+        // The info is read from the cache.
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(query_count(), 1);
+
+        // Rewriting the executable with the same contents doesn't invalidate the cache.
+        fs::write(&mocked_interpreter, &script).unwrap();
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(query_count(), 1);
+
+        // Modifying a dependency does.
+        fs::write(&sysconfig, "# Patched by the distributor").unwrap();
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(query_count(), 2);
+    }
 }
@@ -5,6 +5,7 @@ use thiserror::Error;
 #[cfg(test)]
 use uv_static::EnvVars;
 
+pub use crate::attestation::{AttestationError, PythonDownloadAttestations};
 pub use crate::discovery::{
     EnvironmentPreference, Error as DiscoveryError, PythonDownloads, PythonNotFound,
     PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
@@ -29,6 +30,7 @@ pub use crate::version_files::{
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
+mod attestation;
 mod discovery;
 pub mod downloads;
 mod environment;
@@ -295,7 +297,8 @@ mod tests {
                     },
                     "pointer_size": "64",
                     "gil_disabled": {FREE_THREADED},
-                    "debug_enabled": false
+                    "debug_enabled": false,
+                    "pymalloc": true
                 }
             "##};
 
@@ -387,7 +390,8 @@ mod tests {
                     },
                     "pointer_size": "32",
                     "gil_disabled": false,
-                    "debug_enabled": false
+                    "debug_enabled": false,
+                    "pymalloc": true
                 }
             "##};
 
@@ -381,9 +381,10 @@ impl ManagedPythonInstallation {
             }
             // PyPy uses a full version number, even on Windows.
             ImplementationName::PyPy => format!("{}.{}", self.key.major, self.key.minor),
-            // Pyodide and GraalPy do not have a version suffix.
+            // Pyodide, GraalPy, and Pyston do not have a version suffix.
             ImplementationName::Pyodide => String::new(),
             ImplementationName::GraalPy => String::new(),
+            ImplementationName::Pyston => String::new(),
         };
 
         // On Windows, the executable is just `python.exe` even for alternative variants
@@ -968,6 +969,71 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
         .ok_or(Error::NoExecutableDirectory)
 }
 
+/// Whether managed Python installations are available to the current user or to all users of the
+/// machine.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PythonInstallScope {
+    /// Install into the user's data directory and executable directory, and register the
+    /// installations under `HKEY_CURRENT_USER` on Windows.
+    #[default]
+    User,
+    /// Install into a machine-wide location, and register the installations under
+    /// `HKEY_LOCAL_MACHINE` on Windows.
+    ///
+    /// Requires elevated privileges, e.g., `sudo` on Unix or an administrator terminal on
+    /// Windows.
+    System,
+}
+
+impl PythonInstallScope {
+    /// The directory to store managed Python installations in, if it differs from the default.
+    ///
+    /// For the system scope, this is `/opt/uv/python` on Unix and `%PROGRAMDATA%\uv\python` on
+    /// Windows.
+    pub fn install_dir(self) -> Option<PathBuf> {
+        match self {
+            Self::User => None,
+            Self::System => Some(system_data_dir().join("python")),
+        }
+    }
+
+    /// Find the directory to install Python executables into.
+    ///
+    /// For the system scope, this is `/usr/local/bin` on Unix and `%PROGRAMDATA%\uv\bin` on
+    /// Windows, unless overridden with `UV_PYTHON_BIN_DIR`.
+    pub fn executable_dir(self) -> Result<PathBuf, Error> {
+        match self {
+            Self::User => python_executable_dir(),
+            Self::System => {
+                if let Some(bin_dir) =
+                    std::env::var_os(EnvVars::UV_PYTHON_BIN_DIR).filter(|s| !s.is_empty())
+                {
+                    return Ok(PathBuf::from(bin_dir));
+                }
+                if cfg!(windows) {
+                    Ok(system_data_dir().join("bin"))
+                } else {
+                    Ok(PathBuf::from("/usr/local/bin"))
+                }
+            }
+        }
+    }
+}
+
+/// The machine-wide data directory for uv, i.e., `/opt/uv` on Unix and `%PROGRAMDATA%\uv` on
+/// Windows.
+fn system_data_dir() -> PathBuf {
+    if cfg!(windows) {
+        std::env::var_os(EnvVars::PROGRAMDATA)
+            .filter(|s| !s.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"))
+            .join("uv")
+    } else {
+        PathBuf::from("/opt/uv")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1195,4 +1261,14 @@ mod tests {
         // Same prerelease should not be an upgrade
         assert!(!prerelease.is_upgrade_of(&prerelease));
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_system_scope_install_dir() {
+        assert_eq!(PythonInstallScope::User.install_dir(), None);
+        assert_eq!(
+            PythonInstallScope::System.install_dir(),
+            Some(PathBuf::from("/opt/uv/python"))
+        );
+    }
 }
@@ -220,12 +220,17 @@ impl PythonVersion {
 }
 
 /// Get the environment variable name for the build constraint for a given implementation.
-pub(crate) fn python_build_version_variable(implementation: ImplementationName) -> &'static str {
+///
+/// Returns `None` for implementations without managed Python downloads.
+pub(crate) fn python_build_version_variable(
+    implementation: ImplementationName,
+) -> Option<&'static str> {
     match implementation {
-        ImplementationName::CPython => EnvVars::UV_PYTHON_CPYTHON_BUILD,
-        ImplementationName::PyPy => EnvVars::UV_PYTHON_PYPY_BUILD,
-        ImplementationName::GraalPy => EnvVars::UV_PYTHON_GRAALPY_BUILD,
-        ImplementationName::Pyodide => EnvVars::UV_PYTHON_PYODIDE_BUILD,
+        ImplementationName::CPython => Some(EnvVars::UV_PYTHON_CPYTHON_BUILD),
+        ImplementationName::PyPy => Some(EnvVars::UV_PYTHON_PYPY_BUILD),
+        ImplementationName::GraalPy => Some(EnvVars::UV_PYTHON_GRAALPY_BUILD),
+        ImplementationName::Pyodide => Some(EnvVars::UV_PYTHON_PYODIDE_BUILD),
+        ImplementationName::Pyston => None,
     }
 }
 
@@ -233,7 +238,9 @@ pub(crate) fn python_build_version_variable(implementation: ImplementationName)
 pub(crate) fn python_build_version_from_env(
     implementation: ImplementationName,
 ) -> Result<Option<String>, BuildVersionError> {
-    let variable = python_build_version_variable(implementation);
+    let Some(variable) = python_build_version_variable(implementation) else {
+        return Ok(None);
+    };
 
     let Some(build_os) = env::var_os(variable) else {
         return Ok(None);
@@ -1,6 +1,6 @@
 //! PEP 514 interactions with the Windows registry.
 
-use crate::managed::ManagedPythonInstallation;
+use crate::managed::{ManagedPythonInstallation, PythonInstallScope};
 use crate::{COMPANY_DISPLAY_NAME, COMPANY_KEY, PythonInstallationKey, PythonVersion};
 use anyhow::anyhow;
 use std::cmp::Ordering;
@@ -131,9 +131,22 @@ pub enum ManagedPep514Error {
     WriteError(#[from] windows::core::Error),
 }
 
+/// The registry root for managed Python installations of the given scope, and its abbreviation
+/// for messages.
+fn scope_root(scope: PythonInstallScope) -> (&'static Key, &'static str) {
+    match scope {
+        PythonInstallScope::User => (CURRENT_USER, "HKCU"),
+        PythonInstallScope::System => (LOCAL_MACHINE, "HKLM"),
+    }
+}
+
 /// Register a managed Python installation in the Windows registry following PEP 514.
+///
+/// Installations in the system scope are registered under `HKEY_LOCAL_MACHINE`, which requires
+/// administrator privileges.
 pub fn create_registry_entry(
     installation: &ManagedPythonInstallation,
+    scope: PythonInstallScope,
 ) -> Result<(), ManagedPep514Error> {
     let pointer_width = match installation.key().arch().family().pointer_width() {
         Ok(PointerWidth::U32) => 32,
@@ -145,7 +158,7 @@ pub fn create_registry_entry(
         }
     };
 
-    write_registry_entry(installation, pointer_width)?;
+    write_registry_entry(installation, pointer_width, scope)?;
 
     Ok(())
 }
@@ -153,12 +166,14 @@ pub fn create_registry_entry(
 fn write_registry_entry(
     installation: &ManagedPythonInstallation,
     pointer_width: i32,
+    scope: PythonInstallScope,
 ) -> windows_registry::Result<()> {
     // We currently just overwrite all known keys, without removing prior entries first
 
-    // Similar to using the bin directory in HOME on Unix, we only install for the current user
-    // on Windows.
-    let company = CURRENT_USER.create(format!("Software\\Python\\{COMPANY_KEY}"))?;
+    // Similar to using the bin directory in HOME on Unix, we install for the current user on
+    // Windows, unless the installation is for all users.
+    let (root, _) = scope_root(scope);
+    let company = root.create(format!("Software\\Python\\{COMPANY_KEY}"))?;
     company.set_string("DisplayName", COMPANY_DISPLAY_NAME)?;
     company.set_string("SupportUrl", "https://github.com/astral-sh/uv")?;
 
@@ -207,12 +222,14 @@ fn registry_python_tag(key: &PythonInstallationKey) -> String {
 pub fn remove_registry_entry<'a>(
     installations: impl IntoIterator<Item = &'a ManagedPythonInstallation>,
     all: bool,
+    scope: PythonInstallScope,
     errors: &mut Vec<(PythonInstallationKey, anyhow::Error)>,
 ) {
+    let (root, root_name) = scope_root(scope);
     let astral_key = format!("Software\\Python\\{COMPANY_KEY}");
     if all {
-        debug!("Removing registry key HKCU:\\{}", astral_key);
-        if let Err(err) = CURRENT_USER.remove_tree(&astral_key) {
+        debug!("Removing registry key {root_name}:\\{astral_key}");
+        if let Err(err) = root.remove_tree(&astral_key) {
             if err.code() == HRESULT::from(ERROR_FILE_NOT_FOUND)
                 || err.code() == HRESULT::from(ERROR_KEY_DELETED)
             {
@@ -227,8 +244,8 @@ pub fn remove_registry_entry<'a>(
     for installation in installations {
         let python_tag = registry_python_tag(installation.key());
         let python_entry = format!("{astral_key}\\{python_tag}");
-        debug!("Removing registry key HKCU:\\{}", python_entry);
-        if let Err(err) = CURRENT_USER.remove_tree(&python_entry) {
+        debug!("Removing registry key {root_name}:\\{python_entry}");
+        if let Err(err) = root.remove_tree(&python_entry) {
             if err.code() == HRESULT::from(ERROR_FILE_NOT_FOUND)
                 || err.code() == HRESULT::from(ERROR_KEY_DELETED)
             {
@@ -240,7 +257,9 @@ pub fn remove_registry_entry<'a>(
             } else {
                 errors.push((
                     installation.key().clone(),
-                    anyhow!("Failed to clear registry entries under HKCU:\\{python_entry}: {err}"),
+                    anyhow!(
+                        "Failed to clear registry entries under {root_name}:\\{python_entry}: {err}"
+                    ),
                 ));
             }
         }
@@ -249,13 +268,17 @@ pub fn remove_registry_entry<'a>(
 
 /// Remove Python entries from the Windows Registry (PEP 514) that are not matching any
 /// installation.
-pub fn remove_orphan_registry_entries(installations: &[ManagedPythonInstallation]) {
+pub fn remove_orphan_registry_entries(
+    installations: &[ManagedPythonInstallation],
+    scope: PythonInstallScope,
+) {
+    let (root, root_name) = scope_root(scope);
     let keep: HashSet<_> = installations
         .iter()
         .map(|installation| registry_python_tag(installation.key()))
         .collect();
     let astral_key = format!("Software\\Python\\{COMPANY_KEY}");
-    let key = match CURRENT_USER.open(&astral_key) {
+    let key = match root.open(&astral_key) {
         Ok(subkeys) => subkeys,
         Err(err)
             if err.code() == HRESULT::from(ERROR_FILE_NOT_FOUND)
@@ -265,7 +288,7 @@ pub fn remove_orphan_registry_entries(installations: &[ManagedPythonInstallation
         }
         Err(err) => {
             // TODO(konsti): We don't have an installation key here.
-            warn_user_once!("Failed to open HKCU:\\{astral_key}: {err}");
+            warn_user_once!("Failed to open {root_name}:\\{astral_key}: {err}");
             return;
         }
     };
@@ -280,7 +303,7 @@ pub fn remove_orphan_registry_entries(installations: &[ManagedPythonInstallation
         }
         Err(err) => {
             // TODO(konsti): We don't have an installation key here.
-            warn_user_once!("Failed to list subkeys of HKCU:\\{astral_key}: {err}");
+            warn_user_once!("Failed to list subkeys of {root_name}:\\{astral_key}: {err}");
             return;
         }
     };
@@ -289,15 +312,17 @@ pub fn remove_orphan_registry_entries(installations: &[ManagedPythonInstallation
             continue;
         }
         let python_entry = format!("{astral_key}\\{subkey}");
-        debug!("Removing orphan registry key HKCU:\\{}", python_entry);
-        if let Err(err) = CURRENT_USER.remove_tree(&python_entry) {
+        debug!("Removing orphan registry key {root_name}:\\{python_entry}");
+        if let Err(err) = root.remove_tree(&python_entry) {
             if err.code() == HRESULT::from(ERROR_FILE_NOT_FOUND)
                 || err.code() == HRESULT::from(ERROR_KEY_DELETED)
             {
                 continue;
             }
             // TODO(konsti): We don't have an installation key here.
-            warn_user_once!("Failed to remove orphan registry key HKCU:\\{python_entry}: {err}");
+            warn_user_once!(
+                "Failed to remove orphan registry key {root_name}:\\{python_entry}: {err}"
+            );
         }
     }
 }
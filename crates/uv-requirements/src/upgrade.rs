@@ -6,9 +6,14 @@ use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
 use uv_fs::CWD;
 use uv_git::ResolvedRepositoryReference;
-use uv_requirements_txt::RequirementsTxt;
+use uv_requirements_txt::{RequirementEntry, RequirementsTxt, RequirementsTxtRequirement};
 use uv_resolver::{Lock, LockError, Preference, PreferenceError, PylockToml, PylockTomlErrorKind};
 
+/// The comment preceding the packages that were excluded from a `requirements.txt` output file
+/// (e.g., with `--no-emit-package`), each of which is listed as a commented-out pin.
+pub const EXCLUDED_PACKAGES_HEADER: &str =
+    "# The following packages were excluded from the output:";
+
 #[derive(Debug, Default)]
 pub struct LockedRequirements {
     /// The pinned versions from the lockfile.
@@ -47,13 +52,18 @@ pub async fn read_requirements_txt(
     .await?;
 
     // Map each entry in the lockfile to a preference.
-    let preferences = requirements_txt
+    let mut preferences = requirements_txt
         .requirements
         .into_iter()
         .map(Preference::from_entry)
         .filter_map(Result::transpose)
         .collect::<Result<Vec<_>, PreferenceError>>()?;
 
+    // Retain the pins of any packages that were excluded from the output, which are only present
+    // as comments.
+    let content = fs_err::tokio::read_to_string(output_file).await?;
+    preferences.extend(excluded_preferences(&content)?);
+
     // Apply the upgrade strategy to the requirements.
     Ok(match upgrade {
         // Respect all pinned versions from the existing lockfile.
@@ -68,6 +78,28 @@ pub async fn read_requirements_txt(
     })
 }
 
+/// Read the pins of the packages listed as excluded in a `requirements.txt` output file, e.g.,
+/// `# setuptools==69.0.0` following the [`EXCLUDED_PACKAGES_HEADER`].
+///
+/// The list ends at the first line that isn't a comment. Entries without an exact version, e.g.,
+/// as written by earlier versions of uv, are skipped.
+fn excluded_preferences(content: &str) -> Result<Vec<Preference>, PreferenceError> {
+    content
+        .lines()
+        .skip_while(|line| line.trim() != EXCLUDED_PACKAGES_HEADER)
+        .skip(1)
+        .map_while(|line| line.trim().strip_prefix('#'))
+        .filter_map(|line| RequirementsTxtRequirement::parse(line.trim(), &*CWD, false).ok())
+        .map(|requirement| {
+            Preference::from_entry(RequirementEntry {
+                requirement,
+                hashes: Vec::new(),
+            })
+        })
+        .filter_map(Result::transpose)
+        .collect()
+}
+
 /// Load the preferred requirements from an existing lockfile, applying the upgrade strategy.
 pub fn read_lock_requirements(
     lock: &Lock,
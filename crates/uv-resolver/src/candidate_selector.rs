@@ -18,7 +18,7 @@ use crate::preferences::{Entry, PreferenceSource, Preferences};
 use crate::prerelease::{AllowPrerelease, PrereleaseStrategy};
 use crate::resolution_mode::ResolutionStrategy;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
+use crate::{Exclusions, Manifest, Options, ResolverEnvironment, UpgradePolicy};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
@@ -26,6 +26,8 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    upgrade_policy: UpgradePolicy,
+    prefer_smaller_artifacts: bool,
 }
 
 impl CandidateSelector {
@@ -49,6 +51,8 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            upgrade_policy: options.upgrade_policy,
+            prefer_smaller_artifacts: options.prefer_smaller_artifacts,
         }
     }
 
@@ -114,6 +118,20 @@ impl CandidateSelector {
             return Some(preferred);
         }
 
+        // If the locked version is no longer compatible, prefer a version with the same major
+        // version, if requested.
+        if !upgrade {
+            if let Some(same_major) =
+                self.get_same_major(package_name, range, version_maps, preferences, env)
+            {
+                trace!(
+                    "Using {} {} within the major version of the preference",
+                    same_major.name, same_major.version
+                );
+                return Some(same_major);
+            }
+        }
+
         // If we don't have a preference, find an already-installed distribution that satisfies the
         // range.
         let installed = if reinstall {
@@ -143,6 +161,7 @@ impl CandidateSelector {
         if let Some(installed) = installed {
             if compatible.as_ref().is_none_or(|compatible| {
                 let highest = self.use_highest_version(package_name, env);
+                let prefer_smaller = self.prefer_smaller_artifacts;
                 if highest {
                     installed.version() >= compatible.version()
                 } else {
@@ -239,6 +258,41 @@ impl CandidateSelector {
         )
     }
 
+    /// If the [`UpgradePolicy`] requires it, select the best candidate that shares a major version
+    /// with the package's locked version.
+    ///
+    /// Returns `None` if the package isn't locked, or if no version within the locked major version
+    /// satisfies the current range.
+    fn get_same_major<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        preferences: &'a Preferences,
+        env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        if self.upgrade_policy != UpgradePolicy::SameMajor {
+            return None;
+        }
+
+        preferences
+            .get(package_name)
+            .iter()
+            .filter(|entry| entry.source() == PreferenceSource::Lock)
+            .filter(|entry| env.included_by_marker(entry.marker().pep508()))
+            .find_map(|entry| {
+                let major = entry.pin().version().release().first().copied()?;
+                let same_major = range.intersection(&Range::from_range_bounds(
+                    Version::new([major]).with_min(Some(0))
+                        ..Version::new([major + 1]).with_min(Some(0)),
+                ));
+                if same_major.is_empty() {
+                    return None;
+                }
+                self.select_no_preference(package_name, &same_major, version_maps, env)
+            })
+    }
+
     /// Return the first preference that satisfies the current range and is allowed.
     fn get_preferred_from_iter<'a, InstalledPackages: InstalledPackagesProvider>(
         &'a self,
@@ -432,7 +486,11 @@ impl CandidateSelector {
             AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
         };
 
-        if self.index_strategy == IndexStrategy::UnsafeBestMatch {
+        if matches!(
+            self.index_strategy,
+            IndexStrategy::UnsafeBestMatch | IndexStrategy::MergeLatestCompatible
+        ) {
+            let index_strategy = self.index_strategy;
             if highest {
                 Self::select_candidate(
                     version_maps
@@ -444,19 +502,22 @@ impl CandidateSelector {
                                 .rev()
                                 .map(move |item| (map_index, item))
                         })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
+                        .kmerge_by(|(index1, (version1, dist1)), (index2, (version2, dist2))| {
+                            match version1.cmp(version2) {
+                                std::cmp::Ordering::Equal => Self::prefer_index(
+                                    index_strategy,
+                                    (*index1, dist1),
+                                    (*index2, dist2),
+                                ),
                                 std::cmp::Ordering::Less => false,
                                 std::cmp::Ordering::Greater => true,
-                            },
-                        )
+                            }
+                        })
                         .map(|(_, item)| item),
                     package_name,
                     range,
                     allow_prerelease,
+                    prefer_smaller,
                 )
             } else {
                 Self::select_candidate(
@@ -466,19 +527,22 @@ impl CandidateSelector {
                         .map(|(map_index, version_map)| {
                             version_map.iter(range).map(move |item| (map_index, item))
                         })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
+                        .kmerge_by(|(index1, (version1, dist1)), (index2, (version2, dist2))| {
+                            match version1.cmp(version2) {
+                                std::cmp::Ordering::Equal => Self::prefer_index(
+                                    index_strategy,
+                                    (*index1, dist1),
+                                    (*index2, dist2),
+                                ),
                                 std::cmp::Ordering::Less => true,
                                 std::cmp::Ordering::Greater => false,
-                            },
-                        )
+                            }
+                        })
                         .map(|(_, item)| item),
                     package_name,
                     range,
                     allow_prerelease,
+                    prefer_smaller,
                 )
             }
         } else {
@@ -489,6 +553,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        prefer_smaller,
                     )
                 })
             } else {
@@ -498,12 +563,37 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        prefer_smaller,
                     )
                 })
             }
         }
     }
 
+    /// Returns `true` if the entry for a version from the first index should be considered before
+    /// the entry for the same version from the second index.
+    ///
+    /// With `--index-strategy merge-latest-compatible`, the index with the most compatible wheels is
+    /// preferred; otherwise (and in the case of a tie), the earlier index is preferred.
+    fn prefer_index(
+        index_strategy: IndexStrategy,
+        (index1, dist1): (usize, &VersionMapDistHandle),
+        (index2, dist2): (usize, &VersionMapDistHandle),
+    ) -> bool {
+        if index_strategy == IndexStrategy::MergeLatestCompatible {
+            let wheels1 = dist1
+                .prioritized_dist()
+                .map_or(0, PrioritizedDist::compatible_wheels);
+            let wheels2 = dist2
+                .prioritized_dist()
+                .map_or(0, PrioritizedDist::compatible_wheels);
+            if wheels1 != wheels2 {
+                return wheels1 > wheels2;
+            }
+        }
+        index1 < index2
+    }
+
     /// By default, we select the latest version, but we also allow using the lowest version instead
     /// to check the lower bounds.
     pub(crate) fn use_highest_version(
@@ -526,17 +616,35 @@ impl CandidateSelector {
     /// The returned [`Candidate`] _may not_ be compatible with the current platform; in such
     /// cases, the resolver is responsible for tracking the incompatibility and re-running the
     /// selection process with additional constraints.
+    ///
+    /// If `prefer_smaller` is set, the variants of the first compatible version that only differ
+    /// in their local version segment (e.g., `2.4.0+cpu` and `2.4.0+cu121`) are compared, and
+    /// the variant with the smallest artifact is selected.
     fn select_candidate<'a>(
         versions: impl Iterator<Item = (&'a Version, VersionMapDistHandle<'a>)>,
         package_name: &'a PackageName,
         range: &Range<Version>,
         allow_prerelease: bool,
+        prefer_smaller: bool,
     ) -> Option<Candidate<'a>> {
         let mut steps = 0usize;
         let mut incompatible: Option<Candidate> = None;
+        let mut selected: Option<Candidate> = None;
         for (version, maybe_dist) in versions {
             steps += 1;
 
+            // If we have a compatible candidate, and we've progressed past its variants, return it.
+            if let Some(candidate) = selected.take() {
+                if version.clone().without_local() != candidate.version.clone().without_local() {
+                    trace!(
+                        "Returning smallest variant for package {package_name} with range {range} after {steps} steps: {} version",
+                        candidate.version
+                    );
+                    return Some(candidate);
+                }
+                selected = Some(candidate);
+            }
+
             // If we have an incompatible candidate, and we've progressed past it, return it.
             if incompatible
                 .as_ref()
@@ -603,16 +711,37 @@ impl CandidateSelector {
             // return the first _compatible_ candidate across all indexes, if such a candidate
             // exists.
             if matches!(candidate.dist(), CandidateDist::Incompatible { .. }) {
-                if incompatible.is_none() {
+                if incompatible.is_none() && selected.is_none() {
                     incompatible = Some(candidate);
                 }
                 continue;
             }
 
+            if !prefer_smaller {
+                trace!(
+                    "Returning candidate for package {package_name} with range {range} after {steps} steps",
+                );
+                return Some(candidate);
+            }
+
+            // Keep the variant with the smallest artifact, continuing with the remaining variants.
+            let artifact_size =
+                |candidate: &Candidate| candidate.compatible().and_then(CompatibleDist::size);
+            if selected.as_ref().is_none_or(|selected| {
+                artifact_size(&candidate).is_some_and(|size| {
+                    artifact_size(selected).is_some_and(|selected_size| size < selected_size)
+                })
+            }) {
+                selected = Some(candidate);
+            }
+            incompatible = None;
+        }
+
+        if selected.is_some() {
             trace!(
-                "Returning candidate for package {package_name} with range {range} after {steps} steps",
+                "Returning smallest variant for package {package_name} with range {range} after {steps} steps",
             );
-            return Some(candidate);
+            return selected;
         }
 
         if incompatible.is_some() {
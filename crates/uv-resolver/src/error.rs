@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 
 use indexmap::IndexSet;
 use itertools::Itertools;
@@ -27,6 +28,7 @@ use crate::dependency_provider::UvDependencyProvider;
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_urls::ForkUrls;
 use crate::prerelease::AllowPrerelease;
+use crate::provenance::RequirementProvenance;
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
@@ -157,6 +159,9 @@ pub enum ResolveError {
         expected: PackageName,
         actual: PackageName,
     },
+
+    #[error(transparent)]
+    BudgetExceeded(#[from] Box<BudgetExceededError>),
 }
 
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
@@ -167,6 +172,92 @@ impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
     }
 }
 
+/// A resolution budget that was exhausted, as configured via `max-backtracks` or
+/// `resolution-timeout`.
+#[derive(Debug, Clone)]
+pub enum ResolutionBudget {
+    /// The decisions on a single package were discarded more often than allowed.
+    Backtracks {
+        package: PackageName,
+        backtracks: usize,
+        limit: usize,
+    },
+    /// The resolution took longer than allowed.
+    Timeout(Duration),
+}
+
+/// An error for a resolution that was aborted after exhausting its [`ResolutionBudget`], along with
+/// the packages that were involved in the most conflicts up to that point.
+#[derive(Debug)]
+pub struct BudgetExceededError {
+    budget: ResolutionBudget,
+    contested: Vec<(PackageName, usize)>,
+}
+
+impl BudgetExceededError {
+    pub(crate) fn new(budget: ResolutionBudget, contested: Vec<(PackageName, usize)>) -> Self {
+        Self { budget, contested }
+    }
+
+    /// The budget that was exhausted.
+    pub fn budget(&self) -> &ResolutionBudget {
+        &self.budget
+    }
+
+    /// The packages involved in the most conflicts, most contested first, with the number of
+    /// conflicts they were involved in.
+    pub fn contested(&self) -> &[(PackageName, usize)] {
+        &self.contested
+    }
+}
+
+impl std::error::Error for BudgetExceededError {}
+
+impl std::fmt::Display for BudgetExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.budget {
+            ResolutionBudget::Backtracks {
+                package,
+                backtracks,
+                limit,
+            } => write!(
+                f,
+                "Resolution aborted after backtracking on `{}` {backtracks} times (limit: {limit})",
+                package.cyan(),
+            )?,
+            ResolutionBudget::Timeout(timeout) => write!(
+                f,
+                "Resolution aborted after exceeding the timeout of {}s",
+                timeout.as_secs()
+            )?,
+        }
+
+        if self.contested.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "\n\nThe most contested packages were:")?;
+        for (package, conflicts) in &self.contested {
+            write!(
+                f,
+                "\n  - `{}` ({conflicts} {})",
+                package.cyan(),
+                if *conflicts == 1 {
+                    "conflict"
+                } else {
+                    "conflicts"
+                }
+            )?;
+        }
+        write!(
+            f,
+            "\n\n{}{} Consider adding constraints for the contested packages to narrow the search space",
+            "hint".bold().cyan(),
+            ":".bold(),
+        )
+    }
+}
+
 pub type ErrorTree = DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>;
 
 /// A wrapper around [`pubgrub::error::NoSolutionError`] that displays a resolution failure report.
@@ -188,6 +279,7 @@ pub struct NoSolutionError {
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
     options: Options,
+    provenance: Vec<RequirementProvenance>,
 }
 
 impl NoSolutionError {
@@ -210,6 +302,7 @@ impl NoSolutionError {
         tags: Option<Tags>,
         workspace_members: BTreeSet<PackageName>,
         options: Options,
+        provenance: Vec<RequirementProvenance>,
     ) -> Self {
         Self {
             error,
@@ -229,6 +322,7 @@ impl NoSolutionError {
             tags,
             workspace_members,
             options,
+            provenance,
         }
     }
 
@@ -421,6 +515,12 @@ impl NoSolutionError {
         Ok(())
     }
 
+    /// Get the chains of sources (e.g., files, dependency groups, and other packages) that
+    /// introduced the requirements involved in this error.
+    pub fn provenance(&self) -> &[RequirementProvenance] {
+        &self.provenance
+    }
+
     /// Get the packages that are involved in this error.
     pub fn packages(&self) -> impl Iterator<Item = &PackageName> {
         self.error
@@ -452,6 +552,7 @@ impl std::fmt::Debug for NoSolutionError {
             tags,
             workspace_members,
             options,
+            provenance,
         } = self;
         f.debug_struct("NoSolutionError")
             .field("error", error)
@@ -470,6 +571,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("tags", tags)
             .field("workspace_members", workspace_members)
             .field("options", options)
+            .field("provenance", provenance)
             .finish()
     }
 }
@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use pubgrub::Ranges;
+
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+
+use crate::pubgrub::{PubGrubDependency, PubGrubPackage, PubGrubPackageInner};
+
+/// One side of a [`KnownIncompatibility`], e.g., `pandas==2.1.*` or `numpy>=2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncompatibleRequirement {
+    pub name: PackageName,
+    pub specifiers: VersionSpecifiers,
+}
+
+impl IncompatibleRequirement {
+    /// Returns `true` if the given package version is covered by this requirement.
+    pub fn contains(&self, name: &PackageName, version: &Version) -> bool {
+        self.name == *name && self.specifiers.contains(version)
+    }
+}
+
+impl FromStr for IncompatibleRequirement {
+    type Err = String;
+
+    /// Parses an [`IncompatibleRequirement`] from a string in the format `PACKAGE[SPECIFIERS]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, specifiers) = s
+            .find(|c: char| matches!(c, '<' | '>' | '=' | '!' | '~' | ' '))
+            .map_or((s, ""), |index| s.split_at(index));
+
+        let name = PackageName::from_str(name).map_err(|err| {
+            format!("Invalid `known-incompatibilities` package name `{name}`: {err}")
+        })?;
+        let specifiers = VersionSpecifiers::from_str(specifiers.trim()).map_err(|err| {
+            format!("Invalid `known-incompatibilities` version specifiers `{specifiers}`: {err}")
+        })?;
+
+        Ok(Self { name, specifiers })
+    }
+}
+
+impl std::fmt::Display for IncompatibleRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.name, self.specifiers)
+    }
+}
+
+impl serde::Serialize for IncompatibleRequirement {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IncompatibleRequirement {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for IncompatibleRequirement {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("IncompatibleRequirement")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A package name with optional version specifiers, e.g., `numpy>=2`.",
+        })
+    }
+}
+
+/// A pair of package version ranges that are known not to work together, even though their
+/// metadata permits it (e.g., `pandas==2.1.*` with `numpy>=2`).
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct KnownIncompatibility {
+    /// The package versions that are incompatible, e.g., `pandas==2.1.*`.
+    pub package: IncompatibleRequirement,
+    /// The package versions that `package` is incompatible with, e.g., `numpy>=2`.
+    pub incompatible_with: IncompatibleRequirement,
+    /// An explanation of the incompatibility, shown when it causes a resolution failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl KnownIncompatibility {
+    /// Returns the dependency that enforces this incompatibility for the given package version,
+    /// if the version is covered by either side of it.
+    ///
+    /// Each incompatibility is modeled as a virtual package with two versions: versions covered by
+    /// `package` depend on `0`, and versions covered by `incompatible-with` depend on `1`. Since
+    /// only one version of the virtual package can be selected, the resolver can't select versions
+    /// from both sides at once.
+    pub(crate) fn dependency(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Option<PubGrubDependency> {
+        let side = if self.package.contains(name, version) {
+            Version::new([0])
+        } else if self.incompatible_with.contains(name, version) {
+            Version::new([1])
+        } else {
+            return None;
+        };
+        Some(PubGrubDependency {
+            package: PubGrubPackage::from(PubGrubPackageInner::KnownIncompatibility(Box::new(
+                self.clone(),
+            ))),
+            version: Ranges::singleton(side),
+            parent: None,
+            url: None,
+        })
+    }
+
+    /// Returns the side of this incompatibility that conflicts with the given package.
+    pub(crate) fn counterpart(&self, name: &PackageName) -> &IncompatibleRequirement {
+        if self.package.name == *name {
+            &self.incompatible_with
+        } else {
+            &self.package
+        }
+    }
+}
+
+impl std::fmt::Display for KnownIncompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} | {}", self.package, self.incompatible_with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::IncompatibleRequirement;
+
+    #[test]
+    fn parse() {
+        let requirement = IncompatibleRequirement::from_str("pandas==2.1.*").unwrap();
+        assert_eq!(requirement.to_string(), "pandas==2.1.*");
+        assert!(requirement.contains(&requirement.name, &Version::new([2, 1, 4])));
+        assert!(!requirement.contains(&requirement.name, &Version::new([2, 2, 0])));
+
+        let requirement = IncompatibleRequirement::from_str("numpy >=2, <3").unwrap();
+        assert_eq!(requirement.to_string(), "numpy>=2, <3");
+
+        let requirement = IncompatibleRequirement::from_str("numpy").unwrap();
+        assert!(requirement.contains(&requirement.name, &Version::new([1, 26, 4])));
+
+        assert!(IncompatibleRequirement::from_str("numpy[extra]>=2").is_err());
+        assert!(IncompatibleRequirement::from_str("numpy>=two").is_err());
+    }
+}
@@ -1,23 +1,29 @@
 pub use dependency_mode::DependencyMode;
-pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    BudgetExceededError, ErrorTree, NoSolutionError, NoSolutionHeader, ResolutionBudget,
+    ResolveError, SentinelRange,
+};
 pub use exclude_newer::{
     ExcludeNewer, ExcludeNewerPackage, ExcludeNewerPackageEntry, ExcludeNewerTimestamp,
 };
 pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
+pub use known_incompatibilities::{IncompatibleRequirement, KnownIncompatibility};
 pub use lock::{
-    Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
-    PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
-    VERSION, cyclonedx_json,
+    Installable, Lock, LockError, LockVersion, ManifestPatch, MarkerProvenance, Package,
+    PackageMap, PylockToml, PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest,
+    SatisfiesResult, TreeDisplay, TreeExplorer, TreeRow, TreeRowState, VERSION, cyclonedx_json,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
+pub use provenance::{ProvenanceSource, ProvenanceStep, RequirementProvenance};
 pub use python_requirement::PythonRequirement;
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph,
+    RequiresPythonDiagnostic, ResolverOutput, infer_requires_python_upper_bound,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
@@ -26,8 +32,9 @@ pub use resolver::{
     ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
+pub use upgrade_policy::UpgradePolicy;
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use yanks::{AllowedYank, AllowedYanks, YankedPolicy};
 
 /// A custom `HashSet` using `hashbrown`.
 ///
@@ -49,6 +56,7 @@ mod fork_indexes;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
+mod known_incompatibilities;
 mod lock;
 mod manifest;
 mod marker;
@@ -56,6 +64,7 @@ mod options;
 mod pins;
 mod preferences;
 mod prerelease;
+mod provenance;
 pub mod pubgrub;
 mod python_requirement;
 mod redirect;
@@ -63,5 +72,6 @@ mod resolution;
 mod resolution_mode;
 mod resolver;
 mod universal_marker;
+mod upgrade_policy;
 mod version_map;
 mod yanks;
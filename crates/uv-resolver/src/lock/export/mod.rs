@@ -12,7 +12,7 @@ use uv_configuration::{
     DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep508::MarkerTree;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::{Reachable, marker_reachability};
@@ -338,6 +338,19 @@ impl<'lock> ExportableRequirements<'lock> {
 
         Ok(Self(nodes))
     }
+
+    /// Retain only the requirements that apply to the given [`MarkerEnvironment`].
+    ///
+    /// If `strip_markers` is set, the markers of the retained requirements are removed.
+    fn filter(&mut self, markers: &MarkerEnvironment, strip_markers: bool) {
+        self.0
+            .retain(|requirement| requirement.marker.evaluate(markers, &[]));
+        if strip_markers {
+            for requirement in &mut self.0 {
+                requirement.marker = MarkerTree::TRUE;
+            }
+        }
+    }
 }
 
 /// A node in the graph.
@@ -622,9 +622,11 @@ impl<'lock> PylockToml {
         annotate: bool,
         editable: Option<EditableMode>,
         install_options: &'lock InstallOptions,
+        markers: Option<&MarkerEnvironment>,
+        strip_markers: bool,
     ) -> Result<Self, PylockTomlErrorKind> {
         // Extract the packages from the lock file.
-        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+        let mut requirements = ExportableRequirements::from_lock(
             target,
             prune,
             extras,
@@ -633,6 +635,12 @@ impl<'lock> PylockToml {
             install_options,
         )?;
 
+        // Filter the requirements to the target environment, if any.
+        if let Some(markers) = markers {
+            requirements.filter(markers, strip_markers);
+        }
+        let ExportableRequirements(mut nodes) = requirements;
+
         // Sort the nodes.
         nodes.sort_unstable_by_key(|node| &node.package.id);
 
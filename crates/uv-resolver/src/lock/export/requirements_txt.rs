@@ -3,6 +3,7 @@ use std::fmt::Formatter;
 use std::path::{Component, Path, PathBuf};
 
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use url::Url;
 
 use uv_configuration::{
@@ -12,6 +13,7 @@ use uv_distribution_filename::{DistExtension, SourceDistExtension};
 use uv_fs::Simplified;
 use uv_git_types::GitReference;
 use uv_normalize::PackageName;
+use uv_pep508::MarkerEnvironment;
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -37,9 +39,11 @@ impl<'lock> RequirementsTxtExport<'lock> {
         editable: Option<EditableMode>,
         hashes: bool,
         install_options: &'lock InstallOptions,
+        markers: Option<&MarkerEnvironment>,
+        strip_markers: bool,
     ) -> Result<Self, LockError> {
         // Extract the packages from the lock file.
-        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+        let mut requirements = ExportableRequirements::from_lock(
             target,
             prune,
             extras,
@@ -48,6 +52,12 @@ impl<'lock> RequirementsTxtExport<'lock> {
             install_options,
         )?;
 
+        // Filter the requirements to the target environment, if any.
+        if let Some(markers) = markers {
+            requirements.filter(markers, strip_markers);
+        }
+        let ExportableRequirements(mut nodes) = requirements;
+
         // Sort the nodes, such that unnamed URLs (editables) appear at the top.
         nodes.sort_unstable_by(|a, b| {
             RequirementComparator::from(a.package).cmp(&RequirementComparator::from(b.package))
@@ -59,6 +69,38 @@ impl<'lock> RequirementsTxtExport<'lock> {
             editable,
         })
     }
+
+    /// Remove any requirements that are also present in the given export.
+    ///
+    /// This is used to export a dependency group as an addendum to a requirements file that
+    /// already contains the project's dependencies.
+    #[must_use]
+    pub fn without(mut self, base: &Self) -> Self {
+        let existing = base
+            .nodes
+            .iter()
+            .map(|node| (&node.package.id, node.marker))
+            .collect::<FxHashSet<_>>();
+        self.nodes
+            .retain(|node| !existing.contains(&(&node.package.id, node.marker)));
+        self
+    }
+
+    /// Remove any requirements that can't be used as constraints, i.e., local paths and
+    /// editables, such as the workspace members themselves.
+    ///
+    /// This is used to export the resolution as a constraints file, which pins every package
+    /// without requiring any of them.
+    #[must_use]
+    pub fn into_constraints(mut self) -> Self {
+        self.nodes.retain(|node| {
+            matches!(
+                node.package.id.source,
+                Source::Registry(_) | Source::Git(..) | Source::Direct(..)
+            )
+        });
+        self
+    }
 }
 
 impl std::fmt::Display for RequirementsTxtExport<'_> {
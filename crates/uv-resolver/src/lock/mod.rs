@@ -27,9 +27,9 @@ use uv_distribution_filename::{
 use uv_distribution_types::{
     BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
     Dist, DistributionMetadata, FileLocation, GitSourceDist, IndexLocations, IndexMetadata,
-    IndexUrl, Name, PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel,
-    RegistrySourceDist, RemoteSource, Requirement, RequirementSource, RequiresPython, ResolvedDist,
-    SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
+    IndexUrl, Name, Patch, Patches, PathBuiltDist, PathSourceDist, RegistryBuiltDist,
+    RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Requirement, RequirementSource,
+    RequiresPython, ResolvedDist, SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{PortablePath, PortablePathBuf, relative_to};
 use uv_git::{RepositoryReference, ResolvedRepositoryReference};
@@ -55,12 +55,13 @@ pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{PylockToml, PylockTomlErrorKind, cyclonedx_json};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
-pub use crate::lock::tree::TreeDisplay;
+pub use crate::lock::tree::{TreeDisplay, TreeExplorer, TreeRow, TreeRowState};
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
-    ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, InMemoryIndex, MetadataResponse,
-    PrereleaseMode, ResolutionMode, ResolverOutput,
+    AllowedYank, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, InMemoryIndex,
+    KnownIncompatibility, MetadataResponse, PrereleaseMode, ResolutionMode, ResolverOutput,
+    YankedPolicy,
 };
 
 mod export;
@@ -262,7 +263,23 @@ impl Lock {
                 vec![]
             };
 
+            // Record the marker under which the package is installed, along with the markers of
+            // the forks that selected it. Packages that are installed in all environments, outside
+            // of any fork, are omitted.
+            let marker_provenance = MarkerProvenance {
+                marker: SimplifiedMarkerTree::new(&requires_python, dist.marker.combined()),
+                forks: resolution
+                    .fork_markers
+                    .iter()
+                    .filter(|fork_markers| !fork_markers.is_disjoint(dist.marker))
+                    .copied()
+                    .collect(),
+            };
+
             let mut package = Package::from_annotated_dist(dist, fork_markers, root)?;
+            if !marker_provenance.is_empty() {
+                package.marker_provenance = Some(marker_provenance);
+            }
             Self::remove_unreachable_wheels(resolution, &requires_python, node_index, &mut package);
 
             // Add all dependencies
@@ -347,6 +364,10 @@ impl Lock {
             resolution_mode: resolution.options.resolution_mode,
             prerelease_mode: resolution.options.prerelease_mode,
             fork_strategy: resolution.options.fork_strategy,
+            yanked_policy: resolution.options.yanked_policy,
+            allow_yanked: resolution.options.allow_yanked.clone(),
+            known_incompatibilities: resolution.options.known_incompatibilities.clone(),
+            marker_provenance: false,
             exclude_newer: resolution.options.exclude_newer.clone().into(),
         };
         let lock = Self::new(
@@ -747,6 +768,23 @@ impl Lock {
         self
     }
 
+    /// Retain the marker provenance of each package if `enabled`; otherwise, discard it.
+    #[must_use]
+    pub fn with_marker_provenance(mut self, enabled: bool) -> Self {
+        self.options.marker_provenance = enabled;
+        if !enabled {
+            for package in &mut self.packages {
+                package.marker_provenance = None;
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if this [`Lock`] records the provenance of each package's markers.
+    pub fn has_marker_provenance(&self) -> bool {
+        self.options.marker_provenance
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -805,6 +843,21 @@ impl Lock {
         self.options.fork_strategy
     }
 
+    /// Returns the yanked-version policy used to generate this lock.
+    pub fn yanked_policy(&self) -> YankedPolicy {
+        self.options.yanked_policy
+    }
+
+    /// Returns the yanked versions that were explicitly allowed when generating this lock.
+    pub fn allow_yanked(&self) -> &[AllowedYank] {
+        &self.options.allow_yanked
+    }
+
+    /// Returns the known incompatibilities that were enforced when generating this lock.
+    pub fn known_incompatibilities(&self) -> &[KnownIncompatibility] {
+        &self.options.known_incompatibilities
+    }
+
     /// Returns the exclude newer setting used to generate this lock.
     pub fn exclude_newer(&self) -> ExcludeNewer {
         // TODO(zanieb): It'd be nice not to hide this clone here, but I am hesitant to introduce
@@ -842,6 +895,26 @@ impl Lock {
         &self.manifest.dependency_groups
     }
 
+    /// Returns the constraints that were used to generate this lock.
+    pub fn constraints(&self) -> &BTreeSet<Requirement> {
+        &self.manifest.constraints
+    }
+
+    /// Returns the overrides that were used to generate this lock.
+    pub fn overrides(&self) -> &BTreeSet<Requirement> {
+        &self.manifest.overrides
+    }
+
+    /// Returns the excludes that were used to generate this lock.
+    pub fn excludes(&self) -> &BTreeSet<PackageName> {
+        &self.manifest.excludes
+    }
+
+    /// Returns the dependencies provided by the environment that were used to generate this lock.
+    pub fn provided(&self) -> &BTreeSet<Requirement> {
+        &self.manifest.provided
+    }
+
     /// Returns the build constraints that were used to generate this lock.
     pub fn build_constraints(&self, root: &Path) -> Constraints {
         Constraints::from_requirements(
@@ -1056,6 +1129,42 @@ impl Lock {
                     value(self.options.fork_strategy.to_string()),
                 );
             }
+            if self.options.yanked_policy != YankedPolicy::default() {
+                options_table.insert(
+                    "yanked-policy",
+                    value(self.options.yanked_policy.to_string()),
+                );
+            }
+            if !self.options.allow_yanked.is_empty() {
+                let allow_yanked = each_element_on_its_line_array(
+                    self.options.allow_yanked.iter().map(ToString::to_string),
+                );
+                options_table.insert("allow-yanked", value(allow_yanked));
+            }
+            if !self.options.known_incompatibilities.is_empty() {
+                // The reason is informational, so it isn't recorded in the lockfile.
+                let known_incompatibilities = each_element_on_its_line_array(
+                    self.options
+                        .known_incompatibilities
+                        .iter()
+                        .map(|incompatibility| {
+                            let mut table = InlineTable::new();
+                            table.insert(
+                                "package",
+                                Value::from(incompatibility.package.to_string()),
+                            );
+                            table.insert(
+                                "incompatible-with",
+                                Value::from(incompatibility.incompatible_with.to_string()),
+                            );
+                            table
+                        }),
+                );
+                options_table.insert("known-incompatibilities", value(known_incompatibilities));
+            }
+            if self.options.marker_provenance {
+                options_table.insert("marker-provenance", value(true));
+            }
             let exclude_newer = ExcludeNewer::from(self.options.exclude_newer.clone());
             if !exclude_newer.is_empty() {
                 // Always serialize global exclude-newer as a string
@@ -1171,6 +1280,26 @@ impl Lock {
                 manifest_table.insert("excludes", value(excludes));
             }
 
+            if !self.manifest.provided.is_empty() {
+                let provided = self
+                    .manifest
+                    .provided
+                    .iter()
+                    .map(|requirement| {
+                        serde::Serialize::serialize(
+                            &requirement,
+                            toml_edit::ser::ValueSerializer::new(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let provided = match provided.as_slice() {
+                    [] => Array::new(),
+                    [requirement] => Array::from_iter([requirement]),
+                    provided => each_element_on_its_line_array(provided.iter()),
+                };
+                manifest_table.insert("provided", value(provided));
+            }
+
             if !self.manifest.build_constraints.is_empty() {
                 let build_constraints = self
                     .manifest
@@ -1225,6 +1354,9 @@ impl Lock {
                     if let Some(version) = metadata.version.as_ref() {
                         table.insert("version", value(version.to_string()));
                     }
+                    if let Some(versions) = metadata.versions.as_ref() {
+                        table.insert("versions", value(versions.to_string()));
+                    }
                     if !metadata.requires_dist.is_empty() {
                         table.insert(
                             "requires-dist",
@@ -1246,11 +1378,44 @@ impl Lock {
                             )?),
                         );
                     }
+                    if !metadata.add_requires_dist.is_empty() {
+                        table.insert(
+                            "add-requires-dist",
+                            value(serde::Serialize::serialize(
+                                &metadata.add_requires_dist,
+                                toml_edit::ser::ValueSerializer::new(),
+                            )?),
+                        );
+                    }
+                    if !metadata.remove_requires_dist.is_empty() {
+                        table.insert(
+                            "remove-requires-dist",
+                            value(serde::Serialize::serialize(
+                                &metadata.remove_requires_dist,
+                                toml_edit::ser::ValueSerializer::new(),
+                            )?),
+                        );
+                    }
                     tables.push(table);
                 }
                 manifest_table.insert("dependency-metadata", Item::ArrayOfTables(tables));
             }
 
+            if !self.manifest.patches.is_empty() {
+                let mut tables = ArrayOfTables::new();
+                for patch in &self.manifest.patches {
+                    let mut table = Table::new();
+                    table.insert("name", value(patch.name.to_string()));
+                    if let Some(version) = patch.version.as_ref() {
+                        table.insert("version", value(version.to_string()));
+                    }
+                    table.insert("path", value(PortablePath::from(&patch.path).to_string()));
+                    table.insert("hash", value(patch.hash.to_string()));
+                    tables.push(table);
+                }
+                manifest_table.insert("patches", Item::ArrayOfTables(tables));
+            }
+
             if !manifest_table.is_empty() {
                 doc.insert("manifest", Item::Table(manifest_table));
             }
@@ -1456,9 +1621,11 @@ impl Lock {
         constraints: &[Requirement],
         overrides: &[Requirement],
         excludes: &[PackageName],
+        provided: &[Requirement],
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
+        patches: &Patches,
         indexes: Option<&IndexLocations>,
         tags: &Tags,
         markers: &MarkerEnvironment,
@@ -1581,6 +1748,25 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same provided dependencies.
+        {
+            let expected: BTreeSet<_> = provided
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            let actual: BTreeSet<_> = self
+                .manifest
+                .provided
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            if expected != actual {
+                return Ok(SatisfiesResult::MismatchedProvided(expected, actual));
+            }
+        }
+
         // Validate that the lockfile was generated with the same build constraints.
         {
             let expected: BTreeSet<_> = build_constraints
@@ -1657,6 +1843,19 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same patches.
+        {
+            let expected = patches
+                .values()
+                .map(|patch| ManifestPatch::from(patch).relative_to(root))
+                .collect::<Result<BTreeSet<_>, _>>()
+                .map_err(LockErrorKind::DistributionRelativePath)?;
+            let actual = &self.manifest.patches;
+            if expected != *actual {
+                return Ok(SatisfiesResult::MismatchedPatches(expected, actual));
+            }
+        }
+
         // Collect the set of available indexes (both `--index-url` and `--find-links` entries).
         let mut remotes = indexes.map(|locations| {
             locations
@@ -1689,6 +1888,29 @@ impl Lock {
                 .collect::<BTreeSet<_>>()
         });
 
+        // Collect the packages that are explicitly pinned to an index by a requirement, which take
+        // precedence over any index `packages` patterns.
+        let explicitly_pinned = self
+            .packages
+            .iter()
+            .flat_map(|package| {
+                package
+                    .metadata
+                    .requires_dist
+                    .iter()
+                    .chain(package.metadata.dependency_groups.values().flatten())
+            })
+            .chain(requirements)
+            .chain(dependency_groups.values().flatten())
+            .filter(|requirement| {
+                matches!(
+                    requirement.source,
+                    RequirementSource::Registry { index: Some(_), .. }
+                )
+            })
+            .map(|requirement| &requirement.name)
+            .collect::<FxHashSet<_>>();
+
         // Add the workspace packages to the queue.
         for root_name in packages.keys() {
             let root = self
@@ -1736,6 +1958,43 @@ impl Lock {
                 }
             }
 
+            // If the package is restricted to an index by name, validate that it was locked from
+            // that index.
+            if let (Some(locations), Source::Registry(source)) = (indexes, &package.id.source) {
+                if let Some(pinned) = locations
+                    .pinned_index(&package.id.name)
+                    .filter(|_| !explicitly_pinned.contains(&package.id.name))
+                {
+                    let matches = match (source, pinned.url()) {
+                        (RegistrySource::Url(url), IndexUrl::Pypi(_) | IndexUrl::Url(_)) => {
+                            *url == UrlString::from(pinned.url().without_credentials().as_ref())
+                        }
+                        (RegistrySource::Path(path), IndexUrl::Path(url)) => url
+                            .to_file_path()
+                            .ok()
+                            .and_then(|url| {
+                                relative_to(&url, root)
+                                    .or_else(|_| std::path::absolute(url))
+                                    .ok()
+                            })
+                            .is_some_and(|url| url == **path),
+                        _ => false,
+                    };
+                    if !matches {
+                        let version = package
+                            .id
+                            .version
+                            .as_ref()
+                            .expect("version for registry source");
+                        return Ok(SatisfiesResult::MismatchedPinnedIndex(
+                            &package.id.name,
+                            version,
+                            pinned.url().clone(),
+                        ));
+                    }
+                }
+            }
+
             // If the package is immutable, we don't need to validate it (or its dependencies).
             if package.id.source.is_immutable() {
                 continue;
@@ -2069,6 +2328,8 @@ pub enum SatisfiesResult<'lock> {
     MismatchedOverrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of excludes.
     MismatchedExcludes(BTreeSet<PackageName>, BTreeSet<PackageName>),
+    /// The lockfile uses a different set of provided dependencies.
+    MismatchedProvided(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of build constraints.
     MismatchedBuildConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of dependency groups.
@@ -2078,12 +2339,16 @@ pub enum SatisfiesResult<'lock> {
     ),
     /// The lockfile uses different static metadata.
     MismatchedStaticMetadata(BTreeSet<StaticMetadata>, &'lock BTreeSet<StaticMetadata>),
+    /// The lockfile uses a different set of patches.
+    MismatchedPatches(BTreeSet<ManifestPatch>, &'lock BTreeSet<ManifestPatch>),
     /// The lockfile is missing a workspace member.
     MissingRoot(PackageName),
     /// The lockfile referenced a remote index that was not provided
     MissingRemoteIndex(&'lock PackageName, &'lock Version, &'lock UrlString),
     /// The lockfile referenced a local index that was not provided
     MissingLocalIndex(&'lock PackageName, &'lock Version, &'lock Path),
+    /// A package in the lockfile was not locked from the index that it's restricted to
+    MismatchedPinnedIndex(&'lock PackageName, &'lock Version, IndexUrl),
     /// A package in the lockfile contains different `requires-dist` metadata than expected.
     MismatchedPackageRequirements(
         &'lock PackageName,
@@ -2122,6 +2387,18 @@ struct ResolverOptions {
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
+    /// The [`YankedPolicy`] used to generate this lock.
+    #[serde(default)]
+    yanked_policy: YankedPolicy,
+    /// The yanked versions that were explicitly allowed when generating this lock.
+    #[serde(default)]
+    allow_yanked: Vec<AllowedYank>,
+    /// The known incompatibilities that were enforced when generating this lock.
+    #[serde(default)]
+    known_incompatibilities: Vec<KnownIncompatibility>,
+    /// Whether the provenance of each package's markers was recorded in this lock.
+    #[serde(default)]
+    marker_provenance: bool,
     /// The [`ExcludeNewer`] setting used to generate this lock.
     #[serde(flatten)]
     exclude_newer: ExcludeNewerWire,
@@ -2181,12 +2458,18 @@ pub struct ResolverManifest {
     /// The excludes provided to the resolver.
     #[serde(default)]
     excludes: BTreeSet<PackageName>,
+    /// The dependencies assumed to be provided by the environment.
+    #[serde(default)]
+    provided: BTreeSet<Requirement>,
     /// The build constraints provided to the resolver.
     #[serde(default)]
     build_constraints: BTreeSet<Requirement>,
     /// The static metadata provided to the resolver.
     #[serde(default)]
     dependency_metadata: BTreeSet<StaticMetadata>,
+    /// The patches applied to source distributions.
+    #[serde(default)]
+    patches: BTreeSet<ManifestPatch>,
 }
 
 impl ResolverManifest {
@@ -2198,9 +2481,11 @@ impl ResolverManifest {
         constraints: impl IntoIterator<Item = Requirement>,
         overrides: impl IntoIterator<Item = Requirement>,
         excludes: impl IntoIterator<Item = PackageName>,
+        provided: impl IntoIterator<Item = Requirement>,
         build_constraints: impl IntoIterator<Item = Requirement>,
         dependency_groups: impl IntoIterator<Item = (GroupName, Vec<Requirement>)>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
+        patches: impl IntoIterator<Item = Patch>,
     ) -> Self {
         Self {
             members: members.into_iter().collect(),
@@ -2208,12 +2493,17 @@ impl ResolverManifest {
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
             excludes: excludes.into_iter().collect(),
+            provided: provided.into_iter().collect(),
             build_constraints: build_constraints.into_iter().collect(),
             dependency_groups: dependency_groups
                 .into_iter()
                 .map(|(group, requirements)| (group, requirements.into_iter().collect()))
                 .collect(),
             dependency_metadata: dependency_metadata.into_iter().collect(),
+            patches: patches
+                .into_iter()
+                .map(|patch| ManifestPatch::from(&patch))
+                .collect(),
         }
     }
 
@@ -2237,6 +2527,11 @@ impl ResolverManifest {
                 .map(|requirement| requirement.relative_to(root))
                 .collect::<Result<BTreeSet<_>, _>>()?,
             excludes: self.excludes,
+            provided: self
+                .provided
+                .into_iter()
+                .map(|requirement| requirement.relative_to(root))
+                .collect::<Result<BTreeSet<_>, _>>()?,
             build_constraints: self
                 .build_constraints
                 .into_iter()
@@ -2256,10 +2551,44 @@ impl ResolverManifest {
                 })
                 .collect::<Result<BTreeMap<_, _>, _>>()?,
             dependency_metadata: self.dependency_metadata,
+            patches: self
+                .patches
+                .into_iter()
+                .map(|patch| patch.relative_to(root))
+                .collect::<Result<BTreeSet<_>, _>>()?,
         })
     }
 }
 
+/// A patch applied to a source distribution, as recorded in the lockfile manifest.
+#[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub struct ManifestPatch {
+    name: PackageName,
+    version: Option<Version>,
+    path: PathBuf,
+    hash: Hash,
+}
+
+impl ManifestPatch {
+    /// Convert the patch path to a relative form using the given workspace.
+    fn relative_to(self, root: &Path) -> Result<Self, io::Error> {
+        let path = relative_to(&self.path, root).or_else(|_| std::path::absolute(&self.path))?;
+        Ok(Self { path, ..self })
+    }
+}
+
+impl From<&Patch> for ManifestPatch {
+    fn from(patch: &Patch) -> Self {
+        Self {
+            name: patch.name.clone(),
+            version: patch.version.clone(),
+            path: patch.path.clone(),
+            hash: Hash::from(patch.hash.clone()),
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct LockWire {
@@ -2379,6 +2708,11 @@ pub struct Package {
     dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
     /// The exact requirements from the package metadata.
     metadata: PackageMetadata,
+    /// The marker under which the package is installed, along with the forks that selected it.
+    ///
+    /// Only recorded when `marker-provenance` is enabled, and omitted for packages that are
+    /// installed in all environments outside of any fork. Named `marker-provenance` in `uv.lock`.
+    marker_provenance: Option<MarkerProvenance>,
 }
 
 impl Package {
@@ -2447,6 +2781,7 @@ impl Package {
                 provides_extra,
                 dependency_groups,
             },
+            marker_provenance: None,
         })
     }
 
@@ -3078,6 +3413,13 @@ impl Package {
             }
         }
 
+        if let Some(marker_provenance) = &self.marker_provenance {
+            table.insert(
+                "marker-provenance",
+                Item::Table(marker_provenance.to_toml(requires_python)),
+            );
+        }
+
         Ok(table)
     }
 
@@ -3122,6 +3464,16 @@ impl Package {
         self.id.version.as_ref()
     }
 
+    /// Returns the most recent upload time across the package's distributions, if known.
+    pub fn upload_time(&self) -> Option<Timestamp> {
+        self.sdist
+            .as_ref()
+            .and_then(SourceDist::upload_time)
+            .into_iter()
+            .chain(self.wheels.iter().filter_map(|wheel| wheel.upload_time))
+            .max()
+    }
+
     /// Returns the Git SHA of the package, if it is a Git source.
     pub fn git_sha(&self) -> Option<&GitOid> {
         match &self.id.source {
@@ -3135,6 +3487,14 @@ impl Package {
         self.fork_markers.as_slice()
     }
 
+    /// Returns the [`MarkerProvenance`] of this package, if it was recorded.
+    ///
+    /// If the [`Lock`] records marker provenance, a missing value indicates that the package is
+    /// installed in all environments, outside of any fork.
+    pub fn marker_provenance(&self) -> Option<&MarkerProvenance> {
+        self.marker_provenance.as_ref()
+    }
+
     /// Returns the [`IndexUrl`] for the package, if it is a registry source.
     pub fn index(&self, root: &Path) -> Result<Option<IndexUrl>, LockError> {
         match &self.id.source {
@@ -3203,6 +3563,13 @@ impl Package {
         &self.metadata.provides_extra
     }
 
+    /// Returns the exact requirements from the package metadata, if recorded.
+    ///
+    /// Requirements are only recorded for packages with mutable sources, like workspace members.
+    pub fn requires_dist(&self) -> &BTreeSet<Requirement> {
+        &self.metadata.requires_dist
+    }
+
     /// Returns the dependency groups the package provides, if any.
     pub fn dependency_groups(&self) -> &BTreeMap<GroupName, BTreeSet<Requirement>> {
         &self.metadata.dependency_groups
@@ -3268,6 +3635,8 @@ struct PackageWire {
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
     #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
+    #[serde(default)]
+    marker_provenance: Option<MarkerProvenanceWire>,
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, serde::Deserialize)]
@@ -3334,10 +3703,79 @@ impl PackageWire {
                 .into_iter()
                 .map(|(group, deps)| Ok((group, unwire_deps(deps)?)))
                 .collect::<Result<_, LockError>>()?,
+            marker_provenance: self
+                .marker_provenance
+                .map(|marker_provenance| marker_provenance.unwire(requires_python)),
         })
     }
 }
 
+/// The provenance of a package's markers: the marker under which the package is installed, along
+/// with the markers of the resolver forks that selected it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkerProvenance {
+    /// The marker under which the package is installed, simplified against `requires-python`.
+    marker: SimplifiedMarkerTree,
+    /// The markers of the resolver forks that selected the package, if the resolution forked.
+    forks: Vec<UniversalMarker>,
+}
+
+impl MarkerProvenance {
+    /// Returns the simplified marker under which the package is installed.
+    pub fn marker(&self) -> SimplifiedMarkerTree {
+        self.marker
+    }
+
+    /// Returns the markers of the resolver forks that selected the package.
+    pub fn forks(&self) -> &[UniversalMarker] {
+        &self.forks
+    }
+
+    /// Returns `true` if the package is installed in all environments, outside of any fork.
+    fn is_empty(&self) -> bool {
+        self.marker.as_simplified_marker_tree().is_true() && self.forks.is_empty()
+    }
+
+    /// Returns the TOML representation of this provenance.
+    fn to_toml(&self, requires_python: &RequiresPython) -> Table {
+        let mut table = Table::new();
+        if let Some(marker) = self.marker.try_to_string() {
+            table.insert("marker", value(marker));
+        }
+        let forks = simplified_universal_markers(&self.forks, requires_python);
+        if !forks.is_empty() {
+            table.insert(
+                "forks",
+                value(each_element_on_its_line_array(forks.into_iter())),
+            );
+        }
+        table
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct MarkerProvenanceWire {
+    #[serde(default)]
+    marker: SimplifiedMarkerTree,
+    #[serde(default)]
+    forks: Vec<SimplifiedMarkerTree>,
+}
+
+impl MarkerProvenanceWire {
+    fn unwire(self, requires_python: &RequiresPython) -> MarkerProvenance {
+        MarkerProvenance {
+            marker: self.marker,
+            forks: self
+                .forks
+                .into_iter()
+                .map(|simplified_marker| simplified_marker.into_marker(requires_python))
+                .map(UniversalMarker::from_combined)
+                .collect(),
+        }
+    }
+}
+
 /// Inside the lockfile, we match a dependency entry to a package entry through a key made up
 /// of the name, the version and the source url.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize)]
@@ -4898,10 +5336,20 @@ impl Dependency {
         &self.package_id.name
     }
 
+    /// Returns the version of the package this dependency resolves to, if any.
+    pub fn package_version(&self) -> Option<&Version> {
+        self.package_id.version.as_ref()
+    }
+
     /// Returns the extras specified on this dependency.
     pub fn extra(&self) -> &BTreeSet<ExtraName> {
         &self.extra
     }
+
+    /// Returns the marker of this dependency, simplified against `requires-python`.
+    pub fn simplified_marker(&self) -> SimplifiedMarkerTree {
+        self.simplified_marker
+    }
 }
 
 impl Display for Dependency {
@@ -4958,7 +5406,7 @@ impl DependencyWire {
 ///
 /// A hash is encoded as a single TOML string in the format
 /// `{algorithm}:{digest}`.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Hash(HashDigest);
 
 impl From<HashDigest> for Hash {
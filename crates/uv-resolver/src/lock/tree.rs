@@ -35,6 +35,8 @@ pub struct TreeDisplay<'env> {
     lock: &'env Lock,
     /// Whether to show sizes in the rendered output.
     show_sizes: bool,
+    /// Whether the tree is inverted, such that each package points to its dependents.
+    invert: bool,
 }
 
 impl<'env> TreeDisplay<'env> {
@@ -424,68 +426,161 @@ impl<'env> TreeDisplay<'env> {
             no_dedupe,
             lock,
             show_sizes,
+            invert,
         }
     }
 
-    /// Perform a depth-first traversal of the given package and its dependencies.
-    fn visit(
-        &'env self,
-        cursor: Cursor,
-        visited: &mut FxHashMap<&'env PackageId, Vec<&'env PackageId>>,
-        path: &mut Vec<&'env PackageId>,
-    ) -> Vec<String> {
-        // Short-circuit if the current path is longer than the provided depth.
-        if path.len() > self.depth {
-            return Vec::new();
-        }
-
+    /// Render the label for the package at the given [`Cursor`], e.g., `anyio[trio] v4.3.0`.
+    fn label(&self, cursor: Cursor) -> String {
         let Node::Package(package_id) = self.graph[cursor.node()] else {
-            return Vec::new();
+            return String::new();
         };
         let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
 
-        let line = {
-            let mut line = format!("{}", package_id.name);
+        let mut line = format!("{}", package_id.name);
 
-            if let Some(extras) = edge.and_then(Edge::extras) {
-                if !extras.is_empty() {
-                    line.push('[');
-                    line.push_str(extras.iter().join(", ").as_str());
-                    line.push(']');
-                }
+        if let Some(extras) = edge.and_then(Edge::extras) {
+            if !extras.is_empty() {
+                line.push('[');
+                line.push_str(extras.iter().join(", ").as_str());
+                line.push(']');
             }
+        }
 
-            if let Some(version) = package_id.version.as_ref() {
-                line.push(' ');
-                line.push('v');
-                let _ = write!(line, "{version}");
-            }
+        if let Some(version) = package_id.version.as_ref() {
+            line.push(' ');
+            line.push('v');
+            let _ = write!(line, "{version}");
+        }
 
-            if let Some(edge) = edge {
-                match edge {
-                    Edge::Prod(_) => {}
-                    Edge::Optional(extra, _) => {
-                        let _ = write!(line, " (extra: {extra})");
-                    }
-                    Edge::Dev(group, _) => {
-                        let _ = write!(line, " (group: {group})");
-                    }
+        if let Some(edge) = edge {
+            match edge {
+                Edge::Prod(_) => {}
+                Edge::Optional(extra, _) => {
+                    let _ = write!(line, " (extra: {extra})");
+                }
+                Edge::Dev(group, _) => {
+                    let _ = write!(line, " (group: {group})");
                 }
             }
+        }
 
-            // Append compressed wheel size, if available in the lockfile.
-            // Keep it simple: use the first wheel entry that includes a size.
-            if self.show_sizes {
-                let package = self.lock.find_by_id(package_id);
-                if let Some(size_bytes) = package.wheels.iter().find_map(|wheel| wheel.size) {
-                    let (bytes, unit) = human_readable_bytes(size_bytes);
-                    line.push(' ');
-                    line.push_str(format!("{}", format!("({bytes:.1}{unit})").dimmed()).as_str());
+        // Append compressed wheel size, if available in the lockfile, along with the cumulative
+        // size of the package and its dependencies.
+        if self.show_sizes {
+            if let Some(size_bytes) = self.size(package_id) {
+                let (bytes, unit) = human_readable_bytes(size_bytes);
+                let mut label = format!("{bytes:.1}{unit}");
+                let has_dependencies = self
+                    .graph
+                    .edges_directed(cursor.node(), Direction::Outgoing)
+                    .any(|edge| matches!(self.graph[edge.target()], Node::Package(_)));
+                if !self.invert && has_dependencies {
+                    let (bytes, unit) = human_readable_bytes(self.cumulative_size(cursor.node()));
+                    let _ = write!(label, ", {bytes:.1}{unit} total");
                 }
+                line.push(' ');
+                line.push_str(format!("{}", format!("({label})").dimmed()).as_str());
             }
+        }
 
-            line
+        line
+    }
+
+    /// Return the compressed wheel size of the given package, if available in the lockfile.
+    ///
+    /// Keep it simple: use the first wheel entry that includes a size.
+    fn size(&self, package_id: &PackageId) -> Option<u64> {
+        self.lock
+            .find_by_id(package_id)
+            .wheels
+            .iter()
+            .find_map(|wheel| wheel.size)
+    }
+
+    /// Return the cumulative compressed wheel size of the given node and its transitive
+    /// dependencies, counting each package once.
+    fn cumulative_size(&self, node: NodeIndex) -> u64 {
+        let mut seen = FxHashSet::default();
+        let mut stack = vec![node];
+        let mut total = 0;
+        while let Some(node) = stack.pop() {
+            let Node::Package(package_id) = self.graph[node] else {
+                continue;
+            };
+            if !seen.insert(package_id) {
+                continue;
+            }
+            total += self.size(package_id).unwrap_or(0);
+            stack.extend(
+                self.graph
+                    .edges_directed(node, Direction::Outgoing)
+                    .map(|edge| edge.target()),
+            );
+        }
+        total
+    }
+
+    /// Return the dependencies of the given node, in display order.
+    fn dependencies(&self, node: NodeIndex) -> Vec<Cursor> {
+        let mut dependencies = self
+            .graph
+            .edges_directed(node, Direction::Outgoing)
+            .filter_map(|edge| match self.graph[edge.target()] {
+                Node::Root => None,
+                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|cursor| {
+            let node = &self.graph[cursor.node()];
+            let edge = cursor
+                .edge()
+                .map(|edge_id| &self.graph[edge_id])
+                .map(Edge::kind);
+            (edge, node)
+        });
+        dependencies
+    }
+
+    /// Return the [`PackageId`] of the given node, if it's a package.
+    fn package_id(&self, node: NodeIndex) -> Option<&'env PackageId> {
+        match self.graph[node] {
+            Node::Package(package_id) => Some(package_id),
+            Node::Root => None,
+        }
+    }
+
+    /// Return the top-level entries of the tree, in display order.
+    fn top_level(&self) -> Vec<Cursor> {
+        self.roots
+            .iter()
+            .flat_map(|node| match self.graph[*node] {
+                Node::Root => Either::Left(
+                    self.graph
+                        .edges_directed(*node, Direction::Outgoing)
+                        .map(|edge| Cursor::new(edge.target(), edge.id())),
+                ),
+                Node::Package(_) => Either::Right(std::iter::once(Cursor::root(*node))),
+            })
+            .collect()
+    }
+
+    /// Perform a depth-first traversal of the given package and its dependencies.
+    fn visit(
+        &'env self,
+        cursor: Cursor,
+        visited: &mut FxHashMap<&'env PackageId, Vec<&'env PackageId>>,
+        path: &mut Vec<&'env PackageId>,
+    ) -> Vec<String> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return Vec::new();
+        }
+
+        let Node::Package(package_id) = self.graph[cursor.node()] else {
+            return Vec::new();
         };
+        let line = self.label(cursor);
 
         // Skip the traversal if:
         // 1. The package is in the current traversal path (i.e., a dependency cycle).
@@ -507,22 +602,7 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
-        let mut dependencies = self
-            .graph
-            .edges_directed(cursor.node(), Direction::Outgoing)
-            .filter_map(|edge| match self.graph[edge.target()] {
-                Node::Root => None,
-                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
-            })
-            .collect::<Vec<_>>();
-        dependencies.sort_by_key(|cursor| {
-            let node = &self.graph[cursor.node()];
-            let edge = cursor
-                .edge()
-                .map(|edge_id| &self.graph[edge_id])
-                .map(Edge::kind);
-            (edge, node)
-        });
+        let dependencies = self.dependencies(cursor.node());
 
         let mut lines = vec![line];
 
@@ -587,24 +667,9 @@ impl<'env> TreeDisplay<'env> {
         let mut visited =
             FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
 
-        for node in &self.roots {
-            match self.graph[*node] {
-                Node::Root => {
-                    for edge in self.graph.edges_directed(*node, Direction::Outgoing) {
-                        let node = edge.target();
-                        path.clear();
-                        lines.extend(self.visit(
-                            Cursor::new(node, edge.id()),
-                            &mut visited,
-                            &mut path,
-                        ));
-                    }
-                }
-                Node::Package(_) => {
-                    path.clear();
-                    lines.extend(self.visit(Cursor::root(*node), &mut visited, &mut path));
-                }
-            }
+        for cursor in self.top_level() {
+            path.clear();
+            lines.extend(self.visit(cursor, &mut visited, &mut path));
         }
 
         lines
@@ -652,7 +717,7 @@ enum EdgeKind<'env> {
 }
 
 /// A node in the dependency graph along with the edge that led to it, or `None` for root nodes.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 struct Cursor(NodeIndex, Option<EdgeIndex>);
 
 impl Cursor {
@@ -699,3 +764,641 @@ impl std::fmt::Display for TreeDisplay<'_> {
         Ok(())
     }
 }
+
+/// An interactive view of a [`TreeDisplay`], in which each package can be expanded and collapsed.
+///
+/// Unlike the rendered tree, repeated packages are not de-duplicated, since the dependencies of
+/// each entry are only traversed once it's expanded.
+#[derive(Debug)]
+pub struct TreeExplorer<'env> {
+    /// The underlying dependency tree.
+    tree: &'env TreeDisplay<'env>,
+    /// The paths (from the top of the tree) to the expanded entries.
+    expanded: FxHashSet<Vec<Cursor>>,
+    /// The currently visible rows, in display order.
+    rows: Vec<TreeRow>,
+    /// The index of the selected row.
+    selected: usize,
+    /// The paths to the packages matching the current search, in display order.
+    matches: Vec<Vec<Cursor>>,
+    /// The index of the selected match.
+    current_match: usize,
+    /// The license of each package, if known.
+    licenses: FxHashMap<PackageName, String>,
+}
+
+impl<'env> TreeExplorer<'env> {
+    /// Create a [`TreeExplorer`] with the top-level packages expanded.
+    pub fn new(tree: &'env TreeDisplay<'env>) -> Self {
+        let expanded = tree
+            .top_level()
+            .into_iter()
+            .map(|cursor| vec![cursor])
+            .collect();
+        let mut explorer = Self {
+            tree,
+            expanded,
+            rows: Vec::new(),
+            selected: 0,
+            matches: Vec::new(),
+            current_match: 0,
+            licenses: FxHashMap::default(),
+        };
+        explorer.refresh();
+        explorer
+    }
+
+    /// Set the license of each package, to include in the package details.
+    #[must_use]
+    pub fn with_licenses(mut self, licenses: FxHashMap<PackageName, String>) -> Self {
+        self.licenses = licenses;
+        self
+    }
+
+    /// Return the visible rows, in display order.
+    pub fn rows(&self) -> &[TreeRow] {
+        &self.rows
+    }
+
+    /// Return the index of the selected row.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select the row at the given index, clamped to the visible rows.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// Select the previous row.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Select the next row.
+    pub fn select_next(&mut self) {
+        self.select(self.selected + 1);
+    }
+
+    /// Expand the selected package.
+    pub fn expand(&mut self) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        if row.state == TreeRowState::Collapsed {
+            self.expanded.insert(row.path.clone());
+            self.refresh();
+        }
+    }
+
+    /// Collapse the selected package, or select its parent if it's not expanded.
+    pub fn collapse(&mut self) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        if row.state == TreeRowState::Expanded {
+            self.expanded.remove(&row.path);
+            self.refresh();
+        } else if let Some((_, parent)) = row.path.split_last() {
+            if !parent.is_empty() {
+                let parent = parent.to_vec();
+                self.select_path(&parent);
+            }
+        }
+    }
+
+    /// Expand or collapse the selected package.
+    pub fn toggle(&mut self) {
+        match self.rows.get(self.selected).map(|row| row.state) {
+            Some(TreeRowState::Expanded) => self.collapse(),
+            Some(TreeRowState::Collapsed) => self.expand(),
+            _ => {}
+        }
+    }
+
+    /// Search for the packages whose name contains the given query, and select the first match.
+    ///
+    /// Returns the number of matching packages.
+    pub fn search(&mut self, query: &str) -> usize {
+        let query = query.trim().to_lowercase().replace(['_', '.'], "-");
+        self.matches = if query.is_empty() {
+            Vec::new()
+        } else {
+            self.first_occurrences()
+                .into_iter()
+                .filter(|path| {
+                    path.last()
+                        .and_then(|cursor| self.tree.package_id(cursor.node()))
+                        .is_some_and(|package_id| package_id.name.as_str().contains(&query))
+                })
+                .collect()
+        };
+        self.current_match = 0;
+        if let Some(path) = self.matches.first().cloned() {
+            self.reveal(&path);
+        }
+        self.matches.len()
+    }
+
+    /// Select the next match of the current search, wrapping around at the end.
+    ///
+    /// Returns the (one-based) index of the selected match, and the number of matches.
+    pub fn next_match(&mut self) -> Option<(usize, usize)> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        self.current_match = (self.current_match + 1) % self.matches.len();
+        let path = self.matches[self.current_match].clone();
+        self.reveal(&path);
+        Some((self.current_match + 1, self.matches.len()))
+    }
+
+    /// Select the previous match of the current search, wrapping around at the start.
+    ///
+    /// Returns the (one-based) index of the selected match, and the number of matches.
+    pub fn previous_match(&mut self) -> Option<(usize, usize)> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        self.current_match = self
+            .current_match
+            .checked_sub(1)
+            .unwrap_or(self.matches.len() - 1);
+        let path = self.matches[self.current_match].clone();
+        self.reveal(&path);
+        Some((self.current_match + 1, self.matches.len()))
+    }
+
+    /// Return the packages that depend on the selected package, e.g., `anyio v4.3.0`.
+    ///
+    /// For an inverted tree, these are the dependencies of the selected package.
+    pub fn dependents(&self) -> Vec<String> {
+        self.dependent_nodes()
+            .into_iter()
+            .filter_map(|node| self.tree.package_id(node))
+            .map(package_label)
+            .collect()
+    }
+
+    /// Select the first occurrence of the dependent at the given index in [`Self::dependents`].
+    ///
+    /// Returns `false` if the dependent isn't displayed in the tree (e.g., due to `--depth`).
+    pub fn jump_to_dependent(&mut self, index: usize) -> bool {
+        let Some(node) = self.dependent_nodes().get(index).copied() else {
+            return false;
+        };
+        let Some(path) = self
+            .first_occurrences()
+            .into_iter()
+            .find(|path| path.last().is_some_and(|cursor| cursor.node() == node))
+        else {
+            return false;
+        };
+        self.reveal(&path);
+        true
+    }
+
+    /// Return the details of the selected package, as pairs of field names and values.
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let Some(row) = self.rows.get(self.selected) else {
+            return Vec::new();
+        };
+        let Some(cursor) = row.path.last() else {
+            return Vec::new();
+        };
+        let Some(package_id) = self.tree.package_id(cursor.node()) else {
+            return Vec::new();
+        };
+
+        let mut details = vec![("name", package_id.name.to_string())];
+        if let Some(version) = package_id.version.as_ref() {
+            details.push(("version", version.to_string()));
+        }
+        details.push(("source", package_id.source.to_string()));
+
+        if let Some(edge) = cursor.edge().map(|edge_id| &self.tree.graph[edge_id]) {
+            if let Some(extras) = edge.extras().filter(|extras| !extras.is_empty()) {
+                details.push(("extras", extras.iter().join(", ")));
+            }
+            match edge {
+                Edge::Prod(_) => {}
+                Edge::Optional(extra, _) => details.push(("extra", extra.to_string())),
+                Edge::Dev(group, _) => details.push(("group", group.to_string())),
+            }
+            if let Some(marker) = self.marker(cursor.edge()) {
+                details.push(("marker", marker));
+            }
+        }
+
+        if let Some(license) = self.licenses.get(&package_id.name) {
+            details.push(("license", license.clone()));
+        }
+
+        let dependents = self.dependents();
+        if !dependents.is_empty() {
+            let field = if self.tree.invert {
+                "requires"
+            } else {
+                "required by"
+            };
+            details.push((field, dependents.join(", ")));
+        }
+
+        details
+    }
+
+    /// Return the marker of the dependency represented by the given edge, if any.
+    fn marker(&self, edge: Option<EdgeIndex>) -> Option<String> {
+        let edge = edge?;
+        let (source, target) = self.tree.graph.edge_endpoints(edge)?;
+        let (dependent, dependency) = if self.tree.invert {
+            (target, source)
+        } else {
+            (source, target)
+        };
+        let dependent = self.tree.package_id(dependent)?;
+        let dependency = self.tree.package_id(dependency)?;
+
+        let package = self.tree.lock.find_by_id(dependent);
+        let dependencies = match &self.tree.graph[edge] {
+            Edge::Prod(_) => Some(&package.dependencies),
+            Edge::Optional(extra, _) => package.optional_dependencies.get(*extra),
+            Edge::Dev(group, _) => package.dependency_groups.get(*group),
+        }?;
+        dependencies
+            .iter()
+            .find(|dep| dep.package_id == *dependency)
+            .and_then(|dep| dep.simplified_marker.try_to_string())
+    }
+
+    /// Return the nodes with an edge to the selected package.
+    fn dependent_nodes(&self) -> Vec<NodeIndex> {
+        let Some(cursor) = self.rows.get(self.selected).and_then(|row| row.path.last()) else {
+            return Vec::new();
+        };
+        let mut nodes = self
+            .tree
+            .graph
+            .edges_directed(cursor.node(), Direction::Incoming)
+            .map(|edge| edge.source())
+            .filter(|node| matches!(self.tree.graph[*node], Node::Package(_)))
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|node| &self.tree.graph[*node]);
+        nodes.dedup();
+        nodes
+    }
+
+    /// Return the path to the first occurrence of each package, in display order.
+    fn first_occurrences(&self) -> Vec<Vec<Cursor>> {
+        let mut seen = FxHashSet::default();
+        let mut paths = Vec::new();
+        let mut stack = self
+            .tree
+            .top_level()
+            .into_iter()
+            .rev()
+            .map(|cursor| vec![cursor])
+            .collect::<Vec<_>>();
+        while let Some(path) = stack.pop() {
+            let Some(cursor) = path.last().copied() else {
+                continue;
+            };
+            if !seen.insert(cursor.node()) {
+                continue;
+            }
+            if path.len() <= self.tree.depth {
+                for dependency in self.tree.dependencies(cursor.node()).into_iter().rev() {
+                    let mut path = path.clone();
+                    path.push(dependency);
+                    stack.push(path);
+                }
+            }
+            paths.push(path);
+        }
+        paths
+    }
+
+    /// Expand the ancestors of the given path, and select it.
+    fn reveal(&mut self, path: &[Cursor]) {
+        for len in 1..path.len() {
+            self.expanded.insert(path[..len].to_vec());
+        }
+        self.refresh();
+        self.select_path(path);
+    }
+
+    /// Select the row with the given path, if it's visible.
+    fn select_path(&mut self, path: &[Cursor]) {
+        if let Some(index) = self.rows.iter().position(|row| row.path == path) {
+            self.selected = index;
+        }
+    }
+
+    /// Recompute the visible rows.
+    fn refresh(&mut self) {
+        let mut rows = Vec::new();
+        for cursor in self.tree.top_level() {
+            self.push_rows(&[cursor], &mut rows);
+        }
+        self.rows = rows;
+        self.select(self.selected);
+    }
+
+    /// Push the row for the given path, along with the rows of its dependencies, if expanded.
+    fn push_rows(&self, path: &[Cursor], rows: &mut Vec<TreeRow>) {
+        let Some((cursor, ancestors)) = path.split_last() else {
+            return;
+        };
+        let cursor = *cursor;
+        let depth = ancestors.len();
+
+        let dependencies = if depth < self.tree.depth {
+            self.tree.dependencies(cursor.node())
+        } else {
+            Vec::new()
+        };
+        let state = if ancestors
+            .iter()
+            .any(|ancestor| ancestor.node() == cursor.node())
+        {
+            TreeRowState::Cycle
+        } else if dependencies.is_empty() {
+            TreeRowState::Leaf
+        } else if self.expanded.contains(path) {
+            TreeRowState::Expanded
+        } else {
+            TreeRowState::Collapsed
+        };
+
+        // Incorporate the latest version of the package, if known.
+        let mut label = self.tree.label(cursor);
+        if let Some(version) = self
+            .tree
+            .package_id(cursor.node())
+            .and_then(|package_id| self.tree.latest.get(package_id))
+        {
+            let _ = write!(label, " {}", format!("(latest: v{version})").bold().cyan());
+        }
+
+        let expanded = state == TreeRowState::Expanded;
+        rows.push(TreeRow {
+            path: path.to_vec(),
+            depth,
+            label,
+            state,
+        });
+
+        if expanded {
+            for dependency in dependencies {
+                let mut path = path.to_vec();
+                path.push(dependency);
+                self.push_rows(&path, rows);
+            }
+        }
+    }
+}
+
+/// A visible entry in a [`TreeExplorer`].
+#[derive(Debug, Clone)]
+pub struct TreeRow {
+    /// The path from the top of the tree to this entry.
+    path: Vec<Cursor>,
+    /// The depth of the entry in the tree.
+    depth: usize,
+    /// The label of the entry, e.g., `anyio v4.3.0`.
+    label: String,
+    /// Whether the entry is expanded.
+    state: TreeRowState,
+}
+
+impl TreeRow {
+    /// Return the depth of the entry in the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Return the label of the entry, e.g., `anyio v4.3.0`.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Return whether the entry is expanded.
+    pub fn state(&self) -> TreeRowState {
+        self.state
+    }
+}
+
+impl std::fmt::Display for TreeRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let marker = match self.state {
+            TreeRowState::Leaf | TreeRowState::Cycle => " ",
+            TreeRowState::Collapsed => "▸",
+            TreeRowState::Expanded => "▾",
+        };
+        write!(f, "{}{marker} {}", "  ".repeat(self.depth), self.label)?;
+        if self.state == TreeRowState::Cycle {
+            write!(f, " (*)")?;
+        }
+        Ok(())
+    }
+}
+
+/// The state of a [`TreeRow`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TreeRowState {
+    /// The package has no dependencies to display.
+    Leaf,
+    /// The package has dependencies, which are hidden.
+    Collapsed,
+    /// The package has dependencies, which are displayed.
+    Expanded,
+    /// The package is already displayed as one of its own ancestors.
+    Cycle,
+}
+
+/// Render a package as `name vX.Y.Z`.
+fn package_label(package_id: &PackageId) -> String {
+    match package_id.version.as_ref() {
+        Some(version) => format!("{} v{version}", package_id.name),
+        None => package_id.name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uv_configuration::DependencyGroupsWithDefaults;
+
+    use super::*;
+
+    const LOCK: &str = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+dependencies = [
+    { name = "idna" },
+    { name = "sniffio", marker = "sys_platform == 'win32'" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "anyio" },
+    { name = "sniffio" },
+]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+"#;
+
+    fn render(explorer: &TreeExplorer) -> String {
+        let mut output = String::new();
+        for (index, row) in explorer.rows().iter().enumerate() {
+            let cursor = if index == explorer.selected() {
+                ">"
+            } else {
+                " "
+            };
+            writeln!(output, "{cursor}{row}").unwrap();
+        }
+        output
+    }
+
+    #[test]
+    fn explore() {
+        let lock: Lock = toml::from_str(LOCK).unwrap();
+        let latest = PackageMap::default();
+        let groups = DependencyGroupsWithDefaults::none();
+        let tree = TreeDisplay::new(
+            &lock,
+            None,
+            &latest,
+            255,
+            &[],
+            &[],
+            &groups,
+            false,
+            false,
+            false,
+        );
+        let mut explorer = TreeExplorer::new(&tree);
+
+        // The top-level packages are expanded by default.
+        insta::assert_snapshot!(render(&explorer), @r"
+        >▾ project v0.1.0
+           ▸ anyio v4.3.0
+             sniffio v1.3.1
+        ");
+
+        // Expand `anyio`.
+        explorer.select_next();
+        explorer.expand();
+        insta::assert_snapshot!(render(&explorer), @r"
+         ▾ project v0.1.0
+        >  ▾ anyio v4.3.0
+               idna v3.6
+               sniffio v1.3.1
+             sniffio v1.3.1
+        ");
+
+        // Collapsing a leaf selects its parent.
+        explorer.select_next();
+        explorer.collapse();
+        insta::assert_snapshot!(render(&explorer), @r"
+         ▾ project v0.1.0
+        >  ▾ anyio v4.3.0
+               idna v3.6
+               sniffio v1.3.1
+             sniffio v1.3.1
+        ");
+
+        // Searching reveals the first occurrence of the package.
+        explorer.collapse();
+        assert_eq!(explorer.search("SNIFF"), 1);
+        insta::assert_snapshot!(render(&explorer), @r"
+         ▾ project v0.1.0
+           ▾ anyio v4.3.0
+               idna v3.6
+        >      sniffio v1.3.1
+             sniffio v1.3.1
+        ");
+        insta::assert_debug_snapshot!(explorer.details(), @r#"
+        [
+            (
+                "name",
+                "sniffio",
+            ),
+            (
+                "version",
+                "1.3.1",
+            ),
+            (
+                "source",
+                "registry+https://pypi.org/simple",
+            ),
+            (
+                "marker",
+                "sys_platform == 'win32'",
+            ),
+            (
+                "required by",
+                "anyio v4.3.0, project v0.1.0",
+            ),
+        ]
+        "#);
+        insta::assert_debug_snapshot!(explorer.dependents(), @r#"
+        [
+            "anyio v4.3.0",
+            "project v0.1.0",
+        ]
+        "#);
+
+        // Jump to a reverse dependency.
+        assert!(explorer.jump_to_dependent(0));
+        insta::assert_snapshot!(render(&explorer), @r"
+         ▾ project v0.1.0
+        >  ▾ anyio v4.3.0
+               idna v3.6
+               sniffio v1.3.1
+             sniffio v1.3.1
+        ");
+
+        // The direct dependency on `sniffio` has no marker.
+        explorer.select(4);
+        insta::assert_debug_snapshot!(explorer.details(), @r#"
+        [
+            (
+                "name",
+                "sniffio",
+            ),
+            (
+                "version",
+                "1.3.1",
+            ),
+            (
+                "source",
+                "registry+https://pypi.org/simple",
+            ),
+            (
+                "required by",
+                "anyio v4.3.0, project v0.1.0",
+            ),
+        ]
+        "#);
+    }
+}
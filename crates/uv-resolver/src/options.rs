@@ -1,9 +1,16 @@
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
 use uv_configuration::{BuildOptions, IndexStrategy};
+use uv_distribution_types::Requirement;
 use uv_pypi_types::SupportedEnvironments;
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{
+    AllowedYank, DependencyMode, ExcludeNewer, KnownIncompatibility, PrereleaseMode,
+    ResolutionMode, UpgradePolicy, YankedPolicy,
+};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -12,12 +19,20 @@ pub struct Options {
     pub prerelease_mode: PrereleaseMode,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
+    pub upgrade_policy: UpgradePolicy,
+    pub yanked_policy: YankedPolicy,
+    pub allow_yanked: Vec<AllowedYank>,
+    pub known_incompatibilities: Vec<KnownIncompatibility>,
     pub exclude_newer: ExcludeNewer,
     pub index_strategy: IndexStrategy,
     pub required_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub max_backtracks: Option<NonZeroUsize>,
+    pub resolution_timeout: Option<Duration>,
+    pub prefer_smaller_artifacts: bool,
+    pub fallbacks: Vec<Requirement>,
 }
 
 /// Builder for [`Options`].
@@ -27,12 +42,20 @@ pub struct OptionsBuilder {
     prerelease_mode: PrereleaseMode,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
+    upgrade_policy: UpgradePolicy,
+    yanked_policy: YankedPolicy,
+    allow_yanked: Vec<AllowedYank>,
+    known_incompatibilities: Vec<KnownIncompatibility>,
     exclude_newer: ExcludeNewer,
     index_strategy: IndexStrategy,
     required_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    max_backtracks: Option<NonZeroUsize>,
+    resolution_timeout: Option<Duration>,
+    prefer_smaller_artifacts: bool,
+    fallbacks: Vec<Requirement>,
 }
 
 impl OptionsBuilder {
@@ -69,6 +92,37 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`UpgradePolicy`].
+    #[must_use]
+    pub fn upgrade_policy(mut self, upgrade_policy: UpgradePolicy) -> Self {
+        self.upgrade_policy = upgrade_policy;
+        self
+    }
+
+    /// Sets the [`YankedPolicy`].
+    #[must_use]
+    pub fn yanked_policy(mut self, yanked_policy: YankedPolicy) -> Self {
+        self.yanked_policy = yanked_policy;
+        self
+    }
+
+    /// Sets the yanked versions that are explicitly allowed.
+    #[must_use]
+    pub fn allow_yanked(mut self, allow_yanked: Vec<AllowedYank>) -> Self {
+        self.allow_yanked = allow_yanked;
+        self
+    }
+
+    /// Sets the package version combinations that are known to be incompatible.
+    #[must_use]
+    pub fn known_incompatibilities(
+        mut self,
+        known_incompatibilities: Vec<KnownIncompatibility>,
+    ) -> Self {
+        self.known_incompatibilities = known_incompatibilities;
+        self
+    }
+
     /// Sets the exclusion date.
     #[must_use]
     pub fn exclude_newer(mut self, exclude_newer: ExcludeNewer) -> Self {
@@ -111,6 +165,36 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of times a decision on any single package may be discarded
+    /// before the resolution is aborted.
+    #[must_use]
+    pub fn max_backtracks(mut self, max_backtracks: Option<NonZeroUsize>) -> Self {
+        self.max_backtracks = max_backtracks;
+        self
+    }
+
+    /// Sets the maximum wall-clock time the resolution may take before it's aborted.
+    #[must_use]
+    pub fn resolution_timeout(mut self, resolution_timeout: Option<Duration>) -> Self {
+        self.resolution_timeout = resolution_timeout;
+        self
+    }
+
+    /// Sets whether to prefer the local version variant with the smallest artifacts.
+    #[must_use]
+    pub fn prefer_smaller_artifacts(mut self, prefer_smaller_artifacts: bool) -> Self {
+        self.prefer_smaller_artifacts = prefer_smaller_artifacts;
+        self
+    }
+
+    /// Sets the URL requirements to fall back to when no compatible version of a package is
+    /// available on the index.
+    #[must_use]
+    pub fn fallbacks(mut self, fallbacks: Vec<Requirement>) -> Self {
+        self.fallbacks = fallbacks;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -118,12 +202,20 @@ impl OptionsBuilder {
             prerelease_mode: self.prerelease_mode,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
+            upgrade_policy: self.upgrade_policy,
+            yanked_policy: self.yanked_policy,
+            allow_yanked: self.allow_yanked,
+            known_incompatibilities: self.known_incompatibilities,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
             required_environments: self.required_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            max_backtracks: self.max_backtracks,
+            resolution_timeout: self.resolution_timeout,
+            prefer_smaller_artifacts: self.prefer_smaller_artifacts,
+            fallbacks: self.fallbacks,
         }
     }
 }
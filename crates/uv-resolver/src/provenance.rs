@@ -0,0 +1,373 @@
+use std::collections::Bound;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use pubgrub::{DerivationTree, External, Ranges};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_configuration::{Constraints, Overrides};
+use uv_distribution_types::Requirement;
+use uv_normalize::{GroupName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::RequirementOrigin;
+
+use crate::error::ErrorTree;
+use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
+
+/// A chain of sources that introduced a requirement on a package involved in a resolution
+/// failure.
+///
+/// The chain starts with the package that depends on the requirement, and ends with the direct
+/// requirement, constraint, or override (e.g., in a `pyproject.toml` or `requirements.txt` file)
+/// that ultimately introduced it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RequirementProvenance {
+    /// The package that was required.
+    pub package: PackageName,
+    /// The sources that introduced the requirement, from the nearest to the most distant.
+    pub chain: Vec<ProvenanceStep>,
+}
+
+/// A single step in a [`RequirementProvenance`] chain.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ProvenanceStep {
+    /// A dependency declared by another package, e.g., `flask==3.0.0` depends on `werkzeug>=3`.
+    Dependency {
+        /// The package that declared the dependency.
+        package: PackageName,
+        /// The versions of the package that declare the dependency.
+        versions: String,
+        /// The versions of the dependency that were required.
+        specifier: String,
+    },
+    /// A direct requirement.
+    Requirement {
+        requirement: String,
+        #[serde(flatten)]
+        source: ProvenanceSource,
+    },
+    /// A constraint, e.g., from a `-c constraints.txt` file.
+    Constraint {
+        requirement: String,
+        #[serde(flatten)]
+        source: ProvenanceSource,
+    },
+    /// An override, e.g., from an `--override overrides.txt` file.
+    Override {
+        requirement: String,
+        #[serde(flatten)]
+        source: ProvenanceSource,
+    },
+}
+
+/// The location at which a direct requirement, constraint, or override was declared.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ProvenanceSource {
+    /// The file that declared the requirement (e.g., a `pyproject.toml` or `requirements.txt`
+    /// file).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// The (one-based) line in the file that declared the requirement, if it could be located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The project that declared the requirement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<PackageName>,
+    /// The dependency group that declared the requirement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<GroupName>,
+    /// Whether the requirement was declared in the workspace configuration (e.g.,
+    /// `tool.uv.constraint-dependencies`).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub workspace: bool,
+}
+
+impl ProvenanceSource {
+    fn from_requirement(requirement: &Requirement) -> Self {
+        let Some(origin) = requirement.origin.as_ref() else {
+            return Self::default();
+        };
+        let (path, project, group) = match origin {
+            RequirementOrigin::File(path) => (path, None, None),
+            RequirementOrigin::Project(path, project) => (path, Some(project.clone()), None),
+            RequirementOrigin::Group(path, project, group) => {
+                (path, project.clone(), Some(group.clone()))
+            }
+            RequirementOrigin::Workspace => {
+                return Self {
+                    workspace: true,
+                    ..Self::default()
+                };
+            }
+        };
+        Self {
+            line: find_line(path, &requirement.name),
+            path: Some(path.clone()),
+            project,
+            group,
+            workspace: false,
+        }
+    }
+}
+
+/// Determine the chains of sources that introduced each package in the derivation tree.
+pub(crate) fn collect_provenance(
+    tree: &ErrorTree,
+    requirements: &[Requirement],
+    constraints: &Constraints,
+    overrides: &Overrides,
+) -> Vec<RequirementProvenance> {
+    // Index the dependency incompatibilities by the name of the dependency.
+    let mut dependents = FxHashMap::default();
+    let mut packages = Vec::new();
+    collect_dependents(tree, &mut dependents, &mut packages);
+
+    let mut provenance = Vec::new();
+    for package in packages {
+        let mut chains = Vec::new();
+        let mut visited = FxHashSet::default();
+        visited.insert(package.clone());
+        walk(
+            &package,
+            &dependents,
+            requirements,
+            &mut Vec::new(),
+            &mut visited,
+            &mut chains,
+        );
+
+        for constraint in constraints.get(&package).into_iter().flatten() {
+            chains.push(vec![ProvenanceStep::Constraint {
+                requirement: constraint.to_string(),
+                source: ProvenanceSource::from_requirement(constraint),
+            }]);
+        }
+        for override_ in overrides.get(&package).into_iter().flatten() {
+            chains.push(vec![ProvenanceStep::Override {
+                requirement: override_.to_string(),
+                source: ProvenanceSource::from_requirement(override_),
+            }]);
+        }
+
+        for chain in chains {
+            let entry = RequirementProvenance {
+                package: package.clone(),
+                chain,
+            };
+            if !provenance.contains(&entry) {
+                provenance.push(entry);
+            }
+        }
+    }
+    provenance
+}
+
+type Dependents<'a> =
+    FxHashMap<&'a PackageName, Vec<(&'a PubGrubPackage, &'a Ranges<Version>, &'a Ranges<Version>)>>;
+
+fn collect_dependents<'a>(
+    tree: &'a ErrorTree,
+    dependents: &mut Dependents<'a>,
+    packages: &mut Vec<PackageName>,
+) {
+    match tree {
+        DerivationTree::Derived(derived) => {
+            collect_dependents(&derived.cause1, dependents, packages);
+            collect_dependents(&derived.cause2, dependents, packages);
+        }
+        DerivationTree::External(External::FromDependencyOf(
+            package,
+            versions,
+            dependency,
+            dependency_versions,
+        )) => {
+            let Some(name) = dependency.name() else {
+                return;
+            };
+            if !packages.contains(name) {
+                packages.push(name.clone());
+            }
+            dependents
+                .entry(name)
+                .or_default()
+                .push((package, versions, dependency_versions));
+        }
+        DerivationTree::External(_) => {}
+    }
+}
+
+/// Walk the dependency incompatibilities from the given package up to the root, recording each
+/// chain that terminates in a direct requirement.
+fn walk(
+    name: &PackageName,
+    dependents: &Dependents,
+    requirements: &[Requirement],
+    path: &mut Vec<ProvenanceStep>,
+    visited: &mut FxHashSet<PackageName>,
+    chains: &mut Vec<Vec<ProvenanceStep>>,
+) {
+    for (package, versions, specifier) in dependents.get(name).into_iter().flatten() {
+        match &***package {
+            PubGrubPackageInner::Root(_) => {
+                let mut direct = requirements
+                    .iter()
+                    .filter(|requirement| requirement.name == *name)
+                    .peekable();
+                if direct.peek().is_none() {
+                    let mut chain = path.clone();
+                    chain.push(ProvenanceStep::Requirement {
+                        requirement: match format_range(specifier).as_str() {
+                            "*" => name.to_string(),
+                            specifier => format!("{name}{specifier}"),
+                        },
+                        source: ProvenanceSource::default(),
+                    });
+                    chains.push(chain);
+                }
+                for requirement in direct {
+                    let mut chain = path.clone();
+                    chain.push(ProvenanceStep::Requirement {
+                        requirement: requirement.to_string(),
+                        source: ProvenanceSource::from_requirement(requirement),
+                    });
+                    chains.push(chain);
+                }
+            }
+            PubGrubPackageInner::Package { name: parent, .. }
+            | PubGrubPackageInner::Extra { name: parent, .. }
+            | PubGrubPackageInner::Group { name: parent, .. }
+            | PubGrubPackageInner::Marker { name: parent, .. } => {
+                if !visited.insert(parent.clone()) {
+                    continue;
+                }
+                path.push(ProvenanceStep::Dependency {
+                    package: parent.clone(),
+                    versions: format_range(versions),
+                    specifier: format_range(specifier),
+                });
+                walk(parent, dependents, requirements, path, visited, chains);
+                path.pop();
+                visited.remove(parent);
+            }
+            PubGrubPackageInner::Python(_)
+            | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_) => {}
+        }
+    }
+}
+
+/// Format a version range as a PEP 440-style specifier, e.g., `>=1.0,<2.0`.
+///
+/// Disjoint segments are separated by ` | `, and the full range is formatted as `*`.
+fn format_range(range: &Ranges<Version>) -> String {
+    if range.is_empty() {
+        return "∅".to_string();
+    }
+    range
+        .iter()
+        .map(|(lower, upper)| match (lower, upper) {
+            (Bound::Unbounded, Bound::Unbounded) => "*".to_string(),
+            (Bound::Unbounded, Bound::Included(v)) => format!("<={v}"),
+            (Bound::Unbounded, Bound::Excluded(v)) => format!("<{v}"),
+            (Bound::Included(v), Bound::Unbounded) => format!(">={v}"),
+            (Bound::Excluded(v), Bound::Unbounded) => format!(">{v}"),
+            (Bound::Included(v), Bound::Included(b)) if v == b => format!("=={v}"),
+            (Bound::Included(v), Bound::Included(b)) => format!(">={v},<={b}"),
+            (Bound::Included(v), Bound::Excluded(b)) => format!(">={v},<{b}"),
+            (Bound::Excluded(v), Bound::Included(b)) => format!(">{v},<={b}"),
+            (Bound::Excluded(v), Bound::Excluded(b)) => format!(">{v},<{b}"),
+        })
+        .join(" | ")
+}
+
+/// Locate the (one-based) line on which a requirement for the given package is declared.
+fn find_line(path: &Path, name: &PackageName) -> Option<usize> {
+    let contents = fs_err::read_to_string(path).ok()?;
+    find_line_in(&contents, name)
+}
+
+/// Locate the (one-based) line in the given file contents on which a requirement for the given
+/// package is declared.
+///
+/// This is a best-effort search: the first line that starts with the package name (as in a
+/// `requirements.txt` file) or contains a quoted string that starts with the package name (as in
+/// a `pyproject.toml` file) is returned.
+fn find_line_in(contents: &str, name: &PackageName) -> Option<usize> {
+    contents
+        .lines()
+        .position(|line| {
+            let line = line.trim_start();
+            if line.starts_with('#') {
+                return false;
+            }
+            std::iter::once(line)
+                .chain(line.split(['"', '\'']).skip(1).step_by(2))
+                .any(|candidate| {
+                    let end = candidate
+                        .find(|c: char| {
+                            !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                        })
+                        .unwrap_or(candidate.len());
+                    PackageName::from_str(&candidate[..end])
+                        .is_ok_and(|candidate| candidate == *name)
+                })
+        })
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use pubgrub::Ranges;
+    use uv_pep440::Version;
+
+    use super::{find_line_in, format_range};
+
+    #[test]
+    fn format_ranges() {
+        let version = |v: &str| Version::from_str(v).unwrap();
+        assert_eq!(format_range(&Ranges::full()), "*");
+        assert_eq!(
+            format_range(&Ranges::singleton(version("3.0.0"))),
+            "==3.0.0"
+        );
+        assert_eq!(
+            format_range(&Ranges::between(version("1.0"), version("2.0"))),
+            ">=1.0,<2.0"
+        );
+        assert_eq!(
+            format_range(
+                &Ranges::strictly_lower_than(version("1.0"))
+                    .union(&Ranges::higher_than(version("2.0")))
+            ),
+            "<1.0 | >=2.0"
+        );
+    }
+
+    #[test]
+    fn find_line_requirements_txt() {
+        let contents = "# anyio\nidna==3.6\nanyio>=4 ; python_version >= '3.8'\n";
+
+        let anyio = PackageName::from_str("anyio").unwrap();
+        assert_eq!(find_line_in(contents, &anyio), Some(3));
+
+        let sniffio = PackageName::from_str("sniffio").unwrap();
+        assert_eq!(find_line_in(contents, &sniffio), None);
+    }
+
+    #[test]
+    fn find_line_pyproject_toml() {
+        let contents = "[project]\nname = \"project\"\ndependencies = [\"idna\", \"Typing_Extensions>=4\"]\n\n[dependency-groups]\ndev = [\n    \"anyio>=4\",\n]\n";
+
+        let typing_extensions = PackageName::from_str("typing-extensions").unwrap();
+        assert_eq!(find_line_in(contents, &typing_extensions), Some(3));
+
+        let anyio = PackageName::from_str("anyio").unwrap();
+        assert_eq!(find_line_in(contents, &anyio), Some(7));
+    }
+}
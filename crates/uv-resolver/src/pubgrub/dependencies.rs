@@ -160,6 +160,9 @@ impl PubGrubDependency {
                     unreachable!("Python package in dependencies")
                 }
                 PubGrubPackageInner::System(_) => unreachable!("System package in dependencies"),
+                PubGrubPackageInner::KnownIncompatibility(_) => {
+                    unreachable!("Known incompatibility package in dependencies")
+                }
             }
         })
     }
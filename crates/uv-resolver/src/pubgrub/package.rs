@@ -5,6 +5,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::ConflictItemRef;
 
+use crate::KnownIncompatibility;
 use crate::python_requirement::PythonRequirement;
 
 /// [`Arc`] wrapper around [`PubGrubPackageInner`] to make cloning (inside PubGrub) cheap.
@@ -46,6 +47,11 @@ pub enum PubGrubPackageInner {
     Python(PubGrubPython),
     /// A system package, which is used to represent a non-Python package.
     System(PackageName),
+    /// A virtual package used to enforce a [`KnownIncompatibility`].
+    ///
+    /// Versions covered by either side of the incompatibility depend on a different version of
+    /// this package, such that both sides can't be selected at the same time.
+    KnownIncompatibility(Box<KnownIncompatibility>),
     /// A Python package.
     ///
     /// Note that it is guaranteed that `extra` and `dev` are never both
@@ -142,6 +148,7 @@ impl PubGrubPackage {
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
             | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_)
             | PubGrubPackageInner::Package { .. } => None,
             PubGrubPackageInner::Group { .. } => {
                 // The dependency groups of a package do not by themselves require the package
@@ -164,7 +171,9 @@ impl PubGrubPackage {
         match &**self {
             // A root can never be a dependency of another package, and a `Python` pubgrub
             // package is never returned by `get_dependencies`. So these cases never occur.
-            PubGrubPackageInner::Root(None) | PubGrubPackageInner::Python(_) => None,
+            PubGrubPackageInner::Root(None)
+            | PubGrubPackageInner::Python(_)
+            | PubGrubPackageInner::KnownIncompatibility(_) => None,
             PubGrubPackageInner::Root(Some(name))
             | PubGrubPackageInner::System(name)
             | PubGrubPackageInner::Package { name, .. }
@@ -180,7 +189,8 @@ impl PubGrubPackage {
         match &**self {
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
-            | PubGrubPackageInner::System(_) => None,
+            | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_) => None,
             PubGrubPackageInner::Package { name, .. }
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Group { name, .. }
@@ -196,7 +206,8 @@ impl PubGrubPackage {
             // package is never returned by `get_dependencies`. So these cases never occur.
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
-            | PubGrubPackageInner::System(_) => MarkerTree::TRUE,
+            | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_) => MarkerTree::TRUE,
             PubGrubPackageInner::Package { marker, .. }
             | PubGrubPackageInner::Extra { marker, .. }
             | PubGrubPackageInner::Group { marker, .. } => *marker,
@@ -215,6 +226,7 @@ impl PubGrubPackage {
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
             | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_)
             | PubGrubPackageInner::Package { extra: None, .. }
             | PubGrubPackageInner::Group { .. }
             | PubGrubPackageInner::Marker { .. } => None,
@@ -236,6 +248,7 @@ impl PubGrubPackage {
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
             | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_)
             | PubGrubPackageInner::Package { group: None, .. }
             | PubGrubPackageInner::Extra { .. }
             | PubGrubPackageInner::Marker { .. } => None,
@@ -296,7 +309,8 @@ impl PubGrubPackage {
         match *Arc::make_mut(&mut self.0) {
             PubGrubPackageInner::Root(_)
             | PubGrubPackageInner::Python(_)
-            | PubGrubPackageInner::System(_) => {}
+            | PubGrubPackageInner::System(_)
+            | PubGrubPackageInner::KnownIncompatibility(_) => {}
             PubGrubPackageInner::Package { ref mut marker, .. }
             | PubGrubPackageInner::Extra { ref mut marker, .. }
             | PubGrubPackageInner::Group { ref mut marker, .. }
@@ -313,6 +327,7 @@ impl PubGrubPackage {
             PubGrubPackageInner::Root(_) => "root",
             PubGrubPackageInner::Python(_) => "python",
             PubGrubPackageInner::System(_) => "system",
+            PubGrubPackageInner::KnownIncompatibility(_) => "known-incompatibility",
             PubGrubPackageInner::Package { .. } => "package",
             PubGrubPackageInner::Extra { .. } => "extra",
             PubGrubPackageInner::Group { .. } => "group",
@@ -346,6 +361,9 @@ impl std::fmt::Display for PubGrubPackageInner {
             }
             Self::Python(_) => write!(f, "Python"),
             Self::System(name) => write!(f, "system:{name}"),
+            Self::KnownIncompatibility(incompatibility) => {
+                write!(f, "known-incompatibility:{incompatibility}")
+            }
             Self::Package {
                 name,
                 extra: None,
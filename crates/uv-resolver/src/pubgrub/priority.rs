@@ -130,6 +130,9 @@ impl PubGrubPriorities {
                 (PubGrubPriority::Root, PubGrubTiebreaker::from(2))
             }
             PubGrubPackageInner::System(_) => (PubGrubPriority::Root, PubGrubTiebreaker::from(3)),
+            PubGrubPackageInner::KnownIncompatibility(_) => {
+                (PubGrubPriority::Root, PubGrubTiebreaker::from(4))
+            }
             PubGrubPackageInner::Marker { name, .. }
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Group { name, .. }
@@ -15,7 +15,7 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
-use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, Tags};
+use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTagSet, Tags};
 
 use crate::candidate_selector::CandidateSelector;
 use crate::error::{ErrorTree, PrefixMatch};
@@ -28,7 +28,10 @@ use crate::resolver::{
     MetadataUnavailable, UnavailableErrorChain, UnavailablePackage, UnavailableReason,
     UnavailableVersion,
 };
-use crate::{Flexibility, InMemoryIndex, Options, ResolverEnvironment, VersionsResponse};
+use crate::{
+    Flexibility, InMemoryIndex, IncompatibleRequirement, KnownIncompatibility, Options,
+    ResolverEnvironment, VersionsResponse,
+};
 
 #[derive(Debug)]
 pub(crate) struct PubGrubReportFormatter<'a> {
@@ -142,6 +145,16 @@ impl ReportFormatter<PubGrubPackage, Range<Version>, UnavailableReason>
                 }
             }
             External::FromDependencyOf(package, package_set, dependency, dependency_set) => {
+                if let PubGrubPackageInner::KnownIncompatibility(incompatibility) = &**dependency {
+                    if let Some(name) = package.name() {
+                        return format!(
+                            "{} is known to be incompatible with {}",
+                            self.compatible_range(package, package_set),
+                            incompatibility.counterpart(name),
+                        );
+                    }
+                }
+
                 if package.name_no_root() == dependency.name_no_root() {
                     if let Some(member) = self.format_workspace_member(package) {
                         return format!(
@@ -471,6 +484,18 @@ impl PubGrubReportFormatter<'_> {
         external2: &External<PubGrubPackage, Range<Version>, UnavailableReason>,
     ) -> String {
         match (external1, external2) {
+            (
+                External::FromDependencyOf(package1, package_set1, dependency1, _),
+                External::FromDependencyOf(package2, package_set2, dependency2, _),
+            ) if dependency1 == dependency2
+                && matches!(&**dependency1, PubGrubPackageInner::KnownIncompatibility(_)) =>
+            {
+                format!(
+                    "{} is known to be incompatible with {}",
+                    self.compatible_range(package1, package_set1),
+                    self.compatible_range(package2, package_set2),
+                )
+            }
             (
                 External::FromDependencyOf(package1, package_set1, dependency1, dependency_set1),
                 External::FromDependencyOf(package2, _, dependency2, dependency_set2),
@@ -653,6 +678,14 @@ impl PubGrubReportFormatter<'_> {
                         });
                     }
                 }
+                // Check for a known incompatibility with an explanation.
+                if let PubGrubPackageInner::KnownIncompatibility(incompatibility) = &**dependency {
+                    if incompatibility.reason.is_some() {
+                        output_hints.insert(PubGrubHint::KnownIncompatibility {
+                            incompatibility: (**incompatibility).clone(),
+                        });
+                    }
+                }
                 // Check for no versions due to `Requires-Python`.
                 if matches!(
                     &**dependency,
@@ -788,10 +821,7 @@ impl PubGrubReportFormatter<'_> {
                 // So, instead, we only show the platforms that are linked to otherwise-compatible
                 // wheels (e.g., `manylinux2014` in `cp313-cp313-manylinux2014`). In other words,
                 // we only show platforms for ABI-compatible wheels.
-                let tags = prioritized
-                    .platform_tags(self.tags?)
-                    .cloned()
-                    .collect::<BTreeSet<_>>();
+                let tags = prioritized.platform_tags(self.tags?);
                 if tags.is_empty() {
                     None
                 } else {
@@ -1121,6 +1151,10 @@ pub(crate) enum PubGrubHint {
         package: PackageName,
         workspace: bool,
     },
+    /// A user-declared known incompatibility prevented a combination of package versions.
+    KnownIncompatibility {
+        incompatibility: KnownIncompatibility,
+    },
     /// A package was available on an index, but not at the correct version, and at least one
     /// subsequent index was not queried. As such, a compatible version may be available on
     /// one of the remaining indexes.
@@ -1178,7 +1212,7 @@ pub(crate) enum PubGrubHint {
         // excluded from `PartialEq` and `Hash`
         version: Version,
         // excluded from `PartialEq` and `Hash`
-        tags: BTreeSet<PlatformTag>,
+        tags: PlatformTagSet,
     },
 }
 
@@ -1232,6 +1266,10 @@ enum PubGrubHintCore {
         package: PackageName,
         workspace: bool,
     },
+    KnownIncompatibility {
+        package: IncompatibleRequirement,
+        incompatible_with: IncompatibleRequirement,
+    },
     UncheckedIndex {
         package: PackageName,
     },
@@ -1314,6 +1352,10 @@ impl From<PubGrubHint> for PubGrubHintCore {
             PubGrubHint::DependsOnItself { package, workspace } => {
                 Self::DependsOnItself { package, workspace }
             }
+            PubGrubHint::KnownIncompatibility { incompatibility } => Self::KnownIncompatibility {
+                package: incompatibility.package,
+                incompatible_with: incompatibility.incompatible_with,
+            },
             PubGrubHint::UncheckedIndex { name: package, .. } => Self::UncheckedIndex { package },
             PubGrubHint::UnauthorizedIndex { index } => Self::UnauthorizedIndex { index },
             PubGrubHint::ForbiddenIndex { index } => Self::ForbiddenIndex { index },
@@ -1584,6 +1626,21 @@ impl std::fmt::Display for PubGrubHint {
                     package.cyan(),
                 )
             }
+            Self::KnownIncompatibility { incompatibility } => {
+                write!(
+                    f,
+                    "{}{} `{}` is known to be incompatible with `{}`{}",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    incompatibility.package.cyan(),
+                    incompatibility.incompatible_with.cyan(),
+                    incompatibility
+                        .reason
+                        .as_ref()
+                        .map(|reason| format!(": {reason}"))
+                        .unwrap_or_default(),
+                )
+            }
             Self::UncheckedIndex {
                 name,
                 range,
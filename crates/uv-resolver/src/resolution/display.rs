@@ -5,7 +5,9 @@ use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
+use uv_distribution_types::{
+    DistributionMetadata, Name, RequiresPython, SourceAnnotation, SourceAnnotations,
+};
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 
@@ -35,6 +37,9 @@ pub struct DisplayResolutionGraph<'a> {
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The Python versions to which the output should be narrowed, if narrower than those of the
+    /// resolution.
+    requires_python: Option<RequiresPython>,
 }
 
 #[derive(Debug)]
@@ -79,8 +84,19 @@ impl<'a> DisplayResolutionGraph<'a> {
             include_annotations,
             include_index_annotation,
             annotation_style,
+            requires_python: None,
         }
     }
+
+    /// Narrow the output to the given Python versions.
+    ///
+    /// Packages that are never installed on the given Python versions are omitted, and markers are
+    /// simplified under the assumption that one of the given Python versions is in use.
+    #[must_use]
+    pub fn with_requires_python(mut self, requires_python: RequiresPython) -> Self {
+        self.requires_python = Some(requires_python);
+        self
+    }
 }
 
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
@@ -165,6 +181,11 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             strip_extras(&graph)
         };
 
+        let requires_python = self
+            .requires_python
+            .as_ref()
+            .unwrap_or(&self.resolution.requires_python);
+
         // Collect all packages.
         let mut nodes = graph
             .node_indices()
@@ -175,6 +196,17 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     return None;
                 }
 
+                // Omit any packages that are never installed on the narrowed Python versions.
+                if self
+                    .requires_python
+                    .as_ref()
+                    .is_some_and(|requires_python| {
+                        dist.markers.is_disjoint(requires_python.to_marker_tree())
+                    })
+                {
+                    return None;
+                }
+
                 Some((index, dist))
             })
             .collect::<Vec<_>>();
@@ -186,7 +218,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         for (index, node) in nodes {
             // Display the node itself.
             let mut line = node
-                .to_requirements_txt(&self.resolution.requires_python, self.include_markers)
+                .to_requirements_txt(requires_python, self.include_markers)
                 .to_string();
 
             // Display the distribution hashes, if any.
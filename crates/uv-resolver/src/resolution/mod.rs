@@ -13,11 +13,15 @@ pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
 pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
+pub use crate::resolution::requires_python::{
+    RequiresPythonDiagnostic, infer_requires_python_upper_bound,
+};
 use crate::universal_marker::UniversalMarker;
 
 mod display;
 mod output;
 mod requirements_txt;
+mod requires_python;
 
 /// A pinned package with its resolved distribution and metadata. The [`ResolvedDist`] refers to a
 /// specific distribution (e.g., a specific wheel), while the [`Metadata23`] refers to the metadata
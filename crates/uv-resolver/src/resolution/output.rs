@@ -31,6 +31,7 @@ use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     InMemoryIndex, MetadataResponse, Options, PythonRequirement, ResolveError, VersionsResponse,
+    YankedPolicy,
 };
 
 /// The output of a successful resolution.
@@ -228,6 +229,17 @@ impl ResolverOutput {
             report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides);
         }
 
+        // Omit the warnings for any yanked versions that were explicitly permitted.
+        diagnostics.retain(|diagnostic| {
+            let ResolutionDiagnostic::YankedVersion { dist, .. } = diagnostic else {
+                return true;
+            };
+            options.yanked_policy == YankedPolicy::Warn
+                && !options.allow_yanked.iter().any(|allowed| {
+                    allowed.name == *dist.name() && Some(&allowed.version) == dist.version()
+                })
+        });
+
         let output = Self {
             graph,
             requires_python,
@@ -613,6 +625,18 @@ impl ResolverOutput {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Return the distinct versions of the given package in the graph, in ascending order.
+    pub fn versions(&self, name: &PackageName) -> Vec<&Version> {
+        let mut versions = self
+            .dists()
+            .filter(|dist| dist.name() == name)
+            .map(|dist| &dist.version)
+            .collect::<Vec<_>>();
+        versions.sort_unstable();
+        versions.dedup();
+        versions
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
@@ -0,0 +1,456 @@
+//! Compare the project's `requires-python` against the Python versions supported by the resolved
+//! packages.
+//!
+//! The resolver only enforces the lower bound of a dependency's `requires-python`, and accepts
+//! packages without a source distribution as long as _some_ wheel is compatible. As such, a
+//! resolution can include packages that don't support all Python versions included in the
+//! project's `requires-python`, which only surfaces at install time.
+
+use std::collections::Bound;
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{
+    BuiltDist, Dist, RequiresPython, RequiresPythonRange, ResolvedDist, SourceDist,
+};
+use uv_normalize::PackageName;
+use uv_pep440::{UpperBound, Version, VersionSpecifiers};
+use uv_platform_tags::{AbiTag, LanguageTag};
+
+use crate::ResolverOutput;
+use crate::resolution::ResolutionGraphNode;
+
+/// A resolved package that doesn't support some of the Python versions included in the project's
+/// `requires-python`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequiresPythonDiagnostic {
+    /// The package declares a `requires-python` upper bound that excludes the Python versions.
+    UpperBound {
+        name: PackageName,
+        version: Version,
+        requires_python: VersionSpecifiers,
+        /// The excluded Python minor versions, e.g., `[13]` for Python 3.13.
+        minors: Vec<u8>,
+    },
+    /// The package has no source distribution, and no wheels for the Python versions.
+    MissingWheels {
+        name: PackageName,
+        version: Version,
+        /// The unsupported Python minor versions, e.g., `[13]` for Python 3.13.
+        minors: Vec<u8>,
+    },
+}
+
+impl RequiresPythonDiagnostic {
+    /// The name of the package that doesn't support the Python versions.
+    pub fn package_name(&self) -> &PackageName {
+        match self {
+            Self::UpperBound { name, .. } | Self::MissingWheels { name, .. } => name,
+        }
+    }
+
+    /// The lowest Python minor version that the package doesn't support.
+    fn first_minor(&self) -> Option<u8> {
+        match self {
+            Self::UpperBound { minors, .. } | Self::MissingWheels { minors, .. } => {
+                minors.first().copied()
+            }
+        }
+    }
+}
+
+impl Display for RequiresPythonDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpperBound {
+                name,
+                version,
+                requires_python,
+                minors,
+            } => write!(
+                f,
+                "`{name}=={version}` declares `requires-python = \"{requires_python}\"`, which excludes {}",
+                PythonMinors(minors)
+            ),
+            Self::MissingWheels {
+                name,
+                version,
+                minors,
+            } => write!(
+                f,
+                "`{name}=={version}` has no source distribution and no wheels for {}",
+                PythonMinors(minors)
+            ),
+        }
+    }
+}
+
+/// Display a list of Python minor versions, e.g., `Python 3.12 and 3.13`.
+struct PythonMinors<'a>(&'a [u8]);
+
+impl Display for PythonMinors<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            [] => Ok(()),
+            [minor] => write!(f, "Python 3.{minor}"),
+            [rest @ .., last] => write!(
+                f,
+                "Python {} and 3.{last}",
+                rest.iter().map(|minor| format!("3.{minor}")).join(", ")
+            ),
+        }
+    }
+}
+
+/// Infer the upper bound that the project's `requires-python` would need to cover only the Python
+/// versions supported by all packages, e.g., `<3.13`.
+pub fn infer_requires_python_upper_bound(
+    requires_python: &RequiresPython,
+    diagnostics: &[RequiresPythonDiagnostic],
+) -> Option<VersionSpecifiers> {
+    let minor = diagnostics
+        .iter()
+        .filter_map(RequiresPythonDiagnostic::first_minor)
+        .min()?;
+    let range = RequiresPythonRange::new(
+        requires_python.range().lower().clone(),
+        UpperBound::new(Bound::Excluded(Version::new([3, u64::from(minor)]))),
+    );
+    Some(range.specifiers())
+}
+
+/// The Python versions supported by a resolved package.
+#[derive(Debug)]
+struct PackageSupport<'a> {
+    name: &'a PackageName,
+    version: &'a Version,
+    /// The `requires-python` declared by the package.
+    requires_python: Option<&'a VersionSpecifiers>,
+    /// The wheels of the package.
+    wheels: Vec<&'a WheelFilename>,
+    /// Whether the package has a source distribution, which can be built for any Python version.
+    sdist: bool,
+}
+
+impl ResolverOutput {
+    /// Compare the `requires-python` of the resolution against the Python versions supported by
+    /// each resolved registry package, as declared by its `requires-python` and the tags of its
+    /// wheels.
+    ///
+    /// Python versions beyond the newest version targeted by any wheel in the resolution are not
+    /// considered, such that unreleased Python versions aren't reported.
+    pub fn requires_python_diagnostics(&self) -> Vec<RequiresPythonDiagnostic> {
+        let mut seen = FxHashSet::default();
+        let packages = self
+            .graph
+            .node_weights()
+            .filter_map(|node| match node {
+                ResolutionGraphNode::Dist(dist) if dist.is_base() => Some(dist),
+                _ => None,
+            })
+            .filter(|&dist| seen.insert((&dist.name, &dist.version)))
+            .filter_map(|dist| {
+                let ResolvedDist::Installable { dist: inner, .. } = &dist.dist else {
+                    return None;
+                };
+                let (wheels, sdist) = match inner.as_ref() {
+                    Dist::Built(BuiltDist::Registry(built)) => {
+                        (&built.wheels, built.sdist.is_some())
+                    }
+                    Dist::Source(SourceDist::Registry(source)) => (&source.wheels, true),
+                    // Only consider registry packages, to ignore workspace members and the like.
+                    _ => return None,
+                };
+                Some(PackageSupport {
+                    name: &dist.name,
+                    version: &dist.version,
+                    requires_python: dist
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.requires_python.as_ref()),
+                    wheels: wheels.iter().map(|wheel| &wheel.filename).collect(),
+                    sdist,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        analyze(&self.requires_python, &packages)
+    }
+}
+
+/// Determine the Python minor versions included in the `requires-python` that each package
+/// doesn't support.
+fn analyze(
+    requires_python: &RequiresPython,
+    packages: &[PackageSupport<'_>],
+) -> Vec<RequiresPythonDiagnostic> {
+    // Determine the Python minor versions to consider, from the lower bound of the
+    // `requires-python` up to the newest Python version targeted by any wheel.
+    let Some(lower) = minor_of(requires_python.range().lower().major_minor().as_ref()) else {
+        return Vec::new();
+    };
+    let newest = packages
+        .iter()
+        .flat_map(|package| &package.wheels)
+        .copied()
+        .flat_map(targeted_minors)
+        .max()
+        .unwrap_or(lower);
+    let upper = requires_python.range().upper().major_minor();
+    let minors = (lower..=newest)
+        .filter(|minor| UpperBound::new(Bound::Included(python_minor(*minor))) <= upper)
+        .collect::<Vec<_>>();
+
+    let mut diagnostics = Vec::new();
+    for package in packages {
+        // Find the Python versions excluded by the upper bound of the package's `requires-python`.
+        // (Versions excluded by the lower bound are rejected by the resolver.)
+        let mut excluded = Vec::new();
+        if let Some(specifiers) = package.requires_python {
+            let package_upper = RequiresPython::from_specifiers(specifiers)
+                .range()
+                .upper()
+                .major_minor();
+            excluded = minors
+                .iter()
+                .copied()
+                .filter(|minor| {
+                    UpperBound::new(Bound::Included(python_minor(*minor))) > package_upper
+                })
+                .collect();
+            if !excluded.is_empty() {
+                diagnostics.push(RequiresPythonDiagnostic::UpperBound {
+                    name: package.name.clone(),
+                    version: package.version.clone(),
+                    requires_python: specifiers.clone(),
+                    minors: excluded.clone(),
+                });
+            }
+        }
+
+        // Find the Python versions without a compatible wheel.
+        if !package.sdist {
+            let missing = minors
+                .iter()
+                .copied()
+                .filter(|minor| !excluded.contains(minor))
+                .filter(|minor| {
+                    !package
+                        .wheels
+                        .iter()
+                        .any(|wheel| supports_minor(wheel, *minor))
+                })
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                diagnostics.push(RequiresPythonDiagnostic::MissingWheels {
+                    name: package.name.clone(),
+                    version: package.version.clone(),
+                    minors: missing,
+                });
+            }
+        }
+    }
+    diagnostics
+}
+
+/// Return the Python 3 minor version of a bound, if any.
+fn minor_of(bound: Bound<&Version>) -> Option<u8> {
+    let version = match bound {
+        Bound::Included(version) | Bound::Excluded(version) => version,
+        Bound::Unbounded => return None,
+    };
+    match version.release() {
+        [3, minor, ..] => u8::try_from(*minor).ok(),
+        _ => None,
+    }
+}
+
+fn python_minor(minor: u8) -> Version {
+    Version::new([3, u64::from(minor)])
+}
+
+/// Return the CPython minor versions that the wheel targets specifically, e.g., `12` for
+/// `cp312-cp312-manylinux_2_17_x86_64.whl`.
+fn targeted_minors(wheel: &WheelFilename) -> impl Iterator<Item = u8> + '_ {
+    wheel.python_tags().iter().filter_map(|tag| match tag {
+        LanguageTag::CPython {
+            python_version: (3, minor),
+        } => Some(*minor),
+        _ => None,
+    })
+}
+
+/// Returns `true` if the wheel can be installed on (the GIL-enabled build of) CPython `3.{minor}`,
+/// on any platform.
+fn supports_minor(wheel: &WheelFilename, minor: u8) -> bool {
+    wheel.python_tags().iter().any(|python_tag| {
+        wheel
+            .abi_tags()
+            .iter()
+            .any(|abi_tag| match (python_tag, abi_tag) {
+                // Ex) `py3-none-any`
+                (
+                    LanguageTag::Python {
+                        major: 3,
+                        minor: None,
+                    },
+                    AbiTag::None | AbiTag::Abi3,
+                ) => true,
+                // Ex) `py38-none-any`, which is compatible with Python 3.8 and later.
+                (
+                    LanguageTag::Python {
+                        major: 3,
+                        minor: Some(tag_minor),
+                    },
+                    AbiTag::None | AbiTag::Abi3,
+                ) => *tag_minor <= minor,
+                // Ex) `cp38-abi3-manylinux_2_17_x86_64`, which is compatible with CPython 3.8 and later.
+                (
+                    LanguageTag::CPython {
+                        python_version: (3, tag_minor),
+                    },
+                    AbiTag::Abi3,
+                ) => *tag_minor <= minor,
+                // Ex) `cp312-none-any`
+                (
+                    LanguageTag::CPython {
+                        python_version: (3, tag_minor),
+                    },
+                    AbiTag::None,
+                ) => *tag_minor == minor,
+                // Ex) `cp312-cp312-manylinux_2_17_x86_64`
+                (
+                    LanguageTag::CPython { .. },
+                    AbiTag::CPython {
+                        gil_disabled: false,
+                        python_version: (3, abi_minor),
+                        ..
+                    },
+                ) => *abi_minor == minor,
+                _ => false,
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn wheel(filename: &str) -> WheelFilename {
+        WheelFilename::from_str(filename).unwrap()
+    }
+
+    #[test]
+    fn supports() {
+        let universal = wheel("anyio-4.3.0-py3-none-any.whl");
+        assert!(supports_minor(&universal, 8));
+        assert!(supports_minor(&universal, 13));
+
+        let abi3 = wheel("cryptography-42.0.5-cp39-abi3-manylinux_2_28_x86_64.whl");
+        assert!(!supports_minor(&abi3, 8));
+        assert!(supports_minor(&abi3, 13));
+
+        let specific = wheel("numpy-1.26.4-cp312-cp312-manylinux_2_17_x86_64.whl");
+        assert!(supports_minor(&specific, 12));
+        assert!(!supports_minor(&specific, 13));
+
+        let free_threaded = wheel("numpy-2.1.0-cp313-cp313t-manylinux_2_17_x86_64.whl");
+        assert!(!supports_minor(&free_threaded, 13));
+    }
+
+    #[test]
+    fn diagnostics() {
+        let torch = PackageName::from_str("torch").unwrap();
+        let torch_version = Version::from_str("2.2.1").unwrap();
+        let torch_wheels = [
+            wheel("torch-2.2.1-cp311-cp311-manylinux1_x86_64.whl"),
+            wheel("torch-2.2.1-cp312-cp312-manylinux1_x86_64.whl"),
+        ];
+
+        let scipy = PackageName::from_str("scipy").unwrap();
+        let scipy_version = Version::from_str("1.11.4").unwrap();
+        let scipy_requires_python = VersionSpecifiers::from_str(">=3.9, <3.13").unwrap();
+
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let numpy_version = Version::from_str("2.1.0").unwrap();
+        let numpy_wheels = [
+            wheel("numpy-2.1.0-cp311-cp311-manylinux_2_17_x86_64.whl"),
+            wheel("numpy-2.1.0-cp312-cp312-manylinux_2_17_x86_64.whl"),
+            wheel("numpy-2.1.0-cp313-cp313-manylinux_2_17_x86_64.whl"),
+        ];
+
+        let packages = [
+            PackageSupport {
+                name: &torch,
+                version: &torch_version,
+                requires_python: None,
+                wheels: torch_wheels.iter().collect(),
+                sdist: false,
+            },
+            PackageSupport {
+                name: &scipy,
+                version: &scipy_version,
+                requires_python: Some(&scipy_requires_python),
+                wheels: Vec::new(),
+                sdist: true,
+            },
+            PackageSupport {
+                name: &numpy,
+                version: &numpy_version,
+                requires_python: None,
+                wheels: numpy_wheels.iter().collect(),
+                sdist: true,
+            },
+        ];
+
+        let requires_python =
+            RequiresPython::from_specifiers(&VersionSpecifiers::from_str(">=3.11").unwrap());
+
+        // Without any wheels for Python 3.13 in the resolution, nothing is reported.
+        assert_eq!(analyze(&requires_python, &packages[..2]), []);
+
+        // Otherwise, neither `torch` nor `scipy` support Python 3.13.
+        let diagnostics = analyze(&requires_python, &packages);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "`torch==2.2.1` has no source distribution and no wheels for Python 3.13",
+                "`scipy==1.11.4` declares `requires-python = \">=3.9, <3.13\"`, which excludes Python 3.13",
+            ]
+        );
+        assert_eq!(
+            infer_requires_python_upper_bound(&requires_python, &diagnostics)
+                .unwrap()
+                .to_string(),
+            ">=3.11, <3.13"
+        );
+
+        // An upper bound on the project's `requires-python` excludes Python 3.13.
+        let requires_python =
+            RequiresPython::from_specifiers(&VersionSpecifiers::from_str(">=3.11, <3.13").unwrap());
+        assert_eq!(analyze(&requires_python, &packages), []);
+
+        // Versions older than the lower bound aren't considered.
+        let requires_python =
+            RequiresPython::from_specifiers(&VersionSpecifiers::from_str(">=3.10").unwrap());
+        let diagnostics = analyze(&requires_python, &packages);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "`torch==2.2.1` has no source distribution and no wheels for Python 3.10 and 3.13",
+                "`scipy==1.11.4` declares `requires-python = \">=3.9, <3.13\"`, which excludes Python 3.13",
+            ]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use uv_distribution_types::{IndexMetadata, RequirementSource};
+use uv_distribution_types::{IndexLocations, IndexMetadata, PackageNamePattern, RequirementSource};
 use uv_normalize::PackageName;
 use uv_pypi_types::ConflictItem;
 
@@ -18,8 +18,16 @@ use crate::{DependencyMode, Manifest, ResolverEnvironment};
 /// ```
 ///
 /// [`Indexes`] would contain a single entry mapping `torch` to `https://download.pytorch.org/whl/cu121`.
+///
+/// Packages may also be pinned to an index via the `packages` patterns on the index definition,
+/// which apply to both direct and transitive dependencies.
 #[derive(Debug, Default, Clone)]
-pub(crate) struct Indexes(ForkMap<Entry>);
+pub(crate) struct Indexes {
+    /// The indexes that are explicitly requested by the requirements in the manifest.
+    explicit: ForkMap<Entry>,
+    /// The indexes that claim packages by name pattern.
+    patterns: Vec<(Vec<PackageNamePattern>, IndexMetadata)>,
+}
 
 #[derive(Debug, Clone)]
 struct Entry {
@@ -28,9 +36,10 @@ struct Entry {
 }
 
 impl Indexes {
-    /// Determine the set of explicit, pinned indexes in the [`Manifest`].
+    /// Determine the set of explicit, pinned indexes in the [`Manifest`] and [`IndexLocations`].
     pub(crate) fn from_manifest(
         manifest: &Manifest,
+        locations: &IndexLocations,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
     ) -> Self {
@@ -50,17 +59,38 @@ impl Indexes {
             indexes.add(&requirement, Entry { index, conflict });
         }
 
-        Self(indexes)
+        let patterns = locations
+            .simple_indexes()
+            .filter(|index| !index.packages.is_empty())
+            .map(|index| {
+                (
+                    index.packages.clone(),
+                    IndexMetadata {
+                        url: index.url.clone(),
+                        format: index.format,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            explicit: indexes,
+            patterns,
+        }
     }
 
     /// Returns `true` if the map contains any indexes for a package.
     pub(crate) fn contains_key(&self, name: &PackageName) -> bool {
-        self.0.contains_key(name)
+        self.explicit.contains_key(name) || self.pattern(name).is_some()
     }
 
     /// Return the explicit index used for a package in the given fork.
     pub(crate) fn get(&self, name: &PackageName, env: &ResolverEnvironment) -> Vec<&IndexMetadata> {
-        let entries = self.0.get(name, env);
+        // Explicit requests take precedence over any index patterns.
+        if !self.explicit.contains_key(name) {
+            return self.pattern(name).into_iter().collect();
+        }
+        let entries = self.explicit.get(name, env);
         entries
             .iter()
             .filter(|entry| {
@@ -72,4 +102,12 @@ impl Indexes {
             .map(|entry| &entry.index)
             .collect()
     }
+
+    /// Return the first index whose patterns match the package, if any.
+    fn pattern(&self, name: &PackageName) -> Option<&IndexMetadata> {
+        self.patterns
+            .iter()
+            .find(|(patterns, _)| patterns.iter().any(|pattern| pattern.matches(name)))
+            .map(|(_, index)| index)
+    }
 }
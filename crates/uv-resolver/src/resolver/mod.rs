@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::{Display, Formatter, Write};
+use std::num::NonZeroUsize;
 use std::ops::Bound;
 use std::sync::Arc;
 use std::time::Instant;
@@ -42,13 +43,14 @@ use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError};
+use crate::error::{BudgetExceededError, NoSolutionError, ResolutionBudget, ResolveError};
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
 use crate::preferences::{PreferenceSource, Preferences};
+use crate::provenance::collect_provenance;
 use crate::pubgrub::{
     PubGrubDependency, PubGrubDistribution, PubGrubPackage, PubGrubPackageInner, PubGrubPriorities,
     PubGrubPython,
@@ -99,6 +101,9 @@ mod urls;
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
 const CONFLICT_THRESHOLD: usize = 5;
 
+/// The number of contested packages to report when the resolution exhausts its budget.
+const MOST_CONTESTED_LIMIT: usize = 10;
+
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
     state: ResolverState<InstalledPackages>,
     provider: Provider,
@@ -182,7 +187,13 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
+            AllowedYanks::from_manifest(
+                &manifest,
+                &env,
+                options.dependency_mode,
+                options.yanked_policy,
+                &options.allow_yanked,
+            ),
             hasher,
             options.exclude_newer.clone(),
             build_context.build_options(),
@@ -234,8 +245,14 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             capabilities: capabilities.clone(),
             selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
-            urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode),
-            indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
+            urls: Urls::from_manifest(
+                &manifest,
+                &env,
+                git,
+                options.dependency_mode,
+                &options.fallbacks,
+            ),
+            indexes: Indexes::from_manifest(&manifest, locations, &env, options.dependency_mode),
             project: manifest.project,
             workspace_members: manifest.workspace_members,
             requirements: manifest.requirements,
@@ -275,6 +292,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
     }
 
     /// Resolve a set of requirements into a set of pinned versions.
+    #[instrument(skip_all)]
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
         let state = Arc::new(self.state);
         let provider = Arc::new(self.provider);
@@ -343,6 +361,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let resolution_start = Instant::now();
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -351,6 +370,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
             let start = Instant::now();
             loop {
+                self.check_budget(&state, resolution_start)?;
+
                 let highest_priority_pkg =
                     if let Some(initial) = state.initial_id.take() {
                         // If we just forked based on `requires-python`, we can skip unit
@@ -529,6 +550,28 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     let Some(version) = decision else {
                         debug!("No compatible version found for: {next_package}");
 
+                        // If the index can't satisfy the package, retry with its fallback source,
+                        // pinning the URL for the remainder of the fork.
+                        if let Some(name) = next_package.name_no_root() {
+                            if url.is_none() {
+                                if let Some(fallback) = self.urls.get_fallback(&state.env, name) {
+                                    debug!(
+                                        "Falling back to {} for: {next_package}",
+                                        fallback.verbatim
+                                    );
+                                    let fallback = fallback.clone();
+                                    state.fork_urls.insert(name, &fallback, &state.env)?;
+                                    self.request_package(
+                                        next_package,
+                                        Some(&fallback),
+                                        index,
+                                        request_sink,
+                                    )?;
+                                    continue;
+                                }
+                            }
+                        }
+
                         let term_intersection = state
                             .pubgrub
                             .partial_solution
@@ -744,6 +787,46 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         )
     }
 
+    /// Abort the resolution if it exhausted the configured backtracking or time budget, reporting
+    /// the most contested packages.
+    fn check_budget(
+        &self,
+        state: &ForkState,
+        resolution_start: Instant,
+    ) -> Result<(), ResolveError> {
+        let budget = if let Some((package, backtracks)) = state
+            .conflict_tracker
+            .most_affected
+            .filter(|(_, backtracks)| {
+                self.options
+                    .max_backtracks
+                    .is_some_and(|limit| *backtracks > limit.get())
+            })
+            .and_then(|(package, backtracks)| {
+                let package = state.pubgrub.package_store[package].name()?.clone();
+                Some((package, backtracks))
+            }) {
+            ResolutionBudget::Backtracks {
+                package,
+                backtracks,
+                limit: self.options.max_backtracks.map_or(0, NonZeroUsize::get),
+            }
+        } else if let Some(timeout) = self
+            .options
+            .resolution_timeout
+            .filter(|timeout| resolution_start.elapsed() > *timeout)
+        {
+            ResolutionBudget::Timeout(timeout)
+        } else {
+            return Ok(());
+        };
+
+        debug!("Resolution budget exceeded: {budget:?}");
+        Err(ResolveError::BudgetExceeded(Box::new(
+            BudgetExceededError::new(budget, state.most_contested(MOST_CONTESTED_LIMIT)),
+        )))
+    }
+
     /// Change the priority of often conflicting packages and backtrack.
     ///
     /// To be called after unit propagation.
@@ -1095,7 +1178,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 Ok(None)
             }
 
-            PubGrubPackageInner::System(_) => {
+            PubGrubPackageInner::System(_) | PubGrubPackageInner::KnownIncompatibility(_) => {
                 // We don't care what the actual version is here, just that it's consistent across
                 // the dependency graph.
                 let Some(version) = range.as_singleton() else {
@@ -1800,6 +1883,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     python_requirement,
                 );
 
+                // Pin any packages that are provided by the environment to their provided version.
+                let provided_dependencies = self.excludes.provided().map(|(name, version)| {
+                    PubGrubDependency::from(SystemDependency::provided(
+                        name.clone(),
+                        version.clone(),
+                    ))
+                });
+
                 requirements
                     .flat_map(move |requirement| {
                         PubGrubDependency::from_requirement(
@@ -1809,6 +1900,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             Some(package),
                         )
                     })
+                    .chain(provided_dependencies)
                     .collect()
             }
 
@@ -1907,6 +1999,20 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     })
                     .map(PubGrubDependency::from);
 
+                // Enforce any known incompatibilities that cover this version.
+                let known_incompatibilities = self
+                    .options
+                    .known_incompatibilities
+                    .iter()
+                    .filter(|_| extra.is_none() && group.is_none())
+                    .filter_map(|incompatibility| incompatibility.dependency(name, version))
+                    .inspect(|dependency| {
+                        debug!(
+                            "Adding known incompatibility `{}` for `{package}@{version}`",
+                            dependency.package
+                        );
+                    });
+
                 let requirements = self.flatten_requirements(
                     &metadata.requires_dist,
                     &metadata.dependency_groups,
@@ -1918,16 +2024,26 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 );
 
                 requirements
-                    .filter(|requirement| !self.excludes.contains(&requirement.name))
                     .flat_map(|requirement| {
-                        PubGrubDependency::from_requirement(
-                            &self.conflicts,
-                            requirement,
-                            group.as_ref(),
-                            Some(package),
-                        )
+                        if !self.excludes.contains(&requirement.name) {
+                            Either::Left(PubGrubDependency::from_requirement(
+                                &self.conflicts,
+                                requirement,
+                                group.as_ref(),
+                                Some(package),
+                            ))
+                        } else if self.excludes.provided_version(&requirement.name).is_some() {
+                            // Check requirements on provided packages against the provided version.
+                            Either::Right(
+                                Some(SystemDependency::from_provided_requirement(&requirement))
+                                    .into_iter(),
+                            )
+                        } else {
+                            Either::Right(None.into_iter())
+                        }
                     })
                     .chain(system_dependencies)
+                    .chain(known_incompatibilities)
                     .collect()
             }
 
@@ -1935,6 +2051,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
             PubGrubPackageInner::System(_) => return Ok(Dependencies::Unforkable(Vec::default())),
 
+            PubGrubPackageInner::KnownIncompatibility(_) => {
+                return Ok(Dependencies::Unforkable(Vec::default()));
+            }
+
             // Add a dependency on both the marker and base package.
             PubGrubPackageInner::Marker { name, marker } => {
                 return Ok(Dependencies::Unforkable(
@@ -2781,6 +2901,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        let provenance =
+            collect_provenance(&err, &self.requirements, &self.constraints, &self.overrides);
+
         ResolveError::NoSolution(Box::new(NoSolutionError::new(
             err,
             self.index.clone(),
@@ -2799,6 +2922,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.tags.clone(),
             self.workspace_members.clone(),
             self.options.clone(),
+            provenance,
         )))
     }
 
@@ -2808,6 +2932,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 PubGrubPackageInner::Root(_) => {}
                 PubGrubPackageInner::Python(_) => {}
                 PubGrubPackageInner::System(_) => {}
+                PubGrubPackageInner::KnownIncompatibility(_) => {}
                 PubGrubPackageInner::Marker { .. } => {}
                 PubGrubPackageInner::Extra { .. } => {}
                 PubGrubPackageInner::Group { .. } => {}
@@ -3133,7 +3258,38 @@ impl ForkState {
             if *affected_count == CONFLICT_THRESHOLD {
                 self.conflict_tracker.prioritize.push(self.next);
             }
+            if self
+                .conflict_tracker
+                .most_affected
+                .is_none_or(|(_, count)| *affected_count > count)
+            {
+                self.conflict_tracker.most_affected = Some((self.next, *affected_count));
+            }
+        }
+    }
+
+    /// Return the packages involved in the most conflicts so far, most contested first, along with
+    /// the number of conflicts they were involved in.
+    fn most_contested(&self, limit: usize) -> Vec<(PackageName, usize)> {
+        let mut conflicts: FxHashMap<&PackageName, usize> = FxHashMap::default();
+        for (package, count) in self
+            .conflict_tracker
+            .affected
+            .iter()
+            .chain(&self.conflict_tracker.culprit)
+        {
+            if let Some(name) = self.pubgrub.package_store[*package].name() {
+                *conflicts.entry(name).or_default() += count;
+            }
         }
+        conflicts
+            .into_iter()
+            .sorted_by(|(name_a, count_a), (name_b, count_b)| {
+                count_b.cmp(count_a).then_with(|| name_a.cmp(name_b))
+            })
+            .take(limit)
+            .map(|(name, count)| (name.clone(), count))
+            .collect()
     }
 
     fn add_unavailable_version(&mut self, version: Version, reason: UnavailableVersion) {
@@ -4118,4 +4274,8 @@ struct ConflictTracker {
     ///
     /// Distilled from `culprit` for fast checking in the hot loop.
     deprioritize: Vec<Id<PubGrubPackage>>,
+    /// The package whose decisions were discarded most often, and how often.
+    ///
+    /// Distilled from `affected` for checking the backtracking budget in the hot loop.
+    most_affected: Option<(Id<PubGrubPackage>, usize)>,
 }
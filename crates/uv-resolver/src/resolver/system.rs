@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use pubgrub::Ranges;
 
+use uv_distribution_types::{Requirement, RequirementSource};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_redacted::DisplaySafeUrl;
@@ -18,6 +19,28 @@ pub(super) struct SystemDependency {
 }
 
 impl SystemDependency {
+    /// Create a [`SystemDependency`] for a package that is provided by the environment at the
+    /// given version (e.g., `torch==2.4.0`).
+    pub(super) fn provided(name: PackageName, version: Version) -> Self {
+        Self { name, version }
+    }
+
+    /// Convert a requirement on a package that is provided by the environment into a dependency
+    /// on the corresponding system package, such that the requirement is checked against the
+    /// provided version rather than resolved.
+    pub(super) fn from_provided_requirement(requirement: &Requirement) -> PubGrubDependency {
+        let version = match &requirement.source {
+            RequirementSource::Registry { specifier, .. } => Ranges::from(specifier.clone()),
+            _ => Ranges::full(),
+        };
+        PubGrubDependency {
+            package: PubGrubPackage::from(PubGrubPackageInner::System(requirement.name.clone())),
+            version,
+            parent: None,
+            url: None,
+        }
+    }
+
     /// Extract a [`SystemDependency`] from an index URL.
     ///
     /// For example, given `https://download.pytorch.org/whl/cu124`, returns CUDA 12.4.
@@ -4,6 +4,7 @@ use same_file::is_same_file;
 use tracing::debug;
 
 use uv_cache_key::CanonicalUrl;
+use uv_distribution_types::Requirement;
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, VerbatimUrl};
@@ -29,6 +30,10 @@ pub(crate) struct Urls {
     /// URLs from regular requirements or from constraints. There can be multiple URLs for the same
     /// package as long as they are in different forks.
     regular: FxHashMap<PackageName, Vec<VerbatimParsedUrl>>,
+    /// URLs from `fallback` sources, used in forks where no compatible version of the package is
+    /// available on the index. There can be multiple URLs for the same package as long as they are
+    /// in different forks.
+    fallbacks: FxHashMap<PackageName, Vec<(MarkerTree, VerbatimParsedUrl)>>,
 }
 
 impl Urls {
@@ -37,6 +42,7 @@ impl Urls {
         env: &ResolverEnvironment,
         git: &GitResolver,
         dependencies: DependencyMode,
+        fallback_requirements: &[Requirement],
     ) -> Self {
         let mut regular: FxHashMap<PackageName, Vec<VerbatimParsedUrl>> = FxHashMap::default();
         let mut overrides: FxHashMap<PackageName, Vec<(MarkerTree, VerbatimParsedUrl)>> =
@@ -91,7 +97,28 @@ impl Urls {
                 .push((requirement.marker, url));
         }
 
-        Self { overrides, regular }
+        // Add all fallback URLs. These are only consulted once the index is exhausted, so they
+        // don't restrict the URLs allowed for regular requirements.
+        let mut fallbacks: FxHashMap<PackageName, Vec<(MarkerTree, VerbatimParsedUrl)>> =
+            FxHashMap::default();
+        for requirement in fallback_requirements {
+            if !requirement.evaluate_markers(env.marker_environment(), &[]) {
+                continue;
+            }
+            let Some(url) = requirement.source.to_verbatim_parsed_url() else {
+                continue;
+            };
+            fallbacks
+                .entry(requirement.name.clone())
+                .or_default()
+                .push((requirement.marker, url));
+        }
+
+        Self {
+            overrides,
+            regular,
+            fallbacks,
+        }
     }
 
     /// Return an iterator over the allowed URLs for the given package.
@@ -127,6 +154,19 @@ impl Urls {
         }
     }
 
+    /// Return the fallback URL for the given package in the given fork, if any.
+    pub(crate) fn get_fallback(
+        &self,
+        env: &ResolverEnvironment,
+        name: &PackageName,
+    ) -> Option<&VerbatimParsedUrl> {
+        self.fallbacks
+            .get(name)?
+            .iter()
+            .find(|(marker, _)| env.included_by_marker(*marker))
+            .map(|(_, url)| url)
+    }
+
     /// Return `true` if the package has any URL (from overrides or regular requirements).
     pub(crate) fn any_url(&self, name: &PackageName) -> bool {
         self.get_overrides(name).is_some() || self.get_regular(name).is_some()
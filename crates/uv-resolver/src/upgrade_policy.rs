@@ -0,0 +1,22 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UpgradePolicy {
+    /// When a locked version is no longer compatible, select the best version from the full range
+    /// of compatible versions.
+    #[default]
+    Any,
+    /// When a locked version is no longer compatible, prefer versions with the same major version
+    /// as the locked version, and only move to a different major version if necessary.
+    SameMajor,
+}
+
+impl std::fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            Self::SameMajor => write!(f, "same-major"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use std::sync::Arc;
 
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -8,6 +9,92 @@ use uv_pep440::Version;
 
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
+/// The policy for selecting and installing package versions that are marked as yanked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum YankedPolicy {
+    /// Reject yanked versions, unless they're included in `allow-yanked`.
+    Error,
+    /// Allow yanked versions if they're pinned with `==` or in the lockfile, and warn when they're
+    /// selected or installed.
+    #[default]
+    Warn,
+    /// Allow yanked versions if they're pinned with `==` or in the lockfile, without warning.
+    AllowPinned,
+}
+
+impl std::fmt::Display for YankedPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warn => write!(f, "warn"),
+            Self::AllowPinned => write!(f, "allow-pinned"),
+        }
+    }
+}
+
+/// A package version that is permitted even if it's yanked, e.g., `foo==1.2.3`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AllowedYank {
+    pub name: PackageName,
+    pub version: Version,
+}
+
+impl FromStr for AllowedYank {
+    type Err = String;
+
+    /// Parses an [`AllowedYank`] from a string in the format `PACKAGE==VERSION`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, version)) = s.split_once("==") else {
+            return Err(format!(
+                "Invalid `allow-yanked` value `{s}`: expected format `PACKAGE==VERSION`"
+            ));
+        };
+
+        let name = PackageName::from_str(name.trim())
+            .map_err(|err| format!("Invalid `allow-yanked` package name `{name}`: {err}"))?;
+        let version = Version::from_str(version.trim())
+            .map_err(|err| format!("Invalid `allow-yanked` version `{version}`: {err}"))?;
+
+        Ok(Self { name, version })
+    }
+}
+
+impl std::fmt::Display for AllowedYank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}=={}", self.name, self.version)
+    }
+}
+
+impl serde::Serialize for AllowedYank {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AllowedYank {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AllowedYank {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("AllowedYank")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A yanked package version to allow, in the format `PACKAGE==VERSION`.",
+        })
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
@@ -18,9 +105,24 @@ impl AllowedYanks {
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        yanked_policy: YankedPolicy,
+        allow_yanked: &[AllowedYank],
     ) -> Self {
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
+        // Allow yanks for any versions that were explicitly allowed.
+        for allowed in allow_yanked {
+            allowed_yanks
+                .entry(allowed.name.clone())
+                .or_default()
+                .insert(allowed.version.clone());
+        }
+
+        // Under the strict policy, pins don't permit yanked versions.
+        if yanked_policy == YankedPolicy::Error {
+            return Self(Arc::new(allowed_yanks));
+        }
+
         // Allow yanks for any pinned input requirements.
         for requirement in manifest.requirements(env, dependencies) {
             let RequirementSource::Registry { specifier, .. } = &requirement.source else {
@@ -20,6 +20,9 @@ use uv_workspace::pyproject::{ExtraBuildDependency, Sources};
 
 static FINDER: LazyLock<Finder> = LazyLock::new(|| Finder::new(b"# /// script"));
 
+/// The shebang that executes a script via `uv run`, respecting its inline metadata.
+const UV_SHEBANG: &str = "#!/usr/bin/env -S uv run --script";
+
 /// A PEP 723 item, either read from a script on disk or provided via `stdin`.
 #[derive(Debug)]
 pub enum Pep723Item {
@@ -254,9 +257,14 @@ impl Pep723Script {
     }
 
     /// Create a PEP 723 script at the given path.
+    ///
+    /// If `shebang` is set, the script starts with a shebang that invokes `uv run --script`
+    /// (replacing any existing shebang that doesn't invoke uv), and is marked as executable.
     pub async fn create(
         file: impl AsRef<Path>,
         requires_python: &VersionSpecifiers,
+        dependencies: &[String],
+        shebang: bool,
         existing_contents: Option<Vec<u8>>,
     ) -> Result<(), Pep723Error> {
         let file = file.as_ref();
@@ -266,36 +274,58 @@ impl Pep723Script {
             .and_then(|name| name.to_str())
             .ok_or_else(|| Pep723Error::InvalidFilename(file.to_string_lossy().to_string()))?;
 
+        let dependencies = if dependencies.is_empty() {
+            "[]".to_string()
+        } else {
+            let mut array = "[\n".to_string();
+            for dependency in dependencies {
+                array.push_str(&format!("  {},\n", toml::Value::String(dependency.clone())));
+            }
+            array.push(']');
+            array
+        };
+
         let default_metadata = indoc::formatdoc! {r#"
             requires-python = "{requires_python}"
-            dependencies = []
+            dependencies = {dependencies}
             "#,
         };
         let metadata = serialize_metadata(&default_metadata);
 
         let script = if let Some(existing_contents) = existing_contents {
-            let (mut shebang, contents) = extract_shebang(&existing_contents)?;
-            if !shebang.is_empty() {
-                shebang.push_str("\n#\n");
+            let (mut existing_shebang, contents) = extract_shebang(&existing_contents)?;
+            let invokes_uv = regex::Regex::new(r"\buv\b")
+                .unwrap()
+                .is_match(&existing_shebang);
+            if shebang && !invokes_uv {
+                existing_shebang = UV_SHEBANG.to_string();
+            }
+            if !existing_shebang.is_empty() {
+                existing_shebang.push_str("\n#\n");
                 // If the shebang doesn't contain `uv`, it's probably something like
                 // `#! /usr/bin/env python`, which isn't going to respect the inline metadata.
                 // Issue a warning for users who might not know that.
                 // TODO: There are a lot of mistakes we could consider detecting here, like
                 // `uv run` without `--script` when the file doesn't end in `.py`.
-                if !regex::Regex::new(r"\buv\b").unwrap().is_match(&shebang) {
+                if !shebang && !invokes_uv {
                     warn_user!(
                         "If you execute {} directly, it might ignore its inline metadata.\nConsider replacing its shebang with: {}",
                         file.to_string_lossy().cyan(),
-                        "#!/usr/bin/env -S uv run --script".cyan(),
+                        UV_SHEBANG.cyan(),
                     );
                 }
             }
             indoc::formatdoc! {r"
-            {shebang}{metadata}
+            {existing_shebang}{metadata}
             {contents}" }
         } else {
+            let shebang = if shebang {
+                format!("{UV_SHEBANG}\n#\n")
+            } else {
+                String::new()
+            };
             indoc::formatdoc! {r#"
-            {metadata}
+            {shebang}{metadata}
 
             def main() -> None:
                 print("Hello from {name}!")
@@ -304,12 +334,25 @@ impl Pep723Script {
             if __name__ == "__main__":
                 main()
         "#,
+                shebang = shebang,
                 metadata = metadata,
                 name = script_name,
             }
         };
 
-        Ok(fs_err::tokio::write(file, script).await?)
+        fs_err::tokio::write(file, script).await?;
+
+        // Mark the script as executable, such that the shebang can be used.
+        #[cfg(unix)]
+        if shebang {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mut permissions = fs_err::tokio::metadata(file).await?.permissions();
+            permissions.set_mode(permissions.mode() | 0o111);
+            fs_err::tokio::set_permissions(file, permissions).await?;
+        }
+
+        Ok(())
     }
 
     /// Replace the existing metadata in the file with new metadata and write the updated content.
@@ -438,6 +481,7 @@ pub struct ToolUv {
     pub top_level: ResolverInstallerSchema,
     pub override_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
     pub exclude_dependencies: Option<Vec<uv_normalize::PackageName>>,
+    pub provided_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
     pub constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
     pub build_constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
     pub extra_build_dependencies: Option<BTreeMap<PackageName, Vec<ExtraBuildDependency>>>,
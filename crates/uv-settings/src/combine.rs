@@ -3,27 +3,28 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 
 use url::Url;
 
+use uv_cache::CachePartition;
 use uv_configuration::{
-    BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, Reinstall, RequiredVersion,
-    TargetTriple, TrustedPublishing, Upgrade,
+    BuildIsolation, DownloadRate, ExportFormat, IndexStrategy, KeyringProviderType, Reinstall,
+    RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
     PipFindLinks, PipIndex,
 };
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptLaunchers};
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{PythonDownloadAttestations, PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, UpgradePolicy, YankedPolicy,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{FilesystemOptions, Options, PipOptions, SyncProfile};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -84,6 +85,7 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(CachePartition);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerTimestamp);
 impl_combine_or!(ExportFormat);
@@ -94,25 +96,31 @@ impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
 impl_combine_or!(DisplaySafeUrl);
+impl_combine_or!(DownloadRate);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
 impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
+impl_combine_or!(PythonDownloadAttestations);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
+impl_combine_or!(ScriptLaunchers);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
+impl_combine_or!(UpgradePolicy);
 impl_combine_or!(Url);
+impl_combine_or!(YankedPolicy);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -159,6 +167,22 @@ impl Combine for Option<ExcludeNewerPackage> {
     }
 }
 
+impl Combine for Option<BTreeMap<String, SyncProfile>> {
+    /// Combine two sets of profiles by merging them, with the profiles in `self` taking
+    /// precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (name, profile) in b {
+                    a.entry(name).or_insert(profile);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
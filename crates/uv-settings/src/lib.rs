@@ -211,8 +211,11 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         add: _,
         pip: _,
         cache_keys: _,
+        sync_profiles: _,
+        env_files: _,
         override_dependencies: _,
         exclude_dependencies: _,
+        provided_dependencies: _,
         constraint_dependencies: _,
         build_constraint_dependencies: _,
         environments,
@@ -220,12 +223,16 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         conflicts,
         workspace,
         sources,
+        patches,
         dev_dependencies,
         default_groups,
         dependency_groups,
         managed,
+        marker_provenance,
+        upgrade_policy,
         package,
         build_backend,
+        test,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
     // permitted by the schema since they _can_ be included in `pyproject.toml` files
@@ -239,6 +246,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if sources.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "sources"));
     }
+    if patches.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "patches"));
+    }
     if dev_dependencies.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -260,6 +270,18 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
+    if marker_provenance.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "marker-provenance",
+        ));
+    }
+    if upgrade_policy.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "upgrade-policy",
+        ));
+    }
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
@@ -269,6 +291,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "build-backend",
         ));
     }
+    if test.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "test"));
+    }
     if environments.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -296,12 +321,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_partition,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_downloads_per_host,
+                max_download_rate,
                 allow_insecure_host,
             },
         top_level:
@@ -316,6 +344,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 resolution,
                 prerelease,
                 fork_strategy,
+                yanked_policy,
+                allow_yanked,
+                known_incompatibilities,
+                max_backtracks,
+                resolution_timeout,
+                prefer_smaller_artifacts,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -327,6 +361,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 exclude_newer_package,
                 link_mode,
                 compile_bytecode,
+                script_launcher,
+                no_gui_scripts,
+                wheel_checks,
                 no_sources,
                 upgrade,
                 upgrade_package,
@@ -341,6 +378,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             PythonInstallMirrors {
                 python_install_mirror,
                 pypy_install_mirror,
+                python_download_attestations,
                 python_downloads_json_url,
             },
         publish:
@@ -349,11 +387,17 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 trusted_publishing,
                 check_url,
             },
-        add: AddOptions { add_bounds },
+        add: AddOptions {
+            add_bounds,
+            add_upgrade_policy,
+        },
         pip,
         cache_keys,
+        sync_profiles,
+        env_files,
         override_dependencies,
         exclude_dependencies,
+        provided_dependencies,
         constraint_dependencies,
         build_constraint_dependencies,
         environments: _,
@@ -361,12 +405,16 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         conflicts: _,
         workspace: _,
         sources: _,
+        patches: _,
         dev_dependencies: _,
         default_groups: _,
         dependency_groups: _,
         managed: _,
+        marker_provenance: _,
+        upgrade_policy: _,
         package: _,
         build_backend: _,
+        test: _,
     } = options;
 
     let mut masked_fields = vec![];
@@ -386,6 +434,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_partition.is_some() {
+        masked_fields.push("cache-partition");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -404,6 +455,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if concurrent_installs.is_some() {
         masked_fields.push("concurrent-installs");
     }
+    if concurrent_downloads_per_host.is_some() {
+        masked_fields.push("concurrent-downloads-per-host");
+    }
+    if max_download_rate.is_some() {
+        masked_fields.push("max-download-rate");
+    }
     if allow_insecure_host.is_some() {
         masked_fields.push("allow-insecure-host");
     }
@@ -437,6 +494,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if yanked_policy.is_some() {
+        masked_fields.push("yanked-policy");
+    }
+    if allow_yanked.is_some() {
+        masked_fields.push("allow-yanked");
+    }
+    if known_incompatibilities.is_some() {
+        masked_fields.push("known-incompatibilities");
+    }
+    if max_backtracks.is_some() {
+        masked_fields.push("max-backtracks");
+    }
+    if resolution_timeout.is_some() {
+        masked_fields.push("resolution-timeout");
+    }
+    if prefer_smaller_artifacts.is_some() {
+        masked_fields.push("prefer-smaller-artifacts");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -470,6 +545,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if compile_bytecode.is_some() {
         masked_fields.push("compile-bytecode");
     }
+    if script_launcher.is_some() {
+        masked_fields.push("script-launcher");
+    }
+    if no_gui_scripts.is_some() {
+        masked_fields.push("no-gui-scripts");
+    }
+    if wheel_checks.is_some() {
+        masked_fields.push("wheel-checks");
+    }
     if no_sources.is_some() {
         masked_fields.push("no-sources");
     }
@@ -503,6 +587,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if pypy_install_mirror.is_some() {
         masked_fields.push("pypy-install-mirror");
     }
+    if python_download_attestations.is_some() {
+        masked_fields.push("python-download-attestations");
+    }
     if python_downloads_json_url.is_some() {
         masked_fields.push("python-downloads-json-url");
     }
@@ -518,18 +605,30 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if add_upgrade_policy.is_some() {
+        masked_fields.push("add-upgrade-policy");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
+    if sync_profiles.is_some() {
+        masked_fields.push("sync-profiles");
+    }
+    if env_files.is_some() {
+        masked_fields.push("env-files");
+    }
     if override_dependencies.is_some() {
         masked_fields.push("override-dependencies");
     }
     if exclude_dependencies.is_some() {
         masked_fields.push("exclude-dependencies");
     }
+    if provided_dependencies.is_some() {
+        masked_fields.push("provided-dependencies");
+    }
     if constraint_dependencies.is_some() {
         masked_fields.push("constraint-dependencies");
     }
@@ -576,6 +675,7 @@ pub struct Concurrency {
     pub downloads: Option<NonZeroUsize>,
     pub builds: Option<NonZeroUsize>,
     pub installs: Option<NonZeroUsize>,
+    pub downloads_per_host: Option<NonZeroUsize>,
 }
 
 /// Options loaded from environment variables.
@@ -592,6 +692,7 @@ pub struct EnvironmentOptions {
     pub http_timeout: Duration,
     pub http_retries: u32,
     pub upload_http_timeout: Duration,
+    pub tool_run_cache_ttl: Option<Duration>,
     pub concurrency: Concurrency,
     #[cfg(feature = "tracing-durations-export")]
     pub tracing_durations_file: Option<PathBuf>,
@@ -621,6 +722,9 @@ impl EnvironmentOptions {
                 downloads: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_DOWNLOADS)?,
                 builds: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_BUILDS)?,
                 installs: parse_integer_environment_variable(EnvVars::UV_CONCURRENT_INSTALLS)?,
+                downloads_per_host: parse_integer_environment_variable(
+                    EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+                )?,
             },
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: parse_string_environment_variable(
@@ -629,6 +733,7 @@ impl EnvironmentOptions {
                 pypy_install_mirror: parse_string_environment_variable(
                     EnvVars::UV_PYPY_INSTALL_MIRROR,
                 )?,
+                python_download_attestations: None,
                 python_downloads_json_url: parse_string_environment_variable(
                     EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL,
                 )?,
@@ -641,6 +746,8 @@ impl EnvironmentOptions {
             .or(http_timeout)
             .unwrap_or(Duration::from_secs(15 * 60)),
             http_timeout: http_timeout.unwrap_or(Duration::from_secs(30)),
+            tool_run_cache_ttl: parse_integer_environment_variable(EnvVars::UV_TOOL_RUN_CACHE_TTL)?
+                .map(Duration::from_secs),
             http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES)?
                 .unwrap_or(uv_client::DEFAULT_RETRIES),
             #[cfg(feature = "tracing-durations-export")]
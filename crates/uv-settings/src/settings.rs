@@ -1,26 +1,29 @@
+use std::collections::BTreeMap;
 use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use uv_cache::CachePartition;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, IndexStrategy, KeyringProviderType, PackageNameSpecifier, Reinstall,
-    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    BuildIsolation, DownloadRate, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PackageConfigSettings,
     PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptLaunchers, WheelCheck};
 use uv_macros::{CombineOptions, OptionsMetadata};
-use uv_normalize::{ExtraName, PackageName, PipGroupName};
+use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::Requirement;
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{PythonDownloadAttestations, PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    AllowedYank, AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp,
+    ForkStrategy, KnownIncompatibility, PrereleaseMode, ResolutionMode, UpgradePolicy,
+    YankedPolicy,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -109,6 +112,46 @@ pub struct Options {
     )]
     pub cache_keys: Option<Vec<CacheKey>>,
 
+    /// Named combinations of `uv sync` options, to be selected with `uv sync --profile`.
+    ///
+    /// Each profile can include the groups and extras to install, which packages to omit from
+    /// the installation, and whether the lockfile must be up-to-date, such that frequently-used
+    /// combinations of flags (e.g., for CI or for building a container image) can be versioned
+    /// alongside the project.
+    ///
+    /// The settings in a profile are combined with any flags provided on the command line: lists
+    /// (like `groups`) are extended, and flags (like `no-install-project`) are enabled if they're
+    /// set in either.
+    ///
+    /// If a profile with the same name is defined in both a user-level `uv.toml` and the
+    /// project, the project-level profile is used.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            sync-profiles = { ci = { groups = ["test"], no-install-project = true, locked = true } }
+        "#
+    )]
+    pub sync_profiles: Option<BTreeMap<String, SyncProfile>>,
+
+    /// Environment files to load when running commands with `uv run`.
+    ///
+    /// Files are loaded in order, such that values in later files override those in earlier files.
+    /// Any files provided via `--env-file` are layered on top of these, while variables that are
+    /// already set in the environment take precedence over all files. Values may reference other
+    /// variables with `${VAR}`, including those defined in earlier files.
+    ///
+    /// Relative paths are resolved relative to the directory containing the configuration file.
+    /// Each file must exist. Pass `--no-env-file` to skip loading environment files.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            env-files = [".env", ".env.local"]
+        "#
+    )]
+    pub env_files: Option<Vec<PathBuf>>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -118,6 +161,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub exclude_dependencies: Option<Vec<uv_normalize::PackageName>>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub provided_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
 
@@ -142,6 +188,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub sources: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub patches: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub dev_dependencies: Option<serde::de::IgnoredAny>,
 
@@ -154,11 +203,20 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub managed: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub marker_provenance: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub upgrade_policy: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
 
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_backend: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub test: Option<serde::de::IgnoredAny>,
 }
 
 impl Options {
@@ -176,6 +234,12 @@ impl Options {
         Ok(Self {
             top_level: self.top_level.relative_to(root_dir)?,
             pip: self.pip.map(|pip| pip.relative_to(root_dir)).transpose()?,
+            env_files: self.env_files.map(|env_files| {
+                env_files
+                    .into_iter()
+                    .map(|env_file| root_dir.join(env_file))
+                    .collect()
+            }),
             ..self
         })
     }
@@ -248,6 +312,23 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// Partition the cache by project or by index.
+    ///
+    /// With `project`, each project uses a separate cache, keyed by the path to the workspace root.
+    /// With `index`, each default index uses a separate cache, keyed by the URL of the default index
+    /// configured in `pyproject.toml` or `uv.toml` (or PyPI, if none is configured).
+    ///
+    /// Partitions are stored in the `partitions` directory of the cache directory, and can be
+    /// mounted as separate volumes.
+    #[option(
+        default = "\"none\"",
+        value_type = "str",
+        example = r#"
+            cache-partition = "project"
+        "#,
+        possible_values = true
+    )]
+    pub cache_partition: Option<CachePartition>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -311,6 +392,33 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The maximum number of in-flight concurrent requests that uv will send to any single host
+    /// at any given time (e.g., to avoid tripping the rate limits of an index).
+    ///
+    /// By default, requests are only limited by `concurrent-downloads`.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-downloads-per-host = 4
+        "#
+    )]
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The maximum rate at which uv will download data, in bytes per second, across all
+    /// concurrent downloads.
+    ///
+    /// Accepts a number of bytes with an optional unit, e.g., `500K`, `10M` (megabytes), or
+    /// `10Mi` (mebibytes).
+    ///
+    /// By default, downloads are not rate-limited.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-download-rate = "10M"
+        "#
+    )]
+    pub max_download_rate: Option<DownloadRate>,
     /// Allow insecure connections to host.
     ///
     /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
@@ -365,6 +473,12 @@ pub struct ResolverOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub yanked_policy: Option<YankedPolicy>,
+    pub allow_yanked: Option<Vec<AllowedYank>>,
+    pub known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    pub max_backtracks: Option<NonZeroUsize>,
+    pub resolution_timeout: Option<u64>,
+    pub prefer_smaller_artifacts: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -395,6 +509,12 @@ pub struct ResolverInstallerOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub yanked_policy: Option<YankedPolicy>,
+    pub allow_yanked: Option<Vec<AllowedYank>>,
+    pub known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    pub max_backtracks: Option<NonZeroUsize>,
+    pub resolution_timeout: Option<u64>,
+    pub prefer_smaller_artifacts: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -405,6 +525,9 @@ pub struct ResolverInstallerOptions {
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
     pub link_mode: Option<LinkMode>,
     pub compile_bytecode: Option<bool>,
+    pub script_launcher: Option<ScriptLaunchers>,
+    pub no_gui_scripts: Option<bool>,
+    pub wheel_checks: Option<Vec<WheelCheck>>,
     pub no_sources: Option<bool>,
     pub upgrade: Option<Upgrade>,
     pub reinstall: Option<Reinstall>,
@@ -427,6 +550,12 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            yanked_policy,
+            allow_yanked,
+            known_incompatibilities,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -438,6 +567,9 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             exclude_newer_package,
             link_mode,
             compile_bytecode,
+            script_launcher,
+            no_gui_scripts,
+            wheel_checks,
             no_sources,
             upgrade,
             upgrade_package,
@@ -459,6 +591,12 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             resolution,
             prerelease,
             fork_strategy,
+            yanked_policy,
+            allow_yanked,
+            known_incompatibilities,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -472,6 +610,9 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             exclude_newer_package,
             link_mode,
             compile_bytecode,
+            script_launcher,
+            no_gui_scripts,
+            wheel_checks,
             no_sources,
             upgrade: Upgrade::from_args(
                 upgrade,
@@ -653,8 +794,9 @@ pub struct ResolverInstallerSchema {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`,
+    /// uv reads credentials directly from the system's credential store, i.e., the macOS
+    /// Keychain, the Windows Credential Manager, or the Secret Service on Linux.
     #[option(
         default = "\"disabled\"",
         value_type = "str",
@@ -709,6 +851,94 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The policy to apply when the resolver selects a yanked version.
+    ///
+    /// By default (`warn`), uv will only select a yanked version if it's pinned exactly (e.g.,
+    /// `==1.2.3`) or preserved from an existing lockfile, and will warn when doing so.
+    ///
+    /// Under `allow-pinned`, uv will select such versions without a warning. Under `error`, uv
+    /// will refuse to select any yanked version that isn't listed in `allow-yanked`.
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked-policy = "error"
+        "#,
+        possible_values = true
+    )]
+    pub yanked_policy: Option<YankedPolicy>,
+    /// Yanked versions that the resolver is allowed to select, specified as `PACKAGE==VERSION`.
+    ///
+    /// Listed versions are selected silently, regardless of the `yanked-policy`. The allowlist is
+    /// recorded in the lockfile, such that changing it invalidates the lock.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-yanked = ["attrs==21.1.0"]
+        "#
+    )]
+    pub allow_yanked: Option<Vec<AllowedYank>>,
+    /// Package version combinations that are known to be incompatible, even though their metadata
+    /// permits them to be installed together.
+    ///
+    /// Each entry declares a `package` and the versions it's `incompatible-with`, both specified
+    /// as a package name with optional version specifiers (e.g., `numpy>=2`), along with an
+    /// optional `reason`. The resolver will never select versions from both sides of an entry at
+    /// once; if there's no other solution, resolution fails with an explanation that includes the
+    /// `reason`.
+    ///
+    /// Known incompatibilities are recorded in the lockfile, such that changing them invalidates
+    /// the lock.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            known-incompatibilities = [
+                { package = "pandas==2.1.*", incompatible-with = "numpy>=2", reason = "pandas 2.1 was compiled against the NumPy 1.x ABI" },
+            ]
+        "#
+    )]
+    pub known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    /// The maximum number of times the resolver may backtrack on a single package before aborting
+    /// the resolution.
+    ///
+    /// When the limit is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no limit.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-backtracks = 500
+        "#
+    )]
+    pub max_backtracks: Option<NonZeroUsize>,
+    /// The maximum time, in seconds, the resolver may run before aborting the resolution.
+    ///
+    /// When the timeout is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no timeout.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-timeout = 300
+        "#
+    )]
+    pub resolution_timeout: Option<u64>,
+    /// Prefer the variant with the smallest artifacts among versions that only differ in their
+    /// local version segment, e.g., `2.4.0+cpu` over `2.4.0+cu121`.
+    ///
+    /// By default, the highest local version is selected. With this option, uv compares the size
+    /// of the best-matching wheel of each variant instead, which avoids pulling in large variants
+    /// (such as CUDA builds) when a smaller one satisfies the same requirements.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            prefer-smaller-artifacts = true
+        "#
+    )]
+    pub prefer_smaller_artifacts: Option<bool>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -722,12 +952,22 @@ pub struct ResolverInstallerSchema {
     /// - (Optional) `requires-dist`: The dependencies of the package (e.g., `werkzeug>=0.14`).
     /// - (Optional) `requires-python`: The Python version required by the package (e.g., `>=3.10`).
     /// - (Optional) `provides-extra`: The extras provided by the package.
+    /// - (Optional) `versions`: A version specifier (e.g., `>=1.0,<2.0`) restricting the metadata to
+    ///   matching versions of the package. Entries with an exact `version` take precedence.
+    /// - (Optional) `add-requires-dist`: Dependencies to add to those published by the package.
+    /// - (Optional) `remove-requires-dist`: The names of dependencies to remove from those published
+    ///   by the package.
+    ///
+    /// Entries that set `add-requires-dist` or `remove-requires-dist` patch the published metadata,
+    /// rather than replacing it. uv warns if a patch no longer applies to the published metadata, or
+    /// if an entry is identical to it, as the entry has likely drifted from the package.
     #[option(
         default = r#"[]"#,
         value_type = "list[dict]",
         example = r#"
             dependency-metadata = [
                 { name = "flask", version = "1.0.0", requires-dist = ["werkzeug"], requires-python = ">=3.6" },
+                { name = "tensorflow", versions = "<2.16", remove-requires-dist = ["tensorflow-io-gcs-filesystem"] },
             ]
         "#
     )]
@@ -829,15 +1069,15 @@ pub struct ResolverInstallerSchema {
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
     /// will break all installed packages by way of removing the underlying source files. Use
     /// symlinks with caution.
     #[option(
-        default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
+        default = "\"clone\" (macOS), \"reflink-auto\" (Linux), or \"hardlink\" (Windows)",
         value_type = "str",
         example = r#"
             link-mode = "copy"
@@ -863,6 +1103,63 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub compile_bytecode: Option<bool>,
+    /// The kind of launcher to generate for the console and GUI scripts of installed packages.
+    ///
+    /// - `native`: Generate the platform's native launcher, i.e., a Python script with a shebang on
+    ///   Unix, or a launcher executable on Windows.
+    /// - `shell`: Generate a POSIX shell script that executes the entry point with the
+    ///   environment's interpreter, regardless of the length of the interpreter path.
+    /// - `symlink`: Write each launcher to the package's `.dist-info` directory, alongside its entry
+    ///   point metadata, and symlink to it from the scripts directory.
+    ///
+    /// The `shell` and `symlink` launchers are only supported on Unix; on Windows, uv falls back to
+    /// the native launcher. Similarly, relocatable environments always use native launchers in
+    /// lieu of `symlink`. To use a different launcher on each platform, provide a table with
+    /// `linux`, `macos`, and `windows` keys; omitted platforms use the native launcher.
+    #[option(
+        default = "\"native\"",
+        value_type = "str | dict",
+        example = r#"
+            script-launcher = { linux = "symlink", macos = "shell" }
+        "#
+    )]
+    pub script_launcher: Option<ScriptLaunchers>,
+    /// Skip generating launchers for the GUI scripts (`gui_scripts` entry points) of installed
+    /// packages.
+    ///
+    /// Useful for server deployments, in which GUI applications are never launched.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-gui-scripts = true
+        "#
+    )]
+    pub no_gui_scripts: Option<bool>,
+    /// Checks to perform on the contents of each wheel before it's installed, rejecting wheels
+    /// that fail any of them.
+    ///
+    /// - `prefixes`: Reject wheels with files that would be installed outside of the environment's
+    ///   install locations, i.e., with `..` path components in the `RECORD`, or in a `.data`
+    ///   subdirectory other than `purelib`, `platlib`, `headers`, `scripts`, and `data`.
+    /// - `absolute-paths`: Reject wheels whose `RECORD` lists files by absolute path.
+    /// - `world-writable`: Reject wheels containing world-writable files (only enforced on Unix).
+    /// - `stdlib-shadowing`: Reject wheels with top-level modules that shadow a module of the
+    ///   Python standard library (e.g., `typing.py`), including backports of removed modules.
+    /// - `record-paths`: Reject wheels whose `RECORD` lists malformed paths (e.g., non-UTF-8,
+    ///   absolute, or `\`-separated paths). By default, such paths are normalized with a warning.
+    ///
+    /// The checks are intended as a supply-chain hardening layer, and are disabled by default.
+    /// Regardless of the enabled checks, `RECORD` paths that would escape the install location
+    /// (e.g., with `..` components or a drive prefix) are always rejected.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            wheel-checks = ["prefixes", "absolute-paths", "world-writable", "stdlib-shadowing"]
+        "#
+    )]
+    pub wheel_checks: Option<Vec<WheelCheck>>,
     /// Ignore the `tool.uv.sources` table when resolving dependencies. Used to lock against the
     /// standards-compliant, publishable package metadata, as opposed to using any local or Git
     /// sources.
@@ -996,6 +1293,28 @@ pub struct PythonInstallMirrors {
     )]
     pub pypy_install_mirror: Option<String>,
 
+    /// A trust policy for the build provenance of managed CPython downloads.
+    ///
+    /// When set, uv verifies the [GitHub artifact attestation](https://docs.github.com/en/actions/security-for-github-actions/using-artifact-attestations)
+    /// published for each downloaded CPython archive before extracting it, and fails the
+    /// installation if verification fails. Verification requires the GitHub CLI (`gh`).
+    ///
+    /// By default, the archive must have been built by `astral-sh/python-build-standalone`. The
+    /// `repository` key can be used to trust a different repository (e.g., an internal fork used
+    /// with `python-install-mirror`), `signer-workflow` restricts the workflow that signed the
+    /// attestation, and `deny-self-hosted-runners` rejects builds from self-hosted runners.
+    ///
+    /// PyPy and other implementations do not publish attestations, so they can't be installed
+    /// while a trust policy is set.
+    #[option(
+        default = "None",
+        value_type = "dict",
+        example = r#"
+            python-download-attestations = { repository = "astral-sh/python-build-standalone", deny-self-hosted-runners = true }
+        "#
+    )]
+    pub python_download_attestations: Option<PythonDownloadAttestations>,
+
     /// URL pointing to JSON of custom Python installations.
     #[option(
         default = "None",
@@ -1013,6 +1332,9 @@ impl PythonInstallMirrors {
         Self {
             python_install_mirror: self.python_install_mirror.or(other.python_install_mirror),
             pypy_install_mirror: self.pypy_install_mirror.or(other.pypy_install_mirror),
+            python_download_attestations: self
+                .python_download_attestations
+                .or(other.python_download_attestations),
             python_downloads_json_url: self
                 .python_downloads_json_url
                 .or(other.python_downloads_json_url),
@@ -1182,8 +1504,9 @@ pub struct PipOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// With `subprocess`, uv invokes the `keyring` CLI to handle authentication. With `native`,
+    /// uv reads credentials directly from the system's credential store, i.e., the macOS
+    /// Keychain, the Windows Credential Manager, or the Secret Service on Linux.
     #[option(
         default = "disabled",
         value_type = "str",
@@ -1404,6 +1727,94 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The policy to apply when the resolver selects a yanked version.
+    ///
+    /// By default (`warn`), uv will only select a yanked version if it's pinned exactly (e.g.,
+    /// `==1.2.3`) or preserved from an existing lockfile, and will warn when doing so.
+    ///
+    /// Under `allow-pinned`, uv will select such versions without a warning. Under `error`, uv
+    /// will refuse to select any yanked version that isn't listed in `allow-yanked`.
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked-policy = "error"
+        "#,
+        possible_values = true
+    )]
+    pub yanked_policy: Option<YankedPolicy>,
+    /// Yanked versions that the resolver is allowed to select, specified as `PACKAGE==VERSION`.
+    ///
+    /// Listed versions are selected silently, regardless of the `yanked-policy`. The allowlist is
+    /// recorded in the lockfile, such that changing it invalidates the lock.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-yanked = ["attrs==21.1.0"]
+        "#
+    )]
+    pub allow_yanked: Option<Vec<AllowedYank>>,
+    /// Package version combinations that are known to be incompatible, even though their metadata
+    /// permits them to be installed together.
+    ///
+    /// Each entry declares a `package` and the versions it's `incompatible-with`, both specified
+    /// as a package name with optional version specifiers (e.g., `numpy>=2`), along with an
+    /// optional `reason`. The resolver will never select versions from both sides of an entry at
+    /// once; if there's no other solution, resolution fails with an explanation that includes the
+    /// `reason`.
+    ///
+    /// Known incompatibilities are recorded in the lockfile, such that changing them invalidates
+    /// the lock.
+    #[option(
+        default = "[]",
+        value_type = "list[dict]",
+        example = r#"
+            known-incompatibilities = [
+                { package = "pandas==2.1.*", incompatible-with = "numpy>=2", reason = "pandas 2.1 was compiled against the NumPy 1.x ABI" },
+            ]
+        "#
+    )]
+    pub known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    /// The maximum number of times the resolver may backtrack on a single package before aborting
+    /// the resolution.
+    ///
+    /// When the limit is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no limit.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-backtracks = 500
+        "#
+    )]
+    pub max_backtracks: Option<NonZeroUsize>,
+    /// The maximum time, in seconds, the resolver may run before aborting the resolution.
+    ///
+    /// When the timeout is exceeded, uv fails with a report of the most contested packages, rather
+    /// than continuing to search for a solution. By default, there is no timeout.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-timeout = 300
+        "#
+    )]
+    pub resolution_timeout: Option<u64>,
+    /// Prefer the variant with the smallest artifacts among versions that only differ in their
+    /// local version segment, e.g., `2.4.0+cpu` over `2.4.0+cu121`.
+    ///
+    /// By default, the highest local version is selected. With this option, uv compares the size
+    /// of the best-matching wheel of each variant instead, which avoids pulling in large variants
+    /// (such as CUDA builds) when a smaller one satisfies the same requirements.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            prefer-smaller-artifacts = true
+        "#
+    )]
+    pub prefer_smaller_artifacts: Option<bool>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1417,12 +1828,22 @@ pub struct PipOptions {
     /// - (Optional) `requires-dist`: The dependencies of the package (e.g., `werkzeug>=0.14`).
     /// - (Optional) `requires-python`: The Python version required by the package (e.g., `>=3.10`).
     /// - (Optional) `provides-extra`: The extras provided by the package.
+    /// - (Optional) `versions`: A version specifier (e.g., `>=1.0,<2.0`) restricting the metadata to
+    ///   matching versions of the package. Entries with an exact `version` take precedence.
+    /// - (Optional) `add-requires-dist`: Dependencies to add to those published by the package.
+    /// - (Optional) `remove-requires-dist`: The names of dependencies to remove from those published
+    ///   by the package.
+    ///
+    /// Entries that set `add-requires-dist` or `remove-requires-dist` patch the published metadata,
+    /// rather than replacing it. uv warns if a patch no longer applies to the published metadata, or
+    /// if an entry is identical to it, as the entry has likely drifted from the package.
     #[option(
         default = r#"[]"#,
         value_type = "list[dict]",
         example = r#"
             dependency-metadata = [
                 { name = "flask", version = "1.0.0", requires-dist = ["werkzeug"], requires-python = ">=3.6" },
+                { name = "tensorflow", versions = "<2.16", remove-requires-dist = ["tensorflow-io-gcs-filesystem"] },
             ]
         "#
     )]
@@ -1662,15 +2083,15 @@ pub struct PipOptions {
     pub annotation_style: Option<AnnotationStyle>,
     /// The method to use when installing packages from the global cache.
     ///
-    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
-    /// Windows.
+    /// Defaults to `clone` (also known as Copy-on-Write) on macOS, `reflink-auto` on Linux, and
+    /// `hardlink` on Windows.
     ///
     /// WARNING: The use of symlink link mode is discouraged, as they create tight coupling between
     /// the cache and the target environment. For example, clearing the cache (`uv cache clean`)
     /// will break all installed packages by way of removing the underlying source files. Use
     /// symlinks with caution.
     #[option(
-        default = "\"clone\" (macOS) or \"hardlink\" (Linux, Windows)",
+        default = "\"clone\" (macOS), \"reflink-auto\" (Linux), or \"hardlink\" (Windows)",
         value_type = "str",
         example = r#"
             link-mode = "copy"
@@ -1800,6 +2221,23 @@ pub struct PipOptions {
         "#
     )]
     pub torch_backend: Option<TorchMode>,
+    /// Additional packages to fetch from the PyTorch index selected by `torch-backend`.
+    ///
+    /// By default, `torch-backend` applies to the packages in the PyTorch ecosystem that are
+    /// hosted on the PyTorch indexes (e.g., `torch`, `torchvision`, and `torchaudio`). Packages
+    /// listed here are routed to the same index, e.g., to fetch other GPU-enabled builds that are
+    /// published alongside PyTorch (like `xformers` or `fbgemm-gpu`) for the detected accelerator.
+    ///
+    /// This option is in preview and may change in any future release.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            torch-backend = "auto"
+            torch-backend-packages = ["xformers", "fbgemm-gpu"]
+        "#
+    )]
+    pub torch_backend_packages: Option<Vec<PackageName>>,
 }
 
 impl PipOptions {
@@ -1855,6 +2293,12 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            yanked_policy: value.yanked_policy,
+            allow_yanked: value.allow_yanked,
+            known_incompatibilities: value.known_incompatibilities,
+            max_backtracks: value.max_backtracks,
+            resolution_timeout: value.resolution_timeout,
+            prefer_smaller_artifacts: value.prefer_smaller_artifacts,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -1952,6 +2396,12 @@ pub struct ToolOptions {
     pub resolution: Option<ResolutionMode>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub yanked_policy: Option<YankedPolicy>,
+    pub allow_yanked: Option<Vec<AllowedYank>>,
+    pub known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    pub max_backtracks: Option<NonZeroUsize>,
+    pub resolution_timeout: Option<u64>,
+    pub prefer_smaller_artifacts: Option<bool>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -1982,6 +2432,12 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            yanked_policy: value.yanked_policy,
+            allow_yanked: value.allow_yanked,
+            known_incompatibilities: value.known_incompatibilities,
+            max_backtracks: value.max_backtracks,
+            resolution_timeout: value.resolution_timeout,
+            prefer_smaller_artifacts: value.prefer_smaller_artifacts,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2014,6 +2470,12 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             resolution: value.resolution,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            yanked_policy: value.yanked_policy,
+            allow_yanked: value.allow_yanked,
+            known_incompatibilities: value.known_incompatibilities,
+            max_backtracks: value.max_backtracks,
+            resolution_timeout: value.resolution_timeout,
+            prefer_smaller_artifacts: value.prefer_smaller_artifacts,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -2024,6 +2486,9 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
+            script_launcher: None,
+            no_gui_scripts: None,
+            wheel_checks: None,
             no_sources: value.no_sources,
             upgrade: None,
             reinstall: None,
@@ -2047,12 +2512,15 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_partition: Option<CachePartition>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
+    max_download_rate: Option<DownloadRate>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -2067,6 +2535,12 @@ pub struct OptionsWire {
     resolution: Option<ResolutionMode>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    yanked_policy: Option<YankedPolicy>,
+    allow_yanked: Option<Vec<AllowedYank>>,
+    known_incompatibilities: Option<Vec<KnownIncompatibility>>,
+    max_backtracks: Option<NonZeroUsize>,
+    resolution_timeout: Option<u64>,
+    prefer_smaller_artifacts: Option<bool>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -2078,6 +2552,9 @@ pub struct OptionsWire {
     exclude_newer_package: Option<ExcludeNewerPackage>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
+    script_launcher: Option<ScriptLaunchers>,
+    no_gui_scripts: Option<bool>,
+    wheel_checks: Option<Vec<WheelCheck>>,
     no_sources: Option<bool>,
     upgrade: Option<bool>,
     upgrade_package: Option<Vec<Requirement<VerbatimParsedUrl>>>,
@@ -2092,6 +2569,7 @@ pub struct OptionsWire {
     // install_mirror: PythonInstallMirrors,
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
+    python_download_attestations: Option<PythonDownloadAttestations>,
     python_downloads_json_url: Option<String>,
 
     // #[serde(flatten)]
@@ -2103,15 +2581,19 @@ pub struct OptionsWire {
     // #[serde(flatten)]
     // add: AddOptions
     add_bounds: Option<AddBoundsKind>,
+    add_upgrade_policy: Option<UpgradePolicy>,
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    sync_profiles: Option<BTreeMap<String, SyncProfile>>,
+    env_files: Option<Vec<PathBuf>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
     override_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     exclude_dependencies: Option<Vec<uv_normalize::PackageName>>,
+    provided_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     build_constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
     environments: Option<SupportedEnvironments>,
@@ -2123,7 +2605,10 @@ pub struct OptionsWire {
     conflicts: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
+    patches: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
+    marker_provenance: Option<serde::de::IgnoredAny>,
+    upgrade_policy: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
@@ -2131,6 +2616,9 @@ pub struct OptionsWire {
 
     // Build backend
     build_backend: Option<serde::de::IgnoredAny>,
+
+    // `uv test`
+    test: Option<serde::de::IgnoredAny>,
 }
 
 impl From<OptionsWire> for Options {
@@ -2141,15 +2629,19 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_partition,
             preview,
             python_preference,
             python_downloads,
             python_install_mirror,
             pypy_install_mirror,
+            python_download_attestations,
             python_downloads_json_url,
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            concurrent_downloads_per_host,
+            max_download_rate,
             index,
             index_url,
             extra_index_url,
@@ -2161,6 +2653,12 @@ impl From<OptionsWire> for Options {
             resolution,
             prerelease,
             fork_strategy,
+            yanked_policy,
+            allow_yanked,
+            known_incompatibilities,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -2170,6 +2668,9 @@ impl From<OptionsWire> for Options {
             exclude_newer_package,
             link_mode,
             compile_bytecode,
+            script_launcher,
+            no_gui_scripts,
+            wheel_checks,
             no_sources,
             upgrade,
             upgrade_package,
@@ -2181,8 +2682,11 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             pip,
             cache_keys,
+            sync_profiles,
+            env_files,
             override_dependencies,
             exclude_dependencies,
+            provided_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
             environments,
@@ -2193,16 +2697,21 @@ impl From<OptionsWire> for Options {
             check_url,
             workspace,
             sources,
+            patches,
             default_groups,
             dependency_groups,
             extra_build_dependencies,
             extra_build_variables,
             dev_dependencies,
             managed,
+            marker_provenance,
+            upgrade_policy,
             package,
             add_bounds: bounds,
+            add_upgrade_policy: upgrade_policy,
             // Used by the build backend
             build_backend,
+            test,
         } = value;
 
         Self {
@@ -2212,12 +2721,15 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_partition,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_downloads_per_host,
+                max_download_rate,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
             },
@@ -2232,6 +2744,12 @@ impl From<OptionsWire> for Options {
                 resolution,
                 prerelease,
                 fork_strategy,
+                yanked_policy,
+                allow_yanked,
+                known_incompatibilities,
+                max_backtracks,
+                resolution_timeout,
+                prefer_smaller_artifacts,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -2243,6 +2761,9 @@ impl From<OptionsWire> for Options {
                 exclude_newer_package,
                 link_mode,
                 compile_bytecode,
+                script_launcher,
+                no_gui_scripts,
+                wheel_checks,
                 no_sources,
                 upgrade,
                 upgrade_package,
@@ -2255,9 +2776,12 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            sync_profiles,
+            env_files,
             build_backend,
             override_dependencies,
             exclude_dependencies,
+            provided_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
             environments,
@@ -2265,6 +2789,7 @@ impl From<OptionsWire> for Options {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror,
                 pypy_install_mirror,
+                python_download_attestations,
                 python_downloads_json_url,
             },
             conflicts,
@@ -2273,14 +2798,21 @@ impl From<OptionsWire> for Options {
                 trusted_publishing,
                 check_url,
             },
-            add: AddOptions { add_bounds: bounds },
+            add: AddOptions {
+                add_bounds: bounds,
+                add_upgrade_policy: upgrade_policy,
+            },
             workspace,
             sources,
+            patches,
             dev_dependencies,
             default_groups,
             dependency_groups,
             managed,
+            marker_provenance,
+            upgrade_policy,
             package,
+            test,
         }
     }
 }
@@ -2361,4 +2893,76 @@ pub struct AddOptions {
         possible_values = true
     )]
     pub add_bounds: Option<AddBoundsKind>,
+
+    /// The policy to apply to locked packages when adding a dependency requires them to change.
+    ///
+    /// When adding a dependency to the project, the project is re-resolved, preferring the
+    /// versions in the existing lockfile. If a locked version is no longer compatible, by default,
+    /// the best compatible version is selected, which may be a new major version. With
+    /// `same-major`, versions with the same major version as the locked version are preferred,
+    /// and a different major version is only selected if necessary.
+    #[option(
+        default = "\"any\"",
+        value_type = "str",
+        example = r#"
+            add-upgrade-policy = "same-major"
+        "#,
+        possible_values = true
+    )]
+    pub add_upgrade_policy: Option<UpgradePolicy>,
+}
+
+/// A named combination of `uv sync` options, as defined in `sync-profiles`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SyncProfile {
+    /// Include optional dependencies from the given extras.
+    #[serde(default)]
+    pub extras: Vec<ExtraName>,
+    /// Include all optional dependencies.
+    #[serde(default)]
+    pub all_extras: bool,
+    /// Include dependencies from the given dependency groups.
+    #[serde(default)]
+    pub groups: Vec<GroupName>,
+    /// Disable the given dependency groups.
+    #[serde(default)]
+    pub no_groups: Vec<GroupName>,
+    /// Only include dependencies from the given dependency groups.
+    #[serde(default)]
+    pub only_groups: Vec<GroupName>,
+    /// Include dependencies from all dependency groups.
+    #[serde(default)]
+    pub all_groups: bool,
+    /// Ignore the default dependency groups.
+    #[serde(default)]
+    pub no_default_groups: bool,
+    /// Disable the development dependency group.
+    #[serde(default)]
+    pub no_dev: bool,
+    /// Do not install the current project.
+    #[serde(default)]
+    pub no_install_project: bool,
+    /// Do not install any workspace members, including the root project.
+    #[serde(default)]
+    pub no_install_workspace: bool,
+    /// Do not install local path dependencies.
+    #[serde(default)]
+    pub no_install_local: bool,
+    /// Do not install the given packages.
+    #[serde(default)]
+    pub no_install_package: Vec<PackageName>,
+    /// Do not remove extraneous packages present in the environment.
+    #[serde(default)]
+    pub inexact: bool,
+    /// Sync all packages in the workspace.
+    #[serde(default)]
+    pub all_packages: bool,
+    /// Assert that the `uv.lock` will remain unchanged.
+    #[serde(default)]
+    pub locked: bool,
+    /// Sync without updating the `uv.lock` file.
+    #[serde(default)]
+    pub frozen: bool,
 }
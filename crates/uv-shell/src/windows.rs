@@ -10,7 +10,7 @@ use anyhow::Context;
 use tracing::warn;
 use windows::Win32::Foundation::{ERROR_FILE_NOT_FOUND, ERROR_INVALID_DATA};
 use windows::core::HRESULT;
-use windows_registry::{CURRENT_USER, HSTRING};
+use windows_registry::{CURRENT_USER, HSTRING, Key, LOCAL_MACHINE};
 
 use uv_static::EnvVars;
 
@@ -19,8 +19,25 @@ use uv_static::EnvVars;
 /// Returns `Ok(true)` if the path was successfully appended, and `Ok(false)` if the path was
 /// already in `PATH`.
 pub fn prepend_path(path: &Path) -> anyhow::Result<bool> {
+    prepend_path_in(CURRENT_USER, "Environment", path)
+}
+
+/// Prepend the given [`Path`] to the machine-wide `PATH` environment variable in the Windows
+/// registry, which applies to all users.
+///
+/// Requires administrator privileges. Returns `Ok(true)` if the path was successfully prepended,
+/// and `Ok(false)` if the path was already in `PATH`.
+pub fn prepend_system_path(path: &Path) -> anyhow::Result<bool> {
+    prepend_path_in(
+        LOCAL_MACHINE,
+        r"SYSTEM\CurrentControlSet\Control\Session Manager\Environment",
+        path,
+    )
+}
+
+fn prepend_path_in(root: &Key, environment: &str, path: &Path) -> anyhow::Result<bool> {
     // Get the existing `PATH` variable from the registry.
-    let windows_path = get_windows_path_var()?;
+    let windows_path = get_windows_path_var(root, environment)?;
 
     // Add the new path to the existing `PATH` variable.
     let windows_path =
@@ -31,14 +48,14 @@ pub fn prepend_path(path: &Path) -> anyhow::Result<bool> {
     };
 
     // Set the `PATH` variable in the registry.
-    apply_windows_path_var(&windows_path)?;
+    apply_windows_path_var(root, environment, &windows_path)?;
 
     Ok(true)
 }
 
 /// Set the windows `PATH` variable in the registry.
-fn apply_windows_path_var(path: &HSTRING) -> anyhow::Result<()> {
-    let environment = CURRENT_USER.create("Environment")?;
+fn apply_windows_path_var(root: &Key, environment: &str, path: &HSTRING) -> anyhow::Result<()> {
+    let environment = root.create(environment)?;
 
     if path.is_empty() {
         environment.remove_value(EnvVars::PATH)?;
@@ -52,16 +69,16 @@ fn apply_windows_path_var(path: &HSTRING) -> anyhow::Result<()> {
 /// Retrieve the windows `PATH` variable from the registry.
 ///
 /// Returns `Ok(None)` if the `PATH` variable is not a string.
-fn get_windows_path_var() -> anyhow::Result<Option<HSTRING>> {
-    let environment = CURRENT_USER
-        .create("Environment")
-        .context("Failed to open `Environment` key")?;
+fn get_windows_path_var(root: &Key, environment: &str) -> anyhow::Result<Option<HSTRING>> {
+    let environment_key = root
+        .create(environment)
+        .with_context(|| format!("Failed to open `{environment}` key"))?;
 
-    let reg_value = environment.get_hstring(EnvVars::PATH);
+    let reg_value = environment_key.get_hstring(EnvVars::PATH);
     match reg_value {
         Ok(reg_value) => Ok(Some(reg_value)),
         Err(err) if err.code() == HRESULT::from(ERROR_INVALID_DATA) => {
-            warn!("`HKEY_CURRENT_USER\\Environment\\PATH` is a non-string");
+            warn!("`{environment}\\PATH` is a non-string");
             Ok(None)
         }
         Err(err) if err.code() == HRESULT::from(ERROR_FILE_NOT_FOUND) => Ok(Some(HSTRING::new())),
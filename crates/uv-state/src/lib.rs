@@ -107,6 +107,8 @@ pub enum StateBucket {
     Tools,
     /// Credentials.
     Credentials,
+    /// The uv executable retained by `uv self update`, for `uv self rollback`.
+    SelfUpdate,
 }
 
 impl StateBucket {
@@ -115,6 +117,7 @@ impl StateBucket {
             Self::ManagedPython => "python",
             Self::Tools => "tools",
             Self::Credentials => "credentials",
+            Self::SelfUpdate => "self-update",
         }
     }
 }
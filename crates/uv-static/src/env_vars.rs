@@ -60,6 +60,22 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const UV_CACHE_DIR: &'static str = "UV_CACHE_DIR";
 
+    /// Equivalent to the `--cache-partition` command-line argument. If set, uv will store cache
+    /// entries in a separate partition of the cache directory for each project (`project`) or for
+    /// each default index (`index`).
+    #[attr_added_in("0.9.13")]
+    pub const UV_CACHE_PARTITION: &'static str = "UV_CACHE_PARTITION";
+
+    /// Equivalent to the `--cache-remote` command-line argument. If set, uv will fetch wheels
+    /// built from source distributions from this remote cache before building them locally.
+    #[attr_added_in("0.9.13")]
+    pub const UV_CACHE_REMOTE: &'static str = "UV_CACHE_REMOTE";
+
+    /// Equivalent to the `--cache-remote-write` command-line argument. If set, uv will upload
+    /// wheels built from source distributions to the remote cache.
+    #[attr_added_in("0.9.13")]
+    pub const UV_CACHE_REMOTE_WRITE: &'static str = "UV_CACHE_REMOTE_WRITE";
+
     /// The directory for storage of credentials when using a plain text backend.
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
@@ -84,6 +100,21 @@ impl EnvVars {
     #[attr_added_in("0.5.9")]
     pub const UV_FORK_STRATEGY: &'static str = "UV_FORK_STRATEGY";
 
+    /// Equivalent to the `--max-backtracks` argument. Aborts the resolution after the resolver
+    /// backtracks on a single package more than the given number of times.
+    #[attr_added_in("0.9.13")]
+    pub const UV_MAX_BACKTRACKS: &'static str = "UV_MAX_BACKTRACKS";
+
+    /// Equivalent to the `--max-download-rate` argument. Limits the rate at which uv downloads
+    /// data, in bytes per second, across all concurrent downloads.
+    #[attr_added_in("0.9.13")]
+    pub const UV_MAX_DOWNLOAD_RATE: &'static str = "UV_MAX_DOWNLOAD_RATE";
+
+    /// Equivalent to the `--resolution-timeout` argument. Aborts the resolution after the given
+    /// number of seconds.
+    #[attr_added_in("0.9.13")]
+    pub const UV_RESOLUTION_TIMEOUT: &'static str = "UV_RESOLUTION_TIMEOUT";
+
     /// Equivalent to the `--system` command-line argument. If set to `true`, uv will
     /// use the first Python interpreter found in the system `PATH`.
     ///
@@ -221,6 +252,11 @@ impl EnvVars {
     #[attr_added_in("0.7.22")]
     pub const UV_COMPILE_BYTECODE_TIMEOUT: &'static str = "UV_COMPILE_BYTECODE_TIMEOUT";
 
+    /// Equivalent to the `--wait-timeout` command-line argument. If set, uv will wait at most this
+    /// many seconds for another uv process to release the environment before failing.
+    #[attr_added_in("0.9.13")]
+    pub const UV_WAIT_TIMEOUT: &'static str = "UV_WAIT_TIMEOUT";
+
     /// Equivalent to the `--no-editable` command-line argument. If set, uv
     /// installs or exports any editable dependencies, including the project and any workspace
     /// members, as non-editable.
@@ -317,6 +353,16 @@ impl EnvVars {
     #[attr_added_in("0.4.25")]
     pub const UV_FROZEN: &'static str = "UV_FROZEN";
 
+    /// Equivalent to the `--snapshot` command-line argument. If set, `uv sync` will record a
+    /// snapshot of the project environment before modifying it.
+    #[attr_added_in("0.9.13")]
+    pub const UV_SNAPSHOT: &'static str = "UV_SNAPSHOT";
+
+    /// Equivalent to the `--profile` command-line argument. If set, `uv sync` will apply the
+    /// named profile from `tool.uv.sync-profiles`.
+    #[attr_added_in("0.9.13")]
+    pub const UV_SYNC_PROFILE: &'static str = "UV_SYNC_PROFILE";
+
     /// Equivalent to the `--preview` argument. Enables preview mode.
     #[attr_added_in("0.1.37")]
     pub const UV_PREVIEW: &'static str = "UV_PREVIEW";
@@ -329,6 +375,11 @@ impl EnvVars {
     #[attr_added_in("0.4.10")]
     pub const UV_GITHUB_TOKEN: &'static str = "UV_GITHUB_TOKEN";
 
+    /// Equivalent to the `--channel` argument for self update. The release channel to update
+    /// from, one of `stable` or `preview`.
+    #[attr_added_in("0.9.13")]
+    pub const UV_SELF_UPDATE_CHANNEL: &'static str = "UV_SELF_UPDATE_CHANNEL";
+
     /// Equivalent to the `--no-verify-hashes` argument. Disables hash verification for
     /// `requirements.txt` files.
     #[attr_added_in("0.5.3")]
@@ -362,6 +413,11 @@ impl EnvVars {
     #[attr_added_in("0.1.45")]
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of in-flight concurrent requests that uv will
+    /// send to any single host at any given time.
+    #[attr_added_in("0.9.13")]
+    pub const UV_CONCURRENT_DOWNLOADS_PER_HOST: &'static str = "UV_CONCURRENT_DOWNLOADS_PER_HOST";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
@@ -375,11 +431,25 @@ impl EnvVars {
     #[attr_added_in("0.3.0")]
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
+    /// The number of seconds for which the environment created by `uv tool run` (or `uvx`) is
+    /// reused for identical invocations, without re-resolving the tool's requirements.
+    ///
+    /// By default, `uv tool run` resolves the tool's requirements on every invocation.
+    #[attr_added_in("0.9.13")]
+    pub const UV_TOOL_RUN_CACHE_TTL: &'static str = "UV_TOOL_RUN_CACHE_TTL";
+
     /// Equivalent to the `--build-backend` argument for `uv init`. Determines the default backend
     /// to use when creating a new project.
     #[attr_added_in("0.8.2")]
     pub const UV_INIT_BUILD_BACKEND: &'static str = "UV_INIT_BUILD_BACKEND";
 
+    /// The Python interpreter to compile extension modules for with the uv build backend.
+    ///
+    /// Set automatically by the `uv_build` build hooks to the interpreter running the build.
+    /// Defaults to `python3` on `PATH`.
+    #[attr_added_in("0.9.13")]
+    pub const UV_BUILD_BACKEND_PYTHON: &'static str = "UV_BUILD_BACKEND_PYTHON";
+
     /// Specifies the path to the directory to use for a project virtual environment.
     ///
     /// See the [project documentation](../concepts/projects/config.md#project-environment-path)
@@ -570,6 +640,11 @@ impl EnvVars {
     #[attr_added_in("0.1.42")]
     pub const APPDATA: &'static str = "APPDATA";
 
+    /// Path to the machine-wide application data directory on Windows systems, used for
+    /// system-wide managed Python installations.
+    #[attr_added_in("0.9.13")]
+    pub const PROGRAMDATA: &'static str = "PROGRAMDATA";
+
     /// Path to root directory of user's profile on Windows systems.
     #[attr_added_in("0.0.5")]
     pub const USERPROFILE: &'static str = "USERPROFILE";
@@ -625,6 +700,15 @@ impl EnvVars {
     #[attr_added_in("0.1.38")]
     pub const NO_PROXY: &'static str = "NO_PROXY";
 
+    /// A command used to select the proxy for each request, e.g., by evaluating a proxy
+    /// auto-configuration (PAC) file.
+    ///
+    /// The command is invoked with the request URL as its final argument, and should print either a
+    /// proxy URL or a PAC-style result, like `PROXY proxy.example.com:8080; DIRECT`. When set,
+    /// `HTTP_PROXY`, `HTTPS_PROXY`, and `ALL_PROXY` are ignored, while `NO_PROXY` is respected.
+    #[attr_added_in("0.9.13")]
+    pub const UV_PROXY_HELPER: &'static str = "UV_PROXY_HELPER";
+
     /// Timeout (in seconds) for only upload HTTP requests. (default: 900 s)
     #[attr_added_in("0.9.1")]
     pub const UV_UPLOAD_HTTP_TIMEOUT: &'static str = "UV_UPLOAD_HTTP_TIMEOUT";
@@ -711,6 +795,27 @@ impl EnvVars {
     #[attr_added_in("0.1.42")]
     pub const MACOSX_DEPLOYMENT_TARGET: &'static str = "MACOSX_DEPLOYMENT_TARGET";
 
+    /// The C compiler used by the uv build backend to compile extension modules.
+    #[attr_added_in("0.9.13")]
+    pub const CC: &'static str = "CC";
+
+    /// Additional flags passed to the C compiler when compiling extension modules with the uv
+    /// build backend.
+    #[attr_added_in("0.9.13")]
+    pub const CFLAGS: &'static str = "CFLAGS";
+
+    /// Additional flags passed to the linker when compiling extension modules with the uv build
+    /// backend.
+    #[attr_added_in("0.9.13")]
+    pub const LDFLAGS: &'static str = "LDFLAGS";
+
+    /// A Unix timestamp used by the uv build backend as the modification time of all files in
+    /// wheels and source distributions, for reproducible builds.
+    ///
+    /// See <https://reproducible-builds.org/specs/source-date-epoch/>.
+    #[attr_added_in("0.9.13")]
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Used with `--python-platform arm64-apple-ios` and related variants to set the
     /// deployment target (i.e., the minimum supported iOS version).
     ///
@@ -803,6 +908,11 @@ impl EnvVars {
     #[attr_added_in("0.7.11")]
     pub const GIT_SSH_COMMAND: &'static str = "GIT_SSH_COMMAND";
 
+    /// Disables downloading Git LFS objects when checking out a repository.
+    #[attr_hidden]
+    #[attr_added_in("0.9.13")]
+    pub const GIT_LFS_SKIP_SMUDGE: &'static str = "GIT_LFS_SKIP_SMUDGE";
+
     /// Disable interactive git prompts in terminals, e.g., for credentials. Does not disable
     /// GUI prompts.
     #[attr_hidden]
@@ -900,6 +1010,18 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const TRACING_DURATIONS_FILE: &'static str = "TRACING_DURATIONS_FILE";
 
+    /// The OpenTelemetry collector to export tracing spans to via OTLP over HTTP, e.g.,
+    /// `http://localhost:4318`. Only used when uv is built with the `otlp` feature.
+    #[attr_added_in("0.9.13")]
+    pub const OTEL_EXPORTER_OTLP_ENDPOINT: &'static str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+    /// The OpenTelemetry collector endpoint to export tracing spans to, e.g.,
+    /// `http://localhost:4318/v1/traces`. Takes precedence over `OTEL_EXPORTER_OTLP_ENDPOINT`.
+    /// Only used when uv is built with the `otlp` feature.
+    #[attr_added_in("0.9.13")]
+    pub const OTEL_EXPORTER_OTLP_TRACES_ENDPOINT: &'static str =
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+
     /// Used to set `RUST_HOST_TARGET` at build time via `build.rs`.
     #[attr_hidden]
     #[attr_added_in("0.1.11")]
@@ -937,6 +1059,21 @@ impl EnvVars {
     #[attr_added_in("0.6.4")]
     pub const UV_LOG_CONTEXT: &'static str = "UV_LOG_CONTEXT";
 
+    /// Equivalent to the `--log-format` command-line argument. If set to `json`, log messages are
+    /// written as newline-delimited JSON.
+    #[attr_added_in("0.9.13")]
+    pub const UV_LOG_FORMAT: &'static str = "UV_LOG_FORMAT";
+
+    /// Equivalent to the `--error-format` command-line argument. If set to `json`, resolution
+    /// failures are written as JSON, including the sources of the conflicting requirements.
+    #[attr_added_in("0.9.13")]
+    pub const UV_ERROR_FORMAT: &'static str = "UV_ERROR_FORMAT";
+
+    /// Equivalent to the `--progress` command-line argument. If set to `json`, progress is
+    /// reported as newline-delimited JSON events on stderr.
+    #[attr_added_in("0.9.13")]
+    pub const UV_PROGRESS: &'static str = "UV_PROGRESS";
+
     /// Use to set the stack size used by uv.
     ///
     /// The value is in bytes, and if both `UV_STACK_SIZE` are `RUST_MIN_STACK` unset, uv uses a 4MB
@@ -1088,6 +1225,14 @@ impl EnvVars {
     #[attr_added_in("0.5.19")]
     pub const UV_GIT_LFS: &'static str = "UV_GIT_LFS";
 
+    /// Enables sparse checkouts of Git dependencies that specify a `subdirectory`.
+    ///
+    /// When set, uv performs a blobless fetch of the repository, and only checks out (and fetches
+    /// the file contents of) the subdirectory, along with the files at the root of the repository.
+    /// Files in Git LFS are similarly only fetched for the subdirectory, if `UV_GIT_LFS` is set.
+    #[attr_added_in("0.9.13")]
+    pub const UV_GIT_SPARSE_CHECKOUT: &'static str = "UV_GIT_SPARSE_CHECKOUT";
+
     /// Number of times that `uv run` has been recursively invoked. Used to guard against infinite
     /// recursion, e.g., when `uv run`` is used in a script shebang.
     #[attr_hidden]
@@ -0,0 +1,96 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[error("Invalid executable pattern `{pattern}`")]
+pub struct ExecutablePatternError {
+    pattern: String,
+    #[source]
+    err: glob::PatternError,
+}
+
+/// A glob pattern that selects tool executables by name, e.g., `ansible-*`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExecutablePattern(glob::Pattern);
+
+impl ExecutablePattern {
+    /// Returns `true` if the pattern matches the given executable name.
+    pub fn matches(&self, name: &str) -> bool {
+        self.0.matches(name)
+    }
+}
+
+impl FromStr for ExecutablePattern {
+    type Err = ExecutablePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        glob::Pattern::new(s)
+            .map(Self)
+            .map_err(|err| ExecutablePatternError {
+                pattern: s.to_string(),
+                err,
+            })
+    }
+}
+
+impl Display for ExecutablePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Serialize for ExecutablePattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExecutablePattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The executables to install for a tool, as selected by the user.
+///
+/// If any `include` patterns are provided, only executables matching at least one of them are
+/// installed. Executables matching any `exclude` pattern are never installed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ExecutableFilter {
+    #[serde(default, rename = "include-executables")]
+    include: Vec<ExecutablePattern>,
+    #[serde(default, rename = "exclude-executables")]
+    exclude: Vec<ExecutablePattern>,
+}
+
+impl ExecutableFilter {
+    /// Create a new [`ExecutableFilter`] from the given include and exclude patterns.
+    pub fn new(include: Vec<ExecutablePattern>, exclude: Vec<ExecutablePattern>) -> Self {
+        Self { include, exclude }
+    }
+
+    /// Returns `true` if the executable with the given name should be installed.
+    pub fn allows(&self, name: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(name)))
+            && !self.exclude.iter().any(|pattern| pattern.matches(name))
+    }
+
+    /// Returns `true` if the filter allows all executables.
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// The patterns for executables to install.
+    pub fn include(&self) -> &[ExecutablePattern] {
+        &self.include
+    }
+
+    /// The patterns for executables to skip.
+    pub fn exclude(&self) -> &[ExecutablePattern] {
+        &self.exclude
+    }
+}
@@ -20,9 +20,13 @@ use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_virtualenv::remove_virtualenv;
 
+pub use executables::{ExecutableFilter, ExecutablePattern, ExecutablePatternError};
+pub use lock::{LockedPackage, LockedTool, ToolsLock};
 pub use receipt::ToolReceipt;
 pub use tool::{Tool, ToolEntrypoint};
 
+mod executables;
+mod lock;
 mod receipt;
 mod tool;
 
@@ -69,6 +73,12 @@ pub enum Error {
     ReceiptWrite(PathBuf, #[source] Box<toml_edit::ser::Error>),
     #[error("Failed to read `uv-receipt.toml` at {0}")]
     ReceiptRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to write `uv-tools.lock` at {0}")]
+    LockWrite(PathBuf, #[source] Box<toml_edit::ser::Error>),
+    #[error("Failed to read `uv-tools.lock` at {0}")]
+    LockRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Unsupported `uv-tools.lock` version at {0}: {1}")]
+    UnsupportedLockVersion(PathBuf, u32),
     #[error(transparent)]
     VirtualEnvError(#[from] uv_virtualenv::Error),
     #[error("Failed to read package entry points {0}")]
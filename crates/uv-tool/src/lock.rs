@@ -0,0 +1,283 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use toml_edit::{ArrayOfTables, Item, Table, value};
+
+use uv_distribution_types::Requirement;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonRequest;
+use uv_settings::ToolOptions;
+
+use crate::tool::each_element_on_its_line_array;
+use crate::{ExecutableFilter, Tool};
+
+/// The current version of the `uv-tools.lock` format.
+const VERSION: u32 = 1;
+
+/// A `uv-tools.lock` file pinning the installed tools, such that the same tools can be installed
+/// with the same versions on another machine.
+#[derive(Debug, Clone, Default)]
+pub struct ToolsLock {
+    tools: Vec<LockedTool>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ToolsLockWire {
+    version: u32,
+    #[serde(default)]
+    tool: Vec<LockedTool>,
+}
+
+impl ToolsLock {
+    /// Create a [`ToolsLock`] from the given tools.
+    pub fn new(tools: impl IntoIterator<Item = LockedTool>) -> Self {
+        let mut tools: Vec<_> = tools.into_iter().collect();
+        tools.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { tools }
+    }
+
+    /// Read a [`ToolsLock`] from the given path.
+    pub fn from_path(path: &Path) -> Result<Self, crate::Error> {
+        let contents = fs_err::read_to_string(path)?;
+        let wire: ToolsLockWire = toml::from_str(&contents)
+            .map_err(|err| crate::Error::LockRead(path.to_owned(), Box::new(err)))?;
+        if wire.version != VERSION {
+            return Err(crate::Error::UnsupportedLockVersion(
+                path.to_owned(),
+                wire.version,
+            ));
+        }
+        Ok(Self::new(wire.tool))
+    }
+
+    /// Write the [`ToolsLock`] to the given path.
+    pub fn write(&self, path: &Path) -> Result<(), crate::Error> {
+        let contents = self
+            .to_toml()
+            .map_err(|err| crate::Error::LockWrite(path.to_owned(), Box::new(err)))?;
+        fs_err::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Returns the TOML representation of this lockfile.
+    pub fn to_toml(&self) -> Result<String, toml_edit::ser::Error> {
+        let mut doc = toml_edit::DocumentMut::new();
+        doc.insert("version", value(i64::from(VERSION)));
+
+        let mut tools = ArrayOfTables::new();
+        for tool in &self.tools {
+            tools.push(tool.to_toml()?);
+        }
+        if !tools.is_empty() {
+            doc.insert("tool", Item::ArrayOfTables(tools));
+        }
+
+        Ok(doc.to_string())
+    }
+
+    /// Returns the locked tools, ordered by name.
+    pub fn tools(&self) -> &[LockedTool] {
+        &self.tools
+    }
+}
+
+/// A tool in a [`ToolsLock`], along with the exact versions of the packages in its environment.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LockedTool {
+    /// The name of the tool package.
+    name: PackageName,
+    /// The requirements requested by the user during installation.
+    #[serde(default)]
+    requirements: Vec<Requirement>,
+    /// The constraints requested by the user during installation.
+    #[serde(default)]
+    constraints: Vec<Requirement>,
+    /// The overrides requested by the user during installation.
+    #[serde(default)]
+    overrides: Vec<Requirement>,
+    /// The build constraints requested by the user during installation.
+    #[serde(default, rename = "build-constraint-dependencies")]
+    build_constraints: Vec<Requirement>,
+    /// The Python requested by the user during installation.
+    python: Option<PythonRequest>,
+    /// Additional packages whose executables are installed alongside those of the tool.
+    #[serde(default)]
+    with_executables_from: Vec<PackageName>,
+    /// The filter applied to the executables of the tool.
+    #[serde(flatten)]
+    executables: ExecutableFilter,
+    /// The [`ToolOptions`] used to install this tool.
+    #[serde(default)]
+    options: ToolOptions,
+    /// The packages installed in the tool environment.
+    #[serde(default)]
+    packages: Vec<LockedPackage>,
+}
+
+impl LockedTool {
+    /// Create a [`LockedTool`] from the receipt of an installed tool and the packages installed in
+    /// its environment.
+    pub fn new(
+        name: PackageName,
+        tool: &Tool,
+        packages: impl IntoIterator<Item = (PackageName, Version)>,
+    ) -> Self {
+        let mut with_executables_from = tool
+            .entrypoints()
+            .iter()
+            .filter_map(|entrypoint| PackageName::from_str(entrypoint.from.as_ref()?).ok())
+            .filter(|package| *package != name)
+            .collect::<Vec<_>>();
+        with_executables_from.sort();
+        with_executables_from.dedup();
+
+        let mut packages = packages
+            .into_iter()
+            .map(|(name, version)| LockedPackage { name, version })
+            .collect::<Vec<_>>();
+        packages.sort();
+        packages.dedup();
+
+        Self {
+            name,
+            requirements: tool.requirements().to_vec(),
+            constraints: tool.constraints().to_vec(),
+            overrides: tool.overrides().to_vec(),
+            build_constraints: tool.build_constraints().to_vec(),
+            python: tool.python().clone(),
+            with_executables_from,
+            executables: tool.executables().clone(),
+            options: tool.options().clone(),
+            packages,
+        }
+    }
+
+    /// Returns the TOML table for this tool.
+    fn to_toml(&self) -> Result<Table, toml_edit::ser::Error> {
+        // Reuse the receipt format, omitting the entrypoints, which are specific to the machine.
+        let receipt = Tool::new(
+            self.requirements.clone(),
+            self.constraints.clone(),
+            self.overrides.clone(),
+            self.build_constraints.clone(),
+            self.python.clone(),
+            [],
+            self.executables.clone(),
+            self.options.clone(),
+        )
+        .to_toml()?;
+
+        let mut table = Table::new();
+        table.insert("name", value(self.name.to_string()));
+        for (key, item) in receipt {
+            match key.as_str() {
+                "entrypoints" => {
+                    if !self.with_executables_from.is_empty() {
+                        table.insert(
+                            "with-executables-from",
+                            value(
+                                self.with_executables_from
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<toml_edit::Array>(),
+                            ),
+                        );
+                    }
+                    table.insert(
+                        "packages",
+                        value(each_element_on_its_line_array(
+                            self.packages.iter().map(ToString::to_string),
+                        )),
+                    );
+                }
+                _ => {
+                    table.insert(&key, item);
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    pub fn requirements(&self) -> &[Requirement] {
+        &self.requirements
+    }
+
+    pub fn constraints(&self) -> &[Requirement] {
+        &self.constraints
+    }
+
+    pub fn overrides(&self) -> &[Requirement] {
+        &self.overrides
+    }
+
+    pub fn build_constraints(&self) -> &[Requirement] {
+        &self.build_constraints
+    }
+
+    pub fn python(&self) -> &Option<PythonRequest> {
+        &self.python
+    }
+
+    pub fn with_executables_from(&self) -> &[PackageName] {
+        &self.with_executables_from
+    }
+
+    pub fn executables(&self) -> &ExecutableFilter {
+        &self.executables
+    }
+
+    pub fn options(&self) -> &ToolOptions {
+        &self.options
+    }
+
+    pub fn packages(&self) -> &[LockedPackage] {
+        &self.packages
+    }
+}
+
+/// A package pinned to an exact version in a [`LockedTool`], e.g., `black==24.1.0`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LockedPackage {
+    pub name: PackageName,
+    pub version: Version,
+}
+
+impl Display for LockedPackage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}=={}", self.name, self.version)
+    }
+}
+
+impl FromStr for LockedPackage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, version) = s
+            .split_once("==")
+            .ok_or_else(|| format!("expected a pinned package (e.g., `name==1.0.0`): `{s}`"))?;
+        Ok(Self {
+            name: PackageName::from_str(name.trim()).map_err(|err| err.to_string())?,
+            version: Version::from_str(version.trim()).map_err(|err| err.to_string())?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for LockedPackage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
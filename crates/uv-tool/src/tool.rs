@@ -10,6 +10,8 @@ use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::PythonRequest;
 use uv_settings::ToolOptions;
 
+use crate::ExecutableFilter;
+
 /// A tool entry.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(try_from = "ToolWire", into = "ToolWire")]
@@ -26,6 +28,8 @@ pub struct Tool {
     python: Option<PythonRequest>,
     /// A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
+    /// The filter applied to the executables of this tool.
+    executables: ExecutableFilter,
     /// The [`ToolOptions`] used to install this tool.
     options: ToolOptions,
 }
@@ -43,6 +47,8 @@ struct ToolWire {
     build_constraint_dependencies: Vec<Requirement>,
     python: Option<PythonRequest>,
     entrypoints: Vec<ToolEntrypoint>,
+    #[serde(flatten)]
+    executables: ExecutableFilter,
     #[serde(default)]
     options: ToolOptions,
 }
@@ -70,6 +76,7 @@ impl From<Tool> for ToolWire {
             build_constraint_dependencies: tool.build_constraints,
             python: tool.python,
             entrypoints: tool.entrypoints,
+            executables: tool.executables,
             options: tool.options,
         }
     }
@@ -93,6 +100,7 @@ impl TryFrom<ToolWire> for Tool {
             build_constraints: tool.build_constraint_dependencies,
             python: tool.python,
             entrypoints: tool.entrypoints,
+            executables: tool.executables,
             options: tool.options,
         })
     }
@@ -142,7 +150,9 @@ impl Display for ToolEntrypoint {
 ///     "bar",
 /// ]
 /// ```
-fn each_element_on_its_line_array(elements: impl Iterator<Item = impl Into<Value>>) -> Array {
+pub(crate) fn each_element_on_its_line_array(
+    elements: impl Iterator<Item = impl Into<Value>>,
+) -> Array {
     let mut array = elements
         .map(Into::into)
         .map(|mut value| {
@@ -168,6 +178,7 @@ impl Tool {
         build_constraints: Vec<Requirement>,
         python: Option<PythonRequest>,
         entrypoints: impl IntoIterator<Item = ToolEntrypoint>,
+        executables: ExecutableFilter,
         options: ToolOptions,
     ) -> Self {
         let mut entrypoints: Vec<_> = entrypoints.into_iter().collect();
@@ -179,6 +190,7 @@ impl Tool {
             build_constraints,
             python,
             entrypoints,
+            executables,
             options,
         }
     }
@@ -291,6 +303,32 @@ impl Tool {
             );
         }
 
+        if !self.executables.include().is_empty() {
+            table.insert(
+                "include-executables",
+                value(
+                    self.executables
+                        .include()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Array>(),
+                ),
+            );
+        }
+
+        if !self.executables.exclude().is_empty() {
+            table.insert(
+                "exclude-executables",
+                value(
+                    self.executables
+                        .exclude()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Array>(),
+                ),
+            );
+        }
+
         table.insert("entrypoints", {
             let entrypoints = each_element_on_its_line_array(
                 self.entrypoints
@@ -319,6 +357,10 @@ impl Tool {
         &self.entrypoints
     }
 
+    pub fn executables(&self) -> &ExecutableFilter {
+        &self.executables
+    }
+
     pub fn requirements(&self) -> &[Requirement] {
         &self.requirements
     }
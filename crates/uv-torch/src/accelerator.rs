@@ -64,13 +64,18 @@ impl Accelerator {
     /// 4. `/proc/driver/nvidia/version`, which contains the driver version among other information.
     /// 5. `nvidia-smi --query-gpu=driver_version --format=csv,noheader`.
     /// 6. `rocm_agent_enumerator`, which lists the AMD GPU architectures.
-    /// 7. `/sys/bus/pci/devices`, filtering for the Intel GPU via PCI.
-    /// 8. Windows Managmeent Instrumentation (WMI), filtering for the Intel GPU via PCI.
+    /// 7. `/sys/class/kfd/kfd/topology/nodes`, which lists the AMD GPU architectures for systems
+    ///    without the ROCm user-space tools.
+    /// 8. `/sys/bus/pci/devices`, filtering for the Intel GPU via PCI.
+    /// 9. Windows Management Instrumentation (WMI), filtering for the NVIDIA GPU (and its driver
+    ///    version) or the Intel GPU via PCI.
     pub fn detect() -> Result<Option<Self>, AcceleratorError> {
         // Constants used for PCI device detection.
         const PCI_BASE_CLASS_MASK: u32 = 0x00ff_0000;
         const PCI_BASE_CLASS_DISPLAY: u32 = 0x0003_0000;
         const PCI_VENDOR_ID_INTEL: u32 = 0x8086;
+        #[cfg(windows)]
+        const PCI_VENDOR_ID_NVIDIA: u32 = 0x10de;
 
         // Read from `UV_CUDA_DRIVER_VERSION`.
         if let Ok(driver_version) = std::env::var(EnvVars::UV_CUDA_DRIVER_VERSION) {
@@ -173,6 +178,28 @@ impl Accelerator {
             }
         }
 
+        // Read from `/sys/class/kfd/kfd/topology/nodes` to detect the AMD GPU architecture when
+        // `rocm_agent_enumerator` is unavailable (e.g., in containers with only the kernel driver).
+        match fs_err::read_dir("/sys/class/kfd/kfd/topology/nodes") {
+            Ok(entries) => {
+                let gpu_architecture = entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        fs_err::read_to_string(entry.path().join("properties")).ok()
+                    })
+                    .filter_map(|content| parse_kfd_gfx_target_version(&content))
+                    .min();
+                if let Some(gpu_architecture) = gpu_architecture {
+                    debug!(
+                        "Detected AMD GPU architecture from `/sys/class/kfd/kfd/topology/nodes`: {gpu_architecture}"
+                    );
+                    return Ok(Some(Self::Amd { gpu_architecture }));
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
         // Read from `/sys/bus/pci/devices` to filter for Intel GPU via PCI.
         match fs_err::read_dir("/sys/bus/pci/devices") {
             Ok(entries) => {
@@ -196,7 +223,7 @@ impl Accelerator {
             Err(e) => return Err(e.into()),
         }
 
-        // Detect Intel GPU via WMI on Windows
+        // Detect NVIDIA or Intel GPUs via WMI on Windows
         #[cfg(windows)]
         {
             #[derive(Deserialize, Debug)]
@@ -206,13 +233,38 @@ impl Accelerator {
                 #[serde(rename = "PNPDeviceID")]
                 pnp_device_id: Option<String>,
                 name: Option<String>,
+                driver_version: Option<String>,
             }
 
             match COMLibrary::new() {
                 Ok(com_library) => match WMIConnection::new(com_library) {
                     Ok(wmi_connection) => match wmi_connection.query::<VideoController>() {
                         Ok(gpu_controllers) => {
-                            for gpu_controller in gpu_controllers {
+                            // Prefer an NVIDIA GPU with a known driver version, e.g., if
+                            // `nvidia-smi` isn't on the `PATH`.
+                            for gpu_controller in &gpu_controllers {
+                                let Some(pnp_device_id) = &gpu_controller.pnp_device_id else {
+                                    continue;
+                                };
+                                if !pnp_device_id
+                                    .contains(&format!("VEN_{PCI_VENDOR_ID_NVIDIA:04X}"))
+                                {
+                                    continue;
+                                }
+                                if let Some(driver_version) = gpu_controller
+                                    .driver_version
+                                    .as_deref()
+                                    .and_then(parse_windows_nvidia_driver_version)
+                                {
+                                    debug!(
+                                        "Detected CUDA driver version from WMI: {driver_version} (PNPDeviceID={}, Name={:?})",
+                                        pnp_device_id, gpu_controller.name
+                                    );
+                                    return Ok(Some(Self::Cuda { driver_version }));
+                                }
+                            }
+
+                            for gpu_controller in &gpu_controllers {
                                 if let Some(pnp_device_id) = &gpu_controller.pnp_device_id {
                                     if pnp_device_id
                                         .contains(&format!("VEN_{PCI_VENDOR_ID_INTEL:04X}"))
@@ -270,6 +322,51 @@ fn parse_proc_driver_nvidia_version(content: &str) -> Result<Option<Version>, Ac
     Ok(Some(driver_version))
 }
 
+/// Parse the AMD GPU architecture from the content of a KFD topology node's `properties` file
+/// (e.g., `/sys/class/kfd/kfd/topology/nodes/1/properties`).
+///
+/// Returns `None` for CPU nodes and unknown architectures.
+fn parse_kfd_gfx_target_version(content: &str) -> Option<AmdGpuArchitecture> {
+    // Parse, e.g.:
+    // ```text
+    // cpu_cores_count 0
+    // simd_count 192
+    // gfx_target_version 110000
+    // ```
+    //
+    // The version is encoded as `major * 10000 + minor * 100 + stepping`, such that `90010`
+    // corresponds to `gfx90a` and `110000` to `gfx1100`.
+    let version = content.lines().find_map(|line| {
+        line.strip_prefix("gfx_target_version")
+            .and_then(|value| value.trim().parse::<u32>().ok())
+    })?;
+    if version == 0 {
+        return None;
+    }
+    let major = version / 10000;
+    let minor = (version / 100) % 100;
+    let stepping = version % 100;
+    AmdGpuArchitecture::from_str(&format!("gfx{major}{minor:x}{stepping:x}")).ok()
+}
+
+/// Parse the CUDA driver version from the Windows driver version of an NVIDIA GPU, as reported by
+/// WMI (e.g., `32.0.15.6094`, which corresponds to the CUDA driver version `560.94`).
+#[cfg(any(windows, test))]
+fn parse_windows_nvidia_driver_version(driver_version: &str) -> Option<Version> {
+    // The CUDA driver version is encoded in the last five digits of the last two components.
+    let mut components = driver_version.rsplit('.');
+    let last = components.next()?;
+    let second_last = components.next()?;
+    let digits = format!("{second_last}{last}");
+    if digits.len() < 5 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let digits = &digits[digits.len() - 5..];
+    let major = digits[..3].parse::<u64>().ok()?;
+    let minor = digits[3..].parse::<u64>().ok()?;
+    Some(Version::new([major, minor]))
+}
+
 /// Reads and parses the PCI class and vendor ID from a given device path under `/sys/bus/pci/devices`.
 fn parse_pci_device_ids(device_path: &Path) -> Result<(u32, u32), AcceleratorError> {
     // Parse, e.g.:
@@ -373,4 +470,43 @@ mod tests {
             assert_eq!(version, Version::from_str("572.60").unwrap());
         }
     }
+
+    #[test]
+    fn kfd_gfx_target_version() {
+        let content =
+            "cpu_cores_count 0\nsimd_count 192\ngfx_target_version 110000\nvendor_id 4098\n";
+        assert_eq!(
+            parse_kfd_gfx_target_version(content),
+            Some(AmdGpuArchitecture::Gfx1100)
+        );
+
+        let content = "cpu_cores_count 0\ngfx_target_version 90010\n";
+        assert_eq!(
+            parse_kfd_gfx_target_version(content),
+            Some(AmdGpuArchitecture::Gfx90a)
+        );
+
+        let content = "cpu_cores_count 0\ngfx_target_version 90402\n";
+        assert_eq!(
+            parse_kfd_gfx_target_version(content),
+            Some(AmdGpuArchitecture::Gfx942)
+        );
+
+        // CPU nodes report a target version of zero.
+        let content = "cpu_cores_count 16\ngfx_target_version 0\n";
+        assert_eq!(parse_kfd_gfx_target_version(content), None);
+    }
+
+    #[test]
+    fn windows_nvidia_driver_version() {
+        assert_eq!(
+            parse_windows_nvidia_driver_version("32.0.15.6094"),
+            Some(Version::from_str("560.94").unwrap())
+        );
+        assert_eq!(
+            parse_windows_nvidia_driver_version("31.0.15.5222"),
+            Some(Version::from_str("552.22").unwrap())
+        );
+        assert_eq!(parse_windows_nvidia_driver_version("1.2"), None);
+    }
 }
@@ -198,28 +198,39 @@ pub enum TorchStrategy {
         os: Os,
         driver_version: Version,
         source: TorchSource,
+        packages: Vec<PackageName>,
     },
     /// Select the appropriate PyTorch index based on the operating system and AMD GPU architecture (e.g., `gfx1100`).
     Amd {
         os: Os,
         gpu_architecture: AmdGpuArchitecture,
         source: TorchSource,
+        packages: Vec<PackageName>,
     },
     /// Select the appropriate PyTorch index based on the operating system and Intel GPU presence.
-    Xpu { os: Os, source: TorchSource },
+    Xpu {
+        os: Os,
+        source: TorchSource,
+        packages: Vec<PackageName>,
+    },
     /// Use the specified PyTorch index.
     Backend {
         backend: TorchBackend,
         source: TorchSource,
+        packages: Vec<PackageName>,
     },
 }
 
 impl TorchStrategy {
     /// Determine the [`TorchStrategy`] from the given [`TorchMode`], [`Os`], and [`Accelerator`].
+    ///
+    /// The strategy applies to the PyTorch packages hosted on the selected index, along with any
+    /// additional `packages` (e.g., other packages in the same family of GPU-enabled builds).
     pub fn from_mode(
         mode: TorchMode,
         source: TorchSource,
         os: &Os,
+        packages: Vec<PackageName>,
     ) -> Result<Self, AcceleratorError> {
         let backend = match mode {
             TorchMode::Auto => match Accelerator::detect()? {
@@ -228,6 +239,7 @@ impl TorchStrategy {
                         os: os.clone(),
                         driver_version: driver_version.clone(),
                         source,
+                        packages,
                     });
                 }
                 Some(Accelerator::Amd { gpu_architecture }) => {
@@ -235,12 +247,14 @@ impl TorchStrategy {
                         os: os.clone(),
                         gpu_architecture,
                         source,
+                        packages,
                     });
                 }
                 Some(Accelerator::Xpu) => {
                     return Ok(Self::Xpu {
                         os: os.clone(),
                         source,
+                        packages,
                     });
                 }
                 None => TorchBackend::Cpu,
@@ -290,17 +304,32 @@ impl TorchStrategy {
             TorchMode::Rocm401 => TorchBackend::Rocm401,
             TorchMode::Xpu => TorchBackend::Xpu,
         };
-        Ok(Self::Backend { backend, source })
+        Ok(Self::Backend {
+            backend,
+            source,
+            packages,
+        })
     }
 
     /// Returns `true` if the [`TorchStrategy`] applies to the given [`PackageName`].
     pub fn applies_to(&self, package_name: &PackageName) -> bool {
-        let source = match self {
-            Self::Cuda { source, .. } => *source,
-            Self::Amd { source, .. } => *source,
-            Self::Xpu { source, .. } => *source,
-            Self::Backend { source, .. } => *source,
+        let (source, packages) = match self {
+            Self::Cuda {
+                source, packages, ..
+            } => (*source, packages),
+            Self::Amd {
+                source, packages, ..
+            } => (*source, packages),
+            Self::Xpu {
+                source, packages, ..
+            } => (*source, packages),
+            Self::Backend {
+                source, packages, ..
+            } => (*source, packages),
         };
+        if packages.contains(package_name) {
+            return true;
+        }
         match source {
             TorchSource::PyTorch => {
                 matches!(
@@ -384,6 +413,7 @@ impl TorchStrategy {
                 os,
                 driver_version,
                 source,
+                ..
             } => {
                 // If this is a GPU-enabled package, and CUDA drivers are installed, use PyTorch's CUDA
                 // indexes.
@@ -434,6 +464,7 @@ impl TorchStrategy {
                 os,
                 gpu_architecture,
                 source,
+                ..
             } => match os {
                 Os::Manylinux { .. } | Os::Musllinux { .. } => Either::Left(Either::Right(
                     LINUX_AMD_GPU_DRIVERS
@@ -461,7 +492,7 @@ impl TorchStrategy {
                     TorchBackend::Cpu.index_url(*source),
                 ))),
             },
-            Self::Xpu { os, source } => match os {
+            Self::Xpu { os, source, .. } => match os {
                 Os::Manylinux { .. } | Os::Windows => Either::Right(Either::Right(Either::Left(
                     std::iter::once(TorchBackend::Xpu.index_url(*source)),
                 ))),
@@ -479,9 +510,11 @@ impl TorchStrategy {
                     TorchBackend::Cpu.index_url(*source),
                 ))),
             },
-            Self::Backend { backend, source } => Either::Right(Either::Right(Either::Right(
-                std::iter::once(backend.index_url(*source)),
-            ))),
+            Self::Backend {
+                backend, source, ..
+            } => Either::Right(Either::Right(Either::Right(std::iter::once(
+                backend.index_url(*source),
+            )))),
         }
     }
 }
@@ -12,7 +12,7 @@ use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
     CachedDist, ConfigSettings, DependencyMetadata, DistributionId, ExtraBuildRequires,
     ExtraBuildVariables, IndexCapabilities, IndexLocations, InstalledDist, IsBuildBackendError,
-    PackageConfigSettings, Requirement, Resolution, SourceDist,
+    PackageConfigSettings, Patches, Requirement, Resolution, SourceDist,
 };
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
@@ -111,6 +111,9 @@ pub trait BuildContext {
     /// Get the extra build variables.
     fn extra_build_variables(&self) -> &ExtraBuildVariables;
 
+    /// Get the patches to apply to source distributions before building.
+    fn patches(&self) -> &Patches;
+
     /// Resolve the given requirements into a ready-to-install set of package versions.
     fn resolve<'a>(
         &'a self,
@@ -281,6 +281,18 @@ pub(crate) fn create(
                 )),
             )?;
         }
+        if interpreter.debug_enabled() {
+            // e.g., `python3.13d` or `python3.13td`
+            uv_fs::replace_symlink(
+                "python",
+                scripts.join(format!(
+                    "python{}.{}{}",
+                    interpreter.python_major(),
+                    interpreter.python_minor(),
+                    interpreter.variant().executable_suffix(),
+                )),
+            )?;
+        }
 
         if interpreter.markers().implementation_name() == "pypy" {
             uv_fs::replace_symlink(
@@ -7,7 +7,7 @@ use tracing::error;
 
 use uv_distribution_types::RequiresPython;
 use uv_fs::Simplified;
-use uv_normalize::{DEV_DEPENDENCIES, GroupName};
+use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::Pep508Error;
 use uv_pypi_types::{DependencyGroupSpecifier, VerbatimParsedUrl};
@@ -142,10 +142,75 @@ impl FlatDependencyGroups {
                             }
                         }
                     }
-                    DependencyGroupSpecifier::IncludeGroup { include_group } => {
+                    DependencyGroupSpecifier::IncludeGroup {
+                        include_group,
+                        exclude,
+                        overrides,
+                    } => {
                         resolve_group(resolved, groups, settings, include_group, parents)?;
                         if let Some(included) = resolved.get(include_group) {
-                            requirements.extend(included.requirements.iter().cloned());
+                            let overrides = overrides
+                                .iter()
+                                .map(|requirement| {
+                                    uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(
+                                        requirement,
+                                    )
+                                    .map_err(|err| {
+                                        DependencyGroupErrorInner::GroupParseError(
+                                            name.clone(),
+                                            requirement.clone(),
+                                            Box::new(err),
+                                        )
+                                    })
+                                })
+                                .collect::<Result<Vec<_>, _>>()?;
+
+                            // Every excluded or overridden package must be present in the
+                            // included group, to catch typos and stale entries.
+                            for package in exclude
+                                .iter()
+                                .chain(overrides.iter().map(|requirement| &requirement.name))
+                            {
+                                if !included
+                                    .requirements
+                                    .iter()
+                                    .any(|requirement| requirement.name == *package)
+                                {
+                                    return Err(DependencyGroupErrorInner::IncludePackageNotFound(
+                                        name.clone(),
+                                        package.clone(),
+                                        include_group.clone(),
+                                    ));
+                                }
+                            }
+
+                            requirements.extend(
+                                included
+                                    .requirements
+                                    .iter()
+                                    .filter(|requirement| {
+                                        !exclude.contains(&requirement.name)
+                                            && !overrides
+                                                .iter()
+                                                .any(|over| over.name == requirement.name)
+                                    })
+                                    .cloned(),
+                            );
+
+                            // Apply the included group's `requires-python` to the overrides, as
+                            // for the requirements they replace.
+                            let included_requires_python = settings
+                                .get(include_group)
+                                .and_then(|settings| settings.requires_python.as_ref());
+                            requirements.extend(overrides.into_iter().map(|mut requirement| {
+                                if let Some(requires_python) = included_requires_python {
+                                    requirement.marker.and(
+                                        RequiresPython::from_specifiers(requires_python)
+                                            .to_marker_tree(),
+                                    );
+                                }
+                                requirement
+                            }));
 
                             // Intersect the requires-python for this group with the included group's
                             requires_python_intersection = requires_python_intersection
@@ -277,6 +342,10 @@ pub enum DependencyGroupErrorInner {
     ),
     #[error("Failed to find group `{0}` included by `{1}`")]
     GroupNotFound(GroupName, GroupName),
+    #[error(
+        "Group `{0}` excludes or overrides `{1}`, but `{1}` is not a dependency of the included group `{2}`"
+    )]
+    IncludePackageNotFound(GroupName, PackageName, GroupName),
     #[error(
         "Group `{0}` includes the `dev` group (`include = \"dev\"`), but only `tool.uv.dev-dependencies` was found. To reference the `dev` group via an `include`, remove the `tool.uv.dev-dependencies` section and add any development dependencies to the `dev` entry in the `[dependency-groups]` table instead."
     )]
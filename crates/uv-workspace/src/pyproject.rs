@@ -13,6 +13,7 @@ use std::fmt::Formatter;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use glob::Pattern;
 use owo_colors::OwoColorize;
@@ -520,6 +521,38 @@ pub struct ToolUv {
     )]
     pub exclude_dependencies: Option<Vec<PackageName>>,
 
+    /// Dependencies that are provided by the environment, rather than installed by uv.
+    ///
+    /// Provided dependencies are treated as already satisfied during resolution: they're omitted
+    /// from the lockfile and will never be installed, upgraded, or removed by uv. If a provided
+    /// dependency is pinned to an exact version (e.g., `torch==2.4.0`), the resolver will select
+    /// versions of other packages that are compatible with that version, and `uv sync` will verify
+    /// that the environment contains a matching installation.
+    ///
+    /// This is useful for packages that are supplied by the system or by a container image,
+    /// such as a vendor-built `torch` or an `mpi4py` linked against the system MPI.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `provided-dependencies` from
+    ///     the `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members or `uv.toml` files.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "PEP 508-style requirements, e.g., `torch==2.4.0`, or `mpi4py`."
+        )
+    )]
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            # Assume that the environment provides torch 2.4.0 and any version of mpi4py.
+            provided-dependencies = ["torch==2.4.0", "mpi4py"]
+        "#
+    )]
+    pub provided_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
+
     /// Constraints to apply when resolving the project's dependencies.
     ///
     /// Constraints are used to restrict the versions of dependencies that are selected during
@@ -554,16 +587,18 @@ pub struct ToolUv {
     /// Constraints to apply when solving build dependencies.
     ///
     /// Build constraints are used to restrict the versions of build dependencies that are selected
-    /// when building a package during resolution or installation.
+    /// when building a package during resolution or installation. The constraints apply to every
+    /// build environment, including those used to build the build dependencies themselves from
+    /// source.
     ///
     /// Including a package as a constraint will _not_ trigger installation of the package during
     /// a build; instead, the package must be requested elsewhere in the project's build dependency
     /// graph.
     ///
     /// !!! note
-    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `build-constraint-dependencies` from
-    ///     the `pyproject.toml` at the workspace root, and will ignore any declarations in other
-    ///     workspace members or `uv.toml` files.
+    ///     In `uv lock`, `uv sync`, `uv run`, and `uv build`, uv will only read
+    ///     `build-constraint-dependencies` from the `pyproject.toml` at the workspace root, and will
+    ///     ignore any declarations in other workspace members or `uv.toml` files.
     #[cfg_attr(
         feature = "schemars",
         schemars(
@@ -582,6 +617,34 @@ pub struct ToolUv {
     )]
     pub build_constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
 
+    /// Patches to apply to source distributions before building.
+    ///
+    /// Each entry maps a package name to a list of patch files in unified diff format (as produced
+    /// by `diff -u` or `git diff`), which are applied, in order, to the unpacked source
+    /// distribution before it's built. Paths within each patch are resolved relative to the root
+    /// of the source distribution, with any leading `a/` or `b/` prefix removed.
+    ///
+    /// Patch files are resolved relative to the workspace root. If a `version` is provided, the
+    /// patch is only applied to that version of the package.
+    ///
+    /// The hash of each patch is recorded in the lockfile, such that changes to a patch will
+    /// invalidate the lockfile and trigger a rebuild of the affected package. Patched packages are
+    /// always built from source, even if a compatible wheel is available.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `patches` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, list[dict]]",
+        example = r#"
+            [tool.uv.patches]
+            pyyaml = [{ path = "patches/pyyaml-cython.patch", version = "6.0.1" }]
+        "#
+    )]
+    pub patches: Option<BTreeMap<PackageName, Vec<PackagePatch>>>,
+
     /// A list of supported environments against which to resolve dependencies.
     ///
     /// By default, uv will resolve for all possible environments during a `uv lock` operation.
@@ -646,6 +709,53 @@ pub struct ToolUv {
     )]
     pub required_environments: Option<SupportedEnvironments>,
 
+    /// Whether to record the provenance of each package's markers in the lockfile.
+    ///
+    /// When enabled, each package in the `uv.lock` includes the simplified marker under which it's
+    /// installed, along with the markers of the resolver forks that selected it. The provenance can
+    /// be inspected with `uv lock --explain <package>` to determine which environments a given
+    /// version is installed in, and why, without re-resolving.
+    ///
+    /// The recorded provenance is informational, and is not used when installing from the lockfile.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            marker-provenance = true
+        "#
+    )]
+    pub marker_provenance: Option<bool>,
+
+    /// The policies that determine which packages may be upgraded by `uv lock --upgrade`.
+    ///
+    /// Each entry maps a package name to one of:
+    ///
+    /// - `auto`: Upgrade the package whenever `--upgrade` is provided (the default).
+    /// - `daily`, `weekly`, `monthly`: Only upgrade the package if the locked version was uploaded
+    ///   at least a day, a week, or 30 days ago, respectively, such that a regularly scheduled
+    ///   `uv lock --upgrade` bumps the package at most once per interval.
+    /// - `manual`: Never upgrade the package with `--upgrade`.
+    ///
+    /// Packages that are held back by their policy retain their locked versions, unless the locked
+    /// version is no longer compatible with the project's requirements. Policies only apply to
+    /// `--upgrade`; a package can always be upgraded explicitly with `--upgrade-package`.
+    ///
+    /// If the lockfile doesn't include upload times for a package (e.g., for packages that aren't
+    /// sourced from a registry), interval policies are treated as `auto`.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `upgrade-policy` from the
+    ///     `pyproject.toml` at the workspace root, and will ignore any declarations in other
+    ///     workspace members.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            upgrade-policy = { boto3 = "weekly", django = "manual" }
+        "#
+    )]
+    pub upgrade_policy: Option<BTreeMap<PackageName, PackageUpgradePolicy>>,
+
     /// Declare collections of extras or dependency groups that are conflicting
     /// (i.e., mutually exclusive).
     ///
@@ -690,6 +800,10 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// Configuration for `uv test`.
+    #[option_group]
+    pub test: Option<ToolUvTest>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -700,6 +814,65 @@ pub struct ToolUv {
     pub build_backend: Option<BuildBackendSettingsSchema>,
 }
 
+/// The policy that determines whether a package may be upgraded by `uv lock --upgrade`.
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum PackageUpgradePolicy {
+    /// Upgrade the package whenever `--upgrade` is provided.
+    #[default]
+    Auto,
+    /// Upgrade the package if the locked version was uploaded at least a day ago.
+    Daily,
+    /// Upgrade the package if the locked version was uploaded at least a week ago.
+    Weekly,
+    /// Upgrade the package if the locked version was uploaded at least 30 days ago.
+    Monthly,
+    /// Only upgrade the package when requested explicitly, with `--upgrade-package`.
+    Manual,
+}
+
+impl PackageUpgradePolicy {
+    /// The minimum age of the locked version before the package may be upgraded, if any.
+    pub fn interval(self) -> Option<Duration> {
+        const DAY: u64 = 24 * 60 * 60;
+        match self {
+            Self::Auto | Self::Manual => None,
+            Self::Daily => Some(Duration::from_secs(DAY)),
+            Self::Weekly => Some(Duration::from_secs(7 * DAY)),
+            Self::Monthly => Some(Duration::from_secs(30 * DAY)),
+        }
+    }
+}
+
+impl std::fmt::Display for PackageUpgradePolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Daily => write!(f, "daily"),
+            Self::Weekly => write!(f, "weekly"),
+            Self::Monthly => write!(f, "monthly"),
+            Self::Manual => write!(f, "manual"),
+        }
+    }
+}
+
+/// A patch to apply to a source distribution before building.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct PackagePatch {
+    /// The path to the patch file, relative to the workspace root.
+    pub path: PortablePathBuf,
+    /// The version of the package to which the patch applies.
+    ///
+    /// If omitted, the patch is applied to all versions of the package.
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub version: Option<Version>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -911,6 +1084,27 @@ pub struct ToolUvWorkspace {
     pub exclude: Option<Vec<SerdePattern>>,
 }
 
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolUvTest {
+    /// The command used to run the project's tests, as a list of arguments.
+    ///
+    /// Any arguments passed to `uv test` are appended to the command. The command is run in the
+    /// project environment, after syncing the `test` dependency group (if defined).
+    ///
+    /// Defaults to `["pytest"]`.
+    #[option(
+        default = r#"["pytest"]"#,
+        value_type = "list[str]",
+        example = r#"
+            command = ["python", "-m", "unittest", "discover"]
+        "#
+    )]
+    pub command: Option<Vec<String>>,
+}
+
 /// (De)serialize globs as strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SerdePattern(Pattern);
@@ -1067,6 +1261,11 @@ impl TryFrom<SourcesWire> for Sources {
                     if lhs.group() != rhs.group() {
                         continue;
                     }
+                    // A fallback source is only consulted when the primary source can't satisfy
+                    // the requirement, so it's expected to overlap with it.
+                    if lhs.is_fallback() != rhs.is_fallback() {
+                        continue;
+                    }
 
                     let lhs = lhs.marker();
                     let rhs = rhs.marker();
@@ -1122,6 +1321,10 @@ pub enum Source {
         rev: Option<String>,
         tag: Option<String>,
         branch: Option<String>,
+        /// When set to `true`, the package is resolved from the index, and the Git repository is
+        /// only used in forks where no compatible version is available on the index. `false` by
+        /// default.
+        fallback: Option<bool>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1143,6 +1346,9 @@ pub enum Source {
         /// For source distributions, the path to the directory with the `pyproject.toml`, if it's
         /// not in the archive root.
         subdirectory: Option<PortablePathBuf>,
+        /// When set to `true`, the package is resolved from the index, and the URL is only used in
+        /// forks where no compatible version is available on the index. `false` by default.
+        fallback: Option<bool>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
             serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1226,6 +1432,7 @@ impl<'de> Deserialize<'de> for Source {
             package: Option<bool>,
             index: Option<IndexName>,
             workspace: Option<bool>,
+            fallback: Option<bool>,
             #[serde(
                 skip_serializing_if = "uv_pep508::marker::ser::is_empty",
                 serialize_with = "uv_pep508::marker::ser::serialize",
@@ -1249,6 +1456,7 @@ impl<'de> Deserialize<'de> for Source {
             package,
             index,
             workspace,
+            fallback,
             marker,
             extra,
             group,
@@ -1261,6 +1469,13 @@ impl<'de> Deserialize<'de> for Source {
             ));
         }
 
+        // Only remote sources can act as a fallback for the index.
+        if fallback.is_some() && git.is_none() && url.is_none() {
+            return Err(serde::de::Error::custom(
+                "`fallback` is only supported for `git` and `url` sources",
+            ));
+        }
+
         // If the `git` field is set, we're dealing with a Git source.
         if let Some(git) = git {
             if index.is_some() {
@@ -1320,6 +1535,7 @@ impl<'de> Deserialize<'de> for Source {
                 rev,
                 tag,
                 branch,
+                fallback,
                 marker,
                 extra,
                 group,
@@ -1377,6 +1593,7 @@ impl<'de> Deserialize<'de> for Source {
             return Ok(Self::Url {
                 url,
                 subdirectory,
+                fallback,
                 marker,
                 extra,
                 group,
@@ -1617,6 +1834,7 @@ impl Source {
                         if let Self::Git {
                             git,
                             subdirectory,
+                            fallback,
                             marker,
                             extra,
                             group,
@@ -1629,6 +1847,7 @@ impl Source {
                                 rev,
                                 tag,
                                 branch,
+                                fallback: *fallback,
                                 marker: *marker,
                                 extra: extra.clone(),
                                 group: group.clone(),
@@ -1718,6 +1937,7 @@ impl Source {
             } => Self::Url {
                 url: location,
                 subdirectory: subdirectory.map(PortablePathBuf::from),
+                fallback: None,
                 marker: MarkerTree::TRUE,
                 extra: None,
                 group: None,
@@ -1740,6 +1960,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        fallback: None,
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1751,6 +1972,7 @@ impl Source {
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
+                        fallback: None,
                         marker: MarkerTree::TRUE,
                         extra: None,
                         group: None,
@@ -1794,6 +2016,14 @@ impl Source {
             Self::Workspace { group, .. } => group.as_ref(),
         }
     }
+
+    /// Returns `true` if the source is only used when the index can't satisfy the requirement.
+    pub fn is_fallback(&self) -> bool {
+        match self {
+            Self::Git { fallback, .. } | Self::Url { fallback, .. } => *fallback == Some(true),
+            Self::Path { .. } | Self::Registry { .. } | Self::Workspace { .. } => false,
+        }
+    }
 }
 
 /// The type of a dependency in a `pyproject.toml`.
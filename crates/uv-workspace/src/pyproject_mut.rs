@@ -13,7 +13,7 @@ use toml_edit::{
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
-use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_normalize::{DEV_DEPENDENCIES, ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
 use uv_redacted::DisplaySafeUrl;
@@ -807,6 +807,37 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Set the version specifiers for all occurrences of the dependency with the given name.
+    ///
+    /// Searches the same locations as [`PyProjectTomlMut::find_dependency`]. Requirements with a
+    /// URL are left unchanged.
+    ///
+    /// Returns the number of updated requirements.
+    pub fn set_dependency_specifiers(
+        &mut self,
+        name: &PackageName,
+        specifiers: &VersionSpecifiers,
+    ) -> Result<usize, Error> {
+        let mut updated = 0;
+        for dependency_type in self.find_dependency(name, None) {
+            let dependencies = match &dependency_type {
+                DependencyType::Production => self.dependencies_array()?,
+                DependencyType::Dev => self.dev_dependencies_array()?,
+                DependencyType::Optional(extra) => self.optional_dependencies_array(extra)?,
+                DependencyType::Group(group) => self.dependency_groups_array(group)?,
+            };
+            for (index, mut req) in find_dependencies(name, None, dependencies) {
+                if matches!(req.version_or_url, Some(VersionOrUrl::Url(_))) {
+                    continue;
+                }
+                req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers.clone()));
+                dependencies.replace(index, req.to_string());
+                updated += 1;
+            }
+        }
+        Ok(updated)
+    }
+
     /// Get the TOML array for `project.dependencies`.
     fn dependencies_array(&mut self) -> Result<&mut Array, Error> {
         // Get or create `project.dependencies`.
@@ -1192,6 +1223,79 @@ impl PyProjectTomlMut {
         types
     }
 
+    /// Returns the names of all dependencies of the given type, in declaration order and without
+    /// duplicates.
+    ///
+    /// For [`DependencyType::Dev`], this includes both `tool.uv.dev-dependencies` and the `dev`
+    /// dependency group.
+    pub fn dependency_names(&self, dependency_type: &DependencyType) -> Vec<PackageName> {
+        let project = match self.target {
+            DependencyTarget::Script => Some(self.doc.as_table()),
+            DependencyTarget::PyProjectToml => self.doc.get("project").and_then(Item::as_table),
+        };
+        let groups = self.doc.get("dependency-groups").and_then(Item::as_table);
+        let group = |group: &GroupName| {
+            groups.and_then(|groups| {
+                groups.iter().find_map(|(key, value)| {
+                    if GroupName::from_str(key).is_ok_and(|key| key == *group) {
+                        value.as_array()
+                    } else {
+                        None
+                    }
+                })
+            })
+        };
+        let dev_dependencies = || {
+            self.doc
+                .get("tool")
+                .and_then(Item::as_table)
+                .and_then(|tool| tool.get("uv"))
+                .and_then(Item::as_table)
+                .and_then(|uv| uv.get("dev-dependencies"))
+                .and_then(Item::as_array)
+        };
+
+        let arrays: Vec<&Array> = match dependency_type {
+            DependencyType::Production => project
+                .and_then(|project| project.get("dependencies"))
+                .and_then(Item::as_array)
+                .into_iter()
+                .collect(),
+            DependencyType::Optional(extra) => project
+                .and_then(|project| project.get("optional-dependencies"))
+                .and_then(Item::as_table)
+                .and_then(|extras| {
+                    extras.iter().find_map(|(key, value)| {
+                        if ExtraName::from_str(key).is_ok_and(|key| key == *extra) {
+                            value.as_array()
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .into_iter()
+                .collect(),
+            DependencyType::Dev => dev_dependencies()
+                .into_iter()
+                .chain(group(&*DEV_DEPENDENCIES))
+                .collect(),
+            DependencyType::Group(name) if *name == *DEV_DEPENDENCIES => {
+                dev_dependencies().into_iter().chain(group(name)).collect()
+            }
+            DependencyType::Group(name) => group(name).into_iter().collect(),
+        };
+
+        let mut names = Vec::new();
+        for dep in arrays.into_iter().flat_map(Array::iter) {
+            if let Some(req) = dep.as_str().and_then(try_parse_requirement) {
+                if !names.contains(&req.name) {
+                    names.push(req.name);
+                }
+            }
+        }
+        names
+    }
+
     pub fn version(&mut self) -> Result<Version, Error> {
         let version = self
             .doc
@@ -1750,10 +1854,14 @@ fn split_specifiers(req: &str) -> (&str, &str) {
 
 #[cfg(test)]
 mod test {
-    use super::{AddBoundsKind, reformat_array_multiline, split_specifiers};
+    use super::{
+        AddBoundsKind, DependencyTarget, PyProjectTomlMut, reformat_array_multiline,
+        split_specifiers,
+    };
     use std::str::FromStr;
     use toml_edit::DocumentMut;
-    use uv_pep440::Version;
+    use uv_normalize::PackageName;
+    use uv_pep440::{Version, VersionSpecifiers};
 
     #[test]
     fn split() {
@@ -1927,4 +2035,50 @@ dependencies = [
             assert_eq!(actual, expected, "{version}");
         }
     }
+
+    #[test]
+    fn set_dependency_specifiers() {
+        let mut pyproject = PyProjectTomlMut::from_toml(
+            r#"
+[project]
+name = "project"
+dependencies = [
+    "anyio>=3",
+    "idna",
+    "Anyio[trio]>=3 ; sys_platform == 'linux'",
+]
+
+[project.optional-dependencies]
+async = ["anyio @ https://example.com/anyio-4.0.0-py3-none-any.whl"]
+
+[dependency-groups]
+dev = ["anyio<4"]
+"#,
+            DependencyTarget::PyProjectToml,
+        )
+        .unwrap();
+
+        let updated = pyproject
+            .set_dependency_specifiers(
+                &PackageName::from_str("anyio").unwrap(),
+                &VersionSpecifiers::from_str(">=4.2,<5").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(updated, 3);
+        insta::assert_snapshot!(pyproject.to_string(), @r#"
+        [project]
+        name = "project"
+        dependencies = [
+            "anyio>=4.2,<5",
+            "idna",
+            "anyio[trio]>=4.2,<5 ; sys_platform == 'linux'",
+        ]
+
+        [project.optional-dependencies]
+        async = ["anyio @ https://example.com/anyio-4.0.0-py3-none-any.whl"]
+
+        [dependency-groups]
+        dev = ["anyio>=4.2,<5"]
+        "#);
+    }
 }
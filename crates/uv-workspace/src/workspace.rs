@@ -1,5 +1,6 @@
 //! Resolve the current [`ProjectWorkspace`] or [`Workspace`].
 
+use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -21,11 +22,15 @@ use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
+    PackagePatch, PackageUpgradePolicy, Project, PyProjectToml, PyprojectTomlError, Source,
+    Sources, ToolUvSources, ToolUvWorkspace,
 };
 
 type WorkspaceMembers = Arc<BTreeMap<PackageName, WorkspaceMember>>;
 
+/// The `pyproject.toml` files of workspace members without a `[project]` table, by member root.
+type NonProjectMembers = Arc<BTreeMap<PathBuf, PyProjectToml>>;
+
 /// Cache key for workspace discovery.
 ///
 /// Given this key, the discovered workspace member list is the same.
@@ -40,7 +45,9 @@ struct WorkspaceCacheKey {
 /// Avoid re-reading the `pyproject.toml` files in a workspace for each member by caching the
 /// workspace members by their workspace root.
 #[derive(Debug, Default, Clone)]
-pub struct WorkspaceCache(Arc<Mutex<FxHashMap<WorkspaceCacheKey, WorkspaceMembers>>>);
+pub struct WorkspaceCache(
+    Arc<Mutex<FxHashMap<WorkspaceCacheKey, (WorkspaceMembers, NonProjectMembers)>>>,
+);
 
 #[derive(thiserror::Error, Debug)]
 pub enum WorkspaceError {
@@ -159,6 +166,10 @@ pub struct Workspace {
     /// The workspace members that are required by other members, and whether they were requested
     /// as editable.
     required_members: BTreeMap<PackageName, Editability>,
+    /// The workspace members without a `[project]` table, which only declare dependency groups
+    /// (e.g., a collection of notebooks or infrastructure scripts), by member root.
+    #[cfg_attr(test, serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    non_project_members: BTreeMap<PathBuf, PyProjectToml>,
     /// The sources table from the workspace `pyproject.toml`.
     ///
     /// This table is overridden by the project sources.
@@ -179,7 +190,9 @@ impl Workspace {
     ///
     /// Steps of workspace discovery: Start by looking at the closest `pyproject.toml`:
     /// * If it's an explicit workspace root: Collect workspace from this root, we're done.
-    /// * If it's also not a project: Error, must be either a workspace root or a project.
+    /// * If it's also not a project, but declares dependency groups: Use an explicit workspace root
+    ///   above that includes it, if any, or treat it as an implicit non-project root.
+    /// * If it's also not a project otherwise: Error, must be either a workspace root or a project.
     /// * Otherwise, try to find an explicit workspace root above:
     ///   * If an explicit workspace root exists: Collect workspace from this root, we're done.
     ///   * If there is no explicit workspace: We have a single project workspace, we're done.
@@ -251,8 +264,21 @@ impl Workspace {
                 // We have found the explicit root immediately.
                 workspace
             } else if pyproject_toml.project.is_none() {
-                // Without a project, it can't be an implicit root
-                return Err(WorkspaceError::MissingProject(pyproject_path));
+                if pyproject_toml.dependency_groups.is_none() {
+                    // Without a project or dependency groups, it can't be an implicit root
+                    return Err(WorkspaceError::MissingProject(pyproject_path));
+                }
+                if let Some(workspace) = find_workspace(&project_path, options).await? {
+                    // We have found an explicit root above that includes the non-project member.
+                    workspace
+                } else {
+                    // Support implicit non-project roots that only declare dependency groups.
+                    (
+                        project_path.clone(),
+                        ToolUvWorkspace::default(),
+                        pyproject_toml.clone(),
+                    )
+                }
             } else if let Some(workspace) = find_workspace(&project_path, options).await? {
                 // We have found an explicit root above.
                 workspace
@@ -561,6 +587,30 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the upgrade policies for the workspace, keyed by package name.
+    pub fn upgrade_policies(&self) -> BTreeMap<PackageName, PackageUpgradePolicy> {
+        let Some(policies) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.upgrade_policy.as_ref())
+        else {
+            return BTreeMap::new();
+        };
+        policies.clone()
+    }
+
+    /// Returns `true` if the lockfile should record the provenance of each package's markers.
+    pub fn marker_provenance(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.marker_provenance)
+            .unwrap_or(false)
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Conflicts {
         let mut conflicting = Conflicts::empty();
@@ -623,28 +673,50 @@ impl Workspace {
     /// in any of the workspace members.
     ///
     /// For workspaces with non-`[project]` roots, returns the dependency groups defined in the
-    /// corresponding `pyproject.toml`.
+    /// corresponding `pyproject.toml`. The dependency groups of any non-`[project]` members are
+    /// merged in, such that groups with the same name are combined.
     ///
     /// Otherwise, returns an empty list.
     pub fn workspace_dependency_groups(
         &self,
     ) -> Result<BTreeMap<GroupName, FlatDependencyGroup>, DependencyGroupError> {
-        if self
+        let mut dependency_groups = if self
             .packages
             .values()
             .any(|member| *member.root() == self.install_path)
         {
             // If the workspace has an explicit root, the root is a member, so we don't need to
             // include any root-only requirements.
-            Ok(BTreeMap::default())
+            BTreeMap::default()
         } else {
             // Otherwise, return the dependency groups in the non-project workspace root.
-            let dependency_groups = FlatDependencyGroups::from_pyproject_toml(
-                &self.install_path,
-                &self.pyproject_toml,
-            )?;
-            Ok(dependency_groups.into_inner())
+            FlatDependencyGroups::from_pyproject_toml(&self.install_path, &self.pyproject_toml)?
+                .into_inner()
+        };
+
+        for (root, pyproject_toml) in &self.non_project_members {
+            let member_groups = FlatDependencyGroups::from_pyproject_toml(root, pyproject_toml)?;
+            for (name, group) in member_groups.into_inner() {
+                match dependency_groups.entry(name) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(group);
+                    }
+                    Entry::Occupied(mut entry) => {
+                        let existing = entry.get_mut();
+                        existing.requirements.extend(group.requirements);
+                        existing.requires_python =
+                            match (existing.requires_python.take(), group.requires_python) {
+                                (Some(left), Some(right)) => {
+                                    Some(left.into_iter().chain(right).collect())
+                                }
+                                (left, right) => left.or(right),
+                            };
+                    }
+                }
+            }
         }
+
+        Ok(dependency_groups)
     }
 
     /// Returns the set of overrides for the workspace.
@@ -675,6 +747,34 @@ impl Workspace {
         excludes.clone()
     }
 
+    /// Returns the set of dependencies provided by the environment for the workspace.
+    pub fn provided_dependencies(&self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
+        let Some(provided) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.provided_dependencies.as_ref())
+        else {
+            return vec![];
+        };
+        provided.clone()
+    }
+
+    /// Returns the set of patches to apply to source distributions for the workspace.
+    pub fn patches(&self) -> BTreeMap<PackageName, Vec<PackagePatch>> {
+        let Some(patches) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.patches.as_ref())
+        else {
+            return BTreeMap::new();
+        };
+        patches.clone()
+    }
+
     /// Returns the set of constraints for the workspace.
     pub fn constraints(&self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         let Some(constraints) = self
@@ -861,30 +961,31 @@ impl Workspace {
             let cache = cache.0.lock().expect("there was a panic in another thread");
             cache.get(&cache_key).cloned()
         };
-        let mut workspace_members = if let Some(workspace_members) = cache_entry {
+        let (mut workspace_members, non_project_members) = if let Some(cached) = cache_entry {
             trace!(
                 "Cached workspace members for: `{}`",
                 &workspace_root.simplified_display()
             );
-            workspace_members
+            cached
         } else {
             trace!(
                 "Discovering workspace members for: `{}`",
                 &workspace_root.simplified_display()
             );
-            let workspace_members = Self::collect_members_only(
+            let (workspace_members, non_project_members) = Self::collect_members_only(
                 &workspace_root,
                 &workspace_definition,
                 &workspace_pyproject_toml,
                 options,
             )
             .await?;
+            let members = (Arc::new(workspace_members), Arc::new(non_project_members));
             {
                 // Acquire the lock for the minimal required region
                 let mut cache = cache.0.lock().expect("there was a panic in another thread");
-                cache.insert(cache_key, Arc::new(workspace_members.clone()));
+                cache.insert(cache_key, members.clone());
             }
-            Arc::new(workspace_members)
+            members
         };
 
         // For the cases such as `MemberDiscovery::None`, add the current project if missing.
@@ -944,6 +1045,7 @@ impl Workspace {
             install_path: workspace_root,
             packages: workspace_members,
             required_members,
+            non_project_members: Arc::unwrap_or_clone(non_project_members),
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
@@ -955,8 +1057,15 @@ impl Workspace {
         workspace_definition: &ToolUvWorkspace,
         workspace_pyproject_toml: &PyProjectToml,
         options: &DiscoveryOptions,
-    ) -> Result<BTreeMap<PackageName, WorkspaceMember>, WorkspaceError> {
+    ) -> Result<
+        (
+            BTreeMap<PackageName, WorkspaceMember>,
+            BTreeMap<PathBuf, PyProjectToml>,
+        ),
+        WorkspaceError,
+    > {
         let mut workspace_members = BTreeMap::new();
+        let mut non_project_members = BTreeMap::new();
         // Avoid reading a `pyproject.toml` more than once.
         let mut seen = FxHashSet::default();
 
@@ -1091,7 +1200,27 @@ impl Workspace {
 
                 // Extract the package name.
                 let Some(project) = pyproject_toml.project.clone() else {
-                    return Err(WorkspaceError::MissingProject(pyproject_path));
+                    // Members without a `[project]` table may still declare dependency groups,
+                    // which are resolved alongside those of the workspace root.
+                    if pyproject_toml.dependency_groups.is_none() {
+                        return Err(WorkspaceError::MissingProject(pyproject_path));
+                    }
+                    if pyproject_toml
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.workspace.as_ref())
+                        .is_some()
+                    {
+                        return Err(WorkspaceError::NestedWorkspace(member_root));
+                    }
+
+                    debug!(
+                        "Adding discovered non-project workspace member: `{}`",
+                        member_root.simplified_display()
+                    );
+                    non_project_members.insert(member_root, pyproject_toml);
+                    continue;
                 };
 
                 debug!(
@@ -1130,7 +1259,7 @@ impl Workspace {
                 return Err(WorkspaceError::NestedWorkspace(member.root.clone()));
             }
         }
-        Ok(workspace_members)
+        Ok((workspace_members, non_project_members))
     }
 }
 
@@ -1450,6 +1579,7 @@ impl ProjectWorkspace {
                     install_path: project_path.clone(),
                     packages: current_project_as_members,
                     required_members,
+                    non_project_members: BTreeMap::default(),
                     // There may be package sources, but we don't need to duplicate them into the
                     // workspace sources.
                     sources: workspace_sources,
@@ -1722,10 +1852,24 @@ impl VirtualProject {
                 .map_err(WorkspaceError::Normalize)?
                 .clone();
 
+            // If it declares dependency groups and is included in a workspace above, it's a
+            // non-project member of that workspace.
+            let (workspace_root, workspace_definition, workspace_pyproject_toml) =
+                if pyproject_toml.dependency_groups.is_some() {
+                    find_workspace(&project_path, options).await?
+                } else {
+                    None
+                }
+                .unwrap_or((
+                    project_path,
+                    ToolUvWorkspace::default(),
+                    pyproject_toml,
+                ));
+
             let workspace = Workspace::collect_members(
-                project_path,
-                ToolUvWorkspace::default(),
-                pyproject_toml,
+                workspace_root,
+                workspace_definition,
+                workspace_pyproject_toml,
                 None,
                 options,
                 cache,
@@ -2074,8 +2218,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2175,8 +2321,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2389,8 +2537,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2499,8 +2649,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2622,8 +2774,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2719,8 +2873,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "provided-dependencies": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "patches": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2760,6 +2916,8 @@ bar = ["b"]
                 DependencyGroupSpecifier::Requirement("a".to_string()),
                 DependencyGroupSpecifier::IncludeGroup {
                     include_group: GroupName::from_str("bar").unwrap(),
+                    exclude: vec![],
+                    overrides: vec![],
                 }
             ]
         );
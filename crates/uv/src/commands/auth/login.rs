@@ -11,6 +11,7 @@ use uv_auth::{
     Service, TextCredentialStore,
 };
 use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder};
+use uv_configuration::KeyringProviderType;
 use uv_distribution_types::IndexUrl;
 use uv_pep508::VerbatimUrl;
 use uv_preview::Preview;
@@ -28,6 +29,7 @@ pub(crate) async fn login(
     username: Option<String>,
     password: Option<String>,
     token: Option<String>,
+    keyring_provider: Option<KeyringProviderType>,
     network_settings: &NetworkSettings,
     printer: Printer,
     preview: Preview,
@@ -68,7 +70,10 @@ pub(crate) async fn login(
         return Ok(ExitStatus::Success);
     }
 
-    let backend = AuthBackend::from_settings(preview)?;
+    let backend = AuthBackend::from_settings(
+        preview,
+        keyring_provider.and_then(|provider| provider.to_provider()),
+    )?;
 
     // If the URL includes a known index URL suffix, strip it
     // TODO(zanieb): Use a shared abstraction across `login` and `logout`?
@@ -5,6 +5,7 @@ use owo_colors::OwoColorize;
 
 use uv_auth::{AuthBackend, Credentials, PyxTokenStore, Service, TextCredentialStore, Username};
 use uv_client::BaseClientBuilder;
+use uv_configuration::KeyringProviderType;
 use uv_distribution_types::IndexUrl;
 use uv_pep508::VerbatimUrl;
 use uv_preview::Preview;
@@ -18,6 +19,7 @@ use crate::{commands::ExitStatus, printer::Printer};
 pub(crate) async fn logout(
     service: Service,
     username: Option<String>,
+    keyring_provider: Option<KeyringProviderType>,
     network_settings: &NetworkSettings,
     printer: Printer,
     preview: Preview,
@@ -27,7 +29,10 @@ pub(crate) async fn logout(
         return pyx_logout(&pyx_store, network_settings, printer, preview).await;
     }
 
-    let backend = AuthBackend::from_settings(preview)?;
+    let backend = AuthBackend::from_settings(
+        preview,
+        keyring_provider.and_then(|provider| provider.to_provider()),
+    )?;
 
     // TODO(zanieb): Use a shared abstraction across `login` and `logout`?
     let url = service.url().clone();
@@ -6,6 +6,7 @@ use tracing::debug;
 use uv_auth::{AuthBackend, Service};
 use uv_auth::{Credentials, PyxTokenStore};
 use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder};
+use uv_configuration::KeyringProviderType;
 use uv_preview::Preview;
 
 use crate::commands::ExitStatus;
@@ -17,6 +18,7 @@ use crate::settings::NetworkSettings;
 pub(crate) async fn token(
     service: Service,
     username: Option<String>,
+    keyring_provider: Option<KeyringProviderType>,
     network_settings: &NetworkSettings,
     printer: Printer,
     preview: Preview,
@@ -41,7 +43,10 @@ pub(crate) async fn token(
         return Ok(ExitStatus::Success);
     }
 
-    let backend = AuthBackend::from_settings(preview)?;
+    let backend = AuthBackend::from_settings(
+        preview,
+        keyring_provider.and_then(|provider| provider.to_provider()),
+    )?;
     let url = service.url();
 
     // Extract credentials from URL if present
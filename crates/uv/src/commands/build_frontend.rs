@@ -7,8 +7,9 @@ use std::{fmt, io};
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use sha2::{Digest, Sha256};
 use thiserror::Error;
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 use uv_build_backend::check_direct_build;
 use uv_cache::{Cache, CacheBucket};
@@ -25,12 +26,13 @@ use uv_distribution_filename::{
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    PackageConfigSettings, RequiresPython, SourceDist,
+    NameRequirementSpecification, PackageConfigSettings, Requirement, RequiresPython, SourceDist,
 };
 use uv_fs::{Simplified, relative_to};
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_pep508::RequirementOrigin;
 use uv_preview::Preview;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
@@ -94,6 +96,10 @@ enum Error {
     InvalidBuiltWheelFilename(#[source] uv_distribution_filename::WheelFilenameError),
     #[error("The source distribution declares version {0}, but the wheel declares version {1}")]
     VersionMismatch(Version, Version),
+    #[error("The build is not reproducible, the following distributions differ between builds: {}", _0.join(", "))]
+    NotReproducible(Vec<String>),
+    #[error("The tags of the built wheels are broader than their binaries support:\n{}", _0.join("\n"))]
+    OverbroadTags(Vec<String>),
 }
 
 /// Build source distributions and wheels.
@@ -111,6 +117,8 @@ pub(crate) async fn build_frontend(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    reproducible_check: bool,
+    check_tags: bool,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
     python: Option<String>,
@@ -138,6 +146,8 @@ pub(crate) async fn build_frontend(
         gitignore,
         force_pep517,
         clear,
+        reproducible_check,
+        check_tags,
         &build_constraints,
         hash_checking,
         python.as_deref(),
@@ -183,6 +193,8 @@ async fn build_impl(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    reproducible_check: bool,
+    check_tags: bool,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
     python_request: Option<&str>,
@@ -205,6 +217,12 @@ async fn build_impl(
         resolution: _,
         prerelease: _,
         fork_strategy: _,
+        yanked_policy: _,
+        allow_yanked: _,
+        known_incompatibilities: _,
+        max_backtracks: _,
+        resolution_timeout: _,
+        prefer_smaller_artifacts: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -350,6 +368,8 @@ async fn build_impl(
             gitignore,
             force_pep517,
             clear,
+            reproducible_check,
+            check_tags,
             build_constraints,
             build_isolation,
             extra_build_dependencies,
@@ -459,6 +479,8 @@ async fn build_package(
     gitignore: bool,
     force_pep517: bool,
     clear: bool,
+    reproducible_check: bool,
+    check_tags: bool,
     build_constraints: &[RequirementsSource],
     build_isolation: &BuildIsolation,
     extra_build_dependencies: &ExtraBuildDependencies,
@@ -536,15 +558,30 @@ async fn build_package(
         Some(&PythonDownloadReporter::single(printer)),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
     .await?
     .into_interpreter();
 
-    // Read build constraints.
-    let build_constraints =
-        operations::read_constraints(build_constraints, &client_builder).await?;
+    // Read build constraints, including any declared in the workspace.
+    let build_constraints: Vec<NameRequirementSpecification> =
+        operations::read_constraints(build_constraints, &client_builder)
+            .await?
+            .into_iter()
+            .chain(
+                workspace
+                    .ok()
+                    .map(Workspace::build_constraints)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|requirement| {
+                        Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                    })
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect();
 
     // Collect the set of required hashes.
     let hasher = if let Some(hash_checking) = hash_checking {
@@ -662,7 +699,7 @@ async fn build_package(
     let version_id = source.path().file_name().and_then(|name| name.to_str());
 
     let build_output = match printer {
-        Printer::Default | Printer::NoProgress | Printer::Verbose => {
+        Printer::Default | Printer::NoProgress | Printer::JsonProgress | Printer::Verbose => {
             if build_logs {
                 BuildOutput::Stderr
             } else {
@@ -672,13 +709,85 @@ async fn build_package(
         Printer::Quiet | Printer::Silent => BuildOutput::Quiet,
     };
 
-    let mut build_results = Vec::new();
-    match plan {
-        BuildPlan::SdistToWheel => {
-            // Even when listing files, we still need to build the source distribution for the wheel
-            // build.
-            if list {
-                let sdist_list = build_sdist(
+    // Build the distributions into the given output directory.
+    let build = async |output_dir: &Path| {
+        let mut build_results = Vec::new();
+        match plan {
+            BuildPlan::SdistToWheel => {
+                // Even when listing files, we still need to build the source distribution for the wheel
+                // build.
+                if list {
+                    let sdist_list = build_sdist(
+                        source.path(),
+                        &output_dir,
+                        build_action,
+                        &source,
+                        printer,
+                        "source distribution",
+                        &build_dispatch,
+                        sources,
+                        dist,
+                        subdirectory,
+                        version_id,
+                        build_output,
+                    )
+                    .await?;
+                    build_results.push(sdist_list);
+                }
+                let sdist_build = build_sdist(
+                    source.path(),
+                    &output_dir,
+                    build_action.force_build(),
+                    &source,
+                    printer,
+                    "source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                )
+                .await?;
+                build_results.push(sdist_build.clone());
+
+                // Extract the source distribution into a temporary directory.
+                let path = output_dir.join(sdist_build.raw_filename());
+                let reader = fs_err::tokio::File::open(&path).await?;
+                let ext = SourceDistExtension::from_path(path.as_path()).map_err(|err| {
+                    Error::InvalidSourceDistExt(path.user_display().to_string(), err)
+                })?;
+                let temp_dir =
+                    tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
+                uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
+
+                // Extract the top-level directory from the archive.
+                let extracted = match uv_extract::strip_component(temp_dir.path()) {
+                    Ok(top_level) => top_level,
+                    Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
+                    Err(err) => return Err(err.into()),
+                };
+
+                let wheel_build = build_wheel(
+                    &extracted,
+                    &output_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel from source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    Some(sdist_build.normalized_filename().version()),
+                )
+                .await?;
+                build_results.push(wheel_build);
+            }
+            BuildPlan::Sdist => {
+                let sdist_build = build_sdist(
                     source.path(),
                     &output_dir,
                     build_action,
@@ -693,176 +802,227 @@ async fn build_package(
                     build_output,
                 )
                 .await?;
-                build_results.push(sdist_list);
+                build_results.push(sdist_build);
+            }
+            BuildPlan::Wheel => {
+                let wheel_build = build_wheel(
+                    source.path(),
+                    &output_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    None,
+                )
+                .await?;
+                build_results.push(wheel_build);
+            }
+            BuildPlan::SdistAndWheel => {
+                let sdist_build = build_sdist(
+                    source.path(),
+                    &output_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                )
+                .await?;
+
+                let wheel_build = build_wheel(
+                    source.path(),
+                    &output_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    Some(sdist_build.normalized_filename().version()),
+                )
+                .await?;
+                build_results.push(sdist_build);
+                build_results.push(wheel_build);
+            }
+            BuildPlan::WheelFromSdist => {
+                // Extract the source distribution into a temporary directory.
+                let reader = fs_err::tokio::File::open(source.path()).await?;
+                let ext = SourceDistExtension::from_path(source.path()).map_err(|err| {
+                    Error::InvalidSourceDistExt(source.path().user_display().to_string(), err)
+                })?;
+                let temp_dir = tempfile::tempdir_in(&output_dir)?;
+                uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
+
+                // If the source distribution has a version in its filename, check the version.
+                let version = source
+                    .path()
+                    .file_name()
+                    .and_then(|filename| filename.to_str())
+                    .and_then(|filename| {
+                        SourceDistFilename::parsed_normalized_filename(filename).ok()
+                    })
+                    .map(|filename| filename.version);
+
+                // Extract the top-level directory from the archive.
+                let extracted = match uv_extract::strip_component(temp_dir.path()) {
+                    Ok(top_level) => top_level,
+                    Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
+                    Err(err) => return Err(err.into()),
+                };
+
+                let wheel_build = build_wheel(
+                    &extracted,
+                    &output_dir,
+                    build_action,
+                    &source,
+                    printer,
+                    "wheel from source distribution",
+                    &build_dispatch,
+                    sources,
+                    dist,
+                    subdirectory,
+                    version_id,
+                    build_output,
+                    version.as_ref(),
+                )
+                .await?;
+                build_results.push(wheel_build);
             }
-            let sdist_build = build_sdist(
-                source.path(),
-                &output_dir,
-                build_action.force_build(),
-                &source,
-                printer,
-                "source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-            )
-            .await?;
-            build_results.push(sdist_build.clone());
-
-            // Extract the source distribution into a temporary directory.
-            let path = output_dir.join(sdist_build.raw_filename());
-            let reader = fs_err::tokio::File::open(&path).await?;
-            let ext = SourceDistExtension::from_path(path.as_path())
-                .map_err(|err| Error::InvalidSourceDistExt(path.user_display().to_string(), err))?;
-            let temp_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::SourceDistributions))?;
-            uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
-
-            // Extract the top-level directory from the archive.
-            let extracted = match uv_extract::strip_component(temp_dir.path()) {
-                Ok(top_level) => top_level,
-                Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
-                Err(err) => return Err(err.into()),
-            };
-
-            let wheel_build = build_wheel(
-                &extracted,
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "wheel from source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-                Some(sdist_build.normalized_filename().version()),
-            )
-            .await?;
-            build_results.push(wheel_build);
-        }
-        BuildPlan::Sdist => {
-            let sdist_build = build_sdist(
-                source.path(),
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-            )
-            .await?;
-            build_results.push(sdist_build);
         }
-        BuildPlan::Wheel => {
-            let wheel_build = build_wheel(
-                source.path(),
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "wheel",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-                None,
-            )
-            .await?;
-            build_results.push(wheel_build);
+
+        Ok::<_, Error>(build_results)
+    };
+
+    let mut build_results = build(&output_dir).await?;
+
+    // Build the distributions again into a temporary directory, and compare the artifacts.
+    if reproducible_check {
+        let temp_dir = tempfile::tempdir_in(&*output_dir)?;
+        let rebuild_results = build(temp_dir.path()).await?;
+        let verified = check_reproducible(&build_results, &rebuild_results)?;
+        build_results.extend(verified);
+    }
+
+    // Check the tags of the built wheels against the binaries they contain.
+    if check_tags {
+        let verified = check_wheel_tags(&build_results)?;
+        build_results.extend(verified);
+    }
+
+    Ok(build_results)
+}
+
+/// Check that the tags of the built wheels are justified by their binaries, returning a
+/// [`BuildMessage::CheckedTags`] for each wheel if all of them are.
+fn check_wheel_tags(builds: &[BuildMessage]) -> Result<Vec<BuildMessage>, Error> {
+    let mut verified = Vec::new();
+    let mut mismatches = Vec::new();
+    for build in builds {
+        let BuildMessage::Build {
+            normalized_filename: normalized_filename @ DistFilename::WheelFilename(_),
+            raw_filename,
+            output_dir,
+        } = build
+        else {
+            continue;
+        };
+
+        let wheel_mismatches = uv_build_backend::check_wheel_tags(&output_dir.join(raw_filename))?;
+        if wheel_mismatches.is_empty() {
+            verified.push(BuildMessage::CheckedTags {
+                normalized_filename: normalized_filename.clone(),
+                raw_filename: raw_filename.clone(),
+            });
+        } else {
+            mismatches.extend(
+                wheel_mismatches
+                    .iter()
+                    .map(|mismatch| format!("`{raw_filename}`: {mismatch}")),
+            );
         }
-        BuildPlan::SdistAndWheel => {
-            let sdist_build = build_sdist(
-                source.path(),
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-            )
-            .await?;
+    }
 
-            let wheel_build = build_wheel(
-                source.path(),
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "wheel",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-                Some(sdist_build.normalized_filename().version()),
-            )
-            .await?;
-            build_results.push(sdist_build);
-            build_results.push(wheel_build);
+    if mismatches.is_empty() {
+        Ok(verified)
+    } else {
+        Err(Error::OverbroadTags(mismatches))
+    }
+}
+
+/// Compare the distributions from two builds of the same source, returning a
+/// [`BuildMessage::Reproducible`] for each distribution if all of them are byte-identical.
+fn check_reproducible(
+    builds: &[BuildMessage],
+    rebuilds: &[BuildMessage],
+) -> Result<Vec<BuildMessage>, Error> {
+    let mut verified = Vec::new();
+    let mut mismatches = Vec::new();
+    for (build, rebuild) in builds.iter().zip(rebuilds) {
+        let (
+            BuildMessage::Build {
+                normalized_filename,
+                raw_filename,
+                output_dir,
+            },
+            BuildMessage::Build {
+                raw_filename: rebuild_raw_filename,
+                output_dir: rebuild_output_dir,
+                ..
+            },
+        ) = (build, rebuild)
+        else {
+            continue;
+        };
+
+        if raw_filename != rebuild_raw_filename {
+            mismatches.push(format!(
+                "`{raw_filename}` (rebuilt as `{rebuild_raw_filename}`)"
+            ));
+            continue;
         }
-        BuildPlan::WheelFromSdist => {
-            // Extract the source distribution into a temporary directory.
-            let reader = fs_err::tokio::File::open(source.path()).await?;
-            let ext = SourceDistExtension::from_path(source.path()).map_err(|err| {
-                Error::InvalidSourceDistExt(source.path().user_display().to_string(), err)
-            })?;
-            let temp_dir = tempfile::tempdir_in(&output_dir)?;
-            uv_extract::stream::archive(reader, ext, temp_dir.path()).await?;
-
-            // If the source distribution has a version in its filename, check the version.
-            let version = source
-                .path()
-                .file_name()
-                .and_then(|filename| filename.to_str())
-                .and_then(|filename| SourceDistFilename::parsed_normalized_filename(filename).ok())
-                .map(|filename| filename.version);
-
-            // Extract the top-level directory from the archive.
-            let extracted = match uv_extract::strip_component(temp_dir.path()) {
-                Ok(top_level) => top_level,
-                Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.path().to_path_buf(),
-                Err(err) => return Err(err.into()),
-            };
-
-            let wheel_build = build_wheel(
-                &extracted,
-                &output_dir,
-                build_action,
-                &source,
-                printer,
-                "wheel from source distribution",
-                &build_dispatch,
-                sources,
-                dist,
-                subdirectory,
-                version_id,
-                build_output,
-                version.as_ref(),
-            )
-            .await?;
-            build_results.push(wheel_build);
+
+        let digest = format!(
+            "{:x}",
+            Sha256::digest(fs_err::read(output_dir.join(raw_filename))?)
+        );
+        let rebuild_digest = format!(
+            "{:x}",
+            Sha256::digest(fs_err::read(rebuild_output_dir.join(raw_filename))?)
+        );
+        if digest == rebuild_digest {
+            debug!("`{raw_filename}` is reproducible: sha256:{digest}");
+            verified.push(BuildMessage::Reproducible {
+                normalized_filename: normalized_filename.clone(),
+                raw_filename: raw_filename.clone(),
+                digest,
+            });
+        } else {
+            mismatches.push(format!(
+                "`{raw_filename}` (sha256:{digest} and sha256:{rebuild_digest})"
+            ));
         }
     }
 
-    Ok(build_results)
+    if mismatches.is_empty() {
+        Ok(verified)
+    } else {
+        Err(Error::NotReproducible(mismatches))
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -1211,6 +1371,22 @@ enum BuildMessage {
         // Included file and source file, if not generated.
         file_list: Vec<(String, Option<PathBuf>)>,
     },
+    /// A distribution that was byte-identical across two builds.
+    Reproducible {
+        /// The normalized name of the built distribution.
+        normalized_filename: DistFilename,
+        /// The name of the built distribution before parsing and normalization.
+        raw_filename: String,
+        /// The SHA-256 digest of the built distribution.
+        digest: String,
+    },
+    /// A wheel whose tags are justified by the binaries it contains.
+    CheckedTags {
+        /// The normalized name of the built wheel.
+        normalized_filename: DistFilename,
+        /// The name of the built wheel before parsing and normalization.
+        raw_filename: String,
+    },
 }
 
 impl BuildMessage {
@@ -1225,6 +1401,14 @@ impl BuildMessage {
                 normalized_filename: name,
                 ..
             } => name,
+            Self::Reproducible {
+                normalized_filename: name,
+                ..
+            } => name,
+            Self::CheckedTags {
+                normalized_filename: name,
+                ..
+            } => name,
         }
     }
 
@@ -1237,6 +1421,12 @@ impl BuildMessage {
             Self::List {
                 raw_filename: name, ..
             } => name,
+            Self::Reproducible {
+                raw_filename: name, ..
+            } => name,
+            Self::CheckedTags {
+                raw_filename: name, ..
+            } => name,
         }
     }
 
@@ -1278,6 +1468,25 @@ impl BuildMessage {
                     }
                 }
             }
+            Self::Reproducible {
+                raw_filename,
+                digest,
+                ..
+            } => {
+                writeln!(
+                    printer.stderr(),
+                    "Verified that {} is reproducible ({})",
+                    raw_filename.bold().cyan(),
+                    format!("sha256:{digest}").dimmed()
+                )?;
+            }
+            Self::CheckedTags { raw_filename, .. } => {
+                writeln!(
+                    printer.stderr(),
+                    "Verified that the tags of {} match its binaries",
+                    raw_filename.bold().cyan()
+                )?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,133 @@
+use std::fmt::Write;
+use std::io::{Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use sha2::{Digest, Sha256};
+
+use uv_cache::Cache;
+use uv_distribution_types::{InstalledDistKind, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_platform_tags::TagCompatibility;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::bundle::{BundledPackage, FORMAT_VERSION, MANIFEST, Manifest, WHEELS};
+use crate::commands::index::serve::{CachedWheel, archive_wheel, find_wheels};
+use crate::printer::Printer;
+
+/// Bundle the packages installed in an environment into a single archive.
+pub(crate) async fn create(
+    output: &Path,
+    python: Option<&str>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::BUNDLE) {
+        warn_user!(
+            "The `uv bundle create` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::BUNDLE
+        );
+    }
+
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::OnlyVirtual,
+        PythonPreference::default(),
+        cache,
+        preview,
+    )?;
+    let tags = environment.interpreter().tags()?;
+
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let mut installed = site_packages.iter().collect::<Vec<_>>();
+    installed.sort_unstable_by(|a, b| a.name().cmp(b.name()));
+
+    // Locate a cached wheel for every installed package.
+    let mut wheels = Vec::with_capacity(installed.len());
+    for dist in installed {
+        if !matches!(dist.kind, InstalledDistKind::Registry(_)) {
+            bail!(
+                "Package `{}` was not installed from a registry and cannot be bundled",
+                dist.name()
+            );
+        }
+
+        let (filename, wheel) = find_wheels(cache, dist.name())?
+            .into_iter()
+            .filter(|(filename, _)| filename.version == *dist.version())
+            .filter_map(|(filename, wheel)| match filename.compatibility(tags) {
+                TagCompatibility::Compatible(priority) => Some((priority, filename, wheel)),
+                TagCompatibility::Incompatible(_) => None,
+            })
+            .max_by_key(|(priority, ..)| *priority)
+            .map(|(_, filename, wheel)| (filename, wheel))
+            .with_context(|| {
+                format!(
+                    "No compatible wheel for `{}=={}` found in the cache",
+                    dist.name(),
+                    dist.version()
+                )
+            })?;
+        wheels.push((dist, filename, wheel));
+    }
+
+    let parent = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+    let encoder = zstd::Encoder::new(temp_file, 0)?;
+    let mut builder = tar::Builder::new(encoder);
+
+    let mut packages = Vec::with_capacity(wheels.len());
+    for (dist, filename, wheel) in wheels {
+        let mut file = match wheel {
+            CachedWheel::File(path) => fs_err::File::open(path)?.into_parts().0,
+            CachedWheel::Archive(path) => archive_wheel(&path)?,
+        };
+
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut file, &mut hasher)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        builder.append_file(format!("{WHEELS}/{filename}"), &mut file)?;
+        packages.push(BundledPackage {
+            name: dist.name().clone(),
+            version: dist.version().clone(),
+            filename,
+            sha256: format!("{:x}", hasher.finalize()),
+        });
+    }
+
+    let count = packages.len();
+    let manifest = serde_json::to_vec_pretty(&Manifest {
+        version: FORMAT_VERSION,
+        python: environment.interpreter().python_version().clone(),
+        packages,
+    })?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest.len() as u64);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, MANIFEST, manifest.as_slice())?;
+
+    let temp_file = builder.into_inner()?.finish()?;
+    temp_file
+        .persist(output)
+        .with_context(|| format!("Failed to write bundle to `{}`", output.user_display()))?;
+
+    let s = if count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Bundled {} into `{}`",
+        format!("{count} package{s}").bold(),
+        output.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,155 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use sha2::{Digest, Sha256};
+
+use uv_cache::Cache;
+use uv_cache_info::CacheInfo;
+use uv_distribution_types::{CachedDist, CachedRegistryDist};
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_installer::{Installer, SitePackages};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::bundle::{FORMAT_VERSION, MANIFEST, Manifest, WHEELS};
+use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
+use crate::commands::pip::operations::Changelog;
+use crate::printer::Printer;
+
+/// Install the packages in a bundle into an environment, without accessing a package index.
+pub(crate) async fn install(
+    bundle: &Path,
+    python: Option<&str>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::BUNDLE) {
+        warn_user!(
+            "The `uv bundle install` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::BUNDLE
+        );
+    }
+
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::OnlyVirtual,
+        PythonPreference::default(),
+        cache,
+        preview,
+    )?;
+    let tags = environment.interpreter().tags()?;
+    let _lock = environment.lock().await?;
+
+    // Unpack the bundle into the cache, so that the wheels can be linked into the environment.
+    let temp_dir = tempfile::tempdir_in(cache.root())?;
+    let reader = fs_err::File::open(bundle)?;
+    uv_extract::stream::untar_zst_file(reader, temp_dir.path())
+        .with_context(|| format!("Failed to unpack bundle `{}`", bundle.user_display()))?;
+
+    let manifest: Manifest = serde_json::from_slice(
+        &fs_err::read(temp_dir.path().join(MANIFEST))
+            .with_context(|| format!("Missing `{MANIFEST}` in bundle"))?,
+    )?;
+    if manifest.version != FORMAT_VERSION {
+        bail!(
+            "Unsupported bundle format version: {} (expected {FORMAT_VERSION})",
+            manifest.version
+        );
+    }
+
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    let mut wheels = Vec::with_capacity(manifest.packages.len());
+    let mut uninstalls = Vec::new();
+    for package in manifest.packages {
+        let installed = site_packages.get_packages(&package.name);
+        if installed
+            .iter()
+            .any(|dist| *dist.version() == package.version)
+        {
+            continue;
+        }
+
+        if !package.filename.is_compatible(tags) {
+            bail!(
+                "The wheel `{}` in the bundle is not compatible with the target environment (the bundle was created with Python {})",
+                package.filename,
+                manifest.python
+            );
+        }
+
+        let wheel = temp_dir
+            .path()
+            .join(WHEELS)
+            .join(package.filename.to_string());
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut fs_err::File::open(&wheel)?, &mut hasher)?;
+        let digest = format!("{:x}", hasher.finalize());
+        if digest != package.sha256 {
+            bail!(
+                "Hash mismatch for `{}` in bundle:\n  Expected: {}\n  Computed: {digest}",
+                package.filename,
+                package.sha256
+            );
+        }
+
+        let target = temp_dir.path().join(package.filename.stem());
+        uv_extract::unzip(fs_err::File::open(&wheel)?, &target)?;
+
+        uninstalls.extend(installed.into_iter().cloned());
+        wheels.push(CachedDist::Registry(CachedRegistryDist {
+            filename: package.filename,
+            path: target.into_boxed_path(),
+            hashes: HashDigest {
+                algorithm: HashAlgorithm::Sha256,
+                digest: digest.into(),
+            }
+            .into(),
+            cache_info: CacheInfo::default(),
+            build_info: None,
+        }));
+    }
+
+    let logger = DefaultInstallLogger;
+
+    if wheels.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "All packages in the bundle are installed in: {}",
+                environment.root().user_display()
+            )
+            .dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if !uninstalls.is_empty() {
+        let start = std::time::Instant::now();
+        for dist in &uninstalls {
+            uv_installer::uninstall(dist).await?;
+        }
+        logger.on_uninstall(uninstalls.len(), start, printer)?;
+    }
+
+    let start = std::time::Instant::now();
+    let installed = Installer::new(&environment, preview)
+        .with_link_mode(LinkMode::default())
+        .with_cache(cache)
+        .with_installer_name(Some("uv".into()))
+        .install(wheels)
+        .await?;
+    logger.on_install(installed.len(), start, printer)?;
+
+    logger.on_complete(&Changelog::new(installed, uninstalls), printer)?;
+
+    Ok(ExitStatus::Success)
+}
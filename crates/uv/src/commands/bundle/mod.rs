@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use uv_distribution_filename::WheelFilename;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+pub(crate) mod create;
+pub(crate) mod install;
+
+/// The name of the manifest file at the root of a bundle.
+const MANIFEST: &str = "uv-bundle.json";
+
+/// The directory in a bundle that holds the wheels.
+const WHEELS: &str = "wheels";
+
+/// The version of the bundle format.
+const FORMAT_VERSION: u32 = 1;
+
+/// The manifest of a bundle, listing the wheels it contains.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    /// The version of the bundle format.
+    version: u32,
+    /// The Python version of the environment the bundle was created from.
+    python: Version,
+    /// The packages in the bundle.
+    packages: Vec<BundledPackage>,
+}
+
+/// A package in a bundle.
+#[derive(Debug, Serialize, Deserialize)]
+struct BundledPackage {
+    name: PackageName,
+    version: Version,
+    filename: WheelFilename,
+    /// The SHA-256 digest of the wheel.
+    sha256: String,
+}
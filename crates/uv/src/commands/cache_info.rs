@@ -0,0 +1,226 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_cli::CacheInfoFormat;
+use uv_distribution_types::IndexLocations;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// The buckets that store entries per index, and then per package.
+const INDEXED_BUCKETS: [CacheBucket; 3] = [
+    CacheBucket::Wheels,
+    CacheBucket::SourceDistributions,
+    CacheBucket::Simple,
+];
+
+#[derive(Debug, Default, Serialize)]
+struct CacheInfo {
+    root: String,
+    total: u64,
+    buckets: Vec<Usage>,
+    indexes: Vec<Usage>,
+    packages: Vec<Usage>,
+}
+
+#[derive(Debug, Serialize)]
+struct Usage {
+    name: String,
+    bytes: u64,
+}
+
+/// Display a breakdown of the cache's disk usage by bucket, index, and package.
+pub(crate) fn cache_info(
+    cache: &Cache,
+    index_locations: &IndexLocations,
+    output_format: CacheInfoFormat,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::CACHE_SIZE) {
+        warn_user!(
+            "`uv cache info` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::CACHE_SIZE
+        );
+    }
+
+    let mut info = CacheInfo {
+        root: cache.root().simplified_display().to_string(),
+        ..CacheInfo::default()
+    };
+
+    if cache.root().exists() {
+        info.total = disk_usage(cache.root());
+
+        // Map each index directory to the URL of a known index, where possible.
+        let labels = index_locations
+            .allowed_indexes()
+            .into_iter()
+            .map(|index| (WheelCache::Index(&index.url).root(), index.url.to_string()))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut indexes = FxHashMap::<String, u64>::default();
+        let mut packages = FxHashMap::<PackageName, u64>::default();
+
+        for bucket in CacheBucket::iter() {
+            let root = cache.bucket(bucket);
+            let bytes = disk_usage(&root);
+            if bytes == 0 {
+                continue;
+            }
+            info.buckets.push(Usage {
+                name: bucket.to_string(),
+                bytes,
+            });
+
+            if INDEXED_BUCKETS.contains(&bucket) {
+                for (relative, directory) in index_directories(&root)? {
+                    let label = labels
+                        .get(&relative)
+                        .cloned()
+                        .unwrap_or_else(|| relative.portable_display().to_string());
+                    for entry in fs_err::read_dir(&directory)? {
+                        let entry = entry?;
+                        let bytes = disk_usage(&entry.path());
+                        *indexes.entry(label.clone()).or_default() += bytes;
+
+                        // Entries are stored as a directory per package, or as a `.rkyv` file per
+                        // package in the `simple` bucket.
+                        let file_name = entry.file_name();
+                        let Some(file_name) = file_name.to_str() else {
+                            continue;
+                        };
+                        let name = file_name.strip_suffix(".rkyv").unwrap_or(file_name);
+                        if let Ok(name) = PackageName::from_str(name) {
+                            *packages.entry(name).or_default() += bytes;
+                        }
+                    }
+                }
+            } else if bucket == CacheBucket::Archive {
+                // Unzipped wheels are content-addressed, so attribute them to a package using the
+                // name of the `.dist-info` directory.
+                for entry in fs_err::read_dir(&root)? {
+                    let path = entry?.path();
+                    if let Some(name) = archive_package(&path) {
+                        *packages.entry(name).or_default() += disk_usage(&path);
+                    }
+                }
+            }
+        }
+
+        info.indexes = indexes
+            .into_iter()
+            .map(|(name, bytes)| Usage { name, bytes })
+            .collect();
+        info.packages = packages
+            .into_iter()
+            .map(|(name, bytes)| Usage {
+                name: name.to_string(),
+                bytes,
+            })
+            .collect();
+
+        for usages in [&mut info.buckets, &mut info.indexes, &mut info.packages] {
+            usages.sort_unstable_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+        }
+    }
+
+    match output_format {
+        CacheInfoFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string(&info)?
+            )?;
+        }
+        CacheInfoFormat::Text => {
+            let (bytes, unit) = human_readable_bytes(info.total);
+            writeln!(
+                printer.stdout_important(),
+                "{}: {bytes:.1}{unit}",
+                info.root.cyan()
+            )?;
+            for (heading, usages) in [
+                ("Buckets", &info.buckets),
+                ("Indexes", &info.indexes),
+                ("Packages", &info.packages),
+            ] {
+                if usages.is_empty() {
+                    continue;
+                }
+                writeln!(printer.stdout_important())?;
+                writeln!(printer.stdout_important(), "{}:", heading.bold())?;
+                for usage in usages {
+                    let (bytes, unit) = human_readable_bytes(usage.bytes);
+                    writeln!(
+                        printer.stdout_important(),
+                        "{:>10}  {}",
+                        format!("{bytes:.1}{unit}"),
+                        usage.name
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the total size of the files in the given directory (or of the given file).
+fn disk_usage(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_file() => Some(metadata.len()),
+            _ => None,
+        })
+        .sum()
+}
+
+/// Return the index directories in a bucket, as `(relative path, absolute path)` pairs.
+///
+/// PyPI is stored in a `pypi` directory, while other indexes are stored in an `index` directory,
+/// keyed by the digest of the index URL.
+fn index_directories(bucket: &Path) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let mut directories = Vec::new();
+
+    let pypi = bucket.join("pypi");
+    if pypi.is_dir() {
+        directories.push((PathBuf::from("pypi"), pypi));
+    }
+
+    let index = bucket.join("index");
+    if index.is_dir() {
+        for entry in fs_err::read_dir(&index)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                directories.push((Path::new("index").join(entry.file_name()), entry.path()));
+            }
+        }
+    }
+
+    Ok(directories)
+}
+
+/// Return the name of the package in an unzipped wheel, based on its `.dist-info` directory.
+fn archive_package(path: &Path) -> Option<PackageName> {
+    fs_err::read_dir(path).ok()?.find_map(|entry| {
+        let file_name = entry.ok()?.file_name();
+        let stem = file_name.to_str()?.strip_suffix(".dist-info")?;
+        let (name, _version) = stem.split_once('-')?;
+        PackageName::from_str(name).ok()
+    })
+}
@@ -1,19 +1,37 @@
 use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::debug;
 
 use uv_cache::{Cache, Removal};
+use uv_distribution_types::{InstalledDist, Name};
 use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_tool::InstalledTools;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(
+///
+/// If `tools` is set, only the `uv tool run` environments that have outlived the given
+/// time-to-live are removed. If `referenced_only` is set, any cached wheels that aren't installed
+/// in the project environment or a tool environment are removed, too.
+pub(crate) async fn cache_prune(
     ci: bool,
+    tools: bool,
+    referenced_only: bool,
+    tool_run_cache_ttl: Option<Duration>,
     force: bool,
+    project_dir: &Path,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -49,14 +67,34 @@ pub(crate) fn cache_prune(
 
     let mut summary = Removal::default();
 
-    // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(&cache)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+    if tools {
+        // Prune the expired `uv tool run` environments.
+        summary += cache
+            .prune_tool_environments(tool_run_cache_ttl.filter(|ttl| !ttl.is_zero()))
+            .with_context(|| {
+                format!("Failed to prune cache at: {}", cache.root().user_display())
+            })?;
+    } else {
+        if referenced_only {
+            // Remove any unzipped wheels that aren't installed in a known environment.
+            let referenced = referenced_distributions(project_dir, &cache).await?;
+            summary += cache
+                .prune_archives(|archive| is_referenced(archive, &referenced))
+                .with_context(|| {
+                    format!("Failed to prune cache at: {}", cache.root().user_display())
+                })?;
+        }
+
+        // Prune the source distribution cache, which is tightly coupled to the builder crate.
+        summary += uv_distribution::prune(&cache).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
 
-    // Prune the remaining cache buckets.
-    summary += cache
-        .prune(ci)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+        // Prune the remaining cache buckets.
+        summary += cache.prune(ci).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
 
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
@@ -92,3 +130,89 @@ pub(crate) fn cache_prune(
 
     Ok(ExitStatus::Success)
 }
+
+/// Collect the distributions installed in the project environment and any tool environments.
+async fn referenced_distributions(
+    project_dir: &Path,
+    cache: &Cache,
+) -> Result<FxHashSet<(PackageName, Version)>> {
+    let mut environments = Vec::new();
+
+    // Find the project environment, if there is one.
+    match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => {
+            let root = project.workspace().venv(None);
+            match PythonEnvironment::from_root(&root, cache) {
+                Ok(environment) => environments.push(environment),
+                Err(uv_python::Error::MissingEnvironment(_)) => {}
+                Err(err) => {
+                    return Err(err).with_context(|| {
+                        format!(
+                            "Failed to read project environment at: {}",
+                            root.user_display()
+                        )
+                    });
+                }
+            }
+        }
+        Err(err) => {
+            debug!("Failed to discover project: {err}");
+        }
+    }
+
+    // Find the tool environments.
+    let installed_tools = InstalledTools::from_settings()?;
+    let lock = match installed_tools.lock().await {
+        Ok(lock) => Some(lock),
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    if lock.is_some() {
+        for (name, _) in installed_tools.tools()? {
+            if let Some(environment) = installed_tools.get_environment(&name, cache)? {
+                environments.push(environment.into_environment());
+            }
+        }
+    }
+
+    let mut referenced = FxHashSet::default();
+    for environment in &environments {
+        debug!(
+            "Retaining cached wheels installed in: {}",
+            environment.root().user_display()
+        );
+        let site_packages = SitePackages::from_environment(environment)?;
+        for dist in site_packages.iter() {
+            referenced.insert((dist.name().clone(), dist.version().clone()));
+        }
+    }
+
+    Ok(referenced)
+}
+
+/// Returns `true` if the archive is not an unzipped wheel, or is an unzipped wheel for one of the
+/// `referenced` distributions.
+fn is_referenced(archive: &Path, referenced: &FxHashSet<(PackageName, Version)>) -> bool {
+    let Ok(entries) = fs_err::read_dir(archive) else {
+        return true;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.extension().is_some_and(|ext| ext == "dist-info") {
+            continue;
+        }
+        return match InstalledDist::try_from_path(&path) {
+            Ok(Some(dist)) => referenced.contains(&(dist.name().clone(), dist.version().clone())),
+            _ => true,
+        };
+    }
+
+    // Retain anything other than an unzipped wheel, like archived `uv tool run` environments.
+    true
+}
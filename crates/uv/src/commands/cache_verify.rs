@@ -0,0 +1,112 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Scan the cache for incomplete writes and repair them.
+pub(crate) fn cache_verify(force: bool, cache: Cache, printer: Printer) -> Result<ExitStatus> {
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) if force => {
+            debug!("Cache is currently in use, proceeding due to `--force`");
+            cache
+        }
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish (use `--force` to override)"
+            )?;
+            cache.with_exclusive_lock()?
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let verification = cache
+        .verify()
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+
+    if verification.num_incomplete == 0
+        && verification.num_corrupt == 0
+        && verification.num_dangling == 0
+    {
+        writeln!(
+            printer.stderr(),
+            "No issues found ({})",
+            pluralize(
+                verification.num_archives,
+                "entry verified",
+                "entries verified"
+            )
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if verification.num_incomplete > 0 {
+        writeln!(
+            printer.stderr(),
+            "Rolled back {}",
+            pluralize(
+                verification.num_incomplete,
+                "incomplete write",
+                "incomplete writes"
+            )
+        )?;
+    }
+    if verification.num_corrupt > 0 {
+        writeln!(
+            printer.stderr(),
+            "Removed {}",
+            pluralize(verification.num_corrupt, "corrupt entry", "corrupt entries")
+        )?;
+    }
+    if verification.num_dangling > 0 {
+        writeln!(
+            printer.stderr(),
+            "Removed {}",
+            pluralize(verification.num_dangling, "dangling link", "dangling links")
+        )?;
+    }
+
+    // If any, write a summary of the total byte count removed.
+    let total_bytes = verification.removal.total_bytes;
+    if total_bytes > 0 {
+        let bytes = if total_bytes < 1024 {
+            format!("{total_bytes}B")
+        } else {
+            let (bytes, unit) = human_readable_bytes(total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        writeln!(printer.stderr(), "Freed {}", bytes.green())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn pluralize(count: u64, singular: &str, plural: &str) -> String {
+    if count == 1 {
+        format!("{count} {singular}")
+    } else {
+        format!("{count} {plural}")
+    }
+}
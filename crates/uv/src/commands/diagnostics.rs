@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, LazyLock};
 
 use owo_colors::OwoColorize;
@@ -28,6 +29,14 @@ static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::ne
         .collect()
 });
 
+/// Whether to render diagnostics as JSON, i.e., with `--error-format json`.
+static JSON_ERRORS: AtomicBool = AtomicBool::new(false);
+
+/// Render diagnostics as JSON, rather than human-readable reports.
+pub(crate) fn enable_json_errors() {
+    JSON_ERRORS.store(true, Ordering::Relaxed);
+}
+
 /// A rich reporter for operational diagnostics, i.e., errors that occur during resolution and
 /// installation.
 #[derive(Debug, Default)]
@@ -74,7 +83,9 @@ impl OperationDiagnostic {
     pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
-                if let Some(context) = self.context {
+                if JSON_ERRORS.load(Ordering::Relaxed) {
+                    no_solution_json(&err, self.context, self.hint.as_deref());
+                } else if let Some(context) = self.context {
                     no_solution_context(&err, context);
                 } else if let Some(hint) = self.hint {
                     no_solution_hint(err, hint);
@@ -295,6 +306,41 @@ pub(crate) fn no_solution(err: &uv_resolver::NoSolutionError) {
     anstream::eprint!("{report:?}");
 }
 
+/// Render a [`uv_resolver::NoSolutionError`] as a JSON object, including the chain of sources
+/// that introduced each of the conflicting requirements.
+fn no_solution_json(
+    err: &uv_resolver::NoSolutionError,
+    context: Option<&'static str>,
+    hint: Option<&str>,
+) {
+    #[derive(serde::Serialize)]
+    struct Diagnostic<'a> {
+        kind: &'static str,
+        message: String,
+        report: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+        provenance: &'a [uv_resolver::RequirementProvenance],
+    }
+
+    let header = if let Some(context) = context {
+        err.header().with_context(context)
+    } else {
+        err.header()
+    };
+    let diagnostic = Diagnostic {
+        kind: "no-solution",
+        message: header.to_string(),
+        report: anstream::adapter::strip_str(&err.to_string()).to_string(),
+        hint: hint.map(|hint| anstream::adapter::strip_str(hint).to_string()),
+        provenance: err.provenance(),
+    };
+    match serde_json::to_string(&diagnostic) {
+        Ok(json) => anstream::eprintln!("{json}"),
+        Err(_) => no_solution(err),
+    }
+}
+
 /// Render a [`uv_resolver::NoSolutionError`] with dedicated context.
 pub(crate) fn no_solution_context(err: &uv_resolver::NoSolutionError, context: &'static str) {
     let report = miette::Report::msg(format!("{err}")).context(err.header().with_context(context));
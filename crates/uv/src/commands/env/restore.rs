@@ -0,0 +1,144 @@
+use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_distribution_types::{InstalledMetadata, Name};
+use uv_fs::Simplified;
+use uv_installer::Snapshot;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Restore a virtual environment from a snapshot.
+pub(crate) async fn restore(
+    python: Option<&str>,
+    steps: NonZeroUsize,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::ENV_SNAPSHOTS) {
+        warn_user!(
+            "The `uv env restore` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::ENV_SNAPSHOTS
+        );
+    }
+
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::OnlyVirtual,
+        PythonPreference::default(),
+        cache,
+        preview,
+    )?;
+
+    restore_snapshot(&environment, steps, printer).await
+}
+
+/// Restore the project environment from the most recent snapshot, as in `uv sync --rollback`.
+pub(crate) async fn rollback(
+    project_dir: &Path,
+    active: Option<bool>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::ENV_SNAPSHOTS) {
+        warn_user!(
+            "`uv sync --rollback` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::ENV_SNAPSHOTS
+        );
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Find the project environment, without creating it.
+    let root = project.workspace().venv(active);
+    let environment = PythonEnvironment::from_root(&root, cache).with_context(|| {
+        format!(
+            "No project environment found at `{}`",
+            root.user_display().cyan()
+        )
+    })?;
+
+    restore_snapshot(&environment, NonZeroUsize::MIN, printer).await
+}
+
+/// Restore the environment from the snapshot taken `steps` operations ago.
+async fn restore_snapshot(
+    environment: &PythonEnvironment,
+    steps: NonZeroUsize,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let _lock = environment.lock().await?;
+
+    let mut snapshots = Snapshot::list(environment)?;
+    if snapshots.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No snapshots found for environment at: {}",
+            environment.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+    if steps.get() > snapshots.len() {
+        writeln!(
+            printer.stderr(),
+            "Cannot revert {steps} operations; only {} snapshots found for environment at: {}",
+            snapshots.len(),
+            environment.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let snapshot = snapshots.swap_remove(snapshots.len() - steps.get());
+    let restore = snapshot.restore(environment).await?;
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Restored environment at: {}",
+            environment.root().user_display()
+        )
+        .dimmed()
+    )?;
+
+    let mut changes = restore
+        .removed
+        .iter()
+        .map(|dist| (dist, false))
+        .chain(restore.restored.iter().map(|dist| (dist, true)))
+        .collect::<Vec<_>>();
+    changes.sort_unstable_by(|(a, a_restored), (b, b_restored)| {
+        a.name()
+            .cmp(b.name())
+            .then_with(|| a_restored.cmp(b_restored))
+    });
+    for (dist, restored) in changes {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            if restored {
+                "+".green().to_string()
+            } else {
+                "-".red().to_string()
+            },
+            dist.name().bold(),
+            dist.installed_version().dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,63 @@
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder};
+use uv_distribution_types::IndexLocations;
+use uv_normalize::PackageName;
+
+pub(crate) mod serve;
+pub(crate) mod sync;
+
+/// The minimum similarity for a project to be suggested in place of a missing package.
+const SIMILARITY_THRESHOLD: f64 = 0.9;
+
+/// Suggest a correction for each package that isn't available on any of the configured indexes,
+/// based on the project lists stored by `uv index sync`.
+///
+/// Suggestions are only returned if a project list is stored for every index, since the package
+/// may otherwise be available on an index that hasn't been synced.
+pub(crate) async fn suggest_projects<'a>(
+    names: impl IntoIterator<Item = &'a PackageName>,
+    index_locations: &IndexLocations,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+) -> Vec<(&'a PackageName, PackageName)> {
+    let client = RegistryClientBuilder::new(
+        client_builder.clone().connectivity(Connectivity::Offline),
+        cache.clone(),
+    )
+    .index_locations(index_locations.clone())
+    .build();
+
+    let mut projects = FxHashSet::default();
+    for index in index_locations.indexes() {
+        match client.fetch_simple_index(index.url()).await {
+            Ok(index) => projects.extend(index.iter().cloned()),
+            Err(err) => {
+                debug!("No stored project list for `{}`: {err}", index.url());
+                return Vec::new();
+            }
+        }
+    }
+    if projects.is_empty() {
+        return Vec::new();
+    }
+
+    names
+        .into_iter()
+        .filter(|name| !projects.contains(*name))
+        .filter_map(|name| {
+            let (similarity, suggestion) = projects
+                .iter()
+                .map(|project| {
+                    (
+                        strsim::jaro_winkler(name.as_str(), project.as_str()),
+                        project,
+                    )
+                })
+                .max_by(|(a, _), (b, _)| a.total_cmp(b))?;
+            (similarity >= SIMILARITY_THRESHOLD).then(|| (name, suggestion.clone()))
+        })
+        .collect()
+}
@@ -0,0 +1,420 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::io::{Seek, SeekFrom};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use futures::TryStreamExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full, StreamBody};
+use hyper::body::{Bytes, Frame, Incoming};
+use hyper::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use owo_colors::OwoColorize;
+use tokio::net::TcpListener;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, warn};
+use walkdir::WalkDir;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_distribution::{HttpArchivePointer, LocalArchivePointer};
+use uv_distribution_filename::WheelFilename;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The media type for PEP 691 JSON responses.
+const SIMPLE_JSON: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The media type for PEP 503 HTML responses.
+const SIMPLE_HTML: &str = "application/vnd.pypi.simple.v1+html";
+
+type Body = UnsyncBoxBody<Bytes, std::io::Error>;
+
+/// Serve the wheels in the cache as a package index over HTTP.
+pub(crate) async fn serve(
+    host: std::net::IpAddr,
+    port: u16,
+    cache: Cache,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::INDEX_SERVE) {
+        warn_user!(
+            "The `uv index serve` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::INDEX_SERVE
+        );
+    }
+
+    let listener = TcpListener::bind(SocketAddr::new(host, port))
+        .await
+        .with_context(|| format!("Failed to listen on `{}`", SocketAddr::new(host, port)))?;
+    let addr = listener.local_addr()?;
+
+    writeln!(
+        printer.stderr(),
+        "Serving the cache at {}",
+        format!("http://{addr}/simple").cyan()
+    )?;
+
+    let cache = Arc::new(cache);
+    loop {
+        let (stream, remote) = listener.accept().await?;
+        let cache = cache.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let cache = cache.clone();
+                async move { Ok::<_, Infallible>(handle(&cache, request).await) }
+            });
+            if let Err(err) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!("Failed to serve connection from {remote}: {err}");
+            }
+        });
+    }
+}
+
+/// Respond to a single request against the index.
+async fn handle(cache: &Cache, request: Request<Incoming>) -> Response<Body> {
+    if !matches!(*request.method(), Method::GET | Method::HEAD) {
+        return status(StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    let json = request
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains(SIMPLE_JSON));
+
+    let path = request.uri().path().to_string();
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    let response = match segments.as_slice() {
+        ["simple"] => project_list(cache, json),
+        ["simple", name] => {
+            // Redirect to the normalized name, per PEP 503.
+            match PackageName::from_str(name) {
+                Ok(normalized) if normalized.as_str() != *name || !path.ends_with('/') => {
+                    Ok(redirect(&format!("/simple/{normalized}/")))
+                }
+                Ok(name) => project_page(cache, &name, json),
+                Err(_) => Ok(status(StatusCode::NOT_FOUND)),
+            }
+        }
+        ["files", name, filename] => match PackageName::from_str(name) {
+            Ok(name) => file(cache, &name, filename).await,
+            Err(_) => Ok(status(StatusCode::NOT_FOUND)),
+        },
+        _ => Ok(status(StatusCode::NOT_FOUND)),
+    };
+
+    let response = response.unwrap_or_else(|err| {
+        warn!("Failed to serve `{path}`: {err}");
+        status(StatusCode::INTERNAL_SERVER_ERROR)
+    });
+    debug!("{} {path} -> {}", request.method(), response.status());
+
+    if *request.method() == Method::HEAD {
+        let (parts, _) = response.into_parts();
+        return Response::from_parts(parts, empty());
+    }
+    response
+}
+
+/// Render the list of projects with at least one wheel in the cache.
+fn project_list(cache: &Cache, json: bool) -> Result<Response<Body>> {
+    let projects = find_projects(cache)?;
+
+    if json {
+        let body = serde_json::json!({
+            "meta": { "api-version": "1.0" },
+            "projects": projects
+                .iter()
+                .map(|name| serde_json::json!({ "name": name }))
+                .collect::<Vec<_>>(),
+        });
+        return Ok(content(SIMPLE_JSON, body.to_string()));
+    }
+
+    let mut html = String::from("<!DOCTYPE html>\n<html>\n<body>\n");
+    for name in &projects {
+        writeln!(html, "<a href=\"/simple/{name}/\">{name}</a><br/>")?;
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(content(SIMPLE_HTML, html))
+}
+
+/// Render the files available for a single project.
+fn project_page(cache: &Cache, name: &PackageName, json: bool) -> Result<Response<Body>> {
+    let wheels = find_wheels(cache, name)?;
+    if wheels.is_empty() {
+        return Ok(status(StatusCode::NOT_FOUND));
+    }
+
+    if json {
+        let body = serde_json::json!({
+            "meta": { "api-version": "1.0" },
+            "name": name,
+            "files": wheels
+                .keys()
+                .map(|filename| serde_json::json!({
+                    "filename": filename.to_string(),
+                    "url": format!("/files/{name}/{filename}"),
+                    "hashes": {},
+                }))
+                .collect::<Vec<_>>(),
+        });
+        return Ok(content(SIMPLE_JSON, body.to_string()));
+    }
+
+    let mut html = format!("<!DOCTYPE html>\n<html>\n<body>\n<h1>Links for {name}</h1>\n");
+    for filename in wheels.keys() {
+        writeln!(
+            html,
+            "<a href=\"/files/{name}/{filename}\">{filename}</a><br/>"
+        )?;
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(content(SIMPLE_HTML, html))
+}
+
+/// Serve a single wheel from the cache.
+async fn file(cache: &Cache, name: &PackageName, filename: &str) -> Result<Response<Body>> {
+    let Ok(filename) = WheelFilename::from_str(filename) else {
+        return Ok(status(StatusCode::NOT_FOUND));
+    };
+    let Some(wheel) = find_wheels(cache, name)?.remove(&filename) else {
+        return Ok(status(StatusCode::NOT_FOUND));
+    };
+
+    let file = match wheel {
+        CachedWheel::File(path) => fs_err::File::open(path)?.into_parts().0,
+        CachedWheel::Archive(path) => {
+            tokio::task::spawn_blocking(move || archive_wheel(&path)).await??
+        }
+    };
+    let length = file.metadata()?.len();
+
+    let stream = ReaderStream::new(tokio::fs::File::from_std(file)).map_ok(Frame::data);
+    let body = StreamBody::new(stream).boxed_unsync();
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(CONTENT_LENGTH, length)
+        .body(body)?)
+}
+
+/// A wheel in the cache.
+#[derive(Debug)]
+pub(crate) enum CachedWheel {
+    /// A wheel file, as built from a source distribution.
+    File(PathBuf),
+    /// An unzipped wheel, which must be re-archived before it's served.
+    Archive(PathBuf),
+}
+
+/// Return the names of all projects with a wheel in the cache, in sorted order.
+fn find_projects(cache: &Cache) -> Result<Vec<PackageName>> {
+    let mut projects = BTreeSet::new();
+    for (bucket, depth) in [
+        (CacheBucket::Wheels, 0),
+        (CacheBucket::SourceDistributions, 2),
+    ] {
+        for root in index_roots(cache, bucket)? {
+            for entry in read_dir(&root)? {
+                let Ok(name) = PackageName::from_str(&entry.file_name().to_string_lossy()) else {
+                    continue;
+                };
+                if projects.contains(&name) {
+                    continue;
+                }
+                let dir = entry.path();
+                let has_wheel = WalkDir::new(&dir)
+                    .min_depth(depth + 1)
+                    .max_depth(depth + 1)
+                    .into_iter()
+                    .filter_map(Result::ok)
+                    .any(|entry| is_wheel_entry(bucket, entry.path()));
+                if has_wheel {
+                    projects.insert(name);
+                }
+            }
+        }
+    }
+    Ok(projects.into_iter().collect())
+}
+
+/// Return the wheels for the given project, keyed by filename.
+///
+/// If the same wheel is available from multiple indexes, or as both a built and a downloaded wheel,
+/// the wheel file is preferred, since it can be served as-is.
+pub(crate) fn find_wheels(
+    cache: &Cache,
+    name: &PackageName,
+) -> Result<BTreeMap<WheelFilename, CachedWheel>> {
+    let mut wheels = BTreeMap::new();
+
+    // Wheels built from source distributions, stored as
+    // `<index>/<name>/<version>/<revision>/<filename>.whl`.
+    for root in index_roots(cache, CacheBucket::SourceDistributions)? {
+        for entry in WalkDir::new(root.join(name.as_str()))
+            .min_depth(3)
+            .max_depth(3)
+            .into_iter()
+            .filter_map(Result::ok)
+        {
+            if !is_wheel_entry(CacheBucket::SourceDistributions, entry.path()) {
+                continue;
+            }
+            let Ok(filename) = WheelFilename::from_str(&entry.file_name().to_string_lossy()) else {
+                continue;
+            };
+            if filename.name == *name {
+                wheels
+                    .entry(filename)
+                    .or_insert_with(|| CachedWheel::File(entry.into_path()));
+            }
+        }
+    }
+
+    // Downloaded wheels, stored as pointers to an unzipped archive at `<index>/<name>/<key>.http`.
+    for root in index_roots(cache, CacheBucket::Wheels)? {
+        for entry in read_dir(&root.join(name.as_str()))? {
+            let path = entry.path();
+            let archive = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("http") => HttpArchivePointer::read_from(&path)
+                    .ok()
+                    .flatten()
+                    .map(HttpArchivePointer::into_archive),
+                Some("rev") => LocalArchivePointer::read_from(&path)
+                    .ok()
+                    .flatten()
+                    .map(LocalArchivePointer::into_archive),
+                _ => None,
+            };
+            let Some(archive) = archive else {
+                continue;
+            };
+            let archive_path = cache.archive(&archive.id);
+            if archive.filename.name == *name && archive_path.is_dir() {
+                wheels
+                    .entry(archive.filename)
+                    .or_insert(CachedWheel::Archive(archive_path));
+            }
+        }
+    }
+
+    Ok(wheels)
+}
+
+/// Returns `true` if the entry is a wheel (or a pointer to one) in the given bucket.
+fn is_wheel_entry(bucket: CacheBucket, path: &Path) -> bool {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    match bucket {
+        CacheBucket::Wheels => matches!(extension, Some("http" | "rev")),
+        _ => extension == Some("whl") && path.is_file(),
+    }
+}
+
+/// Return the directories in a bucket that hold artifacts from package indexes, i.e., PyPI and any
+/// alternative indexes, to the exclusion of direct URL, Git, and path dependencies.
+fn index_roots(cache: &Cache, bucket: CacheBucket) -> Result<Vec<PathBuf>> {
+    let bucket = cache.bucket(bucket);
+    let mut roots = vec![bucket.join("pypi")];
+    roots.extend(read_dir(&bucket.join("index"))?.map(|entry| entry.path()));
+    Ok(roots)
+}
+
+/// Read the subdirectories and files of a directory, treating a missing directory as empty.
+fn read_dir(path: &Path) -> Result<impl Iterator<Item = fs_err::DirEntry>> {
+    let entries = match fs_err::read_dir(path) {
+        Ok(entries) => entries.filter_map(Result::ok).collect::<Vec<_>>(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err.into()),
+    };
+    Ok(entries.into_iter())
+}
+
+/// Archive an unzipped wheel into an anonymous temporary file.
+pub(crate) fn archive_wheel(path: &Path) -> Result<std::fs::File> {
+    let mut writer = zip::ZipWriter::new(tempfile::tempfile()?);
+    for entry in WalkDir::new(path).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(path)
+            .expect("walkdir starts with root");
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        #[cfg(unix)]
+        let options = {
+            use std::os::unix::fs::PermissionsExt;
+            options.unix_permissions(entry.metadata()?.permissions().mode())
+        };
+
+        writer.start_file(name, options)?;
+        std::io::copy(&mut fs_err::File::open(entry.path())?, &mut writer)?;
+    }
+    let mut file = writer.finish()?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(file)
+}
+
+/// Return a response with the given content type and body.
+fn content(content_type: &'static str, body: String) -> Response<Body> {
+    let mut response = Response::new(full(body));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, content_type.parse().expect("valid header"));
+    response
+}
+
+/// Return a redirect to the given location.
+fn redirect(location: &str) -> Response<Body> {
+    let mut response = status(StatusCode::MOVED_PERMANENTLY);
+    if let Ok(location) = location.parse() {
+        response.headers_mut().insert(LOCATION, location);
+    }
+    response
+}
+
+/// Return an empty response with the given status.
+fn status(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(empty());
+    *response.status_mut() = status;
+    response
+}
+
+fn full(body: String) -> Body {
+    Full::new(Bytes::from(body))
+        .map_err(|never| match never {})
+        .boxed_unsync()
+}
+
+fn empty() -> Body {
+    full(String::new())
+}
@@ -0,0 +1,100 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_client::{BaseClientBuilder, Connectivity, RegistryClientBuilder};
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::IndexLocations;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store the list of projects available on each configured index in the cache.
+pub(crate) async fn sync(
+    index_locations: &IndexLocations,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::INDEX_SYNC) {
+        warn_user!(
+            "The `uv index sync` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::INDEX_SYNC
+        );
+    }
+
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+
+    // Read the previously synced project lists from the cache, without touching the network.
+    let cached = RegistryClientBuilder::new(
+        client_builder.clone().connectivity(Connectivity::Offline),
+        cache.clone(),
+    )
+    .index_locations(index_locations.clone())
+    .build();
+
+    // Revalidate the project lists, such that unchanged lists aren't downloaded again.
+    let client = RegistryClientBuilder::new(
+        client_builder,
+        cache.clone().with_refresh(Refresh::All(Timestamp::now())),
+    )
+    .index_locations(index_locations.clone())
+    .build();
+
+    let mut indexes = index_locations.indexes().peekable();
+    if indexes.peek().is_none() {
+        writeln!(printer.stderr(), "No indexes to sync")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for index in indexes {
+        // Local indexes are read directly, so there's no previous project list to compare against.
+        let previous = if index.url().url().scheme() == "file" {
+            None
+        } else {
+            cached
+                .fetch_simple_index(index.url())
+                .await
+                .ok()
+                .map(|previous| previous.iter().cloned().collect::<FxHashSet<_>>())
+        };
+        let current = client
+            .fetch_simple_index(index.url())
+            .await?
+            .iter()
+            .cloned()
+            .collect::<FxHashSet<_>>();
+
+        let summary = format!(
+            "Synced {} from {}",
+            format!(
+                "{} project{}",
+                current.len(),
+                if current.len() == 1 { "" } else { "s" }
+            )
+            .bold(),
+            index.url().cyan()
+        );
+        if let Some(previous) = previous {
+            let added = current.difference(&previous).count();
+            let removed = previous.difference(&current).count();
+            writeln!(
+                printer.stderr(),
+                "{summary} {}",
+                format!("({added} added, {removed} removed)").dimmed()
+            )?;
+        } else {
+            writeln!(printer.stderr(), "{summary}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -7,18 +7,26 @@ use std::{fmt::Display, fmt::Write, process::ExitCode};
 use anstream::AutoStream;
 use anyhow::Context;
 use owo_colors::OwoColorize;
-use tracing::debug;
+use tracing::{debug, warn};
 
 pub(crate) use auth::dir::dir as auth_dir;
 pub(crate) use auth::login::login as auth_login;
 pub(crate) use auth::logout::logout as auth_logout;
 pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
+pub(crate) use bundle::create::create as bundle_create;
+pub(crate) use bundle::install::install as bundle_install;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_info::cache_info;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use cache_verify::cache_verify;
+pub(crate) use diagnostics::enable_json_errors;
+pub(crate) use env::restore::{restore as env_restore, rollback as sync_rollback};
 pub(crate) use help::help;
+pub(crate) use index::serve::serve as index_serve;
+pub(crate) use index::sync::sync as index_sync;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -33,10 +41,16 @@ pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
+pub(crate) use project::lock_audit::lock_audit;
+pub(crate) use project::lock_explain::lock_explain;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
+pub(crate) use project::serve::serve;
+pub(crate) use project::shell::shell;
 pub(crate) use project::sync::sync;
+pub(crate) use project::test::test;
 pub(crate) use project::tree::tree;
+pub(crate) use project::verify::verify;
 pub(crate) use project::version::{project_version, self_version};
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
@@ -49,12 +63,14 @@ pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
 #[cfg(feature = "self-update")]
-pub(crate) use self_update::self_update;
+pub(crate) use self_update::{self_rollback, self_update};
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
+pub(crate) use tool::lock::lock as tool_lock;
 pub(crate) use tool::run::ToolRunCommand;
 pub(crate) use tool::run::run as tool_run;
+pub(crate) use tool::sync::sync as tool_sync;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
@@ -62,7 +78,7 @@ use uv_cache::Cache;
 use uv_configuration::Concurrency;
 pub(crate) use uv_console::human_readable_bytes;
 use uv_distribution_types::InstalledMetadata;
-use uv_fs::{CWD, Simplified};
+use uv_fs::{CWD, LockedFile, Simplified};
 use uv_installer::compile_tree;
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
@@ -77,12 +93,17 @@ use crate::printer::Printer;
 mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
+mod bundle;
 mod cache_clean;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
 mod cache_size;
+mod cache_verify;
 mod diagnostics;
+mod env;
 mod help;
+mod index;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -208,6 +229,42 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Acquire the lock for a [`PythonEnvironment`], to serialize modifications across uv processes.
+///
+/// If the lock is held by another process, reports that uv is waiting for it. Returns an error
+/// if the lock isn't released within the `wait_timeout`; other failures to acquire the lock are
+/// logged and ignored.
+pub(super) async fn lock_environment(
+    environment: &PythonEnvironment,
+    wait_timeout: Option<Duration>,
+    printer: Printer,
+) -> anyhow::Result<Option<LockedFile>> {
+    if let Some(lock) = environment.try_lock() {
+        return Ok(Some(lock));
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Waiting for another uv process to release the environment at: {}",
+        environment.root().user_display().cyan()
+    )?;
+
+    let lock = if let Some(wait_timeout) = wait_timeout {
+        environment.lock_timeout(wait_timeout).await
+    } else {
+        environment.lock().await
+    };
+
+    match lock {
+        Ok(lock) => Ok(Some(lock)),
+        Err(err) if err.kind() == std::io::ErrorKind::TimedOut => Err(err.into()),
+        Err(err) => {
+            warn!("Failed to acquire environment lock: {err}");
+            Ok(None)
+        }
+    }
+}
+
 /// A multicasting writer that writes to both the standard output and an output file, if present.
 #[allow(clippy::disallowed_types)]
 struct OutputWriter<'a> {
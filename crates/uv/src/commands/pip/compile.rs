@@ -2,8 +2,10 @@ use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
 use std::io::Write;
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 use itertools::Itertools;
@@ -16,8 +18,8 @@ use uv_python::downloads::ManagedPythonDownloadList;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, IndexStrategy,
-    NoBinary, NoBuild, PipCompileFormat, Reinstall, SourceStrategy, Upgrade,
+    BuildIsolation, BuildOptions, Concurrency, Constraints, Excludes, ExtrasSpecification,
+    IndexStrategy, NoBinary, NoBuild, PipCompileFormat, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -31,6 +33,8 @@ use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
 use uv_python::{
@@ -40,12 +44,12 @@ use uv_python::{
 use uv_requirements::upgrade::{LockedRequirements, read_pylock_toml_requirements};
 use uv_requirements::{
     GroupsSpecification, RequirementsSource, RequirementsSpecification, is_pylock_toml,
-    upgrade::read_requirements_txt,
+    upgrade::{EXCLUDED_PACKAGES_HEADER, read_requirements_txt},
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    AllowedYank, AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex,
+    ForkStrategy, InMemoryIndex, KnownIncompatibility, OptionsBuilder, PrereleaseMode, PylockToml,
+    PythonRequirement, ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -72,7 +76,7 @@ pub(crate) async fn pip_compile(
     overrides_from_workspace: Vec<Requirement>,
     excludes_from_workspace: Vec<uv_normalize::PackageName>,
     build_constraints_from_workspace: Vec<Requirement>,
-    environments: SupportedEnvironments,
+    mut environments: SupportedEnvironments,
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
@@ -80,6 +84,12 @@ pub(crate) async fn pip_compile(
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
+    yanked_policy: YankedPolicy,
+    allow_yanked: Vec<AllowedYank>,
+    known_incompatibilities: Vec<KnownIncompatibility>,
+    max_backtracks: Option<NonZeroUsize>,
+    resolution_timeout: Option<Duration>,
+    prefer_smaller_artifacts: bool,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -97,6 +107,7 @@ pub(crate) async fn pip_compile(
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
+    torch_backend_packages: Vec<PackageName>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
@@ -108,8 +119,10 @@ pub(crate) async fn pip_compile(
     build_options: BuildOptions,
     install_mirrors: PythonInstallMirrors,
     mut python_version: Option<PythonVersion>,
+    mut python_versions: Vec<PythonVersion>,
+    split_python_versions: bool,
     python_platform: Option<TargetTriple>,
-    universal: bool,
+    mut universal: bool,
     exclude_newer: ExcludeNewer,
     sources: SourceStrategy,
     annotation_style: AnnotationStyle,
@@ -193,6 +206,38 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // If the user requests multiple Python versions, perform a single universal resolution that's
+    // limited to those versions, using the lowest version as the lower bound.
+    python_versions.sort_by_key(PythonVersion::python_version);
+    python_versions.dedup_by_key(|version| version.python_version());
+    if let Some(lowest) = python_versions.first() {
+        if split_python_versions {
+            if output_file.is_none() {
+                return Err(anyhow!(
+                    "`--split-python-versions` requires an output file (e.g., `--output-file requirements.txt`)"
+                ));
+            }
+            if matches!(format, PipCompileFormat::PylockToml) {
+                return Err(anyhow!(
+                    "`--split-python-versions` is not supported for `pylock.toml` output"
+                ));
+            }
+        }
+        universal = true;
+        python_version = Some(lowest.clone());
+        environments = SupportedEnvironments::from_markers(
+            python_versions
+                .iter()
+                .map(|version| {
+                    MarkerTree::from_str(&format!(
+                        "python_version == '{}'",
+                        version.python_version()
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+        );
+    }
+
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
     if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
@@ -257,7 +302,7 @@ pub(crate) async fn pip_compile(
         )
         .collect();
 
-    let excludes: Vec<PackageName> = excludes
+    let excludes: Excludes = excludes
         .into_iter()
         .chain(excludes_from_workspace)
         .collect();
@@ -439,6 +484,7 @@ pub(crate) async fn pip_compile(
                     .as_ref()
                     .unwrap_or(interpreter.platform())
                     .os(),
+                torch_backend_packages,
             )
         })
         .transpose()?;
@@ -542,6 +588,12 @@ pub(crate) async fn pip_compile(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .fork_strategy(fork_strategy)
+        .yanked_policy(yanked_policy)
+        .allow_yanked(allow_yanked)
+        .known_incompatibilities(known_incompatibilities)
+        .max_backtracks(max_backtracks)
+        .resolution_timeout(resolution_timeout)
+        .prefer_smaller_artifacts(prefer_smaller_artifacts)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
@@ -589,110 +641,128 @@ pub(crate) async fn pip_compile(
         }
     };
 
-    // Write the resolved dependencies to the output channel.
-    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
-
-    if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
-            )
-            .green()
-        )?;
-    }
+    // Write the resolved dependencies to the output channel, or to a separate output file for
+    // each Python version, if requested.
+    let outputs = if split_python_versions {
+        python_versions
+            .iter()
+            .map(|version| {
+                let version = version.python_version();
+                let output_file =
+                    output_file.map(|output_file| split_output_file(output_file, &version));
+                let requires_python = RequiresPython::from_specifiers(&VersionSpecifiers::from(
+                    VersionSpecifier::equals_star_version(version),
+                ));
+                (output_file, Some(requires_python))
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![(output_file.map(Path::to_path_buf), None)]
+    };
 
-    match format {
-        PipCompileFormat::RequirementsTxt => {
-            if include_marker_expression {
-                if let Some(marker_env) = resolver_env.marker_environment() {
-                    let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
-                    if let Some(relevant_markers) = relevant_markers.contents() {
-                        writeln!(
-                            writer,
-                            "{}",
-                            "# Pinned dependencies known to be valid for:".green()
-                        )?;
-                        writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
-                    }
-                }
-            }
+    for (output_file, requires_python) in outputs {
+        let output_file = output_file.as_deref();
+        let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
-            let mut wrote_preamble = false;
+        if include_header {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
+                )
+                .green()
+            )?;
+        }
 
-            // If necessary, include the `--index-url` and `--extra-index-url` locations.
-            if include_index_url {
-                if let Some(index) = index_locations.default_index() {
-                    writeln!(writer, "--index-url {}", index.url().verbatim())?;
-                    wrote_preamble = true;
-                }
-                let mut seen = FxHashSet::default();
-                for extra_index in index_locations.implicit_indexes() {
-                    if seen.insert(extra_index.url()) {
-                        writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
-                        wrote_preamble = true;
+        match format {
+            PipCompileFormat::RequirementsTxt => {
+                if include_marker_expression {
+                    if let Some(marker_env) = resolver_env.marker_environment() {
+                        let relevant_markers =
+                            resolution.marker_tree(&top_level_index, marker_env)?;
+                        if let Some(relevant_markers) = relevant_markers.contents() {
+                            writeln!(
+                                writer,
+                                "{}",
+                                "# Pinned dependencies known to be valid for:".green()
+                            )?;
+                            writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
+                        }
                     }
                 }
-            }
 
-            // If necessary, include the `--find-links` locations.
-            if include_find_links {
-                for flat_index in index_locations.flat_indexes() {
-                    writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
-                    wrote_preamble = true;
-                }
-            }
+                let mut wrote_preamble = false;
 
-            // If necessary, include the `--no-binary` and `--only-binary` options.
-            if include_build_options {
-                match build_options.no_binary() {
-                    NoBinary::None => {}
-                    NoBinary::All => {
-                        writeln!(writer, "--no-binary :all:")?;
+                // If necessary, include the `--index-url` and `--extra-index-url` locations.
+                if include_index_url {
+                    if let Some(index) = index_locations.default_index() {
+                        writeln!(writer, "--index-url {}", index.url().verbatim())?;
                         wrote_preamble = true;
                     }
-                    NoBinary::Packages(packages) => {
-                        for package in packages {
-                            writeln!(writer, "--no-binary {package}")?;
+                    let mut seen = FxHashSet::default();
+                    for extra_index in index_locations.implicit_indexes() {
+                        if seen.insert(extra_index.url()) {
+                            writeln!(writer, "--extra-index-url {}", extra_index.url().verbatim())?;
                             wrote_preamble = true;
                         }
                     }
                 }
-                match build_options.no_build() {
-                    NoBuild::None => {}
-                    NoBuild::All => {
-                        writeln!(writer, "--only-binary :all:")?;
+
+                // If necessary, include the `--find-links` locations.
+                if include_find_links {
+                    for flat_index in index_locations.flat_indexes() {
+                        writeln!(writer, "--find-links {}", flat_index.url().verbatim())?;
                         wrote_preamble = true;
                     }
-                    NoBuild::Packages(packages) => {
-                        for package in packages {
-                            writeln!(writer, "--only-binary {package}")?;
+                }
+
+                // If necessary, include the `--no-binary` and `--only-binary` options.
+                if include_build_options {
+                    match build_options.no_binary() {
+                        NoBinary::None => {}
+                        NoBinary::All => {
+                            writeln!(writer, "--no-binary :all:")?;
+                            wrote_preamble = true;
+                        }
+                        NoBinary::Packages(packages) => {
+                            for package in packages {
+                                writeln!(writer, "--no-binary {package}")?;
+                                wrote_preamble = true;
+                            }
+                        }
+                    }
+                    match build_options.no_build() {
+                        NoBuild::None => {}
+                        NoBuild::All => {
+                            writeln!(writer, "--only-binary :all:")?;
                             wrote_preamble = true;
                         }
+                        NoBuild::Packages(packages) => {
+                            for package in packages {
+                                writeln!(writer, "--only-binary {package}")?;
+                                wrote_preamble = true;
+                            }
+                        }
                     }
                 }
-            }
 
-            // If we wrote an index, add a newline to separate it from the requirements
-            if wrote_preamble {
-                writeln!(writer)?;
-            }
+                // If we wrote an index, add a newline to separate it from the requirements
+                if wrote_preamble {
+                    writeln!(writer)?;
+                }
 
-            write!(
-                writer,
-                "{}",
-                DisplayResolutionGraph::new(
+                let mut graph = DisplayResolutionGraph::new(
                     &resolution,
                     &resolver_env,
                     &no_emit_packages,
@@ -702,69 +772,72 @@ pub(crate) async fn pip_compile(
                     include_annotations,
                     include_index_annotation,
                     annotation_style,
-                )
-            )?;
-        }
-        PipCompileFormat::PylockToml => {
-            if include_marker_expression {
-                warn_user!(
-                    "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_index_url {
-                warn_user!(
-                    "The `--emit-index-url` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_find_links {
-                warn_user!(
-                    "The `--emit-find-links` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_build_options {
-                warn_user!(
-                    "The `--emit-build-options` option is not supported for `pylock.toml` output"
-                );
-            }
-            if include_index_annotation {
-                warn_user!(
-                    "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
                 );
+                if let Some(requires_python) = requires_python {
+                    graph = graph.with_requires_python(requires_python);
+                }
+                write!(writer, "{graph}")?;
             }
+            PipCompileFormat::PylockToml => {
+                if include_marker_expression {
+                    warn_user!(
+                        "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_index_url {
+                    warn_user!(
+                        "The `--emit-index-url` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_find_links {
+                    warn_user!(
+                        "The `--emit-find-links` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_build_options {
+                    warn_user!(
+                        "The `--emit-build-options` option is not supported for `pylock.toml` output"
+                    );
+                }
+                if include_index_annotation {
+                    warn_user!(
+                        "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
+                    );
+                }
 
-            // Determine the directory relative to which the output file should be written.
-            let output_file = output_file.map(std::path::absolute).transpose()?;
-            let install_path = if let Some(output_file) = output_file.as_deref() {
-                output_file.parent().unwrap()
-            } else {
-                &*CWD
-            };
-
-            // Convert the resolution to a `pylock.toml` file.
-            let export = PylockToml::from_resolution(&resolution, &no_emit_packages, install_path)?;
-            write!(writer, "{}", export.to_toml()?)?;
+                // Determine the directory relative to which the output file should be written.
+                let output_file = output_file.map(std::path::absolute).transpose()?;
+                let install_path = if let Some(output_file) = output_file.as_deref() {
+                    output_file.parent().unwrap()
+                } else {
+                    &*CWD
+                };
+
+                // Convert the resolution to a `pylock.toml` file.
+                let export =
+                    PylockToml::from_resolution(&resolution, &no_emit_packages, install_path)?;
+                write!(writer, "{}", export.to_toml()?)?;
+            }
         }
-    }
 
-    // If any "unsafe" packages were excluded, notify the user.
-    let excluded = no_emit_packages
-        .into_iter()
-        .filter(|name| resolution.contains(name))
-        .collect::<Vec<_>>();
-    if !excluded.is_empty() {
-        writeln!(writer)?;
-        writeln!(
-            writer,
-            "{}",
-            "# The following packages were excluded from the output:".green()
-        )?;
-        for package in excluded {
-            writeln!(writer, "# {package}")?;
+        // If any "unsafe" packages were excluded, notify the user.
+        let excluded = no_emit_packages
+            .iter()
+            .filter(|name| resolution.contains(name))
+            .collect::<Vec<_>>();
+        if !excluded.is_empty() {
+            writeln!(writer)?;
+            writeln!(writer, "{}", EXCLUDED_PACKAGES_HEADER.green())?;
+            for package in excluded {
+                for version in resolution.versions(package) {
+                    writeln!(writer, "# {package}=={version}")?;
+                }
+            }
         }
-    }
 
-    // Commit the output to disk.
-    writer.commit().await?;
+        // Commit the output to disk.
+        writer.commit().await?;
+    }
 
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -772,6 +845,22 @@ pub(crate) async fn pip_compile(
     Ok(ExitStatus::Success)
 }
 
+/// Return the output file for the given Python version.
+///
+/// For example, `requirements.txt` becomes `requirements-py3.10.txt` for Python 3.10.
+fn split_output_file(output_file: &Path, version: &Version) -> PathBuf {
+    let stem = output_file
+        .file_stem()
+        .map(OsStr::to_string_lossy)
+        .unwrap_or_default();
+    let file_name = if let Some(extension) = output_file.extension() {
+        format!("{stem}-py{version}.{}", extension.to_string_lossy())
+    } else {
+        format!("{stem}-py{version}")
+    };
+    output_file.with_file_name(file_name)
+}
+
 /// Format the uv command used to generate the output file.
 #[allow(clippy::fn_params_excessive_bools)]
 fn cmd(
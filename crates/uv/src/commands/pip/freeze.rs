@@ -4,13 +4,20 @@ use std::path::PathBuf;
 use anyhow::Result;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::Cache;
-use uv_distribution_types::{Diagnostic, InstalledDistKind, Name};
+use uv_cli::FreezeFormat;
+use uv_distribution_types::{Diagnostic, InstalledDist, InstalledDistKind, Name};
+use uv_fs::Simplified;
 use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_preview::Preview;
+use uv_pypi_types::DirectUrl;
 use uv_python::PythonPreference;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_redacted::DisplaySafeUrl;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations::report_target_environment;
@@ -20,6 +27,7 @@ use crate::printer::Printer;
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
     strict: bool,
+    format: FreezeFormat,
     python: Option<&str>,
     system: bool,
     paths: Option<Vec<PathBuf>>,
@@ -56,34 +64,71 @@ pub(crate) fn pip_freeze(
         None => vec![SitePackages::from_environment(&environment)?],
     };
 
-    site_packages
+    let dists = site_packages
         .iter()
         .flat_map(uv_installer::SitePackages::iter)
         .filter(|dist| !(exclude_editable && dist.is_editable()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
-        .map(|dist| match &dist.kind {
-            InstalledDistKind::Registry(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::Url(dist) => {
-                if dist.editable {
-                    format!("-e {}", dist.url)
-                } else {
-                    format!("{} @ {}", dist.name().bold(), dist.url)
-                }
-            }
-            InstalledDistKind::EggInfoFile(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::EggInfoDirectory(dist) => {
-                format!("{}=={}", dist.name().bold(), dist.version)
-            }
-            InstalledDistKind::LegacyEditable(dist) => {
-                format!("-e {}", dist.target.display())
+        .collect_vec();
+
+    match format {
+        FreezeFormat::Text => {
+            dists
+                .iter()
+                .map(|dist| format_dist(dist).bold_name())
+                .dedup()
+                .try_for_each(|dist| writeln!(printer.stdout_important(), "{dist}"))?;
+        }
+        FreezeFormat::Json => {
+            let entries = dists
+                .iter()
+                .map(|dist| Entry::from_dist(dist))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .dedup()
+                .collect_vec();
+            let output = serde_json::to_string(&entries)?;
+            writeln!(printer.stdout_important(), "{output}")?;
+        }
+    }
+
+    // If multiple paths were provided, report any packages that are installed with different
+    // versions (or from different sources) across them.
+    if site_packages.len() > 1 {
+        for (name, group) in &dists.iter().chunk_by(|dist| dist.name()) {
+            let variants = group
+                .map(|dist| {
+                    (
+                        format_dist(dist).plain(),
+                        dist.install_path()
+                            .parent()
+                            .unwrap_or(dist.install_path())
+                            .to_path_buf(),
+                    )
+                })
+                .unique_by(|(requirement, _)| requirement.clone())
+                .collect_vec();
+            if variants.len() > 1 {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "warning".yellow().bold(),
+                    ":".bold(),
+                    format!(
+                        "`{name}` is installed with conflicting versions across paths: {}",
+                        variants
+                            .iter()
+                            .map(|(requirement, path)| format!(
+                                "`{requirement}` (in {})",
+                                path.user_display()
+                            ))
+                            .join(", ")
+                    )
+                    .bold()
+                )?;
             }
-        })
-        .dedup()
-        .try_for_each(|dist| writeln!(printer.stdout_important(), "{dist}"))?;
+        }
+    }
 
     // Validate that the environment is consistent.
     if strict {
@@ -106,3 +151,98 @@ pub(crate) fn pip_freeze(
 
     Ok(ExitStatus::Success)
 }
+
+/// A `pip freeze`-style requirement for an installed distribution.
+enum FrozenDist<'a> {
+    /// A distribution pinned to a version, as in `name==version`.
+    Version(&'a PackageName, &'a Version),
+    /// A distribution installed from a URL, as in `name @ url`.
+    Url(&'a PackageName, &'a DisplaySafeUrl),
+    /// An editable distribution, as in `-e url`.
+    Editable(String),
+}
+
+impl FrozenDist<'_> {
+    /// Render the requirement with the package name highlighted.
+    fn bold_name(&self) -> String {
+        match self {
+            Self::Version(name, version) => format!("{}=={version}", name.bold()),
+            Self::Url(name, url) => format!("{} @ {url}", name.bold()),
+            Self::Editable(target) => format!("-e {target}"),
+        }
+    }
+
+    /// Render the requirement without any styling.
+    fn plain(&self) -> String {
+        match self {
+            Self::Version(name, version) => format!("{name}=={version}"),
+            Self::Url(name, url) => format!("{name} @ {url}"),
+            Self::Editable(target) => format!("-e {target}"),
+        }
+    }
+}
+
+/// Return the `pip freeze`-style requirement for an installed distribution.
+fn format_dist(dist: &InstalledDist) -> FrozenDist<'_> {
+    match &dist.kind {
+        InstalledDistKind::Registry(dist) => FrozenDist::Version(dist.name(), &dist.version),
+        InstalledDistKind::Url(dist) => {
+            if dist.editable {
+                FrozenDist::Editable(dist.url.to_string())
+            } else {
+                FrozenDist::Url(dist.name(), &dist.url)
+            }
+        }
+        InstalledDistKind::EggInfoFile(dist) => FrozenDist::Version(dist.name(), &dist.version),
+        InstalledDistKind::EggInfoDirectory(dist) => {
+            FrozenDist::Version(dist.name(), &dist.version)
+        }
+        InstalledDistKind::LegacyEditable(dist) => {
+            FrozenDist::Editable(dist.target.display().to_string())
+        }
+    }
+}
+
+/// An entry in a JSON list of installed packages.
+#[derive(Debug, PartialEq, Serialize)]
+struct Entry {
+    name: PackageName,
+    version: Version,
+    editable: bool,
+    /// The `site-packages` directory in which the package is installed.
+    location: PathBuf,
+    /// The contents of the `direct_url.json` file, for packages installed from a URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direct_url: Option<DirectUrl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<String>,
+    /// The contents of the `INSTALLER` file, i.e., the tool that installed the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installer: Option<String>,
+}
+
+impl Entry {
+    fn from_dist(dist: &InstalledDist) -> Result<Self> {
+        let direct_url = match &dist.kind {
+            InstalledDistKind::Url(dist) => Some((*dist.direct_url).clone()),
+            _ => None,
+        };
+        Ok(Self {
+            name: dist.name().clone(),
+            version: dist.version().clone(),
+            editable: dist.is_editable(),
+            location: dist
+                .install_path()
+                .parent()
+                .unwrap_or(dist.install_path())
+                .to_path_buf(),
+            direct_url,
+            editable_project_location: dist.as_editable().and_then(|url| {
+                url.to_file_path()
+                    .ok()
+                    .map(|path| path.simplified_display().to_string())
+            }),
+            installer: dist.read_installer()?,
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tempfile::TempDir;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::Interpreter;
+
+/// A package in a hypothetical environment, as listed by `uv pip list --format json`.
+#[derive(Debug, Deserialize)]
+struct HypotheticalPackage {
+    name: PackageName,
+    version: Version,
+}
+
+/// A hypothetical environment, used to plan an installation (e.g., with `--assume-installed`)
+/// without access to the environment itself.
+///
+/// The listed packages are materialized as `.dist-info` directories in a temporary
+/// `site-packages` directory, such that they can be indexed like any other environment.
+#[derive(Debug)]
+pub(crate) struct HypotheticalEnvironment {
+    site_packages: TempDir,
+}
+
+impl HypotheticalEnvironment {
+    /// Read a hypothetical environment from a JSON list of `name`/`version` objects.
+    pub(crate) fn from_file(path: &Path, cache: &Cache) -> Result<Self> {
+        let contents = fs_err::read_to_string(path)?;
+        let packages: Vec<HypotheticalPackage> =
+            serde_json::from_str(&contents).with_context(|| {
+                format!(
+                    "Failed to parse installed packages from: `{}`",
+                    path.user_display()
+                )
+            })?;
+
+        let site_packages = cache.venv_dir()?;
+        for package in packages {
+            let dist_info = site_packages.path().join(format!(
+                "{}-{}.dist-info",
+                package.name.as_dist_info_name(),
+                package.version
+            ));
+            fs_err::create_dir_all(&dist_info)?;
+            fs_err::write(
+                dist_info.join("METADATA"),
+                format!(
+                    "Metadata-Version: 2.1\nName: {}\nVersion: {}\n",
+                    package.name, package.version
+                ),
+            )?;
+            fs_err::write(
+                dist_info.join("WHEEL"),
+                "Wheel-Version: 1.0\nGenerator: uv\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+            )?;
+            fs_err::write(dist_info.join("INSTALLER"), "uv\n")?;
+            fs_err::write(dist_info.join("RECORD"), "")?;
+        }
+
+        Ok(Self { site_packages })
+    }
+
+    /// Index the packages in the hypothetical environment.
+    pub(crate) fn site_packages(&self, interpreter: &Interpreter) -> Result<SitePackages> {
+        SitePackages::from_site_packages(interpreter, [self.site_packages.path()])
+    }
+}
@@ -1,39 +1,43 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::time::Duration;
 
 use anyhow::Context;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::{Level, debug, enabled, warn};
+use tracing::{Level, debug, enabled};
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, Reinstall, SourceStrategy, Upgrade,
+    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, Excludes, ExtrasSpecification,
+    HashCheckingMode, IndexStrategy, NoBinary, NoBuild, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Patches, Requirement, Resolution,
     UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptPolicy, WheelChecks};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::Conflicts;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, ImplementationName, Prefix, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PylockToml,
-    PythonRequirement, ResolutionMode, ResolverEnvironment,
+    AllowedYank, DependencyMode, ExcludeNewer, FlatIndex, KnownIncompatibility, OptionsBuilder,
+    PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
+    YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
@@ -42,12 +46,15 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
+use crate::commands::pip::hypothetical::HypotheticalEnvironment;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    find_or_synthesize_environment, operations, resolution_markers, resolution_tags,
+};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, lock_environment};
 use crate::printer::Printer;
 
 /// Install packages into the current environment.
@@ -66,16 +73,25 @@ pub(crate) async fn pip_install(
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    yanked_policy: YankedPolicy,
+    allow_yanked: Vec<AllowedYank>,
+    known_incompatibilities: Vec<KnownIncompatibility>,
+    max_backtracks: Option<NonZeroUsize>,
+    resolution_timeout: Option<Duration>,
+    prefer_smaller_artifacts: bool,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
+    torch_backend_packages: Vec<PackageName>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     reinstall: Reinstall,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
@@ -88,6 +104,7 @@ pub(crate) async fn pip_install(
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    implementation: Option<ImplementationName>,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
@@ -100,8 +117,10 @@ pub(crate) async fn pip_install(
     prefix: Option<Prefix>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
+    wait_timeout: Option<Duration>,
     cache: Cache,
     dry_run: DryRun,
+    assume_installed: Option<&Path>,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
@@ -175,7 +194,7 @@ pub(crate) async fn pip_install(
         )
         .collect();
 
-    let excludes: Vec<PackageName> = excludes
+    let excludes: Excludes = excludes
         .into_iter()
         .chain(excludes_from_workspace)
         .collect();
@@ -193,8 +212,44 @@ pub(crate) async fn pip_install(
             )
             .collect();
 
+    if implementation.is_some() {
+        if target.is_none() && prefix.is_none() {
+            return Err(anyhow::anyhow!(
+                "`--implementation` can only be used with `--target` or `--prefix`"
+            ));
+        }
+        if python.is_some() {
+            return Err(anyhow::anyhow!(
+                "`--implementation` cannot be used with `--python`"
+            ));
+        }
+    }
+
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if let (Some(root), None, Some(python_version), Some(python_platform)) = (
+        target
+            .as_ref()
+            .map(Target::root)
+            .or_else(|| prefix.as_ref().map(Prefix::root)),
+        python.as_deref(),
+        python_version.as_ref(),
+        python_platform.as_ref(),
+    ) {
+        find_or_synthesize_environment(
+            python_version,
+            python_platform,
+            implementation,
+            root,
+            system,
+            python_preference,
+            &client_builder,
+            install_mirrors.python_downloads_json_url.as_deref(),
+            &cache,
+            preview,
+            printer,
+        )
+        .await?
+    } else if target.is_some() || prefix.is_some() {
         let python_request = python.as_deref().map(PythonRequest::parse);
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -208,6 +263,7 @@ pub(crate) async fn pip_install(
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -290,13 +346,7 @@ pub(crate) async fn pip_install(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = lock_environment(&environment, wait_timeout, printer).await?;
 
     // Determine the markers and tags to use for the resolution.
     let interpreter = environment.interpreter();
@@ -311,10 +361,39 @@ pub(crate) async fn pip_install(
         interpreter,
     )?;
 
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    // Determine the set of installed packages, or those of a hypothetical environment.
+    let hypothetical = assume_installed
+        .map(|path| HypotheticalEnvironment::from_file(path, &cache))
+        .transpose()?;
+    let site_packages = if let Some(hypothetical) = &hypothetical {
+        hypothetical.site_packages(interpreter)?
+    } else {
+        SitePackages::from_environment(&environment)?
+    };
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
 
     // Check if the current environment satisfies the requirements.
+    //
+    // This check is performed before any index or network access, such that re-running an
+    // already-satisfied install is effectively free. Installed distributions are also validated
+    // against the required hashes, if any.
+    //
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
     if reinstall.is_none()
@@ -335,6 +414,8 @@ pub(crate) async fn pip_install(
             config_settings_package,
             &extra_build_requires,
             extra_build_variables,
+            &Patches::default(),
+            &hasher,
         )? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
@@ -369,23 +450,6 @@ pub(crate) async fn pip_install(
         PythonRequirement::from_interpreter(interpreter)
     };
 
-    // Collect the set of required hashes.
-    let hasher = if let Some(hash_checking) = hash_checking {
-        HashStrategy::from_requirements(
-            requirements
-                .iter()
-                .chain(overrides.iter())
-                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
-            constraints
-                .iter()
-                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
-            Some(&marker_env),
-            hash_checking,
-        )?
-    } else {
-        HashStrategy::None
-    };
-
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
@@ -420,6 +484,7 @@ pub(crate) async fn pip_install(
                     .as_ref()
                     .unwrap_or(interpreter.platform())
                     .os(),
+                torch_backend_packages,
             )
         })
         .transpose()?;
@@ -436,6 +501,28 @@ pub(crate) async fn pip_install(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Without a Python interpreter, source distributions can't be built.
+    let build_options = if interpreter.is_synthetic() {
+        if !matches!(build_options.no_binary(), NoBinary::None) {
+            return Err(anyhow::anyhow!(
+                "`--no-binary` requires a Python interpreter to build source distributions, but no interpreter was found for Python {}",
+                interpreter.python_version()
+            ));
+        }
+        debug!("Disabling builds, since no Python interpreter is available");
+        build_options.combine(NoBinary::None, NoBuild::All)
+    } else {
+        build_options
+    };
+
+    // Likewise, bytecode can't be compiled.
+    let compile = if compile && interpreter.is_synthetic() {
+        warn_user!("Bytecode compilation requires a Python interpreter; skipping compilation");
+        false
+    } else {
+        compile
+    };
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
@@ -558,6 +645,12 @@ pub(crate) async fn pip_install(
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .yanked_policy(yanked_policy)
+            .allow_yanked(allow_yanked)
+            .known_incompatibilities(known_incompatibilities)
+            .max_backtracks(max_backtracks)
+            .resolution_timeout(resolution_timeout)
+            .prefer_smaller_artifacts(prefer_smaller_artifacts)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(index_strategy)
@@ -648,6 +741,8 @@ pub(crate) async fn pip_install(
         &reinstall,
         &build_options,
         link_mode,
+        script_policy,
+        wheel_checks,
         compile,
         &hasher,
         &tags,
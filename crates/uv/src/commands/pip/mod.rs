@@ -1,13 +1,30 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+use std::path::Path;
 
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
 use uv_configuration::TargetTriple;
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder};
 use uv_platform_tags::{Tags, TagsError};
+use uv_preview::Preview;
 use uv_pypi_types::ResolverMarkerEnvironment;
-use uv_python::{Interpreter, PythonVersion};
+use uv_python::downloads::ManagedPythonDownloadList;
+use uv_python::{
+    EnvironmentPreference, ImplementationName, Interpreter, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
+};
+
+use crate::commands::pip::operations::report_interpreter;
+use crate::printer::Printer;
 
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
+mod hypothetical;
 pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
@@ -50,6 +67,8 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -58,6 +77,8 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -66,6 +87,8 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     })
@@ -85,6 +108,8 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -93,6 +118,8 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             python_platform.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -101,6 +128,8 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
+            interpreter.pymalloc(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     };
@@ -121,3 +150,101 @@ pub(crate) fn resolution_environment(
 
     Ok((tags, markers))
 }
+
+/// Find a Python environment for installing into a `--target` or `--prefix` directory, given an
+/// explicit Python version and platform.
+///
+/// An existing interpreter that matches the requested implementation and version is preferred, as
+/// it can be used to build source distributions. Otherwise, rather than downloading Python, the
+/// environment is backed by a synthetic interpreter for the target platform, which can only be
+/// used to install wheels.
+pub(crate) async fn find_or_synthesize_environment(
+    python_version: &PythonVersion,
+    python_platform: &TargetTriple,
+    implementation: Option<ImplementationName>,
+    root: &Path,
+    system: bool,
+    python_preference: PythonPreference,
+    client_builder: &BaseClientBuilder<'_>,
+    python_downloads_json_url: Option<&str>,
+    cache: &Cache,
+    preview: Preview,
+    printer: Printer,
+) -> anyhow::Result<PythonEnvironment> {
+    let request = if let Some(implementation) = implementation {
+        PythonRequest::ImplementationVersion(implementation, VersionRequest::from(python_version))
+    } else {
+        PythonRequest::Version(VersionRequest::from(python_version))
+    };
+
+    let client = client_builder.clone().retries(0).build();
+    let download_list = ManagedPythonDownloadList::new(&client, python_downloads_json_url).await?;
+    match PythonInstallation::find(
+        &request,
+        EnvironmentPreference::from_system_flag(system, false),
+        python_preference.with_system_flag(system),
+        &download_list,
+        cache,
+        preview,
+    ) {
+        Ok(installation) => {
+            report_interpreter(&installation, true, printer)?;
+            return Ok(PythonEnvironment::from_installation(installation));
+        }
+        Err(uv_python::Error::MissingPython(..)) => {
+            debug!(
+                "No interpreter found for {}; installing without a Python interpreter",
+                request.to_canonical_string()
+            );
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    let implementation = implementation.unwrap_or_default();
+    let (implementation_version, platform_python_implementation) = match implementation {
+        ImplementationName::CPython => (python_version.to_string(), "CPython"),
+        // PyPy has been versioned as 7.3 across all of its recent releases.
+        ImplementationName::PyPy => ("7.3.0".to_string(), "PyPy"),
+        _ => {
+            return Err(anyhow::anyhow!(
+                "No {} interpreter found for Python {python_version}; installing without a Python interpreter is only supported for CPython and PyPy",
+                implementation.pretty()
+            ));
+        }
+    };
+
+    // The platform and version markers are overridden below, so only the implementation markers
+    // need to be accurate here.
+    let markers = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+        implementation_name: implementation.into(),
+        implementation_version: &implementation_version,
+        os_name: "",
+        platform_machine: "",
+        platform_python_implementation,
+        platform_release: "",
+        platform_system: "",
+        platform_version: "",
+        python_full_version: &python_version.to_string(),
+        python_version: &python_version.to_string(),
+        sys_platform: "",
+    })?;
+    let markers = python_version.markers(&python_platform.markers(&markers));
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Using {} {} without a Python interpreter",
+            implementation.pretty(),
+            python_version.version()
+        )
+        .dimmed()
+    )?;
+
+    Ok(PythonEnvironment::from_interpreter(Interpreter::synthetic(
+        markers,
+        python_platform.platform(),
+        python_platform.manylinux_compatible(),
+        root.to_path_buf(),
+    )))
+}
@@ -24,7 +24,7 @@ use uv_distribution_types::{
 };
 use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
 use uv_fs::Simplified;
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptPolicy, WheelChecks};
 use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin};
@@ -105,7 +105,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
     constraints: Vec<NameRequirementSpecification>,
     overrides: Vec<UnresolvedRequirementSpecification>,
-    excludes: Vec<PackageName>,
+    excludes: Excludes,
     source_trees: Vec<SourceTree>,
     mut project: Option<PackageName>,
     workspace_members: BTreeSet<PackageName>,
@@ -285,7 +285,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         overrides
     };
 
-    // Collect constraints, overrides, and excludes.
+    // Collect constraints and overrides.
     let constraints = Constraints::from_requirements(
         constraints
             .into_iter()
@@ -293,7 +293,6 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             .chain(upgrade.constraints().cloned()),
     );
     let overrides = Overrides::from_requirements(overrides);
-    let excludes = excludes.into_iter().collect::<Excludes>();
     let preferences = Preferences::from_iter(preferences, &resolver_env);
 
     // Determine any lookahead requirements.
@@ -446,6 +445,8 @@ pub(crate) async fn install(
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     compile: bool,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -477,6 +478,7 @@ pub(crate) async fn install(
             build_dispatch.config_settings_package(),
             build_dispatch.extra_build_requires(),
             build_dispatch.extra_build_variables(),
+            build_dispatch.patches(),
             cache,
             venv,
             tags,
@@ -537,6 +539,8 @@ pub(crate) async fn install(
             resolution,
             build_options,
             link_mode,
+            script_policy,
+            wheel_checks,
             hasher,
             tags,
             client,
@@ -566,6 +570,8 @@ pub(crate) async fn install(
             resolution,
             build_options,
             link_mode,
+            script_policy,
+            wheel_checks,
             hasher,
             tags,
             client,
@@ -618,6 +624,8 @@ async fn execute_plan(
     resolution: &Resolution,
     build_options: &BuildOptions,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     hasher: &HashStrategy,
     tags: &Tags,
     client: &RegistryClient,
@@ -710,6 +718,8 @@ async fn execute_plan(
         let start = std::time::Instant::now();
         installs = uv_installer::Installer::new(venv, preview)
             .with_link_mode(link_mode)
+            .with_script_policy(script_policy)
+            .with_wheel_checks(wheel_checks)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
             .with_reporter(Arc::new(
@@ -1,15 +1,17 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
-use tracing::{debug, warn};
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, Reinstall, SourceStrategy, Upgrade,
+    HashCheckingMode, IndexStrategy, NoBinary, NoBuild, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -19,14 +21,14 @@ use uv_distribution_types::{
     PackageConfigSettings, Resolution,
 };
 use uv_fs::Simplified;
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptPolicy, WheelChecks};
 use uv_installer::{InstallationStrategy, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::Conflicts;
 use uv_python::{
-    EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
-    PythonPreference, PythonRequest, PythonVersion, Target,
+    EnvironmentPreference, ImplementationName, Prefix, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest, PythonVersion, Target,
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
@@ -40,12 +42,15 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
+use crate::commands::pip::hypothetical::HypotheticalEnvironment;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    find_or_synthesize_environment, operations, resolution_markers, resolution_tags,
+};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, lock_environment};
 use crate::printer::Printer;
 
 /// Install a set of locked requirements into the current Python environment.
@@ -58,11 +63,14 @@ pub(crate) async fn pip_sync(
     groups: &GroupsSpecification,
     reinstall: Reinstall,
     link_mode: LinkMode,
+    script_policy: ScriptPolicy,
+    wheel_checks: WheelChecks,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
+    torch_backend_packages: Vec<PackageName>,
     dependency_metadata: DependencyMetadata,
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
@@ -76,6 +84,7 @@ pub(crate) async fn pip_sync(
     build_options: BuildOptions,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    implementation: Option<ImplementationName>,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
@@ -88,8 +97,10 @@ pub(crate) async fn pip_sync(
     sources: SourceStrategy,
     python_preference: PythonPreference,
     concurrency: Concurrency,
+    wait_timeout: Option<Duration>,
     cache: Cache,
     dry_run: DryRun,
+    assume_installed: Option<&Path>,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -166,8 +177,44 @@ pub(crate) async fn pip_sync(
         }
     }
 
+    if implementation.is_some() {
+        if target.is_none() && prefix.is_none() {
+            return Err(anyhow::anyhow!(
+                "`--implementation` can only be used with `--target` or `--prefix`"
+            ));
+        }
+        if python.is_some() {
+            return Err(anyhow::anyhow!(
+                "`--implementation` cannot be used with `--python`"
+            ));
+        }
+    }
+
     // Detect the current Python interpreter.
-    let environment = if target.is_some() || prefix.is_some() {
+    let environment = if let (Some(root), None, Some(python_version), Some(python_platform)) = (
+        target
+            .as_ref()
+            .map(Target::root)
+            .or_else(|| prefix.as_ref().map(Prefix::root)),
+        python.as_deref(),
+        python_version.as_ref(),
+        python_platform.as_ref(),
+    ) {
+        find_or_synthesize_environment(
+            python_version,
+            python_platform,
+            implementation,
+            root,
+            system,
+            python_preference,
+            &client_builder,
+            install_mirrors.python_downloads_json_url.as_deref(),
+            &cache,
+            preview,
+            printer,
+        )
+        .await?
+    } else if target.is_some() || prefix.is_some() {
         let python_request = python.as_deref().map(PythonRequest::parse);
         let reporter = PythonDownloadReporter::single(printer);
 
@@ -181,6 +228,7 @@ pub(crate) async fn pip_sync(
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -239,13 +287,7 @@ pub(crate) async fn pip_sync(
         }
     }
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = lock_environment(&environment, wait_timeout, printer).await?;
 
     let interpreter = environment.interpreter();
 
@@ -318,6 +360,7 @@ pub(crate) async fn pip_sync(
                     .as_ref()
                     .unwrap_or(interpreter.platform())
                     .os(),
+                torch_backend_packages,
             )
         })
         .transpose()?;
@@ -334,6 +377,28 @@ pub(crate) async fn pip_sync(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Without a Python interpreter, source distributions can't be built.
+    let build_options = if interpreter.is_synthetic() {
+        if !matches!(build_options.no_binary(), NoBinary::None) {
+            return Err(anyhow::anyhow!(
+                "`--no-binary` requires a Python interpreter to build source distributions, but no interpreter was found for Python {}",
+                interpreter.python_version()
+            ));
+        }
+        debug!("Disabling builds, since no Python interpreter is available");
+        build_options.combine(NoBinary::None, NoBuild::All)
+    } else {
+        build_options
+    };
+
+    // Likewise, bytecode can't be compiled.
+    let compile = if compile && interpreter.is_synthetic() {
+        warn_user!("Bytecode compilation requires a Python interpreter; skipping compilation");
+        false
+    } else {
+        compile
+    };
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
@@ -406,8 +471,15 @@ pub(crate) async fn pip_sync(
         preview,
     );
 
-    // Determine the set of installed packages.
-    let site_packages = SitePackages::from_environment(&environment)?;
+    // Determine the set of installed packages, or those of a hypothetical environment.
+    let hypothetical = assume_installed
+        .map(|path| HypotheticalEnvironment::from_file(path, &cache))
+        .transpose()?;
+    let site_packages = if let Some(hypothetical) = &hypothetical {
+        hypothetical.site_packages(interpreter)?
+    } else {
+        SitePackages::from_environment(&environment)?
+    };
 
     let (resolution, hasher) = if let Some(pylock) = pylock {
         // Read the `pylock.toml` from disk, and deserialize it from TOML.
@@ -475,7 +547,7 @@ pub(crate) async fn pip_sync(
             requirements,
             constraints,
             overrides,
-            excludes,
+            excludes.into_iter().collect(),
             source_trees,
             project,
             BTreeSet::default(),
@@ -553,6 +625,8 @@ pub(crate) async fn pip_sync(
         &reinstall,
         &build_options,
         link_mode,
+        script_policy,
+        wheel_checks,
         compile,
         &hasher,
         &tags,
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
@@ -30,14 +30,15 @@ use uv_git::GIT_STORE;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, ExtraName, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::{DependencyGroupSpecifier, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
-use uv_resolver::FlatIndex;
+use uv_resolver::{FlatIndex, UpgradePolicy};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::pyproject::{DependencyType, Source, SourceError, Sources, ToolUvSources};
 use uv_workspace::pyproject_mut::{AddBoundsKind, ArrayEdit, DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
@@ -46,15 +47,17 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::imports::{ImportResolver, ImportScan};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::search::search_interactive;
 use crate::commands::project::{
     PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter,
     UniversalState, default_dependency_groups, init_script_python_requirement,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
-use crate::commands::{ExitStatus, ScriptPath, diagnostics, project};
+use crate::commands::{ExitStatus, ScriptPath, diagnostics, index, project};
 use crate::printer::Printer;
 use crate::settings::{LockCheck, ResolverInstallerSettings};
 
@@ -74,13 +77,16 @@ pub(crate) async fn add(
     only_install_local: bool,
     no_install_package: Vec<PackageName>,
     only_install_package: Vec<PackageName>,
-    requirements: Vec<RequirementsSource>,
+    mut requirements: Vec<RequirementsSource>,
+    from_imports: bool,
+    interactive: bool,
     constraints: Vec<RequirementsSource>,
     marker: Option<MarkerTree>,
     editable: Option<bool>,
     dependency_type: DependencyType,
     raw: bool,
     bounds: Option<AddBoundsKind>,
+    upgrade_policy: UpgradePolicy,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -109,6 +115,13 @@ pub(crate) async fn add(
         );
     }
 
+    if from_imports && !preview.is_enabled(PreviewFeatures::ADD_FROM_IMPORTS) {
+        warn_user_once!(
+            "The `--from-imports` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::ADD_FROM_IMPORTS
+        );
+    }
+
     if !preview.is_enabled(PreviewFeatures::EXTRA_BUILD_DEPENDENCIES)
         && !settings.resolver.extra_build_dependencies.is_empty()
     {
@@ -335,6 +348,61 @@ pub(crate) async fn add(
         }
     };
 
+    // Detect any third-party imports that aren't declared as dependencies.
+    if from_imports {
+        let missing = missing_imports(&target);
+        if missing.is_empty() {
+            if requirements.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No missing dependencies detected from imports"
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Detected {} missing {} from imports: {}",
+                missing.len(),
+                if missing.len() == 1 {
+                    "dependency"
+                } else {
+                    "dependencies"
+                },
+                missing.iter().map(|name| name.cyan()).join(", ")
+            )?;
+            for name in missing {
+                requirements.push(RequirementsSource::from_package_argument(name.as_str())?);
+            }
+        }
+    }
+
+    // Search the configured indexes for a package to add.
+    if interactive {
+        let client = RegistryClientBuilder::new(
+            client_builder
+                .clone()
+                .keyring(settings.resolver.keyring_provider),
+            cache.clone(),
+        )
+        .index_locations(settings.resolver.index_locations.clone())
+        .build();
+        let indexes = settings
+            .resolver
+            .index_locations
+            .indexes()
+            .map(Index::url)
+            .collect::<Vec<_>>();
+        if let Some(requirement) =
+            search_interactive(&client, &indexes, concurrency.downloads, printer).await?
+        {
+            requirements.push(RequirementsSource::from_package_argument(&requirement)?);
+        } else if requirements.is_empty() {
+            writeln!(printer.stderr(), "No package selected")?;
+            return Ok(ExitStatus::Success);
+        }
+    }
+
     let _lock = target
         .acquire_lock()
         .await
@@ -508,6 +576,29 @@ pub(crate) async fn add(
         }
     }
 
+    // If the project lists of the configured indexes are available, suggest corrections for any
+    // packages that aren't available on any index.
+    if preview.is_enabled(PreviewFeatures::INDEX_SYNC) {
+        let names = requirements
+            .iter()
+            .filter(|requirement| matches!(requirement.source, RequirementSource::Registry { .. }))
+            .map(|requirement| &requirement.name);
+        for (name, suggestion) in index::suggest_projects(
+            names,
+            &settings.resolver.index_locations,
+            &client_builder,
+            cache,
+        )
+        .await
+        {
+            warn_user!(
+                "`{}` was not found on any of the configured indexes. Did you mean `{}`?",
+                name.cyan(),
+                suggestion.cyan(),
+            );
+        }
+    }
+
     // Store the content prior to any modifications.
     let snapshot = target.snapshot().await?;
 
@@ -759,6 +850,7 @@ pub(crate) async fn add(
         &defaulted_groups,
         raw,
         bounds,
+        upgrade_policy,
         constraints,
         &settings,
         &client_builder,
@@ -866,6 +958,7 @@ fn edits(
                 rev,
                 tag,
                 branch,
+                fallback,
                 marker,
                 extra,
                 group,
@@ -884,6 +977,7 @@ fn edits(
                     rev,
                     tag,
                     branch,
+                    fallback,
                     marker,
                     extra,
                     group,
@@ -995,6 +1089,7 @@ async fn lock_and_sync(
     groups: &DependencyGroupsWithDefaults,
     raw: bool,
     bound_kind: Option<AddBoundsKind>,
+    upgrade_policy: UpgradePolicy,
     constraints: Vec<NameRequirementSpecification>,
     settings: &ResolverInstallerSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -1022,6 +1117,7 @@ async fn lock_and_sync(
             preview,
         )
         .with_constraints(constraints)
+        .with_upgrade_policy(upgrade_policy)
         .execute((&target).into()),
     )
     .await?
@@ -1252,6 +1348,97 @@ fn resolve_requirement(
     Ok((processed_requirement, source))
 }
 
+/// Detect the distributions imported by the target's Python sources that aren't declared as
+/// dependencies.
+fn missing_imports(target: &AddTarget) -> Vec<PackageName> {
+    let (scan, resolver, declared) = match target {
+        AddTarget::Script(script, _) => {
+            let declared = script
+                .metadata
+                .dependencies
+                .iter()
+                .flatten()
+                .map(|requirement| requirement.name.clone())
+                .collect::<FxHashSet<_>>();
+            (
+                ImportScan::from_file(&script.path),
+                ImportResolver::default(),
+                declared,
+            )
+        }
+        AddTarget::Project(project, python_target) => {
+            let pyproject = project.pyproject_toml();
+
+            // Collect every requirement string declared by the project, across its dependencies,
+            // optional dependencies, and dependency groups.
+            let project_table = pyproject.project.as_ref();
+            let requirements = project_table
+                .and_then(|project| project.dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .chain(
+                    project_table
+                        .and_then(|project| project.optional_dependencies.as_ref())
+                        .into_iter()
+                        .flat_map(|extras| extras.values().flatten()),
+                )
+                .chain(
+                    pyproject
+                        .dependency_groups
+                        .iter()
+                        .flat_map(uv_pypi_types::DependencyGroups::iter)
+                        .flat_map(|(_, specifiers)| specifiers)
+                        .filter_map(|specifier| match specifier {
+                            DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                            _ => None,
+                        }),
+                )
+                .filter_map(|requirement| {
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement).ok()
+                })
+                .chain(
+                    pyproject
+                        .tool
+                        .as_ref()
+                        .and_then(|tool| tool.uv.as_ref())
+                        .and_then(|uv| uv.dev_dependencies.clone())
+                        .into_iter()
+                        .flatten(),
+                )
+                .map(|requirement| requirement.name);
+
+            // Workspace members are always satisfied by the workspace itself.
+            let declared = requirements
+                .chain(project.workspace().packages().keys().cloned())
+                .collect::<FxHashSet<_>>();
+
+            let resolver = match &**python_target {
+                PythonTarget::Environment(environment) => {
+                    ImportResolver::from_environment(environment)
+                }
+                PythonTarget::Interpreter(_) => ImportResolver::default(),
+            };
+
+            (
+                ImportScan::from_directory(project.root()),
+                resolver,
+                declared,
+            )
+        }
+    };
+
+    scan.third_party()
+        .filter_map(|module| {
+            let name = resolver.resolve(module)?;
+            debug!("Resolved import of `{module}` to: `{name}`");
+            Some(name)
+        })
+        .filter(|name| !declared.contains(name))
+        .unique()
+        .sorted()
+        .collect()
+}
+
 /// A Python [`Interpreter`] or [`PythonEnvironment`] for a project.
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
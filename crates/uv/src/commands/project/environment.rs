@@ -1,4 +1,5 @@
 use std::path::Path;
+use std::time::Duration;
 
 use tracing::debug;
 
@@ -10,7 +11,7 @@ use crate::commands::project::{
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, TargetTriple};
@@ -248,3 +249,58 @@ impl CachedEnvironment {
         }
     }
 }
+
+/// A pointer from a `uv tool run` request to the [`CachedEnvironment`] that satisfied it.
+///
+/// Identical requests made within the time-to-live reuse the environment directly, skipping
+/// resolution entirely.
+#[derive(Debug)]
+pub(crate) struct CachedToolEnvironment {
+    entry: CacheEntry,
+    ttl: Duration,
+}
+
+impl CachedToolEnvironment {
+    /// Create a [`CachedToolEnvironment`] for the request identified by the given key.
+    pub(crate) fn new(key: &str, ttl: Duration, cache: &Cache) -> Self {
+        Self {
+            entry: cache.tool_environments().entry(cache_digest(&key)),
+            ttl,
+        }
+    }
+
+    /// Return the cached environment for the request, if it exists and hasn't expired.
+    pub(crate) fn read(&self, cache: &Cache) -> Option<PythonEnvironment> {
+        let modified = fs_err::metadata(self.entry.path())
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        if modified.elapsed().is_ok_and(|elapsed| elapsed > self.ttl) {
+            debug!(
+                "Cached tool environment has expired: `{}`",
+                self.entry.path().display()
+            );
+            return None;
+        }
+        let id = fs_err::read_to_string(self.entry.path()).ok()?;
+        PythonEnvironment::from_root(cache.bucket(CacheBucket::Archive).join(id), cache).ok()
+    }
+
+    /// Record the environment that satisfied the request.
+    pub(crate) fn write(
+        &self,
+        environment: &PythonEnvironment,
+        cache: &Cache,
+    ) -> Result<(), std::io::Error> {
+        // Store the archive ID, rather than the absolute path to the environment.
+        let Some(id) = environment
+            .root()
+            .strip_prefix(cache.bucket(CacheBucket::Archive))
+            .ok()
+            .and_then(Path::to_str)
+        else {
+            return Ok(());
+        };
+        fs_err::create_dir_all(self.entry.dir())?;
+        uv_fs::write_atomic_sync(self.entry.path(), id)
+    }
+}
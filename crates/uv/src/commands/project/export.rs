@@ -1,6 +1,8 @@
+use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
 use std::io::Write;
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, anyhow};
@@ -11,18 +13,20 @@ use owo_colors::OwoColorize;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
+    Concurrency, DependencyGroups, DependencyGroupsWithDefaults, EditableMode, ExportFormat,
+    ExtrasSpecification, InstallOptions, TargetTriple,
 };
-use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName, PackageName};
 use uv_preview::Preview;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{Installable, PylockToml, RequirementsTxtExport, cyclonedx_json};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::resolution_markers;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
@@ -64,6 +68,8 @@ pub(crate) async fn export(
     hashes: bool,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
+    split_groups: bool,
+    strip_markers: bool,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
     editable: Option<EditableMode>,
@@ -73,6 +79,8 @@ pub(crate) async fn export(
     include_header: bool,
     script: Option<Pep723Script>,
     python: Option<String>,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     client_builder: BaseClientBuilder<'_>,
@@ -141,8 +149,12 @@ pub(crate) async fn export(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
-    // Find an interpreter for the project, unless `--frozen` is set.
-    let interpreter = if frozen {
+    // Determine whether to filter the requirements to a target environment.
+    let filter = strip_markers || python_version.is_some() || python_platform.is_some();
+
+    // Find an interpreter for the project, unless `--frozen` is set and the requirements are
+    // exported for all environments.
+    let interpreter = if frozen && !filter {
         None
     } else {
         Some(match &target {
@@ -351,20 +363,117 @@ pub(crate) async fn export(
         }
     }
 
+    if split_groups && !matches!(format, ExportFormat::RequirementsTxt) {
+        return Err(anyhow!(
+            "`{}` is only supported for the `requirements.txt` format",
+            "--split-groups".green()
+        ));
+    }
+
+    if matches!(format, ExportFormat::DockerfileSnippet) {
+        if matches!(target, InstallTarget::Script { .. }) {
+            return Err(anyhow!(
+                "The `dockerfile-snippet` format is not supported for scripts"
+            ));
+        }
+        if strip_markers || python_platform.is_some() {
+            return Err(anyhow!(
+                "`{}` and `{}` are not supported for the `dockerfile-snippet` format",
+                "--strip-markers".green(),
+                "--python-platform".green()
+            ));
+        }
+    }
+
+    if filter && matches!(format, ExportFormat::CycloneDX1_5) {
+        return Err(anyhow!(
+            "`{}`, `{}`, and `{}` are not supported for the CycloneDX format",
+            "--strip-markers".green(),
+            "--python-version".green(),
+            "--python-platform".green()
+        ));
+    }
+
+    // Determine the markers of the target environment, if the requirements should be filtered.
+    let markers = filter.then(|| {
+        resolution_markers(
+            python_version.as_ref(),
+            python_platform.as_ref(),
+            interpreter
+                .as_ref()
+                .expect("an interpreter is required to filter requirements"),
+        )
+    });
+
     // Generate the export.
     match format {
         ExportFormat::RequirementsTxt => {
+            // If we're splitting the dependency groups into separate files, the base file only
+            // includes the project's dependencies.
             let export = RequirementsTxtExport::from_lock(
                 &target,
                 &prune,
                 &extras,
-                &groups,
+                &if split_groups {
+                    DependencyGroupsWithDefaults::none()
+                } else {
+                    groups.clone()
+                },
                 include_annotations,
                 editable,
                 hashes,
                 &install_options,
+                markers.as_deref(),
+                strip_markers,
             )?;
 
+            if split_groups {
+                let output_file = output_file
+                    .as_deref()
+                    .expect("`--split-groups` requires `--output-file`");
+
+                // Identify the enabled groups across the roots and the workspace itself.
+                let group_names = target
+                    .roots()
+                    .filter_map(|root| lock.find_by_name(root).ok().flatten())
+                    .flat_map(|package| package.dependency_groups().keys())
+                    .chain(lock.dependency_groups().keys())
+                    .filter(|group| groups.contains(group))
+                    .collect::<BTreeSet<_>>();
+
+                for group in group_names {
+                    let group_export = RequirementsTxtExport::from_lock(
+                        &target,
+                        &prune,
+                        &extras,
+                        &DependencyGroups::from_group(group.clone())
+                            .with_defaults(DefaultGroups::default()),
+                        include_annotations,
+                        editable,
+                        hashes,
+                        &install_options,
+                        markers.as_deref(),
+                        strip_markers,
+                    )?
+                    .without(&export);
+
+                    let group_file = split_group_path(output_file, group);
+                    let mut group_writer = OutputWriter::new(false, Some(&group_file));
+                    if include_header {
+                        writeln!(
+                            group_writer,
+                            "# This file was autogenerated by uv via the following command:"
+                        )?;
+                        writeln!(group_writer, "#    {}", cmd())?;
+                    }
+                    if let Some(file_name) = output_file.file_name() {
+                        writeln!(group_writer, "-r {}", file_name.to_string_lossy())?;
+                    }
+                    write!(group_writer, "{group_export}")?;
+                    group_writer.commit().await?;
+                }
+            }
+
             if include_header {
                 writeln!(
                     writer,
@@ -384,6 +493,8 @@ pub(crate) async fn export(
                 include_annotations,
                 editable,
                 &install_options,
+                markers.as_deref(),
+                strip_markers,
             )?;
 
             if include_header {
@@ -396,6 +507,48 @@ pub(crate) async fn export(
             }
             write!(writer, "{}", export.to_toml()?)?;
         }
+        ExportFormat::DockerfileSnippet => {
+            let python = if let Some(python_version) = python_version.as_ref() {
+                Some(format!(
+                    "{}.{}",
+                    python_version.major(),
+                    python_version.minor()
+                ))
+            } else if let Some(interpreter) = interpreter.as_ref() {
+                Some(format!(
+                    "{}.{}",
+                    interpreter.python_major(),
+                    interpreter.python_minor()
+                ))
+            } else {
+                match &**lock.requires_python().range().lower() {
+                    Bound::Included(version) | Bound::Excluded(version) => {
+                        match version.release().get(..2) {
+                            Some([major, minor]) => Some(format!("{major}.{minor}")),
+                            _ => None,
+                        }
+                    }
+                    Bound::Unbounded => None,
+                }
+            };
+
+            let snippet = DockerfileSnippet {
+                python,
+                workspace: matches!(target, InstallTarget::NonProjectWorkspace { .. })
+                    || lock.members().len() > 1,
+                flags: sync_flags(all_packages, &package, &extras, &groups, &install_options),
+            };
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{snippet}")?;
+        }
         ExportFormat::CycloneDX1_5 => {
             let export = cyclonedx_json::from_lock(
                 &target,
@@ -417,6 +570,172 @@ pub(crate) async fn export(
     Ok(ExitStatus::Success)
 }
 
+/// A multi-stage Dockerfile that installs the project with `uv sync`.
+///
+/// The dependencies are installed in a separate layer from the project itself, such that the
+/// (expensive) dependency layer is only rebuilt when the lockfile changes. Bytecode is compiled at
+/// install time, and the uv cache is persisted across builds via a cache mount.
+#[derive(Debug)]
+struct DockerfileSnippet {
+    /// The `major.minor` Python version to use for the base image, if known.
+    python: Option<String>,
+    /// Whether the project is a workspace with multiple members.
+    workspace: bool,
+    /// The flags to pass to `uv sync`, to reproduce the exported selection.
+    flags: Vec<String>,
+}
+
+impl std::fmt::Display for DockerfileSnippet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let image = match &self.python {
+            Some(python) => format!("python:{python}-slim"),
+            None => "python:3-slim".to_string(),
+        };
+        let flags = self
+            .flags
+            .iter()
+            .map(|flag| format!(" {flag}"))
+            .collect::<String>();
+
+        writeln!(f, "FROM {image} AS builder")?;
+        writeln!(
+            f,
+            "COPY --from=ghcr.io/astral-sh/uv:{} /uv /uvx /bin/",
+            uv_version::version()
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "# Compile bytecode at install time, copy from the cache mount (rather than linking), and"
+        )?;
+        writeln!(
+            f,
+            "# use the Python interpreter provided by the base image."
+        )?;
+        writeln!(
+            f,
+            "ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0"
+        )?;
+        writeln!(f)?;
+        writeln!(f, "WORKDIR /app")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "# Install the dependencies in a separate layer, such that it's only rebuilt when the"
+        )?;
+        writeln!(f, "# lockfile changes.")?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        writeln!(f, "    --mount=type=bind,source=uv.lock,target=uv.lock \\")?;
+        if self.workspace {
+            // The workspace members can't be mounted individually, so the lockfile can't be
+            // validated against them.
+            writeln!(
+                f,
+                "    uv sync --frozen --no-install-workspace --no-editable{flags}"
+            )?;
+        } else {
+            writeln!(
+                f,
+                "    --mount=type=bind,source=pyproject.toml,target=pyproject.toml \\"
+            )?;
+            writeln!(
+                f,
+                "    uv sync --locked --no-install-project --no-editable{flags}"
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "# Install the project itself.")?;
+        writeln!(f, "COPY . /app")?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        writeln!(f, "    uv sync --locked --no-editable{flags}")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "# Copy the environment, but not the source code or uv itself, into the final image."
+        )?;
+        writeln!(f, "FROM {image}")?;
+        writeln!(f, "COPY --from=builder /app/.venv /app/.venv")?;
+        writeln!(f, "ENV PATH=\"/app/.venv/bin:$PATH\"")?;
+        Ok(())
+    }
+}
+
+/// Reconstruct the `uv sync` flags that select the same packages, extras, and dependency groups
+/// as the export.
+fn sync_flags(
+    all_packages: bool,
+    package: &[PackageName],
+    extras: &ExtrasSpecification,
+    groups: &DependencyGroups,
+    install_options: &InstallOptions,
+) -> Vec<String> {
+    let mut flags = vec![];
+
+    if all_packages {
+        flags.push("--all-packages".to_string());
+    }
+    for name in package {
+        flags.push(format!("--package {name}"));
+    }
+
+    let extras = extras.history();
+    if extras.all_extras {
+        flags.push("--all-extras".to_string());
+    }
+    if extras.no_default_extras {
+        flags.push("--no-default-extras".to_string());
+    }
+    for extra in &extras.extra {
+        flags.push(format!("--extra {extra}"));
+    }
+    for extra in &extras.no_extra {
+        flags.push(format!("--no-extra {extra}"));
+    }
+
+    let groups = groups.history();
+    if groups.all_groups {
+        flags.push("--all-groups".to_string());
+    }
+    if groups.no_default_groups {
+        flags.push("--no-default-groups".to_string());
+    }
+    if let Some(dev_mode) = groups.dev_mode.as_ref() {
+        flags.push(dev_mode.as_flag().to_string());
+    }
+    for group in &groups.group {
+        flags.push(format!("--group {group}"));
+    }
+    for group in &groups.only_group {
+        flags.push(format!("--only-group {group}"));
+    }
+    for group in &groups.no_group {
+        flags.push(format!("--no-group {group}"));
+    }
+
+    for name in &install_options.no_install_package {
+        flags.push(format!("--no-install-package {name}"));
+    }
+    if install_options.no_install_local {
+        flags.push("--no-install-local".to_string());
+    }
+
+    flags
+}
+
+/// Return the path of the requirements file for a dependency group, next to the given output
+/// file (e.g., `requirements-dev.txt` for `requirements.txt`).
+fn split_group_path(output_file: &Path, group: &GroupName) -> PathBuf {
+    let stem = output_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let file_name = match output_file.extension() {
+        Some(extension) => format!("{stem}-{group}.{}", extension.to_string_lossy()),
+        None => format!("{stem}-{group}"),
+    };
+    output_file.with_file_name(file_name)
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
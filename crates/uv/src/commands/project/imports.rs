@@ -0,0 +1,351 @@
+//! Static analysis of the imports in a project's Python sources.
+//!
+//! This is intentionally a lightweight, line-based scan rather than a full Python parser: it
+//! only needs to recover the top-level module names referenced by `import` and `from ... import`
+//! statements, and it should never execute (or require) a Python interpreter.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::{debug, trace};
+use walkdir::WalkDir;
+
+use uv_distribution_types::Name;
+use uv_install_wheel::is_stdlib_module;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+
+/// A mapping from well-known import names to the distributions that provide them, for cases in
+/// which the two differ (e.g., `yaml` is provided by `pyyaml`).
+static IMPORT_NAMES: LazyLock<FxHashMap<String, PackageName>> = LazyLock::new(|| {
+    let import_names: Vec<(String, String)> =
+        serde_json::from_str(include_str!("import_names.json")).unwrap();
+    import_names
+        .into_iter()
+        .map(|(module, package)| (module, PackageName::from_str(&package).unwrap()))
+        .collect()
+});
+
+/// Directories that are never scanned for imports.
+const IGNORED_DIRECTORIES: &[&str] = &[
+    "__pycache__",
+    "build",
+    "dist",
+    "node_modules",
+    "site-packages",
+    "venv",
+];
+
+/// The top-level imports discovered in a set of Python sources.
+#[derive(Debug, Default)]
+pub(crate) struct ImportScan {
+    /// The top-level module names that are imported.
+    imports: BTreeSet<String>,
+    /// The top-level module names that are provided by the sources themselves.
+    local: FxHashSet<String>,
+}
+
+impl ImportScan {
+    /// Scan all Python files under the given directory.
+    ///
+    /// Hidden directories, virtual environments, and build artifacts are skipped.
+    pub(crate) fn from_directory(root: &Path) -> Self {
+        let mut scan = Self::default();
+
+        let walker = WalkDir::new(root)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| {
+                if entry.depth() == 0 || !entry.file_type().is_dir() {
+                    return true;
+                }
+                let name = entry.file_name().to_string_lossy();
+                !name.starts_with('.')
+                    && !IGNORED_DIRECTORIES.contains(&name.as_ref())
+                    && !entry.path().join("pyvenv.cfg").is_file()
+            });
+
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    debug!("Failed to read directory entry: {err}");
+                    continue;
+                }
+            };
+            if !entry.file_type().is_file()
+                || entry.path().extension().is_none_or(|ext| ext != "py")
+            {
+                continue;
+            }
+
+            // Treat every module and package in the tree as first-party, along with the
+            // top-level package that contains it (relative to the root or a `src` layout).
+            let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            let relative = relative.strip_prefix("src").unwrap_or(relative);
+            for component in relative.components() {
+                let component = component.as_os_str().to_string_lossy();
+                let component = component.strip_suffix(".py").unwrap_or(&component);
+                scan.local.insert(component.to_string());
+            }
+
+            scan.add_file(entry.path());
+        }
+
+        scan
+    }
+
+    /// Scan a single Python file.
+    ///
+    /// Modules and packages alongside the file are treated as first-party, since they're
+    /// importable when the file is executed as a script.
+    pub(crate) fn from_file(path: &Path) -> Self {
+        let mut scan = Self::default();
+        if let Some(Ok(entries)) = path.parent().map(fs_err::read_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().is_some_and(|ext| ext == "py") {
+                    if let Some(stem) = path.file_stem() {
+                        scan.local.insert(stem.to_string_lossy().to_string());
+                    }
+                } else if path.join("__init__.py").is_file() {
+                    scan.local
+                        .insert(entry.file_name().to_string_lossy().to_string());
+                }
+            }
+        }
+        scan.add_file(path);
+        scan
+    }
+
+    /// Add the imports from the given Python file to the scan.
+    fn add_file(&mut self, path: &Path) {
+        let source = match fs_err::read_to_string(path) {
+            Ok(source) => source,
+            Err(err) => {
+                debug!("Failed to read Python source: {err}");
+                return;
+            }
+        };
+        for module in parse_imports(&source) {
+            trace!("Found import of `{module}` in: {}", path.display());
+            self.imports.insert(module.to_string());
+        }
+    }
+
+    /// Return the imported top-level modules that are neither part of the standard library nor
+    /// provided by the scanned sources themselves.
+    pub(crate) fn third_party(&self) -> impl Iterator<Item = &str> {
+        self.imports
+            .iter()
+            .map(String::as_str)
+            .filter(|module| !is_stdlib_module(module))
+            .filter(|module| !self.local.contains(*module))
+    }
+}
+
+/// A mapping from top-level module names to the distributions that provide them.
+#[derive(Debug, Default)]
+pub(crate) struct ImportResolver {
+    installed: FxHashMap<String, Vec<PackageName>>,
+}
+
+impl ImportResolver {
+    /// Index the top-level modules provided by each distribution installed in the environment.
+    pub(crate) fn from_environment(environment: &PythonEnvironment) -> Self {
+        let site_packages = match SitePackages::from_environment(environment) {
+            Ok(site_packages) => site_packages,
+            Err(err) => {
+                debug!("Failed to read installed packages: {err}");
+                return Self::default();
+            }
+        };
+
+        let mut installed: FxHashMap<String, Vec<PackageName>> = FxHashMap::default();
+        for dist in site_packages.iter() {
+            for module in top_level_modules(dist.install_path()) {
+                installed
+                    .entry(module)
+                    .or_default()
+                    .push(dist.name().clone());
+            }
+        }
+        Self { installed }
+    }
+
+    /// Determine the distribution that provides the given top-level module.
+    ///
+    /// Installed distributions take precedence over the bundled mapping; otherwise, the module
+    /// name is assumed to match the distribution name.
+    pub(crate) fn resolve(&self, module: &str) -> Option<PackageName> {
+        self.providers(module).into_iter().next()
+    }
+
+    /// Determine all distributions that may provide the given top-level module.
+    ///
+    /// A module can be provided by multiple installed distributions, as with namespace packages
+    /// (e.g., `google` is shared by `protobuf` and `googleapis-common-protos`).
+    pub(crate) fn providers(&self, module: &str) -> Vec<PackageName> {
+        if let Some(names) = self.installed.get(module) {
+            return names.clone();
+        }
+        if let Some(name) = IMPORT_NAMES.get(module) {
+            return vec![name.clone()];
+        }
+        PackageName::from_str(module).ok().into_iter().collect()
+    }
+}
+
+/// Read the top-level modules provided by an installed distribution.
+///
+/// Prefers `top_level.txt` (as written by setuptools), falling back to the `RECORD` file.
+fn top_level_modules(install_path: &Path) -> BTreeSet<String> {
+    if let Ok(contents) = fs_err::read_to_string(install_path.join("top_level.txt")) {
+        return contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.replace('/', "."))
+            .filter_map(|line| line.split('.').next().map(ToString::to_string))
+            .collect();
+    }
+
+    let Ok(mut record) = fs_err::File::open(install_path.join("RECORD")) else {
+        return BTreeSet::new();
+    };
+    let Ok(entries) = uv_install_wheel::read_record_file(&mut record) else {
+        return BTreeSet::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let first = entry.path.split('/').next()?;
+            if first == ".." || first == "__pycache__" {
+                return None;
+            }
+            if Path::new(first).extension().is_some_and(|ext| {
+                ext.eq_ignore_ascii_case("dist-info")
+                    || ext.eq_ignore_ascii_case("data")
+                    || ext.eq_ignore_ascii_case("pth")
+            }) {
+                return None;
+            }
+            // Strip any file extension, including ABI-tagged extension modules (e.g.,
+            // `_cffi_backend.cpython-312-x86_64-linux-gnu.so`).
+            let module = first.split('.').next()?;
+            if module.is_empty() {
+                return None;
+            }
+            Some(module.to_string())
+        })
+        .collect()
+}
+
+/// Extract the top-level module names from the `import` statements in a Python source file.
+///
+/// Relative imports are ignored, as are statements within strings.
+fn parse_imports(source: &str) -> Vec<&str> {
+    let mut modules = Vec::new();
+    let mut in_string: Option<&str> = None;
+
+    for line in source.lines() {
+        // Track (and skip over) triple-quoted strings, like docstrings.
+        if let Some(delimiter) = in_string {
+            if line.matches(delimiter).count() % 2 == 1 {
+                in_string = None;
+            }
+            continue;
+        }
+        for delimiter in ["\"\"\"", "'''"] {
+            if line.matches(delimiter).count() % 2 == 1 {
+                in_string = Some(delimiter);
+            }
+        }
+
+        let line = line.split('#').next().unwrap_or_default();
+        for statement in line.split(';') {
+            let statement = statement.trim();
+            if let Some(rest) = statement.strip_prefix("import ") {
+                for alias in rest.split(',') {
+                    let Some(name) = alias.split_whitespace().next() else {
+                        continue;
+                    };
+                    let name = name.trim_start_matches('(');
+                    if let Some(module) = top_level(name) {
+                        modules.push(module);
+                    }
+                }
+            } else if let Some(rest) = statement.strip_prefix("from ") {
+                let Some((name, _)) = rest.split_once(" import") else {
+                    continue;
+                };
+                if let Some(module) = top_level(name.trim()) {
+                    modules.push(module);
+                }
+            }
+        }
+    }
+
+    modules
+}
+
+/// Return the top-level module of a dotted module path, if it's a valid, absolute import.
+fn top_level(name: &str) -> Option<&str> {
+    if name.starts_with('.') {
+        return None;
+    }
+    let module = name.split('.').next()?;
+    let mut chars = module.chars();
+    let first = chars.next()?;
+    if !(first.is_alphabetic() || first == '_') {
+        return None;
+    }
+    if !chars.all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_imports;
+
+    #[test]
+    fn imports() {
+        let source = r#"
+"""A module docstring.
+
+import not_an_import
+"""
+import os, sys
+import numpy as np
+import yaml.constructor
+from requests import Session
+from . import sibling
+from .sibling import helper
+from google.protobuf import message  # A comment: import commented
+import attr; import rich
+if True:
+    from typing_extensions import Self
+print("from x import y")
+"#;
+        assert_eq!(
+            parse_imports(source),
+            vec![
+                "os",
+                "sys",
+                "numpy",
+                "yaml",
+                "requests",
+                "google",
+                "attr",
+                "rich",
+                "typing_extensions"
+            ]
+        );
+    }
+}
@@ -46,6 +46,8 @@ pub(crate) async fn init(
     name: Option<PackageName>,
     package: bool,
     init_kind: InitKind,
+    shebang: bool,
+    with: Vec<String>,
     bare: bool,
     description: Option<String>,
     no_description: bool,
@@ -73,6 +75,8 @@ pub(crate) async fn init(
 
             init_script(
                 path,
+                shebang,
+                &with,
                 python,
                 install_mirrors,
                 client_builder,
@@ -201,6 +205,8 @@ pub(crate) async fn init(
 #[allow(clippy::fn_params_excessive_bools)]
 async fn init_script(
     script_path: &Path,
+    shebang: bool,
+    with: &[String],
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     client_builder: &BaseClientBuilder<'_>,
@@ -275,7 +281,14 @@ async fn init_script(
         fs_err::tokio::create_dir_all(parent).await?;
     }
 
-    Pep723Script::create(script_path, requires_python.specifiers(), content).await?;
+    Pep723Script::create(
+        script_path,
+        requires_python.specifiers(),
+        with,
+        shebang,
+        content,
+    )
+    .await?;
 
     Ok(())
 }
@@ -555,6 +568,7 @@ async fn determine_requires_python(
                         Some(reporter),
                         install_mirrors.python_install_mirror.as_deref(),
                         install_mirrors.pypy_install_mirror.as_deref(),
+                        install_mirrors.python_download_attestations.as_ref(),
                         install_mirrors.python_downloads_json_url.as_deref(),
                         preview,
                     )
@@ -583,6 +597,7 @@ async fn determine_requires_python(
                     Some(reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_download_attestations.as_ref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                     preview,
                 )
@@ -656,6 +671,7 @@ async fn determine_requires_python(
                 Some(reporter),
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
+                install_mirrors.python_download_attestations.as_ref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
                 preview,
             )
@@ -686,6 +702,7 @@ async fn determine_requires_python(
             Some(reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -7,7 +7,7 @@ use itertools::Either;
 use rustc_hash::FxHashSet;
 
 use uv_configuration::{Constraints, DependencyGroupsWithDefaults, ExtrasSpecification};
-use uv_distribution_types::Index;
+use uv_distribution_types::{Index, Patches};
 use uv_normalize::{ExtraName, PackageName};
 use uv_pypi_types::{DependencyGroupSpecifier, LenientRequirement, VerbatimParsedUrl};
 use uv_resolver::{Installable, Lock, Package};
@@ -15,7 +15,7 @@ use uv_scripts::Pep723Script;
 use uv_workspace::Workspace;
 use uv_workspace::pyproject::{Source, Sources, ToolUvSources};
 
-use crate::commands::project::ProjectError;
+use crate::commands::project::{ProjectError, workspace_patches};
 
 /// A target that can be installed from a lockfile.
 #[derive(Debug, Copy, Clone)]
@@ -260,6 +260,19 @@ impl<'lock> InstallTarget<'lock> {
         self.lock().build_constraints(self.install_path())
     }
 
+    /// Returns the set of patches to apply to source distributions for the [`InstallTarget`].
+    ///
+    /// Patches are not supported for PEP 723 scripts.
+    pub(crate) fn patches(self) -> Result<Patches, std::io::Error> {
+        match self {
+            Self::Project { workspace, .. }
+            | Self::Projects { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace_patches(workspace),
+            Self::Script { .. } => Ok(Patches::default()),
+        }
+    }
+
     /// Validate the extras requested by the [`ExtrasSpecification`].
     #[allow(clippy::result_large_err)]
     pub(crate) fn validate_extras(self, extras: &ExtrasSpecification) -> Result<(), ProjectError> {
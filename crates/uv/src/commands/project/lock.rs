@@ -2,29 +2,33 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use jiff::Timestamp;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cli::LockFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Reinstall,
-    Upgrade,
+    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, Excludes,
+    ExtrasSpecification, InstallOptions, Reinstall, SourceStrategy, Upgrade, UpgradeTarget,
 };
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    Patches, Requirement, RequirementSource, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
-use uv_normalize::{GroupName, PackageName};
-use uv_pep440::Version;
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName, PackageName};
+use uv_pep440::{Operator, Version, VersionSpecifiers};
+use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
@@ -32,19 +36,24 @@ use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_resolver::{
     FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    RequirementsTxtExport, RequiresPythonDiagnostic, ResolverEnvironment, ResolverManifest,
+    SatisfiesResult, UniversalMarker, UpgradePolicy, infer_requires_python_upper_bound,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject::PackageUpgradePolicy;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_mismatch::{LockMismatchReport, write_lock_mismatch};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
-    init_script_python_requirement, script_extra_build_requires,
+    init_script_python_requirement, patched_build_options, script_extra_build_requires,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
@@ -82,12 +91,17 @@ impl LockResult {
 pub(crate) async fn lock(
     project_dir: &Path,
     lock_check: LockCheck,
+    format: LockFormat,
     frozen: bool,
     dry_run: DryRun,
+    upgrade_to: Option<UpgradeTarget>,
+    save: bool,
+    strict_requires_python: bool,
+    emit_constraints: Option<PathBuf>,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
-    settings: ResolverSettings,
+    mut settings: ResolverSettings,
     client_builder: BaseClientBuilder<'_>,
     script: Option<ScriptPath>,
     python_preference: PythonPreference,
@@ -122,6 +136,18 @@ pub(crate) async fn lock(
         None => None,
     };
 
+    // Constrain the package selected with `--upgrade-package` to the `--to` target.
+    let upgrade_target = upgrade_to
+        .map(|target| apply_upgrade_target(&mut settings.upgrade, target))
+        .transpose()?;
+
+    // Update the requirements before locking, such that the lockfile reflects them.
+    let saved = if let Some((package, target)) = upgrade_target.as_ref().filter(|_| save) {
+        save_upgrade_target(project_dir, package, &target.requirement_specifiers()).await?
+    } else {
+        Vec::new()
+    };
+
     // Find the project requirements.
     let workspace_cache = WorkspaceCache::default();
     let workspace;
@@ -204,10 +230,18 @@ pub(crate) async fn lock(
             preview,
         )
         .with_refresh(&refresh)
+        .with_strict_requires_python(strict_requires_python)
         .execute(target),
     )
     .await
-    {
+    .inspect_err(|_| {
+        // If locking failed, revert the changes to the requirements.
+        for (path, contents) in &saved {
+            if let Err(err) = fs_err::write(path, contents) {
+                warn_user!("Failed to restore `{}`: {err}", path.user_display());
+            }
+        }
+    }) {
         Ok(lock) => {
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
@@ -237,10 +271,47 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Write the resolution as a constraints file, if requested.
+            if let Some(path) = emit_constraints.as_deref() {
+                write_constraints(target, lock.lock(), path).await?;
+                writeln!(
+                    printer.stderr(),
+                    "Wrote constraints to: {}",
+                    path.user_display().cyan()
+                )?;
+            }
+
+            // With `--check`, report that no inputs changed.
+            if matches!(format, LockFormat::Json) && matches!(lock_check, LockCheck::Enabled(_)) {
+                let report = LockMismatchReport::default();
+                writeln!(
+                    printer.stdout_important(),
+                    "{}",
+                    serde_json::to_string_pretty(&report)?
+                )?;
+            }
+
             Ok(ExitStatus::Success)
         }
-        Err(err @ ProjectError::LockMismatch(..)) => {
-            writeln!(printer.stderr(), "{}", err.to_string().bold())?;
+        Err(ProjectError::LockMismatch(prev, cur, lock_source)) => {
+            match format {
+                LockFormat::Text => write_lock_mismatch(printer, prev, cur, lock_source)?,
+                LockFormat::Json => {
+                    let report = LockMismatchReport::from_locks(prev.as_deref(), &cur);
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        ProjectError::LockMismatch(prev, cur, lock_source)
+                            .to_string()
+                            .bold()
+                    )?;
+                    writeln!(
+                        printer.stdout_important(),
+                        "{}",
+                        serde_json::to_string_pretty(&report)?
+                    )?;
+                }
+            }
             Ok(ExitStatus::Failure)
         }
         Err(ProjectError::Operation(err)) => {
@@ -252,6 +323,198 @@ pub(crate) async fn lock(
     }
 }
 
+/// Write the locked resolution as a constraints file.
+///
+/// The constraints cover all extras and dependency groups, omitting any local packages, such
+/// as the workspace members.
+async fn write_constraints(target: LockTarget<'_>, lock: &Lock, path: &Path) -> anyhow::Result<()> {
+    let target = match target {
+        LockTarget::Workspace(workspace) if workspace.is_non_project() => {
+            InstallTarget::NonProjectWorkspace { workspace, lock }
+        }
+        LockTarget::Workspace(workspace) => InstallTarget::Workspace { workspace, lock },
+        LockTarget::Script(script) => InstallTarget::Script { script, lock },
+    };
+    let export = RequirementsTxtExport::from_lock(
+        &target,
+        &[],
+        &ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default()),
+        &DependencyGroups::from_args(
+            false,
+            false,
+            false,
+            Vec::new(),
+            Vec::new(),
+            false,
+            Vec::new(),
+            true,
+        )
+        .with_defaults(DefaultGroups::default()),
+        false,
+        None,
+        false,
+        &InstallOptions::default(),
+        None,
+        false,
+    )?
+    .into_constraints();
+
+    let contents = format!(
+        "# This file was autogenerated by uv from the lockfile, and should not be edited.\n{export}"
+    );
+    if let Some(parent) = path.parent() {
+        fs_err::tokio::create_dir_all(parent).await?;
+    }
+    uv_fs::write_atomic(path, contents).await?;
+    Ok(())
+}
+
+/// Constrain the single package selected with `--upgrade-package` to the `--to` target.
+fn apply_upgrade_target(
+    upgrade: &mut Upgrade,
+    target: UpgradeTarget,
+) -> anyhow::Result<(PackageName, UpgradeTarget)> {
+    let Upgrade::Packages(packages) = upgrade else {
+        anyhow::bail!("`--to` requires `--upgrade-package`");
+    };
+    if packages.len() != 1 {
+        anyhow::bail!(
+            "`--to` requires exactly one `--upgrade-package`, but {} packages were provided",
+            packages.len()
+        );
+    }
+    let (package, requirements) = packages.iter_mut().next().expect("one package");
+    requirements.push(Requirement {
+        name: package.clone(),
+        extras: Box::default(),
+        groups: Box::default(),
+        marker: MarkerTree::TRUE,
+        source: RequirementSource::Registry {
+            specifier: target.specifiers(),
+            index: None,
+            conflict: None,
+        },
+        origin: None,
+    });
+    Ok((package.clone(), target))
+}
+
+/// Restrict `--upgrade` to the locked packages whose `upgrade-policy` allows them to be upgraded.
+///
+/// Packages with a `manual` policy are held back, as are packages with an interval policy whose
+/// locked version was uploaded more recently than the interval. Explicit `--upgrade-package`
+/// requests are left unchanged.
+fn apply_upgrade_policies(
+    upgrade: &Upgrade,
+    policies: &BTreeMap<PackageName, PackageUpgradePolicy>,
+    lock: Option<&Lock>,
+) -> Upgrade {
+    let (Upgrade::All, Some(lock)) = (upgrade, lock) else {
+        return upgrade.clone();
+    };
+    if policies.is_empty() {
+        return upgrade.clone();
+    }
+
+    let now = Timestamp::now();
+    let held = lock
+        .packages()
+        .iter()
+        .filter_map(|package| {
+            let policy = policies.get(package.name())?;
+            let held = match policy {
+                PackageUpgradePolicy::Auto => false,
+                PackageUpgradePolicy::Manual => true,
+                PackageUpgradePolicy::Daily
+                | PackageUpgradePolicy::Weekly
+                | PackageUpgradePolicy::Monthly => {
+                    let interval = policy.interval()?;
+                    let upload_time = package.upload_time()?;
+                    now.duration_since(upload_time)
+                        .as_secs()
+                        .try_into()
+                        .is_ok_and(|age: u64| age < interval.as_secs())
+                }
+            };
+            if held {
+                debug!(
+                    "Holding back `{}` due to its `{policy}` upgrade policy",
+                    package.name()
+                );
+            }
+            held.then_some(package.name())
+        })
+        .collect::<BTreeSet<_>>();
+    if held.is_empty() {
+        return upgrade.clone();
+    }
+
+    let packages = lock
+        .packages()
+        .iter()
+        .map(Package::name)
+        .filter(|name| !held.contains(name))
+        .map(|name| (name.clone(), Vec::new()))
+        .collect::<FxHashMap<_, _>>();
+    if packages.is_empty() {
+        Upgrade::None
+    } else {
+        Upgrade::Packages(packages)
+    }
+}
+
+/// Set the specifiers of all requirements on the given package in the `pyproject.toml` files of
+/// the workspace, returning the original contents of the modified files.
+async fn save_upgrade_target(
+    project_dir: &Path,
+    package: &PackageName,
+    specifiers: &VersionSpecifiers,
+) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    let paths = std::iter::once(workspace.install_path().join("pyproject.toml"))
+        .chain(
+            workspace
+                .packages()
+                .values()
+                .map(|member| member.root().join("pyproject.toml")),
+        )
+        .collect::<BTreeSet<_>>();
+
+    let mut saved = Vec::new();
+    for path in paths {
+        let contents = match fs_err::tokio::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+        let mut pyproject =
+            PyProjectTomlMut::from_toml(&contents, DependencyTarget::PyProjectToml)?;
+        if pyproject.set_dependency_specifiers(package, specifiers)? == 0 {
+            continue;
+        }
+        debug!(
+            "Updating the requirement on `{package}` in: {}",
+            path.user_display()
+        );
+        fs_err::tokio::write(&path, pyproject.to_string()).await?;
+        saved.push((path, contents));
+    }
+
+    if saved.is_empty() {
+        warn_user!(
+            "`{package}` is not a direct dependency of the workspace, so `--save` has no effect"
+        );
+    }
+
+    Ok(saved)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -269,6 +532,8 @@ pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
     refresh: Option<&'env Refresh>,
+    upgrade_policy: UpgradePolicy,
+    strict_requires_python: bool,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -298,6 +563,8 @@ impl<'env> LockOperation<'env> {
             mode,
             constraints: vec![],
             refresh: None,
+            upgrade_policy: UpgradePolicy::default(),
+            strict_requires_python: false,
             settings,
             client_builder,
             state,
@@ -327,6 +594,21 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the [`UpgradePolicy`] to apply to locked packages for the [`LockOperation`].
+    #[must_use]
+    pub(super) fn with_upgrade_policy(mut self, upgrade_policy: UpgradePolicy) -> Self {
+        self.upgrade_policy = upgrade_policy;
+        self
+    }
+
+    /// Fail if a resolved package doesn't support all Python versions included in the
+    /// `requires-python`, rather than warning.
+    #[must_use]
+    pub(super) fn with_strict_requires_python(mut self, strict_requires_python: bool) -> Self {
+        self.strict_requires_python = strict_requires_python;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -363,6 +645,8 @@ impl<'env> LockOperation<'env> {
                     Some(existing),
                     self.constraints,
                     self.refresh,
+                    self.upgrade_policy,
+                    self.strict_requires_python,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -407,6 +691,8 @@ impl<'env> LockOperation<'env> {
                     existing,
                     self.constraints,
                     self.refresh,
+                    self.upgrade_policy,
+                    self.strict_requires_python,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -439,6 +725,8 @@ async fn do_lock(
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
     refresh: Option<&Refresh>,
+    upgrade_policy: UpgradePolicy,
+    strict_requires_python: bool,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
     state: &UniversalState,
@@ -459,6 +747,12 @@ async fn do_lock(
         resolution,
         prerelease,
         fork_strategy,
+        yanked_policy,
+        allow_yanked,
+        known_incompatibilities,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -488,14 +782,24 @@ async fn do_lock(
     let requirements = target.requirements();
     let overrides = target.overrides();
     let excludes = target.exclude_dependencies();
+    let provided = target.provided_dependencies();
     let constraints = target.constraints();
     let build_constraints = target.build_constraints();
+    let patches = target.patches()?;
     let dependency_groups = target.dependency_groups()?;
     let source_trees = vec![];
 
+    // Patched packages must be built from source.
+    let build_options = &patched_build_options(build_options, &patches);
+
+    // Hold back any packages whose upgrade policy doesn't allow them to be upgraded.
+    let upgrade =
+        &apply_upgrade_policies(upgrade, &target.upgrade_policies(), existing_lock.as_ref());
+
     // If necessary, lower the overrides and constraints.
     let requirements = target.lower(requirements, index_locations, *sources)?;
     let overrides = target.lower(overrides, index_locations, *sources)?;
+    let provided = target.lower(provided, index_locations, *sources)?;
     let constraints = target.lower(constraints, index_locations, *sources)?;
     let build_constraints = target.lower(build_constraints, index_locations, *sources)?;
     let dependency_groups = dependency_groups
@@ -600,6 +904,9 @@ async fn do_lock(
         None
     };
 
+    // Determine whether to record the provenance of each package's markers.
+    let marker_provenance = target.marker_provenance();
+
     // Determine the supported Python range. If no range is defined, and warn and default to the
     // current minor version.
     let requires_python = target.requires_python()?;
@@ -683,14 +990,30 @@ async fn do_lock(
         }
     };
 
+    // Lower the `fallback` sources, which the resolver uses once the index is exhausted.
+    let fallbacks = match &target {
+        LockTarget::Workspace(workspace) if matches!(*sources, SourceStrategy::Enabled) => {
+            LoweredRequirement::fallbacks(workspace)?
+        }
+        LockTarget::Workspace(_) | LockTarget::Script(_) => Vec::new(),
+    };
+
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .yanked_policy(*yanked_policy)
+        .allow_yanked(allow_yanked.clone())
+        .known_incompatibilities(known_incompatibilities.clone())
+        .max_backtracks(*max_backtracks)
+        .resolution_timeout(*resolution_timeout)
+        .prefer_smaller_artifacts(*prefer_smaller_artifacts)
+        .upgrade_policy(upgrade_policy)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .required_environments(required_environments.cloned().unwrap_or_default())
+        .fallbacks(fallbacks)
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -751,7 +1074,8 @@ async fn do_lock(
         workspace_cache.clone(),
         concurrency,
         preview,
-    );
+    )
+    .with_patches(patches.clone());
 
     let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
 
@@ -768,11 +1092,14 @@ async fn do_lock(
             &constraints,
             &overrides,
             &excludes,
+            &provided,
             &build_constraints,
             &conflicts,
             environments,
             required_environments,
+            marker_provenance,
             dependency_metadata,
+            &patches,
             interpreter,
             &requires_python,
             index_locations,
@@ -802,8 +1129,9 @@ async fn do_lock(
     };
 
     match existing_lock {
-        // Resolution from the lockfile succeeded.
-        Some(ValidatedLock::Satisfies(lock)) => {
+        // Resolution from the lockfile succeeded. With `--strict-requires-python`, we resolve
+        // regardless, to analyze the supported Python versions of the resolved packages.
+        Some(ValidatedLock::Satisfies(lock)) if !strict_requires_python => {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
@@ -891,7 +1219,13 @@ async fn do_lock(
                     .cloned()
                     .map(UnresolvedRequirementSpecification::from)
                     .collect(),
-                excludes.clone(),
+                excludes
+                    .iter()
+                    .cloned()
+                    .collect::<Excludes>()
+                    .with_provided(provided.iter().map(|requirement| {
+                        (requirement.name.clone(), provided_version(requirement))
+                    })),
                 source_trees,
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
@@ -925,15 +1259,33 @@ async fn do_lock(
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // Notify the user of any packages that don't support all Python versions included in
+            // the `requires-python`.
+            let diagnostics = resolution.requires_python_diagnostics();
+            if !diagnostics.is_empty() {
+                if strict_requires_python {
+                    return Err(ProjectError::UnsupportedRequiresPython(
+                        requires_python,
+                        diagnostics,
+                    ));
+                }
+                warn_user!(
+                    "{}",
+                    format_requires_python_diagnostics(&requires_python, &diagnostics)
+                );
+            }
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
                 constraints,
                 overrides,
                 excludes.clone(),
+                provided,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata.values().cloned(),
+                patches.values().cloned(),
             )
             .relative_to(target.install_path())?;
 
@@ -952,7 +1304,8 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
-                );
+                )
+                .with_marker_provenance(marker_provenance);
 
             if previous.as_ref().is_some_and(|previous| *previous == lock) {
                 Ok(LockResult::Unchanged(lock))
@@ -963,6 +1316,29 @@ async fn do_lock(
     }
 }
 
+/// Format the packages that don't support all Python versions included in the `requires-python`,
+/// along with the inferred upper bound.
+pub(super) fn format_requires_python_diagnostics(
+    requires_python: &RequiresPython,
+    diagnostics: &[RequiresPythonDiagnostic],
+) -> String {
+    let mut message = format!(
+        "The `requires-python` value (`{requires_python}`) includes Python versions that aren't supported by all dependencies:"
+    );
+    for diagnostic in diagnostics {
+        let _ = write!(message, "\n- {diagnostic}");
+    }
+    if let Some(specifiers) = infer_requires_python_upper_bound(requires_python, diagnostics) {
+        let _ = write!(
+            message,
+            "\n\n{}{} Consider adding an upper bound to the `requires-python` value (e.g., `{specifiers}`)",
+            "hint".bold().cyan(),
+            ":".bold(),
+        );
+    }
+    message
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, but its contents should be ignored.
@@ -990,11 +1366,14 @@ impl ValidatedLock {
         constraints: &[Requirement],
         overrides: &[Requirement],
         excludes: &[PackageName],
+        provided: &[Requirement],
         build_constraints: &[Requirement],
         conflicts: &Conflicts,
         environments: Option<&SupportedEnvironments>,
         required_environments: Option<&SupportedEnvironments>,
+        marker_provenance: bool,
         dependency_metadata: &DependencyMetadata,
+        patches: &Patches,
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
         index_locations: &IndexLocations,
@@ -1028,6 +1407,41 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if lock.yanked_policy() != options.yanked_policy {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in yanked policy: `{}` vs. `{}`",
+                lock.yanked_policy().cyan(),
+                options.yanked_policy.cyan()
+            );
+            return Ok(Self::Unusable(lock));
+        }
+        let lock_allow_yanked = lock.allow_yanked().iter().collect::<BTreeSet<_>>();
+        let options_allow_yanked = options.allow_yanked.iter().collect::<BTreeSet<_>>();
+        if lock_allow_yanked != options_allow_yanked {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in allowed yanked versions"
+            );
+            return Ok(Self::Unusable(lock));
+        }
+        let lock_known_incompatibilities = lock
+            .known_incompatibilities()
+            .iter()
+            .map(|incompatibility| (&incompatibility.package, &incompatibility.incompatible_with))
+            .collect::<BTreeSet<_>>();
+        let options_known_incompatibilities = options
+            .known_incompatibilities
+            .iter()
+            .map(|incompatibility| (&incompatibility.package, &incompatibility.incompatible_with))
+            .collect::<BTreeSet<_>>();
+        if lock_known_incompatibilities != options_known_incompatibilities {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in known incompatibilities"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         let lock_exclude_newer = lock.exclude_newer();
         let options_exclude_newer = &options.exclude_newer;
 
@@ -1157,6 +1571,16 @@ impl ValidatedLock {
             return Ok(Self::Versions(lock));
         }
 
+        // If marker provenance was enabled or disabled, re-resolve to record or discard it.
+        if marker_provenance != lock.has_marker_provenance() {
+            debug!(
+                "Resolving despite existing lockfile due to change in marker provenance: `{}` vs. `{}`",
+                lock.has_marker_provenance(),
+                marker_provenance
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the Requires-Python bound has changed, we have to perform a clean resolution, since
         // the set of `resolution-markers` may no longer cover the entire supported Python range.
         if lock.requires_python().range() != requires_python.range() {
@@ -1221,9 +1645,11 @@ impl ValidatedLock {
                 constraints,
                 overrides,
                 excludes,
+                provided,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
+                patches,
                 indexes,
                 interpreter.tags()?,
                 interpreter.markers(),
@@ -1320,6 +1746,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedProvided(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched provided dependencies:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MismatchedBuildConstraints(expected, actual) => {
                 debug!(
                     "Resolving despite existing lockfile due to mismatched build constraints:\n  Requested: {:?}\n  Existing: {:?}",
@@ -1341,6 +1774,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedPatches(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched patches:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MissingRoot(name) => {
                 debug!("Resolving despite existing lockfile due to missing root package: `{name}`");
                 Ok(Self::Preferable(lock))
@@ -1351,6 +1791,12 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedPinnedIndex(name, version, index) => {
+                debug!(
+                    "Resolving despite existing lockfile due to package not locked from its pinned index: `{name}` `{version}` (expected `{index}`)"
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MissingLocalIndex(name, version, index) => {
                 debug!(
                     "Resolving despite existing lockfile due to missing local index: `{name}` `{version}` from `{}`",
@@ -1420,9 +1866,9 @@ impl ValidatedLock {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct LockEventVersion<'lock> {
+pub(super) struct LockEventVersion<'lock> {
     /// The version of the package, or `None` if the package has a dynamic version.
-    version: Option<&'lock Version>,
+    pub(super) version: Option<&'lock Version>,
     /// The short Git SHA of the package, if it was installed from a Git repository.
     sha: Option<&'lock str>,
 }
@@ -1449,7 +1895,7 @@ impl std::fmt::Display for LockEventVersion<'_> {
 
 /// A modification to a lockfile.
 #[derive(Debug, Clone)]
-enum LockEvent<'lock> {
+pub(super) enum LockEvent<'lock> {
     Update(
         DryRun,
         PackageName,
@@ -1462,7 +1908,7 @@ enum LockEvent<'lock> {
 
 impl<'lock> LockEvent<'lock> {
     /// Detect the change events between an (optional) existing and updated lockfile.
-    fn detect_changes(
+    pub(super) fn detect_changes(
         existing_lock: Option<&'lock Lock>,
         new_lock: &'lock Lock,
         dry_run: DryRun,
@@ -1589,3 +2035,24 @@ impl std::fmt::Display for LockEvent<'_> {
         }
     }
 }
+
+/// Return the version at which a provided dependency is assumed to be installed.
+///
+/// Only exact (`==`) pins are used to constrain the resolution; for any other requirement, the
+/// dependency is excluded from the resolution without checking compatibility.
+fn provided_version(requirement: &Requirement) -> Option<Version> {
+    if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+        if let [specifier] = &**specifier {
+            if *specifier.operator() == Operator::Equal {
+                return Some(specifier.version().clone());
+            }
+        }
+        if !specifier.is_empty() {
+            warn_user_once!(
+                "Provided dependency `{requirement}` is not pinned to an exact version (e.g., `{}==1.0.0`); its version will not be checked during resolution",
+                requirement.name
+            );
+        }
+    }
+    None
+}
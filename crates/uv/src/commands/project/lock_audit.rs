@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_configuration::DryRun;
+use uv_distribution_types::Requirement;
+use uv_fs::Simplified;
+use uv_normalize::{GroupName, PackageName};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_resolver::Lock;
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::project::lock::{LockEvent, LockEventVersion};
+use crate::printer::Printer;
+
+/// Explain the changes between two lockfiles.
+pub(crate) async fn lock_audit(
+    old: &Path,
+    new: &Path,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::LOCK_AUDIT) {
+        warn_user!(
+            "`uv lock --audit` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::LOCK_AUDIT
+        );
+    }
+
+    let old_lock = read_lock(old).await?;
+    let new_lock = read_lock(new).await?;
+
+    let old_index = LockIndex::from_lock(&old_lock);
+    let new_index = LockIndex::from_lock(&new_lock);
+
+    let mut changed = false;
+    for event in LockEvent::detect_changes(Some(&old_lock), &new_lock, DryRun::Disabled) {
+        changed = true;
+        writeln!(printer.stdout(), "{event}")?;
+
+        let (name, previous, current) = match &event {
+            LockEvent::Update(_, name, previous, current) => (name, Some(previous), Some(current)),
+            LockEvent::Add(_, name, current) => (name, None, Some(current)),
+            LockEvent::Remove(_, name, previous) => (name, Some(previous), None),
+        };
+
+        let mut causes = Vec::new();
+
+        // Identify any requirements, constraints, or overrides on the package that changed.
+        let empty = BTreeMap::new();
+        let old_requirements = old_index.requirements.get(name).unwrap_or(&empty);
+        let new_requirements = new_index.requirements.get(name).unwrap_or(&empty);
+        for origin in old_requirements
+            .keys()
+            .chain(new_requirements.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let before = old_requirements.get(origin);
+            let after = new_requirements.get(origin);
+            if before == after {
+                continue;
+            }
+            causes.push(match (before, after) {
+                (None, Some(after)) => format!("added {} to {origin}", format_requirements(after)),
+                (Some(before), None) => {
+                    format!("removed {} from {origin}", format_requirements(before))
+                }
+                (Some(before), Some(after)) => format!(
+                    "changed {} to {} in {origin}",
+                    format_requirements(before),
+                    format_requirements(after)
+                ),
+                (None, None) => unreachable!(),
+            });
+        }
+
+        // Identify any changes to the packages that depend on the package.
+        let empty = BTreeMap::new();
+        let old_dependents = old_index.dependents.get(name).unwrap_or(&empty);
+        let new_dependents = new_index.dependents.get(name).unwrap_or(&empty);
+        for (dependent, versions) in new_dependents {
+            match old_dependents.get(dependent) {
+                None => causes.push(format!(
+                    "new dependency of `{dependent}` {}",
+                    format_versions(versions)
+                )),
+                Some(previous) if previous != versions => causes.push(format!(
+                    "required by `{dependent}`, which was updated from {} to {}",
+                    format_versions(previous),
+                    format_versions(versions)
+                )),
+                Some(_) => {}
+            }
+        }
+        for dependent in old_dependents.keys() {
+            if new_dependents.contains_key(dependent) {
+                continue;
+            }
+            if new_index.versions.contains_key(dependent) {
+                causes.push(format!("no longer required by `{dependent}`"));
+            } else {
+                causes.push(format!("`{dependent}` was removed"));
+            }
+        }
+
+        // Fall back to changes in the resolver options, or in the available versions.
+        if causes.is_empty() && old_lock.exclude_newer() != new_lock.exclude_newer() {
+            causes.push("the `exclude-newer` cutoff changed".to_string());
+        }
+        if causes.is_empty() {
+            let previous = previous
+                .and_then(|versions| versions.iter().filter_map(|version| version.version).max());
+            let current = current
+                .and_then(|versions| versions.iter().filter_map(|version| version.version).max());
+            match (previous, current) {
+                (Some(previous), Some(current)) if current > previous => {
+                    causes.push("a newer version became available".to_string());
+                }
+                (Some(previous), Some(current)) if current < previous => {
+                    causes.push(
+                        "the previous version is no longer available (e.g., it was yanked)"
+                            .to_string(),
+                    );
+                }
+                _ => {
+                    causes.push("no corresponding change in requirements was found".to_string());
+                }
+            }
+        }
+
+        for cause in causes {
+            writeln!(printer.stdout(), "  {} {cause}", "-".dimmed())?;
+        }
+    }
+
+    if !changed {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No lockfile changes detected".bold()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read and parse the lockfile at the given path.
+async fn read_lock(path: &Path) -> Result<Lock> {
+    let encoded = fs_err::tokio::read_to_string(path).await?;
+    toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse lockfile at: `{}`", path.user_display()))
+}
+
+/// The origin of a requirement on a package in a lockfile.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Origin<'lock> {
+    /// The dependencies of a workspace member.
+    Member(&'lock PackageName),
+    /// A dependency group of a workspace member.
+    MemberGroup(&'lock PackageName, &'lock GroupName),
+    /// The requirements of the project, exclusive of the workspace members.
+    Requirements,
+    /// A dependency group of the project, exclusive of the workspace members.
+    Group(&'lock GroupName),
+    /// The constraints provided to the resolver.
+    Constraints,
+    /// The overrides provided to the resolver.
+    Overrides,
+}
+
+impl std::fmt::Display for Origin<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Member(name) => write!(f, "the dependencies of `{name}`"),
+            Self::MemberGroup(name, group) => {
+                write!(f, "the `{group}` dependency group of `{name}`")
+            }
+            Self::Requirements => write!(f, "the project requirements"),
+            Self::Group(group) => write!(f, "the `{group}` dependency group"),
+            Self::Constraints => write!(f, "the constraints"),
+            Self::Overrides => write!(f, "the overrides"),
+        }
+    }
+}
+
+/// An index over the requirements and dependency edges in a lockfile.
+#[derive(Debug, Default)]
+struct LockIndex<'lock> {
+    /// The locked versions of each package.
+    versions: BTreeMap<&'lock PackageName, BTreeSet<LockEventVersion<'lock>>>,
+    /// The requirements on each package, by origin.
+    requirements: BTreeMap<&'lock PackageName, BTreeMap<Origin<'lock>, BTreeSet<String>>>,
+    /// The locked versions of the packages that depend on each package.
+    dependents: BTreeMap<
+        &'lock PackageName,
+        BTreeMap<&'lock PackageName, BTreeSet<LockEventVersion<'lock>>>,
+    >,
+}
+
+impl<'lock> LockIndex<'lock> {
+    fn from_lock(lock: &'lock Lock) -> Self {
+        let mut index = Self::default();
+
+        for package in lock.packages() {
+            index
+                .versions
+                .entry(package.name())
+                .or_default()
+                .insert(LockEventVersion::from(package));
+
+            for requirement in package.requires_dist() {
+                index.add_requirement(Origin::Member(package.name()), requirement);
+            }
+            for (group, requirements) in package.dependency_groups() {
+                for requirement in requirements {
+                    index.add_requirement(Origin::MemberGroup(package.name(), group), requirement);
+                }
+            }
+
+            // Include the dependencies of the package across its extras and dependency groups.
+            for dependency in package
+                .dependencies()
+                .iter()
+                .chain(package.optional_dependencies().values().flatten())
+                .chain(package.resolved_dependency_groups().values().flatten())
+            {
+                index
+                    .dependents
+                    .entry(dependency.package_name())
+                    .or_default()
+                    .entry(package.name())
+                    .or_default()
+                    .insert(LockEventVersion::from(package));
+            }
+        }
+
+        for requirement in lock.requirements() {
+            index.add_requirement(Origin::Requirements, requirement);
+        }
+        for (group, requirements) in lock.dependency_groups() {
+            for requirement in requirements {
+                index.add_requirement(Origin::Group(group), requirement);
+            }
+        }
+        for requirement in lock.constraints() {
+            index.add_requirement(Origin::Constraints, requirement);
+        }
+        for requirement in lock.overrides() {
+            index.add_requirement(Origin::Overrides, requirement);
+        }
+
+        index
+    }
+
+    fn add_requirement(&mut self, origin: Origin<'lock>, requirement: &'lock Requirement) {
+        self.requirements
+            .entry(&requirement.name)
+            .or_default()
+            .entry(origin)
+            .or_default()
+            .insert(requirement.to_string());
+    }
+}
+
+fn format_requirements(requirements: &BTreeSet<String>) -> String {
+    requirements
+        .iter()
+        .map(|requirement| format!("`{requirement}`"))
+        .join(", ")
+}
+
+fn format_versions(versions: &BTreeSet<LockEventVersion>) -> String {
+    versions.iter().join(", ")
+}
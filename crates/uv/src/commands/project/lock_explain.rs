@@ -0,0 +1,178 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_distribution_types::SimplifiedMarkerTree;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_resolver::{Lock, Package};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::ProjectError;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+
+/// Explain under which environments each locked version of a package is installed, and why.
+pub(crate) async fn lock_explain(
+    project_dir: &Path,
+    name: &PackageName,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::LOCK_EXPLAIN) {
+        warn_user!(
+            "`uv lock --explain` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::LOCK_EXPLAIN
+        );
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache).await?;
+    let Some(lock) = LockTarget::Workspace(&workspace).read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    let packages = lock
+        .packages()
+        .iter()
+        .filter(|package| package.name() == name)
+        .collect::<Vec<_>>();
+    if packages.is_empty() {
+        bail!("Package `{}` is not present in the lockfile", name.cyan());
+    }
+
+    if !lock.has_marker_provenance() {
+        bail!(
+            "The lockfile does not include marker provenance\n\n{}{} Set `{}` in the `[tool.uv]` section of your `pyproject.toml`, then run `{}` to record it",
+            "hint".bold().cyan(),
+            ":".bold(),
+            "marker-provenance = true".green(),
+            "uv lock".green(),
+        );
+    }
+
+    for (index, package) in packages.into_iter().enumerate() {
+        if index > 0 {
+            writeln!(printer.stdout())?;
+        }
+        explain_package(&lock, package, printer)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Write the provenance of a single locked package.
+fn explain_package(lock: &Lock, package: &Package, printer: Printer) -> Result<()> {
+    match package.version() {
+        Some(version) => writeln!(
+            printer.stdout(),
+            "{}",
+            format!("{}=={version}", package.name()).bold()
+        )?,
+        None => writeln!(printer.stdout(), "{}", package.name().bold())?,
+    }
+
+    // If no provenance was recorded, the package is installed in all environments, outside of any
+    // fork.
+    let (marker, forks) = package
+        .marker_provenance()
+        .map(|provenance| (provenance.marker(), provenance.forks()))
+        .unwrap_or_default();
+
+    writeln!(
+        printer.stdout(),
+        "  {} {}",
+        "Installed when:".bold(),
+        format_marker(marker)
+    )?;
+
+    if !forks.is_empty() {
+        writeln!(printer.stdout(), "  {}", "Selected in forks:".bold())?;
+        for fork in forks {
+            writeln!(
+                printer.stdout(),
+                "    {} {}",
+                "-".dimmed(),
+                format_marker(SimplifiedMarkerTree::new(
+                    lock.requires_python(),
+                    fork.pep508()
+                ))
+            )?;
+        }
+    }
+
+    // Identify the packages that depend on this version, along with the dependency markers.
+    let mut dependents = Vec::new();
+    for dependent in lock.packages() {
+        let edges =
+            dependent
+                .dependencies()
+                .iter()
+                .map(|dependency| (None, dependency))
+                .chain(dependent.optional_dependencies().iter().flat_map(
+                    |(extra, dependencies)| {
+                        dependencies
+                            .iter()
+                            .map(move |dependency| (Some(format!("[{extra}]")), dependency))
+                    },
+                ))
+                .chain(dependent.resolved_dependency_groups().iter().flat_map(
+                    |(group, dependencies)| {
+                        dependencies
+                            .iter()
+                            .map(move |dependency| (Some(format!(" ({group})")), dependency))
+                    },
+                ));
+        for (qualifier, dependency) in edges {
+            if dependency.package_name() != package.name() {
+                continue;
+            }
+            if dependency
+                .package_version()
+                .is_some_and(|version| Some(version) != package.version())
+            {
+                continue;
+            }
+            let mut label = match dependent.version() {
+                Some(version) => format!("{}=={version}", dependent.name()),
+                None => dependent.name().to_string(),
+            };
+            if let Some(qualifier) = qualifier {
+                label.push_str(&qualifier);
+            }
+            dependents.push((label, dependency.simplified_marker()));
+        }
+    }
+
+    if dependents.is_empty() {
+        writeln!(printer.stdout(), "  {}", "Required by the project".bold())?;
+    } else {
+        writeln!(printer.stdout(), "  {}", "Required by:".bold())?;
+        for (label, marker) in dependents {
+            match marker.try_to_string() {
+                Some(marker) => writeln!(
+                    printer.stdout(),
+                    "    {} {label} {}",
+                    "-".dimmed(),
+                    format!("; {marker}").dimmed()
+                )?,
+                None => writeln!(printer.stdout(), "    {} {label}", "-".dimmed())?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Format a simplified marker, e.g., `sys_platform == 'linux'`, or `all environments`.
+fn format_marker(marker: SimplifiedMarkerTree) -> String {
+    marker
+        .try_to_string()
+        .map(|marker| marker.cyan().to_string())
+        .unwrap_or_else(|| "all environments".to_string())
+}
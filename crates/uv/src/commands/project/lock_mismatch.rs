@@ -0,0 +1,495 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_normalize::{GroupName, PackageName};
+use uv_pep440::VersionSpecifiers;
+use uv_pep508::MarkerTree;
+use uv_resolver::Lock;
+
+use crate::commands::project::ProjectError;
+use crate::printer::Printer;
+use crate::settings::LockCheckSource;
+
+/// The kind of resolver input that differs between the lockfile and the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum LockInput {
+    /// The `requires-python` of the project.
+    RequiresPython,
+    /// The set of workspace members.
+    Members,
+    /// The requirements of a workspace member, or of the project.
+    Requirements,
+    /// The extras provided by a workspace member, or by the project.
+    Extras,
+    /// The set of dependency groups declared by a workspace member, or by the project.
+    DependencyGroups,
+    /// The requirements in a dependency group.
+    DependencyGroup,
+    /// The sources of the requirements of a workspace member, or of the project.
+    Sources,
+    /// The constraints provided to the resolver.
+    Constraints,
+    /// The overrides provided to the resolver.
+    Overrides,
+    /// The excludes provided to the resolver.
+    Excludes,
+    /// The dependencies provided by the environment.
+    Provided,
+    /// The environments for which the lockfile is resolved.
+    SupportedEnvironments,
+    /// The environments for which the lockfile must include a wheel.
+    RequiredEnvironments,
+}
+
+/// A resolver input that differs between the lockfile and the project.
+#[derive(Debug, Serialize)]
+struct LockInputChange {
+    /// The kind of input that changed.
+    input: LockInput,
+    /// The workspace member that declares the input, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<PackageName>,
+    /// The dependency group that declares the input, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+    /// The values recorded in the lockfile that are no longer present.
+    old: Vec<String>,
+    /// The values in the project that aren't recorded in the lockfile.
+    new: Vec<String>,
+}
+
+impl std::fmt::Display for LockInputChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.input == LockInput::RequiresPython {
+            return write!(
+                f,
+                "`requires-python` changed from {} to {}",
+                format_values(&self.old),
+                format_values(&self.new)
+            );
+        }
+
+        let owner = match (&self.package, &self.group) {
+            (Some(package), Some(group)) => {
+                format!("the `{group}` dependency group of `{package}`")
+            }
+            (None, Some(group)) => format!("the `{group}` dependency group"),
+            (Some(package), None) => format!("`{package}`"),
+            (None, None) => "the project".to_string(),
+        };
+        match self.input {
+            LockInput::RequiresPython | LockInput::Requirements | LockInput::DependencyGroup => {
+                write!(f, "The requirements of {owner} changed")?;
+            }
+            LockInput::Extras => write!(f, "The extras of {owner} changed")?,
+            LockInput::DependencyGroups => write!(f, "The dependency groups of {owner} changed")?,
+            LockInput::Sources => write!(f, "The sources of {owner} changed")?,
+            LockInput::Members => write!(f, "The workspace members changed")?,
+            LockInput::Constraints => write!(f, "The constraints changed")?,
+            LockInput::Overrides => write!(f, "The overrides changed")?,
+            LockInput::Excludes => write!(f, "The excludes changed")?,
+            LockInput::Provided => write!(f, "The provided dependencies changed")?,
+            LockInput::SupportedEnvironments => write!(f, "The supported environments changed")?,
+            LockInput::RequiredEnvironments => write!(f, "The required environments changed")?,
+        }
+
+        let mut separator = ":";
+        if !self.old.is_empty() {
+            write!(f, "{separator} removed {}", format_values(&self.old))?;
+            separator = ";";
+        }
+        if !self.new.is_empty() {
+            write!(f, "{separator} added {}", format_values(&self.new))?;
+        }
+        Ok(())
+    }
+}
+
+/// A report of the resolver inputs that differ between an existing lockfile and the lockfile
+/// that would be written for the project.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct LockMismatchReport {
+    changes: Vec<LockInputChange>,
+}
+
+impl LockMismatchReport {
+    /// Compare the inputs recorded in the existing lockfile against those in the updated lockfile.
+    pub(crate) fn from_locks(existing: Option<&Lock>, updated: &Lock) -> Self {
+        let mut report = Self::default();
+        let Some(existing) = existing else {
+            return report;
+        };
+
+        if existing.requires_python() != updated.requires_python() {
+            report.changes.push(LockInputChange {
+                input: LockInput::RequiresPython,
+                package: None,
+                group: None,
+                old: vec![existing.requires_python().to_string()],
+                new: vec![updated.requires_python().to_string()],
+            });
+        }
+
+        report.push_difference(
+            LockInput::SupportedEnvironments,
+            None,
+            None,
+            format_markers(existing.supported_environments()),
+            format_markers(updated.supported_environments()),
+        );
+        report.push_difference(
+            LockInput::RequiredEnvironments,
+            None,
+            None,
+            format_markers(existing.required_environments()),
+            format_markers(updated.required_environments()),
+        );
+
+        report.push_difference(
+            LockInput::Members,
+            None,
+            None,
+            existing.members().iter().map(ToString::to_string).collect(),
+            updated.members().iter().map(ToString::to_string).collect(),
+        );
+
+        // Compare the requirements of each workspace member (or local package), along with the
+        // requirements of the project itself.
+        let empty = BTreeSet::new();
+        let existing_packages = PackageInputs::from_lock(existing);
+        let updated_packages = PackageInputs::from_lock(updated);
+        for package in existing_packages
+            .requirements
+            .keys()
+            .chain(updated_packages.requirements.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let old = existing_packages
+                .requirements
+                .get(package)
+                .unwrap_or(&empty);
+            let new = updated_packages.requirements.get(package).unwrap_or(&empty);
+            report.push_requirements(LockInput::Requirements, package.cloned(), None, old, new);
+        }
+
+        for package in existing_packages
+            .extras
+            .keys()
+            .chain(updated_packages.extras.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            report.push_difference(
+                LockInput::Extras,
+                Some((*package).clone()),
+                None,
+                existing_packages
+                    .extras
+                    .get(package)
+                    .cloned()
+                    .unwrap_or_default(),
+                updated_packages
+                    .extras
+                    .get(package)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+
+        // Compare the set of dependency groups declared by each package, then the requirements of
+        // the groups that are present on both sides.
+        for package in existing_packages
+            .groups
+            .keys()
+            .chain(updated_packages.groups.keys())
+            .map(|(package, _)| *package)
+            .collect::<BTreeSet<_>>()
+        {
+            let old = existing_packages.group_names(package);
+            let new = updated_packages.group_names(package);
+            report.push_difference(
+                LockInput::DependencyGroups,
+                package.cloned(),
+                None,
+                old.iter().map(ToString::to_string).collect(),
+                new.iter().map(ToString::to_string).collect(),
+            );
+            for group in old.intersection(&new) {
+                let key = (package, *group);
+                report.push_requirements(
+                    LockInput::DependencyGroup,
+                    package.cloned(),
+                    Some((*group).clone()),
+                    &existing_packages.groups[&key],
+                    &updated_packages.groups[&key],
+                );
+            }
+        }
+
+        report.push_difference(
+            LockInput::Constraints,
+            None,
+            None,
+            existing
+                .constraints()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            updated
+                .constraints()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        );
+        report.push_difference(
+            LockInput::Overrides,
+            None,
+            None,
+            existing
+                .overrides()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            updated
+                .overrides()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        );
+        report.push_difference(
+            LockInput::Excludes,
+            None,
+            None,
+            existing
+                .excludes()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            updated.excludes().iter().map(ToString::to_string).collect(),
+        );
+        report.push_difference(
+            LockInput::Provided,
+            None,
+            None,
+            existing
+                .provided()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            updated.provided().iter().map(ToString::to_string).collect(),
+        );
+
+        report
+    }
+
+    /// Record a change in a set of requirements, separating changes to the requirements
+    /// themselves from changes to their sources.
+    fn push_requirements(
+        &mut self,
+        input: LockInput,
+        package: Option<PackageName>,
+        group: Option<GroupName>,
+        old: &BTreeSet<&Requirement>,
+        new: &BTreeSet<&Requirement>,
+    ) {
+        self.push_difference(
+            input,
+            package.clone(),
+            group.clone(),
+            old.iter()
+                .map(|requirement| format_specifier(requirement))
+                .collect(),
+            new.iter()
+                .map(|requirement| format_specifier(requirement))
+                .collect(),
+        );
+
+        // Only report source changes for dependencies that are present on both sides; added or
+        // removed dependencies are reported as requirement changes.
+        let old_sources = sources(old);
+        let new_sources = sources(new);
+        let mut old_changed = BTreeSet::new();
+        let mut new_changed = BTreeSet::new();
+        for (name, old_source) in &old_sources {
+            let Some(new_source) = new_sources.get(name) else {
+                continue;
+            };
+            if old_source != new_source {
+                old_changed.extend(old_source.iter().map(|source| format!("{name} @ {source}")));
+                new_changed.extend(new_source.iter().map(|source| format!("{name} @ {source}")));
+            }
+        }
+        self.push_difference(LockInput::Sources, package, group, old_changed, new_changed);
+    }
+
+    /// Record a change in a set of values, if the sets differ.
+    fn push_difference(
+        &mut self,
+        input: LockInput,
+        package: Option<PackageName>,
+        group: Option<GroupName>,
+        old: BTreeSet<String>,
+        new: BTreeSet<String>,
+    ) {
+        if old == new {
+            return;
+        }
+        self.changes.push(LockInputChange {
+            input,
+            package,
+            group,
+            old: old.difference(&new).cloned().collect(),
+            new: new.difference(&old).cloned().collect(),
+        });
+    }
+
+    /// Format the report for display, one line per changed input.
+    pub(crate) fn to_human_readable_string(&self) -> String {
+        let mut output = String::new();
+        for change in &self.changes {
+            writeln!(output, "  {} {change}", "-".dimmed()).expect("writing to a string");
+        }
+        output
+    }
+}
+
+/// Write the error for a lockfile that needs to be updated, along with the inputs that changed.
+pub(crate) fn write_lock_mismatch(
+    printer: Printer,
+    existing: Option<Box<Lock>>,
+    updated: Box<Lock>,
+    lock_source: LockCheckSource,
+) -> std::fmt::Result {
+    let report = LockMismatchReport::from_locks(existing.as_deref(), &updated);
+    writeln!(
+        printer.stderr(),
+        "{}",
+        ProjectError::LockMismatch(existing, updated, lock_source)
+            .to_string()
+            .bold()
+    )?;
+    write!(printer.stderr(), "{}", report.to_human_readable_string())
+}
+
+/// The requirements, extras, and dependency groups declared by the packages in a lockfile, keyed
+/// by the declaring package.
+///
+/// Requirements declared by the project itself, rather than a workspace member (e.g., in a
+/// workspace with a non-project root), are keyed by `None`.
+#[derive(Debug, Default)]
+struct PackageInputs<'lock> {
+    requirements: BTreeMap<Option<&'lock PackageName>, BTreeSet<&'lock Requirement>>,
+    groups: BTreeMap<(Option<&'lock PackageName>, &'lock GroupName), BTreeSet<&'lock Requirement>>,
+    extras: BTreeMap<&'lock PackageName, BTreeSet<String>>,
+}
+
+impl<'lock> PackageInputs<'lock> {
+    fn from_lock(lock: &'lock Lock) -> Self {
+        let mut inputs = Self::default();
+        for package in lock.packages() {
+            if !package.requires_dist().is_empty() {
+                inputs
+                    .requirements
+                    .entry(Some(package.name()))
+                    .or_default()
+                    .extend(package.requires_dist());
+            }
+            if !package.provides_extras().is_empty() {
+                inputs.extras.insert(
+                    package.name(),
+                    package
+                        .provides_extras()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                );
+            }
+            for (group, requirements) in package.dependency_groups() {
+                inputs
+                    .groups
+                    .entry((Some(package.name()), group))
+                    .or_default()
+                    .extend(requirements);
+            }
+        }
+        if !lock.requirements().is_empty() {
+            inputs
+                .requirements
+                .entry(None)
+                .or_default()
+                .extend(lock.requirements());
+        }
+        for (group, requirements) in lock.dependency_groups() {
+            inputs
+                .groups
+                .entry((None, group))
+                .or_default()
+                .extend(requirements);
+        }
+        inputs
+    }
+
+    /// Return the names of the dependency groups declared by the given package.
+    fn group_names(&self, package: Option<&'lock PackageName>) -> BTreeSet<&'lock GroupName> {
+        self.groups
+            .keys()
+            .filter(|(name, _)| *name == package)
+            .map(|(_, group)| *group)
+            .collect()
+    }
+}
+
+/// Format a [`Requirement`] without its source, e.g., `anyio>=4 ; sys_platform == 'linux'`.
+fn format_specifier(requirement: &Requirement) -> String {
+    let specifier = match &requirement.source {
+        RequirementSource::Registry { specifier, .. } => specifier.clone(),
+        RequirementSource::Url { .. }
+        | RequirementSource::Git { .. }
+        | RequirementSource::Path { .. }
+        | RequirementSource::Directory { .. } => VersionSpecifiers::empty(),
+    };
+    Requirement {
+        source: RequirementSource::Registry {
+            specifier,
+            index: None,
+            conflict: None,
+        },
+        ..requirement.clone()
+    }
+    .to_string()
+}
+
+/// Collect the explicit sources of a set of requirements, by package name.
+fn sources<'a>(
+    requirements: &BTreeSet<&'a Requirement>,
+) -> BTreeMap<&'a PackageName, BTreeSet<String>> {
+    let mut sources: BTreeMap<&PackageName, BTreeSet<String>> = BTreeMap::new();
+    for requirement in requirements {
+        let source = match &requirement.source {
+            RequirementSource::Registry { index: None, .. } => "registry".to_string(),
+            RequirementSource::Registry {
+                index: Some(index), ..
+            } => index.url.to_string(),
+            source => source.to_string().trim().to_string(),
+        };
+        sources.entry(&requirement.name).or_default().insert(source);
+    }
+    sources
+}
+
+/// Format a set of environment markers, omitting any that are always true.
+fn format_markers(markers: &[MarkerTree]) -> BTreeSet<String> {
+    markers
+        .iter()
+        .filter_map(|marker| marker.try_to_string())
+        .collect()
+}
+
+fn format_values(values: &[String]) -> String {
+    values.iter().map(|value| format!("`{value}`")).join(", ")
+}
@@ -5,16 +5,17 @@ use itertools::Either;
 
 use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
 use uv_distribution::LoweredRequirement;
-use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_distribution_types::{Index, IndexLocations, Patches, Requirement, RequiresPython};
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
 use uv_scripts::Pep723Script;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
+use uv_workspace::pyproject::PackageUpgradePolicy;
 use uv_workspace::{Editability, Workspace, WorkspaceMember};
 
-use crate::commands::project::{ProjectError, find_requires_python};
+use crate::commands::project::{ProjectError, find_requires_python, workspace_patches};
 
 /// A target that can be resolved into a lockfile.
 #[derive(Debug, Copy, Clone)]
@@ -79,6 +80,23 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the set of dependencies provided by the environment for the [`LockTarget`].
+    pub(crate) fn provided_dependencies(self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
+        match self {
+            Self::Workspace(workspace) => workspace.provided_dependencies(),
+            Self::Script(script) => script
+                .metadata
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.provided_dependencies.as_ref())
+                .into_iter()
+                .flatten()
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Returns the set of constraints for the [`LockTarget`].
     pub(crate) fn constraints(self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         match self {
@@ -113,6 +131,16 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the set of patches to apply to source distributions for the [`LockTarget`].
+    ///
+    /// Patches are not supported for PEP 723 scripts.
+    pub(crate) fn patches(self) -> Result<Patches, std::io::Error> {
+        match self {
+            Self::Workspace(workspace) => workspace_patches(workspace),
+            Self::Script(_) => Ok(Patches::default()),
+        }
+    }
+
     /// Return the dependency groups that are attached to the target directly, as opposed to being
     /// attached to any members within the target.
     pub(crate) fn dependency_groups(
@@ -205,6 +233,24 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the upgrade policies for the [`LockTarget`], keyed by package name.
+    ///
+    /// Upgrade policies are not supported for PEP 723 scripts.
+    pub(crate) fn upgrade_policies(self) -> BTreeMap<PackageName, PackageUpgradePolicy> {
+        match self {
+            Self::Workspace(workspace) => workspace.upgrade_policies(),
+            Self::Script(_) => BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if the lockfile for the [`LockTarget`] should record marker provenance.
+    pub(crate) fn marker_provenance(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.marker_provenance(),
+            Self::Script(_) => false,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Conflicts {
         match self {
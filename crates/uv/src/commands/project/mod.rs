@@ -12,14 +12,14 @@ use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::cache_digest;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Reinstall,
-    TargetTriple, Upgrade,
+    BuildOptions, Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun,
+    ExtrasSpecification, NoBinary, NoBuild, Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
 use uv_distribution_types::{
-    ExtraBuildRequirement, ExtraBuildRequires, Index, Requirement, RequiresPython, Resolution,
-    UnresolvedRequirement, UnresolvedRequirementSpecification,
+    ExtraBuildRequirement, ExtraBuildRequires, Index, Patch, Patches, Requirement, RequiresPython,
+    Resolution, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::{CWD, LockedFile, Simplified};
 use uv_git::ResolvedRepositoryReference;
@@ -28,7 +28,9 @@ use uv_normalize::{DEV_DEPENDENCIES, DefaultGroups, ExtraName, GroupName, Packag
 use uv_pep440::{TildeVersionSpecifier, Version, VersionSpecifiers};
 use uv_pep508::MarkerTreeContents;
 use uv_preview::{Preview, PreviewFeatures};
-use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts};
+use uv_pypi_types::{
+    ConflictItem, ConflictKind, ConflictSet, Conflicts, HashAlgorithm, HashDigest,
+};
 use uv_python::{
     EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
@@ -38,7 +40,7 @@ use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
     FlatIndex, Installable, Lock, OptionsBuilder, Preference, PythonRequirement,
-    ResolverEnvironment, ResolverOutput,
+    RequiresPythonDiagnostic, ResolverEnvironment, ResolverOutput,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -65,14 +67,24 @@ pub(crate) mod add;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod format;
+mod imports;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
+pub(crate) mod lock_audit;
+pub(crate) mod lock_explain;
+pub(crate) mod lock_mismatch;
 mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
+mod run_stamp;
+mod search;
+pub(crate) mod serve;
+pub(crate) mod shell;
 pub(crate) mod sync;
+pub(crate) mod test;
 pub(crate) mod tree;
+pub(crate) mod verify;
 pub(crate) mod version;
 
 #[derive(thiserror::Error, Debug)]
@@ -110,6 +122,16 @@ pub(crate) enum ProjectError {
     )]
     LockedPythonIncompatibility(Version, RequiresPython),
 
+    #[error(
+        "`{0}` is declared in `provided-dependencies`, but is not installed in the environment"
+    )]
+    MissingProvidedDependency(Box<Requirement>),
+
+    #[error(
+        "`{0}` is declared in `provided-dependencies`, but the environment provides version {1}"
+    )]
+    IncompatibleProvidedDependency(Box<Requirement>, Version),
+
     #[error(
         "The current Python platform is not compatible with the lockfile's supported environments: {0}"
     )]
@@ -192,6 +214,9 @@ pub(crate) enum ProjectError {
     )]
     DisjointRequiresPython(BTreeMap<(PackageName, Option<GroupName>), VersionSpecifiers>),
 
+    #[error("{}", lock::format_requires_python_diagnostics(_0, _1))]
+    UnsupportedRequiresPython(RequiresPython, Vec<RequiresPythonDiagnostic>),
+
     #[error("Environment marker is empty")]
     EmptyEnvironment,
 
@@ -717,6 +742,7 @@ impl ScriptInterpreter {
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -996,6 +1022,7 @@ impl ProjectInterpreter {
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -1705,6 +1732,12 @@ pub(crate) async fn resolve_names(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy: _,
+                yanked_policy: _,
+                allow_yanked: _,
+                known_incompatibilities: _,
+                max_backtracks: _,
+                resolution_timeout: _,
+                prefer_smaller_artifacts: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -1718,6 +1751,8 @@ pub(crate) async fn resolve_names(
                 upgrade: _,
             },
         compile_bytecode: _,
+        script_policy: _,
+        wheel_checks: _,
         reinstall: _,
     } = settings;
 
@@ -1861,6 +1896,12 @@ pub(crate) async fn resolve_environment(
         resolution,
         prerelease,
         fork_strategy,
+        yanked_policy,
+        allow_yanked,
+        known_incompatibilities,
+        max_backtracks,
+        resolution_timeout,
+        prefer_smaller_artifacts,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -1918,6 +1959,12 @@ pub(crate) async fn resolve_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .yanked_policy(*yanked_policy)
+        .allow_yanked(allow_yanked.clone())
+        .known_incompatibilities(known_incompatibilities.clone())
+        .max_backtracks(*max_backtracks)
+        .resolution_timeout(*resolution_timeout)
+        .prefer_smaller_artifacts(*prefer_smaller_artifacts)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2000,7 +2047,7 @@ pub(crate) async fn resolve_environment(
         requirements,
         constraints,
         overrides,
-        excludes,
+        excludes.into_iter().collect(),
         source_trees,
         project,
         BTreeSet::default(),
@@ -2057,6 +2104,8 @@ pub(crate) async fn sync_environment(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        script_policy,
+        wheel_checks,
         reinstall,
         build_options,
         sources,
@@ -2143,6 +2192,8 @@ pub(crate) async fn sync_environment(
         reinstall,
         build_options,
         link_mode,
+        script_policy,
+        wheel_checks,
         compile_bytecode,
         &hasher,
         tags,
@@ -2214,6 +2265,12 @@ pub(crate) async fn update_environment(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy,
+                yanked_policy,
+                allow_yanked,
+                known_incompatibilities,
+                max_backtracks,
+                resolution_timeout,
+                prefer_smaller_artifacts,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2227,6 +2284,8 @@ pub(crate) async fn update_environment(
                 upgrade,
             },
         compile_bytecode,
+        script_policy,
+        wheel_checks,
         reinstall,
     } = settings;
 
@@ -2266,6 +2325,8 @@ pub(crate) async fn update_environment(
             config_settings_package,
             &extra_build_requires,
             extra_build_variables,
+            &Patches::default(),
+            &HashStrategy::default(),
         )? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
@@ -2315,6 +2376,12 @@ pub(crate) async fn update_environment(
         .resolution_mode(*resolution)
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .yanked_policy(*yanked_policy)
+        .allow_yanked(allow_yanked.clone())
+        .known_incompatibilities(known_incompatibilities.clone())
+        .max_backtracks(*max_backtracks)
+        .resolution_timeout(*resolution_timeout)
+        .prefer_smaller_artifacts(*prefer_smaller_artifacts)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2371,7 +2438,7 @@ pub(crate) async fn update_environment(
         requirements,
         constraints,
         overrides,
-        excludes,
+        excludes.into_iter().collect(),
         source_trees,
         project,
         BTreeSet::default(),
@@ -2411,6 +2478,8 @@ pub(crate) async fn update_environment(
         reinstall,
         build_options,
         *link_mode,
+        *script_policy,
+        *wheel_checks,
         *compile_bytecode,
         &hasher,
         &tags,
@@ -2480,6 +2549,7 @@ pub(crate) async fn init_script_python_requirement(
         Some(reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
@@ -2691,6 +2761,40 @@ pub(crate) fn script_extra_build_requires(
     ))
 }
 
+/// Read the patches declared in `tool.uv.patches`, relative to the workspace root.
+pub(crate) fn workspace_patches(workspace: &Workspace) -> Result<Patches, std::io::Error> {
+    let mut entries = Vec::new();
+    for (name, patches) in workspace.patches() {
+        for patch in patches {
+            let path = workspace.install_path().join(patch.path);
+            let contents = fs_err::read(&path)?;
+            let mut hasher = uv_extract::hash::Hasher::from(HashAlgorithm::Sha256);
+            hasher.update(&contents);
+            entries.push(Patch {
+                name: name.clone(),
+                version: patch.version,
+                path,
+                hash: HashDigest::from(hasher),
+            });
+        }
+    }
+    Ok(Patches::from_entries(entries))
+}
+
+/// Disable binary distributions for any patched packages, which must be built from source.
+pub(crate) fn patched_build_options(
+    build_options: &BuildOptions,
+    patches: &Patches,
+) -> BuildOptions {
+    if patches.is_empty() {
+        return build_options.clone();
+    }
+    build_options.clone().combine(
+        NoBinary::Packages(patches.names().cloned().collect()),
+        NoBuild::None,
+    )
+}
+
 /// Warn if the user provides (e.g.) an `--index-url` in a requirements file.
 fn warn_on_requirements_txt_setting(spec: &RequirementsSpecification, settings: &ResolverSettings) {
     let RequirementsSpecification {
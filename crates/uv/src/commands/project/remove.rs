@@ -4,7 +4,9 @@ use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
@@ -15,8 +17,8 @@ use uv_configuration::{
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups};
-use uv_preview::Preview;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user_once;
@@ -27,6 +29,7 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
+use crate::commands::project::imports::{ImportResolver, ImportScan};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
@@ -47,6 +50,8 @@ pub(crate) async fn remove(
     active: Option<bool>,
     no_sync: bool,
     packages: Vec<PackageName>,
+    unused: bool,
+    dry_run: bool,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
@@ -63,6 +68,13 @@ pub(crate) async fn remove(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if unused && !preview.is_enabled(PreviewFeatures::REMOVE_UNUSED) {
+        warn_user_once!(
+            "The `--unused` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::REMOVE_UNUSED
+        );
+    }
+
     let target = if let Some(script) = script {
         // If we found a PEP 723 script and the user provided a project-only setting, warn.
         if package.is_some() {
@@ -122,6 +134,41 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // Detect any declared dependencies that are never imported.
+    let packages = if unused {
+        let unused = unused_dependencies(&target, &toml, &dependency_type, active, cache);
+        if unused.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No unused dependencies detected from imports"
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+
+        writeln!(
+            printer.stderr(),
+            "{} {} unused {} from imports: {}",
+            if dry_run { "Found" } else { "Detected" },
+            unused.len(),
+            if unused.len() == 1 {
+                "dependency"
+            } else {
+                "dependencies"
+            },
+            unused.iter().map(|name| name.cyan()).join(", ")
+        )?;
+
+        // In `--dry-run` mode, report the unused dependencies and exit with a failure, such
+        // that the check can be used for linting.
+        if dry_run {
+            return Ok(ExitStatus::Failure);
+        }
+
+        unused
+    } else {
+        packages
+    };
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -446,6 +493,52 @@ impl RemoveTarget {
     }
 }
 
+/// Determine the declared dependencies of the given type that don't provide any of the modules
+/// imported by the target's Python sources.
+fn unused_dependencies(
+    target: &RemoveTarget,
+    toml: &PyProjectTomlMut,
+    dependency_type: &DependencyType,
+    active: Option<bool>,
+    cache: &Cache,
+) -> Vec<PackageName> {
+    let (scan, resolver) = match target {
+        RemoveTarget::Script(script) => (
+            ImportScan::from_file(&script.path),
+            ImportResolver::default(),
+        ),
+        RemoveTarget::Project(project) => {
+            // Use the project environment, if it exists, to map imports to the distributions that
+            // provide them; there's no need to create it just to analyze the sources.
+            let resolver =
+                match PythonEnvironment::from_root(project.workspace().venv(active), cache) {
+                    Ok(environment) => ImportResolver::from_environment(&environment),
+                    Err(err) => {
+                        debug!("Failed to read the project environment: {err}");
+                        ImportResolver::default()
+                    }
+                };
+            (ImportScan::from_directory(project.root()), resolver)
+        }
+    };
+
+    let used = scan
+        .third_party()
+        .flat_map(|module| resolver.providers(module))
+        .collect::<FxHashSet<_>>();
+
+    toml.dependency_names(dependency_type)
+        .into_iter()
+        .filter(|name| {
+            let used = used.contains(name);
+            if !used {
+                debug!("No imports resolved to dependency: `{name}`");
+            }
+            !used
+        })
+        .collect()
+}
+
 /// Show a hint if a dependency with the given name is present as any dependency type.
 ///
 /// This is useful when a dependency of the user-specified type was not found, but it may be present
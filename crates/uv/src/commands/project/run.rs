@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use std::env::VarError;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
 use std::io;
 use std::io::Read;
@@ -10,6 +10,7 @@ use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use thiserror::Error;
 use tokio::process::Command;
 use tracing::{debug, trace, warn};
@@ -23,12 +24,13 @@ use uv_configuration::{
     InstallOptions, TargetTriple,
 };
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::Requirement;
+use uv_distribution_types::{InstalledDistKind, Name, Patches, Requirement};
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_preview::Preview;
+use uv_pep440::VersionSpecifiers;
+use uv_preview::{Preview, PreviewFeatures};
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -37,10 +39,11 @@ use uv_python::{
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{Installable, Lock, Preference};
-use uv_scripts::Pep723Item;
+use uv_scripts::{Pep723Error, Pep723Item, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
+use uv_types::HashStrategy;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache, WorkspaceError};
 
@@ -64,6 +67,7 @@ use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironm
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::run_stamp::RunStamp;
 use crate::commands::project::{
     EnvironmentSpecification, PreferenceLocation, ProjectEnvironment, ProjectError,
     ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
@@ -88,6 +92,7 @@ pub(crate) async fn run(
     active: Option<bool>,
     no_sync: bool,
     isolated: bool,
+    snapshot: Option<&Path>,
     all_packages: bool,
     package: Option<PackageName>,
     no_project: bool,
@@ -158,46 +163,24 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         bail!("Cannot read both requirements file and script from stdin");
     }
 
+    // A snapshot captures the ephemeral `--with` environment, so it requires some requirements.
+    if snapshot.is_some() && requirements.is_empty() {
+        bail!(
+            "`{}` requires `{}`, `{}`, or `{}`",
+            "--snapshot".green(),
+            "--with".green(),
+            "--with-editable".green(),
+            "--with-requirements".green(),
+        );
+    }
+
     // Initialize any shared state.
     let lock_state = UniversalState::default();
     let sync_state = lock_state.fork();
     let workspace_cache = WorkspaceCache::default();
 
-    // Read from the `.env` file, if necessary.
-    for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
-        match dotenvy::from_path(env_file_path) {
-            Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                bail!(
-                    "No environment file found at: `{}`",
-                    env_file_path.simplified_display()
-                );
-            }
-            Err(dotenvy::Error::Io(err)) => {
-                bail!(
-                    "Failed to read environment file `{}`: {err}",
-                    env_file_path.simplified_display()
-                );
-            }
-            Err(dotenvy::Error::LineParse(content, position)) => {
-                warn_user!(
-                    "Failed to parse environment file `{}` at position {position}: {content}",
-                    env_file_path.simplified_display(),
-                );
-            }
-            Err(err) => {
-                warn_user!(
-                    "Failed to parse environment file `{}`: {err}",
-                    env_file_path.simplified_display(),
-                );
-            }
-            Ok(()) => {
-                debug!(
-                    "Read environment file at: `{}`",
-                    env_file_path.simplified_display()
-                );
-            }
-        }
-    }
+    // Read from the `.env` files, if necessary.
+    load_env_files(env_file.iter().map(PathBuf::as_path))?;
 
     // Initialize any output reporters.
     let download_reporter = PythonDownloadReporter::single(printer);
@@ -663,6 +646,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Some(&download_reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_download_attestations.as_ref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                     preview,
                 )
@@ -717,6 +701,37 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 .into_environment()?
             };
 
+            // Compute a digest of the inputs to the lock and sync operations, to skip them if
+            // nothing changed since the last sync. The lock is needed to resolve any `--with`
+            // requirements, so the digest is only used in their absence.
+            let run_inputs = format!(
+                "{:?}",
+                (
+                    &extras,
+                    &groups,
+                    &editable,
+                    &modifications,
+                    all_packages,
+                    package.as_ref(),
+                    frozen,
+                    &lock_check,
+                    python_platform.as_ref(),
+                    &settings,
+                )
+            );
+            let run_stamp = if !preview.is_enabled(PreviewFeatures::RUN_CACHE)
+                || no_sync
+                || isolated
+                || !requirements.is_empty()
+                || !cache.refresh().is_none()
+            {
+                None
+            } else {
+                RunStamp::compute(project.workspace(), &venv, &run_inputs)
+                    .inspect_err(|err| debug!("Failed to compute run stamp: {err}"))
+                    .ok()
+            };
+
             if no_sync {
                 debug!("Skipping environment synchronization due to `--no-sync`");
 
@@ -730,6 +745,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                         .flatten()
                         .map(|lock| (lock, project.workspace().install_path().to_owned()));
                 }
+            } else if run_stamp
+                .as_ref()
+                .is_some_and(|run_stamp| run_stamp.is_fresh(&venv))
+            {
+                debug!(
+                    "Skipping environment synchronization, since the project is unchanged since the last sync"
+                );
             } else {
                 let _lock = venv
                     .lock()
@@ -874,6 +896,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     result.into_lock(),
                     project.workspace().install_path().to_owned(),
                 ));
+
+                // Record the inputs to the successful sync, which may have updated the lockfile
+                // and the environment.
+                if run_stamp.is_some() {
+                    match RunStamp::compute(project.workspace(), &venv, &run_inputs) {
+                        Ok(run_stamp) => run_stamp.write(&venv),
+                        Err(err) => debug!("Failed to compute run stamp: {err}"),
+                    }
+                }
             }
 
             venv.into_interpreter()
@@ -905,6 +936,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     Some(&download_reporter),
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
+                    install_mirrors.python_download_attestations.as_ref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
                     preview,
                 )
@@ -958,8 +990,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     let base_site_packages = SitePackages::from_interpreter(&base_interpreter)?;
     let requirements_env = match spec {
         None => None,
+        // If we're writing a snapshot, the requirements must be resolved in isolation, even if the
+        // base environment already satisfies them.
         Some(spec)
-            if can_skip_ephemeral(&spec, &base_interpreter, &base_site_packages, &settings) =>
+            if snapshot.is_none()
+                && can_skip_ephemeral(&spec, &base_interpreter, &base_site_packages, &settings) =>
         {
             None
         }
@@ -1315,7 +1350,89 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         .spawn()
         .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
 
-    run_to_completion(handle).await
+    let status = run_to_completion(handle).await?;
+
+    // Record the `--with` requirements, if the command succeeded.
+    if let Some(snapshot) = snapshot {
+        if matches!(status, ExitStatus::Success | ExitStatus::External(0)) {
+            if let Some(requirements_env) = requirements_env.as_ref() {
+                write_snapshot(snapshot, requirements_env).await?;
+                writeln!(
+                    printer.stderr(),
+                    "Wrote `--with` requirements to: {}",
+                    snapshot.user_display().cyan()
+                )?;
+            }
+        } else {
+            warn_user!(
+                "Skipping snapshot, since the command did not exit successfully: `{}`",
+                snapshot.user_display()
+            );
+        }
+    }
+
+    Ok(status)
+}
+
+/// Write the packages installed in the `--with` environment to a requirements file or, for Python
+/// scripts, to the `dependencies` of the inline script metadata.
+async fn write_snapshot(path: &Path, environment: &PythonEnvironment) -> anyhow::Result<()> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    let requirements = site_packages
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        .map(|dist| match &dist.kind {
+            InstalledDistKind::Url(dist) => format!("{} @ {}", dist.name, dist.url),
+            InstalledDistKind::LegacyEditable(dist) => {
+                format!("{} @ {}", dist.name, dist.target_url)
+            }
+            _ => format!("{}=={}", dist.name(), dist.version()),
+        })
+        .dedup()
+        .collect::<Vec<_>>();
+
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("py"))
+    {
+        // Replace the `dependencies` in the existing metadata, or add a metadata block.
+        let script = match Pep723Script::read(path).await {
+            Ok(Some(script)) => script,
+            Ok(None) => Pep723Script::init(path, &VersionSpecifiers::empty()).await?,
+            Err(Pep723Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                let (prelude, metadata, postlude) =
+                    Pep723Script::init_metadata(&[], &VersionSpecifiers::empty())?;
+                Pep723Script {
+                    path: std::path::absolute(path)?,
+                    metadata,
+                    prelude,
+                    postlude,
+                }
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut dependencies = toml_edit::Array::new();
+        for requirement in requirements {
+            dependencies
+                .push_formatted(toml_edit::Value::from(requirement).decorated("\n    ", ""));
+        }
+        dependencies.set_trailing_comma(true);
+        dependencies.set_trailing("\n");
+
+        let mut metadata = script.metadata.raw.parse::<toml_edit::DocumentMut>()?;
+        metadata["dependencies"] = toml_edit::value(dependencies);
+        script.write(&metadata.to_string())?;
+    } else {
+        let mut contents = String::new();
+        for requirement in requirements {
+            contents.push_str(&requirement);
+            contents.push('\n');
+        }
+        fs_err::tokio::write(path, contents).await?;
+    }
+
+    Ok(())
 }
 
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
@@ -1366,6 +1483,8 @@ fn can_skip_ephemeral(
         config_settings_package,
         &extra_build_requires,
         extra_build_variables,
+        &Patches::default(),
+        &HashStrategy::default(),
     ) {
         // If the requirements are already satisfied, we're done.
         Ok(SatisfiesResult::Fresh {
@@ -1992,3 +2111,69 @@ fn copy_entrypoint(
 
     Ok(())
 }
+
+/// Load environment variables from the given `.env` files into the current process.
+///
+/// Files are layered in order, such that values in later files override those in earlier files,
+/// while variables that are already set in the environment take precedence over all files.
+/// `${VAR}` references are expanded against the variables defined so far, including those defined
+/// in earlier files.
+#[allow(unsafe_code)]
+pub(crate) fn load_env_files<'a>(paths: impl IntoIterator<Item = &'a Path>) -> anyhow::Result<()> {
+    let mut preset: Option<FxHashSet<OsString>> = None;
+
+    for path in paths {
+        let iter = match dotenvy::from_path_iter(path) {
+            Ok(iter) => iter,
+            Err(dotenvy::Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+                bail!(
+                    "No environment file found at: `{}`",
+                    path.simplified_display()
+                );
+            }
+            Err(err) => {
+                bail!(
+                    "Failed to read environment file `{}`: {err}",
+                    path.simplified_display()
+                );
+            }
+        };
+
+        // Capture the variables that were set before reading any environment file.
+        let preset =
+            preset.get_or_insert_with(|| std::env::vars_os().map(|(key, _)| key).collect());
+
+        for item in iter {
+            match item {
+                Ok((key, value)) => {
+                    if preset.contains(OsStr::new(&key)) {
+                        continue;
+                    }
+                    // SAFETY: This mirrors `dotenvy::from_path`, which sets variables in the same
+                    // way; environment files are read before any subprocesses are spawned.
+                    unsafe {
+                        std::env::set_var(key, value);
+                    }
+                }
+                Err(dotenvy::Error::LineParse(content, position)) => {
+                    warn_user!(
+                        "Failed to parse environment file `{}` at position {position}: {content}",
+                        path.simplified_display(),
+                    );
+                    break;
+                }
+                Err(err) => {
+                    warn_user!(
+                        "Failed to parse environment file `{}`: {err}",
+                        path.simplified_display(),
+                    );
+                    break;
+                }
+            }
+        }
+
+        debug!("Read environment file at: `{}`", path.simplified_display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::io;
+use std::path::PathBuf;
+
+use tracing::debug;
+
+use uv_cache_info::{CacheInfo, Timestamp};
+use uv_cache_key::hash_digest;
+use uv_normalize::PackageName;
+use uv_python::PythonEnvironment;
+use uv_workspace::Workspace;
+
+/// The name of the file, within the project environment, that records the [`RunStamp`] of the
+/// last successful sync by `uv run`.
+const RUN_STAMP: &str = ".uv-run-stamp";
+
+/// A digest of every input to locking and syncing the project environment in `uv run`.
+///
+/// The digest covers the workspace members (via the same `cache-keys` used to detect changes to
+/// local packages), the contents of the lockfile, the resolved settings, the interpreter, and the
+/// timestamps of the environment's `site-packages` directories, which change whenever a package
+/// is installed or removed.
+///
+/// If the digest matches that of the last successful sync, neither the lockfile nor the
+/// environment needs to be checked, and `uv run` can launch the command immediately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RunStamp(String);
+
+impl RunStamp {
+    /// Compute the [`RunStamp`] for the given workspace and environment.
+    ///
+    /// The `settings` should capture any options that affect the lock or sync operations, e.g.,
+    /// the requested extras and groups.
+    pub(crate) fn compute(
+        workspace: &Workspace,
+        environment: &PythonEnvironment,
+        settings: &str,
+    ) -> Result<Self, io::Error> {
+        let members = workspace
+            .packages()
+            .iter()
+            .map(|(name, member)| {
+                let cache_info =
+                    CacheInfo::from_directory(member.root()).map_err(io::Error::other)?;
+                Ok((name.clone(), cache_info))
+            })
+            .collect::<Result<Vec<(PackageName, CacheInfo)>, io::Error>>()?;
+
+        // The workspace root may not be a member (e.g., in a virtual workspace).
+        let root = CacheInfo::from_directory(workspace.install_path()).map_err(io::Error::other)?;
+
+        let lock = match fs_err::read(workspace.install_path().join("uv.lock")) {
+            Ok(contents) => Some(contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
+        let interpreter = environment.interpreter();
+        let site_packages = environment
+            .site_packages()
+            .map(|path| {
+                let timestamp = Timestamp::from_path(&path).ok();
+                (path.into_owned(), timestamp)
+            })
+            .collect::<Vec<(PathBuf, Option<Timestamp>)>>();
+
+        Ok(Self(hash_digest(&(
+            uv_version::version(),
+            workspace.install_path(),
+            members,
+            root,
+            lock,
+            settings,
+            interpreter.sys_executable(),
+            interpreter.python_full_version(),
+            site_packages,
+        ))))
+    }
+
+    /// Returns `true` if the [`RunStamp`] matches that of the last successful sync of the
+    /// environment.
+    pub(crate) fn is_fresh(&self, environment: &PythonEnvironment) -> bool {
+        fs_err::read_to_string(environment.root().join(RUN_STAMP))
+            .is_ok_and(|contents| contents.trim() == self.0)
+    }
+
+    /// Record the [`RunStamp`] as that of the last successful sync of the environment.
+    pub(crate) fn write(&self, environment: &PythonEnvironment) {
+        if let Err(err) = fs_err::write(environment.root().join(RUN_STAMP), &self.0) {
+            debug!("Failed to write run stamp: {err}");
+        }
+    }
+}
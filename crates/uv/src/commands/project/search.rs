@@ -0,0 +1,175 @@
+//! Interactive search for packages on the configured indexes, as used by `uv add --interactive`.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use console::Term;
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use uv_client::{ProjectSummary, RegistryClient};
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifiers;
+
+use crate::printer::Printer;
+
+/// The maximum number of matches to show per index.
+const RESULTS_PER_INDEX: usize = 10;
+
+/// A project matching the search query.
+#[derive(Debug)]
+struct SearchResult<'a> {
+    name: PackageName,
+    index: &'a IndexUrl,
+    summary: Option<ProjectSummary>,
+}
+
+impl std::fmt::Display for SearchResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name.bold())?;
+        let Some(summary) = &self.summary else {
+            return write!(
+                f,
+                " {}",
+                format!("({})", self.index.without_credentials()).dimmed()
+            );
+        };
+        write!(f, " {}", format!("v{}", summary.version).dimmed())?;
+        if let Some(description) = &summary.summary {
+            write!(f, " {description}")?;
+        }
+        let mut details = Vec::new();
+        if let Some(requires_python) = &summary.requires_python {
+            details.push(format!("requires-python {requires_python}"));
+        }
+        if let Some(downloads) = summary.downloads {
+            details.push(format!("{downloads} downloads last month"));
+        }
+        if !details.is_empty() {
+            write!(f, " {}", format!("({})", details.join(", ")).dimmed())?;
+        }
+        Ok(())
+    }
+}
+
+/// Search the given indexes for a package, prompting the user for a query, a project, and an
+/// optional version specifier.
+///
+/// Returns the requirement to add, or `None` if the user cancelled the search.
+pub(crate) async fn search_interactive(
+    client: &RegistryClient,
+    indexes: &[&IndexUrl],
+    concurrency: usize,
+    printer: Printer,
+) -> Result<Option<String>> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        bail!("`uv add --interactive` requires an interactive terminal");
+    }
+    if indexes.is_empty() {
+        bail!("`uv add --interactive` requires at least one index, but `--no-index` was provided");
+    }
+
+    let selected = loop {
+        let query = uv_console::input(&format!("{} ", "Search for a package:".bold()), &term)
+            .context("Failed to read search query")?;
+        if query.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let results = search_indexes(client, indexes, &query, concurrency).await;
+        if results.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No packages found matching `{}`",
+                query.trim().cyan()
+            )?;
+            continue;
+        }
+
+        let items = results.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let Some(position) = uv_console::select("Select a package", &items, &term)? else {
+            continue;
+        };
+        break results.into_iter().nth(position).unwrap().name;
+    };
+
+    // Prompt for an optional version specifier, e.g., `>=2.0`.
+    loop {
+        let prompt = format!(
+            "{} {} ",
+            format!("Version specifier for {selected}:").bold(),
+            "(leave empty to use the default bounds)".dimmed()
+        );
+        let specifiers =
+            uv_console::input(&prompt, &term).context("Failed to read version specifier")?;
+        let specifiers = specifiers.trim();
+        if specifiers.is_empty() {
+            return Ok(Some(selected.to_string()));
+        }
+        match VersionSpecifiers::from_str(specifiers) {
+            Ok(specifiers) => return Ok(Some(format!("{selected}{specifiers}"))),
+            Err(err) => {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} Invalid version specifier `{}`: {err}",
+                    "error".red().bold(),
+                    ":".bold(),
+                    specifiers.cyan()
+                )?;
+            }
+        }
+    }
+}
+
+/// Search all indexes for the query, deduplicating projects across indexes in order of priority,
+/// and fetch the summary for each match.
+///
+/// Failures are not fatal: an index that can't be searched is skipped, and a summary that can't
+/// be fetched is omitted.
+async fn search_indexes<'a>(
+    client: &RegistryClient,
+    indexes: &[&'a IndexUrl],
+    query: &str,
+    concurrency: usize,
+) -> Vec<SearchResult<'a>> {
+    let mut seen = FxHashSet::default();
+    let mut matches = Vec::new();
+    for index in indexes {
+        match client
+            .search_projects(index, query, RESULTS_PER_INDEX)
+            .await
+        {
+            Ok(names) => {
+                for name in names {
+                    if seen.insert(name.clone()) {
+                        matches.push((name, *index));
+                    }
+                }
+            }
+            Err(err) => debug!("Failed to search `{index}`: {err}"),
+        }
+    }
+
+    futures::stream::iter(matches)
+        .map(async |(name, index)| {
+            let summary = client
+                .project_summary(index, &name)
+                .await
+                .inspect_err(|err| debug!("Failed to fetch summary for `{name}`: {err}"))
+                .ok()
+                .flatten();
+            SearchResult {
+                name,
+                index,
+                summary,
+            }
+        })
+        .buffered(concurrency)
+        .collect()
+        .await
+}
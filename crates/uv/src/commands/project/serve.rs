@@ -0,0 +1,700 @@
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader,
+};
+use tokio::sync::Semaphore;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cache_key::cache_digest;
+use uv_client::{
+    BaseClientBuilder, MetadataFormat, RegistryClient, RegistryClientBuilder, VersionFiles,
+};
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults};
+use uv_distribution_types::IndexCapabilities;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::{Interpreter, PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{Lock, NoSolutionError};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, ProjectInterpreter, UniversalState};
+use crate::commands::{ExitStatus, pip};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// The JSON-RPC error code for a request that could not be parsed.
+const PARSE_ERROR: i64 = -32700;
+
+/// The JSON-RPC error code for an unknown method.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The JSON-RPC error code for invalid method parameters.
+const INVALID_PARAMS: i64 = -32602;
+
+/// The JSON-RPC error code for a request that failed.
+const REQUEST_FAILED: i64 = -32000;
+
+/// The JSON-RPC error code for a resolution without a solution.
+const NO_SOLUTION: i64 = -32001;
+
+/// The methods supported by the server.
+const METHODS: &[&str] = &[
+    "initialize",
+    "resolve",
+    "explain",
+    "outdated",
+    "metadata",
+    "shutdown",
+];
+
+/// Answer resolution and inspection queries for the project over JSON-RPC, until a client requests
+/// a shutdown.
+pub(crate) async fn serve(
+    project_dir: &Path,
+    socket: Option<PathBuf>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::SERVE) {
+        warn_user!(
+            "The `uv serve` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::SERVE
+        );
+    }
+
+    // Ensure that the project exists before listening.
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let socket = socket.unwrap_or_else(|| default_socket(workspace.install_path()));
+
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(settings.index_locations.clone())
+        .keyring(settings.keyring_provider)
+        .build();
+
+    let server = Server {
+        project_dir,
+        python,
+        install_mirrors,
+        settings,
+        client_builder,
+        client,
+        capabilities: IndexCapabilities::default(),
+        download_concurrency: Semaphore::new(concurrency.downloads),
+        interpreter: RefCell::new(None),
+        python_preference,
+        python_downloads,
+        concurrency,
+        no_config,
+        cache,
+        preview,
+    };
+
+    let mut listener = Listener::bind(&socket).await?;
+    writeln!(
+        printer.stderr(),
+        "Listening on {}",
+        socket.simplified_display().cyan()
+    )?;
+
+    // Serve each connection concurrently, answering the requests on a connection in order.
+    let mut connections = FuturesUnordered::new();
+    loop {
+        tokio::select! {
+            stream = listener.accept() => {
+                connections.push(server.serve_connection(stream?));
+            }
+            Some(result) = connections.next(), if !connections.is_empty() => {
+                match result {
+                    Ok(true) => break,
+                    Ok(false) => {}
+                    Err(err) => debug!("Closed connection after error: {err}"),
+                }
+            }
+        }
+    }
+
+    debug!("Shutting down server");
+    Ok(ExitStatus::Success)
+}
+
+/// The state shared across all requests to the server.
+struct Server<'a> {
+    project_dir: &'a Path,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'a>,
+    client: RegistryClient,
+    capabilities: IndexCapabilities,
+    download_concurrency: Semaphore,
+    /// The interpreter used for resolution, discovered on the first request that needs it.
+    interpreter: RefCell<Option<Interpreter>>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &'a Cache,
+    preview: Preview,
+}
+
+/// A JSON-RPC 2.0 request, or a notification if the `id` is omitted.
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// The parameters of a `metadata` request.
+#[derive(Debug, Deserialize)]
+struct MetadataParams {
+    name: PackageName,
+}
+
+/// A JSON-RPC 2.0 error.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::new(
+            REQUEST_FAILED,
+            anstream::adapter::strip_str(&format!("{err:#}")).to_string(),
+        )
+    }
+}
+
+impl From<ProjectError> for RpcError {
+    fn from(err: ProjectError) -> Self {
+        Self::from(anyhow::Error::from(err))
+    }
+}
+
+impl Server<'_> {
+    /// Answer the requests on a single connection, until the client disconnects.
+    ///
+    /// Returns `true` if the client requested a shutdown.
+    async fn serve_connection<S: tokio::io::AsyncRead + AsyncWrite>(
+        &self,
+        stream: S,
+    ) -> Result<bool> {
+        let (reader, mut writer) = tokio::io::split(stream);
+        let mut reader = BufReader::new(reader);
+        while let Some(body) = read_message(&mut reader).await? {
+            let request = match serde_json::from_slice::<Request>(&body) {
+                Ok(request) => request,
+                Err(err) => {
+                    let error = RpcError::new(PARSE_ERROR, format!("Invalid request: {err}"));
+                    write_message(&mut writer, &response(Value::Null, Err(error))).await?;
+                    continue;
+                }
+            };
+
+            debug!("Received `{}` request", request.method);
+            let result = self.handle(&request.method, request.params).await;
+            if let Some(id) = request.id {
+                write_message(&mut writer, &response(id, result)).await?;
+            }
+            if request.method == "shutdown" {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Answer a single request.
+    async fn handle(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(json!({
+                "name": "uv",
+                "version": uv_version::version(),
+                "methods": METHODS,
+            })),
+            "resolve" => match self.resolve().await? {
+                Ok(lock) => Ok(json!({
+                    "packages": lock
+                        .packages()
+                        .iter()
+                        .map(|package| json!({
+                            "name": package.name(),
+                            "version": package.version(),
+                        }))
+                        .collect::<Vec<_>>(),
+                })),
+                Err(err) => Err(RpcError {
+                    code: NO_SOLUTION,
+                    message: err.header().to_string(),
+                    data: Some(conflict(&err)),
+                }),
+            },
+            "explain" => match self.resolve().await? {
+                Ok(_) => Ok(json!({ "conflict": null })),
+                Err(err) => Ok(json!({ "conflict": conflict(&err) })),
+            },
+            "outdated" => self.outdated().await,
+            "metadata" => {
+                let params = serde_json::from_value::<MetadataParams>(params)
+                    .map_err(|err| RpcError::new(INVALID_PARAMS, err.to_string()))?;
+                self.metadata(&params.name).await
+            }
+            "shutdown" => Ok(Value::Null),
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: `{method}`"),
+            )),
+        }
+    }
+
+    /// Resolve the project, without writing the lockfile.
+    ///
+    /// The project is re-discovered for each request, such that changes to the `pyproject.toml`
+    /// are reflected.
+    async fn resolve(&self) -> Result<Result<Lock, Box<NoSolutionError>>, RpcError> {
+        let workspace_cache = WorkspaceCache::default();
+        let workspace = Workspace::discover(
+            self.project_dir,
+            &DiscoveryOptions::default(),
+            &workspace_cache,
+        )
+        .await
+        .map_err(ProjectError::from)?;
+        let interpreter = self.interpreter(&workspace).await?;
+
+        let state = UniversalState::default();
+        let result = Box::pin(
+            LockOperation::new(
+                LockMode::DryRun(&interpreter),
+                &self.settings,
+                &self.client_builder,
+                &state,
+                Box::new(DefaultResolveLogger),
+                self.concurrency,
+                self.cache,
+                &workspace_cache,
+                Printer::Silent,
+                self.preview,
+            )
+            .execute(LockTarget::Workspace(&workspace)),
+        )
+        .await;
+
+        match result {
+            Ok(result) => Ok(Ok(result.into_lock())),
+            Err(ProjectError::Operation(pip::operations::Error::Resolve(
+                uv_resolver::ResolveError::NoSolution(err),
+            ))) => Ok(Err(err)),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Return the interpreter for the project, discovering it on first use.
+    async fn interpreter(&self, workspace: &Workspace) -> Result<Interpreter, ProjectError> {
+        if let Some(interpreter) = self.interpreter.borrow().as_ref() {
+            return Ok(interpreter.clone());
+        }
+
+        let interpreter = ProjectInterpreter::discover(
+            workspace,
+            self.project_dir,
+            &DependencyGroupsWithDefaults::none(),
+            self.python.as_deref().map(PythonRequest::parse),
+            &self.client_builder,
+            self.python_preference,
+            self.python_downloads,
+            &self.install_mirrors,
+            false,
+            self.no_config,
+            Some(false),
+            self.cache,
+            Printer::Silent,
+            self.preview,
+        )
+        .await?
+        .into_interpreter();
+        debug!(
+            "Using Python {} interpreter at: {}",
+            interpreter.python_version(),
+            interpreter.sys_executable().user_display()
+        );
+
+        *self.interpreter.borrow_mut() = Some(interpreter.clone());
+        Ok(interpreter)
+    }
+
+    /// List the locked packages for which a newer version is available on the index.
+    async fn outdated(&self) -> Result<Value, RpcError> {
+        let workspace = Workspace::discover(
+            self.project_dir,
+            &DiscoveryOptions::default(),
+            &WorkspaceCache::default(),
+        )
+        .await
+        .map_err(ProjectError::from)?;
+        let target = LockTarget::Workspace(&workspace);
+        let Some(lock) = target.read().await? else {
+            return Err(ProjectError::MissingLockfile.into());
+        };
+
+        // Filter to packages that are derived from a registry.
+        let packages = lock
+            .packages()
+            .iter()
+            .filter_map(|package| match package.index(target.install_path()) {
+                Ok(Some(index)) => Some(Ok((package, index))),
+                Ok(None) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(anyhow::Error::from)?;
+
+        let exclude_newer = lock.exclude_newer();
+        let client = LatestClient {
+            client: &self.client,
+            capabilities: &self.capabilities,
+            prerelease: lock.prerelease_mode(),
+            exclude_newer: &exclude_newer,
+            requires_python: lock.requires_python(),
+            tags: None,
+        };
+
+        let mut fetches = futures::stream::iter(packages)
+            .map(async |(package, index)| {
+                let latest = client
+                    .find_latest(package.name(), Some(&index), &self.download_concurrency)
+                    .await?;
+                Ok::<_, anyhow::Error>(latest.map(|latest| (package, latest.into_version())))
+            })
+            .buffer_unordered(self.concurrency.downloads);
+
+        let mut outdated = Vec::new();
+        while let Some(entry) = fetches.next().await.transpose()? {
+            let Some((package, latest)) = entry else {
+                continue;
+            };
+            let Some(version) = package.version() else {
+                continue;
+            };
+            if latest > *version {
+                outdated.push((package.name(), version, latest));
+            }
+        }
+        outdated.sort();
+
+        Ok(json!({
+            "packages": outdated
+                .into_iter()
+                .map(|(name, version, latest)| json!({
+                    "name": name,
+                    "version": version,
+                    "latest": latest,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    /// Query the versions of a package that are available on the configured indexes.
+    async fn metadata(&self, name: &PackageName) -> Result<Value, RpcError> {
+        let archives = self
+            .client
+            .simple_detail(name, None, &self.capabilities, &self.download_concurrency)
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        // Map each version to whether all of its files are yanked.
+        let mut versions = std::collections::BTreeMap::<Version, bool>::new();
+        for (_, archive) in archives {
+            match archive {
+                MetadataFormat::Simple(archive) => {
+                    for datum in archive.iter() {
+                        let version =
+                            rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                                .expect("archived version always deserializes");
+                        let files =
+                            rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                                .expect("archived version files always deserializes");
+                        let yanked = files
+                            .all()
+                            .all(|(_, file)| file.yanked.is_some_and(|yanked| yanked.is_yanked()));
+                        versions
+                            .entry(version)
+                            .and_modify(|all| *all &= yanked)
+                            .or_insert(yanked);
+                    }
+                }
+                MetadataFormat::Flat(entries) => {
+                    for entry in entries {
+                        versions.insert(entry.filename.version().clone(), false);
+                    }
+                }
+            }
+        }
+
+        let latest = versions
+            .iter()
+            .rev()
+            .find(|(version, yanked)| !**yanked && version.is_stable())
+            .map(|(version, _)| version);
+
+        Ok(json!({
+            "name": name,
+            "latest": latest,
+            "versions": versions
+                .iter()
+                .map(|(version, yanked)| json!({ "version": version, "yanked": yanked }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+}
+
+/// Render a resolution failure, along with the sources of the conflicting requirements.
+fn conflict(err: &NoSolutionError) -> Value {
+    json!({
+        "message": err.header().to_string(),
+        "report": anstream::adapter::strip_str(&err.to_string()).to_string(),
+        "provenance": err.provenance(),
+    })
+}
+
+/// Build a JSON-RPC 2.0 response to the request with the given ID.
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(err) => {
+            let mut error = json!({ "code": err.code, "message": err.message });
+            if let Some(data) = err.data {
+                error["data"] = data;
+            }
+            json!({ "jsonrpc": "2.0", "id": id, "error": error })
+        }
+    }
+}
+
+/// Read a message framed with a `Content-Length` header, as in the Language Server Protocol.
+///
+/// Returns `None` once the client closes the connection.
+async fn read_message(reader: &mut (impl AsyncBufRead + Unpin)) -> Result<Option<Vec<u8>>> {
+    let mut length = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            length = Some(
+                value
+                    .trim()
+                    .parse::<usize>()
+                    .with_context(|| format!("Invalid `Content-Length` header: `{line}`"))?,
+            );
+        }
+    }
+
+    let Some(length) = length else {
+        bail!("Missing `Content-Length` header");
+    };
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    Ok(Some(body))
+}
+
+/// Write a message framed with a `Content-Length` header.
+async fn write_message(writer: &mut (impl AsyncWrite + Unpin), message: &Value) -> Result<()> {
+    let body = serde_json::to_vec(message)?;
+    writer
+        .write_all(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes())
+        .await?;
+    writer.write_all(&body).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Return the default socket for the project, which is unique to the project's root.
+fn default_socket(root: &Path) -> PathBuf {
+    let digest = cache_digest(&root);
+    #[cfg(unix)]
+    {
+        std::env::temp_dir().join(format!("uv-serve-{digest}.sock"))
+    }
+    #[cfg(windows)]
+    {
+        PathBuf::from(format!(r"\\.\pipe\uv-serve-{digest}"))
+    }
+}
+
+/// A Unix domain socket that accepts connections from local clients.
+///
+/// The socket is only accessible to the current user, and is removed when the listener is dropped.
+#[cfg(unix)]
+struct Listener {
+    path: PathBuf,
+    listener: tokio::net::UnixListener,
+}
+
+#[cfg(unix)]
+impl Listener {
+    async fn bind(path: &Path) -> Result<Self> {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Remove a stale socket, unless another server is still listening on it.
+        if path.exists() {
+            if tokio::net::UnixStream::connect(path).await.is_ok() {
+                bail!(
+                    "Another server is already listening on `{}`",
+                    path.user_display()
+                );
+            }
+            fs_err::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        let listener = tokio::net::UnixListener::bind(path)
+            .with_context(|| format!("Failed to listen on `{}`", path.user_display()))?;
+        fs_err::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            listener,
+        })
+    }
+
+    async fn accept(&mut self) -> std::io::Result<tokio::net::UnixStream> {
+        let (stream, _) = self.listener.accept().await?;
+        Ok(stream)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Listener {
+    fn drop(&mut self) {
+        if let Err(err) = fs_err::remove_file(&self.path) {
+            debug!("Failed to remove socket: {err}");
+        }
+    }
+}
+
+/// A named pipe that accepts connections from local clients.
+#[cfg(windows)]
+struct Listener {
+    name: PathBuf,
+    server: tokio::net::windows::named_pipe::NamedPipeServer,
+}
+
+#[cfg(windows)]
+impl Listener {
+    #[allow(clippy::unused_async)]
+    async fn bind(name: &Path) -> Result<Self> {
+        let server = tokio::net::windows::named_pipe::ServerOptions::new()
+            .first_pipe_instance(true)
+            .create(name)
+            .with_context(|| format!("Failed to listen on `{}`", name.user_display()))?;
+        Ok(Self {
+            name: name.to_path_buf(),
+            server,
+        })
+    }
+
+    async fn accept(
+        &mut self,
+    ) -> std::io::Result<tokio::net::windows::named_pipe::NamedPipeServer> {
+        self.server.connect().await?;
+        // Create the next instance before handing off the connected one, such that clients can
+        // always connect.
+        let next = tokio::net::windows::named_pipe::ServerOptions::new().create(&self.name)?;
+        Ok(std::mem::replace(&mut self.server, next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{read_message, write_message};
+
+    #[tokio::test]
+    async fn framing() -> anyhow::Result<()> {
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": "resolve" });
+
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &message).await?;
+        write_message(&mut buffer, &message).await?;
+        let header = format!(
+            "Content-Length: {}\r\n\r\n",
+            serde_json::to_vec(&message)?.len()
+        );
+        assert!(buffer.starts_with(header.as_bytes()));
+
+        let mut reader = buffer.as_slice();
+        for _ in 0..2 {
+            let body = read_message(&mut reader).await?.unwrap();
+            assert_eq!(serde_json::from_slice::<serde_json::Value>(&body)?, message);
+        }
+        assert!(read_message(&mut reader).await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn framing_headers() -> anyhow::Result<()> {
+        let mut reader: &[u8] = b"content-length: 2\r\nContent-Type: application/json\r\n\r\n{}";
+        assert_eq!(read_message(&mut reader).await?.unwrap(), b"{}");
+
+        let mut reader: &[u8] = b"Content-Type: application/json\r\n\r\n{}";
+        assert!(read_message(&mut reader).await.is_err());
+        Ok(())
+    }
+}
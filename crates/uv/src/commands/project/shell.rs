@@ -0,0 +1,210 @@
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+use uv_shell::{Shell, shlex_posix, shlex_windows};
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Spawn a shell with the project environment activated.
+pub(crate) async fn shell(
+    project_dir: &Path,
+    command: Option<String>,
+    active: Option<bool>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Find the project environment, without creating it.
+    let root = project.workspace().venv(active);
+    let environment = PythonEnvironment::from_root(&root, cache).with_context(|| {
+        format!(
+            "No project environment found at `{}`; run `{}` to create it",
+            root.user_display(),
+            "uv sync".green()
+        )
+    })?;
+
+    let shell = Shell::from_env();
+    let executable = executable(shell);
+    debug!(
+        "Using shell `{}` ({})",
+        executable.to_string_lossy(),
+        shell.map_or_else(|| "unknown".to_string(), ToString::to_string)
+    );
+
+    let mut process = Command::new(&executable);
+
+    // Keep any temporary configuration files alive until the shell exits.
+    let _temp_dir;
+
+    if let Some(command) = command {
+        // Run the command non-interactively, with the environment variables that activation would
+        // set.
+        activate_env(&mut process, &environment)?;
+        match shell {
+            Some(Shell::Powershell) => process.arg("-Command"),
+            Some(Shell::Cmd) => process.arg("/C"),
+            _ => process.arg("-c"),
+        };
+        process.arg(command);
+    } else {
+        if std::env::var_os(EnvVars::VIRTUAL_ENV)
+            .is_some_and(|virtual_env| Path::new(&virtual_env) == environment.root())
+        {
+            bail!(
+                "The project environment at `{}` is already activated",
+                environment.root().user_display()
+            );
+        }
+
+        writeln!(
+            printer.stderr(),
+            "Spawning a shell with the environment activated at: {}",
+            environment.root().user_display().cyan()
+        )?;
+
+        // Where possible, source the environment's activation script from the shell's startup
+        // sequence, so that the prompt reflects the active environment.
+        let scripts = environment.scripts();
+        match shell {
+            Some(Shell::Bash) => {
+                let temp_dir = tempfile::tempdir()?;
+                let rcfile = temp_dir.path().join("bashrc");
+                fs_err::write(
+                    &rcfile,
+                    format!(
+                        "[ -f ~/.bashrc ] && . ~/.bashrc\n. {}\n",
+                        shlex_posix(scripts.join("activate"))
+                    ),
+                )?;
+                process.arg("--rcfile").arg(rcfile);
+                _temp_dir = temp_dir;
+            }
+            Some(Shell::Zsh) => {
+                // Zsh reads its startup files from `ZDOTDIR`, so point it at a temporary
+                // directory that restores the user's `ZDOTDIR` and sources their startup files.
+                let temp_dir = tempfile::tempdir()?;
+                let zdotdir = std::env::var_os(EnvVars::ZDOTDIR)
+                    .or_else(|| std::env::var_os(EnvVars::HOME))
+                    .map(PathBuf::from)
+                    .unwrap_or_default();
+                fs_err::write(
+                    temp_dir.path().join(".zshenv"),
+                    format!(
+                        "ZDOTDIR={}\n[ -f \"$ZDOTDIR/.zshenv\" ] && . \"$ZDOTDIR/.zshenv\"\n_uv_zdotdir=\"$ZDOTDIR\"\nZDOTDIR={}\n",
+                        shlex_posix(&zdotdir),
+                        shlex_posix(temp_dir.path())
+                    ),
+                )?;
+                fs_err::write(
+                    temp_dir.path().join(".zshrc"),
+                    format!(
+                        "ZDOTDIR=\"$_uv_zdotdir\"\nunset _uv_zdotdir\n[ -f \"$ZDOTDIR/.zshrc\" ] && . \"$ZDOTDIR/.zshrc\"\n. {}\n",
+                        shlex_posix(scripts.join("activate"))
+                    ),
+                )?;
+                process.env(EnvVars::ZDOTDIR, temp_dir.path());
+                _temp_dir = temp_dir;
+            }
+            Some(Shell::Fish) => {
+                process.arg("--init-command").arg(format!(
+                    "source {}",
+                    shlex_posix(scripts.join("activate.fish"))
+                ));
+            }
+            Some(Shell::Nushell) => {
+                process.arg("--execute").arg(format!(
+                    "overlay use {}",
+                    shlex_posix(scripts.join("activate.nu"))
+                ));
+            }
+            Some(Shell::Powershell) => {
+                process
+                    .arg("-NoExit")
+                    .arg("-Command")
+                    .arg(shlex_windows(scripts.join("activate"), Shell::Powershell));
+            }
+            Some(Shell::Cmd) => {
+                process
+                    .arg("/K")
+                    .arg(shlex_windows(scripts.join("activate"), Shell::Cmd));
+            }
+            Some(Shell::Csh | Shell::Ksh) | None => {
+                // Otherwise, set the environment variables directly; the prompt is left as-is.
+                activate_env(&mut process, &environment)?;
+            }
+        }
+    }
+
+    let handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn shell: `{}`", executable.to_string_lossy()))?;
+    run_to_completion(handle).await
+}
+
+/// Set the environment variables that activating the environment would set.
+fn activate_env(process: &mut Command, environment: &PythonEnvironment) -> Result<()> {
+    let path = std::env::join_paths(
+        std::iter::once(environment.scripts().to_path_buf()).chain(
+            std::env::var_os(EnvVars::PATH)
+                .as_ref()
+                .iter()
+                .flat_map(std::env::split_paths),
+        ),
+    )?;
+    process.env(EnvVars::PATH, path);
+    process.env(EnvVars::VIRTUAL_ENV, environment.root().as_os_str());
+    Ok(())
+}
+
+/// Return the executable for the given shell, preferring the user's `SHELL`.
+fn executable(shell: Option<Shell>) -> OsString {
+    let user_shell = std::env::var_os(EnvVars::SHELL);
+    let Some(shell) = shell else {
+        return user_shell.unwrap_or_else(|| {
+            if cfg!(windows) {
+                OsString::from("cmd")
+            } else {
+                OsString::from("sh")
+            }
+        });
+    };
+    if let Some(user_shell) =
+        user_shell.filter(|user_shell| Shell::from_shell_path(user_shell) == Some(shell))
+    {
+        return user_shell;
+    }
+    OsString::from(match shell {
+        Shell::Bash => "bash",
+        Shell::Zsh => "zsh",
+        Shell::Fish => "fish",
+        Shell::Nushell => "nu",
+        Shell::Csh => "csh",
+        Shell::Ksh => "ksh",
+        Shell::Powershell => {
+            if which::which("pwsh").is_ok() {
+                "pwsh"
+            } else {
+                "powershell"
+            }
+        }
+        Shell::Cmd => "cmd",
+    })
+}
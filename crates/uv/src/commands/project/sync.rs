@@ -2,12 +2,13 @@ use std::fmt::Write;
 use std::ops::Deref;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use serde::Serialize;
-use tracing::warn;
+use tracing::debug;
 use uv_cache::Cache;
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
@@ -19,16 +20,19 @@ use uv_configuration::{
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, Requirement, RequirementSource, Resolution, ResolvedDist,
+    SourceDist,
 };
 use uv_fs::{PortablePathBuf, Simplified};
-use uv_installer::{InstallationStrategy, SitePackages};
+use uv_installer::{InstallationStrategy, SitePackages, Snapshot, StagedEnvironment};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeatures};
-use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, ResolverMarkerEnvironment};
+use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode, YankedPolicy,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
@@ -42,13 +46,14 @@ use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
+use crate::commands::project::lock_mismatch::write_lock_mismatch;
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
-    default_dependency_groups, detect_conflicts, script_extra_build_requires, script_specification,
-    update_environment,
+    default_dependency_groups, detect_conflicts, patched_build_options,
+    script_extra_build_requires, script_specification, update_environment,
 };
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, diagnostics, lock_environment};
 use crate::printer::Printer;
 use crate::settings::{
     InstallerSettingsRef, LockCheck, LockCheckSource, ResolverInstallerSettings, ResolverSettings,
@@ -61,6 +66,8 @@ pub(crate) async fn sync(
     lock_check: LockCheck,
     frozen: bool,
     dry_run: DryRun,
+    snapshot: bool,
+    atomic: bool,
     active: Option<bool>,
     all_packages: bool,
     package: Vec<PackageName>,
@@ -79,6 +86,7 @@ pub(crate) async fn sync(
     script: Option<Pep723Script>,
     installer_metadata: bool,
     concurrency: Concurrency,
+    wait_timeout: Option<Duration>,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
@@ -93,6 +101,13 @@ pub(crate) async fn sync(
         );
     }
 
+    if snapshot && !preview.is_enabled(PreviewFeatures::ENV_SNAPSHOTS) {
+        warn_user!(
+            "The `--snapshot` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::ENV_SNAPSHOTS
+        );
+    }
+
     // Identify the target.
     let workspace_cache = WorkspaceCache::default();
     let target = if let Some(script) = script {
@@ -199,13 +214,7 @@ pub(crate) async fn sync(
         ),
     };
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let _lock = lock_environment(&environment, wait_timeout, printer).await?;
 
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
@@ -360,13 +369,7 @@ pub(crate) async fn sync(
                 // sync operation, but exit with a non-zero status.
                 Outcome::LockMismatch(prev, cur, lock_source)
             } else {
-                writeln!(
-                    printer.stderr(),
-                    "{}",
-                    ProjectError::LockMismatch(prev, cur, lock_source)
-                        .to_string()
-                        .bold()
-                )?;
+                write_lock_mismatch(printer, prev, cur, lock_source)?;
                 return Ok(ExitStatus::Failure);
             }
         }
@@ -397,10 +400,21 @@ pub(crate) async fn sync(
 
     let state = state.fork();
 
+    let snapshot = create_snapshot(&environment, snapshot, dry_run)?;
+
+    // If requested, apply the changes to a staged copy of the environment.
+    let staged = if atomic && !dry_run.enabled() {
+        Some(StagedEnvironment::create(&environment)?)
+    } else {
+        None
+    };
+
     // Perform the sync operation.
     match do_sync(
         sync_target,
-        &environment,
+        staged
+            .as_ref()
+            .map_or(&*environment, StagedEnvironment::environment),
         &extras,
         &groups,
         editable,
@@ -421,7 +435,12 @@ pub(crate) async fn sync(
     )
     .await
     {
-        Ok(()) => {}
+        Ok(()) => {
+            if let Some(staged) = staged {
+                staged.commit()?;
+            }
+            finish_snapshot(snapshot, &environment, printer)?;
+        }
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
                 .report(err)
@@ -433,18 +452,48 @@ pub(crate) async fn sync(
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur, lock_source) => {
-            writeln!(
-                printer.stderr(),
-                "{}",
-                ProjectError::LockMismatch(prev, cur, lock_source)
-                    .to_string()
-                    .bold()
-            )?;
+            write_lock_mismatch(printer, prev, cur, lock_source)?;
             Ok(ExitStatus::Failure)
         }
     }
 }
 
+/// Record a snapshot of the environment before modifying it, if requested.
+fn create_snapshot(
+    environment: &PythonEnvironment,
+    snapshot: bool,
+    dry_run: DryRun,
+) -> Result<Option<Snapshot>> {
+    if !snapshot || dry_run.enabled() {
+        return Ok(None);
+    }
+    let snapshot = Snapshot::create(environment).context("Failed to snapshot the environment")?;
+    Ok(Some(snapshot))
+}
+
+/// Retain the snapshot if the environment changed since it was recorded, and discard it
+/// otherwise.
+fn finish_snapshot(
+    snapshot: Option<Snapshot>,
+    environment: &PythonEnvironment,
+    printer: Printer,
+) -> Result<()> {
+    let Some(snapshot) = snapshot else {
+        return Ok(());
+    };
+    if snapshot.is_current(environment)? {
+        debug!("Environment is unchanged; discarding snapshot");
+        snapshot.discard()?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Saved environment snapshot; run `{}` to revert these changes",
+            "uv sync --rollback".green()
+        )?;
+    }
+    Ok(())
+}
+
 /// The outcome of a `lock` operation within a `sync` operation.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -620,6 +669,8 @@ pub(super) async fn do_sync(
         exclude_newer,
         link_mode,
         compile_bytecode,
+        script_policy,
+        wheel_checks,
         reinstall,
         build_options,
         sources,
@@ -656,6 +707,12 @@ pub(super) async fn do_sync(
                 dependency_metadata: dependency_metadata.clone(),
                 exclude_newer: exclude_newer.clone(),
                 fork_strategy: ForkStrategy::default(),
+                yanked_policy: YankedPolicy::default(),
+                allow_yanked: Vec::new(),
+                known_incompatibilities: Vec::new(),
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: index_locations.clone(),
                 index_strategy,
                 keyring_provider,
@@ -673,6 +730,10 @@ pub(super) async fn do_sync(
     }
     .into_inner();
 
+    // Read the patches, if any. Patched packages must be built from source.
+    let patches = target.patches()?;
+    let build_options = &patched_build_options(build_options, &patches);
+
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
     // Validate that the Python version is supported by the lockfile.
@@ -806,9 +867,14 @@ pub(super) async fn do_sync(
         workspace_cache.clone(),
         concurrency,
         preview,
-    );
+    )
+    .with_patches(patches);
 
-    let site_packages = SitePackages::from_environment(venv)?;
+    let mut site_packages = SitePackages::from_environment(venv)?;
+
+    // Verify that any dependencies provided by the environment are present, and ensure that they
+    // aren't removed as extraneous.
+    verify_provided_dependencies(target.lock(), &marker_env, &mut site_packages, venv, cache)?;
 
     // Sync the environment.
     operations::install(
@@ -819,6 +885,8 @@ pub(super) async fn do_sync(
         reinstall,
         build_options,
         link_mode,
+        script_policy,
+        wheel_checks,
         compile_bytecode,
         &hasher,
         &tags,
@@ -839,6 +907,67 @@ pub(super) async fn do_sync(
     Ok(())
 }
 
+/// Verify that the environment satisfies the dependencies that the lockfile assumes to be
+/// provided by the environment.
+///
+/// Any matching installations are removed from the [`SitePackages`], such that they're left
+/// untouched when syncing the environment.
+#[allow(clippy::result_large_err)]
+fn verify_provided_dependencies(
+    lock: &Lock,
+    marker_env: &ResolverMarkerEnvironment,
+    site_packages: &mut SitePackages,
+    venv: &PythonEnvironment,
+    cache: &Cache,
+) -> Result<(), ProjectError> {
+    // If the environment includes the system site packages, the provided dependencies may be
+    // installed in the base environment instead.
+    let mut base_site_packages = None;
+
+    for requirement in lock.provided() {
+        if !requirement.evaluate_markers(Some(marker_env), &[]) {
+            continue;
+        }
+
+        let mut installed = site_packages.remove_packages(&requirement.name);
+        if installed.is_empty()
+            && venv
+                .cfg()
+                .is_ok_and(|cfg| cfg.include_system_site_packages())
+        {
+            if base_site_packages.is_none() {
+                base_site_packages = venv
+                    .interpreter()
+                    .sys_base_executable()
+                    .and_then(|executable| Interpreter::query(executable, cache).ok())
+                    .and_then(|interpreter| SitePackages::from_interpreter(&interpreter).ok());
+            }
+            if let Some(base_site_packages) = &mut base_site_packages {
+                installed = base_site_packages.remove_packages(&requirement.name);
+            }
+        }
+
+        let Some(dist) = installed.first() else {
+            return Err(ProjectError::MissingProvidedDependency(Box::new(
+                requirement.clone(),
+            )));
+        };
+
+        if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+            if !specifier.contains(dist.version()) {
+                return Err(ProjectError::IncompatibleProvidedDependency(
+                    Box::new(requirement.clone()),
+                    dist.version().clone(),
+                ));
+            }
+        }
+
+        debug!("Using provided dependency: {dist}");
+    }
+
+    Ok(())
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -0,0 +1,164 @@
+use std::ffi::OsString;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use anyhow::Result;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DependencyGroups, EditableMode, EnvFile, ExtrasSpecification};
+use uv_normalize::{GroupName, PackageName};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::PyProjectToml;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::run::{RunCommand, run};
+use crate::printer::Printer;
+use crate::settings::{LockCheck, ResolverInstallerSettings};
+
+/// The dependency group that's synced before running the tests, if defined.
+static TEST_GROUP: LazyLock<GroupName> = LazyLock::new(|| GroupName::from_str("test").unwrap());
+
+/// Run the project's tests.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn test(
+    project_dir: &Path,
+    args: Vec<String>,
+    show_resolution: bool,
+    lock_check: LockCheck,
+    frozen: bool,
+    active: Option<bool>,
+    no_sync: bool,
+    all_packages: bool,
+    package: Option<PackageName>,
+    no_config: bool,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    editable: Option<EditableMode>,
+    modifications: Modifications,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: Cache,
+    printer: Printer,
+    env_file: EnvFile,
+    preview: Preview,
+    max_recursion_depth: u32,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::TEST) {
+        warn_user!(
+            "`uv test` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::TEST
+        );
+    }
+
+    let project = VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+
+    // Read the configuration from the targeted workspace member, if any.
+    let pyproject_toml = package
+        .as_ref()
+        .and_then(|package| project.workspace().packages().get(package))
+        .map_or_else(
+            || project.pyproject_toml(),
+            |member| member.pyproject_toml(),
+        );
+
+    // Prefer the member's test command, falling back to the workspace root's.
+    let mut command = test_command(pyproject_toml)
+        .or_else(|| test_command(project.workspace().pyproject_toml()))
+        .unwrap_or_else(|| vec!["pytest".to_string()])
+        .into_iter()
+        .map(OsString::from);
+    let Some(executable) = command.next() else {
+        anyhow::bail!("The `tool.uv.test.command` setting must not be empty");
+    };
+    let command = RunCommand::External(
+        executable,
+        command
+            .chain(args.into_iter().map(OsString::from))
+            .collect(),
+    );
+
+    // Include the `test` group, if it's defined.
+    let groups = if pyproject_toml
+        .dependency_groups
+        .as_ref()
+        .is_some_and(|groups| groups.contains_key(&*TEST_GROUP))
+        || project
+            .workspace()
+            .workspace_dependency_groups()?
+            .contains_key(&*TEST_GROUP)
+    {
+        debug!("Including the `{}` dependency group", *TEST_GROUP);
+        groups.with_group(TEST_GROUP.clone())
+    } else {
+        groups
+    };
+
+    Box::pin(run(
+        project_dir,
+        None,
+        Some(command),
+        vec![],
+        show_resolution,
+        lock_check,
+        frozen,
+        active,
+        no_sync,
+        false,
+        None,
+        all_packages,
+        package,
+        false,
+        no_config,
+        extras,
+        groups,
+        editable,
+        modifications,
+        python,
+        None,
+        install_mirrors,
+        settings,
+        client_builder,
+        python_preference,
+        python_downloads,
+        installer_metadata,
+        concurrency,
+        cache,
+        printer,
+        env_file,
+        preview,
+        max_recursion_depth,
+    ))
+    .await
+}
+
+/// Return the test command configured in a `pyproject.toml`, if any.
+fn test_command(pyproject_toml: &PyProjectToml) -> Option<Vec<String>> {
+    pyproject_toml
+        .tool
+        .as_ref()?
+        .uv
+        .as_ref()?
+        .test
+        .as_ref()?
+        .command
+        .clone()
+}
@@ -1,21 +1,29 @@
 use std::path::Path;
 
 use anstream::print;
-use anyhow::{Error, Result};
+use anyhow::{Error, Result, bail};
+use console::{Key, Term, style, truncate_str};
 use futures::StreamExt;
+use rustc_hash::FxHashMap;
 use tokio::sync::Semaphore;
+use tracing::debug;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, DependencyGroups, TargetTriple};
-use uv_distribution_types::IndexCapabilities;
+use uv_distribution_types::{IndexCapabilities, Name};
+use uv_installer::SitePackages;
 use uv_normalize::DefaultGroups;
 use uv_normalize::PackageName;
-use uv_preview::Preview;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
-use uv_resolver::{PackageMap, TreeDisplay};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::Metadata23;
+use uv_python::{
+    PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
+};
+use uv_resolver::{PackageMap, TreeDisplay, TreeExplorer};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::pip::latest::LatestClient;
@@ -47,6 +55,7 @@ pub(crate) async fn tree(
     invert: bool,
     outdated: bool,
     show_sizes: bool,
+    interactive: bool,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python: Option<String>,
@@ -62,6 +71,18 @@ pub(crate) async fn tree(
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    if interactive {
+        if !preview.is_enabled(PreviewFeatures::TREE_INTERACTIVE) {
+            warn_user!(
+                "The `--interactive` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeatures::TREE_INTERACTIVE
+            );
+        }
+        if !Term::stdout().is_term() {
+            bail!("`uv tree --interactive` requires an interactive terminal");
+        }
+    }
+
     // Find the project requirements.
     let workspace_cache = WorkspaceCache::default();
     let workspace;
@@ -201,6 +222,12 @@ pub(crate) async fn tree(
                 resolution: _,
                 prerelease: _,
                 fork_strategy: _,
+                yanked_policy: _,
+                allow_yanked: _,
+                known_incompatibilities: _,
+                max_backtracks: _,
+                resolution_timeout: _,
+                prefer_smaller_artifacts: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
@@ -285,7 +312,236 @@ pub(crate) async fn tree(
         show_sizes,
     );
 
+    if interactive {
+        // Show the licenses of the packages installed in the project environment, if any.
+        let licenses = match target {
+            LockTarget::Workspace(workspace) => read_licenses(&workspace.venv(Some(false)), cache),
+            LockTarget::Script(_) => FxHashMap::default(),
+        };
+        explore(TreeExplorer::new(&tree).with_licenses(licenses))?;
+        return Ok(ExitStatus::Success);
+    }
+
     print!("{tree}");
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the license of each package installed in the environment at the given path.
+fn read_licenses(root: &Path, cache: &Cache) -> FxHashMap<PackageName, String> {
+    let site_packages = match PythonEnvironment::from_root(root, cache)
+        .map_err(Error::from)
+        .and_then(|environment| SitePackages::from_environment(&environment))
+    {
+        Ok(site_packages) => site_packages,
+        Err(err) => {
+            debug!("Unable to read licenses from `{}`: {err}", root.display());
+            return FxHashMap::default();
+        }
+    };
+
+    site_packages
+        .iter()
+        .filter_map(|dist| {
+            let contents = fs_err::read(dist.install_path().join("METADATA")).ok()?;
+            let metadata = Metadata23::parse(&contents).ok()?;
+            let license = metadata
+                .license_expression
+                .or(metadata.license)
+                .filter(|license| !license.trim().is_empty())?;
+            Some((dist.name().clone(), license))
+        })
+        .collect()
+}
+
+/// The view shown by [`explore`].
+enum View {
+    /// The dependency tree.
+    Tree,
+    /// The reverse dependencies of the selected package.
+    Dependents {
+        dependents: Vec<String>,
+        selected: usize,
+    },
+}
+
+/// Explore the dependency tree in the terminal, until the user quits.
+fn explore(mut explorer: TreeExplorer) -> Result<()> {
+    let term = Term::stdout();
+    term.hide_cursor()?;
+    let result = explore_loop(&term, &mut explorer);
+    term.clear_screen()?;
+    term.show_cursor()?;
+    term.flush()?;
+    result
+}
+
+fn explore_loop(term: &Term, explorer: &mut TreeExplorer) -> Result<()> {
+    let mut view = View::Tree;
+    let mut status = String::new();
+    let mut offset = 0;
+
+    loop {
+        render(term, explorer, &view, &status, &mut offset)?;
+        let key = term.read_key_raw()?;
+        status.clear();
+
+        match &mut view {
+            View::Tree => match key {
+                Key::Char('q') | Key::Escape | Key::CtrlC => return Ok(()),
+                Key::ArrowUp | Key::Char('k') => explorer.select_previous(),
+                Key::ArrowDown | Key::Char('j') => explorer.select_next(),
+                Key::PageUp => explorer.select(explorer.selected().saturating_sub(page(term))),
+                Key::PageDown => explorer.select(explorer.selected() + page(term)),
+                Key::Home | Key::Char('g') => explorer.select(0),
+                Key::End | Key::Char('G') => explorer.select(usize::MAX),
+                Key::ArrowRight | Key::Char('l') => explorer.expand(),
+                Key::ArrowLeft | Key::Char('h') => explorer.collapse(),
+                Key::Enter | Key::Char(' ') => explorer.toggle(),
+                Key::Char('/') => {
+                    let (height, _) = term.size();
+                    term.move_cursor_to(0, usize::from(height).saturating_sub(1))?;
+                    term.clear_line()?;
+                    let query = uv_console::input("/", term)?;
+                    term.hide_cursor()?;
+                    let matches = explorer.search(&query);
+                    if !query.trim().is_empty() {
+                        status = if matches == 0 {
+                            format!("No packages match `{}`", query.trim())
+                        } else {
+                            format!("Match 1 of {matches}")
+                        };
+                    }
+                }
+                Key::Char('n') => {
+                    if let Some((index, matches)) = explorer.next_match() {
+                        status = format!("Match {index} of {matches}");
+                    }
+                }
+                Key::Char('N') => {
+                    if let Some((index, matches)) = explorer.previous_match() {
+                        status = format!("Match {index} of {matches}");
+                    }
+                }
+                Key::Char('r') => {
+                    let dependents = explorer.dependents();
+                    if dependents.is_empty() {
+                        status = "The selected package has no reverse dependencies".to_string();
+                    } else {
+                        view = View::Dependents {
+                            dependents,
+                            selected: 0,
+                        };
+                    }
+                }
+                _ => {}
+            },
+            View::Dependents {
+                dependents,
+                selected,
+            } => match key {
+                Key::CtrlC => return Ok(()),
+                Key::Char('q' | 'h') | Key::Escape | Key::ArrowLeft => {
+                    view = View::Tree;
+                }
+                Key::ArrowUp | Key::Char('k') => *selected = selected.saturating_sub(1),
+                Key::ArrowDown | Key::Char('j') => {
+                    *selected = (*selected + 1).min(dependents.len() - 1);
+                }
+                Key::Enter | Key::ArrowRight | Key::Char('l') => {
+                    if !explorer.jump_to_dependent(*selected) {
+                        status =
+                            format!("`{}` is not displayed in the tree", dependents[*selected]);
+                    }
+                    view = View::Tree;
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+/// The number of rows to move for a page up or page down.
+fn page(term: &Term) -> usize {
+    let (height, _) = term.size();
+    usize::from(height).saturating_sub(2).max(1)
+}
+
+/// Draw the current view to the terminal.
+fn render(
+    term: &Term,
+    explorer: &TreeExplorer,
+    view: &View,
+    status: &str,
+    offset: &mut usize,
+) -> std::io::Result<()> {
+    let (height, width) = term.size();
+    let (height, width) = (usize::from(height), usize::from(width));
+
+    let details = explorer.details();
+
+    // Reserve a line for the header, and lines for the separator, details, and status.
+    let footer = details.len() + 2;
+    let body = height.saturating_sub(footer + 1).max(1);
+
+    let mut lines = Vec::with_capacity(height);
+    match view {
+        View::Tree => {
+            lines.push(
+                style("↑/↓ move · ←/→ collapse/expand · / search · n/N next/previous match · r reverse dependencies · q quit")
+                    .for_stdout()
+                    .dim()
+                    .to_string(),
+            );
+
+            // Scroll such that the selected row is visible.
+            let selected = explorer.selected();
+            if selected < *offset {
+                *offset = selected;
+            } else if selected >= *offset + body {
+                *offset = selected + 1 - body;
+            }
+
+            for (index, row) in explorer.rows().iter().enumerate().skip(*offset).take(body) {
+                let line = truncate_str(&row.to_string(), width, "…").into_owned();
+                if index == selected {
+                    lines.push(style(line).for_stdout().reverse().to_string());
+                } else {
+                    lines.push(line);
+                }
+            }
+        }
+        View::Dependents {
+            dependents,
+            selected,
+        } => {
+            lines.push(
+                style("Reverse dependencies · enter jump · esc back")
+                    .for_stdout()
+                    .dim()
+                    .to_string(),
+            );
+            let skip = (selected + 1).saturating_sub(body);
+            for (index, dependent) in dependents.iter().enumerate().skip(skip).take(body) {
+                let line = truncate_str(dependent, width, "…").into_owned();
+                if index == *selected {
+                    lines.push(style(line).for_stdout().reverse().to_string());
+                } else {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+    lines.resize(body + 1, String::new());
+
+    lines.push(style("─".repeat(width)).for_stdout().dim().to_string());
+    for (field, value) in details {
+        let line = format!("{}: {value}", style(field).for_stdout().bold());
+        lines.push(truncate_str(&line, width, "…").into_owned());
+    }
+    lines.push(style(status).for_stdout().yellow().to_string());
+
+    term.clear_screen()?;
+    term.write_str(&lines.join("\n"))?;
+    term.flush()
+}
@@ -0,0 +1,290 @@
+use std::fmt::Write;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::prelude::BASE64_URL_SAFE_NO_PAD;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_cache::Cache;
+use uv_distribution_types::{InstalledDist, InstalledDistKind, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_python::PythonEnvironment;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+
+/// Verify the integrity of the project environment.
+pub(crate) async fn verify(
+    project_dir: &Path,
+    active: Option<bool>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::VERIFY) {
+        warn_user!(
+            "`uv verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::VERIFY
+        );
+    }
+
+    let start = Instant::now();
+
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+    let workspace = project.workspace();
+
+    // Find the project environment, without creating it.
+    let root = workspace.venv(active);
+    let environment = PythonEnvironment::from_root(&root, cache).with_context(|| {
+        format!(
+            "No project environment found at `{}`; run `{}` to create it",
+            root.user_display(),
+            "uv sync".green()
+        )
+    })?;
+
+    let lock = LockTarget::Workspace(workspace).read().await?;
+
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let packages = site_packages
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
+        .collect::<Vec<_>>();
+
+    let mut issues = Vec::new();
+
+    // Compare the installed files against the `RECORD` of each distribution.
+    let mut recorded = FxHashSet::default();
+    let mut top_level = FxHashSet::default();
+    for dist in &packages {
+        if !matches!(
+            dist.kind,
+            InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+        ) {
+            debug!("Skipping verification of non-wheel distribution: {dist}");
+            continue;
+        }
+        verify_record(dist, &mut recorded, &mut top_level, &mut issues)?;
+    }
+
+    // Find any files in the directories of installed distributions that aren't part of any
+    // distribution.
+    for (site_packages, directory) in top_level.iter().sorted() {
+        for entry in WalkDir::new(directory)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "__pycache__")
+        {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            if !recorded.contains(entry.path()) {
+                issues.push(Issue::ExtraneousFile(relative_to(
+                    entry.path(),
+                    site_packages,
+                )));
+            }
+        }
+    }
+
+    // Compare the installed distributions against the lockfile.
+    if let Some(lock) = lock.as_ref() {
+        for dist in &packages {
+            let locked = lock
+                .packages()
+                .iter()
+                .filter(|package| package.name() == dist.name())
+                .collect::<Vec<_>>();
+            if locked.is_empty() {
+                issues.push(Issue::NotLocked(dist.to_string()));
+            } else if !locked.iter().any(|package| {
+                package
+                    .version()
+                    .is_none_or(|version| version == dist.version())
+            }) {
+                issues.push(Issue::VersionMismatch(
+                    dist.to_string(),
+                    locked
+                        .iter()
+                        .filter_map(|package| package.version())
+                        .map(ToString::to_string)
+                        .join(", "),
+                ));
+            }
+        }
+    } else {
+        debug!("No lockfile found; skipping lockfile verification");
+    }
+
+    let s = if packages.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} {}",
+            format!("{} package{}", packages.len(), s).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+
+    if issues.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All installed files match their records".dimmed()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let s = if issues.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Found {}", format!("{} issue{s}", issues.len()).bold()).dimmed()
+    )?;
+    for issue in &issues {
+        writeln!(printer.stderr(), "{}", issue.bold())?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Verify the files of an installed distribution against its `RECORD`.
+fn verify_record(
+    dist: &InstalledDist,
+    recorded: &mut FxHashSet<PathBuf>,
+    top_level: &mut FxHashSet<(PathBuf, PathBuf)>,
+    issues: &mut Vec<Issue>,
+) -> Result<()> {
+    let dist_info = dist.install_path();
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(());
+    };
+
+    let record_path = dist_info.join("RECORD");
+    let record = match fs_err::File::open(&record_path) {
+        Ok(mut file) => uv_install_wheel::read_record_file(&mut file)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            issues.push(Issue::MissingFile(
+                dist.to_string(),
+                relative_to(&record_path, site_packages),
+            ));
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    for entry in record {
+        let relative = Path::new(&entry.path);
+        let path = site_packages.join(relative);
+
+        // Track the top-level directories of the distribution within `site-packages`, ignoring
+        // any files installed elsewhere (e.g., scripts) and the `.dist-info` directory.
+        let mut components = relative.components();
+        if let (Some(Component::Normal(first)), Some(_)) = (components.next(), components.next()) {
+            let is_metadata = Path::new(first)
+                .extension()
+                .is_some_and(|ext| ext == "dist-info" || ext == "data");
+            if !is_metadata {
+                top_level.insert((site_packages.to_path_buf(), site_packages.join(first)));
+            }
+        }
+
+        let Some(hash) = entry.hash.as_deref() else {
+            // Files without a hash (e.g., the `RECORD` itself, or bytecode) are not verified.
+            recorded.insert(path);
+            continue;
+        };
+
+        match fs_err::File::open(&path) {
+            Ok(mut file) => {
+                if let Some(expected) = hash.strip_prefix("sha256=") {
+                    let actual = sha256(&mut file)?;
+                    if actual != expected {
+                        issues.push(Issue::ModifiedFile(dist.to_string(), entry.path.clone()));
+                    }
+                } else {
+                    debug!("Skipping unsupported hash for `{}`: {hash}", path.display());
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                issues.push(Issue::MissingFile(dist.to_string(), entry.path.clone()));
+            }
+            Err(err) => return Err(err.into()),
+        }
+        recorded.insert(path);
+    }
+
+    Ok(())
+}
+
+/// Compute the URL-safe base64-encoded SHA-256 digest of a file, as used in `RECORD` files.
+fn sha256(reader: &mut impl Read) -> Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(reader, &mut hasher)?;
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(hasher.finalize()))
+}
+
+/// Display a path relative to the `site-packages` directory.
+fn relative_to(path: &Path, site_packages: &Path) -> String {
+    path.strip_prefix(site_packages)
+        .unwrap_or(path)
+        .portable_display()
+        .to_string()
+}
+
+#[derive(Debug)]
+enum Issue {
+    /// A file listed in a distribution's `RECORD` has a different hash.
+    ModifiedFile(String, String),
+    /// A file listed in a distribution's `RECORD` does not exist.
+    MissingFile(String, String),
+    /// A file in a distribution's directory is not listed in any `RECORD`.
+    ExtraneousFile(String),
+    /// An installed distribution is not present in the lockfile.
+    NotLocked(String),
+    /// An installed distribution has a different version than the lockfile.
+    VersionMismatch(String, String),
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ModifiedFile(dist, path) => {
+                write!(f, "The file `{path}` of `{dist}` was modified")
+            }
+            Self::MissingFile(dist, path) => {
+                write!(f, "The file `{path}` of `{dist}` is missing")
+            }
+            Self::ExtraneousFile(path) => {
+                write!(
+                    f,
+                    "The file `{path}` is not recorded by any installed package"
+                )
+            }
+            Self::NotLocked(dist) => write!(f, "The package `{dist}` is not in the lockfile"),
+            Self::VersionMismatch(dist, versions) => write!(
+                f,
+                "The package `{dist}` does not match the locked version ({versions})"
+            ),
+        }
+    }
+}
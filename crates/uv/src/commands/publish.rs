@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::sync::Arc;
 
@@ -11,9 +12,11 @@ use uv_cache::Cache;
 use uv_client::{AuthIntegration, BaseClient, BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_types::{IndexCapabilities, IndexLocations, IndexUrl};
+use uv_preview::{Preview, PreviewFeatures};
 use uv_publish::{
-    CheckUrlClient, FormMetadata, PublishError, TrustedPublishResult, check_trusted_publishing,
-    group_files_for_publishing, upload,
+    CheckUrlClient, FormMetadata, PublishError, StaticIndex, TrustedPublishResult,
+    UploadDistribution, UploadSession, check_trusted_publishing, group_files_for_publishing,
+    supports_upload_sessions, upload,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_settings::EnvironmentOptions;
@@ -38,6 +41,7 @@ pub(crate) async fn publish(
     dry_run: bool,
     no_attestations: bool,
     cache: &Cache,
+    preview: Preview,
     printer: Printer,
 ) -> Result<ExitStatus> {
     if client_builder.is_offline() {
@@ -130,6 +134,51 @@ pub(crate) async fn publish(
         .auth_integration(AuthIntegration::NoAuthMiddleware)
         .wrap_existing(&upload_client);
 
+    // Publishing to a directory or S3 bucket doesn't use the upload API, so we write the files and
+    // regenerate the index listings directly.
+    if let Some(static_index) = StaticIndex::from_url(&publish_url)? {
+        debug!("Publishing to a static index");
+        for group in groups {
+            let size = fs_err::metadata(&group.file)?.len();
+            let (bytes, unit) = human_readable_bytes(size);
+            if dry_run {
+                writeln!(
+                    printer.stderr(),
+                    "{} {} {}",
+                    "Checking".bold().cyan(),
+                    group.filename,
+                    format!("({bytes:.1}{unit})").dimmed()
+                )?;
+                continue;
+            }
+            writeln!(
+                printer.stderr(),
+                "{} {} {}",
+                "Uploading".bold().green(),
+                group.filename,
+                format!("({bytes:.1}{unit})").dimmed()
+            )?;
+            let uploaded = static_index
+                .publish(&group, &upload_client)
+                .await
+                .map_err(|err| {
+                    PublishError::StaticIndex(
+                        group.file.clone(),
+                        Box::new(publish_url.clone()),
+                        Box::new(err),
+                    )
+                })?;
+            if !uploaded {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    "File already exists, skipping".dimmed()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     let retry_policy = client_builder.retry_policy();
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
@@ -167,6 +216,26 @@ pub(crate) async fn publish(
         None
     };
 
+    // If the index supports it, stage the files of each release in an upload session, such that
+    // the release is published atomically.
+    if !dry_run
+        && preview.is_enabled(PreviewFeatures::PUBLISH_UPLOAD_V2)
+        && supports_upload_sessions(&publish_url, &upload_client, &credentials).await
+    {
+        debug!("Publishing with upload sessions");
+        publish_upload_sessions(
+            groups,
+            &publish_url,
+            &upload_client,
+            &credentials,
+            check_url_client.as_ref(),
+            &download_concurrency,
+            printer,
+        )
+        .await?;
+        return Ok(ExitStatus::Success);
+    }
+
     for group in groups {
         if let Some(check_url_client) = &check_url_client {
             if uv_publish::check_url(
@@ -255,6 +324,111 @@ pub(crate) async fn publish(
     Ok(ExitStatus::Success)
 }
 
+/// Publish the files using one [PEP 694](https://peps.python.org/pep-0694/) upload session per
+/// release.
+///
+/// If any file of a release fails to upload, the session is cancelled and none of the release's
+/// files are published.
+async fn publish_upload_sessions(
+    groups: Vec<UploadDistribution>,
+    publish_url: &DisplaySafeUrl,
+    upload_client: &BaseClient,
+    credentials: &Credentials,
+    check_url_client: Option<&CheckUrlClient<'_>>,
+    download_concurrency: &Semaphore,
+    printer: Printer,
+) -> Result<()> {
+    let mut releases: BTreeMap<_, Vec<UploadDistribution>> = BTreeMap::new();
+    for group in groups {
+        let release = (
+            group.filename.name().clone(),
+            group.filename.version().clone(),
+        );
+        releases.entry(release).or_default().push(group);
+    }
+
+    for ((name, version), groups) in releases {
+        let release = format!("{name}=={version}");
+        let session_error = |err| {
+            PublishError::UploadSession(
+                release.clone(),
+                Box::new(publish_url.clone()),
+                Box::new(err),
+            )
+        };
+
+        let mut pending = Vec::with_capacity(groups.len());
+        for group in groups {
+            if let Some(check_url_client) = check_url_client {
+                if uv_publish::check_url(
+                    check_url_client,
+                    &group.file,
+                    &group.filename,
+                    download_concurrency,
+                )
+                .await?
+                {
+                    writeln!(
+                        printer.stderr(),
+                        "File {} already exists, skipping",
+                        group.filename
+                    )?;
+                    continue;
+                }
+            }
+            pending.push(group);
+        }
+        if pending.is_empty() {
+            continue;
+        }
+
+        let session = UploadSession::create(
+            publish_url,
+            name.as_ref(),
+            &version.to_string(),
+            upload_client,
+            credentials,
+        )
+        .await
+        .map_err(session_error)?;
+
+        let mut result = Ok(());
+        for group in &pending {
+            let size = fs_err::metadata(&group.file)?.len();
+            let (bytes, unit) = human_readable_bytes(size);
+            writeln!(
+                printer.stderr(),
+                "{} {} {}",
+                "Uploading".bold().green(),
+                group.filename,
+                format!("({bytes:.1}{unit})").dimmed()
+            )?;
+            let reporter = PublishReporter::single(printer);
+            result = session
+                .upload(group, upload_client, credentials, Arc::new(reporter))
+                .await;
+            if result.is_err() {
+                break;
+            }
+        }
+
+        if let Err(err) = result {
+            if let Err(cancel_err) = session.cancel(upload_client, credentials).await {
+                debug!("Failed to cancel upload session for {release}: {cancel_err}");
+            }
+            return Err(session_error(err).into());
+        }
+
+        session
+            .publish(upload_client, credentials)
+            .await
+            .map_err(session_error)?;
+        info!("Published {release}");
+    }
+
+    Ok(())
+}
+
 /// Whether to allow prompting for username and password.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Prompt {
@@ -1,15 +1,19 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonFindFormat;
 use uv_client::BaseClientBuilder;
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_fs::Simplified;
+use uv_pep440::Version;
 use uv_preview::Preview;
 use uv_python::downloads::ManagedPythonDownloadList;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -22,12 +26,25 @@ use crate::commands::{
 };
 use crate::printer::Printer;
 
+#[derive(Debug, Serialize)]
+struct PrintData {
+    key: String,
+    path: String,
+    version: Version,
+    implementation: String,
+    variant: String,
+    arch: String,
+    os: String,
+    libc: String,
+}
+
 /// Find a Python interpreter.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
+    output_format: PythonFindFormat,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -69,7 +86,10 @@ pub(crate) async fn find(
         python_request,
         requires_python,
     } = WorkspacePython::from_request(
-        request.map(|request| PythonRequest::parse(&request)),
+        request
+            .as_deref()
+            .map(PythonRequest::parse_with_filters)
+            .transpose()?,
         project.as_ref().map(VirtualProject::workspace),
         &groups,
         project_dir,
@@ -105,19 +125,7 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            python.interpreter().python_version()
-        )?;
-    } else {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            std::path::absolute(python.interpreter().sys_executable())?.simplified_display()
-        )?;
-    }
+    print_interpreter(python.interpreter(), show_version, output_format, printer)?;
 
     Ok(ExitStatus::Success)
 }
@@ -125,6 +133,7 @@ pub(crate) async fn find(
 pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
     show_version: bool,
+    output_format: PythonFindFormat,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -157,15 +166,41 @@ pub(crate) async fn find_script(
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
-    if show_version {
-        writeln!(printer.stdout(), "{}", interpreter.python_version())?;
-    } else {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            std::path::absolute(interpreter.sys_executable())?.simplified_display()
-        )?;
-    }
+    print_interpreter(&interpreter, show_version, output_format, printer)?;
 
     Ok(ExitStatus::Success)
 }
+
+/// Display the discovered interpreter in the requested format.
+fn print_interpreter(
+    interpreter: &Interpreter,
+    show_version: bool,
+    output_format: PythonFindFormat,
+    printer: Printer,
+) -> Result<()> {
+    let path = std::path::absolute(interpreter.sys_executable())?;
+    match output_format {
+        PythonFindFormat::Json => {
+            let key = interpreter.key();
+            let data = PrintData {
+                key: key.to_string(),
+                path: path.simplified_display().to_string(),
+                version: interpreter.python_version().clone(),
+                implementation: key.implementation().to_string(),
+                variant: key.variant().to_string(),
+                arch: key.arch().to_string(),
+                os: key.os().to_string(),
+                libc: key.libc().to_string(),
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        PythonFindFormat::Text => {
+            if show_version {
+                writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+            } else {
+                writeln!(printer.stdout(), "{}", path.simplified_display())?;
+            }
+        }
+    }
+    Ok(())
+}
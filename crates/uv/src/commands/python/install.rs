@@ -23,18 +23,22 @@ use uv_python::downloads::{
     PythonDownloadRequest,
 };
 use uv_python::managed::{
-    ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
-    create_link_to_executable, python_executable_dir,
+    ManagedPythonInstallation, ManagedPythonInstallations, PythonInstallScope,
+    PythonMinorVersionLink, create_link_to_executable,
 };
 use uv_python::{
-    PythonDownloads, PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionFilePreference, VersionRequest,
+    PythonDownloadAttestations, PythonDownloads, PythonInstallationKey,
+    PythonInstallationMinorVersionKey, PythonRequest, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionFilePreference, VersionRequest,
 };
 use uv_shell::Shell;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::{warn_user, write_error_chain};
 
-use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::python::relink::{
+    RelinkedEnvironment, RelinkedEnvironmentKind, relink_environments,
+};
+use crate::commands::python::{ChangeEvent, ChangeEventKind, ensure_system_scope_writable};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
@@ -181,14 +185,17 @@ pub(crate) async fn install(
     targets: Vec<String>,
     reinstall: bool,
     upgrade: PythonUpgrade,
+    relink: bool,
     bin: Option<bool>,
     registry: Option<bool>,
     force: bool,
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
+    python_download_attestations: Option<PythonDownloadAttestations>,
     python_downloads_json_url: Option<String>,
     client_builder: BaseClientBuilder<'_>,
     default: bool,
+    scope: PythonInstallScope,
     python_downloads: PythonDownloads,
     no_config: bool,
     preview: Preview,
@@ -220,6 +227,23 @@ pub(crate) async fn install(
         anyhow::bail!("The `--default` flag cannot be used with multiple targets");
     }
 
+    // Installations for all users are stored in a machine-wide location by default.
+    let install_dir = install_dir.or_else(|| scope.install_dir());
+    let bin_dir = if matches!(bin, Some(false)) {
+        None
+    } else {
+        Some(scope.executable_dir()?)
+    };
+    if scope == PythonInstallScope::System {
+        ensure_system_scope_writable(
+            &install_dir
+                .iter()
+                .chain(bin_dir.iter())
+                .map(PathBuf::as_path)
+                .collect::<Vec<_>>(),
+        )?;
+    }
+
     // Read the existing installations, lock the directory for the duration
     let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
     let installations_dir = installations.root();
@@ -473,7 +497,9 @@ pub(crate) async fn install(
                         reinstall,
                         python_install_mirror.as_deref(),
                         pypy_install_mirror.as_deref(),
+                        python_download_attestations.as_ref(),
                         Some(&reporter),
+                        preview,
                     )
                     .await,
             )
@@ -518,12 +544,6 @@ pub(crate) async fn install(
         }
     }
 
-    let bin_dir = if matches!(bin, Some(false)) {
-        None
-    } else {
-        Some(python_executable_dir()?)
-    };
-
     let installations: Vec<_> = downloaded.iter().chain(satisfied.iter().copied()).collect();
 
     // Ensure that the installations are _complete_ for both downloaded installations and existing
@@ -537,6 +557,15 @@ pub(crate) async fn install(
             e.warn_user(installation);
         }
 
+        // Make installations for all users readable by all users, regardless of the permissions of
+        // the extracted archive.
+        #[cfg(unix)]
+        if scope == PythonInstallScope::System {
+            use std::os::unix::fs::PermissionsExt;
+
+            fs_err::set_permissions(installation.path(), std::fs::Permissions::from_mode(0o755))?;
+        }
+
         let upgradeable = (default || is_default_install)
             || requested_minor_versions.contains(&installation.key().version().python_version());
 
@@ -564,7 +593,7 @@ pub(crate) async fn install(
         if !matches!(registry, Some(false)) {
             #[cfg(windows)]
             {
-                match uv_python::windows_registry::create_registry_entry(installation) {
+                match uv_python::windows_registry::create_registry_entry(installation, scope) {
                     Ok(()) => {}
                     Err(err) => {
                         errors.push((
@@ -599,6 +628,27 @@ pub(crate) async fn install(
         }
     }
 
+    // Re-link any environments that use an older patch release of the upgraded versions.
+    let relinked = if relink {
+        let known = installations
+            .iter()
+            .copied()
+            .chain(existing_installations.iter())
+            .collect::<Vec<_>>();
+        let upgraded = minor_versions
+            .iter()
+            .filter(|(key, _)| {
+                installations
+                    .iter()
+                    .any(|installation| installation.minor_version_key() == *key)
+            })
+            .map(|(_, installation)| installation)
+            .collect::<Vec<_>>();
+        relink_environments(project_dir, &known, &upgraded).await?
+    } else {
+        Vec::new()
+    };
+
     if changelog.installed.is_empty() && errors.is_empty() {
         if is_default_install {
             if matches!(
@@ -657,6 +707,7 @@ pub(crate) async fn install(
                 writeln!(printer.stderr(), "All requested versions already installed")?;
             }
         }
+        report_relinked(&relinked, printer)?;
         return Ok(ExitStatus::Success);
     }
 
@@ -759,10 +810,26 @@ pub(crate) async fn install(
         }
 
         if let Some(bin_dir) = bin_dir.as_ref() {
+            // On Windows, the executable directory for all users is added to the system `PATH`.
+            #[cfg(windows)]
+            if scope == PythonInstallScope::System {
+                if uv_shell::windows::prepend_system_path(bin_dir)? {
+                    writeln!(
+                        printer.stderr(),
+                        "Added `{}` to the system PATH; restart your shell to use the installed executables",
+                        bin_dir.simplified_display().cyan()
+                    )?;
+                }
+            } else {
+                warn_if_not_on_path(bin_dir);
+            }
+            #[cfg(not(windows))]
             warn_if_not_on_path(bin_dir);
         }
     }
 
+    report_relinked(&relinked, printer)?;
+
     if !errors.is_empty() {
         // If there are only side-effect install errors and the user didn't opt-in, we're only going
         // to warn
@@ -827,6 +894,25 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Report the environments that were re-linked to an upgraded Python installation.
+fn report_relinked(relinked: &[RelinkedEnvironment], printer: Printer) -> Result<()> {
+    for environment in relinked {
+        let name = match &environment.kind {
+            RelinkedEnvironmentKind::Project => {
+                format!("`{}`", environment.root.user_display().cyan())
+            }
+            RelinkedEnvironmentKind::Tool(name) => format!("tool `{}`", name.cyan()),
+        };
+        writeln!(
+            printer.stderr(),
+            "Relinked {name} to {} (from {})",
+            format!("Python {}", environment.to.version()).bold(),
+            environment.from.version(),
+        )?;
+    }
+    Ok(())
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 ///
 /// This function is fallible, but errors are pushed to `errors` instead of being thrown.
@@ -8,11 +8,13 @@ use uv_preview::Preview;
 use anyhow::Result;
 use itertools::Either;
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_fs::Simplified;
-use uv_python::downloads::{ManagedPythonDownloadList, PythonDownloadRequest};
+use uv_python::downloads::{
+    ManagedPythonDownload, ManagedPythonDownloadList, PythonDownloadRequest,
+};
 use uv_python::{
     DiscoveryError, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonNotFound,
     PythonPreference, PythonRequest, PythonSource, find_python_installations,
@@ -49,6 +51,12 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    source: &'static str,
+    install_path: Option<String>,
+    sha256: Option<String>,
+    build: Option<String>,
+    end_of_life: Option<&'static str>,
+    is_end_of_life: Option<bool>,
 }
 
 /// List available Python installations.
@@ -81,6 +89,8 @@ pub(crate) async fn list(
     let download_list =
         ManagedPythonDownloadList::new(&client, python_downloads_json_url.as_deref()).await?;
     let mut output = BTreeSet::new();
+    let mut download_metadata = FxHashMap::<_, &ManagedPythonDownload>::default();
+    let mut installation_metadata = FxHashMap::default();
     if let Some(base_download_request) = base_download_request {
         let download_request = match kinds {
             PythonListKinds::Installed => None,
@@ -118,6 +128,7 @@ pub(crate) async fn list(
             .filter(|download| !download.key().variant().is_debug());
 
         for download in downloads {
+            download_metadata.insert(download.url(), download);
             output.insert((
                 download.key().clone(),
                 Kind::Download,
@@ -158,11 +169,15 @@ pub(crate) async fn list(
             } else {
                 Kind::System
             };
-            output.insert((
-                installation.key(),
-                kind,
-                Either::Left(installation.interpreter().real_executable().to_path_buf()),
-            ));
+            let executable = installation.interpreter().real_executable().to_path_buf();
+            installation_metadata.insert(
+                executable.clone(),
+                (
+                    *installation.source(),
+                    installation.interpreter().sys_base_prefix().to_path_buf(),
+                ),
+            );
+            output.insert((installation.key(), kind, Either::Left(executable)));
         }
     }
 
@@ -221,16 +236,28 @@ pub(crate) async fn list(
 
     match output_format {
         PythonListFormat::Json => {
+            let today = jiff::Zoned::now().strftime("%Y-%m").to_string();
             let data = include
                 .iter()
                 .map(|(key, uri)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
+                    let mut install_path_or_none: Option<String> = None;
+                    let mut sha256_or_none: Option<String> = None;
+                    let mut build_or_none: Option<String> = None;
+                    let source;
                     match uri {
                         Either::Left(path) => {
                             path_or_none = Some(path.user_display().to_string());
 
+                            if let Some((python_source, prefix)) = installation_metadata.get(path) {
+                                source = source_name(*python_source);
+                                install_path_or_none = Some(prefix.user_display().to_string());
+                            } else {
+                                source = "system";
+                            }
+
                             let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
                             if is_symlink {
                                 symlink_or_none =
@@ -239,10 +266,17 @@ pub(crate) async fn list(
                         }
                         Either::Right(url) => {
                             url_or_none = Some((*url).to_string());
+                            source = "download";
+
+                            if let Some(download) = download_metadata.get(url) {
+                                sha256_or_none = download.sha256().map(ToString::to_string);
+                                build_or_none = download.build().map(ToString::to_string);
+                            }
                         }
                     }
                     let version = key.version();
                     let release = version.release();
+                    let end_of_life = key.end_of_life();
 
                     Ok(PrintData {
                         key: key.to_string(),
@@ -261,6 +295,12 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        source,
+                        install_path: install_path_or_none,
+                        sha256: sha256_or_none,
+                        build: build_or_none,
+                        end_of_life,
+                        is_end_of_life: end_of_life.map(|end_of_life| end_of_life < today.as_str()),
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -310,3 +350,19 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// Return the name used for a [`PythonSource`] in the JSON output.
+fn source_name(source: PythonSource) -> &'static str {
+    match source {
+        PythonSource::Managed => "managed",
+        PythonSource::ProvidedPath => "provided-path",
+        PythonSource::ActiveEnvironment | PythonSource::DiscoveredEnvironment => {
+            "virtual-environment"
+        }
+        PythonSource::CondaPrefix | PythonSource::BaseCondaPrefix => "conda",
+        PythonSource::SearchPath | PythonSource::SearchPathFirst => "search-path",
+        PythonSource::Registry => "registry",
+        PythonSource::MicrosoftStore => "microsoft-store",
+        PythonSource::ParentInterpreter => "parent-interpreter",
+    }
+}
@@ -3,9 +3,17 @@ pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
+mod relink;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::Result;
+
+use uv_fs::Simplified;
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub(super) enum ChangeEventKind {
     /// The Python version was uninstalled.
@@ -21,3 +29,28 @@ pub(super) struct ChangeEvent {
     key: uv_python::PythonInstallationKey,
     kind: ChangeEventKind,
 }
+
+/// Ensure that the current user can write to the directories of a system-wide Python
+/// installation, failing with a hint to elevate privileges otherwise.
+pub(super) fn ensure_system_scope_writable(dirs: &[&Path]) -> Result<()> {
+    for dir in dirs {
+        let writable =
+            fs_err::create_dir_all(dir).and_then(|()| tempfile::tempfile_in(dir).map(drop));
+        match writable {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+                let hint = if cfg!(windows) {
+                    "run uv from an administrator terminal"
+                } else {
+                    "run uv as root, e.g., with `sudo`"
+                };
+                anyhow::bail!(
+                    "Managing Python for all users requires write access to `{}`; {hint}",
+                    dir.user_display()
+                );
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(())
+}
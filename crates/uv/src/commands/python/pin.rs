@@ -135,6 +135,7 @@ pub(crate) async fn pin(
         Some(&reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
@@ -246,6 +247,12 @@ pub(crate) async fn pin(
 
 fn pep440_version_from_request(request: &PythonRequest) -> Option<uv_pep440::Version> {
     let version_request = match request {
+        // e.g., `graalpy-24` requests a GraalPy release rather than a Python version.
+        PythonRequest::ImplementationVersion(implementation, version)
+            if implementation.is_implementation_version(version) =>
+        {
+            return None;
+        }
         PythonRequest::Version(version) | PythonRequest::ImplementationVersion(_, version) => {
             version
         }
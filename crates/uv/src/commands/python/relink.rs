@@ -0,0 +1,171 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_python::managed::ManagedPythonInstallation;
+use uv_python::{PyVenvConfiguration, PythonInstallationKey};
+use uv_tool::InstalledTools;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+/// A virtual environment that was re-linked to an upgraded Python installation.
+#[derive(Debug)]
+pub(super) struct RelinkedEnvironment {
+    /// The kind of the virtual environment.
+    pub(super) kind: RelinkedEnvironmentKind,
+    /// The root of the virtual environment.
+    pub(super) root: PathBuf,
+    /// The Python installation the virtual environment previously used.
+    pub(super) from: PythonInstallationKey,
+    /// The Python installation the virtual environment now uses.
+    pub(super) to: PythonInstallationKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum RelinkedEnvironmentKind {
+    /// The virtual environment of the current project.
+    Project,
+    /// The virtual environment of an installed tool.
+    Tool(uv_normalize::PackageName),
+}
+
+/// Re-link the project environment and any tool environments that use an older patch release of
+/// one of the `upgraded` installations to the upgraded installation.
+///
+/// `installations` includes every known managed Python installation, and is used to determine
+/// which installation an environment currently uses.
+pub(super) async fn relink_environments(
+    project_dir: &Path,
+    installations: &[&ManagedPythonInstallation],
+    upgraded: &[&ManagedPythonInstallation],
+) -> Result<Vec<RelinkedEnvironment>> {
+    let mut relinked = Vec::new();
+
+    // Re-link the project environment, if there is one.
+    match VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await
+    {
+        Ok(project) => {
+            let root = project.workspace().venv(None);
+            if let Some((from, to)) = relink_environment(&root, installations, upgraded)? {
+                relinked.push(RelinkedEnvironment {
+                    kind: RelinkedEnvironmentKind::Project,
+                    root,
+                    from,
+                    to,
+                });
+            }
+        }
+        Err(err) => {
+            debug!("Failed to discover virtual project: {err}");
+        }
+    }
+
+    // Re-link the tool environments.
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(relinked);
+        }
+        Err(err) => return Err(err.into()),
+    };
+    for (name, _) in installed_tools.tools()? {
+        let root = installed_tools.tool_dir(&name);
+        if let Some((from, to)) = relink_environment(&root, installations, upgraded)? {
+            relinked.push(RelinkedEnvironment {
+                kind: RelinkedEnvironmentKind::Tool(name),
+                root,
+                from,
+                to,
+            });
+        }
+    }
+
+    Ok(relinked)
+}
+
+/// Re-link the virtual environment at `root` to an upgraded Python installation, if it was
+/// created by uv from an older patch release of one of the `upgraded` installations.
+///
+/// Returns the keys of the previous and new installations if the environment was re-linked.
+fn relink_environment(
+    root: &Path,
+    installations: &[&ManagedPythonInstallation],
+    upgraded: &[&ManagedPythonInstallation],
+) -> Result<Option<(PythonInstallationKey, PythonInstallationKey)>> {
+    let cfg = root.join("pyvenv.cfg");
+    if !cfg.is_file() {
+        return Ok(None);
+    }
+    if !PyVenvConfiguration::parse(&cfg)?.is_uv() {
+        debug!(
+            "Skipping virtual environment not created by uv: {}",
+            root.user_display()
+        );
+        return Ok(None);
+    }
+
+    let content = fs_err::read_to_string(&cfg)?;
+    let Some(home) = content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "home").then(|| PathBuf::from(value.trim()))
+    }) else {
+        return Ok(None);
+    };
+
+    // Determine the installation that the environment uses. Environments that use a minor version
+    // link are upgraded transparently, and don't match any installation.
+    let Some(previous) = installations
+        .iter()
+        .find(|installation| home.starts_with(installation.path()))
+    else {
+        return Ok(None);
+    };
+    let Some(upgrade) = upgraded
+        .iter()
+        .find(|installation| installation.is_upgrade_of(previous))
+    else {
+        return Ok(None);
+    };
+
+    debug!(
+        "Re-linking virtual environment at `{}` from `{}` to `{}`",
+        root.user_display(),
+        previous.key(),
+        upgrade.key()
+    );
+
+    let executable = upgrade.executable(false);
+    let Some(python_home) = executable.parent() else {
+        return Ok(None);
+    };
+
+    // Point the `home` key at the upgraded installation, and update the recorded version.
+    let content = PyVenvConfiguration::set(
+        &content,
+        "home",
+        &python_home.simplified_display().to_string(),
+    );
+    let content =
+        PyVenvConfiguration::set(&content, "version_info", &upgrade.version().to_string());
+    fs_err::write(&cfg, content)?;
+
+    // On Unix, the environment's interpreter is a symlink to the base interpreter. On Windows, the
+    // launcher reads the `home` key from the `pyvenv.cfg`, so there's nothing more to do.
+    #[cfg(unix)]
+    {
+        let scripts = root.join("bin");
+        let link = scripts.join("python");
+        if fs_err::read_link(&link).is_ok_and(|target| target.starts_with(previous.path())) {
+            uv_fs::replace_symlink(&executable, &link)?;
+        }
+    }
+
+    Ok(Some((previous.key().clone(), upgrade.key().clone())))
+}
@@ -14,13 +14,11 @@ use tracing::{debug, warn};
 use uv_fs::Simplified;
 use uv_preview::Preview;
 use uv_python::downloads::PythonDownloadRequest;
-use uv_python::managed::{
-    ManagedPythonInstallations, PythonMinorVersionLink, python_executable_dir,
-};
+use uv_python::managed::{ManagedPythonInstallations, PythonInstallScope, PythonMinorVersionLink};
 use uv_python::{PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest};
 
 use crate::commands::python::install::format_executables;
-use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::python::{ChangeEvent, ChangeEventKind, ensure_system_scope_writable};
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
 
@@ -29,15 +27,24 @@ pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    scope: PythonInstallScope,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
+    // Installations for all users are stored in a machine-wide location by default.
+    let install_dir = install_dir.or_else(|| scope.install_dir());
+    if scope == PythonInstallScope::System {
+        ensure_system_scope_writable(
+            &install_dir.iter().map(PathBuf::as_path).collect::<Vec<_>>(),
+        )?;
+    }
+
     let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
 
     let _lock = installations.lock().await?;
 
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer, preview).await?;
+    do_uninstall(&installations, targets, all, scope, printer, preview).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -65,6 +72,7 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    scope: PythonInstallScope,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -116,6 +124,7 @@ async fn do_uninstall(
             {
                 uv_python::windows_registry::remove_orphan_registry_entries(
                     &installed_installations,
+                    scope,
                 );
             }
 
@@ -148,15 +157,20 @@ async fn do_uninstall(
         uv_python::windows_registry::remove_registry_entry(
             &matching_installations,
             all,
+            scope,
             &mut errors,
         );
-        uv_python::windows_registry::remove_orphan_registry_entries(&installed_installations);
+        uv_python::windows_registry::remove_orphan_registry_entries(
+            &installed_installations,
+            scope,
+        );
     }
 
     // Find and remove all relevant Python executables
     let mut uninstalled_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>> =
         FxHashMap::default();
-    for executable in python_executable_dir()?
+    for executable in scope
+        .executable_dir()?
         .read_dir()
         .into_iter()
         .flatten()
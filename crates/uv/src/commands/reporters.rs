@@ -2,12 +2,14 @@ use std::env;
 use std::fmt::Write;
 use std::ops::Deref;
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use crate::commands::human_readable_bytes;
 use crate::printer::Printer;
@@ -42,6 +44,94 @@ enum ProgressMode {
         multi_progress: MultiProgress,
         state: Arc<Mutex<BarState>>,
     },
+    /// Reports progress of all concurrent download, build, and checkout processes as JSON events.
+    Json {
+        /// A monotonic counter for event IDs.
+        id: AtomicUsize,
+    },
+}
+
+/// A machine-readable progress event, written to stderr as a single line of JSON.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum ProgressEvent<'a> {
+    DownloadStart {
+        id: usize,
+        name: &'a str,
+        size: Option<u64>,
+    },
+    DownloadComplete {
+        id: usize,
+    },
+    UploadStart {
+        id: usize,
+        name: &'a str,
+        size: Option<u64>,
+    },
+    UploadComplete {
+        id: usize,
+    },
+    ExtractStart {
+        id: usize,
+        name: &'a str,
+        size: Option<u64>,
+    },
+    ExtractComplete {
+        id: usize,
+    },
+    BuildStart {
+        id: usize,
+        source: String,
+    },
+    BuildComplete {
+        id: usize,
+        source: String,
+    },
+    CheckoutStart {
+        id: usize,
+        url: String,
+        rev: &'a str,
+    },
+    CheckoutComplete {
+        id: usize,
+        url: String,
+        rev: &'a str,
+    },
+    ResolveProgress {
+        name: &'a PackageName,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
+    ResolveComplete,
+    PrepareProgress {
+        name: &'a PackageName,
+        version: &'a Version,
+        completed: u64,
+        total: Option<u64>,
+    },
+    PrepareComplete,
+    InstallProgress {
+        name: &'a PackageName,
+        version: &'a Version,
+        completed: u64,
+        total: Option<u64>,
+    },
+    InstallComplete,
+}
+
+impl ProgressEvent<'_> {
+    /// Write the event to stderr, if the printer reports progress as JSON.
+    fn emit(&self, printer: Printer) {
+        if printer != Printer::JsonProgress {
+            return;
+        }
+        if let Ok(event) = serde_json::to_string(self) {
+            // Write each event in a single call, such that concurrent events aren't interleaved.
+            let _ = printer.stderr().write_str(&format!("{event}\n"));
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -131,7 +221,11 @@ impl From<uv_python::downloads::Direction> for Direction {
 
 impl ProgressReporter {
     fn new(root: ProgressBar, multi_progress: MultiProgress, printer: Printer) -> Self {
-        let mode = if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
+        let mode = if printer == Printer::JsonProgress {
+            ProgressMode::Json {
+                id: AtomicUsize::new(0),
+            }
+        } else if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
             // Disable concurrent progress bars when running inside a Jupyter notebook
             // because the Jupyter terminal does not support clearing previous lines.
             // See: https://github.com/astral-sh/uv/issues/3887.
@@ -151,6 +245,16 @@ impl ProgressReporter {
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let ProgressMode::Json { id } = &self.mode {
+            let id = id.fetch_add(1, Ordering::Relaxed) + 1;
+            ProgressEvent::BuildStart {
+                id,
+                source: source.to_string(),
+            }
+            .emit(self.printer);
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -184,6 +288,15 @@ impl ProgressReporter {
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            ProgressEvent::BuildComplete {
+                id,
+                source: source.to_string(),
+            }
+            .emit(self.printer);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -210,6 +323,18 @@ impl ProgressReporter {
     }
 
     fn on_request_start(&self, direction: Direction, name: String, size: Option<u64>) -> usize {
+        if let ProgressMode::Json { id } = &self.mode {
+            let id = id.fetch_add(1, Ordering::Relaxed) + 1;
+            let name = name.as_str();
+            match direction {
+                Direction::Download => ProgressEvent::DownloadStart { id, name, size },
+                Direction::Upload => ProgressEvent::UploadStart { id, name, size },
+                Direction::Extract => ProgressEvent::ExtractStart { id, name, size },
+            }
+            .emit(self.printer);
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -301,6 +426,16 @@ impl ProgressReporter {
     }
 
     fn on_request_complete(&self, direction: Direction, id: usize) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            match direction {
+                Direction::Download => ProgressEvent::DownloadComplete { id },
+                Direction::Upload => ProgressEvent::UploadComplete { id },
+                Direction::Extract => ProgressEvent::ExtractComplete { id },
+            }
+            .emit(self.printer);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -357,6 +492,17 @@ impl ProgressReporter {
     }
 
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        if let ProgressMode::Json { id } = &self.mode {
+            let id = id.fetch_add(1, Ordering::Relaxed) + 1;
+            ProgressEvent::CheckoutStart {
+                id,
+                url: url.to_string(),
+                rev,
+            }
+            .emit(self.printer);
+            return id;
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -387,6 +533,16 @@ impl ProgressReporter {
     }
 
     fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        if let ProgressMode::Json { .. } = &self.mode {
+            ProgressEvent::CheckoutComplete {
+                id,
+                url: url.to_string(),
+                rev,
+            }
+            .emit(self.printer);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -445,8 +601,15 @@ impl PrepareReporter {
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
-    fn on_progress(&self, _dist: &CachedDist) {
+    fn on_progress(&self, dist: &CachedDist) {
         self.reporter.root.inc(1);
+        ProgressEvent::PrepareProgress {
+            name: dist.name(),
+            version: &dist.filename().version,
+            completed: self.reporter.root.position(),
+            total: self.reporter.root.length(),
+        }
+        .emit(self.reporter.printer);
     }
 
     fn on_complete(&self) {
@@ -454,6 +617,7 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         // in Jupyter notebooks.
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
+        ProgressEvent::PrepareComplete.emit(self.reporter.printer);
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
@@ -520,9 +684,21 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
         match version_or_url {
             VersionOrUrlRef::Version(version) => {
                 self.reporter.root.set_message(format!("{name}=={version}"));
+                ProgressEvent::ResolveProgress {
+                    name,
+                    version: Some(version.to_string()),
+                    url: None,
+                }
+                .emit(self.reporter.printer);
             }
             VersionOrUrlRef::Url(url) => {
                 self.reporter.root.set_message(format!("{name} @ {url}"));
+                ProgressEvent::ResolveProgress {
+                    name,
+                    version: None,
+                    url: Some(url.to_string()),
+                }
+                .emit(self.reporter.printer);
             }
         }
     }
@@ -530,6 +706,7 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
     fn on_complete(&self) {
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
+        ProgressEvent::ResolveComplete.emit(self.reporter.printer);
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
@@ -593,6 +770,7 @@ impl uv_distribution::Reporter for ResolverReporter {
 
 #[derive(Debug)]
 pub(crate) struct InstallReporter {
+    printer: Printer,
     progress: ProgressBar,
 }
 
@@ -603,7 +781,7 @@ impl From<Printer> for InstallReporter {
             ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
         );
         progress.set_message("Installing wheels...");
-        Self { progress }
+        Self { printer, progress }
     }
 }
 
@@ -619,11 +797,19 @@ impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
         self.progress.set_message(format!("{wheel}"));
         self.progress.inc(1);
+        ProgressEvent::InstallProgress {
+            name: wheel.name(),
+            version: &wheel.filename().version,
+            completed: self.progress.position(),
+            total: self.progress.length(),
+        }
+        .emit(self.printer);
     }
 
     fn on_install_complete(&self) {
         self.progress.set_message("");
         self.progress.finish_and_clear();
+        ProgressEvent::InstallComplete.emit(self.printer);
     }
 }
 
@@ -1,12 +1,21 @@
 use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use axoupdater::{AxoUpdater, AxoupdateError, UpdateRequest};
+use fs_err as fs;
 use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tempfile::NamedTempFile;
 use tracing::debug;
 
+use uv_cli::SelfUpdateChannel;
 use uv_client::{BaseClientBuilder, WrappedReqwestError};
 use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -14,6 +23,7 @@ use crate::printer::Printer;
 /// Attempt to update the uv binary.
 pub(crate) async fn self_update(
     version: Option<String>,
+    channel: Option<SelfUpdateChannel>,
     token: Option<String>,
     dry_run: bool,
     printer: Printer,
@@ -104,10 +114,10 @@ pub(crate) async fn self_update(
         )
     )?;
 
-    let update_request = if let Some(version) = version {
-        UpdateRequest::SpecificTag(version)
-    } else {
-        UpdateRequest::Latest
+    let update_request = match (version, channel) {
+        (Some(version), _) => UpdateRequest::SpecificTag(version),
+        (None, Some(SelfUpdateChannel::Preview)) => UpdateRequest::LatestMaybePrerelease,
+        (None, Some(SelfUpdateChannel::Stable) | None) => UpdateRequest::Latest,
     };
 
     updater.configure_version_specifier(update_request.clone());
@@ -143,10 +153,31 @@ pub(crate) async fn self_update(
         return Ok(ExitStatus::Success);
     }
 
+    // Retain a copy of the current binary, such that the update can be reverted with
+    // `uv self rollback`. The copy is only persisted if the update succeeds.
+    let store = RollbackStore::from_settings()?;
+    let staged = match store.stage() {
+        Ok(staged) => Some(staged),
+        Err(err) => {
+            warn_user!(
+                "Failed to retain the current uv binary; `uv self rollback` will be unavailable: {err}"
+            );
+            None
+        }
+    };
+
     // Run the updater. This involves a network request, since we need to determine the latest
     // available version of uv.
     match updater.run().await {
         Ok(Some(result)) => {
+            if let Some(staged) = staged {
+                if let Err(err) = store.persist(staged) {
+                    warn_user!(
+                        "Failed to retain the previous uv binary; `uv self rollback` will be unavailable: {err}"
+                    );
+                }
+            }
+
             let direction = if result
                 .old_version
                 .as_ref()
@@ -220,3 +251,154 @@ pub(crate) async fn self_update(
 
     Ok(ExitStatus::Success)
 }
+
+/// Restore the uv binary that was replaced by the most recent `uv self update`.
+pub(crate) fn self_rollback(printer: Printer) -> Result<ExitStatus> {
+    let store = RollbackStore::from_settings()?;
+    let Some(previous) = store.read()? else {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format_args!(
+                "{}{} No previous version of uv is available; `uv self rollback` can only revert an update performed by `uv self update`",
+                "error".red().bold(),
+                ":".bold()
+            )
+        )?;
+        return Ok(ExitStatus::Error);
+    };
+
+    // Verify the retained binary before replacing the current executable with it.
+    let binary = store.binary();
+    let digest = sha256(&binary)?;
+    if digest != previous.sha256 {
+        anyhow::bail!(
+            "The retained uv binary at `{}` does not match its recorded checksum (expected `{}`, found `{}`)",
+            binary.user_display(),
+            previous.sha256,
+            digest
+        );
+    }
+
+    self_replace::self_replace(&binary).context("Failed to restore the previous uv binary")?;
+    store.clear()?;
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format_args!(
+            "{}{} Rolled back uv from {} to {}",
+            "success".green().bold(),
+            ":".bold(),
+            format!("v{}", env!("CARGO_PKG_VERSION")).bold().cyan(),
+            format!("v{}", previous.version).bold().cyan()
+        )
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Metadata for the uv binary retained by `uv self update`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PreviousRelease {
+    /// The version of the retained binary.
+    version: String,
+    /// The hex-encoded SHA-256 digest of the retained binary.
+    sha256: String,
+}
+
+/// A copy of the current uv binary, staged prior to an update.
+struct StagedBinary {
+    file: NamedTempFile,
+    release: PreviousRelease,
+}
+
+/// The location of the uv binary retained for `uv self rollback`.
+struct RollbackStore {
+    root: PathBuf,
+}
+
+impl RollbackStore {
+    /// Return the rollback store within the uv state directory.
+    fn from_settings() -> Result<Self, io::Error> {
+        Ok(Self {
+            root: StateStore::from_settings(None)?.bucket(StateBucket::SelfUpdate),
+        })
+    }
+
+    /// The path to the retained binary.
+    fn binary(&self) -> PathBuf {
+        self.root
+            .join(format!("uv-previous{}", std::env::consts::EXE_SUFFIX))
+    }
+
+    /// The path to the metadata for the retained binary.
+    fn metadata(&self) -> PathBuf {
+        self.root.join("uv-previous.json")
+    }
+
+    /// Copy the current executable into the store, without replacing any retained binary.
+    fn stage(&self) -> Result<StagedBinary> {
+        fs::create_dir_all(&self.root)?;
+        let current_exe = std::env::current_exe()?;
+
+        let file = NamedTempFile::new_in(&self.root)?;
+        fs::copy(&current_exe, file.path())?;
+        let sha256 = sha256(file.path())?;
+
+        Ok(StagedBinary {
+            file,
+            release: PreviousRelease {
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                sha256,
+            },
+        })
+    }
+
+    /// Persist a staged binary, replacing any previously retained binary.
+    fn persist(&self, staged: StagedBinary) -> Result<()> {
+        let binary = self.binary();
+        staged.file.persist(&binary)?;
+
+        fs::write(self.metadata(), serde_json::to_vec(&staged.release)?)?;
+        debug!(
+            "Retained uv v{} at `{}`",
+            staged.release.version,
+            binary.user_display()
+        );
+        Ok(())
+    }
+
+    /// Read the metadata for the retained binary, if any.
+    fn read(&self) -> Result<Option<PreviousRelease>> {
+        let contents = match fs::read(self.metadata()) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        if !self.binary().is_file() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_slice(&contents)?))
+    }
+
+    /// Remove the retained binary and its metadata.
+    fn clear(&self) -> Result<(), io::Error> {
+        for path in [self.metadata(), self.binary()] {
+            match fs::remove_file(path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compute the hex-encoded SHA-256 digest of a file.
+fn sha256(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -25,7 +25,7 @@ use uv_python::{
 };
 use uv_settings::{PythonInstallMirrors, ToolOptions};
 use uv_shell::Shell;
-use uv_tool::{InstalledTools, Tool, ToolEntrypoint, entrypoint_paths};
+use uv_tool::{ExecutableFilter, InstalledTools, Tool, ToolEntrypoint, entrypoint_paths};
 use uv_warnings::warn_user_once;
 
 use crate::commands::pip;
@@ -147,6 +147,7 @@ pub(crate) async fn refine_interpreter(
         Some(reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
@@ -173,6 +174,7 @@ pub(crate) fn finalize_tool_install(
     environment: &PythonEnvironment,
     name: &PackageName,
     entrypoints: &[PackageName],
+    executables: &ExecutableFilter,
     installed_tools: &InstalledTools,
     options: &ToolOptions,
     force: bool,
@@ -214,10 +216,18 @@ pub(crate) fn finalize_tool_install(
             .first()
             .context("Expected at least one requirement")?;
         let dist_entrypoints = entrypoint_paths(&site_packages, dist.name(), dist.version())?;
+        let has_entrypoints = !dist_entrypoints.is_empty();
 
         // Determine the entry points targets. Use a sorted collection for deterministic output.
         let target_entrypoints = dist_entrypoints
             .into_iter()
+            .filter(|(name, _)| {
+                let allowed = executables.allows(name);
+                if !allowed {
+                    debug!("Skipping executable excluded by filter: `{name}`");
+                }
+                allowed
+            })
             .map(|(name, source_path)| {
                 let target_path = executable_directory.join(
                     source_path
@@ -229,6 +239,32 @@ pub(crate) fn finalize_tool_install(
             })
             .collect::<BTreeSet<_>>();
 
+        if target_entrypoints.is_empty() && has_entrypoints {
+            // The package provides executables, but the user filtered out all of them.
+            if package != name {
+                writeln!(
+                    printer.stdout(),
+                    "No executables from package `{}` match the executable filters",
+                    package.cyan(),
+                )?;
+                continue;
+            }
+
+            writeln!(
+                printer.stdout(),
+                "No executables from package `{}` match the executable filters; removing tool",
+                package.cyan()
+            )?;
+
+            // Clean up the environment we just created.
+            installed_tools.remove_environment(name)?;
+
+            return Err(anyhow::anyhow!(
+                "Failed to install entrypoints for `{}`",
+                package.cyan()
+            ));
+        }
+
         if target_entrypoints.is_empty() {
             // If package is not the root package, suggest to install it as a dependency.
             if package != name {
@@ -337,6 +373,7 @@ pub(crate) fn finalize_tool_install(
         build_constraints,
         python,
         installed_entrypoints,
+        executables.clone(),
         options.clone(),
     );
     installed_tools.add_tool_receipt(name, tool)?;
@@ -11,7 +11,7 @@ use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, Reinstall, TargetTriple, Upgrade};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ExtraBuildRequires, NameRequirementSpecification, Requirement, RequirementSource,
+    ExtraBuildRequires, NameRequirementSpecification, Patches, Requirement, RequirementSource,
     UnresolvedRequirementSpecification,
 };
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
@@ -24,7 +24,8 @@ use uv_python::{
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
-use uv_tool::InstalledTools;
+use uv_tool::{ExecutableFilter, InstalledTools};
+use uv_types::HashStrategy;
 use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 
@@ -56,6 +57,7 @@ pub(crate) async fn install(
     excludes: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
     entrypoints: &[PackageName],
+    executables: ExecutableFilter,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
@@ -87,6 +89,7 @@ pub(crate) async fn install(
         Some(&reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
@@ -377,6 +380,7 @@ pub(crate) async fn install(
                 && constraints == tool_receipt.constraints()
                 && overrides == tool_receipt.overrides()
                 && build_constraints == tool_receipt.build_constraints()
+                && executables == *tool_receipt.executables()
             {
                 let ResolverInstallerSettings {
                     resolver:
@@ -414,6 +418,8 @@ pub(crate) async fn install(
                         config_settings_package,
                         &extra_build_requires,
                         extra_build_variables,
+                        &Patches::default(),
+                        &HashStrategy::default(),
                     ),
                     Ok(SatisfiesResult::Fresh { .. })
                 ) {
@@ -639,6 +645,7 @@ pub(crate) async fn install(
         &environment,
         package_name,
         entrypoints,
+        &executables,
         &installed_tools,
         &options,
         force || invalid_tool_receipt,
@@ -0,0 +1,91 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_distribution_types::{InstalledDistKind, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_tool::{InstalledTools, LockedTool, ToolsLock};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Record the installed tools in a lockfile.
+pub(crate) async fn lock(
+    path: &Path,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::TOOL_LOCK) {
+        warn_user!(
+            "The `uv tool lock` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::TOOL_LOCK
+        );
+    }
+
+    let installed_tools = InstalledTools::from_settings()?;
+    let (_lock, tools) = match installed_tools.lock().await {
+        Ok(lock) => (Some(lock), installed_tools.tools()?),
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            (None, Vec::new())
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut locked = Vec::with_capacity(tools.len());
+    for (name, tool) in tools {
+        let Ok(tool) = tool else {
+            bail!(
+                "`{}` is missing a valid receipt; run `{}` to reinstall",
+                name.cyan(),
+                format!("uv tool install --force {name}").green()
+            );
+        };
+
+        let Some(environment) = installed_tools.get_environment(&name, cache)? else {
+            bail!(
+                "`{}` is missing a valid environment; run `{}` to reinstall",
+                name.cyan(),
+                format!("uv tool install --force {name}").green()
+            );
+        };
+
+        // Pin the packages installed from a registry. Packages installed from a URL are already
+        // pinned by the requirements of the tool.
+        let site_packages = SitePackages::from_environment(environment.environment())?;
+        let packages = site_packages
+            .iter()
+            .filter(|dist| match &dist.kind {
+                InstalledDistKind::Url(_) | InstalledDistKind::LegacyEditable(_) => {
+                    debug!("Skipping pin for `{}` in tool `{name}`", dist.name());
+                    false
+                }
+                _ => true,
+            })
+            .map(|dist| (dist.name().clone(), dist.version().clone()))
+            .collect::<Vec<_>>();
+
+        locked.push(LockedTool::new(name, &tool, packages));
+    }
+
+    let lock = ToolsLock::new(locked);
+    lock.write(path)?;
+
+    let count = lock.tools().len();
+    let s = if count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Locked {} to: {}",
+        format!("{count} tool{s}").bold(),
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
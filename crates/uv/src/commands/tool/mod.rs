@@ -10,7 +10,9 @@ mod common;
 pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod lock;
 pub(crate) mod run;
+pub(crate) mod sync;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
 pub(crate) mod upgrade;
@@ -3,6 +3,7 @@ use std::fmt::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anstream::eprint;
 use anyhow::{Context, bail};
@@ -22,7 +23,7 @@ use uv_configuration::TargetTriple;
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
-    IndexUrl, Name, NameRequirementSpecification, Requirement, RequirementSource,
+    IndexUrl, Name, NameRequirementSpecification, Patches, Requirement, RequirementSource,
     UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
@@ -40,24 +41,26 @@ use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_tool::{InstalledTools, entrypoint_paths};
-use uv_warnings::warn_user;
+use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
 use crate::child::run_to_completion;
 use crate::commands::ExitStatus;
+use crate::commands::diagnostics;
 use crate::commands::pip;
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations;
+use crate::commands::project::environment::{CachedEnvironment, CachedToolEnvironment};
+use crate::commands::project::run::load_env_files;
 use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_names,
 };
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::{matching_packages, refine_interpreter};
 use crate::commands::tool::{Target, ToolRequest};
-use crate::commands::{diagnostics, project::environment::CachedEnvironment};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 use crate::settings::ResolverSettings;
@@ -106,6 +109,7 @@ pub(crate) async fn run(
     printer: Printer,
     env_file: Vec<PathBuf>,
     no_env_file: bool,
+    cache_ttl: Option<Duration>,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
     /// Whether or not a path looks like a Python script based on the file extension.
@@ -114,42 +118,9 @@ pub(crate) async fn run(
             .is_some_and(|ext| ext.eq_ignore_ascii_case("py") || ext.eq_ignore_ascii_case("pyw"))
     }
 
-    // Read from the `.env` file, if necessary.
+    // Read from the `.env` files, if necessary.
     if !no_env_file {
-        for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
-            match dotenvy::from_path(env_file_path) {
-                Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                    bail!(
-                        "No environment file found at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::Io(err)) => {
-                    bail!(
-                        "Failed to read environment file `{}`: {err}",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::LineParse(content, position)) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}` at position {position}: {content}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Err(err) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}`: {err}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Ok(()) => {
-                    debug!(
-                        "Read environment file at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-            }
-        }
+        load_env_files(env_file.iter().map(PathBuf::as_path))?;
     }
 
     let Some(command) = command else {
@@ -276,6 +247,7 @@ pub(crate) async fn run(
         &settings,
         &client_builder,
         isolated,
+        cache_ttl,
         python_preference,
         python_downloads,
         installer_metadata,
@@ -691,6 +663,7 @@ async fn get_or_create_environment(
     settings: &ResolverInstallerSettings,
     client_builder: &BaseClientBuilder<'_>,
     isolated: bool,
+    cache_ttl: Option<Duration>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
@@ -751,6 +724,7 @@ async fn get_or_create_environment(
         Some(&reporter),
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
         install_mirrors.python_downloads_json_url.as_deref(),
         preview,
     )
@@ -949,7 +923,7 @@ async fn get_or_create_environment(
                     .get_tool_receipt(&requirement.name)
                     .ok()
                     .flatten()
-                    .is_some_and(|receipt| ToolOptions::from(options) == *receipt.options())
+                    .is_some_and(|receipt| ToolOptions::from(options.clone()) == *receipt.options())
                 {
                     let ResolverInstallerSettings {
                         resolver:
@@ -988,6 +962,8 @@ async fn get_or_create_environment(
                             config_settings_package,
                             &extra_build_requires,
                             extra_build_variables,
+                            &Patches::default(),
+                            &HashStrategy::default(),
                         ),
                         Ok(SatisfiesResult::Fresh { .. })
                     ) {
@@ -999,6 +975,49 @@ async fn get_or_create_environment(
         }
     }
 
+    // Read the `--build-constraints` requirements.
+    let build_constraints = Constraints::from_requirements(
+        operations::read_constraints(build_constraints, client_builder)
+            .await?
+            .into_iter()
+            .map(|constraint| constraint.requirement),
+    );
+
+    // If enabled, reuse the environment from an identical, recent request without resolving.
+    let cached_tool_environment = cache_ttl
+        .filter(|_| matches!(cache.refresh(), Refresh::None(_)))
+        .filter(|_| {
+            // Local paths and Git references are mutable, so requests that include them can't be
+            // reused.
+            requirements
+                .iter()
+                .chain(&overrides)
+                .all(|requirement| matches!(requirement.source, RequirementSource::Registry { .. }))
+        })
+        .map(|ttl| {
+            let key = format!(
+                "{}\n{:?}\n{}\n{}\n{}\n{}\n{}",
+                interpreter.sys_executable().display(),
+                python_platform,
+                requirements.iter().join(", "),
+                constraints.iter().join(", "),
+                overrides.iter().join(", "),
+                build_constraints.requirements().join(", "),
+                serde_json::to_string(&ToolOptions::from(options)).unwrap_or_default(),
+            );
+            CachedToolEnvironment::new(&key, ttl, cache)
+        });
+    if let Some(environment) = cached_tool_environment
+        .as_ref()
+        .and_then(|cached| cached.read(cache))
+    {
+        debug!(
+            "Using cached tool environment: `{}`",
+            environment.root().user_display()
+        );
+        return Ok((from, environment));
+    }
+
     // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
     let spec = EnvironmentSpecification::from(RequirementsSpecification {
         requirements: requirements
@@ -1016,14 +1035,6 @@ async fn get_or_create_environment(
         ..spec
     });
 
-    // Read the `--build-constraints` requirements.
-    let build_constraints = Constraints::from_requirements(
-        operations::read_constraints(build_constraints, client_builder)
-            .await?
-            .into_iter()
-            .map(|constraint| constraint.requirement),
-    );
-
     // TODO(zanieb): When implementing project-level tools, discover the project and check if it has the tool.
     // TODO(zanieb): Determine if we should layer on top of the project environment if it is present.
 
@@ -1116,6 +1127,13 @@ async fn get_or_create_environment(
             err => return Err(err),
         },
     };
+    let environment = PythonEnvironment::from(environment);
+
+    if let Some(cached_tool_environment) = cached_tool_environment {
+        if let Err(err) = cached_tool_environment.write(&environment, cache) {
+            debug!("Failed to cache tool environment: {err}");
+        }
+    }
 
-    Ok((from, environment.into()))
+    Ok((from, environment))
 }
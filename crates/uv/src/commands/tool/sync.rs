@@ -0,0 +1,274 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use owo_colors::{AnsiColors, OwoColorize};
+use tracing::{debug, trace};
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, Constraints};
+use uv_distribution_types::{InstalledDistKind, Name, Requirement};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::VerbatimParsedUrl;
+use uv_python::{EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference};
+use uv_requirements::RequirementsSpecification;
+use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions};
+use uv_tool::{InstalledTools, LockedPackage, LockedTool, ToolsLock};
+use uv_warnings::{warn_user, write_error_chain};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, SummaryResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::{PlatformState, resolve_environment, sync_environment};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::tool::common::{finalize_tool_install, remove_entrypoints};
+use crate::commands::{ExitStatus, conjunction};
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Install the tools recorded in a lockfile.
+pub(crate) async fn sync(
+    path: &Path,
+    install_mirrors: PythonInstallMirrors,
+    filesystem: ResolverInstallerOptions,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::TOOL_LOCK) {
+        warn_user!(
+            "The `uv tool sync` command is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::TOOL_LOCK
+        );
+    }
+
+    let lock = ToolsLock::from_path(path)
+        .with_context(|| format!("Failed to read tools from: {}", path.user_display()))?;
+
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let _lock = installed_tools.lock().await?;
+
+    let mut synced = Vec::new();
+    let mut errors = Vec::new();
+    for tool in lock.tools() {
+        if is_synced(&installed_tools, tool, cache) {
+            debug!("Tool `{}` is already in sync", tool.name());
+            continue;
+        }
+
+        debug!("Syncing tool: `{}`", tool.name());
+        let result = sync_tool(
+            tool,
+            &installed_tools,
+            &install_mirrors,
+            &filesystem,
+            &client_builder,
+            python_preference,
+            python_downloads,
+            installer_metadata,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await;
+
+        match result {
+            Ok(()) => synced.push(tool.name()),
+            Err(err) => errors.push((tool.name(), err)),
+        }
+    }
+
+    if !errors.is_empty() {
+        for (name, err) in errors {
+            trace!("Error trace: {err:?}");
+            write_error_chain(
+                err.context(format!("Failed to sync {}", name.green()))
+                    .as_ref(),
+                printer.stderr(),
+                "error",
+                AnsiColors::Red,
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    if synced.is_empty() {
+        writeln!(printer.stderr(), "All tools are already in sync")?;
+    } else {
+        let tools = synced
+            .iter()
+            .map(|name| format!("`{}`", name.cyan()))
+            .collect::<Vec<_>>();
+        let s = if tools.len() > 1 { "s" } else { "" };
+        writeln!(printer.stderr(), "Synced tool{s} {}", conjunction(tools))?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the tool is installed with the same requirements and package versions as
+/// recorded in the lockfile.
+fn is_synced(installed_tools: &InstalledTools, tool: &LockedTool, cache: &Cache) -> bool {
+    let Ok(Some(receipt)) = installed_tools.get_tool_receipt(tool.name()) else {
+        return false;
+    };
+    if receipt.requirements() != tool.requirements()
+        || receipt.constraints() != tool.constraints()
+        || receipt.overrides() != tool.overrides()
+        || receipt.build_constraints() != tool.build_constraints()
+    {
+        return false;
+    }
+
+    let Ok(Some(environment)) = installed_tools.get_environment(tool.name(), cache) else {
+        return false;
+    };
+    let Ok(site_packages) = SitePackages::from_environment(environment.environment()) else {
+        return false;
+    };
+    let installed = site_packages
+        .iter()
+        .filter(|dist| {
+            !matches!(
+                dist.kind,
+                InstalledDistKind::Url(_) | InstalledDistKind::LegacyEditable(_)
+            )
+        })
+        .map(|dist| LockedPackage {
+            name: dist.name().clone(),
+            version: dist.version().clone(),
+        })
+        .collect::<BTreeSet<_>>();
+
+    installed == tool.packages().iter().cloned().collect::<BTreeSet<_>>()
+}
+
+/// Install a tool with the package versions recorded in the lockfile.
+async fn sync_tool(
+    tool: &LockedTool,
+    installed_tools: &InstalledTools,
+    install_mirrors: &PythonInstallMirrors,
+    filesystem: &ResolverInstallerOptions,
+    client_builder: &BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<()> {
+    let reporter = PythonDownloadReporter::single(printer);
+
+    let interpreter = PythonInstallation::find_or_download(
+        tool.python().as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+        install_mirrors.python_download_attestations.as_ref(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Resolve the appropriate settings, preferring: lockfile > user.
+    let options =
+        ResolverInstallerOptions::from(tool.options().clone()).combine(filesystem.clone());
+    let settings = ResolverInstallerSettings::from(options);
+
+    let build_constraints =
+        Constraints::from_requirements(tool.build_constraints().iter().cloned());
+
+    // Constrain every package to the version recorded in the lockfile.
+    let pins = tool
+        .packages()
+        .iter()
+        .map(|package| {
+            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(&package.to_string())
+                .map(Requirement::from)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let spec = RequirementsSpecification::from_overrides(
+        tool.requirements().to_vec(),
+        tool.constraints().iter().cloned().chain(pins).collect(),
+        tool.overrides().to_vec(),
+    );
+
+    // Initialize any shared state.
+    let state = PlatformState::default();
+
+    let resolution = resolve_environment(
+        spec.into(),
+        &interpreter,
+        None,
+        build_constraints.clone(),
+        &settings.resolver,
+        client_builder,
+        &state,
+        Box::new(SummaryResolveLogger),
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Remove the executables of any existing installation of the tool.
+    if let Ok(Some(receipt)) = installed_tools.get_tool_receipt(tool.name()) {
+        remove_entrypoints(&receipt);
+    }
+
+    let environment = installed_tools.create_environment(tool.name(), interpreter, preview)?;
+
+    let environment = sync_environment(
+        environment,
+        &resolution.into(),
+        Modifications::Exact,
+        build_constraints,
+        (&settings).into(),
+        client_builder,
+        &state,
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    finalize_tool_install(
+        &environment,
+        tool.name(),
+        tool.with_executables_from(),
+        tool.executables(),
+        installed_tools,
+        tool.options(),
+        true,
+        tool.python().clone(),
+        tool.requirements().to_vec(),
+        tool.constraints().to_vec(),
+        tool.overrides().to_vec(),
+        tool.build_constraints().to_vec(),
+        printer,
+    )?;
+
+    Ok(())
+}
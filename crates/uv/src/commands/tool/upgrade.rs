@@ -99,6 +99,7 @@ pub(crate) async fn upgrade(
                 Some(&reporter),
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
+                install_mirrors.python_download_attestations.as_ref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
                 preview,
             )
@@ -439,6 +440,7 @@ async fn upgrade_tool(
             &environment,
             name,
             &entrypoints,
+            existing_tool_receipt.executables(),
             installed_tools,
             &ToolOptions::from(options),
             true,
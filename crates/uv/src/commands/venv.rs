@@ -23,7 +23,8 @@ use uv_install_wheel::LinkMode;
 use uv_normalize::DefaultGroups;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, LenientImplementationName, PythonDownloads,
+    PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
 };
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
@@ -55,6 +56,18 @@ enum VenvError {
 
     #[error("Failed to resolve `--find-links` entry")]
     FlatIndex(#[source] uv_client::FlatIndexError),
+
+    #[error("Failed to inspect the environment at `{}`", _0.user_display())]
+    SeedEnvironment(PathBuf, #[source] uv_python::Error),
+
+    #[error(
+        "The environment at `{}` uses {1}, but the virtual environment uses {2}",
+        _0.user_display()
+    )]
+    SeedIncompatible(PathBuf, String, String),
+
+    #[error("Failed to link packages from the environment at `{}`", _0.user_display())]
+    SeedLink(PathBuf, #[source] std::io::Error),
 }
 
 /// Create a virtual environment.
@@ -75,6 +88,7 @@ pub(crate) async fn venv(
     prompt: uv_virtualenv::Prompt,
     system_site_packages: bool,
     seed: bool,
+    seed_from: Option<&Path>,
     on_existing: OnExisting,
     exclude_newer: ExcludeNewer,
     concurrency: Concurrency,
@@ -159,6 +173,7 @@ pub(crate) async fn venv(
             Some(&reporter),
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_download_attestations.as_ref(),
             install_mirrors.python_downloads_json_url.as_deref(),
             preview,
         )
@@ -167,6 +182,26 @@ pub(crate) async fn venv(
         python.into_interpreter()
     };
 
+    // Inspect the environment to seed from, and ensure it's compatible with the interpreter.
+    let base = if let Some(seed_from) = seed_from {
+        let seed_from = std::path::absolute(seed_from)?;
+        let base = PythonEnvironment::from_root(&seed_from, cache)
+            .map_err(|err| VenvError::SeedEnvironment(seed_from.clone(), err))?;
+        if base.interpreter().implementation_name() != interpreter.implementation_name()
+            || base.interpreter().python_tuple() != interpreter.python_tuple()
+        {
+            return Err(VenvError::SeedIncompatible(
+                seed_from,
+                describe_interpreter(base.interpreter()),
+                describe_interpreter(&interpreter),
+            )
+            .into());
+        }
+        Some(base)
+    } else {
+        None
+    };
+
     // Check if the discovered Python version is incompatible with the current workspace
     if let Some(requires_python) = requires_python {
         match validate_project_requires_python(
@@ -209,6 +244,23 @@ pub(crate) async fn venv(
     )
     .map_err(VenvError::Creation)?;
 
+    // Link the packages from the base environment.
+    if let Some(base) = base {
+        let count = link_site_packages(&base, &venv)
+            .map_err(|err| VenvError::SeedLink(base.root().to_path_buf(), err))?;
+        let s = if count == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Linked {} from: {}",
+                format!("{count} package{s}").bold(),
+                base.root().user_display().cyan()
+            )
+            .dimmed()
+        )?;
+    }
+
     // Install seed packages.
     if seed {
         // Extract the interpreter.
@@ -344,3 +396,59 @@ pub(crate) async fn venv(
 
     Ok(ExitStatus::Success)
 }
+
+/// Describe the Python implementation and version of an interpreter, e.g., `CPython 3.12`.
+fn describe_interpreter(interpreter: &Interpreter) -> String {
+    let (major, minor) = interpreter.python_tuple();
+    format!(
+        "{} {major}.{minor}",
+        LenientImplementationName::from(interpreter.implementation_name()).pretty()
+    )
+}
+
+/// Link the files in the `site-packages` directories of the base environment into the
+/// `site-packages` directories of the target environment.
+///
+/// Directories are created, rather than linked, such that installing into or uninstalling from
+/// the target environment never modifies the base environment. Files that already exist in the
+/// target environment (e.g., the `_virtualenv.pth` written during creation) are left as-is.
+///
+/// Returns the number of distributions that were linked.
+fn link_site_packages(
+    base: &PythonEnvironment,
+    target: &PythonEnvironment,
+) -> Result<usize, std::io::Error> {
+    let mut count = 0;
+    for (source, destination) in base.site_packages().zip(target.site_packages()) {
+        if !source.is_dir() {
+            continue;
+        }
+        let walker = walkdir::WalkDir::new(&source)
+            .min_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_entry(|entry| entry.file_name() != "__pycache__");
+        for entry in walker {
+            let entry = entry?;
+            let relative = entry
+                .path()
+                .strip_prefix(&source)
+                .expect("walkdir starts with root");
+            let path = destination.join(relative);
+            if entry.file_type().is_dir() {
+                if entry.depth() == 1
+                    && entry
+                        .path()
+                        .extension()
+                        .is_some_and(|extension| extension == "dist-info")
+                {
+                    count += 1;
+                }
+                fs_err::create_dir_all(&path)?;
+            } else if !path.exists() {
+                uv_fs::symlink_or_copy_file(entry.path(), &path)?;
+            }
+        }
+    }
+    Ok(count)
+}
@@ -21,15 +21,16 @@ use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
 use tracing::{debug, instrument, trace};
 
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, CachePartition, PartitionKey, Refresh};
 use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
-    AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand,
-    SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace,
-    compat::CompatArgs,
+    AuthCommand, AuthNamespace, BuildBackendCommand, BundleCommand, BundleNamespace, CacheCommand,
+    CacheNamespace, Cli, Commands, EnvCommand, EnvNamespace, ErrorFormat, IndexCommand,
+    IndexNamespace, PipCommand, PipNamespace, ProgressFormat, ProjectCommand, PythonCommand,
+    PythonNamespace, ScriptCommand, ScriptNamespace, SelfCommand, SelfNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
@@ -44,8 +45,9 @@ use uv_python::PythonRequest;
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
-use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
+use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options, ResolverOptions};
 use uv_static::EnvVars;
+use uv_tool::{ExecutableFilter, ExecutablePattern};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
@@ -54,7 +56,7 @@ use crate::printer::Printer;
 use crate::settings::{
     CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
     PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    PublishSettings, ResolverSettings,
 };
 
 pub(crate) mod child;
@@ -77,6 +79,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         std::env::set_current_dir(directory)?;
     }
 
+    // `uv script add` and `uv script remove` are shorthands for `uv add --script` and
+    // `uv remove --script`, respectively.
+    if matches!(&*cli.command, Commands::Script(_)) {
+        let Commands::Script(ScriptNamespace { command }) = *cli.command else {
+            unreachable!()
+        };
+        let command = match command {
+            ScriptCommand::Add(args) => ProjectCommand::Add(args.into()),
+            ScriptCommand::Remove(args) => ProjectCommand::Remove(args.into()),
+        };
+        cli.command = Box::new(Commands::Project(Box::new(command)));
+    }
+
     // Determine the project directory.
     let project_dir = cli
         .top_level
@@ -189,6 +204,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 settings.preview,
                 settings.network_settings.timeout,
                 settings.network_settings.retries,
+            )
+            .throttle(
+                settings.network_settings.max_download_rate,
+                settings.network_settings.concurrent_downloads_per_host,
             );
             Some(
                 RunCommand::from_args(command, client_builder, *module, *script, *gui_script)
@@ -377,6 +396,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         logging::setup_durations(environment.tracing_durations_file.as_ref())?;
     #[cfg(not(feature = "tracing-durations-export"))]
     let durations_layer = None::<tracing_subscriber::layer::Identity>;
+    #[cfg(feature = "otlp")]
+    let (otlp_layer, _otlp_guard) = logging::setup_otlp()?.unzip();
+    #[cfg(not(feature = "otlp"))]
+    let otlp_layer = None::<tracing_subscriber::layer::Identity>;
     logging::setup_logging(
         match globals.verbose {
             0 => logging::Level::Off,
@@ -385,7 +408,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             3.. => logging::Level::TraceAll,
         },
         durations_layer,
+        otlp_layer,
         globals.color,
+        cli.top_level.global_args.log_format.unwrap_or_default(),
         environment.log_context.unwrap_or_default(),
     )?;
 
@@ -394,6 +419,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Printer::Quiet
     } else if globals.quiet > 1 {
         Printer::Silent
+    } else if cli.top_level.global_args.progress == Some(ProgressFormat::Json)
+        && !cli.top_level.global_args.no_progress
+    {
+        Printer::JsonProgress
     } else if globals.verbose > 0 {
         Printer::Verbose
     } else if globals.no_progress {
@@ -409,6 +438,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         uv_warnings::enable();
     }
 
+    // Configure the format of user-facing diagnostics.
+    if cli.top_level.global_args.error_format == Some(ErrorFormat::Json) {
+        commands::enable_json_errors();
+    }
+
     anstream::ColorChoice::write_global(globals.color.into());
 
     miette::set_hook(Box::new(|_| {
@@ -452,7 +486,46 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    if cache_settings.remote.is_some() && !globals.preview.is_enabled(PreviewFeatures::REMOTE_CACHE)
+    {
+        warn_user!(
+            "The `--cache-remote` option is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::REMOTE_CACHE
+        );
+    }
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_remote(cache_settings.remote);
+
+    // Partition the cache by project or by index, if requested.
+    let cache = match cache_settings.partition {
+        CachePartition::None => cache,
+        CachePartition::Project => {
+            let root =
+                Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                    .await
+                    .map(|workspace| workspace.install_path().clone())
+                    .unwrap_or_else(|_| project_dir.to_path_buf());
+            debug!(
+                "Partitioning the cache by project: `{}`",
+                root.user_display()
+            );
+            cache.with_partition(PartitionKey::Project(&root))
+        }
+        CachePartition::Index => {
+            // Use the default index from the configuration files, since the index-related
+            // command-line arguments vary by command.
+            let index_locations =
+                ResolverSettings::combine(ResolverOptions::default(), filesystem.clone())
+                    .index_locations;
+            match index_locations.default_index() {
+                Some(index) => {
+                    debug!("Partitioning the cache by index: `{}`", index.url);
+                    cache.with_partition(PartitionKey::Index(&index.url))
+                }
+                None => cache,
+            }
+        }
+    };
 
     // Configure the global network settings.
     let client_builder = BaseClientBuilder::new(
@@ -462,6 +535,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         globals.preview,
         globals.network_settings.timeout,
         globals.network_settings.retries,
+    )
+    .throttle(
+        globals.network_settings.max_download_rate,
+        globals.network_settings.concurrent_downloads_per_host,
     );
 
     match *cli.command {
@@ -482,6 +559,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.username,
                 args.password,
                 args.token,
+                args.keyring_provider,
                 &args.network_settings,
                 printer,
                 globals.preview,
@@ -503,6 +581,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::auth_logout(
                 args.service,
                 args.username,
+                args.keyring_provider,
                 &args.network_settings,
                 printer,
                 globals.preview,
@@ -524,6 +603,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::auth_token(
                 args.service,
                 args.username,
+                args.keyring_provider,
                 &args.network_settings,
                 printer,
                 globals.preview,
@@ -605,6 +685,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.fork_strategy,
+                args.settings.yanked_policy,
+                args.settings.allow_yanked,
+                args.settings.known_incompatibilities,
+                args.settings.max_backtracks,
+                args.settings.resolution_timeout,
+                args.settings.prefer_smaller_artifacts,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -622,6 +708,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
+                args.settings.torch_backend_packages,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 &client_builder,
@@ -633,6 +720,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.install_mirrors,
                 args.settings.python_version,
+                args.python_versions,
+                args.split_python_versions,
                 args.settings.python_platform,
                 args.settings.universal,
                 args.settings.exclude_newer,
@@ -694,11 +783,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.reinstall,
                 args.settings.link_mode,
+                args.settings.script_policy,
+                args.settings.wheel_checks,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
+                args.settings.torch_backend_packages,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 &client_builder,
@@ -712,6 +804,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.implementation,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
@@ -724,8 +817,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.sources,
                 globals.python_preference,
                 globals.concurrency,
+                globals.wait_timeout,
                 cache,
                 args.dry_run,
+                args.assume_installed.as_deref(),
                 printer,
                 globals.preview,
             )
@@ -843,16 +938,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.yanked_policy,
+                args.settings.allow_yanked,
+                args.settings.known_incompatibilities,
+                args.settings.max_backtracks,
+                args.settings.resolution_timeout,
+                args.settings.prefer_smaller_artifacts,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
+                args.settings.torch_backend_packages,
                 args.settings.dependency_metadata,
                 args.settings.keyring_provider,
                 &client_builder,
                 args.settings.reinstall,
                 args.settings.link_mode,
+                args.settings.script_policy,
+                args.settings.wheel_checks,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
                 globals.installer_metadata,
@@ -865,6 +969,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.modifications,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.implementation,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
@@ -877,8 +982,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.prefix,
                 globals.python_preference,
                 globals.concurrency,
+                globals.wait_timeout,
                 cache,
                 args.dry_run,
+                args.assume_installed.as_deref(),
                 printer,
                 globals.preview,
             )
@@ -933,6 +1040,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::pip_freeze(
                 args.exclude_editable,
                 args.settings.strict,
+                args.format,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.paths,
@@ -1059,14 +1167,45 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer)
+            commands::cache_prune(
+                args.ci,
+                args.tools,
+                args.referenced_only,
+                environment.tool_run_cache_ttl,
+                args.force,
+                &project_dir,
+                cache,
+                printer,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.force, cache, printer)
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::CacheInfoSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::cache_info(
+                &cache,
+                &args.index_locations,
+                args.output_format,
+                printer,
+                globals.preview,
+            )
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1098,6 +1237,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.gitignore,
                 args.force_pep517,
                 args.clear,
+                args.reproducible_check,
+                args.check_tags,
                 build_constraints,
                 args.hash_checking,
                 args.python,
@@ -1174,6 +1315,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 uv_virtualenv::Prompt::from_args(prompt),
                 args.system_site_packages,
                 args.seed,
+                args.seed_from.as_deref(),
                 on_existing,
                 args.settings.exclude_newer,
                 globals.concurrency,
@@ -1207,10 +1349,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command:
                 SelfCommand::Update(SelfUpdateArgs {
                     target_version,
+                    channel,
                     token,
                     dry_run,
                 }),
-        }) => commands::self_update(target_version, token, dry_run, printer, client_builder).await,
+        }) => {
+            commands::self_update(
+                target_version,
+                channel,
+                token,
+                dry_run,
+                printer,
+                client_builder,
+            )
+            .await
+        }
+        #[cfg(feature = "self-update")]
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Rollback,
+        }) => commands::self_rollback(printer),
         Commands::Self_(SelfNamespace {
             command:
                 SelfCommand::Version {
@@ -1341,6 +1498,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 printer,
                 args.env_file,
                 args.no_env_file,
+                args.cache_ttl,
                 globals.preview,
             ))
             .await
@@ -1389,6 +1547,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 requirements.push(source);
             }
 
+            let executables = ExecutableFilter::new(
+                args.include_executables
+                    .iter()
+                    .map(|pattern| ExecutablePattern::from_str(pattern))
+                    .collect::<Result<Vec<_>, _>>()?,
+                args.exclude_executables
+                    .iter()
+                    .map(|pattern| ExecutablePattern::from_str(pattern))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+
             let constraints = args
                 .constraints
                 .into_iter()
@@ -1420,6 +1589,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &excludes,
                 &build_constraints,
                 &entrypoints,
+                executables,
                 args.python,
                 args.python_platform,
                 args.install_mirrors,
@@ -1486,6 +1656,43 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Lock(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolLockSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tool_lock(&args.path, &cache, printer, globals.preview).await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve(args, filesystem, &environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::tool_sync(
+                &args.path,
+                args.install_mirrors,
+                args.filesystem,
+                client_builder,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Uninstall(args),
         }) => {
@@ -1552,14 +1759,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 args.reinstall,
                 args.upgrade,
+                false,
                 args.bin,
                 args.registry,
                 args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
+                args.python_download_attestations,
                 args.python_downloads_json_url,
                 client_builder,
                 args.default,
+                args.scope,
                 globals.python_downloads,
                 cli.top_level.no_config,
                 globals.preview,
@@ -1581,14 +1791,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.targets,
                 args.reinstall,
                 upgrade,
+                args.relink,
                 args.bin,
                 args.registry,
                 args.force,
                 args.python_install_mirror,
                 args.pypy_install_mirror,
+                args.python_download_attestations,
                 args.python_downloads_json_url,
                 client_builder,
                 args.default,
+                args.scope,
                 globals.python_downloads,
                 cli.top_level.no_config,
                 globals.preview,
@@ -1607,6 +1820,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_dir,
                 args.targets,
                 args.all,
+                args.scope,
                 printer,
                 globals.preview,
             )
@@ -1625,6 +1839,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::python_find_script(
                     (&script).into(),
                     args.show_version,
+                    args.output_format,
                     &client_builder,
                     globals.python_preference,
                     globals.python_downloads,
@@ -1639,6 +1854,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.request,
                     args.show_version,
+                    args.output_format,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -1737,6 +1953,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 dry_run,
                 no_attestations,
                 &cache,
+                globals.preview,
                 printer,
             )
             .await
@@ -1752,6 +1969,92 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 commands::list(&project_dir, args.paths, globals.preview, printer).await
             }
         },
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::IndexSyncSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::index_sync(
+                &args.settings.index_locations,
+                args.settings.keyring_provider,
+                &client_builder,
+                &cache,
+                globals.preview,
+                printer,
+            )
+            .await
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Serve(args),
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::index_serve(args.host, args.port, cache, globals.preview, printer).await
+        }
+        Commands::Env(EnvNamespace {
+            command: EnvCommand::Restore(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::EnvRestoreSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::env_restore(
+                args.python.as_deref(),
+                args.steps,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Bundle(BundleNamespace {
+            command: BundleCommand::Create(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleCreateSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::bundle_create(
+                &args.output,
+                args.python.as_deref(),
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Bundle(BundleNamespace {
+            command: BundleCommand::Install(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BundleInstallSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::bundle_install(
+                &args.bundle,
+                args.python.as_deref(),
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Script(_) => unreachable!("`uv script` commands are handled as project commands"),
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1860,6 +2163,8 @@ async fn run_project(
                 args.name,
                 args.package,
                 args.kind,
+                args.shebang,
+                args.with,
                 args.bare,
                 args.description,
                 args.no_description,
@@ -1920,6 +2225,7 @@ async fn run_project(
                 args.active,
                 args.no_sync,
                 args.isolated,
+                args.snapshot.as_deref(),
                 args.all_packages,
                 args.package,
                 args.no_project,
@@ -1947,9 +2253,21 @@ async fn run_project(
         }
         ProjectCommand::Sync(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
-            let args = settings::SyncSettings::resolve(args, filesystem, environment);
+            let args = settings::SyncSettings::resolve(args, filesystem, environment)?;
             show_settings!(args);
 
+            if args.rollback {
+                let cache = cache.init()?;
+                return commands::sync_rollback(
+                    project_dir,
+                    args.active,
+                    &cache,
+                    printer,
+                    globals.preview,
+                )
+                .await;
+            }
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -1969,6 +2287,8 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.snapshot,
+                args.atomic,
                 args.active,
                 args.all_packages,
                 args.package,
@@ -1987,6 +2307,7 @@ async fn run_project(
                 script,
                 globals.installer_metadata,
                 globals.concurrency,
+                globals.wait_timeout,
                 no_config,
                 &cache,
                 printer,
@@ -2000,6 +2321,20 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem, environment);
             show_settings!(args);
 
+            if let Some((old, new)) = &args.audit {
+                return Box::pin(commands::lock_audit(old, new, printer, globals.preview)).await;
+            }
+
+            if let Some(package) = &args.explain {
+                return Box::pin(commands::lock_explain(
+                    project_dir,
+                    package,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            }
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -2023,8 +2358,13 @@ async fn run_project(
             Box::pin(commands::lock(
                 project_dir,
                 args.lock_check,
+                args.format,
                 args.frozen,
                 args.dry_run,
+                args.upgrade_to,
+                args.save,
+                args.strict_requires_python,
+                args.emit_constraints,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2140,12 +2480,15 @@ async fn run_project(
                 args.no_install_package,
                 args.only_install_package,
                 requirements,
+                args.from_imports,
+                args.interactive,
                 constraints,
                 args.marker,
                 args.editable,
                 args.dependency_type,
                 args.raw,
                 args.bounds,
+                args.upgrade_policy,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -2195,6 +2538,8 @@ async fn run_project(
                 args.active,
                 args.no_sync,
                 args.packages,
+                args.unused,
+                args.dry_run,
                 args.dependency_type,
                 args.package,
                 args.python,
@@ -2281,6 +2626,7 @@ async fn run_project(
                 args.invert,
                 args.outdated,
                 args.show_sizes,
+                args.interactive,
                 args.python_version,
                 args.python_platform,
                 args.python,
@@ -2322,6 +2668,8 @@ async fn run_project(
                 args.hashes,
                 args.install_options,
                 args.output_file,
+                args.split_groups,
+                args.strip_markers,
                 args.extras,
                 args.groups,
                 args.editable,
@@ -2331,6 +2679,8 @@ async fn run_project(
                 args.include_header,
                 script,
                 args.python,
+                args.python_version,
+                args.python_platform,
                 args.install_mirrors,
                 args.settings,
                 client_builder,
@@ -2346,6 +2696,40 @@ async fn run_project(
             .boxed_local()
             .await
         }
+        ProjectCommand::Shell(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ShellSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::shell(
+                project_dir,
+                args.command,
+                args.active,
+                &cache,
+                printer,
+            ))
+            .await
+        }
+        ProjectCommand::Verify(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VerifySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::verify(
+                project_dir,
+                args.active,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Format(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::FormatSettings::resolve(args, filesystem);
@@ -2368,6 +2752,74 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Test(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::TestSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
+            );
+
+            Box::pin(commands::test(
+                project_dir,
+                args.args,
+                globals.verbose > 0,
+                args.lock_check,
+                args.frozen,
+                args.active,
+                args.no_sync,
+                args.all_packages,
+                args.package,
+                no_config,
+                args.extras,
+                args.groups,
+                args.editable,
+                args.modifications,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                client_builder,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.concurrency,
+                cache,
+                printer,
+                args.env_file,
+                globals.preview,
+                args.max_recursion_depth,
+            ))
+            .await
+        }
+        ProjectCommand::Serve(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ServeSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::serve(
+                project_dir,
+                args.socket,
+                args.python,
+                args.install_mirrors,
+                args.resolver,
+                client_builder,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
     }
 }
 
@@ -1,19 +1,28 @@
 use std::str::FromStr;
 
 use anyhow::Context;
+#[cfg(feature = "otlp")]
+use opentelemetry::trace::TracerProvider;
+#[cfg(feature = "otlp")]
+use opentelemetry_sdk::trace::{SdkTracerProvider, Tracer};
 #[cfg(feature = "tracing-durations-export")]
 use tracing_durations_export::{
     DurationsLayer, DurationsLayerBuilder, DurationsLayerDropGuard, plot::PlotConfig,
 };
+#[cfg(feature = "otlp")]
+use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::filter::Directive;
+use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{EnvFilter, Layer, Registry};
 use tracing_tree::HierarchicalLayer;
 use tracing_tree::time::Uptime;
 
-use uv_cli::ColorChoice;
+use uv_cli::{ColorChoice, LogFormat};
 use uv_logging::UvFormat;
+#[cfg(feature = "otlp")]
+use uv_static::EnvVars;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Level {
@@ -33,7 +42,9 @@ pub(crate) enum Level {
 pub(crate) fn setup_logging(
     level: Level,
     durations_layer: Option<impl Layer<Registry> + Send + Sync>,
+    otlp_layer: Option<impl Layer<Registry> + Send + Sync>,
     color: ColorChoice,
+    format: LogFormat,
     detailed_logging: bool,
 ) -> anyhow::Result<()> {
     // We use directives here to ensure `RUST_LOG` can override them
@@ -58,12 +69,31 @@ pub(crate) fn setup_logging(
 
     // Avoid setting the default log level to INFO
     let durations_layer = durations_layer.map(|durations_layer| {
-        durations_layer.with_filter(
-            // Only record our own spans
-            tracing_subscriber::filter::Targets::new()
-                .with_target("", tracing::level_filters::LevelFilter::INFO),
-        )
+        durations_layer
+            .with_filter(
+                // Only record our own spans
+                tracing_subscriber::filter::Targets::new()
+                    .with_target("", tracing::level_filters::LevelFilter::INFO),
+            )
+            .boxed()
+    });
+    // Export spans regardless of the verbosity, which only affects the log output.
+    let otlp_layer = otlp_layer.map(|otlp_layer| {
+        otlp_layer
+            .with_filter(
+                tracing_subscriber::filter::Targets::new()
+                    .with_target("", tracing::level_filters::LevelFilter::INFO),
+            )
+            .boxed()
     });
+    // An empty list of layers would disable all callsites, so use `None` instead.
+    let span_layers = Some(
+        durations_layer
+            .into_iter()
+            .chain(otlp_layer)
+            .collect::<Vec<_>>(),
+    )
+    .filter(|layers| !layers.is_empty());
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive)
         .from_env()
@@ -87,10 +117,25 @@ pub(crate) fn setup_logging(
         };
     let writer = std::sync::Mutex::new(anstream::AutoStream::new(std::io::stderr(), color_choice));
 
-    if detailed_logging {
+    if format == LogFormat::Json {
+        // Machine-readable output: include the span hierarchy with each message, and emit an event
+        // with the busy and idle time of each span when it closes.
+        tracing_subscriber::registry()
+            .with(span_layers)
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_span_list(true)
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_writer(std::io::stderr)
+                    .with_ansi(false)
+                    .with_filter(filter),
+            )
+            .init();
+    } else if detailed_logging {
         // Regardless of the tracing level, include the uptime and target for each message.
         tracing_subscriber::registry()
-            .with(durations_layer)
+            .with(span_layers)
             .with(
                 HierarchicalLayer::default()
                     .with_targets(true)
@@ -102,7 +147,7 @@ pub(crate) fn setup_logging(
             .init();
     } else {
         tracing_subscriber::registry()
-            .with(durations_layer)
+            .with(span_layers)
             .with(
                 tracing_subscriber::fmt::layer()
                     .event_format(UvFormat::default())
@@ -150,3 +195,55 @@ pub(crate) fn setup_durations(
         Ok((None, None))
     }
 }
+
+/// Flushes and shuts down the OpenTelemetry exporter when dropped.
+#[cfg(feature = "otlp")]
+pub(crate) struct OtlpGuard(SdkTracerProvider);
+
+#[cfg(feature = "otlp")]
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        // The exporter uses a blocking HTTP client, which must not be used on the async runtime.
+        let provider = self.0.clone();
+        let _ = std::thread::spawn(move || provider.shutdown()).join();
+    }
+}
+
+/// Export spans to an OpenTelemetry collector if `OTEL_EXPORTER_OTLP_ENDPOINT` or
+/// `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT` is set.
+///
+/// The exporter is configured through the standard `OTEL_*` environment variables, e.g.,
+/// `OTEL_EXPORTER_OTLP_HEADERS` and `OTEL_RESOURCE_ATTRIBUTES`.
+#[cfg(feature = "otlp")]
+pub(crate) fn setup_otlp()
+-> anyhow::Result<Option<(OpenTelemetryLayer<Registry, Tracer>, OtlpGuard)>> {
+    if std::env::var_os(EnvVars::OTEL_EXPORTER_OTLP_ENDPOINT).is_none()
+        && std::env::var_os(EnvVars::OTEL_EXPORTER_OTLP_TRACES_ENDPOINT).is_none()
+    {
+        return Ok(None);
+    }
+
+    // The exporter creates a blocking HTTP client, which can't be created on the async runtime.
+    let provider = std::thread::spawn(|| -> anyhow::Result<SdkTracerProvider> {
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .build()
+            .context("Failed to create the OTLP exporter")?;
+        let resource = opentelemetry_sdk::Resource::builder()
+            .with_service_name("uv")
+            .with_attribute(opentelemetry::KeyValue::new(
+                "service.version",
+                uv_version::version(),
+            ))
+            .build();
+        Ok(SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource)
+            .build())
+    })
+    .join()
+    .map_err(|_| anyhow::anyhow!("Failed to initialize the OTLP exporter"))??;
+
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("uv"));
+    Ok(Some((layer, OtlpGuard(provider))))
+}
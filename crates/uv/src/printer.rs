@@ -13,6 +13,9 @@ pub(crate) enum Printer {
     Verbose,
     /// A printer that prints to standard streams, excluding all progress outputs
     NoProgress,
+    /// A printer that prints to standard streams, reporting progress as JSON events in lieu of
+    /// progress bars.
+    JsonProgress,
 }
 
 impl Printer {
@@ -26,6 +29,7 @@ impl Printer {
             // Otherwise, it gets interleaved with debug messages.
             Self::Verbose => ProgressDrawTarget::hidden(),
             Self::NoProgress => ProgressDrawTarget::hidden(),
+            Self::JsonProgress => ProgressDrawTarget::hidden(),
         }
     }
 
@@ -38,6 +42,7 @@ impl Printer {
             Self::Default => Stdout::Enabled,
             Self::Verbose => Stdout::Enabled,
             Self::NoProgress => Stdout::Enabled,
+            Self::JsonProgress => Stdout::Enabled,
         }
     }
 
@@ -49,6 +54,7 @@ impl Printer {
             Self::Default => Stdout::Enabled,
             Self::Verbose => Stdout::Enabled,
             Self::NoProgress => Stdout::Enabled,
+            Self::JsonProgress => Stdout::Enabled,
         }
     }
 
@@ -60,6 +66,7 @@ impl Printer {
             Self::Default => Stderr::Enabled,
             Self::Verbose => Stderr::Enabled,
             Self::NoProgress => Stderr::Enabled,
+            Self::JsonProgress => Stderr::Enabled,
         }
     }
 }
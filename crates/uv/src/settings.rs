@@ -7,48 +7,56 @@ use std::time::Duration;
 
 use crate::commands::{PythonUpgrade, PythonUpgradeSource};
 use uv_auth::Service;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, CachePartition, Refresh, RemoteCache};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs,
-    PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
-    PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
-    PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
-    VersionBumpSpec, VersionFormat,
+    AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, BundleCreateArgs, BundleInstallArgs,
+    CacheInfoFormat, ColorChoice, EnvRestoreArgs, ExternalCommand, FreezeFormat, GlobalArgs,
+    IndexSyncArgs, InfoArgs, InitArgs, ListFormat, LockArgs, LockFormat, Maybe, PipCheckArgs,
+    PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs,
+    PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonFindFormat, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs,
+    ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
+    VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    ResolverInstallerArgs, ServeArgs, ShellArgs, TestArgs, ToolLockArgs, ToolSyncArgs,
+    ToolUpgradeArgs, VerifyArgs,
     options::{flag, resolver_installer_options, resolver_options},
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PipCompileFormat, ProjectBuildBackend, Reinstall,
-    RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
-    VersionControlSystem,
+    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DownloadRate, DryRun,
+    EditableMode, EnvFile, ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy,
+    InstallOptions, KeyringProviderType, NoBinary, NoBuild, PipCompileFormat, ProjectBuildBackend,
+    Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing,
+    Upgrade, UpgradeTarget, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
     PackageConfigSettings, Requirement,
 };
-use uv_install_wheel::LinkMode;
+use uv_install_wheel::{LinkMode, ScriptPolicy, WheelChecks};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, RequirementOrigin};
 use uv_preview::Preview;
 use uv_pypi_types::SupportedEnvironments;
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::managed::PythonInstallScope;
+use uv_python::{
+    ImplementationName, Prefix, PythonDownloadAttestations, PythonDownloads, PythonPreference,
+    PythonVersion, Target,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    AllowedYank, AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
+    KnownIncompatibility, PrereleaseMode, ResolutionMode, UpgradePolicy, YankedPolicy,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
     PythonInstallMirrors, ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
+    SyncProfile,
 };
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -77,6 +85,7 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    pub(crate) wait_timeout: Option<Duration>,
 }
 
 impl GlobalSettings {
@@ -160,6 +169,7 @@ impl GlobalSettings {
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            wait_timeout: args.wait_timeout.map(Duration::from_secs),
         }
     }
 }
@@ -187,6 +197,8 @@ pub(crate) struct NetworkSettings {
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
     pub(crate) timeout: Duration,
     pub(crate) retries: u32,
+    pub(crate) max_download_rate: Option<DownloadRate>,
+    pub(crate) concurrent_downloads_per_host: Option<NonZeroUsize>,
 }
 
 impl NetworkSettings {
@@ -223,12 +235,20 @@ impl NetworkSettings {
                     .flatten(),
             )
             .collect();
+        let max_download_rate = args
+            .max_download_rate
+            .combine(workspace.and_then(|workspace| workspace.globals.max_download_rate));
+        let concurrent_downloads_per_host = environment.concurrency.downloads_per_host.combine(
+            workspace.and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+        );
         Self {
             connectivity,
             native_tls,
             allow_insecure_host,
             timeout: environment.http_timeout,
             retries: environment.http_retries,
+            max_download_rate,
+            concurrent_downloads_per_host,
         }
     }
 }
@@ -238,6 +258,8 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) remote: Option<RemoteCache>,
+    pub(crate) partition: CachePartition,
 }
 
 impl CacheSettings {
@@ -251,6 +273,37 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            remote: args
+                .cache_remote
+                .map(|url| RemoteCache::new(url.into_url(), args.cache_remote_write)),
+            partition: args
+                .cache_partition
+                .combine(workspace.and_then(|workspace| workspace.globals.cache_partition))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for a `cache info` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheInfoSettings {
+    pub(crate) output_format: CacheInfoFormat,
+    pub(crate) index_locations: IndexLocations,
+}
+
+impl CacheInfoSettings {
+    /// Resolve the [`CacheInfoSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: InfoArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let InfoArgs { output_format } = args;
+
+        // Label the index-specific cache entries using the indexes from the configuration files.
+        let ResolverSettings {
+            index_locations, ..
+        } = ResolverSettings::combine(ResolverOptions::default(), filesystem);
+
+        Self {
+            output_format,
+            index_locations,
         }
     }
 }
@@ -262,6 +315,8 @@ pub(crate) struct InitSettings {
     pub(crate) name: Option<PackageName>,
     pub(crate) package: bool,
     pub(crate) kind: InitKind,
+    pub(crate) shebang: bool,
+    pub(crate) with: Vec<String>,
     pub(crate) bare: bool,
     pub(crate) description: Option<String>,
     pub(crate) no_description: bool,
@@ -293,6 +348,8 @@ impl InitSettings {
             app,
             lib,
             script,
+            shebang,
+            with,
             description,
             no_description,
             vcs,
@@ -332,6 +389,8 @@ impl InitSettings {
             name,
             package,
             kind,
+            shebang,
+            with: with.iter().map(ToString::to_string).collect(),
             bare,
             description,
             no_description,
@@ -389,6 +448,7 @@ pub(crate) struct RunSettings {
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) isolated: bool,
+    pub(crate) snapshot: Option<PathBuf>,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
@@ -443,6 +503,7 @@ impl RunSettings {
             with_editable,
             with_requirements,
             isolated,
+            snapshot,
             active,
             no_active,
             no_sync,
@@ -462,6 +523,9 @@ impl RunSettings {
             max_recursion_depth,
         } = args;
 
+        let env_files = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.env_files.clone());
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -512,6 +576,7 @@ impl RunSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             isolated,
+            snapshot,
             show_resolution,
             all_packages,
             package,
@@ -525,7 +590,7 @@ impl RunSettings {
                 resolver_installer_options(installer, build),
                 filesystem,
             ),
-            env_file: EnvFile::from_args(env_file, no_env_file),
+            env_file: EnvFile::from_settings(env_file, no_env_file, env_files),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -534,6 +599,156 @@ impl RunSettings {
     }
 }
 
+/// The resolved settings to use for a `test` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct TestSettings {
+    pub(crate) lock_check: LockCheck,
+    pub(crate) frozen: bool,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) groups: DependencyGroups,
+    pub(crate) editable: Option<EditableMode>,
+    pub(crate) modifications: Modifications,
+    pub(crate) all_packages: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) active: Option<bool>,
+    pub(crate) no_sync: bool,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+    pub(crate) env_file: EnvFile,
+    pub(crate) max_recursion_depth: u32,
+    pub(crate) args: Vec<String>,
+}
+
+impl TestSettings {
+    /// Resolve the [`TestSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: TestArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let TestArgs {
+            extra,
+            all_extras,
+            no_extra,
+            no_all_extras,
+            no_dev,
+            group,
+            no_group,
+            no_default_groups,
+            all_groups,
+            editable,
+            no_editable,
+            inexact,
+            exact,
+            env_file,
+            no_env_file,
+            active,
+            no_active,
+            no_sync,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            all_packages,
+            package,
+            python,
+            args,
+        } = args;
+
+        let env_files = filesystem
+            .as_ref()
+            .and_then(|filesystem| filesystem.env_files.clone());
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            lock_check: if locked {
+                LockCheck::Enabled(LockCheckSource::Locked)
+            } else {
+                LockCheck::Disabled
+            },
+            frozen,
+            extras: ExtrasSpecification::from_args(
+                extra.unwrap_or_default(),
+                no_extra,
+                false,
+                vec![],
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+            ),
+            groups: DependencyGroups::from_args(
+                false,
+                no_dev,
+                false,
+                group,
+                no_group,
+                no_default_groups,
+                vec![],
+                all_groups,
+            ),
+            editable: flag(editable, no_editable, "editable").map(EditableMode::from),
+            modifications: if flag(exact, inexact, "inexact").unwrap_or(false) {
+                Modifications::Exact
+            } else {
+                Modifications::Sufficient
+            },
+            all_packages,
+            package,
+            active: flag(active, no_active, "active"),
+            no_sync,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+            env_file: EnvFile::from_settings(env_file, no_env_file, env_files),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+            max_recursion_depth: RunSettings::DEFAULT_MAX_RECURSION_DEPTH,
+            args,
+        }
+    }
+}
+
+/// The resolved settings to use for a `serve` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ServeSettings {
+    pub(crate) socket: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) resolver: ResolverSettings,
+}
+
+impl ServeSettings {
+    /// Resolve the [`ServeSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: ServeArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let ServeArgs { socket, python } = args;
+
+        let install_mirrors = filesystem
+            .as_ref()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            socket,
+            python: python.and_then(Maybe::into_option),
+            install_mirrors: environment.install_mirrors.combine(install_mirrors),
+            resolver: ResolverSettings::combine(ResolverOptions::default(), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool run` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct ToolRunSettings {
@@ -555,6 +770,7 @@ pub(crate) struct ToolRunSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) cache_ttl: Option<Duration>,
 }
 
 impl ToolRunSettings {
@@ -668,6 +884,7 @@ impl ToolRunSettings {
                 .combine(filesystem_install_mirrors),
             env_file,
             no_env_file,
+            cache_ttl: environment.tool_run_cache_ttl.filter(|ttl| !ttl.is_zero()),
         }
     }
 }
@@ -680,6 +897,8 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_executables_from: Vec<String>,
+    pub(crate) include_executables: Vec<String>,
+    pub(crate) exclude_executables: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
@@ -711,6 +930,8 @@ impl ToolInstallSettings {
             with_editable,
             with_requirements,
             with_executables_from,
+            include_executable,
+            exclude_executable,
             constraints,
             overrides,
             excludes,
@@ -758,6 +979,8 @@ impl ToolInstallSettings {
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
                 .collect(),
+            include_executables: include_executable,
+            exclude_executables: exclude_executable,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -823,6 +1046,10 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             config_setting,
             config_setting_package: config_settings_package,
             no_build_isolation,
@@ -859,6 +1086,10 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            yanked_policy,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -966,6 +1197,64 @@ impl ToolDirSettings {
     }
 }
 
+/// The resolved settings to use for a `tool lock` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolLockSettings {
+    pub(crate) path: PathBuf,
+}
+
+impl ToolLockSettings {
+    /// Resolve the [`ToolLockSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolLockArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolLockArgs { path } = args;
+
+        Self {
+            path: path.unwrap_or_else(|| PathBuf::from("uv-tools.lock")),
+        }
+    }
+}
+
+/// The resolved settings to use for a `tool sync` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ToolSyncSettings {
+    pub(crate) path: PathBuf,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) filesystem: ResolverInstallerOptions,
+}
+
+impl ToolSyncSettings {
+    /// Resolve the [`ToolSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: ToolSyncArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: &EnvironmentOptions,
+    ) -> Self {
+        let ToolSyncArgs { path } = args;
+
+        let filesystem = filesystem.map(FilesystemOptions::into_options);
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|options| options.install_mirrors)
+            .unwrap_or_default();
+        let top_level = ResolverInstallerOptions::from(
+            filesystem
+                .map(|options| options.top_level)
+                .unwrap_or_default(),
+        );
+
+        Self {
+            path: path.unwrap_or_else(|| PathBuf::from("uv-tools.lock")),
+            install_mirrors: environment
+                .install_mirrors
+                .clone()
+                .combine(filesystem_install_mirrors),
+            filesystem: top_level,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub(crate) enum PythonListKinds {
     #[default]
@@ -1068,8 +1357,10 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) registry: Option<bool>,
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) python_download_attestations: Option<PythonDownloadAttestations>,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
+    pub(crate) scope: PythonInstallScope,
 }
 
 impl PythonInstallSettings {
@@ -1092,6 +1383,7 @@ impl PythonInstallSettings {
         let PythonInstallMirrors {
             python_install_mirror,
             pypy_install_mirror,
+            python_download_attestations,
             python_downloads_json_url,
         } = install_mirrors;
 
@@ -1109,6 +1401,7 @@ impl PythonInstallSettings {
             pypy_mirror: _,
             python_downloads_json_url: _,
             default,
+            system_scope,
         } = args;
 
         Self {
@@ -1126,8 +1419,14 @@ impl PythonInstallSettings {
                 .or(environment.python_install_registry),
             python_install_mirror,
             pypy_install_mirror,
+            python_download_attestations,
             python_downloads_json_url,
             default,
+            scope: if system_scope {
+                PythonInstallScope::System
+            } else {
+                PythonInstallScope::User
+            },
         }
     }
 }
@@ -1142,10 +1441,13 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) registry: Option<bool>,
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
+    pub(crate) python_download_attestations: Option<PythonDownloadAttestations>,
     pub(crate) reinstall: bool,
+    pub(crate) relink: bool,
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) scope: PythonInstallScope,
 }
 
 impl PythonUpgradeSettings {
@@ -1168,6 +1470,7 @@ impl PythonUpgradeSettings {
         let PythonInstallMirrors {
             python_install_mirror,
             pypy_install_mirror,
+            python_download_attestations,
             python_downloads_json_url,
         } = install_mirrors;
 
@@ -1182,7 +1485,9 @@ impl PythonUpgradeSettings {
             mirror: _,
             pypy_mirror: _,
             reinstall,
+            relink,
             python_downloads_json_url: _,
+            system_scope,
         } = args;
 
         Self {
@@ -1192,10 +1497,17 @@ impl PythonUpgradeSettings {
             registry,
             python_install_mirror,
             pypy_install_mirror,
+            python_download_attestations,
             reinstall,
+            relink,
             python_downloads_json_url,
             default,
             bin,
+            scope: if system_scope {
+                PythonInstallScope::System
+            } else {
+                PythonInstallScope::User
+            },
         }
     }
 }
@@ -1206,6 +1518,7 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) scope: PythonInstallScope,
 }
 
 impl PythonUninstallSettings {
@@ -1219,12 +1532,18 @@ impl PythonUninstallSettings {
             install_dir,
             targets,
             all,
+            system_scope,
         } = args;
 
         Self {
             install_dir,
             targets,
             all,
+            scope: if system_scope {
+                PythonInstallScope::System
+            } else {
+                PythonInstallScope::User
+            },
         }
     }
 }
@@ -1234,6 +1553,7 @@ impl PythonUninstallSettings {
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
+    pub(crate) output_format: PythonFindFormat,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -1250,6 +1570,7 @@ impl PythonFindSettings {
         let PythonFindArgs {
             request,
             show_version,
+            output_format,
             no_project,
             system,
             no_system,
@@ -1271,12 +1592,14 @@ impl PythonFindSettings {
         let PythonInstallMirrors {
             python_install_mirror: _,
             pypy_install_mirror: _,
+            python_download_attestations: _,
             python_downloads_json_url,
         } = install_mirrors;
 
         Self {
             request,
             show_version,
+            output_format,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
             python_downloads_json_url,
@@ -1351,6 +1674,9 @@ pub(crate) struct SyncSettings {
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) output_format: SyncFormat,
+    pub(crate) snapshot: bool,
+    pub(crate) rollback: bool,
+    pub(crate) atomic: bool,
 }
 
 impl SyncSettings {
@@ -1360,7 +1686,7 @@ impl SyncSettings {
         args: SyncArgs,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         let SyncArgs {
             extra,
             all_extras,
@@ -1369,40 +1695,91 @@ impl SyncSettings {
             dev,
             no_dev,
             only_dev,
-            group,
-            no_group,
-            no_default_groups,
-            only_group,
-            all_groups,
+            mut group,
+            mut no_group,
+            mut no_default_groups,
+            mut only_group,
+            mut all_groups,
             editable,
             no_editable,
             inexact,
             exact,
-            no_install_project,
+            mut no_install_project,
             only_install_project,
-            no_install_workspace,
+            mut no_install_workspace,
             only_install_workspace,
-            no_install_local,
+            mut no_install_local,
             only_install_local,
-            no_install_package,
+            mut no_install_package,
             only_install_package,
-            locked,
-            frozen,
+            mut locked,
+            mut frozen,
             active,
             no_active,
             dry_run,
             installer,
             build,
             refresh,
-            all_packages,
+            mut all_packages,
             package,
+            profile,
             script,
             python,
             python_platform,
             check,
             no_check,
             output_format,
+            snapshot,
+            rollback,
+            atomic,
         } = args;
+
+        // Combine the named profile, if any, with the command-line arguments. Lists are extended,
+        // and flags are enabled unless they conflict with an explicit command-line argument.
+        let profile = if let Some(name) = profile {
+            let profiles = filesystem
+                .as_ref()
+                .and_then(|filesystem| filesystem.sync_profiles.as_ref());
+            let Some(profile) = profiles.and_then(|profiles| profiles.get(&name)) else {
+                let available = profiles
+                    .map(|profiles| {
+                        profiles
+                            .keys()
+                            .map(|name| format!("`{name}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .filter(|available| !available.is_empty());
+                if let Some(available) = available {
+                    anyhow::bail!(
+                        "Sync profile `{name}` not found in `tool.uv.sync-profiles` (available profiles: {available})"
+                    );
+                }
+                anyhow::bail!("Sync profile `{name}` not found in `tool.uv.sync-profiles`");
+            };
+            profile.clone()
+        } else {
+            SyncProfile::default()
+        };
+
+        let mut extra = extra.unwrap_or_default();
+        extra.extend(profile.extras);
+        group.extend(profile.groups);
+        no_group.extend(profile.no_groups);
+        only_group.extend(profile.only_groups);
+        all_groups |= profile.all_groups;
+        no_default_groups |= profile.no_default_groups;
+        let no_dev = no_dev || (profile.no_dev && !dev && !only_dev);
+        no_install_project |= profile.no_install_project && !only_install_project;
+        no_install_workspace |= profile.no_install_workspace && !only_install_workspace;
+        no_install_local |= profile.no_install_local && !only_install_local;
+        if only_install_package.is_empty() {
+            no_install_package.extend(profile.no_install_package);
+        }
+        all_packages |= profile.all_packages && package.is_empty();
+        locked |= profile.locked && !frozen;
+        frozen |= profile.frozen && !locked;
+
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -1425,21 +1802,24 @@ impl SyncSettings {
             LockCheck::Disabled
         };
 
-        Self {
+        Ok(Self {
             output_format,
+            snapshot,
+            rollback,
+            atomic,
             lock_check,
             frozen,
             dry_run,
             script,
             active: flag(active, no_active, "active"),
             extras: ExtrasSpecification::from_args(
-                extra.unwrap_or_default(),
+                extra,
                 no_extra,
                 // TODO(blueraft): support no_default_extras
                 false,
                 // TODO(blueraft): support only_extra
                 vec![],
-                flag(all_extras, no_all_extras, "all-extras").unwrap_or_default(),
+                flag(all_extras, no_all_extras, "all-extras").unwrap_or(profile.all_extras),
             ),
             groups: DependencyGroups::from_args(
                 dev,
@@ -1462,7 +1842,7 @@ impl SyncSettings {
                 no_install_package,
                 only_install_package,
             ),
-            modifications: if flag(exact, inexact, "inexact").unwrap_or(true) {
+            modifications: if flag(exact, inexact, "inexact").unwrap_or(!profile.inexact) {
                 Modifications::Exact
             } else {
                 Modifications::Sufficient
@@ -1476,7 +1856,7 @@ impl SyncSettings {
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
-        }
+        })
     }
 }
 
@@ -1487,7 +1867,14 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) format: LockFormat,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) audit: Option<(PathBuf, PathBuf)>,
+    pub(crate) explain: Option<PackageName>,
+    pub(crate) upgrade_to: Option<UpgradeTarget>,
+    pub(crate) save: bool,
+    pub(crate) strict_requires_python: bool,
+    pub(crate) emit_constraints: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1507,7 +1894,14 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            format,
             script,
+            audit,
+            explain,
+            to,
+            save,
+            strict_requires_python,
+            emit_constraints,
             resolver,
             build,
             refresh,
@@ -1531,7 +1925,16 @@ impl LockSettings {
             lock_check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
+            format,
             script,
+            audit: audit
+                .and_then(|paths| <[PathBuf; 2]>::try_from(paths).ok())
+                .map(|[old, new]| (old, new)),
+            explain,
+            upgrade_to: to,
+            save,
+            strict_requires_python,
+            emit_constraints,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1552,6 +1955,8 @@ pub(crate) struct AddSettings {
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) from_imports: bool,
+    pub(crate) interactive: bool,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) marker: Option<MarkerTree>,
     pub(crate) dependency_type: DependencyType,
@@ -1559,6 +1964,7 @@ pub(crate) struct AddSettings {
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw: bool,
     pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) upgrade_policy: UpgradePolicy,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -1591,6 +1997,8 @@ impl AddSettings {
         let AddArgs {
             packages,
             requirements,
+            from_imports,
+            interactive,
             constraints,
             marker,
             dev,
@@ -1601,6 +2009,7 @@ impl AddSettings {
             extra,
             raw,
             bounds,
+            upgrade_policy,
             rev,
             tag,
             branch,
@@ -1703,6 +2112,9 @@ impl AddSettings {
             .unwrap_or_default();
 
         let bounds = bounds.or(filesystem.as_ref().and_then(|fs| fs.add.add_bounds));
+        let upgrade_policy = upgrade_policy
+            .or(filesystem.as_ref().and_then(|fs| fs.add.add_upgrade_policy))
+            .unwrap_or_default();
 
         Self {
             lock_check: if locked {
@@ -1715,6 +2127,8 @@ impl AddSettings {
             no_sync,
             packages,
             requirements,
+            from_imports,
+            interactive,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -1723,6 +2137,7 @@ impl AddSettings {
             dependency_type,
             raw,
             bounds,
+            upgrade_policy,
             rev,
             tag,
             branch,
@@ -1762,6 +2177,8 @@ pub(crate) struct RemoveSettings {
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    pub(crate) unused: bool,
+    pub(crate) dry_run: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
@@ -1783,6 +2200,8 @@ impl RemoveSettings {
             dev,
             optional,
             packages,
+            unused,
+            dry_run,
             group,
             no_sync,
             locked,
@@ -1827,6 +2246,8 @@ impl RemoveSettings {
             active: flag(active, no_active, "active"),
             no_sync,
             packages,
+            unused,
+            dry_run,
             dependency_type,
             package,
             script,
@@ -1936,6 +2357,7 @@ pub(crate) struct TreeSettings {
     pub(crate) invert: bool,
     pub(crate) outdated: bool,
     pub(crate) show_sizes: bool,
+    pub(crate) interactive: bool,
     #[allow(dead_code)]
     pub(crate) script: Option<PathBuf>,
     pub(crate) python_version: Option<PythonVersion>,
@@ -1955,6 +2377,7 @@ impl TreeSettings {
         let TreeArgs {
             tree,
             universal,
+            interactive,
             dev,
             only_dev,
             no_dev,
@@ -2003,6 +2426,7 @@ impl TreeSettings {
             invert: tree.invert,
             outdated: tree.outdated,
             show_sizes: tree.show_sizes,
+            interactive,
             script,
             python_version,
             python_platform,
@@ -2029,12 +2453,16 @@ pub(crate) struct ExportSettings {
     pub(crate) hashes: bool,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) split_groups: bool,
+    pub(crate) strip_markers: bool,
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: bool,
     pub(crate) include_annotations: bool,
     pub(crate) include_header: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -2074,6 +2502,8 @@ impl ExportSettings {
             hashes,
             no_hashes,
             output_file,
+            split_groups,
+            strip_markers,
             no_emit_project,
             only_emit_project,
             no_emit_workspace,
@@ -2089,6 +2519,8 @@ impl ExportSettings {
             refresh,
             script,
             python,
+            python_version,
+            python_platform,
         } = args;
         let filesystem_install_mirrors = filesystem
             .clone()
@@ -2132,6 +2564,8 @@ impl ExportSettings {
                 only_emit_package,
             ),
             output_file,
+            split_groups,
+            strip_markers,
             lock_check: if locked {
                 LockCheck::Enabled(LockCheckSource::Locked)
             } else {
@@ -2142,6 +2576,8 @@ impl ExportSettings {
             include_header: flag(header, no_header, "header").unwrap_or(true),
             script,
             python: python.and_then(Maybe::into_option),
+            python_version,
+            python_platform,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors: environment
@@ -2182,6 +2618,126 @@ impl FormatSettings {
     }
 }
 
+/// The resolved settings to use for a `shell` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct ShellSettings {
+    pub(crate) command: Option<String>,
+    pub(crate) active: Option<bool>,
+}
+
+impl ShellSettings {
+    /// Resolve the [`ShellSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ShellArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ShellArgs {
+            command,
+            active,
+            no_active,
+        } = args;
+
+        Self {
+            command,
+            active: flag(active, no_active, "active"),
+        }
+    }
+}
+
+/// The resolved settings to use for a `verify` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct VerifySettings {
+    pub(crate) active: Option<bool>,
+}
+
+impl VerifySettings {
+    /// Resolve the [`VerifySettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: VerifyArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let VerifyArgs { active, no_active } = args;
+
+        Self {
+            active: flag(active, no_active, "active"),
+        }
+    }
+}
+
+/// The resolved settings to use for an `index sync` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexSyncSettings {
+    pub(crate) settings: PipSettings,
+}
+
+impl IndexSyncSettings {
+    /// Resolve the [`IndexSyncSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: IndexSyncArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let IndexSyncArgs { fetch } = args;
+
+        Self {
+            settings: PipSettings::combine(PipOptions::from(fetch), filesystem, environment),
+        }
+    }
+}
+
+/// The resolved settings to use for an `env restore` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct EnvRestoreSettings {
+    pub(crate) steps: NonZeroUsize,
+    pub(crate) python: Option<String>,
+}
+
+impl EnvRestoreSettings {
+    /// Resolve the [`EnvRestoreSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: EnvRestoreArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let EnvRestoreArgs { steps, python } = args;
+
+        Self {
+            steps,
+            python: python.and_then(Maybe::into_option),
+        }
+    }
+}
+
+/// The resolved settings to use for a `bundle create` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct BundleCreateSettings {
+    pub(crate) output: PathBuf,
+    pub(crate) python: Option<String>,
+}
+
+impl BundleCreateSettings {
+    /// Resolve the [`BundleCreateSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: BundleCreateArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleCreateArgs { output, python } = args;
+
+        Self {
+            output,
+            python: python.and_then(Maybe::into_option),
+        }
+    }
+}
+
+/// The resolved settings to use for a `bundle install` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct BundleInstallSettings {
+    pub(crate) bundle: PathBuf,
+    pub(crate) python: Option<String>,
+}
+
+impl BundleInstallSettings {
+    /// Resolve the [`BundleInstallSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: BundleInstallArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let BundleInstallArgs { bundle, python } = args;
+
+        Self {
+            bundle,
+            python: python.and_then(Maybe::into_option),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
@@ -2196,6 +2752,8 @@ pub(crate) struct PipCompileSettings {
     pub(crate) excludes_from_workspace: Vec<uv_normalize::PackageName>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) python_versions: Vec<PythonVersion>,
+    pub(crate) split_python_versions: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2243,6 +2801,8 @@ impl PipCompileSettings {
             no_binary,
             only_binary,
             python_version,
+            python_versions,
+            split_python_versions,
             python_platform,
             universal,
             no_universal,
@@ -2342,6 +2902,8 @@ impl PipCompileSettings {
             excludes_from_workspace,
             build_constraints_from_workspace,
             environments,
+            python_versions: python_versions.unwrap_or_default(),
+            split_python_versions,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2399,7 +2961,9 @@ pub(crate) struct PipSyncSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) implementation: Option<ImplementationName>,
     pub(crate) dry_run: DryRun,
+    pub(crate) assume_installed: Option<PathBuf>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2440,9 +3004,11 @@ impl PipSyncSettings {
             only_binary,
             python_version,
             python_platform,
+            implementation,
             strict,
             no_strict,
             dry_run,
+            assume_installed,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -2457,7 +3023,9 @@ impl PipSyncSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            implementation,
             dry_run: DryRun::from_args(dry_run),
+            assume_installed,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2506,7 +3074,9 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) implementation: Option<ImplementationName>,
     pub(crate) dry_run: DryRun,
+    pub(crate) assume_installed: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) excludes_from_workspace: Vec<uv_normalize::PackageName>,
@@ -2556,11 +3126,13 @@ impl PipInstallSettings {
             only_binary,
             python_version,
             python_platform,
+            implementation,
             inexact,
             exact,
             strict,
             no_strict,
             dry_run,
+            assume_installed,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2636,7 +3208,9 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            implementation,
             dry_run: DryRun::from_args(dry_run),
+            assume_installed,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -2740,6 +3314,7 @@ impl PipUninstallSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipFreezeSettings {
     pub(crate) exclude_editable: bool,
+    pub(crate) format: FreezeFormat,
     pub(crate) paths: Option<Vec<PathBuf>>,
     pub(crate) settings: PipSettings,
 }
@@ -2753,6 +3328,7 @@ impl PipFreezeSettings {
     ) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            format,
             strict,
             no_strict,
             python,
@@ -2764,6 +3340,7 @@ impl PipFreezeSettings {
 
         Self {
             exclude_editable,
+            format,
             paths,
             settings: PipSettings::combine(
                 PipOptions {
@@ -2979,6 +3556,8 @@ pub(crate) struct BuildSettings {
     pub(crate) gitignore: bool,
     pub(crate) force_pep517: bool,
     pub(crate) clear: bool,
+    pub(crate) reproducible_check: bool,
+    pub(crate) check_tags: bool,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
@@ -3004,6 +3583,8 @@ impl BuildSettings {
             list,
             force_pep517,
             clear,
+            reproducible_check,
+            check_tags,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -3034,6 +3615,8 @@ impl BuildSettings {
             build_logs: flag(build_logs, no_build_logs, "build-logs").unwrap_or(true),
             force_pep517,
             clear,
+            reproducible_check,
+            check_tags,
             gitignore: flag(create_gitignore, no_create_gitignore, "create-gitignore")
                 .unwrap_or(true),
             build_constraints: build_constraints
@@ -3058,6 +3641,7 @@ impl BuildSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
+    pub(crate) seed_from: Option<PathBuf>,
     pub(crate) allow_existing: bool,
     pub(crate) clear: bool,
     pub(crate) no_clear: bool,
@@ -3082,6 +3666,7 @@ impl VenvSettings {
             system,
             no_system,
             seed,
+            seed_from,
             allow_existing,
             clear,
             no_clear,
@@ -3102,6 +3687,7 @@ impl VenvSettings {
 
         Self {
             seed,
+            seed_from,
             allow_existing,
             clear,
             no_clear,
@@ -3148,6 +3734,8 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) exclude_newer: &'a ExcludeNewer,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) script_policy: ScriptPolicy,
+    pub(crate) wheel_checks: WheelChecks,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
     pub(crate) sources: SourceStrategy,
@@ -3165,6 +3753,12 @@ pub(crate) struct ResolverSettings {
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) yanked_policy: YankedPolicy,
+    pub(crate) allow_yanked: Vec<AllowedYank>,
+    pub(crate) known_incompatibilities: Vec<KnownIncompatibility>,
+    pub(crate) max_backtracks: Option<NonZeroUsize>,
+    pub(crate) resolution_timeout: Option<Duration>,
+    pub(crate) prefer_smaller_artifacts: bool,
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -3217,6 +3811,12 @@ impl From<ResolverOptions> for ResolverSettings {
             resolution: value.resolution.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            yanked_policy: value.yanked_policy.unwrap_or_default(),
+            allow_yanked: value.allow_yanked.unwrap_or_default(),
+            known_incompatibilities: value.known_incompatibilities.unwrap_or_default(),
+            max_backtracks: value.max_backtracks,
+            resolution_timeout: value.resolution_timeout.map(Duration::from_secs),
+            prefer_smaller_artifacts: value.prefer_smaller_artifacts.unwrap_or(false),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -3248,6 +3848,8 @@ impl From<ResolverOptions> for ResolverSettings {
 pub(crate) struct ResolverInstallerSettings {
     pub(crate) resolver: ResolverSettings,
     pub(crate) compile_bytecode: bool,
+    pub(crate) script_policy: ScriptPolicy,
+    pub(crate) wheel_checks: WheelChecks,
     pub(crate) reinstall: Reinstall,
 }
 
@@ -3310,6 +3912,12 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .collect(),
                 ),
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
+                yanked_policy: value.yanked_policy.unwrap_or_default(),
+                allow_yanked: value.allow_yanked.unwrap_or_default(),
+                known_incompatibilities: value.known_incompatibilities.unwrap_or_default(),
+                max_backtracks: value.max_backtracks,
+                resolution_timeout: value.resolution_timeout.map(Duration::from_secs),
+                prefer_smaller_artifacts: value.prefer_smaller_artifacts.unwrap_or(false),
                 index_locations,
                 index_strategy: value.index_strategy.unwrap_or_default(),
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
@@ -3323,6 +3931,11 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 upgrade: value.upgrade.unwrap_or_default(),
             },
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
+            script_policy: ScriptPolicy {
+                launcher: value.script_launcher.unwrap_or_default().current(),
+                gui_scripts: !value.no_gui_scripts.unwrap_or_default(),
+            },
+            wheel_checks: WheelChecks::from_checks(&value.wheel_checks.unwrap_or_default()),
             reinstall: value.reinstall.unwrap_or_default(),
         }
     }
@@ -3346,6 +3959,7 @@ pub(crate) struct PipSettings {
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) torch_backend: Option<TorchMode>,
+    pub(crate) torch_backend_packages: Vec<PackageName>,
     pub(crate) build_isolation: BuildIsolation,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
@@ -3356,6 +3970,12 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) yanked_policy: YankedPolicy,
+    pub(crate) allow_yanked: Vec<AllowedYank>,
+    pub(crate) known_incompatibilities: Vec<KnownIncompatibility>,
+    pub(crate) max_backtracks: Option<NonZeroUsize>,
+    pub(crate) resolution_timeout: Option<Duration>,
+    pub(crate) prefer_smaller_artifacts: bool,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -3379,6 +3999,8 @@ pub(crate) struct PipSettings {
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
+    pub(crate) script_policy: ScriptPolicy,
+    pub(crate) wheel_checks: WheelChecks,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) upgrade: Upgrade,
@@ -3414,6 +4036,7 @@ impl PipSettings {
             find_links,
             index_strategy,
             torch_backend,
+            torch_backend_packages,
             keyring_provider,
             no_build,
             no_binary,
@@ -3432,6 +4055,12 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            yanked_policy,
+            allow_yanked,
+            known_incompatibilities,
+            max_backtracks,
+            resolution_timeout,
+            prefer_smaller_artifacts,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -3476,6 +4105,12 @@ impl PipSettings {
             resolution: top_level_resolution,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            yanked_policy: top_level_yanked_policy,
+            allow_yanked: top_level_allow_yanked,
+            known_incompatibilities: top_level_known_incompatibilities,
+            max_backtracks: top_level_max_backtracks,
+            resolution_timeout: top_level_resolution_timeout,
+            prefer_smaller_artifacts: top_level_prefer_smaller_artifacts,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
@@ -3486,6 +4121,9 @@ impl PipSettings {
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
+            script_launcher: top_level_script_launcher,
+            no_gui_scripts: top_level_no_gui_scripts,
+            wheel_checks: top_level_wheel_checks,
             no_sources: top_level_no_sources,
             upgrade: top_level_upgrade,
             upgrade_package: top_level_upgrade_package,
@@ -3512,6 +4150,14 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let yanked_policy = yanked_policy.combine(top_level_yanked_policy);
+        let allow_yanked = allow_yanked.combine(top_level_allow_yanked);
+        let known_incompatibilities =
+            known_incompatibilities.combine(top_level_known_incompatibilities);
+        let max_backtracks = max_backtracks.combine(top_level_max_backtracks);
+        let resolution_timeout = resolution_timeout.combine(top_level_resolution_timeout);
+        let prefer_smaller_artifacts =
+            prefer_smaller_artifacts.combine(top_level_prefer_smaller_artifacts);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -3580,6 +4226,24 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            yanked_policy: args
+                .yanked_policy
+                .combine(yanked_policy)
+                .unwrap_or_default(),
+            allow_yanked: args.allow_yanked.combine(allow_yanked).unwrap_or_default(),
+            known_incompatibilities: args
+                .known_incompatibilities
+                .combine(known_incompatibilities)
+                .unwrap_or_default(),
+            max_backtracks: args.max_backtracks.combine(max_backtracks),
+            resolution_timeout: args
+                .resolution_timeout
+                .combine(resolution_timeout)
+                .map(Duration::from_secs),
+            prefer_smaller_artifacts: args
+                .prefer_smaller_artifacts
+                .combine(prefer_smaller_artifacts)
+                .unwrap_or(false),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -3643,6 +4307,10 @@ impl PipSettings {
                 .combine(config_settings_package)
                 .unwrap_or_default(),
             torch_backend: args.torch_backend.combine(torch_backend),
+            torch_backend_packages: args
+                .torch_backend_packages
+                .combine(torch_backend_packages)
+                .unwrap_or_default(),
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
             universal: args.universal.combine(universal).unwrap_or_default(),
@@ -3691,6 +4359,11 @@ impl PipSettings {
                 .compile_bytecode
                 .combine(compile_bytecode)
                 .unwrap_or_default(),
+            script_policy: ScriptPolicy {
+                launcher: top_level_script_launcher.unwrap_or_default().current(),
+                gui_scripts: !top_level_no_gui_scripts.unwrap_or_default(),
+            },
+            wheel_checks: WheelChecks::from_checks(&top_level_wheel_checks.unwrap_or_default()),
             sources: SourceStrategy::from_args(
                 args.no_sources.combine(no_sources).unwrap_or_default(),
             ),
@@ -3758,6 +4431,8 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             exclude_newer: &settings.resolver.exclude_newer,
             link_mode: settings.resolver.link_mode,
             compile_bytecode: settings.compile_bytecode,
+            script_policy: settings.script_policy,
+            wheel_checks: settings.wheel_checks,
             reinstall: &settings.reinstall,
             build_options: &settings.resolver.build_options,
             sources: settings.resolver.sources,
@@ -3853,6 +4528,7 @@ impl PublishSettings {
 pub(crate) struct AuthLogoutSettings {
     pub(crate) service: Service,
     pub(crate) username: Option<String>,
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
 
     // Both CLI and configuration.
     pub(crate) network_settings: NetworkSettings,
@@ -3869,6 +4545,7 @@ impl AuthLogoutSettings {
         Self {
             service: args.service,
             username: args.username,
+            keyring_provider: args.keyring_provider,
             network_settings: NetworkSettings::resolve(global_args, filesystem, environment),
         }
     }
@@ -3879,6 +4556,7 @@ impl AuthLogoutSettings {
 pub(crate) struct AuthTokenSettings {
     pub(crate) service: Service,
     pub(crate) username: Option<String>,
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
 
     // Both CLI and configuration.
     pub(crate) network_settings: NetworkSettings,
@@ -3895,6 +4573,7 @@ impl AuthTokenSettings {
         Self {
             service: args.service,
             username: args.username,
+            keyring_provider: args.keyring_provider,
             network_settings: NetworkSettings::resolve(global_args, filesystem, environment),
         }
     }
@@ -3907,6 +4586,7 @@ pub(crate) struct AuthLoginSettings {
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
     pub(crate) token: Option<String>,
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
 
     // Both CLI and configuration.
     pub(crate) network_settings: NetworkSettings,
@@ -3925,6 +4605,7 @@ impl AuthLoginSettings {
             username: args.username,
             password: args.password,
             token: args.token,
+            keyring_provider: args.keyring_provider,
             network_settings: NetworkSettings::resolve(global_args, filesystem, environment),
         }
     }
@@ -225,6 +225,100 @@ fn add_package_native_auth() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "native-auth")]
+fn add_package_keyring_provider_native() -> Result<()> {
+    let context = TestContext::new("3.12").with_real_home();
+
+    // Clear state before the test
+    context
+        .auth_logout()
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--username")
+        .arg("public")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "native")
+        .status()?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.11, <4"
+        dependencies = []
+        "#
+    })?;
+
+    // The `keyring` command doesn't support storing credentials.
+    uv_snapshot!(context.auth_login()
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--username")
+        .arg("public")
+        .arg("--password")
+        .arg("heron")
+        .arg("--keyring-provider")
+        .arg("subprocess"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The 'subprocess' keyring provider does not support storing credentials
+    "
+    );
+
+    // Login to the index, using the native store without enabling the preview.
+    uv_snapshot!(context.auth_login()
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--username")
+        .arg("public")
+        .arg("--password")
+        .arg("heron")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "native"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for public@https://pypi-proxy.fly.dev/basic-auth
+    "
+    );
+
+    // The credentials are read from the native store, without the `keyring` command.
+    uv_snapshot!(context.add().arg("anyio").arg("--default-index").arg("https://public@pypi-proxy.fly.dev/basic-auth/simple")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "native"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    // Logout of the index
+    uv_snapshot!(context.auth_logout()
+        .arg("https://pypi-proxy.fly.dev/basic-auth/simple")
+        .arg("--username")
+        .arg("public")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "native"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for public@https://pypi-proxy.fly.dev/basic-auth
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "native-auth")]
 fn token_native_auth() -> Result<()> {
@@ -894,6 +894,63 @@ fn build_constraints() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn build_constraints_workspace() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\\\.", "")])
+        .collect::<Vec<_>>();
+
+    let project = context.temp_dir.child("project");
+
+    let pyproject_toml = project.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["hatchling>=1.0"]
+        build-backend = "hatchling.build"
+
+        [tool.uv]
+        build-constraint-dependencies = ["hatchling==0.1.0"]
+        "#,
+    )?;
+
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+
+    uv_snapshot!(&filters, context.build().current_dir(&project), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution...
+      × Failed to build `[TEMP_DIR]/project`
+      ├─▶ Failed to resolve requirements from `build-system.requires`
+      ├─▶ No solution found when resolving: `hatchling>=1.0`
+      ╰─▶ Because you require hatchling>=1.0 and hatchling==0.1.0, we can conclude that your requirements are unsatisfiable.
+    "###);
+
+    project
+        .child("dist")
+        .child("project-0.1.0.tar.gz")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
 #[test]
 fn build_sha() -> Result<()> {
     let context = TestContext::new(DEFAULT_PYTHON_VERSION);
@@ -2214,6 +2271,143 @@ fn build_clear() -> Result<()> {
     Ok(())
 }
 
+/// Test `uv build --reproducible-check`.
+#[test]
+fn build_reproducible_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"sha256:[0-9a-f]{64}", "sha256:[DIGEST]")])
+        .collect::<Vec<_>>();
+
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+
+    uv_snapshot!(filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output1"))
+        .arg("--reproducible-check"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output1/built_by_uv-0.1.0.tar.gz
+    Successfully built output1/built_by_uv-0.1.0-py3-none-any.whl
+    Verified that built_by_uv-0.1.0.tar.gz is reproducible (sha256:[DIGEST])
+    Verified that built_by_uv-0.1.0-py3-none-any.whl is reproducible (sha256:[DIGEST])
+    ");
+
+    // The temporary build directory is removed.
+    assert_eq!(
+        fs_err::read_dir(context.temp_dir.join("output1"))?
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .count(),
+        0
+    );
+
+    // `SOURCE_DATE_EPOCH` sets the timestamps, and the build remains reproducible.
+    uv_snapshot!(filters, context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output2"))
+        .arg("--wheel")
+        .arg("--reproducible-check")
+        .env(EnvVars::SOURCE_DATE_EPOCH, "1700000000"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel (uv build backend)...
+    Building wheel (uv build backend)...
+    Successfully built output2/built_by_uv-0.1.0-py3-none-any.whl
+    Verified that built_by_uv-0.1.0-py3-none-any.whl is reproducible (sha256:[DIGEST])
+    ");
+
+    // The timestamps differ from the default, so the wheels differ.
+    assert_ne!(
+        fs_err::read(
+            context
+                .temp_dir
+                .join("output1")
+                .join("built_by_uv-0.1.0-py3-none-any.whl")
+        )?,
+        fs_err::read(
+            context
+                .temp_dir
+                .join("output2")
+                .join("built_by_uv-0.1.0-py3-none-any.whl")
+        )?
+    );
+
+    Ok(())
+}
+
+/// Test `uv build --check-tags`.
+#[cfg(unix)]
+#[test]
+fn build_check_tags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A pure Python wheel passes the check.
+    let built_by_uv = current_dir()?.join("../../scripts/packages/built-by-uv");
+    uv_snapshot!(context.filters(), context.build()
+        .arg(&built_by_uv)
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output1"))
+        .arg("--wheel")
+        .arg("--check-tags"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel (uv build backend)...
+    Successfully built output1/built_by_uv-0.1.0-py3-none-any.whl
+    Verified that the tags of built_by_uv-0.1.0-py3-none-any.whl match its binaries
+    ");
+
+    // A wheel tagged as platform-independent must not contain binaries.
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["uv_build>=0.8.0,<0.10.0"]
+        build-backend = "uv_build"
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+    fs_err::copy("/usr/bin/true", project.child("src/project/helper").path())?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg(project.path())
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output2"))
+        .arg("--wheel")
+        .arg("--check-tags"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel (uv build backend)...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ The tags of the built wheels are broader than their binaries support:
+          `project-0.1.0-py3-none-any.whl`: `project/helper` is a compiled binary, but the wheel is tagged as platform-independent (tag `any`)
+    ");
+
+    Ok(())
+}
+
 /// Test `uv build --no-create-gitignore`.
 #[test]
 fn build_no_gitignore() -> Result<()> {
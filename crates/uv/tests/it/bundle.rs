@@ -0,0 +1,59 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use predicates::prelude::predicate;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Bundle an environment, then install the bundle into a fresh environment without network access.
+#[test]
+fn bundle_create_install() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.bundle_create().arg("--preview-features").arg("bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Bundled 1 package into `bundle.tar.zst`
+    ");
+
+    context
+        .temp_dir
+        .child("bundle.tar.zst")
+        .assert(predicate::path::is_file());
+
+    // Install the bundle into a fresh environment, without access to an index.
+    context.venv().arg("--clear").assert().success();
+
+    uv_snapshot!(context.filters(), context.bundle_install().arg("bundle.tar.zst").arg("--offline").arg("--preview-features").arg("bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    context.assert_command("import iniconfig").success();
+
+    // Installing the bundle again is a no-op.
+    uv_snapshot!(context.filters(), context.bundle_install().arg("bundle.tar.zst").arg("--preview-features").arg("bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All packages in the bundle are installed in: .venv
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use assert_cmd::assert::OutputAssertExt;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Test that `cache info` reports an empty cache.
+#[test]
+fn cache_info_empty() {
+    let context = TestContext::new("3.12");
+
+    // Clean cache first to ensure truly empty state
+    context.clean().assert().success();
+
+    uv_snapshot!(context.cache_info().arg("--preview"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/: 0.0B
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.cache_info().arg("--preview").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"root":"[CACHE_DIR]/","total":0,"buckets":[],"indexes":[],"packages":[]}
+
+    ----- stderr -----
+    "#);
+}
+
+/// Test that `cache info` attributes cache entries to the index and package they belong to.
+#[test]
+fn cache_info_with_packages() {
+    let context = TestContext::new("3.12");
+
+    // Install a requirement to populate the cache.
+    context.pip_install().arg("iniconfig").assert().success();
+
+    let output = context
+        .cache_info()
+        .arg("--preview")
+        .arg("--output-format")
+        .arg("json")
+        .output()
+        .expect("Failed to run `uv cache info`");
+    assert!(output.status.success());
+
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let names = |key: &str| -> Vec<String> {
+        info[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|usage| usage["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert!(
+        names("buckets")
+            .iter()
+            .any(|name| name.starts_with("wheels-"))
+    );
+    assert_eq!(
+        names("indexes"),
+        vec!["https://pypi.org/simple".to_string()]
+    );
+    assert!(names("packages").contains(&"iniconfig".to_string()));
+}
+
+/// Test that `--cache-partition` places the cache in a dedicated partition.
+#[test]
+fn cache_partition() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.cache_dir().arg("--cache-partition").arg("index"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/partitions/index-pypi
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.cache_dir().env(EnvVars::UV_CACHE_PARTITION, "none"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/
+
+    ----- stderr -----
+    ");
+}
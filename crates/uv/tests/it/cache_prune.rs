@@ -196,6 +196,76 @@ fn prune_stale_symlink() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune --referenced-only` should remove any cached wheels that aren't installed in the
+/// project environment.
+#[test]
+fn prune_referenced_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+    "#})?;
+
+    // Install a requirement, to populate the cache.
+    context.sync().assert().success();
+
+    // Replace the requirement, such that the cached wheels are no longer referenced.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+    context.sync().assert().success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((r"Removed \d+ files", "Removed [N] files")))
+        .collect();
+
+    uv_snapshot!(&filters, context.prune().arg("--referenced-only"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    // The referenced wheel should be retained, such that it can be reinstalled offline.
+    context
+        .sync()
+        .arg("--reinstall")
+        .arg("--offline")
+        .assert()
+        .success();
+
+    // But the unreferenced wheels should have been removed.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+    "#})?;
+    context
+        .sync()
+        .arg("--reinstall")
+        .arg("--offline")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
 #[test]
 fn prune_force() -> Result<()> {
     let context = TestContext::new("3.12").with_filtered_counts();
@@ -0,0 +1,141 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::TestContext;
+use crate::common::uv_snapshot;
+
+/// `cache verify` should be a no-op if the cache is intact.
+#[test]
+fn verify_no_op() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    No issues found (1 entry verified)
+    ");
+
+    Ok(())
+}
+
+/// `cache verify` should remove any unpacked wheels that are missing files, such that they're
+/// downloaded again on next use.
+#[test]
+fn verify_corrupt_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Truncate a file in the unpacked wheel.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0"))?
+        .next()
+        .expect("cache to contain an archive")?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "")?;
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain(std::iter::once((
+            r"Freed \d+(\.\d+)?[KM]?i?B",
+            "Freed [SIZE]",
+        )))
+        .collect();
+
+    uv_snapshot!(&filters, context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Removed 1 corrupt entry
+    Freed [SIZE]
+    ");
+
+    assert!(!archive.exists());
+
+    // The wheel should be downloaded again.
+    uv_snapshot!(context.filters(), context.pip_sync().arg("requirements.txt").arg("--reinstall"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// `cache verify` should roll back any writes that were interrupted before completing.
+#[test]
+fn verify_incomplete_write() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Simulate an interrupted write, by leaving a pending record in the journal for an archive
+    // that was moved into place.
+    let archive = context.cache_dir.child("archive-v0").child("interrupted");
+    archive.child("partial.py").write_str("")?;
+    context
+        .cache_dir
+        .child(".journal")
+        .child("interrupted")
+        .write_str("wheels-v5/pypi/interrupted/0.1.0-py3-none-any")?;
+
+    uv_snapshot!(context.filters(), context.cache_verify(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Rolled back 1 incomplete write
+    ");
+
+    archive.assert(predicates::path::missing());
+    context
+        .cache_dir
+        .child(".journal")
+        .child("interrupted")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -1138,6 +1138,62 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv shell` command with options shared across scenarios.
+    pub fn shell(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("shell");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv test` command with options shared across scenarios.
+    pub fn test(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("test");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv verify` command with options shared across scenarios.
+    pub fn verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv index sync` command with options shared across scenarios.
+    pub fn index_sync(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("index").arg("sync");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv env restore` command with options shared across scenarios.
+    pub fn env_restore(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("env").arg("restore");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv bundle create` command with options shared across scenarios.
+    pub fn bundle_create(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("bundle").arg("create");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv bundle install` command with options shared across scenarios.
+    pub fn bundle_install(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("bundle").arg("install");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = Self::new_command();
@@ -1167,6 +1223,13 @@ impl TestContext {
         command
     }
 
+    pub fn self_rollback(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("self").arg("rollback");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv publish` command with options shared across scenarios.
     #[allow(clippy::unused_self)]
     pub fn publish(&self) -> Command {
@@ -1273,6 +1336,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool lock` command with options shared across scenarios.
+    pub fn tool_lock(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("tool").arg("lock");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool sync` command with options shared across scenarios.
+    pub fn tool_sync(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("tool").arg("sync");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv tool list` command with options shared across scenarios.
     pub fn tool_list(&self) -> Command {
         let mut command = Self::new_command();
@@ -1313,6 +1392,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv script add` command for the given requirements.
+    pub fn script_add(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("script").arg("add");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv script remove` command for the given requirements.
+    pub fn script_remove(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("script").arg("remove");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv tree` command with options shared across scenarios.
     pub fn tree(&self) -> Command {
         let mut command = Self::new_command();
@@ -1345,6 +1440,30 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache info` command.
+    pub fn cache_info(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("info");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache verify` command.
+    pub fn cache_verify(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("verify");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache dir` command.
+    pub fn cache_dir(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("dir");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
@@ -889,7 +889,7 @@ fn add_raw_error() -> Result<()> {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--raw'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     ");
@@ -2131,7 +2131,7 @@ fn disallow_group_script_add() -> Result<()> {
     ----- stderr -----
     error: the argument '--group <GROUP>' cannot be used with '--script <SCRIPT>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --group <GROUP> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --group <GROUP> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###);
@@ -4099,6 +4099,181 @@ fn add_frozen() -> Result<()> {
     Ok(())
 }
 
+/// Add the third-party packages imported by the project's sources.
+#[test]
+fn add_from_imports() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+            """A project.
+
+            import not_an_import
+            """
+            import os
+            import anyio
+            import yaml
+            from requests import Session
+
+            from project import helpers
+            from . import helpers
+        "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("helpers.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports").arg("--frozen").arg("--preview-features").arg("add-from-imports"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Detected 2 missing dependencies from imports: pyyaml, requests
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+            "pyyaml",
+            "requests",
+        ]
+        "###
+        );
+    });
+
+    // Running again should detect nothing.
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports").arg("--frozen").arg("--preview-features").arg("add-from-imports"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    No missing dependencies detected from imports
+    "###);
+
+    Ok(())
+}
+
+/// Remove the dependencies that aren't imported by the project's sources.
+#[test]
+fn remove_unused() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "pyyaml", "requests", "iniconfig"]
+
+        [dependency-groups]
+        dev = ["pytest"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+            """A project.
+
+            import requests
+            """
+            import os
+            import anyio
+            import yaml
+        "#})?;
+
+    // In `--dry-run` mode, the unused dependencies are reported, and the project is unchanged.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dry-run").arg("--frozen").arg("--preview-features").arg("remove-unused"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Found 2 unused dependencies from imports: requests, iniconfig
+    "###);
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--frozen").arg("--preview-features").arg("remove-unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Detected 2 unused dependencies from imports: requests, iniconfig
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+            "pyyaml",
+        ]
+
+        [dependency-groups]
+        dev = ["pytest"]
+        "###
+        );
+    });
+
+    // Running again should detect nothing.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dry-run").arg("--frozen").arg("--preview-features").arg("remove-unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No unused dependencies detected from imports
+    "###);
+
+    Ok(())
+}
+
 /// Add a requirement without updating the environment.
 #[test]
 fn add_no_sync() -> Result<()> {
@@ -4169,7 +4344,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--branch <BRANCH>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -4190,7 +4365,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used with '--rev <REV>'
 
-    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --tag <TAG> --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -4211,7 +4386,7 @@ fn add_reject_multiple_git_ref_flags() {
     ----- stderr -----
     error: the argument '--tag <TAG>' cannot be used multiple times
 
-    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add [OPTIONS] <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###
@@ -5369,9 +5544,9 @@ fn add_requirements_file() -> Result<()> {
 
     ----- stderr -----
     error: the following required arguments were not provided:
-      <PACKAGES|--requirements <REQUIREMENTS>>
+      <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
-    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>>
+    Usage: uv add --cache-dir [CACHE_DIR] --exclude-newer <EXCLUDE_NEWER> <PACKAGES|--requirements <REQUIREMENTS>|--from-imports>
 
     For more information, try '--help'.
     "###);
@@ -7647,6 +7822,102 @@ fn remove_script() -> Result<()> {
     Ok(())
 }
 
+/// `uv script add` and `uv script remove` are shorthands for `uv add --script` and
+/// `uv remove --script`.
+#[test]
+fn script_add_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "requests<3",
+        #   "rich",
+        # ]
+        # ///
+
+        import requests
+        from rich.pretty import pprint
+
+        resp = requests.get("https://peps.python.org/api/peps.json")
+        data = resp.json()
+        pprint([(k, v["title"]) for k, v in data.items()][:10])
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.script_add().arg("script.py").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated `script.py`
+    "###);
+
+    let script_content = context.read("script.py");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r###"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "anyio",
+        #   "requests<3",
+        #   "rich",
+        # ]
+        # ///
+
+        import requests
+        from rich.pretty import pprint
+
+        resp = requests.get("https://peps.python.org/api/peps.json")
+        data = resp.json()
+        pprint([(k, v["title"]) for k, v in data.items()][:10])
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.script_remove().arg("script.py").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated `script.py`
+    "###);
+
+    let script_content = context.read("script.py");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            script_content, @r###"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "requests<3",
+        #   "rich",
+        # ]
+        # ///
+
+        import requests
+        from rich.pretty import pprint
+
+        resp = requests.get("https://peps.python.org/api/peps.json")
+        data = resp.json()
+        pprint([(k, v["title"]) for k, v in data.items()][:10])
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Remove last dependency PEP 723 script
 #[test]
 fn remove_last_dep_script() -> Result<()> {
@@ -13673,6 +13944,72 @@ fn add_bounds_requirement_over_bounds_kind() -> Result<()> {
 
 /// Add a path dependency with `--workspace` flag to add it to workspace members. The root already
 /// contains a workspace definition, so the package should be added to the workspace members.
+/// Prefer versions within the locked major version when a locked package must change.
+#[test]
+fn add_upgrade_policy_same_major() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<3.7"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    // By default, the locked `anyio==3.6.2` is upgraded to the latest version.
+    uv_snapshot!(context.filters(), context.add().arg("anyio>=3.7").arg("--no-sync"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"4.3.0\""));
+
+    // With `same-major`, the locked `anyio==3.6.2` is upgraded within the same major version.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<3.7"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio>=3.7").arg("--no-sync").arg("--upgrade-policy").arg("same-major"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"anyio\"\nversion = \"3.7.1\""));
+
+    let pyproject_toml = context.read("pyproject.toml");
+    assert_snapshot!(
+        pyproject_toml, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["anyio>=3.7"]
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn add_path_with_existing_workspace() -> Result<()> {
     let context = TestContext::new("3.12");
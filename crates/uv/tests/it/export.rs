@@ -461,6 +461,87 @@ fn requirements_txt_dependency_marker() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn requirements_txt_dependency_marker_python_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio ; sys_platform == 'darwin'", "iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Requirements that don't apply to Linux should be omitted.
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--python-platform").arg("linux"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --python-platform linux
+    -e .
+    iniconfig==2.0.0
+        # via project
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Requirements that apply to macOS should be retained, with their markers.
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--python-platform").arg("macos"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --python-platform macos
+    -e .
+    anyio==4.3.0 ; sys_platform == 'darwin'
+        # via project
+    idna==3.6 ; sys_platform == 'darwin'
+        # via anyio
+    iniconfig==2.0.0
+        # via project
+    sniffio==1.3.1 ; sys_platform == 'darwin'
+        # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Unless `--strip-markers` is provided.
+    uv_snapshot!(context.filters(), context.export().arg("--no-hashes").arg("--python-platform").arg("macos").arg("--strip-markers"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-hashes --python-platform macos --strip-markers
+    -e .
+    anyio==4.3.0
+        # via project
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via project
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn requirements_txt_dependency_multiple_markers() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2604,6 +2685,88 @@ fn requirements_txt_export_group() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn requirements_txt_split_groups() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = ["anyio ; sys_platform == 'darwin'"]
+        bar = ["iniconfig", "typing-extensions"]
+        dev = ["sniffio"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--split-groups").arg("--all-groups").arg("--no-group").arg("dev").arg("--output-file").arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-groups --all-groups --no-group dev --output-file requirements.txt
+    typing-extensions==4.10.0 \
+        --hash=sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475 \
+        --hash=sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb
+        # via project
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "###);
+
+    // Each group includes the base file, and only lists the requirements that it adds.
+    let contents = apply_filters(context.read("requirements-foo.txt"), context.filters());
+    insta::assert_snapshot!(contents, @r###"
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-groups --all-groups --no-group dev --output-file requirements.txt
+    -r requirements.txt
+    anyio==4.3.0 ; sys_platform == 'darwin' \
+        --hash=sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8 \
+        --hash=sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6
+    idna==3.6 ; sys_platform == 'darwin' \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    sniffio==1.3.1 ; sys_platform == 'darwin' \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+    "###);
+
+    let contents = apply_filters(context.read("requirements-bar.txt"), context.filters());
+    insta::assert_snapshot!(contents, @r###"
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --split-groups --all-groups --no-group dev --output-file requirements.txt
+    -r requirements.txt
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "###);
+
+    // Excluded groups are not written.
+    assert!(!context.temp_dir.child("requirements-dev.txt").exists());
+
+    uv_snapshot!(context.filters(), context.export().arg("--split-groups").arg("--format").arg("pylock.toml").arg("--output-file").arg("pylock.toml"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    error: `--split-groups` is only supported for the `requirements.txt` format
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn requirements_txt_script() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -8325,3 +8488,94 @@ fn cyclonedx_export_alternative_registry() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dockerfile_snippet() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        async = ["sniffio"]
+
+        [dependency-groups]
+        dev = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"astral-sh/uv:\S+", "astral-sh/uv:[VERSION]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.export().arg("--format").arg("dockerfile-snippet").arg("--no-dev").arg("--extra").arg("async"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --format dockerfile-snippet --no-dev --extra async
+    FROM python:3.12-slim AS builder
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode at install time, copy from the cache mount (rather than linking), and
+    # use the Python interpreter provided by the base image.
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0
+
+    WORKDIR /app
+
+    # Install the dependencies in a separate layer, such that it's only rebuilt when the
+    # lockfile changes.
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --locked --no-install-project --no-editable --extra async --no-dev
+
+    # Install the project itself.
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --locked --no-editable --extra async --no-dev
+
+    # Copy the environment, but not the source code or uv itself, into the final image.
+    FROM python:3.12-slim
+    COPY --from=builder /app/.venv /app/.venv
+    ENV PATH="/app/.venv/bin:$PATH"
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "#);
+
+    // Scripts aren't supported.
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = ["iniconfig"]
+        # ///
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile-snippet").arg("--script").arg("script.py"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The `dockerfile-snippet` format is not supported for scripts
+    ");
+
+    Ok(())
+}
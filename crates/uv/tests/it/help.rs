@@ -18,6 +18,7 @@ fn help() {
     Commands:
       auth                       Manage authentication
       run                        Run a command or script
+      shell                      Spawn a shell with the project's environment activated
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
@@ -26,22 +27,34 @@ fn help() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project's environment
       format                     Format Python code in the project
+      test                       Run the project's tests
+      serve                      Run a JSON-RPC server for editor integrations
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      script                     Manage the inline metadata of Python scripts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -56,6 +69,10 @@ fn help() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -65,6 +82,11 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -100,6 +122,7 @@ fn help_flag() {
     Commands:
       auth     Manage authentication
       run      Run a command or script
+      shell    Spawn a shell with the project's environment activated
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
@@ -108,21 +131,33 @@ fn help_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      verify   Verify the integrity of the project's environment
       format   Format Python code in the project
+      test     Run the project's tests
+      serve    Run a JSON-RPC server for editor integrations
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      script   Manage the inline metadata of Python scripts
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -137,6 +172,10 @@ fn help_flag() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -146,6 +185,11 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -180,6 +224,7 @@ fn help_short_flag() {
     Commands:
       auth     Manage authentication
       run      Run a command or script
+      shell    Spawn a shell with the project's environment activated
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
@@ -188,21 +233,33 @@ fn help_short_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      verify   Verify the integrity of the project's environment
       format   Format Python code in the project
+      test     Run the project's tests
+      serve    Run a JSON-RPC server for editor integrations
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      script   Manage the inline metadata of Python scripts
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -217,6 +274,10 @@ fn help_short_flag() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -226,6 +287,11 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -324,6 +390,41 @@ fn help_subcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-partition <MODE>
+              Partition the cache by project or by index.
+              
+              With `project`, each project uses a separate cache, keyed by the path to the workspace
+              root. With `index`, each default index uses a separate cache, keyed by the URL of the
+              default index configured in `pyproject.toml` or `uv.toml` (or PyPI, if none is
+              configured).
+              
+              Partitions are stored in the `partitions` directory of the cache directory, and can be
+              mounted as separate volumes. To view the location of the partition, run `uv cache dir`.
+
+              Possible values:
+              - none:    Share a single cache across all projects and indexes
+              - project: Use a separate cache for each project, keyed by the path to the workspace root
+              - index:   Use a separate cache for each default index, keyed by the index URL
+              
+              [env: UV_CACHE_PARTITION=]
+
+          --cache-remote <URL>
+              The URL of a remote cache to fetch wheels built from source distributions from, rather
+              than building them locally.
+              
+              Accepts `http://` and `https://` URLs, along with `s3://<bucket>/<prefix>` URLs, which are
+              resolved against the endpoint in `UV_S3_ENDPOINT_URL`.
+              
+              Wheels are keyed by the SHA-256 hash of the source distribution and its build settings, so
+              only source distributions with a known hash are read from the remote cache.
+              
+              [env: UV_CACHE_REMOTE=]
+
+          --cache-remote-write
+              Upload wheels built from source distributions to the remote cache
+              
+              [env: UV_CACHE_REMOTE_WRITE=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -368,6 +469,32 @@ fn help_subcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --log-format <LOG_FORMAT>
+              The format to use for log messages.
+              
+              With `json`, each log message is written to stderr as a JSON object, including the spans
+              it was emitted in. In addition, an event is written when a span closes, recording the time
+              spent in it. Logging is enabled separately, e.g., with `--verbose` or `RUST_LOG`.
+
+              Possible values:
+              - text: Human-readable log messages
+              - json: Newline-delimited JSON log messages
+              
+              [env: UV_LOG_FORMAT=]
+
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages.
+              
+              With `json`, resolution failures are written to stderr as a JSON object, including the
+              chain of sources (e.g., a `pyproject.toml` file, a dependency group, a constraints file,
+              or another package) that introduced each of the conflicting requirements.
+
+              Possible values:
+              - text: Human-readable error messages
+              - json: JSON error messages
+              
+              [env: UV_ERROR_FORMAT=]
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -409,6 +536,27 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --progress <PROGRESS>
+              The format to use for progress output.
+              
+              With `json`, progress bars and spinners are replaced by newline-delimited JSON events on
+              stderr (e.g., when a download or build starts or completes, or as packages are prepared
+              and installed), for consumption by other tools. Ignored with `--no-progress` or `--quiet`.
+
+              Possible values:
+              - text: Interactive progress bars and spinners
+              - json: Newline-delimited JSON progress events
+              
+              [env: UV_PROGRESS=]
+
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds.
+              
+              Concurrent uv processes that modify the same environment are serialized via an advisory
+              lock on the environment. By default, uv waits indefinitely for the lock to be released.
+              
+              [env: UV_WAIT_TIMEOUT=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -580,6 +728,18 @@ fn help_subsubcommand() {
               
               If multiple Python versions are requested, uv will exit with an error.
 
+          --system-scope
+              Install Python for all users of the machine.
+              
+              Installations are stored in `/opt/uv/python` on Unix and `%PROGRAMDATA%\uv\python` on
+              Windows, unless `--install-dir` is provided. Executables are linked into
+              `/usr/local/bin` on Unix and `%PROGRAMDATA%\uv\bin` on Windows, unless
+              `UV_PYTHON_BIN_DIR` is set. On Windows, the installations are registered under
+              `HKEY_LOCAL_MACHINE` and the executable directory is added to the system `PATH`.
+              
+              Requires elevated privileges, e.g., running with `sudo` on Unix or from an
+              administrator terminal on Windows.
+
     Cache options:
       -n, --no-cache
               Avoid reading from or writing to the cache, instead using a temporary directory for the
@@ -597,6 +757,41 @@ fn help_subsubcommand() {
               
               [env: UV_CACHE_DIR=]
 
+          --cache-partition <MODE>
+              Partition the cache by project or by index.
+              
+              With `project`, each project uses a separate cache, keyed by the path to the workspace
+              root. With `index`, each default index uses a separate cache, keyed by the URL of the
+              default index configured in `pyproject.toml` or `uv.toml` (or PyPI, if none is
+              configured).
+              
+              Partitions are stored in the `partitions` directory of the cache directory, and can be
+              mounted as separate volumes. To view the location of the partition, run `uv cache dir`.
+
+              Possible values:
+              - none:    Share a single cache across all projects and indexes
+              - project: Use a separate cache for each project, keyed by the path to the workspace root
+              - index:   Use a separate cache for each default index, keyed by the index URL
+              
+              [env: UV_CACHE_PARTITION=]
+
+          --cache-remote <URL>
+              The URL of a remote cache to fetch wheels built from source distributions from, rather
+              than building them locally.
+              
+              Accepts `http://` and `https://` URLs, along with `s3://<bucket>/<prefix>` URLs, which are
+              resolved against the endpoint in `UV_S3_ENDPOINT_URL`.
+              
+              Wheels are keyed by the SHA-256 hash of the source distribution and its build settings, so
+              only source distributions with a known hash are read from the remote cache.
+              
+              [env: UV_CACHE_REMOTE=]
+
+          --cache-remote-write
+              Upload wheels built from source distributions to the remote cache
+              
+              [env: UV_CACHE_REMOTE_WRITE=]
+
     Python options:
           --managed-python
               Require use of uv-managed Python versions.
@@ -641,6 +836,32 @@ fn help_subsubcommand() {
               - always: Enables colored output regardless of the detected environment
               - never:  Disables colored output
 
+          --log-format <LOG_FORMAT>
+              The format to use for log messages.
+              
+              With `json`, each log message is written to stderr as a JSON object, including the spans
+              it was emitted in. In addition, an event is written when a span closes, recording the time
+              spent in it. Logging is enabled separately, e.g., with `--verbose` or `RUST_LOG`.
+
+              Possible values:
+              - text: Human-readable log messages
+              - json: Newline-delimited JSON log messages
+              
+              [env: UV_LOG_FORMAT=]
+
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages.
+              
+              With `json`, resolution failures are written to stderr as a JSON object, including the
+              chain of sources (e.g., a `pyproject.toml` file, a dependency group, a constraints file,
+              or another package) that introduced each of the conflicting requirements.
+
+              Possible values:
+              - text: Human-readable error messages
+              - json: JSON error messages
+              
+              [env: UV_ERROR_FORMAT=]
+
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store.
               
@@ -682,6 +903,27 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --progress <PROGRESS>
+              The format to use for progress output.
+              
+              With `json`, progress bars and spinners are replaced by newline-delimited JSON events on
+              stderr (e.g., when a download or build starts or completes, or as packages are prepared
+              and installed), for consumption by other tools. Ignored with `--no-progress` or `--quiet`.
+
+              Possible values:
+              - text: Interactive progress bars and spinners
+              - json: Newline-delimited JSON progress events
+              
+              [env: UV_PROGRESS=]
+
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds.
+              
+              Concurrent uv processes that modify the same environment are serialized via an advisory
+              lock on the environment. By default, uv waits indefinitely for the lock to be released.
+              
+              [env: UV_WAIT_TIMEOUT=]
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -754,9 +996,17 @@ fn help_flag_subcommand() {
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -771,6 +1021,10 @@ fn help_flag_subcommand() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -780,6 +1034,11 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -833,11 +1092,21 @@ fn help_flag_subsubcommand() {
               Upgrade existing Python installations to the latest patch version
           --default
               Use as the default Python version
+          --system-scope
+              Install Python for all users of the machine
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -852,6 +1121,10 @@ fn help_flag_subsubcommand() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -861,6 +1134,11 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -880,7 +1158,7 @@ fn help_flag_subsubcommand() {
 fn help_unknown_subcommand() {
     let context = TestContext::new_with_versions(&[]);
 
-    uv_snapshot!(context.filters(), context.help().arg("foobar"), @r"
+    uv_snapshot!(context.filters(), context.help().arg("foobar"), @"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -889,6 +1167,7 @@ fn help_unknown_subcommand() {
     error: There is no command `foobar` for `uv`. Did you mean one of:
         auth
         run
+        shell
         init
         add
         remove
@@ -897,6 +1176,7 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        verify
         format
         tool
         python
@@ -909,7 +1189,7 @@ fn help_unknown_subcommand() {
         generate-shell-completion
     ");
 
-    uv_snapshot!(context.filters(), context.help().arg("foo").arg("bar"), @r"
+    uv_snapshot!(context.filters(), context.help().arg("foo").arg("bar"), @"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -918,6 +1198,7 @@ fn help_unknown_subcommand() {
     error: There is no command `foo bar` for `uv`. Did you mean one of:
         auth
         run
+        shell
         init
         add
         remove
@@ -926,6 +1207,7 @@ fn help_unknown_subcommand() {
         lock
         export
         tree
+        verify
         format
         tool
         python
@@ -976,6 +1258,7 @@ fn help_with_global_option() {
     Commands:
       auth                       Manage authentication
       run                        Run a command or script
+      shell                      Spawn a shell with the project's environment activated
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
@@ -984,22 +1267,34 @@ fn help_with_global_option() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project's environment
       format                     Format Python code in the project
+      test                       Run the project's tests
+      serve                      Run a JSON-RPC server for editor integrations
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      script                     Manage the inline metadata of Python scripts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1014,6 +1309,10 @@ fn help_with_global_option() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -1023,6 +1322,11 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -1099,6 +1403,7 @@ fn help_with_no_pager() {
     Commands:
       auth                       Manage authentication
       run                        Run a command or script
+      shell                      Spawn a shell with the project's environment activated
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
@@ -1107,22 +1412,34 @@ fn help_with_no_pager() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      verify                     Verify the integrity of the project's environment
       format                     Format Python code in the project
+      test                       Run the project's tests
+      serve                      Run a JSON-RPC server for editor integrations
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      script                     Manage the inline metadata of Python scripts
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
     Cache options:
-      -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
-                                   directory for the duration of the operation [env: UV_NO_CACHE=]
-          --cache-dir [CACHE_DIR]  Path to the cache directory [env: UV_CACHE_DIR=]
+      -n, --no-cache                Avoid reading from or writing to the cache, instead using a
+                                    temporary directory for the duration of the operation [env:
+                                    UV_NO_CACHE=]
+          --cache-dir [CACHE_DIR]   Path to the cache directory [env: UV_CACHE_DIR=]
+          --cache-partition <MODE>  Partition the cache by project or by index [env:
+                                    UV_CACHE_PARTITION=] [possible values: none, project, index]
+          --cache-remote <URL>      The URL of a remote cache to fetch wheels built from source
+                                    distributions from, rather than building them locally [env:
+                                    UV_CACHE_REMOTE=]
+          --cache-remote-write      Upload wheels built from source distributions to the remote cache
+                                    [env: UV_CACHE_REMOTE_WRITE=]
 
     Python options:
           --managed-python       Require use of uv-managed Python versions [env: UV_MANAGED_PYTHON=]
@@ -1137,6 +1454,10 @@ fn help_with_no_pager() {
               Use verbose output
           --color <COLOR_CHOICE>
               Control the use of color in output [possible values: auto, always, never]
+          --log-format <LOG_FORMAT>
+              The format to use for log messages [env: UV_LOG_FORMAT=] [possible values: text, json]
+          --error-format <ERROR_FORMAT>
+              The format to use for error messages [env: UV_ERROR_FORMAT=] [possible values: text, json]
           --native-tls
               Whether to load TLS certificates from the platform's native certificate store [env:
               UV_NATIVE_TLS=]
@@ -1146,6 +1467,11 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --progress <PROGRESS>
+              The format to use for progress output [env: UV_PROGRESS=] [possible values: text, json]
+          --wait-timeout <SECONDS>
+              The maximum time to wait for another uv process to release the environment, in seconds
+              [env: UV_WAIT_TIMEOUT=]
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIRECTORY=]
           --project <PROJECT>
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Mount a paginated PEP 691 project list at `/simple/`, with the projects split across two pages.
+async fn mount_project_list(server: &MockServer, first: &[&str], second: &[&str]) {
+    let page = |projects: &[&str]| {
+        let projects = projects
+            .iter()
+            .map(|name| format!(r#"{{"name": "{name}"}}"#))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(r#"{{"meta": {{"api-version": "1.0"}}, "projects": [{projects}]}}"#)
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/simple/"))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", r#"</simple/?page=2>; rel="next""#)
+                .set_body_raw(page(first), "application/vnd.pypi.simple.v1+json"),
+        )
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/simple/"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(page(second), "application/vnd.pypi.simple.v1+json"),
+        )
+        .mount(server)
+        .await;
+}
+
+/// Sync a paginated project list, then re-sync it after the list changes.
+#[tokio::test]
+async fn index_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    mount_project_list(&server, &["anyio", "flask"], &["requests"]).await;
+
+    uv_snapshot!(context.filters(), context
+        .index_sync()
+        .arg("--preview-features")
+        .arg("index-sync")
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Synced 3 projects from http://[LOCALHOST]/simple
+    ");
+
+    // Add and remove a project.
+    server.reset().await;
+    mount_project_list(&server, &["anyio", "httpx"], &["requests", "rich"]).await;
+
+    uv_snapshot!(context.filters(), context
+        .index_sync()
+        .arg("--preview-features")
+        .arg("index-sync")
+        .arg("--default-index")
+        .arg(format!("{}/simple", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Synced 4 projects from http://[LOCALHOST]/simple (2 added, 1 removed)
+    ");
+
+    Ok(())
+}
+
+/// Suggest corrections for misspelled package names, based on the synced project list.
+#[tokio::test]
+async fn index_sync_add_suggestion() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    let server = MockServer::start().await;
+    mount_project_list(&server, &["anyio", "flask"], &["requests"]).await;
+    let index = format!("{}/simple", server.uri());
+
+    // Without a synced project list, there's no suggestion.
+    uv_snapshot!(context.filters(), context
+        .add()
+        .arg("--preview-features")
+        .arg("index-sync")
+        .arg("--frozen")
+        .arg("--default-index")
+        .arg(&index)
+        .arg("reqests"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .index_sync()
+        .arg("--preview-features")
+        .arg("index-sync")
+        .arg("--default-index")
+        .arg(&index), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Synced 3 projects from http://[LOCALHOST]/simple
+    ");
+
+    // With a synced project list, the closest match is suggested.
+    uv_snapshot!(context.filters(), context
+        .add()
+        .arg("--preview-features")
+        .arg("index-sync")
+        .arg("--frozen")
+        .arg("--default-index")
+        .arg(&index)
+        .arg("flaks"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `flaks` was not found on any of the configured indexes. Did you mean `flask`?
+    ");
+
+    Ok(())
+}
@@ -898,6 +898,79 @@ fn init_script_shebang() -> Result<()> {
     "#
     );
 
+    // With `--shebang`, a shebang that doesn't invoke uv is replaced.
+    let contents = "#! /usr/bin/env python3\nprint(\"Hello, world!\")";
+    fs_err::write(&script_path, contents)?;
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("script.py").arg("--shebang"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `script.py`
+    ");
+    let resulting_script = fs_err::read_to_string(&script_path)?;
+    assert_snapshot!(resulting_script, @r#"
+    #!/usr/bin/env -S uv run --script
+    #
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = []
+    # ///
+
+    print("Hello, world!")
+    "#
+    );
+
+    Ok(())
+}
+
+/// Create an executable script with dependencies.
+#[test]
+fn init_script_shebang_with() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("greet").arg("--shebang").arg("--with").arg("anyio>=4").arg("--with").arg("rich"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `greet`
+    ");
+
+    let script = fs_err::read_to_string(context.temp_dir.join("greet"))?;
+    assert_snapshot!(script, @r#"
+    #!/usr/bin/env -S uv run --script
+    #
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #   "anyio>=4",
+    #   "rich",
+    # ]
+    # ///
+
+
+    def main() -> None:
+        print("Hello from greet!")
+
+
+    if __name__ == "__main__":
+        main()
+    "#
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs_err::metadata(context.temp_dir.join("greet"))?
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+
     Ok(())
 }
 
@@ -1682,7 +1682,7 @@ fn lock_project_with_overrides() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 9 packages in [TIME]
+    Resolved 8 packages in [TIME]
     "###);
 
     // Re-run with `--locked`.
@@ -1799,7 +1799,7 @@ fn lock_project_with_excludes() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 8 packages in [TIME]
+    Resolved 9 packages in [TIME]
     "###);
 
     // Re-run with `--locked`.
@@ -1966,6 +1966,210 @@ fn lock_project_with_excludes() -> Result<()> {
     ----- stderr -----
     Resolved 8 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The excludes changed: added `jinja2`
+    ");
+
+    Ok(())
+}
+/// Lock a project with `uv.tool.provided-dependencies`.
+#[test]
+fn lock_project_with_provided() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "mpi4py"]
+
+        [tool.uv]
+        provided-dependencies = ["torch==2.4.0", "mpi4py"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["torch>=2.5"]
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    // The provided version of `torch` is incompatible with the requirements of `child`.
+    uv_snapshot!(context.filters(), context.lock().arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because child depends on system:torch>=2.5 and your workspace requires child, we can conclude that your workspace requires system:torch>=2.5.
+          And because your workspace requires system:torch==2.4.0, we can conclude that your workspace's requirements are unsatisfiable.
+    ");
+
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["torch>=2.0"]
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Check the lockfile contains the provided dependencies, but not the packages themselves.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        members = [
+            "child",
+            "project",
+        ]
+        provided = [
+            { name = "mpi4py" },
+            { name = "torch", specifier = "==2.4.0" },
+        ]
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { virtual = "child" }
+
+        [package.metadata]
+        requires-dist = [{ name = "torch", specifier = ">=2.0" }]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "child", virtual = "child" },
+            { name = "mpi4py" },
+        ]
+        "#
+        );
+    });
+
+    // Syncing fails if the environment doesn't provide the dependencies.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `mpi4py` is declared in `provided-dependencies`, but is not installed in the environment
+    ");
+
+    // Install the provided dependencies out-of-band.
+    let site_packages = context.site_packages();
+    for (name, version) in [("torch", "2.3.1"), ("mpi4py", "3.1.6")] {
+        let dist_info = site_packages.join(format!("{name}-{version}.dist-info"));
+        fs_err::create_dir_all(&dist_info)?;
+        fs_err::write(
+            dist_info.join("METADATA"),
+            format!("Metadata-Version: 2.1\nName: {name}\nVersion: {version}\n"),
+        )?;
+    }
+
+    // Syncing fails if the environment provides an incompatible version.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `torch==2.4.0` is declared in `provided-dependencies`, but the environment provides version 2.3.1
+    ");
+
+    let dist_info = site_packages.join("torch-2.4.0.dist-info");
+    fs_err::rename(site_packages.join("torch-2.3.1.dist-info"), &dist_info)?;
+    fs_err::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: torch\nVersion: 2.4.0\n",
+    )?;
+
+    // The provided dependencies are left untouched.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited in [TIME]
+    ");
+
+    // Modify the provided dependencies and verify that `--locked` fails.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "mpi4py"]
+
+        [tool.uv]
+        provided-dependencies = ["torch==2.4.1", "mpi4py"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The provided dependencies changed: removed `torch==2.4.0`; added `torch==2.4.1`
     ");
 
     Ok(())
@@ -6909,7 +7113,7 @@ fn lock_requires_python_maximum_version() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 2 packages in [TIME]
     "###);
 
     let lock = fs_err::read_to_string(&lockfile).unwrap();
@@ -7068,7 +7272,7 @@ fn lock_requires_python_fewest_versions() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
     "###);
 
     let lock = fs_err::read_to_string(&lockfile).unwrap();
@@ -7408,14 +7612,14 @@ fn lock_conditional_unconditional() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    ");
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -7486,14 +7690,14 @@ fn lock_multiple_markers() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    "###);
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -8264,7 +8468,7 @@ fn lock_resolution_mode() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
+    Resolved 5 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -10962,92 +11166,249 @@ fn lock_upgrade_package() -> Result<()> {
     Ok(())
 }
 
-/// Check that we discard the fork marker from the lockfile when using `--upgrade`.
-#[test]
-fn lock_upgrade_drop_fork_markers() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements = r#"[project]
-    name = "forking"
-    version = "0.1.0"
-    requires-python = ">=3.12"
-    dependencies = ["fork-upgrade-foo==1"]
-
-    [build-system]
-        requires = ["flit_core>=3.8,<4"]
-        build-backend = "flit_core.buildapi"
-    "#;
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(requirements)?;
-    context
-        .lock()
-        .arg("--index-url")
-        .arg(packse_index_url())
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .assert()
-        .success();
-    let lock = context.read("uv.lock");
-    assert!(lock.contains("resolution-markers"));
-
-    // Remove the bound and lock with `--upgrade`.
-    pyproject_toml.write_str(&requirements.replace("fork-upgrade-foo==1", "fork-upgrade-foo"))?;
-    context
-        .lock()
-        .arg("--index-url")
-        .arg(packse_index_url())
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("--upgrade")
-        .assert()
-        .success();
-    let lock = context.read("uv.lock");
-    assert!(!lock.contains("resolution-markers"));
-    Ok(())
-}
-
-/// Warn when there are missing bounds on transitive dependencies with `--resolution lowest`.
+/// Move a specific package to a target version with `--upgrade-package` and `--to`.
 #[test]
-fn lock_warn_missing_transitive_lower_bounds() -> Result<()> {
+fn lock_upgrade_package_to() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
         [project]
-        name = "foo"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["pytest>8"]
+        dependencies = ["anyio", "idna"]
+
+        [dependency-groups]
+        dev = ["anyio>=4"]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("lowest"), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
-    warning: The transitive dependency `packaging` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
-    warning: The transitive dependency `iniconfig` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
-    warning: The transitive dependency `colorama` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    Resolved 4 packages in [TIME]
     "###);
 
-    Ok(())
-}
+    // Move `anyio` to an older version, without touching the `pyproject.toml`.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio").arg("--to").arg("4.1.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-/// Lock a local wheel via `--find-links`.
-#[test]
-fn lock_find_links_local_wheel() -> Result<()> {
-    let context = TestContext::new("3.12");
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v4.3.0 -> v4.1.0
+    "###);
 
-    // Populate the `--find-links` entries.
-    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    // `--to` requires a single package.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio").arg("--upgrade-package").arg("idna").arg("--to").arg("4.2.0"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
-    for entry in fs_err::read_dir(context.workspace_root.join("scripts/links"))? {
-        let entry = entry?;
-        let path = entry.path();
-        if path
+    ----- stderr -----
+    error: `--to` requires exactly one `--upgrade-package`, but 2 packages were provided
+    "###);
+
+    // Move `anyio` to a newer version, and update the requirements to match.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("anyio").arg("--to").arg("4.2").arg("--save"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v4.1.0 -> v4.2.0
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("pyproject.toml"), @r#"
+
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4.2", "idna"]
+
+        [dependency-groups]
+        dev = ["anyio>=4.2"]
+        "#
+        );
+    });
+
+    // The lockfile is consistent with the updated requirements.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Hold back packages with a `manual` upgrade policy when using `--upgrade`.
+#[test]
+fn lock_upgrade_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Constrain `anyio` and `idna`.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<=2", "idna<=3"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Remove the constraints, but prevent `idna` from being upgraded. `anyio` was uploaded long
+    // ago, so its `weekly` policy allows it to be upgraded.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "idna"]
+
+        [tool.uv]
+        upgrade-policy = { anyio = "weekly", idna = "manual" }
+        "#,
+    )?;
+
+    // Upgrade `anyio`, but not `idna`.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v2.0.0 -> v4.3.0
+    "###);
+
+    // `idna` can still be upgraded explicitly.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated idna v3.0 -> v3.6
+    "###);
+
+    Ok(())
+}
+
+/// Check that we discard the fork marker from the lockfile when using `--upgrade`.
+#[test]
+fn lock_upgrade_drop_fork_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements = r#"[project]
+    name = "forking"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["fork-upgrade-foo==1"]
+
+    [build-system]
+        requires = ["flit_core>=3.8,<4"]
+        build-backend = "flit_core.buildapi"
+    "#;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(requirements)?;
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("resolution-markers"));
+
+    // Remove the bound and lock with `--upgrade`.
+    pyproject_toml.write_str(&requirements.replace("fork-upgrade-foo==1", "fork-upgrade-foo"))?;
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--upgrade")
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("resolution-markers"));
+    Ok(())
+}
+
+/// Warn when there are missing bounds on transitive dependencies with `--resolution lowest`.
+#[test]
+fn lock_warn_missing_transitive_lower_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest>8"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    warning: The transitive dependency `packaging` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    warning: The transitive dependency `iniconfig` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    warning: The transitive dependency `colorama` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    "###);
+
+    Ok(())
+}
+
+/// Lock a local wheel via `--find-links`.
+#[test]
+fn lock_find_links_local_wheel() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+
+    for entry in fs_err::read_dir(context.workspace_root.join("scripts/links"))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path
             .file_name()
             .and_then(|file_name| file_name.to_str())
             .is_some_and(|file_name| file_name.starts_with("tqdm-"))
@@ -13401,6 +13762,8 @@ fn check_outdated_lock() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `myproject` changed: removed `sortedcollections`
+      - The requirements of `project` changed: added `iniconfig`
     ");
 
     // Providing both `--check` and `--locked` is okay
@@ -13413,11 +13776,94 @@ fn check_outdated_lock() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `myproject` changed: removed `sortedcollections`
+      - The requirements of `project` changed: added `iniconfig`
     ");
 
     Ok(())
 }
 
+/// Checks the output of `uv lock --check --format json` when the lock is outdated
+#[test]
+fn check_outdated_lock_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // An up-to-date lockfile reports no changes.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check").arg("--format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "changes": []
+    }
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "#);
+
+    // Change the requirements and `requires-python`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2", "typing-extensions"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check").arg("--format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "changes": [
+        {
+          "input": "requires-python",
+          "old": [
+            ">=3.11"
+          ],
+          "new": [
+            ">=3.12"
+          ]
+        },
+        {
+          "input": "requirements",
+          "package": "project",
+          "old": [
+            "iniconfig"
+          ],
+          "new": [
+            "iniconfig<2",
+            "typing-extensions"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+    "#);
+
+    Ok(())
+}
+
 /// This checks that markers that normalize to 'false', which are serialized
 /// to the lockfile as `python_full_version < '0'`, get read back as false.
 /// Otherwise `uv lock --check` will always fail.
@@ -13716,7 +14162,7 @@ fn lock_remove_member() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -13831,6 +14277,7 @@ fn lock_remove_member() -> Result<()> {
     ----- stderr -----
     Resolved 5 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: removed `leaf`
     ");
 
     // Re-run without `--locked`.
@@ -13933,6 +14380,8 @@ fn lock_remove_member() -> Result<()> {
     ----- stderr -----
     Resolved 1 package in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The workspace members changed: removed `leaf`, `project`
+      - The requirements of `leaf` changed: removed `anyio>3`
     ");
 
     // Re-run without `--locked`.
@@ -14078,6 +14527,8 @@ fn lock_add_member_with_build_system() -> Result<()> {
     ----- stderr -----
     Resolved 5 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The workspace members changed: added `leaf`, `project`
+      - The requirements of `leaf` changed: added `anyio>3`
     ");
 
     // Re-run with `--offline`. This should also fail, during the resolve phase.
@@ -14286,6 +14737,8 @@ fn lock_add_member_without_build_system() -> Result<()> {
     ----- stderr -----
     Resolved 5 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The workspace members changed: added `leaf`, `project`
+      - The requirements of `leaf` changed: added `anyio>3`
     ");
 
     // Re-run with `--offline`. This should also fail, during the resolve phase.
@@ -14422,6 +14875,7 @@ fn lock_add_member_without_build_system() -> Result<()> {
     ----- stderr -----
     Resolved 5 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: added `leaf`
     ");
 
     // Re-run without `--locked`.
@@ -14648,6 +15102,7 @@ fn lock_redundant_add_member() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: added `idna`
     ");
 
     // Re-run without `--locked`.
@@ -14846,6 +15301,7 @@ fn lock_new_constraints() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The constraints changed: added `anyio<4.3`
     ");
 
     // Re-run without `--locked`.
@@ -14961,7 +15417,7 @@ fn lock_remove_member_non_project() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
+    Resolved 5 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -15056,6 +15512,8 @@ fn lock_remove_member_non_project() -> Result<()> {
     warning: No `requires-python` value found in the workspace. Defaulting to `>=3.12`.
     Resolved in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The workspace members changed: removed `leaf`
+      - The requirements of `leaf` changed: removed `anyio>3`
     ");
 
     // Re-run without `--locked`.
@@ -15187,6 +15645,8 @@ fn lock_rename_project() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: removed `iniconfig`
+      - The requirements of `renamed` changed: added `iniconfig`
     ");
 
     // Re-run without `--locked`.
@@ -15546,14 +16006,14 @@ fn lock_reorder() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
-    "###);
+    Resolved 4 packages in [TIME]
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -15907,14 +16367,14 @@ fn lock_exclude_unnecessary_python_forks() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
-    ");
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -16189,6 +16649,7 @@ fn lock_constrained_environment() -> Result<()> {
     ----- stderr -----
     Resolved 8 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The supported environments changed: removed `platform_system != 'Windows'`
     ");
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
@@ -17460,6 +17921,7 @@ fn lock_add_empty_dependency_group() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The dependency groups of `project` changed: added `empty`
     ");
 
     // Re-lock the project.
@@ -17542,6 +18004,7 @@ fn lock_add_empty_dependency_group() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The dependency groups of `project` changed: removed `empty`
     ");
 
     // Re-lock the project.
@@ -17623,14 +18086,14 @@ fn lock_trailing_slash_index_url() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
-    "###);
+    Resolved 6 packages in [TIME]
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -17907,14 +18370,14 @@ fn lock_explicit_default_index() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    ");
+    "###);
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
 
@@ -18763,14 +19226,14 @@ fn lock_named_index_overlap() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    "###);
+    ");
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
 
@@ -19305,7 +19768,7 @@ fn lock_implicit_package_path() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
+    Resolved 3 packages in [TIME]
     ");
 
     let lock = context.read("uv.lock");
@@ -20082,7 +20545,7 @@ fn lock_dependency_metadata() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 5 packages in [TIME]
     ");
 
     let lock = context.read("uv.lock");
@@ -20294,6 +20757,80 @@ fn lock_dependency_metadata() -> Result<()> {
     Ok(())
 }
 
+/// Patch individual requirements of a package, rather than replacing its metadata.
+#[test]
+fn lock_dependency_metadata_patch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        versions = ">=3,<4"
+        add-requires-dist = ["iniconfig"]
+        remove-requires-dist = ["sniffio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── anyio v3.7.0
+        ├── idna v3.6
+        └── iniconfig v2.0.0
+
+    ----- stderr -----
+    ");
+
+    // Removing a requirement that the package doesn't declare should warn.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        versions = ">=3,<4"
+        remove-requires-dist = ["trio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The `dependency-metadata` entry for `anyio` removes `trio`, but `anyio==3.7.0` does not depend on `trio`; the entry may be outdated
+    Resolved 4 packages in [TIME]
+    Removed iniconfig v2.0.0
+    Added sniffio v1.3.1
+    ");
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "git")]
 fn lock_dependency_metadata_git() -> Result<()> {
@@ -20831,14 +21368,14 @@ fn lock_change_requires_python() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
-    ");
+    Resolved 4 packages in [TIME]
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -21595,7 +22132,7 @@ fn lock_multiple_sources_index_disjoint_markers() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
+    Resolved 3 packages in [TIME]
     "###);
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -21830,6 +22367,109 @@ fn lock_multiple_sources_index_mixed() -> Result<()> {
     Ok(())
 }
 
+/// Fall back to a URL source in the forks where the index can't satisfy the requirement.
+#[test]
+fn lock_fallback_source() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2022-01-01T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig<2 ; sys_platform != 'linux'",
+            "iniconfig>=2 ; sys_platform == 'linux'",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", fallback = true }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'linux'",
+            "sys_platform != 'linux'",
+        ]
+
+        [options]
+        exclude-newer = "2022-01-01T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "1.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform != 'linux'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/23/a2/97899f6bd0e873fed3a7e67ae8d3a08b21799430fb4da15cfedf10d6e2c2/iniconfig-1.1.1.tar.gz", hash = "sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32", size = 8104, upload-time = "2020-10-14T10:20:18.572Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl", hash = "sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3", size = 4990, upload-time = "2020-10-16T17:37:23.05Z" },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        resolution-markers = [
+            "sys_platform == 'linux'",
+        ]
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig", version = "1.1.1", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform != 'linux'" },
+            { name = "iniconfig", version = "2.0.0", source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }, marker = "sys_platform == 'linux'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", marker = "sys_platform != 'linux'", specifier = "<2" },
+            { name = "iniconfig", marker = "sys_platform == 'linux'", specifier = ">=2" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn lock_multiple_sources_index_non_total() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
@@ -21860,7 +22500,7 @@ fn lock_multiple_sources_index_non_total() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 4 packages in [TIME]
     "###);
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -22107,7 +22747,7 @@ fn lock_multiple_sources_non_total() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
+    Resolved 2 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -22262,7 +22902,7 @@ fn lock_multiple_sources_respect_marker() -> Result<()> {
 }
 
 #[test]
-fn lock_multiple_sources_extra() -> Result<()> {
+fn lock_marker_provenance() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -22272,15 +22912,10 @@ fn lock_multiple_sources_extra() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["iniconfig"]
-
-        [project.optional-dependencies]
-        cpu = []
+        dependencies = ["iniconfig ; sys_platform == 'win32'"]
 
-        [tool.uv.sources]
-        iniconfig = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", marker = "extra == 'cpu'" },
-        ]
+        [tool.uv]
+        marker-provenance = true
         "#,
     )?;
 
@@ -22305,32 +22940,31 @@ fn lock_multiple_sources_extra() -> Result<()> {
         requires-python = ">=3.12"
 
         [options]
+        marker-provenance = true
         exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
         name = "iniconfig"
         version = "2.0.0"
-        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
         ]
 
+        [package.marker-provenance]
+        marker = "sys_platform == 'win32'"
+
         [[package]]
         name = "project"
         version = "0.1.0"
         source = { virtual = "." }
-
-        [package.optional-dependencies]
-        cpu = [
-            { name = "iniconfig" },
+        dependencies = [
+            { name = "iniconfig", marker = "sys_platform == 'win32'" },
         ]
 
         [package.metadata]
-        requires-dist = [
-            { name = "iniconfig", marker = "extra == 'cpu'", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
-            { name = "iniconfig", marker = "extra != 'cpu'" },
-        ]
-        provides-extras = ["cpu"]
+        requires-dist = [{ name = "iniconfig", marker = "sys_platform == 'win32'" }]
         "#
         );
     });
@@ -22345,12 +22979,25 @@ fn lock_multiple_sources_extra() -> Result<()> {
     Resolved 2 packages in [TIME]
     "###);
 
+    // Explain the environments in which `iniconfig` is installed.
+    uv_snapshot!(context.filters(), context.lock().arg("--explain").arg("iniconfig").arg("--preview-features").arg("lock-explain"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+      Installed when: sys_platform == 'win32'
+      Required by:
+        - project==0.1.0 ; sys_platform == 'win32'
+
+    ----- stderr -----
+    "###);
+
     Ok(())
 }
 
 #[test]
-fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
-    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+fn lock_multiple_sources_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -22359,50 +23006,137 @@ fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = []
+        dependencies = ["iniconfig"]
 
         [project.optional-dependencies]
-        cu118 = ["jinja2==3.1.2"]
-        cu124 = ["jinja2==3.1.3"]
-
-        [tool.uv]
-        constraint-dependencies = ["markupsafe<3"]
+        cpu = []
 
         [tool.uv.sources]
-        jinja2 = [
-            { index = "torch-cu118", extra = "cu118" },
-            { index = "torch-cu124", extra = "cu124" },
+        iniconfig = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", marker = "extra == 'cpu'" },
         ]
-
-        [[tool.uv.index]]
-        name = "torch-cu118"
-        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
-        explicit = true
-
-        [[tool.uv.index]]
-        name = "torch-cu124"
-        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
-        explicit = true
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
-    success: false
-    exit_code: 1
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to resolve dependencies for `project` (v0.1.0)
-      ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
-          - https://astral-sh.github.io/pytorch-mirror/whl/cu118
-          - https://astral-sh.github.io/pytorch-mirror/whl/cu124
-    ");
+    Resolved 3 packages in [TIME]
+    "###);
 
-    Ok(())
-}
+    let lock = context.read("uv.lock");
 
-/// Sources will be ignored when an `extra` is applied, but references a non-existent extra.
-#[test]
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.optional-dependencies]
+        cpu = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", marker = "extra == 'cpu'", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" },
+            { name = "iniconfig", marker = "extra != 'cpu'" },
+        ]
+        provides-extras = ["cpu"]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cu118 = ["jinja2==3.1.2"]
+        cu124 = ["jinja2==3.1.3"]
+
+        [tool.uv]
+        constraint-dependencies = ["markupsafe<3"]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", extra = "cu118" },
+            { index = "torch-cu124", extra = "cu124" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "torch-cu124"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu124"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `project` (v0.1.0)
+      ╰─▶ Requirements contain conflicting indexes for package `jinja2` in all marker environments:
+          - https://astral-sh.github.io/pytorch-mirror/whl/cu118
+          - https://astral-sh.github.io/pytorch-mirror/whl/cu124
+    ");
+
+    Ok(())
+}
+
+/// Sources will be ignored when an `extra` is applied, but references a non-existent extra.
+#[test]
 fn lock_multiple_index_with_missing_extra() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
 
@@ -22720,7 +23454,7 @@ fn lock_group_include() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 11 packages in [TIME]
+    Resolved 5 packages in [TIME]
     ");
 
     let lock = context.read("uv.lock");
@@ -23245,7 +23979,7 @@ fn lock_group_includes_requires_python_contradiction() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    Resolved 11 packages in [TIME]
     ");
 
     let lock = context.read("uv.lock");
@@ -24175,7 +24909,7 @@ fn lock_dynamic_version_dependencies() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
+    Resolved 5 packages in [TIME]
     ");
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -24883,7 +25617,7 @@ fn lock_dynamic_version_self_extra_setuptools() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 5 packages in [TIME]
+    Resolved 1 package in [TIME]
     ");
 
     let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock")).unwrap();
@@ -25804,7 +26538,7 @@ fn lock_derivation_chain_prod() -> Result<()> {
           SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
 
           hint: This usually indicates a problem with the package or the build environment.
-      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `wsgiref==0.1.2`
+      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `child` (v0.1.0) which depends on `wsgiref>=0.1, <0.2`
     "#);
 
     Ok(())
@@ -25861,7 +26595,7 @@ fn lock_derivation_chain_extra() -> Result<()> {
           SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
 
           hint: This usually indicates a problem with the package or the build environment.
-      help: `wsgiref` (v0.1.2) was included because `project[wsgi]` (v0.1.0) depends on `wsgiref>=0.1`
+      help: `wsgiref` (v0.1.2) was included because `project:wsgi` (v0.1.0) depends on `wsgiref`
     "#);
 
     Ok(())
@@ -25920,7 +26654,7 @@ fn lock_derivation_chain_group() -> Result<()> {
           SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
 
           hint: This usually indicates a problem with the package or the build environment.
-      help: `wsgiref` (v0.1.2) was included because `project:wsgi` (v0.1.0) depends on `wsgiref`
+      help: `wsgiref` (v0.1.2) was included because `project[wsgi]` (v0.1.0) depends on `wsgiref>=0.1`
     "#);
 
     Ok(())
@@ -25990,7 +26724,7 @@ fn lock_derivation_chain_extended() -> Result<()> {
           SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
 
           hint: This usually indicates a problem with the package or the build environment.
-      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `child` (v0.1.0) which depends on `wsgiref>=0.1, <0.2`
+      help: `wsgiref` (v0.1.2) was included because `project` (v0.1.0) depends on `wsgiref==0.1.2`
     "#);
 
     Ok(())
@@ -26627,7 +27361,7 @@ fn lock_self_incompatible() -> Result<()> {
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because your project depends on itself at an incompatible version (project==0.2.0), we can conclude that your project's requirements are unsatisfiable.
+      ╰─▶ Because your project depends on itself at an incompatible version (project{sys_platform == 'win32'}>0.1), we can conclude that your project's requirements are unsatisfiable.
 
           hint: The project `project` depends on itself at an incompatible version. This is likely a mistake. If you intended to depend on a third-party package named `project`, consider renaming the project `project` to avoid creating a conflict.
     ");
@@ -27065,7 +27799,7 @@ fn lock_self_marker_incompatible() -> Result<()> {
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because your project depends on itself at an incompatible version (project{sys_platform == 'win32'}>0.1), we can conclude that your project's requirements are unsatisfiable.
+      ╰─▶ Because your project depends on itself at an incompatible version (project==0.2.0), we can conclude that your project's requirements are unsatisfiable.
 
           hint: The project `project` depends on itself at an incompatible version. This is likely a mistake. If you intended to depend on a third-party package named `project`, consider renaming the project `project` to avoid creating a conflict.
     ");
@@ -27217,14 +27951,14 @@ fn lock_arm() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    ");
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -27368,14 +28102,14 @@ fn lock_x86() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    "###);
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -27533,6 +28267,7 @@ fn lock_script() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of the project changed: added `iniconfig`
     ");
 
     Ok(())
@@ -28454,14 +29189,14 @@ fn lock_pytorch_index_preferences() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 25 packages in [TIME]
-    "###);
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -28921,14 +29656,14 @@ fn lock_intel_mac() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 25 packages in [TIME]
-    ");
+    "###);
 
     let lock = context.read("uv.lock");
 
@@ -29935,6 +30670,8 @@ fn lock_empty_extra() -> Result<()> {
     ----- stderr -----
     Resolved 3 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: added `typing-extensions ; extra == 'foo'`
+      - The extras of `project` changed: added `foo`
     ");
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
@@ -29971,6 +30708,7 @@ fn lock_empty_extra() -> Result<()> {
     ----- stderr -----
     Resolved 3 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The extras of `project` changed: added `bar`
     ");
 
     uv_snapshot!(context.filters(), context.lock(), @r###"
@@ -30710,6 +31448,7 @@ fn lock_trailing_slash_index_url_in_lockfile_not_pyproject() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The sources of `project` changed: removed `anyio @ https://pypi-proxy.fly.dev/simple/`; added `anyio @ https://pypi-proxy.fly.dev/simple`
     ");
 
     Ok(())
@@ -30801,6 +31540,7 @@ fn lock_trailing_slash_index_url_in_pyproject_and_not_lockfile() -> Result<()> {
     ----- stderr -----
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The sources of `project` changed: removed `anyio @ https://pypi-proxy.fly.dev/simple`; added `anyio @ https://pypi-proxy.fly.dev/simple/`
     ");
 
     Ok(())
@@ -32243,7 +32983,7 @@ fn lock_unsupported_wheel_url_requires_python() -> Result<()> {
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because only numpy==2.3.5 is available and numpy==2.3.5 has no wheels with a matching Python version tag (e.g., `cp312`), we can conclude that all versions of numpy cannot be used.
+      ╰─▶ Because only numpy==2.3.5 is available and numpy==2.3.5 has no Windows-compatible wheels, we can conclude that all versions of numpy cannot be used.
           And because your project depends on numpy, we can conclude that your project's requirements are unsatisfiable.
     ");
 
@@ -32275,9 +33015,527 @@ fn lock_unsupported_wheel_url_required_platform() -> Result<()> {
 
     ----- stderr -----
       × No solution found when resolving dependencies:
-      ╰─▶ Because only numpy==2.3.5 is available and numpy==2.3.5 has no Windows-compatible wheels, we can conclude that all versions of numpy cannot be used.
+      ╰─▶ Because only numpy==2.3.5 is available and numpy==2.3.5 has no wheels with a matching Python version tag (e.g., `cp312`), we can conclude that all versions of numpy cannot be used.
           And because your project depends on numpy, we can conclude that your project's requirements are unsatisfiable.
     ");
 
     Ok(())
 }
+
+/// Explain the changes between two lockfiles with `--audit`.
+#[test]
+fn lock_audit() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let old = context.temp_dir.child("old.lock");
+    old.write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+            { name = "six" },
+        ]
+
+        [package.dev-dependencies]
+        dev = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio", specifier = ">=3" },
+            { name = "six" },
+        ]
+
+        [package.metadata.requires-dev]
+        dev = [{ name = "iniconfig" }]
+
+        [[package]]
+        name = "six"
+        version = "1.16.0"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+    "#})?;
+
+    let new = context.temp_dir.child("new.lock");
+    new.write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+            { name = "typing-extensions" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+            { name = "six" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio", specifier = ">=4" },
+            { name = "six" },
+        ]
+
+        [[package]]
+        name = "six"
+        version = "1.15.0"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi.org/simple" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--audit")
+        .arg("old.lock")
+        .arg("new.lock")
+        .arg("--preview-features")
+        .arg("lock-audit"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated anyio v3.7.0 -> v4.3.0
+      - changed `anyio>=3` to `anyio>=4` in the dependencies of `project`
+    Removed iniconfig v2.0.0
+      - removed `iniconfig` from the `dev` dependency group of `project`
+      - no longer required by `project`
+    Updated six v1.16.0 -> v1.15.0
+      - the previous version is no longer available (e.g., it was yanked)
+    Added typing-extensions v4.10.0
+      - new dependency of `anyio` v4.3.0
+
+    ----- stderr -----
+    ");
+
+    // Comparing a lockfile to itself reports no changes.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--audit")
+        .arg("new.lock")
+        .arg("new.lock")
+        .arg("--preview-features")
+        .arg("lock-audit"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No lockfile changes detected
+    ");
+
+    Ok(())
+}
+
+/// Restrict packages to an index by name pattern via `packages` on `[[tool.uv.index]]`.
+#[test]
+fn lock_index_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate a "public" index with `ok==2.0.0` and an "internal" index with `ok==1.0.0`.
+    for (index, filename) in [
+        ("public", "ok-2.0.0-py3-none-any.whl"),
+        ("internal", "ok-1.0.0-py3-none-any.whl"),
+    ] {
+        fs_err::create_dir_all(context.temp_dir.join(index))?;
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(filename),
+            context.temp_dir.join(index).join(filename),
+        )?;
+    }
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        format = "flat"
+        url = "./internal"
+        explicit = true
+        packages = ["O?"]
+
+        [[tool.uv.index]]
+        name = "public"
+        format = "flat"
+        url = "./public"
+        default = true
+        "#,
+    )?;
+
+    // `ok` matches the pattern, so it should only be resolved from the internal index.
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "internal" }
+        wheels = [
+            { path = "ok-1.0.0-py3-none-any.whl" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "ok" }]
+        "#
+        );
+    });
+
+    // Without the pattern, `ok` is resolved from the public index.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        format = "flat"
+        url = "./internal"
+        explicit = true
+
+        [[tool.uv.index]]
+        name = "public"
+        format = "flat"
+        url = "./public"
+        default = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("ok"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Updated ok v1.0.0 -> v2.0.0
+    ");
+
+    // Restoring the pattern should invalidate the lockfile, since `ok` was locked from the public
+    // index.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "internal"
+        format = "flat"
+        url = "./internal"
+        explicit = true
+        packages = ["o?"]
+
+        [[tool.uv.index]]
+        name = "public"
+        format = "flat"
+        url = "./public"
+        default = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
+/// With `--index-strategy merge-latest-compatible`, a version that exists on multiple indexes is
+/// taken from the index with the most compatible wheels, rather than the first index.
+#[test]
+fn lock_index_strategy_merge_latest_compatible() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Populate a "mirror" index that only carries a Windows wheel for `ok==1.0.0`, and an
+    // "upstream" index that carries both the Windows wheel and a pure-Python wheel.
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/ok-1.0.0-py3-none-any.whl");
+    for (index, filenames) in [
+        ("mirror", &["ok-1.0.0-cp312-cp312-win_amd64.whl"][..]),
+        (
+            "upstream",
+            &[
+                "ok-1.0.0-cp312-cp312-win_amd64.whl",
+                "ok-1.0.0-py3-none-any.whl",
+            ][..],
+        ),
+    ] {
+        fs_err::create_dir_all(context.temp_dir.join(index))?;
+        for filename in filenames {
+            fs_err::copy(&wheel, context.temp_dir.join(index).join(filename))?;
+        }
+    }
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "mirror"
+        format = "flat"
+        url = "./mirror"
+
+        [[tool.uv.index]]
+        name = "upstream"
+        format = "flat"
+        url = "./upstream"
+        default = true
+        "#,
+    )?;
+
+    // With `unsafe-best-match`, the entry from the first index is used.
+    uv_snapshot!(context.filters(), context.lock().arg("--index-strategy").arg("unsafe-best-match"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "mirror" }
+        wheels = [
+            { path = "ok-1.0.0-cp312-cp312-win_amd64.whl" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "ok" }]
+        "#
+        );
+    });
+
+    // With `merge-latest-compatible`, the entry from the index with the most compatible wheels is
+    // used.
+    uv_snapshot!(context.filters(), context.lock().arg("--index-strategy").arg("merge-latest-compatible").arg("--upgrade"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "upstream" }
+        wheels = [
+            { path = "ok-1.0.0-py3-none-any.whl" },
+            { path = "ok-1.0.0-cp312-cp312-win_amd64.whl" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "ok" }]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Write the locked resolution as a constraints file with `--emit-constraints`.
+#[test]
+fn lock_emit_constraints() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [dependency-groups]
+        dev = ["typing-extensions"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--emit-constraints").arg("constraints.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Wrote constraints to: constraints.txt
+    ");
+
+    // The constraints include all extras and groups, but not the project itself.
+    let constraints = context.read("constraints.txt");
+    assert_snapshot!(constraints, @r"
+    # This file was autogenerated by uv from the lockfile, and should not be edited.
+    anyio==3.7.0
+    idna==3.6
+    iniconfig==2.0.0
+    sniffio==1.3.1
+    typing-extensions==4.10.0
+    ");
+
+    // The constraints can also be written from an existing lockfile.
+    fs_err::remove_file(context.temp_dir.join("constraints.txt"))?;
+    uv_snapshot!(context.filters(), context.lock().arg("--frozen").arg("--emit-constraints").arg("constraints.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote constraints to: constraints.txt
+    ");
+    assert_eq!(context.read("constraints.txt"), constraints);
+
+    Ok(())
+}
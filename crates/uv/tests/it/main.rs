@@ -13,15 +13,24 @@ mod build;
 #[cfg(feature = "python")]
 mod build_backend;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_info;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_size;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -36,6 +45,9 @@ mod format;
 
 mod help;
 
+#[cfg(feature = "python")]
+mod index;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
@@ -104,12 +116,18 @@ mod run;
 #[cfg(feature = "self-update")]
 mod self_update;
 
+#[cfg(all(feature = "python", unix))]
+mod shell;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod show_settings;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod sync;
 
+#[cfg(feature = "python")]
+mod test_command;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
@@ -119,6 +137,9 @@ mod tool_install;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_list;
 
+#[cfg(feature = "python")]
+mod tool_lock;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
@@ -134,6 +155,9 @@ mod tree;
 #[cfg(feature = "python")]
 mod venv;
 
+#[cfg(feature = "python")]
+mod verify;
+
 mod version;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -2441,6 +2441,54 @@ fn compile_git_subdirectory_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Resolve two packages from different subdirectories of the same Git repository, using sparse
+/// checkouts.
+#[test]
+#[cfg(feature = "git")]
+fn compile_git_sparse_checkout() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in
+        .write_str("example-pkg-a @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_a\nexample-pkg-b @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_b")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .env(EnvVars::UV_GIT_SPARSE_CHECKOUT, "1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    example-pkg-a @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_a
+        # via -r requirements.in
+    example-pkg-b @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_b
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    // Each checkout should only contain its own subdirectory.
+    let checkouts = fs_err::read_dir(context.cache_dir.join("git-v0").join("checkouts"))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    for repository in checkouts {
+        for checkout in fs_err::read_dir(repository)? {
+            let checkout = checkout?.path();
+            let pkg_a = checkout.join("pkg_resources").join("pkg_a").is_dir();
+            let pkg_b = checkout.join("pkg_resources").join("pkg_b").is_dir();
+            assert!(
+                pkg_a != pkg_b,
+                "{} contains both subdirectories",
+                checkout.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Resolve two packages from a `requirements.in` file with the same Git HTTPS dependency.
 #[test]
 #[cfg(feature = "git")]
@@ -3944,6 +3992,136 @@ fn compile_yanked_version_direct() -> Result<()> {
     Ok(())
 }
 
+/// Reject a directly-pinned yanked version of `attrs` under `--yanked-policy error`, unless it's
+/// included in `allow-yanked`.
+#[test]
+fn compile_yanked_version_policy_error() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("attrs==21.1.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--yanked-policy")
+            .arg("error"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because attrs==21.1.0 was yanked (reason: Installable but not importable on Python 3.4) and you require attrs==21.1.0, we can conclude that your requirements are unsatisfiable.
+    "
+    );
+
+    // Allow the yanked version explicitly; no warning is shown.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        yanked-policy = "error"
+        allow-yanked = ["attrs==21.1.0"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    attrs==21.1.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Avoid selecting versions that are declared as incompatible in `known-incompatibilities`.
+#[test]
+fn compile_known_incompatibilities() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [pip]
+        known-incompatibilities = [
+            { package = "anyio==3.7.*", incompatible-with = "idna>=3.5", reason = "anyio 3.7 is broken by idna 3.5" },
+        ]
+    "#})?;
+
+    // The latest compatible version of `idna` is selected instead.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.4
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Abort the resolution once it exceeds `--resolution-timeout`.
+#[test]
+fn compile_resolution_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolution-timeout")
+            .arg("0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution aborted after exceeding the timeout of 0s
+    "
+    );
+
+    // Without a timeout, the resolution succeeds.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .env(EnvVars::UV_MAX_BACKTRACKS, "100"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    anyio==3.7.0
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Fail to resolve `attrs` due to the indirect use of a yanked version (`21.1.0`).
 #[test]
 fn compile_yanked_version_indirect() -> Result<()> {
@@ -8102,6 +8280,95 @@ fn universal_multi_version() -> Result<()> {
     Ok(())
 }
 
+/// Resolve for multiple Python versions at once, with `--python-versions`.
+#[test]
+fn python_versions() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==2.0.0 ; python_version > '3.12'
+        iniconfig==1.0.0 ; python_version == '3.12'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-versions")
+            .arg("3.12,3.13"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-versions 3.12,3.13
+    iniconfig==1.0.0 ; python_full_version < '3.13'
+        # via -r requirements.in
+    iniconfig==2.0.0 ; python_full_version >= '3.13'
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Write a separate output file for each Python version, with `--split-python-versions`.
+#[test]
+fn python_versions_split() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        iniconfig==2.0.0 ; python_version > '3.12'
+        iniconfig==1.0.0 ; python_version == '3.12'
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-versions")
+            .arg("3.12,3.13")
+            .arg("--split-python-versions")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--no-header")
+            .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    insta::assert_snapshot!(context.read("requirements-py3.12.txt"), @r###"
+    iniconfig==1.0.0
+        # via -r requirements.in
+    "###
+    );
+
+    insta::assert_snapshot!(context.read("requirements-py3.13.txt"), @r###"
+    iniconfig==2.0.0
+        # via -r requirements.in
+    "###
+    );
+
+    // An output file is required.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-versions")
+            .arg("3.12,3.13")
+            .arg("--split-python-versions"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--split-python-versions` requires an output file (e.g., `--output-file requirements.txt`)
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn universal_platform_fork() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
@@ -10101,6 +10368,69 @@ fn compile_constraints_incompatible_version() -> Result<()> {
     Ok(())
 }
 
+/// Report the sources of the conflicting requirements with `--error-format json`.
+#[test]
+fn compile_constraints_incompatible_version_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("# Pinned for compatibility.\nfilelock==1.0.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("filelock==3.8.0")?;
+
+    let output = context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--constraint")
+        .arg("constraints.txt")
+        .arg("--error-format")
+        .arg("json")
+        .output()?;
+    assert!(!output.status.success());
+
+    let diagnostic: serde_json::Value = serde_json::from_slice(&output.stderr)?;
+    assert_eq!(diagnostic["kind"], "no-solution");
+    assert_eq!(
+        diagnostic["message"],
+        "No solution found when resolving dependencies:"
+    );
+
+    // Identify each source by its kind, requirement, file name, and line.
+    let sources = diagnostic["provenance"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|provenance| provenance["package"] == "filelock")
+        .flat_map(|provenance| provenance["chain"].as_array().unwrap())
+        .map(|step| {
+            (
+                step["kind"].as_str().unwrap().to_string(),
+                step["requirement"].as_str().unwrap().to_string(),
+                std::path::Path::new(step["path"].as_str().unwrap())
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string(),
+                step["line"].as_u64().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert!(sources.contains(&(
+        "requirement".to_string(),
+        "filelock==1.0.0".to_string(),
+        "requirements.in".to_string(),
+        2
+    )));
+    assert!(sources.contains(&(
+        "constraint".to_string(),
+        "filelock==3.8.0".to_string(),
+        "constraints.txt".to_string(),
+        1
+    )));
+
+    Ok(())
+}
+
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file pinning one of
 /// its direct dependencies to an incompatible version.
 #[test]
@@ -10317,7 +10647,7 @@ fn unsafe_package() -> Result<()> {
         # via flask
 
     # The following packages were excluded from the output:
-    # jinja2
+    # jinja2==3.1.3
 
     ----- stderr -----
     Resolved 7 packages in [TIME]
@@ -10327,6 +10657,96 @@ fn unsafe_package() -> Result<()> {
     Ok(())
 }
 
+/// Retain the pin of an excluded package from an existing output file.
+#[test]
+fn unsafe_package_retain_pin() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask")?;
+
+    // The pin for `jinja2` is only present in the list of excluded packages.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        flask==3.0.2
+
+        # The following packages were excluded from the output:
+        # jinja2==3.1.2
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--unsafe-package")
+            .arg("jinja2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --unsafe-package jinja2
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    # The following packages were excluded from the output:
+    # jinja2==3.1.2
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    // The pin is ignored when upgrading.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--unsafe-package")
+            .arg("jinja2")
+            .arg("--upgrade-package")
+            .arg("jinja2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --unsafe-package jinja2
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    # The following packages were excluded from the output:
+    # jinja2==3.1.3
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve a package with a strict upper bound, allowing pre-releases. Per PEP 440, pre-releases
 /// that match the bound (e.g., `2.0.0rc1`) should be _not_ allowed.
 #[test]
@@ -17461,7 +17881,7 @@ fn pep_751_compile_no_emit_package() -> Result<()> {
     wheels = [{ url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", upload-time = 2024-02-25T23:20:01Z, size = 10235, hashes = { sha256 = "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2" } }]
 
     # The following packages were excluded from the output:
-    # idna
+    # idna==3.6
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
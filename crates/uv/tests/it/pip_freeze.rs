@@ -431,6 +431,87 @@ fn freeze_multiple_paths() -> Result<()> {
     Ok(())
 }
 
+/// Warn when a package is installed with different versions across multiple paths.
+#[test]
+fn freeze_multiple_paths_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt1 = context.temp_dir.child("requirements1.txt");
+    requirements_txt1.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    let requirements_txt2 = context.temp_dir.child("requirements2.txt");
+    requirements_txt2.write_str("MarkupSafe==2.1.2")?;
+
+    let target1 = context.temp_dir.child("install-path1");
+    let target2 = context.temp_dir.child("install-path2");
+
+    // Run `pip sync`.
+    for (target, requirements_txt) in [
+        (target1.path(), requirements_txt1),
+        (target2.path(), requirements_txt2),
+    ] {
+        context
+            .pip_sync()
+            .arg(requirements_txt.path())
+            .arg("--target")
+            .arg(target)
+            .assert()
+            .success();
+    }
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), context.pip_freeze().arg("--path").arg(target1.path()).arg("--path").arg(target2.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.2
+    markupsafe==2.1.3
+    tomli==2.0.1
+
+    ----- stderr -----
+    warning: `markupsafe` is installed with conflicting versions across paths: `markupsafe==2.1.2` (in install-path2), `markupsafe==2.1.3` (in install-path1)
+    ");
+
+    Ok(())
+}
+
+/// List the packages in an installation path in JSON format.
+#[test]
+#[cfg(unix)]
+fn freeze_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    let target = context.temp_dir.child("install-path");
+
+    // Run `pip sync`.
+    context
+        .pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--target")
+        .arg(target.path())
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), context.pip_freeze()
+        .arg("--path")
+        .arg(target.path())
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"markupsafe","version":"2.1.3","editable":false,"location":"[TEMP_DIR]/install-path","installer":"uv"},{"name":"tomli","version":"2.0.1","editable":false,"location":"[TEMP_DIR]/install-path","installer":"uv"}]
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
 // We follow pip in just ignoring nonexistent paths
 #[test]
 fn freeze_nonexistent_path() {
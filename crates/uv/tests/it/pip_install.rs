@@ -4064,6 +4064,156 @@ fn launcher_with_symlink() -> Result<()> {
     Ok(())
 }
 
+/// Generate script launchers that symlink to the `.dist-info` directory.
+#[test]
+#[cfg(unix)]
+fn launcher_script_launcher_symlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let project_root = fs_err::canonicalize(std::env::current_dir()?.join("../.."))?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        script-launcher = "symlink"
+    "#})?;
+
+    let filters = [
+        (r"(\d+m )?(\d+\.)?\d+(ms|s)", "[TIME]"),
+        (
+            r"simple-launcher==0\.1\.0 \(from .+\.whl\)",
+            "simple_launcher.whl",
+        ),
+    ];
+
+    uv_snapshot!(filters,
+        context.pip_install()
+            .arg(format!("simple_launcher@{}", project_root.join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl").display()))
+            .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple_launcher.whl
+    "
+    );
+
+    // The launcher should be a relative symlink into the `.dist-info` directory.
+    let launcher = context.venv.join("bin").join("simple_launcher");
+    let target = fs_err::read_link(&launcher)?;
+    assert!(target.is_relative());
+    assert!(target.ends_with("simple_launcher-0.1.0.dist-info/scripts/simple_launcher"));
+
+    uv_snapshot!(Command::new(&launcher), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hi from the simple launcher!
+
+    ----- stderr -----
+    ");
+
+    // Uninstalling the package should remove the symlink.
+    context
+        .pip_uninstall()
+        .arg("simple_launcher")
+        .assert()
+        .success();
+    assert!(fs_err::symlink_metadata(&launcher).is_err());
+
+    Ok(())
+}
+
+/// Generate script launchers as POSIX shell scripts, configured per platform.
+#[test]
+#[cfg(unix)]
+fn launcher_script_launcher_shell() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let project_root = fs_err::canonicalize(std::env::current_dir()?.join("../.."))?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        script-launcher = { linux = "shell", macos = "shell", windows = "native" }
+    "#})?;
+
+    context
+        .pip_install()
+        .arg(format!(
+            "simple_launcher@{}",
+            project_root
+                .join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl")
+                .display()
+        ))
+        .assert()
+        .success();
+
+    let launcher = context.venv.join("bin").join("simple_launcher");
+    let contents = fs_err::read_to_string(&launcher)?;
+    assert!(contents.starts_with("#!/bin/sh\n'''exec' "));
+
+    uv_snapshot!(Command::new(&launcher), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hi from the simple launcher!
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Reject a wheel that shadows a standard library module with `wheel-checks`.
+#[test]
+fn wheel_checks_stdlib_shadowing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        wheel-checks = ["stdlib-shadowing"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("enum34==1.1.10")
+        .arg("--strict"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    error: Failed to install: enum34-1.1.10-py3-none-any.whl (enum34==1.1.10)
+      Caused by: The wheel provides the top-level module `enum`, which shadows a standard library module (rejected by the `stdlib-shadowing` check)
+    "
+    );
+
+    context.assert_not_installed("enum34");
+
+    // Other checks don't reject the wheel.
+    uv_toml.write_str(indoc! {r#"
+        wheel-checks = ["prefixes", "absolute-paths", "world-writable"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("enum34==1.1.10")
+        .arg("--strict"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + enum34==1.1.10
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn config_settings_registry() {
     let context = TestContext::new("3.12");
@@ -5348,6 +5498,49 @@ fn dry_run_install() -> std::result::Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Plan an installation against a hypothetical environment with `--assume-installed`.
+#[test]
+fn dry_run_install_assume_installed() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("httpx==0.25.1")?;
+
+    let installed_json = context.temp_dir.child("installed.json");
+    installed_json.write_str(indoc! {r#"
+        [
+            {"name": "anyio", "version": "4.3.0"},
+            {"name": "certifi", "version": "2024.2.2"},
+            {"name": "h11", "version": "0.14.0"},
+            {"name": "idna", "version": "3.6"},
+            {"name": "sniffio", "version": "1.3.1"}
+        ]
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--assume-installed")
+        .arg("installed.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Would download 2 packages
+    Would install 2 packages
+     + httpcore==1.0.4
+     + httpx==0.25.1
+    "###
+    );
+
+    // The target environment is left untouched.
+    context.assert_not_installed("anyio");
+
+    Ok(())
+}
+
 #[test]
 fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let context = TestContext::new("3.12");
@@ -6954,6 +7147,79 @@ fn require_hashes_mismatch() -> Result<()> {
     Ok(())
 }
 
+/// Re-install already-satisfied requirements with `--require-hashes`.
+#[test]
+fn require_hashes_satisfied() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.0.0
+    "###
+    );
+
+    // The hashes of the installed archive should be recorded.
+    assert!(
+        context
+            .site_packages()
+            .join("anyio-4.0.0.dist-info")
+            .join("uv_hashes.json")
+            .is_file()
+    );
+
+    // Re-installing should validate the environment without resolving.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // The hash requirements should be enforced even if the environment is already satisfied.
+    requirements_txt.write_str(
+        "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--no-deps")
+        .arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    "###
+    );
+
+    Ok(())
+}
+
 /// Omit a transitive dependency in `--require-hashes`.
 #[test]
 fn require_hashes_missing_dependency() -> Result<()> {
@@ -12917,6 +13183,7 @@ fn overlapping_packages_warning() -> Result<()> {
     Resolved 2 packages in [TIME]
     Prepared 2 packages in [TIME]
     warning: The module `built_by_uv` is provided by more than one package, which causes an install race condition and can result in a broken module. Consider removing your dependency on either `built-by-uv` (v0.1.0) or `also-built-by-uv` (v0.1.0).
+    warning: The file `built_by_uv/__init__.py` is provided with different contents by more than one package, so only one of the copies was installed, which can result in a broken module. Consider removing your dependency on either `built-by-uv` (v0.1.0) or `also-built-by-uv` (v0.1.0).
     Installed 2 packages in [TIME]
      + also-built-by-uv==0.1.0 (from file://[TEMP_DIR]/also-built-by-uv)
      + built-by-uv==0.1.0 (from file://[WORKSPACE]/scripts/packages/built-by-uv)
@@ -13304,3 +13571,241 @@ fn install_missing_python_version_with_target() {
         "###
     );
 }
+
+/// With an explicit Python version and platform, `uv pip install --target` should install wheels
+/// without a Python interpreter.
+#[test]
+fn install_target_without_interpreter() {
+    // Create a context with no installed python interpreters.
+    let context = TestContext::new_with_versions(&[]);
+
+    let target_dir = context.temp_dir.child("target-dir");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("simple_launcher")
+        .arg("--python-version").arg("3.12")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28")
+        .arg("--compile-bytecode")
+        .arg("--no-python-downloads")
+        .arg("--target").arg(target_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12 without a Python interpreter
+    warning: Bytecode compilation requires a Python interpreter; skipping compilation
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + simple-launcher==0.1.0
+    "
+    );
+
+    target_dir
+        .child("simple_launcher")
+        .child("__init__.py")
+        .assert(predicates::path::exists());
+    target_dir
+        .child("bin")
+        .child("simple_launcher")
+        .assert(predicates::path::exists());
+
+    // Source distributions can't be built without an interpreter.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("basic_package")
+        .arg("--no-binary").arg("basic_package")
+        .arg("--python-version").arg("3.12")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28")
+        .arg("--no-python-downloads")
+        .arg("--target").arg(target_dir.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12 without a Python interpreter
+    error: `--no-binary` requires a Python interpreter to build source distributions, but no interpreter was found for Python 3.12.0
+    ");
+
+    // `--implementation` is only supported for `--target` and `--prefix` installs.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("ok")
+        .arg("--implementation").arg("pypy")
+        .arg("--python-version").arg("3.12")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--implementation` can only be used with `--target` or `--prefix`
+    ");
+}
+
+/// `uv pip install --prefix` should support installing for an alternative implementation without
+/// a Python interpreter.
+#[test]
+fn install_prefix_without_interpreter_implementation() {
+    let context = TestContext::new_with_versions(&[]);
+
+    let prefix_dir = context.temp_dir.child("prefix-dir");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("ok")
+        .arg("--implementation").arg("pypy")
+        .arg("--python-version").arg("3.10")
+        .arg("--python-platform").arg("x86_64-manylinux_2_28")
+        .arg("--no-python-downloads")
+        .arg("--prefix").arg(prefix_dir.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using PyPy 3.10 without a Python interpreter
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    prefix_dir
+        .child("lib")
+        .child("pypy3.10")
+        .child("site-packages")
+        .child("ok")
+        .assert(predicates::path::exists());
+}
+
+/// Report progress as newline-delimited JSON events with `--progress json`.
+#[test]
+fn install_progress_json() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--progress")
+        .arg("json")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("ok"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    {"event":"resolve_progress","name":"ok","version":"2.0.0"}
+    {"event":"resolve_complete"}
+    Resolved 1 package in [TIME]
+    {"event":"prepare_progress","name":"ok","version":"2.0.0","completed":1,"total":1}
+    {"event":"prepare_complete"}
+    Prepared 1 package in [TIME]
+    {"event":"install_progress","name":"ok","version":"2.0.0","completed":1,"total":1}
+    {"event":"install_complete"}
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    "#);
+}
+
+/// With `--preview-features trusted-backends`, simple `hatchling` projects are built directly,
+/// without installing `hatchling` into a build environment.
+#[test]
+fn install_trusted_backend() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("VALUE = 42")?;
+
+    // Without the preview feature, `hatchling` must be installed, which isn't possible offline.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--offline")
+        .arg("./project"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/project`
+      ├─▶ Failed to resolve requirements from `build-system.requires`
+      ├─▶ No solution found when resolving: `hatchling`
+      ╰─▶ Because hatchling was not found in the cache and you require hatchling, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--preview-features")
+        .arg("trusted-backends")
+        .arg("--offline")
+        .arg("./project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    context
+        .assert_command("import project; assert project.VALUE == 42")
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--preview-features")
+        .arg("trusted-backends")
+        .arg("--offline")
+        .arg("-e")
+        .arg("./project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/project)
+    ");
+
+    context
+        .assert_command("import project; assert project.VALUE == 42")
+        .success();
+
+    Ok(())
+}
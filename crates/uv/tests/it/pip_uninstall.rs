@@ -4,6 +4,7 @@ use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
+use predicates::prelude::predicate;
 
 use crate::common::{TestContext, get_bin, uv_snapshot};
 
@@ -397,6 +398,77 @@ fn uninstall_egg_info() -> Result<()> {
     Ok(())
 }
 
+/// Uninstall a package from a `pkgutil`-style namespace package, which shares its `__init__.py`
+/// with another package.
+#[test]
+fn uninstall_shared_namespace_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let site_packages = ChildPath::new(context.site_packages());
+
+    // Manually create two packages in the `ns` namespace, each of which records `ns/__init__.py`.
+    for name in ["a", "b"] {
+        let dist_info = site_packages.child(format!("ns_{name}-1.0.0.dist-info"));
+        dist_info.child("METADATA").write_str(&format!(
+            "Metadata-Version: 2.1\nName: ns-{name}\nVersion: 1.0.0\n"
+        ))?;
+        dist_info.child("RECORD").write_str(&format!(
+            "ns/__init__.py,,\nns/{name}/__init__.py,,\nns_{name}-1.0.0.dist-info/METADATA,,\nns_{name}-1.0.0.dist-info/RECORD,,\n"
+        ))?;
+        site_packages
+            .child("ns")
+            .child(name)
+            .child("__init__.py")
+            .write_str("")?;
+    }
+    site_packages
+        .child("ns")
+        .child("__init__.py")
+        .write_str("__path__ = __import__('pkgutil').extend_path(__path__, __name__)\n")?;
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("ns-a"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - ns-a==1.0.0
+    "###);
+
+    // The shared `__init__.py` is retained for `ns-b`.
+    site_packages
+        .child("ns")
+        .child("a")
+        .assert(predicate::path::missing());
+    site_packages
+        .child("ns")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    site_packages
+        .child("ns")
+        .child("b")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+
+    // Once the last package in the namespace is removed, so is the namespace.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("ns-b"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - ns-b==1.0.0
+    "###);
+
+    site_packages.child("ns").assert(predicate::path::missing());
+
+    Ok(())
+}
+
 fn normcase(s: &str) -> String {
     if cfg!(windows) {
         s.replace('/', "\\").to_lowercase()
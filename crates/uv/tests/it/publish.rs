@@ -1,15 +1,18 @@
 use crate::common::{TestContext, uv_snapshot, venv_bin_path};
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::assert::PathAssert;
 use assert_fs::fixture::{FileTouch, FileWriteStr, PathChild};
 use fs_err::OpenOptions;
 use indoc::{formatdoc, indoc};
+use predicates::prelude::predicate;
 use serde_json::json;
 use sha2::{Digest, Sha256};
 use std::env;
 use std::env::current_dir;
 use std::io::Write;
+use url::Url;
 use uv_static::EnvVars;
-use wiremock::matchers::{basic_auth, method, path};
+use wiremock::matchers::{basic_auth, body_partial_json, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
@@ -606,3 +609,182 @@ async fn gitlab_trusted_publishing_testpypi_id_token() {
     "
     );
 }
+
+/// Publish a release atomically through a PEP 694 upload session.
+#[tokio::test]
+async fn upload_session() {
+    let context = TestContext::new("3.12");
+
+    let server = MockServer::start().await;
+    let upload_v2 = "application/vnd.pypi.upload.v2+json";
+
+    // The index advertises support for the Upload 2.0 API.
+    Mock::given(method("GET"))
+        .and(path("/upload"))
+        .and(header("accept", upload_v2))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            json!({"meta": {"api-version": "2.0"}}).to_string(),
+            upload_v2,
+        ))
+        .mount(&server)
+        .await;
+
+    let session = json!({
+        "meta": {"api-version": "2.0"},
+        "links": {
+            "session": format!("{}/session/1", server.uri()),
+            "upload": format!("{}/session/1/upload", server.uri()),
+        },
+        "status": "pending",
+    });
+    Mock::given(method("POST"))
+        .and(path("/upload"))
+        .and(basic_auth("__token__", "secret"))
+        .and(body_partial_json(json!({"name": "ok", "version": "1.0.0"})))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(session.to_string(), upload_v2))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/session/1/upload"))
+        .and(body_partial_json(json!({
+            "filename": "ok-1.0.0-py3-none-any.whl",
+            "mechanism": "http-post-bytes",
+        })))
+        .respond_with(
+            ResponseTemplate::new(202).set_body_raw(
+                json!({
+                    "meta": {"api-version": "2.0"},
+                    "links": {"file-upload-session": format!("{}/session/1/file/1", server.uri())},
+                    "status": "pending",
+                    "mechanism": {
+                        "identifier": "http-post-bytes",
+                        "file-url": format!("{}/session/1/file/1/content", server.uri()),
+                    },
+                })
+                .to_string(),
+                upload_v2,
+            ),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/session/1/file/1/content"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/session/1/file/1"))
+        .and(body_partial_json(json!({"action": "complete"})))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut published = session.clone();
+    published["status"] = json!("published");
+    Mock::given(method("POST"))
+        .and(path("/session/1"))
+        .and(body_partial_json(json!({"action": "publish"})))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(published.to_string(), upload_v2))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--preview-features")
+        .arg("publish-upload-v2")
+        .arg("--token")
+        .arg("secret")
+        .arg("--publish-url")
+        .arg(format!("{}/upload", server.uri()))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to http://[LOCALHOST]/upload
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+}
+
+/// Publish to a local directory laid out as a simple index, then install from it.
+#[test]
+fn publish_directory() {
+    let context = TestContext::new("3.12");
+
+    let index = context.temp_dir.child("index");
+    let index_url = Url::from_directory_path(index.path()).unwrap();
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg(index_url.as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to file://[TEMP_DIR]/index/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    "
+    );
+
+    index
+        .child("ok")
+        .child("ok-1.0.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+    index
+        .child("ok")
+        .child("ok-1.0.0-py3-none-any.whl.metadata")
+        .assert(predicate::path::is_file());
+    index
+        .child("ok")
+        .child("index.html")
+        .assert(predicate::str::contains(
+            "<a href=\"ok-1.0.0-py3-none-any.whl#sha256=",
+        ));
+    index
+        .child("index.html")
+        .assert(predicate::str::contains("<a href=\"ok/\">ok</a><br/>"));
+
+    // Publishing the same file again is a no-op.
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--publish-url")
+        .arg(index_url.as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to file://[TEMP_DIR]/index/
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    File already exists, skipping
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("ok")
+        .arg("--index-url")
+        .arg(index_url.as_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+}
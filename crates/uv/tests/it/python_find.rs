@@ -1,3 +1,4 @@
+use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::{FileTouch, PathChild};
 use assert_fs::{fixture::FileWriteStr, prelude::PathCreateDir};
@@ -1218,6 +1219,90 @@ fn python_find_show_version() {
     ");
 }
 
+#[test]
+fn python_find_filters() {
+    let context: TestContext =
+        TestContext::new_with_versions(&["3.11", "3.12"]).with_filtered_python_sources();
+
+    let os = Os::from_env();
+    let arch = Arch::from_env();
+
+    // Request a version range for the current architecture
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg(format!("cpython>=3.12,<3.13; arch={arch}")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // Filters may be combined, and the version may be omitted
+    uv_snapshot!(context.filters(), context.python_find()
+        .arg(format!("; os={os}, arch={arch}")), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+
+    // Filters must be `key=value` pairs
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12; arch"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid filter `arch` in Python request: expected `key=value`
+    ");
+
+    // Unknown keys are rejected
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12; abi=cp312"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid filter `abi=cp312` in Python request: unknown key `abi`, expected one of `implementation`, `arch`, `os`, or `libc`
+    ");
+}
+
+#[test]
+fn python_find_json() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_find()
+        .arg("3.12")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(data["implementation"], "cpython");
+    assert!(data["arch"].is_string());
+    assert_eq!(data["os"], Os::from_env().to_string());
+    assert!(data["version"].as_str().unwrap().starts_with("3.12."));
+    assert!(data["key"].as_str().unwrap().starts_with("cpython-3.12."));
+    assert!(data["libc"].is_string());
+    assert!(data["path"].is_string());
+
+    // `--show-version` only applies to the text format
+    context
+        .python_find()
+        .arg("--show-version")
+        .arg("--output-format")
+        .arg("json")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
 #[test]
 fn python_find_path() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_not_executable();
@@ -3950,3 +3950,50 @@ fn python_install_upgrade_version_file() {
     hint: The version request came from a `.python-version` file; change the patch version in the file to upgrade instead
     ");
 }
+
+#[test]
+fn python_install_download_attestations() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc! {r#"
+        [python-download-attestations]
+        signer-workflow = "astral-sh/python-build-standalone/.github/workflows/release.yml"
+    "#})
+        .unwrap();
+
+    // Hide any `gh` installed on the machine.
+    let empty_path = context.temp_dir.child("empty");
+    empty_path.create_dir_all().unwrap();
+
+    // The GitHub CLI is required to verify attestations, so nothing is downloaded without it.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("3.12")
+        .env(EnvVars::PATH, empty_path.as_os_str()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install cpython-3.12.12-[PLATFORM]
+      Caused by: Verifying the build provenance of managed Python downloads requires the GitHub CLI (`gh`), but it was not found
+    ");
+
+    // Attestations are only published for CPython.
+    uv_snapshot!(context.filters(), context.python_install()
+        .arg("pypy3.10")
+        .env(EnvVars::UV_PYTHON_PYPY_BUILD, "7.3.19"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install pypy-3.10.16-[PLATFORM]
+      Caused by: Build provenance attestations are not published for PyPy distributions
+    ");
+}
@@ -410,6 +410,42 @@ fn python_list_downloads() {
     ");
 }
 
+#[test]
+fn python_list_downloads_json() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[]);
+
+    let output = context
+        .python_list()
+        .arg("3.10")
+        .arg("--only-downloads")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let data: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)?;
+    let implementations = data
+        .iter()
+        .map(|entry| entry["implementation"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(implementations, ["cpython", "pypy", "graalpy"]);
+
+    for entry in &data {
+        assert_eq!(entry["source"], "download");
+        assert!(entry["install_path"].is_null());
+        assert!(entry["url"].is_string());
+    }
+    assert!(data[0]["sha256"].is_string());
+
+    // Only CPython versions have a known end-of-life date.
+    assert_eq!(data[0]["end_of_life"], "2026-10");
+    assert!(data[0]["is_end_of_life"].is_boolean());
+    assert!(data[1]["end_of_life"].is_null());
+    assert!(data[1]["is_end_of_life"].is_null());
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "python-managed")]
 fn python_list_downloads_installed() {
@@ -1,8 +1,12 @@
+use std::process::Command;
+
 use crate::common::{TestContext, uv_snapshot};
+
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
-use assert_fs::fixture::FileTouch;
+use assert_fs::fixture::{FileTouch, FileWriteStr};
 use assert_fs::prelude::PathChild;
+use indoc::indoc;
 
 use uv_static::EnvVars;
 
@@ -457,6 +461,92 @@ fn python_no_transparent_upgrade_with_venv_patch_specification() {
     );
 }
 
+// Virtual environments with a patch version should be re-linked with `--relink`.
+#[test]
+fn python_upgrade_relink() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.13"])
+        .with_python_download_cache()
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.10"
+        dependencies = []
+        "#
+        })?;
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.10.17"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.17 in [TIME]
+     + cpython-3.10.17-[PLATFORM] (python3.10)
+    ");
+
+    // Create a virtual environment with a patch version
+    uv_snapshot!(context.filters(), context.venv().arg("-p").arg("3.10.17"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.10.17
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    ");
+
+    let venv_python = if cfg!(windows) {
+        context.venv.child("Scripts/python.exe")
+    } else {
+        context.venv.child("bin/python")
+    };
+
+    // Upgrade patch version, re-linking the virtual environment
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10").arg("--relink"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.19 in [TIME]
+     + cpython-3.10.19-[PLATFORM] (python3.10)
+    Relinked `.venv` to Python 3.10.19 (from 3.10.17)
+    ");
+
+    // The virtual environment uses the new patch version.
+    uv_snapshot!(context.filters(), Command::new(venv_python.as_os_str()).arg("--version"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.10.19
+
+    ----- stderr -----
+    "
+    );
+
+    // Re-linking again is a no-op.
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10").arg("--relink"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Python 3.10 is already on the latest supported patch release
+    ");
+
+    Ok(())
+}
+
 // Transparent upgrades should work for virtual environments created within
 // virtual environments.
 #[test]
@@ -1146,6 +1146,79 @@ fn run_exact() -> Result<()> {
     Ok(())
 }
 
+/// With the `run-cache` preview feature, `uv run` skips the lock and sync operations if the
+/// project is unchanged since the last sync.
+#[test]
+fn run_cache() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("run-cache").arg("python").arg("-c").arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // The project is unchanged, so the environment isn't checked.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("run-cache").arg("python").arg("-c").arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // Requesting a different set of groups invalidates the digest.
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("run-cache").arg("--no-dev").arg("python").arg("-c").arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    // Changing the project's dependencies invalidates the digest.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["iniconfig", "sniffio"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--preview-features").arg("run-cache").arg("python").arg("-c").arg("import sniffio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_with() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4608,6 +4681,85 @@ fn run_with_multiple_env_files() -> Result<()> {
     Ok(())
 }
 
+/// Environment files from `tool.uv.env-files` are loaded first, with any `--env-file` layered on
+/// top; values may reference variables defined in earlier files.
+#[test]
+fn run_with_env_files_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('THE_EMPIRE_VARIABLE'))
+        print(os.environ.get('REBEL_1'))
+        print(os.environ.get('REBEL_2'))
+       "
+    })?;
+
+    context.temp_dir.child("uv.toml").write_str(indoc! { r#"
+        env-files = [".env1"]
+       "#
+    })?;
+
+    context.temp_dir.child(".env1").write_str(indoc! { "
+        THE_EMPIRE_VARIABLE=palpatine
+        REBEL_1=leia_organa
+       "
+    })?;
+
+    context.temp_dir.child(".env2").write_str(indoc! { r#"
+        REBEL_1=obi_wan_kenobi
+        REBEL_2="${THE_EMPIRE_VARIABLE}_vs_${REBEL_1}"
+       "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    leia_organa
+    None
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env2").arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    palpatine
+    obi_wan_kenobi
+    palpatine_vs_obi_wan_kenobi
+
+    ----- stderr -----
+    "###);
+
+    // Variables set in the environment take precedence over all files.
+    uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env2").arg("test.py").env("THE_EMPIRE_VARIABLE", "vader"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    vader
+    obi_wan_kenobi
+    vader_vs_obi_wan_kenobi
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-env-file").arg("test.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+    None
+    None
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_with_env_omitted() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -6094,3 +6246,130 @@ fn run_only_group_and_extra_conflict() -> Result<()> {
 
     Ok(())
 }
+
+/// Write the `--with` requirements to a requirements file or a script after a successful run.
+#[test]
+fn run_with_snapshot() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let links = context.workspace_root.join("scripts/links");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .arg("--with")
+        .arg("ok")
+        .arg("--snapshot")
+        .arg("requirements.txt")
+        .arg("python")
+        .arg("-c")
+        .arg("import ok"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    Wrote `--with` requirements to: requirements.txt
+    ");
+
+    assert_snapshot!(context.read("requirements.txt"), @"ok==2.0.0");
+
+    // Replace the dependencies of an existing script, retaining the rest of its metadata.
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! { r#"
+        #!/usr/bin/env -S uv run --script
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = ["ok"]
+        # ///
+
+        import ok
+    "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .arg("--with")
+        .arg("ok==1.0.0")
+        .arg("--snapshot")
+        .arg("script.py")
+        .arg("python")
+        .arg("-c")
+        .arg("import ok"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    Wrote `--with` requirements to: script.py
+    ");
+
+    assert_snapshot!(context.read("script.py"), @r#"
+    #!/usr/bin/env -S uv run --script
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #     "ok==1.0.0",
+    # ]
+    # ///
+
+    import ok
+    "#);
+
+    // If the command fails, the snapshot isn't written.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&links)
+        .arg("--with")
+        .arg("ok")
+        .arg("--snapshot")
+        .arg("new.py")
+        .arg("python")
+        .arg("-c")
+        .arg("raise SystemExit(1)"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Skipping snapshot, since the command did not exit successfully: `new.py`
+    ");
+
+    context
+        .temp_dir
+        .child("new.py")
+        .assert(predicate::path::missing());
+
+    // A snapshot requires `--with` requirements.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--snapshot")
+        .arg("requirements.txt")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--snapshot` requires `--with`, `--with-editable`, or `--with-requirements`
+    ");
+
+    Ok(())
+}
@@ -57,3 +57,18 @@ fn test_self_update_offline_error() {
     error: Self-update is not possible because network connectivity is disabled (i.e., with `--offline`)
     ");
 }
+
+#[test]
+fn test_self_rollback_without_update() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.self_rollback(),
+    @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No previous version of uv is available; `uv self rollback` can only revert an update performed by `uv self update`
+    ");
+}
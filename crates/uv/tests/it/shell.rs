@@ -0,0 +1,73 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+fn pyproject() -> &'static str {
+    r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+    "#
+}
+
+/// Run a command in a shell with the project environment activated.
+#[test]
+fn shell_command() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject())?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.shell().arg("--command").arg("echo $VIRTUAL_ENV && command -v python"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/
+    [VENV]/bin/python
+
+    ----- stderr -----
+    ");
+
+    // The exit code of the command is propagated.
+    uv_snapshot!(context.filters(), context.shell().arg("-c").arg("exit 3"), @"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// The project environment is not created by `uv shell`.
+#[test]
+fn shell_missing_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject())?;
+
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.shell().arg("--command").arg("true"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No project environment found at `.venv`; run `uv sync` to create it
+    ");
+
+    Ok(())
+}
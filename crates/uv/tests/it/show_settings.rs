@@ -68,6 +68,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -84,12 +86,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -107,6 +112,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -150,6 +157,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -159,6 +168,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -188,6 +198,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -205,6 +216,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -239,6 +256,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -271,6 +298,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -287,12 +316,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -310,6 +342,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -353,6 +387,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -362,6 +398,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -391,6 +428,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -408,6 +446,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -442,6 +486,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -475,6 +529,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -491,12 +547,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -514,6 +573,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -557,6 +618,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -566,6 +629,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -595,6 +659,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -612,6 +677,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -646,6 +717,16 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -711,6 +792,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -727,12 +810,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -750,6 +836,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -793,6 +881,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -802,6 +892,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -831,6 +922,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -848,6 +940,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -882,6 +980,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -916,6 +1024,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -932,12 +1042,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -955,6 +1068,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -973,6 +1088,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -1002,6 +1118,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -1019,6 +1136,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1053,6 +1176,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1097,6 +1230,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1113,12 +1248,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -1136,6 +1274,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1179,6 +1319,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -1188,6 +1330,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -1217,6 +1360,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -1234,6 +1378,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1270,6 +1420,16 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1327,6 +1487,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1343,12 +1505,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -1366,6 +1531,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1409,6 +1576,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1442,6 +1611,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -1451,6 +1622,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -1480,6 +1652,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -1497,6 +1670,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1531,6 +1710,16 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1565,6 +1754,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1581,12 +1772,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -1604,6 +1798,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1649,6 +1845,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1682,6 +1880,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -1715,6 +1915,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -1724,6 +1926,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -1753,6 +1956,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -1770,6 +1974,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1804,6 +2014,16 @@ fn resolve_index_url() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -1861,6 +2081,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1877,12 +2099,15 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -1900,6 +2125,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1944,6 +2171,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 no_index: true,
@@ -1952,6 +2181,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -1981,6 +2211,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -1998,6 +2229,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2032,6 +2269,16 @@ fn resolve_find_links() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2088,6 +2335,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2104,12 +2353,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -2127,6 +2379,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2145,6 +2399,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -2174,6 +2429,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -2191,6 +2447,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2225,6 +2487,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2274,6 +2546,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2290,12 +2564,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -2313,6 +2590,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2356,6 +2635,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -2389,6 +2670,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -2398,6 +2681,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -2427,6 +2711,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -2444,6 +2729,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2478,6 +2769,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2510,6 +2811,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2526,12 +2829,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -2549,6 +2855,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2592,6 +2900,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -2625,6 +2935,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -2634,6 +2946,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -2663,6 +2976,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -2680,6 +2994,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2714,6 +3034,16 @@ fn resolve_top_level() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2769,6 +3099,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2785,12 +3117,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -2808,6 +3143,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2826,6 +3163,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -2855,6 +3193,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -2872,6 +3211,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2906,6 +3251,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -2945,6 +3300,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2961,12 +3318,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -2984,6 +3344,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3002,6 +3364,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -3031,6 +3394,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -3048,6 +3412,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3082,6 +3452,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3121,6 +3501,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3137,12 +3519,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -3160,6 +3545,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3178,6 +3565,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -3207,6 +3595,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -3224,6 +3613,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3258,6 +3653,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3299,6 +3704,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3315,12 +3722,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -3338,6 +3748,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3356,6 +3768,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -3385,6 +3798,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -3402,6 +3816,12 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3436,6 +3856,16 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3496,6 +3926,8 @@ fn resolve_tool() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3512,12 +3944,15 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3525,6 +3960,8 @@ fn resolve_tool() -> anyhow::Result<()> {
         with: [],
         with_requirements: [],
         with_executables_from: [],
+        include_executables: [],
+        exclude_executables: [],
         with_editable: [],
         constraints: [],
         overrides: [],
@@ -3553,6 +3990,12 @@ fn resolve_tool() -> anyhow::Result<()> {
             ),
             prerelease: None,
             fork_strategy: None,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
@@ -3565,6 +4008,9 @@ fn resolve_tool() -> anyhow::Result<()> {
                 Clone,
             ),
             compile_bytecode: None,
+            script_launcher: None,
+            no_gui_scripts: None,
+            wheel_checks: None,
             no_sources: None,
             upgrade: None,
             reinstall: None,
@@ -3595,6 +4041,12 @@ fn resolve_tool() -> anyhow::Result<()> {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -3616,6 +4068,16 @@ fn resolve_tool() -> anyhow::Result<()> {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
         force: false,
@@ -3623,6 +4085,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
     }
@@ -3684,6 +4147,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3700,12 +4165,15 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -3723,6 +4191,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3741,6 +4211,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -3770,6 +4241,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -3787,6 +4259,12 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3821,6 +4299,16 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -3894,6 +4382,8 @@ fn resolve_both() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3910,12 +4400,15 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -3933,6 +4426,8 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3976,6 +4471,8 @@ fn resolve_both() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -3985,6 +4482,7 @@ fn resolve_both() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -4014,6 +4512,7 @@ fn resolve_both() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -4031,6 +4530,12 @@ fn resolve_both() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4065,6 +4570,16 @@ fn resolve_both() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4143,6 +4658,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4159,12 +4676,15 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -4182,6 +4702,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4225,6 +4747,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -4234,6 +4758,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -4263,6 +4788,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -4280,6 +4806,12 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4314,6 +4846,16 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4471,6 +5013,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4487,12 +5031,15 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -4510,6 +5057,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4553,6 +5102,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -4562,6 +5113,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -4591,6 +5143,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -4608,6 +5161,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4642,6 +5201,16 @@ fn resolve_config_file() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4672,7 +5241,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         .arg("--show-settings")
         .arg("--config-file")
         .arg(config.path())
-        .arg("requirements.in"), @r"
+        .arg("requirements.in"), @"
     success: false
     exit_code: 2
     ----- stdout -----
@@ -4683,7 +5252,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `script-launcher`, `no-gui-scripts`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `provided-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `patches`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4774,6 +5343,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4790,12 +5361,15 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -4813,6 +5387,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4831,6 +5407,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -4860,6 +5437,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -4877,6 +5455,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4911,6 +5495,16 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -4953,6 +5547,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4969,12 +5565,15 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -4992,6 +5591,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5010,6 +5611,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -5039,6 +5641,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -5056,6 +5659,12 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5090,6 +5699,16 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5151,6 +5770,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             ],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5167,12 +5788,15 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -5190,6 +5814,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5208,6 +5834,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -5237,6 +5864,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -5254,6 +5882,12 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5288,6 +5922,16 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5341,6 +5985,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5357,12 +6003,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -5380,6 +6029,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5425,6 +6076,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5458,6 +6111,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -5467,6 +6122,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -5496,6 +6152,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -5513,6 +6170,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5547,6 +6210,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5579,6 +6252,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5595,12 +6270,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -5618,6 +6296,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5663,6 +6343,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5696,6 +6378,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -5705,6 +6389,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -5734,6 +6419,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -5751,6 +6437,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5785,6 +6477,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -5823,6 +6525,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5839,12 +6543,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -5862,6 +6569,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5907,6 +6616,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -5940,6 +6651,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -5949,6 +6662,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -5978,6 +6692,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -5995,6 +6710,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6029,6 +6750,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6062,6 +6793,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6078,12 +6811,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -6101,6 +6837,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6146,6 +6884,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6179,6 +6919,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -6188,6 +6930,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -6217,6 +6960,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -6234,6 +6978,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6268,6 +7018,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6308,6 +7068,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6324,12 +7086,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -6347,6 +7112,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6392,6 +7159,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6425,6 +7194,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -6434,6 +7205,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -6463,6 +7235,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -6480,6 +7253,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6514,6 +7293,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6547,6 +7336,8 @@ fn index_priority() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6563,12 +7354,15 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -6586,6 +7380,8 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6631,6 +7427,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                     Index {
                         name: None,
@@ -6664,6 +7462,8 @@ fn index_priority() -> anyhow::Result<()> {
                         authenticate: Auto,
                         ignore_error_codes: None,
                         cache_control: None,
+                        tls: None,
+                        packages: [],
                     },
                 ],
                 flat_index: [],
@@ -6673,6 +7473,7 @@ fn index_priority() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -6702,6 +7503,7 @@ fn index_priority() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -6719,6 +7521,12 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6753,6 +7561,16 @@ fn index_priority() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6799,6 +7617,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6815,12 +7635,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -6832,7 +7655,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -6856,6 +7681,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -6885,6 +7711,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -6902,6 +7729,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6936,6 +7769,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -6968,6 +7811,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6984,12 +7829,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -7001,7 +7849,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7025,6 +7875,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -7054,6 +7905,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -7071,6 +7923,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7105,6 +7963,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -7135,6 +8003,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7151,12 +8021,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -7168,7 +8041,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7192,6 +8067,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -7221,6 +8097,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -7238,6 +8115,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7272,6 +8155,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Require,
@@ -7304,6 +8197,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7320,12 +8215,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -7337,7 +8235,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7361,6 +8261,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -7390,6 +8291,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -7407,6 +8309,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7441,6 +8349,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -7471,6 +8389,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7487,12 +8407,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -7504,7 +8427,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7528,6 +8453,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -7557,6 +8483,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -7574,6 +8501,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7608,6 +8541,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: None,
             upgrade: None,
@@ -7639,6 +8582,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7655,12 +8600,15 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipInstallSettings {
         package: [],
@@ -7672,7 +8620,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         excludes: [],
         build_constraints: [],
+        implementation: None,
         dry_run: Disabled,
+        assume_installed: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7696,6 +8646,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -7725,6 +8676,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -7742,6 +8694,12 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7776,6 +8734,16 @@ fn verify_hashes() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -7822,6 +8790,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7831,19 +8801,22 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | S3_ENDPOINT | CACHE_SIZE | INIT_PROJECT_FLAG | WORKSPACE_METADATA | WORKSPACE_DIR | WORKSPACE_LIST | SBOM_EXPORT,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | S3_ENDPOINT | CACHE_SIZE | INIT_PROJECT_FLAG | WORKSPACE_METADATA | WORKSPACE_DIR | WORKSPACE_LIST | SBOM_EXPORT | ADD_FROM_IMPORTS | PUBLISH_UPLOAD_V2 | VERIFY | INDEX_SYNC | LOCK_AUDIT | ENV_SNAPSHOTS | TRUSTED_BACKENDS | PYTHON_DELTA_DOWNLOADS | TOOL_LOCK | TREE_INTERACTIVE | REMOTE_CACHE | INDEX_SERVE | REMOVE_UNUSED | HTTP3 | BUNDLE,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -7860,6 +8833,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -7892,6 +8866,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -7913,6 +8893,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -7936,6 +8926,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7952,12 +8944,15 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -7974,6 +8969,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -8006,6 +9002,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8027,6 +9029,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -8050,6 +9062,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8059,19 +9073,22 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | S3_ENDPOINT | CACHE_SIZE | INIT_PROJECT_FLAG | WORKSPACE_METADATA | WORKSPACE_DIR | WORKSPACE_LIST | SBOM_EXPORT,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | PACKAGE_CONFLICTS | EXTRA_BUILD_DEPENDENCIES | DETECT_MODULE_CONFLICTS | FORMAT | NATIVE_AUTH | S3_ENDPOINT | CACHE_SIZE | INIT_PROJECT_FLAG | WORKSPACE_METADATA | WORKSPACE_DIR | WORKSPACE_LIST | SBOM_EXPORT | ADD_FROM_IMPORTS | PUBLISH_UPLOAD_V2 | VERIFY | INDEX_SYNC | LOCK_AUDIT | ENV_SNAPSHOTS | TRUSTED_BACKENDS | PYTHON_DELTA_DOWNLOADS | TOOL_LOCK | TREE_INTERACTIVE | REMOTE_CACHE | INDEX_SERVE | REMOVE_UNUSED | HTTP3 | BUNDLE,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -8088,6 +9105,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -8120,6 +9138,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8141,6 +9165,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -8164,6 +9198,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8180,12 +9216,15 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -8202,6 +9241,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -8234,6 +9274,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8255,6 +9301,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -8278,6 +9334,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8294,12 +9352,15 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -8316,6 +9377,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -8348,6 +9410,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8369,6 +9437,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -8394,6 +9472,8 @@ fn preview_features() {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8410,12 +9490,15 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     VersionSettings {
         value: None,
@@ -8432,6 +9515,7 @@ fn preview_features() {
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -8464,6 +9548,12 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                yanked_policy: Warn,
+                allow_yanked: [],
+                known_incompatibilities: [],
+                max_backtracks: None,
+                resolution_timeout: None,
+                prefer_smaller_artifacts: false,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8485,6 +9575,16 @@ fn preview_features() {
                 upgrade: None,
             },
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             reinstall: None,
         },
     }
@@ -8529,6 +9629,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8545,12 +9647,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -8568,6 +9673,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8586,6 +9693,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -8615,6 +9723,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -8632,6 +9741,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -8666,6 +9781,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -8706,6 +9831,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8722,12 +9849,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -8745,6 +9875,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8763,6 +9895,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -8792,6 +9925,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -8809,6 +9943,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -8843,6 +9983,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -8906,6 +10056,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8922,12 +10074,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -8945,6 +10100,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8963,6 +10120,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -8992,6 +10150,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9009,6 +10168,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9043,6 +10208,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -9081,6 +10256,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9097,12 +10274,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -9120,6 +10300,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9138,6 +10320,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -9167,6 +10350,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9184,6 +10368,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9218,6 +10408,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -9250,6 +10450,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9266,12 +10468,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -9289,6 +10494,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9307,6 +10514,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -9336,6 +10544,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9353,6 +10562,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9387,6 +10602,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -9420,6 +10645,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9436,12 +10663,15 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -9459,6 +10689,8 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9477,6 +10709,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -9506,6 +10739,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9523,6 +10757,12 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -9557,6 +10797,16 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -9655,6 +10905,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9671,22 +10923,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -9718,6 +10977,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -9774,6 +11039,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9790,22 +11057,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -9837,6 +11111,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -9916,6 +11196,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -9932,22 +11214,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -9979,6 +11268,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10033,6 +11328,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10049,22 +11346,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -10096,6 +11400,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10140,6 +11450,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10156,22 +11468,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -10203,6 +11522,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10248,6 +11573,8 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10264,22 +11591,29 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: false,
         dry_run: Disabled,
+        format: Text,
         script: None,
+        audit: None,
+        explain: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
+            python_download_attestations: None,
             python_downloads_json_url: None,
         },
         refresh: None(
@@ -10311,6 +11645,12 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
                 ),
             },
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -10420,6 +11760,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10436,12 +11778,15 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -10459,6 +11804,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10477,6 +11824,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -10506,6 +11854,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: Shared,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -10523,6 +11872,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -10557,6 +11912,16 @@ fn build_isolation_override() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
@@ -10592,6 +11957,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
             allow_insecure_host: [],
             timeout: [TIME],
             retries: 3,
+            max_download_rate: None,
+            concurrent_downloads_per_host: None,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -10608,12 +11975,15 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        wait_timeout: None,
     }
     CacheSettings {
         no_cache: false,
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        remote: None,
+        partition: None,
     }
     PipCompileSettings {
         format: None,
@@ -10631,6 +12001,8 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        python_versions: [],
+        split_python_versions: false,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10649,6 +12021,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
+                python_download_attestations: None,
                 python_downloads_json_url: None,
             },
             system: false,
@@ -10678,6 +12051,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
             torch_backend: None,
+            torch_backend_packages: [],
             build_isolation: SharedPackage(
                 [
                     PackageName(
@@ -10701,6 +12075,12 @@ fn build_isolation_override() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            yanked_policy: Warn,
+            allow_yanked: [],
+            known_incompatibilities: [],
+            max_backtracks: None,
+            resolution_timeout: None,
+            prefer_smaller_artifacts: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -10735,6 +12115,16 @@ fn build_isolation_override() -> anyhow::Result<()> {
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
+            script_policy: ScriptPolicy {
+                launcher: Native,
+                gui_scripts: true,
+            },
+            wheel_checks: WheelChecks {
+                prefixes: false,
+                absolute_paths: false,
+                world_writable: false,
+                stdlib_shadowing: false,
+            },
             sources: Enabled,
             hash_checking: Some(
                 Verify,
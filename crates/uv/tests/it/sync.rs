@@ -94,6 +94,7 @@ fn locked() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: removed `anyio==3.7.0`; added `iniconfig`
     "###);
 
     let updated = context.read("uv.lock");
@@ -539,6 +540,7 @@ fn sync_json() -> Result<()> {
     ----- stderr -----
     Resolved 2 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: removed `iniconfig`; added `iniconfig<2`
     ");
 
     // Test that JSON output is shown even with --quiet flag
@@ -3445,6 +3447,67 @@ fn sync_include_group() -> Result<()> {
     Ok(())
 }
 
+/// Include a group with `exclude` and `overrides`.
+#[test]
+fn sync_include_group_exclude_overrides() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        runtime = ["anyio", "iniconfig>=1"]
+        test = [{include-group = "runtime", exclude = ["anyio"], overrides = ["iniconfig==1.1.1"]}]
+        "#,
+    )?;
+
+    // The `test` group should omit `anyio` and pin `iniconfig`.
+    uv_snapshot!(context.filters(), context.sync().arg("--only-group").arg("test"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    ");
+
+    // Excluding a package that isn't in the included group is an error.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        runtime = ["anyio", "iniconfig>=1"]
+        test = [{include-group = "runtime", exclude = ["pytest"]}]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Project `project` has malformed dependency groups
+      Caused by: Group `test` excludes or overrides `pytest`, but `pytest` is not a dependency of the included group `runtime`
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn sync_exclude_group() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -5417,6 +5480,65 @@ fn no_install_project() -> Result<()> {
     Ok(())
 }
 
+/// Apply a named sync profile from `tool.uv.sync-profiles`.
+#[test]
+fn sync_profile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv.sync-profiles.ci]
+        groups = ["test"]
+        no-install-project = true
+        "#,
+    )?;
+
+    // Generate a lockfile.
+    context.lock().assert().success();
+
+    // The profile should install the `test` group, but not `project`.
+    uv_snapshot!(context.filters(), context.sync().arg("--profile").arg("ci"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    // An unknown profile should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--profile").arg("docs"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Sync profile `docs` not found in `tool.uv.sync-profiles` (available profiles: `ci`)
+    ");
+
+    Ok(())
+}
+
 /// Avoid syncing workspace members and the project when `--no-install-workspace` is provided, but
 /// include all dependencies.
 #[test]
@@ -6060,6 +6182,69 @@ fn virtual_dependency_group() -> Result<()> {
     Ok(())
 }
 
+/// Sync dependency groups declared by virtual workspace members, i.e., members without a
+/// `[project]` table.
+#[test]
+fn virtual_dependency_group_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["infra", "notebooks"]
+
+        [dependency-groups]
+        dev = ["sniffio"]
+    "#})?;
+
+    let infra = context.temp_dir.child("infra");
+    infra.child("pyproject.toml").write_str(indoc! {r#"
+        [dependency-groups]
+        dev = ["iniconfig"]
+    "#})?;
+
+    let notebooks = context.temp_dir.child("notebooks");
+    notebooks.child("pyproject.toml").write_str(indoc! {r#"
+        [dependency-groups]
+        notebooks = ["sortedcontainers"]
+    "#})?;
+
+    // The `dev` groups of the root and the `infra` member are merged.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: No `requires-python` value found in the workspace. Defaulting to `>=3.12`.
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    // Syncing from within a member uses the workspace root.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--group").arg("notebooks").current_dir(notebooks.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: No `requires-python` value found in the workspace. Defaulting to `>=3.12`.
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sortedcontainers==2.4.0
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("notebooks = [{ name = \"sortedcontainers\" }]"));
+
+    Ok(())
+}
+
 #[test]
 fn virtual_no_build_dynamic_cached() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -10747,6 +10932,7 @@ fn sync_dry_run_and_locked() -> Result<()> {
     Would install 1 package
      + iniconfig==2.0.0
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of `project` changed: removed `anyio==3.7.0`; added `iniconfig`
     ");
 
     let updated = context.read("uv.lock");
@@ -11062,6 +11248,7 @@ fn sync_locked_script() -> Result<()> {
     Using script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
     Resolved 4 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - The requirements of the project changed: added `iniconfig`
     ");
 
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py"), @r"
@@ -11165,6 +11352,7 @@ fn sync_locked_script() -> Result<()> {
     warning: Resolving despite existing lockfile due to fork markers being disjoint with `requires-python`: `python_full_version >= '3.11'` vs `python_full_version >= '3.8' and python_full_version < '3.11'`
     Resolved 6 packages in [TIME]
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+      - `requires-python` changed from `>=3.11` to `>=3.8, <3.11`
     ");
 
     uv_snapshot!(context.filters(), context.sync().arg("--script").arg("script.py"), @r"
@@ -14760,3 +14948,492 @@ fn sync_fails_ambiguous_url() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a source distribution for `dep==0.1.0`, with an in-tree build backend, to `links`.
+fn write_patchable_source_dist(context: &TestContext) -> Result<()> {
+    let pyproject_toml = indoc! {r#"
+        [project]
+        name = "dep"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        backend-path = ["."]
+        build-backend = "backend"
+    "#};
+    let backend = indoc! {r#"
+        import base64
+        import hashlib
+        import os
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            filename = "dep-0.1.0-py3-none-any.whl"
+            with open(os.path.join("dep", "__init__.py"), "rb") as f:
+                init = f.read()
+            files = {
+                "dep/__init__.py": init,
+                "dep-0.1.0.dist-info/METADATA": b"Metadata-Version: 2.1\nName: dep\nVersion: 0.1.0\n",
+                "dep-0.1.0.dist-info/WHEEL": b"Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+            }
+            record = []
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                for path, data in files.items():
+                    wheel.writestr(path, data)
+                    digest = base64.urlsafe_b64encode(hashlib.sha256(data).digest()).rstrip(b"=")
+                    record.append(f"{path},sha256={digest.decode()},{len(data)}")
+                record.append("dep-0.1.0.dist-info/RECORD,,")
+                wheel.writestr("dep-0.1.0.dist-info/RECORD", "\n".join(record) + "\n")
+            return filename
+    "#};
+
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    let file = fs_err::File::create(links.join("dep-0.1.0.tar.gz"))?;
+    let enc = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+    let mut tar = tar::Builder::new(enc);
+    for (path, contents) in [
+        ("dep-0.1.0/pyproject.toml", pyproject_toml),
+        ("dep-0.1.0/backend.py", backend),
+        ("dep-0.1.0/dep/__init__.py", "VALUE = 1\n"),
+    ] {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        tar.append_data(&mut header, path, std::io::Cursor::new(contents))?;
+    }
+    tar.into_inner()?.finish()?;
+
+    Ok(())
+}
+
+/// Apply `tool.uv.patches` to a source distribution before building it.
+#[test]
+fn sync_patches() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_patchable_source_dist(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["dep"]
+
+        [tool.uv]
+        package = false
+        no-index = true
+        find-links = ["links"]
+
+        [tool.uv.patches]
+        dep = [{ path = "patches/dep.patch" }]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("patches/dep.patch")
+        .write_str(indoc! {r"
+        --- a/dep/__init__.py
+        +++ b/dep/__init__.py
+        @@ -1 +1 @@
+        -VALUE = 1
+        +VALUE = 2
+    "})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + dep==0.1.0
+    ");
+
+    let lock = context.read("uv.lock");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+
+        [[manifest.patches]]
+        name = "dep"
+        path = "patches/dep.patch"
+        hash = "sha256:dcf9f9bf828fa3f6f833218ff8001867b3a362be67d783040399f466413f5e9e"
+
+        [[package]]
+        name = "dep"
+        version = "0.1.0"
+        source = { registry = "links" }
+        sdist = { path = "dep-0.1.0.tar.gz" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "dep" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "dep" }]
+        "#
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.python_command().arg("-c").arg("import dep; print(dep.VALUE)"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2
+
+    ----- stderr -----
+    ");
+
+    // Changing the patch should invalidate the lockfile and rebuild the package.
+    context
+        .temp_dir
+        .child("patches/dep.patch")
+        .write_str(indoc! {r"
+        --- a/dep/__init__.py
+        +++ b/dep/__init__.py
+        @@ -1 +1 @@
+        -VALUE = 1
+        +VALUE = 3
+    "})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ dep==0.1.0
+    ");
+
+    uv_snapshot!(context.filters(), context.python_command().arg("-c").arg("import dep; print(dep.VALUE)"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// A patch that doesn't apply should fail the build.
+#[test]
+fn sync_patches_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    write_patchable_source_dist(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["dep"]
+
+        [tool.uv]
+        package = false
+        no-index = true
+        find-links = ["links"]
+
+        [tool.uv.patches]
+        dep = [{ path = "dep.patch" }]
+    "#})?;
+
+    context.temp_dir.child("dep.patch").write_str(indoc! {r"
+        --- a/dep/__init__.py
+        +++ b/dep/__init__.py
+        @@ -1 +1 @@
+        -VALUE = 0
+        +VALUE = 2
+    "})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `dep==0.1.0`
+      ├─▶ Failed to apply patch `dep.patch`
+      ╰─▶ Hunk #1 failed to apply to `dep/__init__.py`
+      help: `dep` (v0.1.0) was included because `project` (v0.1.0) depends on `dep`
+    ");
+
+    Ok(())
+}
+
+/// Record snapshots with `uv sync --snapshot` and revert them with `uv sync --rollback` and
+/// `uv env restore`.
+#[test]
+fn sync_snapshot_rollback() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(filename),
+            context.temp_dir.join("links").join(filename),
+        )?;
+    }
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject = |requirement: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["{requirement}"]
+
+            [[tool.uv.index]]
+            name = "links"
+            format = "flat"
+            url = "./links"
+            default = true
+            "#
+        })
+    };
+
+    write_pyproject("ok==1.0.0")?;
+    uv_snapshot!(context.filters(), context.sync().arg("--snapshot").arg("--preview-features").arg("env-snapshots"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + ok==1.0.0
+    Saved environment snapshot; run `uv sync --rollback` to revert these changes
+    ");
+
+    write_pyproject("ok==2.0.0")?;
+    uv_snapshot!(context.filters(), context.sync().arg("--snapshot").arg("--preview-features").arg("env-snapshots"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - ok==1.0.0
+     + ok==2.0.0
+    Saved environment snapshot; run `uv sync --rollback` to revert these changes
+    ");
+
+    // An unchanged environment doesn't retain a snapshot.
+    uv_snapshot!(context.filters(), context.sync().arg("--snapshot").arg("--preview-features").arg("env-snapshots"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Audited [N] packages in [TIME]
+    ");
+
+    // Revert the upgrade.
+    uv_snapshot!(context.filters(), context.sync().arg("--rollback").arg("--preview-features").arg("env-snapshots"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Restored environment at: .venv
+     - ok==2.0.0
+     + ok==1.0.0
+    ");
+
+    context
+        .assert_command("import ok; assert ok.__version__ == '1.0.0'")
+        .success();
+
+    // Revert the initial installation.
+    uv_snapshot!(context.filters(), context.env_restore().arg("--preview-features").arg("env-snapshots"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Restored environment at: .venv
+     - ok==1.0.0
+    ");
+
+    context.assert_command("import ok").failure();
+
+    // No snapshots remain.
+    uv_snapshot!(context.filters(), context.env_restore().arg("--preview-features").arg("env-snapshots"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    No snapshots found for environment at: .venv
+    ");
+
+    Ok(())
+}
+
+/// Stage changes with `uv sync --atomic`, leaving the environment unchanged if the sync fails.
+#[test]
+fn sync_atomic() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context.workspace_root.join("scripts/links").join(filename),
+            context.temp_dir.join("links").join(filename),
+        )?;
+    }
+
+    // A package that always fails to build.
+    let broken = context.temp_dir.child("broken");
+    broken.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "broken"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = []
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+    broken.child("build_backend.py").write_str(indoc! {r#"
+        import sys
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            print("Build failed", file=sys.stderr)
+            sys.exit(1)
+    "#})?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject = |dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = [{dependencies}]
+
+            [tool.uv.sources]
+            broken = {{ path = "broken" }}
+
+            [[tool.uv.index]]
+            name = "links"
+            format = "flat"
+            url = "./links"
+            default = true
+            "#
+        })
+    };
+
+    write_pyproject(r#""ok==1.0.0""#)?;
+    uv_snapshot!(context.filters(), context.sync().arg("--atomic"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + ok==1.0.0
+    ");
+
+    context
+        .assert_command("import ok; assert ok.__version__ == '1.0.0'")
+        .success();
+
+    // If the sync fails, the environment is left unchanged.
+    write_pyproject(r#""ok==2.0.0", "broken""#)?;
+    uv_snapshot!(context.filters(), context.sync().arg("--atomic"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+      × Failed to build `broken @ file://[TEMP_DIR]/broken`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Build failed
+
+          hint: This usually indicates a problem with the package or the build environment.
+      help: `broken` was included because `project` (v0.1.0) depends on `broken`
+    ");
+
+    context
+        .assert_command("import ok; assert ok.__version__ == '1.0.0'")
+        .success();
+
+    // Once the sync succeeds, the staged environment is swapped into place.
+    write_pyproject(r#""ok==2.0.0""#)?;
+    uv_snapshot!(context.filters(), context.sync().arg("--atomic"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - ok==1.0.0
+     + ok==2.0.0
+    ");
+
+    context
+        .assert_command("import ok; assert ok.__version__ == '2.0.0'")
+        .success();
+
+    // No staging directories are left behind.
+    let leftover = fs_err::read_dir(&context.temp_dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(".venv.uv-staging-")
+        })
+        .count();
+    assert_eq!(leftover, 0);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Run the configured test command with the `test` group synced, passing through arguments.
+#[test]
+fn test_command() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&format!(
+            r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["ok"]
+
+        [tool.uv]
+        package = false
+        no-index = true
+        find-links = ["{}"]
+
+        [tool.uv.test]
+        command = ["python", "-c", "import sys, ok; print(sys.argv[1:])"]
+        "#,
+            context
+                .workspace_root
+                .join("scripts/links")
+                .portable_display()
+        ))?;
+
+    uv_snapshot!(context.filters(), context.test().arg("--preview-features").arg("test").arg("tests/test_foo.py").arg("-x"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['tests/test_foo.py', '-x']
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// An empty test command is rejected.
+#[test]
+fn test_command_empty() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.test]
+        command = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.test().arg("--preview-features").arg("test"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `tool.uv.test.command` setting must not be empty
+    ");
+
+    Ok(())
+}
@@ -4050,6 +4050,85 @@ fn tool_install_with_executables_from() {
     "###);
 }
 
+/// Test installing a tool with `--include-executable` and `--exclude-executable`.
+#[test]
+fn tool_install_executable_filters() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("--with-executables-from")
+        .arg("ansible-core")
+        .arg("--include-executable")
+        .arg("ansible-*")
+        .arg("--exclude-executable")
+        .arg("ansible-test")
+        .arg("ansible==9.3.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + ansible==9.3.0
+     + ansible-core==2.16.4
+     + cffi==1.16.0
+     + cryptography==42.0.5
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + packaging==24.0
+     + pycparser==2.21
+     + pyyaml==6.0.1
+     + resolvelib==1.0.1
+    Installed 9 executables from `ansible-core`: ansible-config, ansible-connection, ansible-console, ansible-doc, ansible-galaxy, ansible-inventory, ansible-playbook, ansible-pull, ansible-vault
+    Installed 1 executable: ansible-community
+    ");
+
+    bin_dir
+        .child(format!("ansible{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+    bin_dir
+        .child(format!("ansible-test{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("ansible").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [
+            { name = "ansible", specifier = "==9.3.0" },
+            { name = "ansible-core" },
+        ]
+        include-executables = ["ansible-*"]
+        exclude-executables = ["ansible-test"]
+        entrypoints = [
+            { name = "ansible-community", install-path = "[TEMP_DIR]/bin/ansible-community", from = "ansible" },
+            { name = "ansible-config", install-path = "[TEMP_DIR]/bin/ansible-config", from = "ansible-core" },
+            { name = "ansible-connection", install-path = "[TEMP_DIR]/bin/ansible-connection", from = "ansible-core" },
+            { name = "ansible-console", install-path = "[TEMP_DIR]/bin/ansible-console", from = "ansible-core" },
+            { name = "ansible-doc", install-path = "[TEMP_DIR]/bin/ansible-doc", from = "ansible-core" },
+            { name = "ansible-galaxy", install-path = "[TEMP_DIR]/bin/ansible-galaxy", from = "ansible-core" },
+            { name = "ansible-inventory", install-path = "[TEMP_DIR]/bin/ansible-inventory", from = "ansible-core" },
+            { name = "ansible-playbook", install-path = "[TEMP_DIR]/bin/ansible-playbook", from = "ansible-core" },
+            { name = "ansible-pull", install-path = "[TEMP_DIR]/bin/ansible-pull", from = "ansible-core" },
+            { name = "ansible-vault", install-path = "[TEMP_DIR]/bin/ansible-vault", from = "ansible-core" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+}
+
 /// Test installing a tool with `--with-executables-from`, but the package has no entrypoints.
 #[test]
 fn tool_install_with_executables_from_no_entrypoints() {
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+use insta::assert_snapshot;
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Build a `hello` wheel with a `hello` executable into the `links` directory.
+fn build_hello(context: &TestContext, version: &str, ok: &str) -> Result<()> {
+    let project = context.temp_dir.child(format!("hello-{version}"));
+    project.child("pyproject.toml").write_str(&formatdoc! {r#"
+        [project]
+        name = "hello"
+        version = "{version}"
+        requires-python = ">=3.12"
+        dependencies = ["ok{ok}"]
+
+        [project.scripts]
+        hello = "hello:main"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project
+        .child("src/hello/__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+
+    context
+        .build()
+        .arg("--wheel")
+        .arg("--offline")
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("links"))
+        .current_dir(&project)
+        .assert()
+        .success();
+    Ok(())
+}
+
+#[test]
+fn tool_lock_sync() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let links = context.temp_dir.child("links");
+
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-1.0.0-py3-none-any.whl"),
+        links.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    build_hello(&context, "1.0.0", ">=1")?;
+
+    context
+        .tool_install()
+        .arg("hello")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_lock()
+        .arg("--preview-features")
+        .arg("tool-lock")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locked 1 tool to: uv-tools.lock
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(context.read("uv-tools.lock"), @r#"
+        version = 1
+
+        [[tool]]
+        name = "hello"
+        requirements = [{ name = "hello" }]
+        packages = [
+            "hello==1.0.0",
+            "ok==1.0.0",
+        ]
+
+        [tool.options]
+        no-index = true
+        find-links = ["file://[TEMP_DIR]/links"]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "#);
+    });
+
+    // Publish newer versions of the tool and its dependency.
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("scripts/links/ok-2.0.0-py3-none-any.whl"),
+        links.join("ok-2.0.0-py3-none-any.whl"),
+    )?;
+    build_hello(&context, "2.0.0", ">=2")?;
+
+    // The tools are already in sync.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--preview-features")
+        .arg("tool-lock")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All tools are already in sync
+    ");
+
+    // On a new machine, the locked versions are installed, rather than the latest versions.
+    let tool_dir = context.temp_dir.child("new-tools");
+    let bin_dir = context.temp_dir.child("new-bin");
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .arg("--preview-features")
+        .arg("tool-lock")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 packages in [TIME]
+     + hello==1.0.0
+     + ok==1.0.0
+    Installed 1 executable: hello
+    Synced tool `hello`
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello v1.0.0
+    - hello
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
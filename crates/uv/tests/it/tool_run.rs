@@ -3191,3 +3191,85 @@ fn tool_run_windows_dotted_package_name() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Reuse the environment from a previous `uv tool run` invocation within `UV_TOOL_RUN_CACHE_TTL`.
+#[test]
+fn tool_run_cache_ttl() {
+    let context = TestContext::new("3.13").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let find_links = context.workspace_root.join("scripts").join("links");
+
+    let tool_run = || {
+        let mut command = context.tool_run();
+        command
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(&find_links)
+            .arg("basic-app")
+            .env(EnvVars::UV_TOOL_RUN_CACHE_TTL, "3600")
+            .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+            .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str());
+        command
+    };
+
+    uv_snapshot!(context.filters(), tool_run(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from basic-app!
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + basic-app==0.1.0
+    ");
+
+    // The second invocation reuses the environment without resolving.
+    uv_snapshot!(context.filters(), tool_run(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from basic-app!
+
+    ----- stderr -----
+    ");
+
+    // The environment hasn't expired, so it's retained.
+    uv_snapshot!(context.filters(), context.prune()
+        .arg("--tools")
+        .env(EnvVars::UV_TOOL_RUN_CACHE_TTL, "3600"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    ");
+
+    // Without a time-to-live, the environment is removed.
+    uv_snapshot!(context.filters(), context.prune().arg("--tools"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    // The next invocation resolves again.
+    uv_snapshot!(context.filters(), tool_run(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from basic-app!
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + basic-app==0.1.0
+    ");
+}
@@ -1704,6 +1704,39 @@ fn show_sizes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn show_cumulative_sizes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#,
+    )?;
+
+    // Packages with dependencies should include the size of their transitive dependencies.
+    uv_snapshot!(context.filters(), context.tree().arg("--size").arg("--universal"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── anyio v3.7.0 ([SIZE], [SIZE] total)
+        ├── idna v3.6 ([SIZE])
+        └── sniffio v1.3.1 ([SIZE])
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn workspace_circular_dependencies() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1799,3 +1832,30 @@ fn workspace_circular_dependencies() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn interactive_requires_terminal() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--interactive").arg("--preview-features").arg("tree-interactive"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv tree --interactive` requires an interactive terminal
+    ");
+
+    Ok(())
+}
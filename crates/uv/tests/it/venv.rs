@@ -882,6 +882,113 @@ fn seed_older_python_version() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn seed_from() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Create a base environment with a package installed.
+    let base = context.temp_dir.child("base");
+    context
+        .venv()
+        .arg(base.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("--python")
+        .arg(base.as_os_str())
+        .arg("iniconfig")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed-from")
+        .arg("base")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Linked 1 package from: base
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    // The linked package should be visible in the new environment.
+    uv_snapshot!(context.filters(), context.pip_list(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package   Version
+    --------- -------
+    iniconfig 2.0.0
+
+    ----- stderr -----
+    ");
+
+    // Uninstalling the package from the new environment should leave the base environment intact.
+    uv_snapshot!(context.filters(), context.pip_uninstall().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_list().arg("--python").arg("base"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package   Version
+    --------- -------
+    iniconfig 2.0.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn seed_from_incompatible() {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let base = context.temp_dir.child("base");
+    context
+        .venv()
+        .arg(base.as_os_str())
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed-from")
+        .arg("base")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    error: The environment at `base` uses CPython 3.11, but the virtual environment uses CPython 3.12
+    "
+    );
+}
+
 #[test]
 fn create_venv_with_invalid_http_timeout() {
     let context = TestContext::new_with_versions(&["3.12"]).with_http_timeout("not_a_number");
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use uv_fs::Simplified;
+
+use crate::common::{TestContext, uv_snapshot};
+
+fn pyproject(context: &TestContext) -> String {
+    format!(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok", "validation"]
+
+        [tool.uv]
+        package = false
+        no-index = true
+        find-links = ["{}"]
+        "#,
+        context
+            .workspace_root
+            .join("scripts/links")
+            .portable_display()
+    )
+}
+
+#[test]
+fn verify() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject(&context))?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.verify().arg("--preview-features").arg("verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 2 packages in [TIME]
+    All installed files match their records
+    ");
+
+    Ok(())
+}
+
+/// Detect modified, missing, and extraneous files.
+#[test]
+fn verify_modified() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject(&context))?;
+
+    context.sync().assert().success();
+
+    let site_packages = context.site_packages();
+    fs_err::write(
+        site_packages.join("ok").join("__init__.py"),
+        "print('hello')\n",
+    )?;
+    fs_err::remove_file(site_packages.join("validation").join("__init__.py"))?;
+    fs_err::write(site_packages.join("ok").join("extra.py"), "")?;
+
+    uv_snapshot!(context.filters(), context.verify().arg("--preview-features").arg("verify"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 2 packages in [TIME]
+    Found 3 issues
+    The file `ok/__init__.py` of `ok==2.0.0` was modified
+    The file `validation/__init__.py` of `validation==1.0.0` is missing
+    The file `ok/extra.py` is not recorded by any installed package
+    ");
+
+    Ok(())
+}
+
+/// Detect packages that are installed but not locked.
+#[test]
+fn verify_not_locked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject(&context))?;
+
+    context.sync().assert().success();
+
+    context
+        .pip_install()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links"))
+        .arg("build-tag")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.verify().arg("--preview-features").arg("verify"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 3 packages in [TIME]
+    Found 1 issue
+    The package `build-tag==1.0.0` is not in the lockfile
+    ");
+
+    Ok(())
+}
+
+/// Verifying without a project environment should fail.
+#[test]
+fn verify_no_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&pyproject(&context))?;
+
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.verify().arg("--preview-features").arg("verify"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No project environment found at `.venv`; run `uv sync` to create it
+      Caused by: No virtual environment or system Python installation found for directory `.venv`; run `uv venv` to create an environment
+    ");
+
+    Ok(())
+}